@@ -564,6 +564,47 @@ async fn do_list<'local>(op: &mut Operator, path: String) -> Result<JObject<'loc
     Ok(jarray.into())
 }
 
+/// # Safety
+///
+/// This function should not be called before the Operator is ready.
+#[no_mangle]
+pub unsafe extern "system" fn Java_org_apache_opendal_AsyncOperator_lister(
+    mut env: JNIEnv,
+    _: JClass,
+    op: *mut Operator,
+    executor: *const Executor,
+    path: JString,
+) -> jlong {
+    intern_lister(&mut env, op, executor, path).unwrap_or_else(|e| {
+        e.throw(&mut env);
+        0
+    })
+}
+
+fn intern_lister(
+    env: &mut JNIEnv,
+    op: *mut Operator,
+    executor: *const Executor,
+    path: JString,
+) -> Result<jlong> {
+    let op = unsafe { &mut *op };
+    let id = request_id(env)?;
+
+    let path = jstring_to_string(env, &path)?;
+
+    executor_or_default(env, executor)?.spawn(async move {
+        let result = do_lister(op, path).await;
+        complete_future(id, result.map(JValueOwned::Long))
+    });
+
+    Ok(id)
+}
+
+async fn do_lister(op: &mut Operator, path: String) -> Result<jlong> {
+    let lister = op.lister(&path).await?;
+    Ok(Box::into_raw(Box::new(lister)) as jlong)
+}
+
 /// # Safety
 ///
 /// This function should not be called before the Operator is ready.
@@ -730,7 +771,7 @@ fn make_object<'local>(
     Ok(o)
 }
 
-fn complete_future(id: jlong, result: Result<JValueOwned>) {
+pub(crate) fn complete_future(id: jlong, result: Result<JValueOwned>) {
     try_complete_future(id, result).expect("complete future must succeed");
 }
 
@@ -760,7 +801,7 @@ fn try_complete_future(id: jlong, result: Result<JValueOwned>) -> Result<()> {
     Ok(())
 }
 
-fn request_id(env: &mut JNIEnv) -> Result<jlong> {
+pub(crate) fn request_id(env: &mut JNIEnv) -> Result<jlong> {
     Ok(env
         .call_static_method(
             "org/apache/opendal/AsyncOperator$AsyncRegistry",
@@ -35,6 +35,7 @@ mod convert;
 mod error;
 mod executor;
 mod layer;
+mod lister;
 mod operator;
 mod operator_input_stream;
 mod operator_output_stream;
@@ -94,7 +95,7 @@ fn make_operator_info<'a>(env: &mut JNIEnv<'a>, info: OperatorInfo) -> Result<JO
 fn make_capability<'a>(env: &mut JNIEnv<'a>, cap: Capability) -> Result<JObject<'a>> {
     let capability = env.new_object(
         "org/apache/opendal/Capability",
-        "(ZZZZZZZZZZZZZZZJJZZZZZZZZZZZZZZ)V",
+        "(ZZZZZZZZZZZZZZZZZZZZZJJZZZZZZZZZZZZZZZZZZZZ)V",
         &[
             JValue::Bool(cap.stat as jboolean),
             JValue::Bool(cap.stat_with_if_match as jboolean),
@@ -105,26 +106,38 @@ fn make_capability<'a>(env: &mut JNIEnv<'a>, cap: Capability) -> Result<JObject<
             JValue::Bool(cap.read_with_override_cache_control as jboolean),
             JValue::Bool(cap.read_with_override_content_disposition as jboolean),
             JValue::Bool(cap.read_with_override_content_type as jboolean),
+            JValue::Bool(cap.read_with_version as jboolean),
             JValue::Bool(cap.write as jboolean),
             JValue::Bool(cap.write_can_multi as jboolean),
             JValue::Bool(cap.write_can_append as jboolean),
             JValue::Bool(cap.write_with_content_type as jboolean),
             JValue::Bool(cap.write_with_content_disposition as jboolean),
             JValue::Bool(cap.write_with_cache_control as jboolean),
+            JValue::Bool(cap.write_with_content_encoding as jboolean),
+            JValue::Bool(cap.write_with_if_match as jboolean),
+            JValue::Bool(cap.write_with_if_none_match as jboolean),
+            JValue::Bool(cap.write_with_if_not_exists as jboolean),
+            JValue::Bool(cap.write_with_user_metadata as jboolean),
             JValue::Long(convert::usize_to_jlong(cap.write_multi_max_size)),
             JValue::Long(convert::usize_to_jlong(cap.write_multi_min_size)),
             JValue::Bool(cap.create_dir as jboolean),
             JValue::Bool(cap.delete as jboolean),
+            JValue::Bool(cap.delete_with_version as jboolean),
             JValue::Bool(cap.copy as jboolean),
             JValue::Bool(cap.rename as jboolean),
             JValue::Bool(cap.list as jboolean),
             JValue::Bool(cap.list_with_limit as jboolean),
             JValue::Bool(cap.list_with_start_after as jboolean),
             JValue::Bool(cap.list_with_recursive as jboolean),
+            JValue::Bool(cap.list_with_versions as jboolean),
+            JValue::Bool(cap.list_with_deleted as jboolean),
             JValue::Bool(cap.presign as jboolean),
             JValue::Bool(cap.presign_read as jboolean),
             JValue::Bool(cap.presign_stat as jboolean),
             JValue::Bool(cap.presign_write as jboolean),
+            JValue::Bool(cap.presign_write_multi as jboolean),
+            JValue::Bool(cap.presign_post as jboolean),
+            JValue::Bool(cap.presign_with_headers as jboolean),
             JValue::Bool(cap.shared as jboolean),
             JValue::Bool(cap.blocking as jboolean),
         ],
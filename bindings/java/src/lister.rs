@@ -0,0 +1,122 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use futures::TryStreamExt;
+use jni::objects::JClass;
+use jni::objects::JObject;
+use jni::objects::JValueOwned;
+use jni::sys::jlong;
+use jni::sys::jobject;
+use jni::JNIEnv;
+use opendal::BlockingLister;
+use opendal::Lister;
+
+use crate::async_operator::complete_future;
+use crate::async_operator::request_id;
+use crate::executor::executor_or_default;
+use crate::executor::get_current_env;
+use crate::executor::Executor;
+use crate::make_entry;
+use crate::Result;
+
+/// # Safety
+///
+/// This function should not be called before the Lister is ready.
+#[no_mangle]
+pub unsafe extern "system" fn Java_org_apache_opendal_Lister_disposeInternal(
+    _: JNIEnv,
+    _: JObject,
+    lister: *mut BlockingLister,
+) {
+    drop(Box::from_raw(lister));
+}
+
+/// # Safety
+///
+/// This function should not be called before the Lister is ready.
+#[no_mangle]
+pub unsafe extern "system" fn Java_org_apache_opendal_Lister_next(
+    mut env: JNIEnv,
+    _: JClass,
+    lister: *mut BlockingLister,
+) -> jobject {
+    intern_next(&mut env, &mut *lister).unwrap_or_else(|e| {
+        e.throw(&mut env);
+        JObject::default().into_raw()
+    })
+}
+
+fn intern_next(env: &mut JNIEnv, lister: &mut BlockingLister) -> Result<jobject> {
+    match lister.next().transpose()? {
+        None => Ok(JObject::null().into_raw()),
+        Some(entry) => Ok(make_entry(env, entry)?.into_raw()),
+    }
+}
+
+/// # Safety
+///
+/// This function should not be called before the AsyncLister is ready.
+#[no_mangle]
+pub unsafe extern "system" fn Java_org_apache_opendal_AsyncLister_disposeInternal(
+    _: JNIEnv,
+    _: JObject,
+    lister: *mut Lister,
+) {
+    drop(Box::from_raw(lister));
+}
+
+/// # Safety
+///
+/// This function should not be called before the AsyncLister is ready.
+#[no_mangle]
+pub unsafe extern "system" fn Java_org_apache_opendal_AsyncLister_next(
+    mut env: JNIEnv,
+    _: JClass,
+    lister: *mut Lister,
+    executor: *const Executor,
+) -> jlong {
+    intern_async_next(&mut env, lister, executor).unwrap_or_else(|e| {
+        e.throw(&mut env);
+        0
+    })
+}
+
+fn intern_async_next(
+    env: &mut JNIEnv,
+    lister: *mut Lister,
+    executor: *const Executor,
+) -> Result<jlong> {
+    let lister = unsafe { &mut *lister };
+    let id = request_id(env)?;
+
+    executor_or_default(env, executor)?.spawn(async move {
+        let result = do_async_next(lister).await;
+        complete_future(id, result.map(JValueOwned::Object))
+    });
+
+    Ok(id)
+}
+
+async fn do_async_next<'local>(lister: &mut Lister) -> Result<JObject<'local>> {
+    match lister.try_next().await? {
+        None => Ok(JObject::null()),
+        Some(entry) => {
+            let mut env = unsafe { get_current_env() };
+            make_entry(&mut env, entry)
+        }
+    }
+}
@@ -0,0 +1,58 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+package org.apache.opendal;
+
+import java.util.concurrent.CompletableFuture;
+
+/**
+ * AsyncLister lists entries under a path lazily, entries are fetched from the underlying
+ * service page by page while iterating.
+ *
+ * <p>
+ * AsyncLister holds a native object, please close it after use.
+ *
+ * @see AsyncOperator#lister(String)
+ */
+public class AsyncLister extends NativeObject {
+    private final long executorHandle;
+
+    AsyncLister(long nativeHandle, long executorHandle) {
+        super(nativeHandle);
+        this.executorHandle = executorHandle;
+    }
+
+    /**
+     * Fetch the next entry.
+     *
+     * <p>
+     * Please wait for the returned future to complete before calling next again.
+     *
+     * @return the future of next entry, which completes with null if there are no more entries.
+     */
+    public CompletableFuture<Entry> next() {
+        final long requestId = next(nativeHandle, executorHandle);
+        return AsyncOperator.AsyncRegistry.take(requestId);
+    }
+
+    @Override
+    protected native void disposeInternal(long handle);
+
+    private static native long next(long lister, long executorHandle);
+}
@@ -45,7 +45,7 @@ public class AsyncOperator extends NativeObject {
      * @see <a href="https://docs.oracle.com/javase/8/docs/technotes/guides/jni/spec/functions.html#global_references">Global References</a>
      * @see <a href="https://docs.rs/jni/latest/jni/objects/struct.GlobalRef.html">jni::objects::GlobalRef</a>
      */
-    private enum AsyncRegistry {
+    enum AsyncRegistry {
         INSTANCE;
 
         private final Map<Long, CompletableFuture<?>> registry = new ConcurrentHashMap<>();
@@ -93,7 +93,7 @@ public class AsyncOperator extends NativeObject {
          * @return the future associated with the request ID
          */
         @SuppressWarnings("unchecked")
-        private static <T> CompletableFuture<T> take(long requestId) {
+        static <T> CompletableFuture<T> take(long requestId) {
             final CompletableFuture<?> f = get(requestId);
             if (f != null) {
                 f.whenComplete((r, e) -> INSTANCE.registry.remove(requestId));
@@ -189,6 +189,36 @@ public class AsyncOperator extends NativeObject {
         return new Operator(nativeHandle, info);
     }
 
+    /**
+     * Create an {@link OperatorInputStream} to read the given path.
+     *
+     * <p>The stream is backed by a blocking reader, so it can be used in existing {@code java.io}
+     * pipelines.
+     */
+    public OperatorInputStream createInputStream(String path) {
+        try (final Operator op = blocking()) {
+            return new OperatorInputStream(op, path);
+        }
+    }
+
+    /**
+     * Create an {@link OperatorOutputStream} to write the given path.
+     *
+     * <p>The stream is backed by a blocking writer, so it can be used in existing {@code java.io}
+     * pipelines.
+     */
+    public OperatorOutputStream createOutputStream(String path) {
+        try (final Operator op = blocking()) {
+            return new OperatorOutputStream(op, path);
+        }
+    }
+
+    public OperatorOutputStream createOutputStream(String path, int maxBytes) {
+        try (final Operator op = blocking()) {
+            return new OperatorOutputStream(op, path, maxBytes);
+        }
+    }
+
     public CompletableFuture<Void> write(String path, String content) {
         return write(path, content.getBytes(StandardCharsets.UTF_8));
     }
@@ -217,16 +247,49 @@ public class AsyncOperator extends NativeObject {
         return AsyncRegistry.take(requestId);
     }
 
+    /**
+     * Presign an operation to read the content of the path.
+     *
+     * <p>
+     * The returned {@link PresignedRequest} carries the HTTP method, signed URI and headers,
+     * which can be handed to clients to perform the request against the service directly.
+     *
+     * @param path the path to presign.
+     * @param duration how long the presigned request stays valid.
+     * @return the future of presigned request.
+     */
     public CompletableFuture<PresignedRequest> presignRead(String path, Duration duration) {
         final long requestId = presignRead(nativeHandle, executorHandle, path, duration.toNanos());
         return AsyncRegistry.take(requestId);
     }
 
+    /**
+     * Presign an operation to write content to the path.
+     *
+     * <p>
+     * The returned {@link PresignedRequest} carries the HTTP method, signed URI and headers,
+     * which can be handed to clients to perform the request against the service directly.
+     *
+     * @param path the path to presign.
+     * @param duration how long the presigned request stays valid.
+     * @return the future of presigned request.
+     */
     public CompletableFuture<PresignedRequest> presignWrite(String path, Duration duration) {
         final long requestId = presignWrite(nativeHandle, executorHandle, path, duration.toNanos());
         return AsyncRegistry.take(requestId);
     }
 
+    /**
+     * Presign an operation to stat the metadata of the path.
+     *
+     * <p>
+     * The returned {@link PresignedRequest} carries the HTTP method, signed URI and headers,
+     * which can be handed to clients to perform the request against the service directly.
+     *
+     * @param path the path to presign.
+     * @param duration how long the presigned request stays valid.
+     * @return the future of presigned request.
+     */
     public CompletableFuture<PresignedRequest> presignStat(String path, Duration duration) {
         final long requestId = presignStat(nativeHandle, executorHandle, path, duration.toNanos());
         return AsyncRegistry.take(requestId);
@@ -263,6 +326,18 @@ public class AsyncOperator extends NativeObject {
         return Objects.requireNonNull(result).thenApplyAsync(Arrays::asList);
     }
 
+    /**
+     * Create a lister to list entries under the path lazily.
+     *
+     * @param path the path to list, must end with "/" to list a dir.
+     * @return the future of lister, which should be closed after use.
+     */
+    public CompletableFuture<AsyncLister> lister(String path) {
+        final long requestId = lister(nativeHandle, executorHandle, path);
+        final CompletableFuture<Long> result = AsyncRegistry.take(requestId);
+        return Objects.requireNonNull(result).thenApply(handle -> new AsyncLister(handle, executorHandle));
+    }
+
     @Override
     protected native void disposeInternal(long handle);
 
@@ -299,4 +374,6 @@ public class AsyncOperator extends NativeObject {
     private static native long removeAll(long nativeHandle, long executorHandle, String path);
 
     private static native long list(long nativeHandle, long executorHandle, String path);
+
+    private static native long lister(long nativeHandle, long executorHandle, String path);
 }
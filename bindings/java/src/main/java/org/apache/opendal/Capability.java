@@ -68,6 +68,11 @@ public class Capability {
      */
     public final boolean readWithOverrideContentType;
 
+    /**
+     * If operator supports read with version.
+     */
+    public final boolean readWithVersion;
+
     /**
      * If operator supports write.
      */
@@ -98,6 +103,31 @@ public class Capability {
      */
     public final boolean writeWithCacheControl;
 
+    /**
+     * If operator supports write with content encoding.
+     */
+    public final boolean writeWithContentEncoding;
+
+    /**
+     * If operator supports write with if matched.
+     */
+    public final boolean writeWithIfMatch;
+
+    /**
+     * If operator supports write with if none match.
+     */
+    public final boolean writeWithIfNoneMatch;
+
+    /**
+     * If operator supports write with if not exists.
+     */
+    public final boolean writeWithIfNotExists;
+
+    /**
+     * If operator supports write with user metadata.
+     */
+    public final boolean writeWithUserMetadata;
+
     /**
      * write_multi_max_size is the max size that services support in write_multi.
      * For example, AWS S3 supports 5GiB as max in write_multi.
@@ -120,6 +150,11 @@ public class Capability {
      */
     public final boolean delete;
 
+    /**
+     * If operator supports delete with version.
+     */
+    public final boolean deleteWithVersion;
+
     /**
      * If operator supports copy.
      */
@@ -150,6 +185,16 @@ public class Capability {
      */
     public final boolean listWithRecursive;
 
+    /**
+     * If backend supports list with versions.
+     */
+    public final boolean listWithVersions;
+
+    /**
+     * If backend supports list with deleted.
+     */
+    public final boolean listWithDeleted;
+
     /**
      * If operator supports presign.
      */
@@ -170,6 +215,21 @@ public class Capability {
      */
     public final boolean presignWrite;
 
+    /**
+     * If operator supports presign multipart upload.
+     */
+    public final boolean presignWriteMulti;
+
+    /**
+     * If operator supports presign post.
+     */
+    public final boolean presignPost;
+
+    /**
+     * If operator supports presign with extra signed headers.
+     */
+    public final boolean presignWithHeaders;
+
     /**
      * If operator supports shared.
      */
@@ -190,26 +250,38 @@ public class Capability {
             boolean readWithOverrideCacheControl,
             boolean readWithOverrideContentDisposition,
             boolean readWithOverrideContentType,
+            boolean readWithVersion,
             boolean write,
             boolean writeCanMulti,
             boolean writeCanAppend,
             boolean writeWithContentType,
             boolean writeWithContentDisposition,
             boolean writeWithCacheControl,
+            boolean writeWithContentEncoding,
+            boolean writeWithIfMatch,
+            boolean writeWithIfNoneMatch,
+            boolean writeWithIfNotExists,
+            boolean writeWithUserMetadata,
             long writeMultiMaxSize,
             long writeMultiMinSize,
             boolean createDir,
             boolean delete,
+            boolean deleteWithVersion,
             boolean copy,
             boolean rename,
             boolean list,
             boolean listWithLimit,
             boolean listWithStartAfter,
             boolean listWithRecursive,
+            boolean listWithVersions,
+            boolean listWithDeleted,
             boolean presign,
             boolean presignRead,
             boolean presignStat,
             boolean presignWrite,
+            boolean presignWriteMulti,
+            boolean presignPost,
+            boolean presignWithHeaders,
             boolean blocking,
             boolean shared) {
         this.stat = stat;
@@ -221,26 +293,38 @@ public class Capability {
         this.readWithOverrideCacheControl = readWithOverrideCacheControl;
         this.readWithOverrideContentDisposition = readWithOverrideContentDisposition;
         this.readWithOverrideContentType = readWithOverrideContentType;
+        this.readWithVersion = readWithVersion;
         this.write = write;
         this.writeCanMulti = writeCanMulti;
         this.writeCanAppend = writeCanAppend;
         this.writeWithContentType = writeWithContentType;
         this.writeWithContentDisposition = writeWithContentDisposition;
         this.writeWithCacheControl = writeWithCacheControl;
+        this.writeWithContentEncoding = writeWithContentEncoding;
+        this.writeWithIfMatch = writeWithIfMatch;
+        this.writeWithIfNoneMatch = writeWithIfNoneMatch;
+        this.writeWithIfNotExists = writeWithIfNotExists;
+        this.writeWithUserMetadata = writeWithUserMetadata;
         this.writeMultiMaxSize = writeMultiMaxSize;
         this.writeMultiMinSize = writeMultiMinSize;
         this.createDir = createDir;
         this.delete = delete;
+        this.deleteWithVersion = deleteWithVersion;
         this.copy = copy;
         this.rename = rename;
         this.list = list;
         this.listWithLimit = listWithLimit;
         this.listWithStartAfter = listWithStartAfter;
         this.listWithRecursive = listWithRecursive;
+        this.listWithVersions = listWithVersions;
+        this.listWithDeleted = listWithDeleted;
         this.presign = presign;
         this.presignRead = presignRead;
         this.presignStat = presignStat;
         this.presignWrite = presignWrite;
+        this.presignWriteMulti = presignWriteMulti;
+        this.presignPost = presignPost;
+        this.presignWithHeaders = presignWithHeaders;
         this.blocking = blocking;
         this.shared = shared;
     }
@@ -0,0 +1,66 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+package org.apache.opendal;
+
+import java.util.Iterator;
+import java.util.NoSuchElementException;
+
+/**
+ * Lister lists entries under a path lazily, entries are fetched from the underlying
+ * service page by page while iterating.
+ *
+ * <p>
+ * Lister holds a native object, please close it after use.
+ *
+ * @see Operator#lister(String)
+ */
+public class Lister extends NativeObject implements Iterator<Entry> {
+    private Entry nextEntry;
+    private boolean fetched = false;
+
+    Lister(long nativeHandle) {
+        super(nativeHandle);
+    }
+
+    @Override
+    public boolean hasNext() {
+        if (!fetched) {
+            nextEntry = next(nativeHandle);
+            fetched = true;
+        }
+        return nextEntry != null;
+    }
+
+    @Override
+    public Entry next() {
+        if (!hasNext()) {
+            throw new NoSuchElementException();
+        }
+        final Entry entry = nextEntry;
+        nextEntry = null;
+        fetched = false;
+        return entry;
+    }
+
+    @Override
+    protected native void disposeInternal(long handle);
+
+    private static native Entry next(long lister);
+}
@@ -69,6 +69,8 @@ public class OpenDALException extends RuntimeException {
         NotADirectory,
         AlreadyExists,
         RateLimited,
+        Unavailable,
+        TimedOut,
         IsSameFile,
         ConditionNotMatch,
         RangeNotSatisfied,
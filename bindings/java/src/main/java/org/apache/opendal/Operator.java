@@ -123,6 +123,16 @@ public class Operator extends NativeObject {
         return Arrays.asList(list(nativeHandle, path));
     }
 
+    /**
+     * Create a lister to list entries under the path lazily.
+     *
+     * @param path the path to list, must end with "/" to list a dir.
+     * @return the lister, which should be closed after use.
+     */
+    public Lister lister(String path) {
+        return new Lister(lister(nativeHandle, path));
+    }
+
     @Override
     protected native void disposeInternal(long handle);
 
@@ -145,4 +155,6 @@ public class Operator extends NativeObject {
     private static native void removeAll(long op, String path);
 
     private static native Entry[] list(long op, String path);
+
+    private static native long lister(long op, String path);
 }
@@ -46,16 +46,39 @@ public class OperatorInputStream extends InputStream {
 
     @Override
     public int read() throws IOException {
-        if (bytes != null && offset >= bytes.length) {
-            bytes = readNextBytes(reader.nativeHandle);
-            offset = 0;
+        if (!fill()) {
+            return -1;
         }
+        return bytes[offset++] & 0xFF;
+    }
 
-        if (bytes != null) {
-            return bytes[offset++] & 0xFF;
+    /**
+     * Read up to {@code len} bytes into {@code b}.
+     *
+     * <p>Bytes are transferred from native side chunk by chunk, so this method is much more
+     * efficient than reading byte by byte.
+     */
+    @Override
+    public int read(byte[] b, int off, int len) throws IOException {
+        if (off < 0 || len < 0 || len > b.length - off) {
+            throw new IndexOutOfBoundsException("off: " + off + ", len: " + len + ", size: " + b.length);
         }
+        if (len == 0) {
+            return 0;
+        }
+        if (!fill()) {
+            return -1;
+        }
+
+        final int n = Math.min(len, bytes.length - offset);
+        System.arraycopy(bytes, offset, b, off, n);
+        offset += n;
+        return n;
+    }
 
-        return -1;
+    @Override
+    public int available() throws IOException {
+        return bytes == null ? 0 : bytes.length - offset;
     }
 
     @Override
@@ -63,6 +86,19 @@ public class OperatorInputStream extends InputStream {
         reader.close();
     }
 
+    /**
+     * Fetch the next chunk from native side if current one has been consumed.
+     *
+     * @return false if the stream reaches its end.
+     */
+    private boolean fill() {
+        while (bytes != null && offset >= bytes.length) {
+            bytes = readNextBytes(reader.nativeHandle);
+            offset = 0;
+        }
+        return bytes != null;
+    }
+
     private static native long constructReader(long op, String path);
 
     private static native long disposeReader(long reader);
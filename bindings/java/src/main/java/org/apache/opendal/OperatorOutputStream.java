@@ -62,8 +62,35 @@ public class OperatorOutputStream extends OutputStream {
         }
     }
 
+    /**
+     * Write {@code len} bytes from {@code b}.
+     *
+     * <p>Bytes are buffered and transferred to native side every {@code maxBytes} bytes.
+     */
+    @Override
+    public void write(byte[] b, int off, int len) throws IOException {
+        if (off < 0 || len < 0 || len > b.length - off) {
+            throw new IndexOutOfBoundsException("off: " + off + ", len: " + len + ", size: " + b.length);
+        }
+
+        while (len > 0) {
+            final int n = Math.min(len, maxBytes - offset);
+            System.arraycopy(b, off, bytes, offset, n);
+            offset += n;
+            off += n;
+            len -= n;
+            if (offset >= maxBytes) {
+                flush();
+            }
+        }
+    }
+
     @Override
     public void flush() throws IOException {
+        if (offset == 0) {
+            return;
+        }
+
         if (offset > maxBytes) {
             throw new IOException("INTERNAL ERROR: " + offset + " > " + maxBytes);
         } else if (offset < maxBytes) {
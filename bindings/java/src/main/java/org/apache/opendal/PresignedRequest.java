@@ -22,6 +22,13 @@ package org.apache.opendal;
 import java.util.Map;
 import lombok.Data;
 
+/**
+ * PresignedRequest is a signed HTTP request that can be sent without credentials.
+ *
+ * @see AsyncOperator#presignRead(String, java.time.Duration)
+ * @see AsyncOperator#presignWrite(String, java.time.Duration)
+ * @see AsyncOperator#presignStat(String, java.time.Duration)
+ */
 @Data
 public class PresignedRequest {
     /**
@@ -279,3 +279,25 @@ fn intern_list(env: &mut JNIEnv, op: &mut BlockingOperator, path: JString) -> Re
 
     Ok(jarray.into_raw())
 }
+
+/// # Safety
+///
+/// This function should not be called before the Operator is ready.
+#[no_mangle]
+pub unsafe extern "system" fn Java_org_apache_opendal_Operator_lister(
+    mut env: JNIEnv,
+    _: JClass,
+    op: *mut BlockingOperator,
+    path: JString,
+) -> jlong {
+    intern_lister(&mut env, &mut *op, path).unwrap_or_else(|e| {
+        e.throw(&mut env);
+        0
+    })
+}
+
+fn intern_lister(env: &mut JNIEnv, op: &mut BlockingOperator, path: JString) -> Result<jlong> {
+    let path = jstring_to_string(env, &path)?;
+    let lister = op.lister(&path)?;
+    Ok(Box::into_raw(Box::new(lister)) as jlong)
+}
@@ -21,10 +21,13 @@ package org.apache.opendal.test;
 
 import static org.assertj.core.api.Assertions.assertThat;
 import java.io.BufferedReader;
+import java.io.ByteArrayOutputStream;
 import java.io.InputStreamReader;
 import java.nio.file.Path;
+import java.util.Random;
 import java.util.concurrent.atomic.AtomicLong;
 import java.util.stream.Stream;
+import org.apache.opendal.AsyncOperator;
 import org.apache.opendal.Operator;
 import org.apache.opendal.OperatorInputStream;
 import org.apache.opendal.OperatorOutputStream;
@@ -62,4 +65,31 @@ public class OperatorInputOutputStreamTest {
             }
         }
     }
+
+    @Test
+    void testReadWriteWithStreamInBulk() throws Exception {
+        final ServiceConfig.Fs fs =
+                ServiceConfig.Fs.builder().root(tempDir.toString()).build();
+
+        try (final AsyncOperator op = AsyncOperator.of(fs)) {
+            final String path = "OperatorInputOutputStreamTestInBulk.bin";
+            final byte[] content = new byte[1024 * 1024 + 17];
+            new Random().nextBytes(content);
+
+            try (final OperatorOutputStream os = op.createOutputStream(path, 4096)) {
+                os.write(content, 0, 10);
+                os.write(content, 10, content.length - 10);
+            }
+
+            try (final OperatorInputStream is = op.createInputStream(path)) {
+                final ByteArrayOutputStream out = new ByteArrayOutputStream();
+                final byte[] buf = new byte[8192];
+                int n;
+                while ((n = is.read(buf, 0, buf.length)) != -1) {
+                    out.write(buf, 0, n);
+                }
+                assertThat(out.toByteArray()).isEqualTo(content);
+            }
+        }
+    }
 }
@@ -28,6 +28,7 @@ import java.util.Collections;
 import java.util.List;
 import java.util.UUID;
 import java.util.stream.Collectors;
+import org.apache.opendal.AsyncLister;
 import org.apache.opendal.Capability;
 import org.apache.opendal.Entry;
 import org.apache.opendal.Metadata;
@@ -73,6 +74,31 @@ class AsyncListTest extends BehaviorTestBase {
         asyncOp().delete(path).join();
     }
 
+    /**
+     * Lister should return newly created file lazily.
+     */
+    @Test
+    public void testListerDir() {
+        final String parent = UUID.randomUUID().toString();
+        final String path = String.format("%s/%s", parent, UUID.randomUUID());
+        final byte[] content = generateBytes();
+
+        asyncOp().write(path, content).join();
+
+        boolean found = false;
+        try (AsyncLister lister = asyncOp().lister(parent + "/").join()) {
+            Entry entry;
+            while ((entry = lister.next().join()) != null) {
+                if (entry.getPath().equals(path)) {
+                    assertTrue(entry.getMetadata().isFile());
+                    found = true;
+                }
+            }
+        }
+        assertTrue(found);
+        asyncOp().delete(path).join();
+    }
+
     /**
      * listing a directory, which contains more objects than a single page can take.
      */
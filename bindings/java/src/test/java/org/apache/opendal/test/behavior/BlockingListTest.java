@@ -27,6 +27,7 @@ import java.util.List;
 import java.util.UUID;
 import org.apache.opendal.Capability;
 import org.apache.opendal.Entry;
+import org.apache.opendal.Lister;
 import org.apache.opendal.Metadata;
 import org.apache.opendal.OpenDALException;
 import org.apache.opendal.test.condition.OpenDALExceptionCondition;
@@ -71,6 +72,29 @@ public class BlockingListTest extends BehaviorTestBase {
         op().delete(path);
     }
 
+    @Test
+    public void testBlockingListerDir() {
+        final String parent = UUID.randomUUID().toString();
+        final String path = String.format("%s/%s", parent, UUID.randomUUID());
+        final byte[] content = generateBytes();
+
+        op().write(path, content);
+
+        boolean found = false;
+        try (Lister lister = op().lister(parent + "/")) {
+            while (lister.hasNext()) {
+                final Entry entry = lister.next();
+                if (entry.getPath().equals(path)) {
+                    assertTrue(entry.getMetadata().isFile());
+                    found = true;
+                }
+            }
+        }
+        assertTrue(found);
+
+        op().delete(path);
+    }
+
     @Test
     public void testBlockingListNonExistDir() {
         final String dir = String.format("%s/", UUID.randomUUID());
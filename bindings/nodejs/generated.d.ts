@@ -99,6 +99,19 @@ export interface PresignedRequest {
   /** HTTP headers of this request. */
   headers: Record<string, string>
 }
+/** RetryOptions is used to attach a retry layer while constructing an `Operator`. */
+export interface RetryOptions {
+  /** Set max_times of current backoff. */
+  maxTimes?: number
+  /** Enable jitter of current backoff. */
+  jitter?: boolean
+  /** Set factor of current backoff, must not be smaller than `1.0`. */
+  factor?: number
+  /** Set min_delay of current backoff in millisecond. */
+  minDelay?: number
+  /** Set max_delay of current backoff in millisecond. */
+  maxDelay?: number
+}
 /**
  * Capability is used to describe what operations are supported
  *  by the current Operator.
@@ -130,6 +143,8 @@ export class Capability {
   get readWithOverrideContentDisposition(): boolean
   /** if operator supports read with override content type. */
   get readWithOverrideContentType(): boolean
+  /** If operator supports read with version. */
+  get readWithVersion(): boolean
   /** If operator supports write. */
   get write(): boolean
   /** If operator supports write can be called in multi times. */
@@ -144,6 +159,16 @@ export class Capability {
   get writeWithContentDisposition(): boolean
   /** If operator supports write with cache control. */
   get writeWithCacheControl(): boolean
+  /** If operator supports write with content encoding. */
+  get writeWithContentEncoding(): boolean
+  /** If operator supports write with if match. */
+  get writeWithIfMatch(): boolean
+  /** If operator supports write with if none match. */
+  get writeWithIfNoneMatch(): boolean
+  /** If operator supports write with if not exists. */
+  get writeWithIfNotExists(): boolean
+  /** If operator supports write with user metadata. */
+  get writeWithUserMetadata(): boolean
   /**
    * write_multi_max_size is the max size that services support in write_multi.
    *
@@ -166,6 +191,8 @@ export class Capability {
   get createDir(): boolean
   /** If operator supports delete. */
   get delete(): boolean
+  /** If operator supports delete with version. */
+  get deleteWithVersion(): boolean
   /** If operator supports copy. */
   get copy(): boolean
   /** If operator supports rename. */
@@ -178,6 +205,10 @@ export class Capability {
   get listWithStartAfter(): boolean
   /** If backend supports list with recursive. */
   get listWithRecursive(): boolean
+  /** If backend supports list with versions. */
+  get listWithVersions(): boolean
+  /** If backend supports list with deleted. */
+  get listWithDeleted(): boolean
   /** If operator supports presign. */
   get presign(): boolean
   /** If operator supports presign read. */
@@ -186,6 +217,12 @@ export class Capability {
   get presignStat(): boolean
   /** If operator supports presign write. */
   get presignWrite(): boolean
+  /** If operator supports presign multipart upload. */
+  get presignWriteMulti(): boolean
+  /** If operator supports presign post. */
+  get presignPost(): boolean
+  /** If operator supports presign with extra signed headers. */
+  get presignWithHeaders(): boolean
   /** If operator supports shared. */
   get shared(): boolean
   /** If operator supports blocking. */
@@ -197,8 +234,21 @@ export class Operator {
    * And the options,
    * please refer to the documentation of the corresponding service for the corresponding parameters.
    * Note that the current options key is snake_case.
+   *
+   * Besides the service options, the following options are also accepted:
+   *
+   * - `retry`: attach a retry layer with given `RetryOptions`, like `{ maxTimes: 5 }`.
+   * - `timeout`: timeout of every operation and io call, the unit is millisecond and it
+   *   will be rounded up to seconds. It's a shortcut of `default_timeout` and
+   *   `default_io_timeout`, which take precedence if set.
+   *
+   * Other operator defaults like `default_retry_max_times` are accepted as well.
+   *
+   * ```javascript
+   * const op = new Operator("s3", { bucket: "test", retry: { maxTimes: 5 }, timeout: 30_000 })
+   * ```
    */
-  constructor(scheme: string, options?: Record<string, string> | undefined | null)
+  constructor(scheme: string, options?: Record<string, string | number | RetryOptions> | undefined | null)
   /** Get current operator(service)'s full capability. */
   capability(): Capability
   /**
@@ -90,6 +90,12 @@ impl Capability {
         self.0.read_with_override_content_type
     }
 
+    /// If operator supports read with version.
+    #[napi(getter)]
+    pub fn read_with_version(&self) -> bool {
+        self.0.read_with_version
+    }
+
     /// If operator supports write.
     #[napi(getter)]
     pub fn write(&self) -> bool {
@@ -132,6 +138,36 @@ impl Capability {
         self.0.write_with_cache_control
     }
 
+    /// If operator supports write with content encoding.
+    #[napi(getter)]
+    pub fn write_with_content_encoding(&self) -> bool {
+        self.0.write_with_content_encoding
+    }
+
+    /// If operator supports write with if match.
+    #[napi(getter)]
+    pub fn write_with_if_match(&self) -> bool {
+        self.0.write_with_if_match
+    }
+
+    /// If operator supports write with if none match.
+    #[napi(getter)]
+    pub fn write_with_if_none_match(&self) -> bool {
+        self.0.write_with_if_none_match
+    }
+
+    /// If operator supports write with if not exists.
+    #[napi(getter)]
+    pub fn write_with_if_not_exists(&self) -> bool {
+        self.0.write_with_if_not_exists
+    }
+
+    /// If operator supports write with user metadata.
+    #[napi(getter)]
+    pub fn write_with_user_metadata(&self) -> bool {
+        self.0.write_with_user_metadata
+    }
+
     /// write_multi_max_size is the max size that services support in write_multi.
     ///
     /// For example, AWS S3 supports 5GiB as max in write_multi.
@@ -167,6 +203,12 @@ impl Capability {
         self.0.delete
     }
 
+    /// If operator supports delete with version.
+    #[napi(getter)]
+    pub fn delete_with_version(&self) -> bool {
+        self.0.delete_with_version
+    }
+
     /// If operator supports copy.
     #[napi(getter)]
     pub fn copy(&self) -> bool {
@@ -203,6 +245,18 @@ impl Capability {
         self.0.list_with_recursive
     }
 
+    /// If backend supports list with versions.
+    #[napi(getter)]
+    pub fn list_with_versions(&self) -> bool {
+        self.0.list_with_versions
+    }
+
+    /// If backend supports list with deleted.
+    #[napi(getter)]
+    pub fn list_with_deleted(&self) -> bool {
+        self.0.list_with_deleted
+    }
+
     /// If operator supports presign.
     #[napi(getter)]
     pub fn presign(&self) -> bool {
@@ -227,6 +281,24 @@ impl Capability {
         self.0.presign_write
     }
 
+    /// If operator supports presign multipart upload.
+    #[napi(getter)]
+    pub fn presign_write_multi(&self) -> bool {
+        self.0.presign_write_multi
+    }
+
+    /// If operator supports presign post.
+    #[napi(getter)]
+    pub fn presign_post(&self) -> bool {
+        self.0.presign_post
+    }
+
+    /// If operator supports presign with extra signed headers.
+    #[napi(getter)]
+    pub fn presign_with_headers(&self) -> bool {
+        self.0.presign_with_headers
+    }
+
     /// If operator supports shared.
     #[napi(getter)]
     pub fn shared(&self) -> bool {
@@ -27,6 +27,8 @@ use std::time::Duration;
 use futures::AsyncReadExt;
 use futures::TryStreamExt;
 use napi::bindgen_prelude::*;
+use napi::JsObject;
+use napi::JsUnknown;
 
 mod capability;
 
@@ -39,17 +41,62 @@ impl Operator {
     /// And the options,
     /// please refer to the documentation of the corresponding service for the corresponding parameters.
     /// Note that the current options key is snake_case.
+    ///
+    /// Besides the service options, the following options are also accepted:
+    ///
+    /// - `retry`: attach a retry layer with given `RetryOptions`, like `{ maxTimes: 5 }`.
+    /// - `timeout`: timeout of every operation and io call, the unit is millisecond and it
+    ///   will be rounded up to seconds. It's a shortcut of `default_timeout` and
+    ///   `default_io_timeout`, which take precedence if set.
+    ///
+    /// Other operator defaults like `default_retry_max_times` are accepted as well.
+    ///
+    /// ```javascript
+    /// const op = new Operator("s3", { bucket: "test", retry: { maxTimes: 5 }, timeout: 30_000 })
+    /// ```
     #[napi(constructor)]
-    pub fn new(scheme: String, options: Option<HashMap<String, String>>) -> Result<Self> {
+    pub fn new(
+        scheme: String,
+        #[napi(ts_arg_type = "Record<string, string | number | RetryOptions> | undefined | null")]
+        options: Option<JsObject>,
+    ) -> Result<Self> {
         let scheme = opendal::Scheme::from_str(&scheme)
             .map_err(|err| {
                 opendal::Error::new(opendal::ErrorKind::Unexpected, "not supported scheme")
                     .set_source(err)
             })
             .map_err(format_napi_error)?;
-        let options = options.unwrap_or_default();
 
-        let mut op = opendal::Operator::via_iter(scheme, options).map_err(format_napi_error)?;
+        let mut map = HashMap::new();
+        let mut retry: Option<RetryOptions> = None;
+        let mut timeout: Option<f64> = None;
+        if let Some(options) = options {
+            for key in JsObject::keys(&options)? {
+                match key.as_str() {
+                    "retry" => retry = Some(options.get_named_property_unchecked(&key)?),
+                    "timeout" => timeout = Some(options.get_named_property_unchecked(&key)?),
+                    _ => {
+                        let value: JsUnknown = options.get_named_property_unchecked(&key)?;
+                        let value = value.coerce_to_string()?.into_utf8()?.into_owned()?;
+                        map.insert(key, value);
+                    }
+                }
+            }
+        }
+
+        if let Some(timeout) = timeout {
+            let secs = parse_timeout(timeout)?.to_string();
+            map.entry("default_timeout".to_string())
+                .or_insert_with(|| secs.clone());
+            map.entry("default_io_timeout".to_string()).or_insert(secs);
+        }
+
+        // Timeout is applied while building, so retry applied here could
+        // retry timed out requests.
+        let mut op = opendal::Operator::via_iter(scheme, map).map_err(format_napi_error)?;
+        if let Some(retry) = retry {
+            op = op.layer(retry.into_layer());
+        }
 
         if !op.info().full_capability().blocking {
             if let Ok(handle) = tokio::runtime::Handle::try_current() {
@@ -517,7 +564,7 @@ impl Operator {
         let mut l = self.0.list_with(&path);
         if let Some(options) = options {
             if let Some(limit) = options.limit {
-                l = l.limit(limit as usize);
+                l = l.chunk(limit as usize);
             }
             if let Some(recursive) = options.recursive {
                 l = l.recursive(recursive);
@@ -567,7 +614,7 @@ impl Operator {
         let mut l = self.0.blocking().list_with(&path);
         if let Some(options) = options {
             if let Some(limit) = options.limit {
-                l = l.limit(limit as usize);
+                l = l.chunk(limit as usize);
             }
             if let Some(recursive) = options.recursive {
                 l = l.recursive(recursive);
@@ -1123,8 +1170,39 @@ impl RetryLayer {
 
     #[napi]
     pub fn build(&self) -> External<Layer> {
+        let l = RetryOptions {
+            max_times: self.max_times,
+            jitter: Some(self.jitter),
+            factor: self.factor,
+            min_delay: self.min_delay,
+            max_delay: self.max_delay,
+        }
+        .into_layer();
+
+        External::new(Layer { inner: Box::new(l) })
+    }
+}
+
+/// RetryOptions is used to attach a retry layer while constructing an `Operator`.
+#[napi(object)]
+#[derive(Default)]
+pub struct RetryOptions {
+    /// Set max_times of current backoff.
+    pub max_times: Option<u32>,
+    /// Enable jitter of current backoff.
+    pub jitter: Option<bool>,
+    /// Set factor of current backoff, must not be smaller than `1.0`.
+    pub factor: Option<f64>,
+    /// Set min_delay of current backoff in millisecond.
+    pub min_delay: Option<f64>,
+    /// Set max_delay of current backoff in millisecond.
+    pub max_delay: Option<f64>,
+}
+
+impl RetryOptions {
+    fn into_layer(self) -> opendal::layers::RetryLayer {
         let mut l = opendal::layers::RetryLayer::default();
-        if self.jitter {
+        if self.jitter.unwrap_or_default() {
             l = l.with_jitter();
         }
         if let Some(max_times) = self.max_times {
@@ -1139,14 +1217,24 @@ impl RetryLayer {
         if let Some(min_delay) = self.min_delay {
             l = l.with_min_delay(Duration::from_millis(min_delay as u64));
         }
-
-        External::new(Layer { inner: Box::new(l) })
+        l
     }
 }
 
 /// Format opendal error to napi error.
 ///
 /// FIXME: handle error correctly.
+/// Parse the timeout in millisecond into seconds, rounded up.
+fn parse_timeout(timeout: f64) -> Result<u64> {
+    if !timeout.is_finite() || timeout <= 0.0 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("timeout must be a positive number in millisecond, got {timeout}"),
+        ));
+    }
+    Ok((timeout / 1000.0).ceil() as u64)
+}
+
 fn format_napi_error(err: impl Display) -> Error {
     Error::from_reason(format!("{}", err))
 }
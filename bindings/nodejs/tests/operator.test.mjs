@@ -0,0 +1,41 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+import { describe, expect, test } from 'vitest'
+import { Operator } from '../index.js'
+
+describe('Operator options', () => {
+  test('accept timeout and retry', async () => {
+    const op = new Operator('memory', { timeout: 1500, retry: { maxTimes: 3 } })
+
+    await op.write('test', 'hello')
+    expect((await op.read('test')).toString()).toBe('hello')
+  })
+
+  test('accept operator defaults', async () => {
+    const op = new Operator('memory', { default_timeout: 30, default_retry_max_times: 3 })
+
+    await op.write('test', 'hello')
+    expect((await op.read('test')).toString()).toBe('hello')
+  })
+
+  test.each([-1, 0, NaN, Infinity])('reject invalid timeout %s', (timeout) => {
+    expect(() => new Operator('memory', { timeout })).toThrowError(/timeout must be a positive number/)
+  })
+})
@@ -0,0 +1,260 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use ext_php_rs::binary::Binary;
+use ext_php_rs::exception::PhpException;
+use ext_php_rs::exception::PhpResult;
+use ext_php_rs::prelude::*;
+use ext_php_rs::zend::ce;
+use opendal as ocore;
+
+/// Unexpected errors.
+#[php_class(name = "OpenDAL\\UnexpectedException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct UnexpectedException;
+
+/// Unsupported operation.
+#[php_class(name = "OpenDAL\\UnsupportedException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct UnsupportedException;
+
+/// Config is invalid.
+#[php_class(name = "OpenDAL\\ConfigInvalidException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct ConfigInvalidException;
+
+/// Not found.
+#[php_class(name = "OpenDAL\\NotFoundException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct NotFoundException;
+
+/// Permission denied.
+#[php_class(name = "OpenDAL\\PermissionDeniedException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct PermissionDeniedException;
+
+/// Is a directory.
+#[php_class(name = "OpenDAL\\IsADirectoryException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct IsADirectoryException;
+
+/// Not a directory.
+#[php_class(name = "OpenDAL\\NotADirectoryException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct NotADirectoryException;
+
+/// Already exists.
+#[php_class(name = "OpenDAL\\AlreadyExistsException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct AlreadyExistsException;
+
+/// Rate limited, please slow down and retry later.
+#[php_class(name = "OpenDAL\\RateLimitedException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct RateLimitedException;
+
+/// Service is unavailable for now, usually temporary and can be retried later.
+#[php_class(name = "OpenDAL\\UnavailableException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct UnavailableException;
+
+/// Operation timed out, usually temporary and can be retried later.
+#[php_class(name = "OpenDAL\\TimedOutException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct TimedOutException;
+
+/// Is same file.
+#[php_class(name = "OpenDAL\\IsSameFileException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct IsSameFileException;
+
+/// Condition not match.
+#[php_class(name = "OpenDAL\\ConditionNotMatchException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct ConditionNotMatchException;
+
+/// Range not satisfied.
+#[php_class(name = "OpenDAL\\RangeNotSatisfiedException")]
+#[extends(ce::exception)]
+#[derive(Default)]
+pub struct RangeNotSatisfiedException;
+
+/// Convert an OpenDAL error into the matching php exception.
+fn format_php_err(err: ocore::Error) -> PhpException {
+    let msg = err.to_string();
+    match err.kind() {
+        ocore::ErrorKind::Unsupported => PhpException::from_class::<UnsupportedException>(msg),
+        ocore::ErrorKind::ConfigInvalid => PhpException::from_class::<ConfigInvalidException>(msg),
+        ocore::ErrorKind::NotFound => PhpException::from_class::<NotFoundException>(msg),
+        ocore::ErrorKind::PermissionDenied => {
+            PhpException::from_class::<PermissionDeniedException>(msg)
+        }
+        ocore::ErrorKind::IsADirectory => PhpException::from_class::<IsADirectoryException>(msg),
+        ocore::ErrorKind::NotADirectory => PhpException::from_class::<NotADirectoryException>(msg),
+        ocore::ErrorKind::AlreadyExists => PhpException::from_class::<AlreadyExistsException>(msg),
+        ocore::ErrorKind::RateLimited => PhpException::from_class::<RateLimitedException>(msg),
+        ocore::ErrorKind::Unavailable => PhpException::from_class::<UnavailableException>(msg),
+        ocore::ErrorKind::TimedOut => PhpException::from_class::<TimedOutException>(msg),
+        ocore::ErrorKind::IsSameFile => PhpException::from_class::<IsSameFileException>(msg),
+        ocore::ErrorKind::ConditionNotMatch => {
+            PhpException::from_class::<ConditionNotMatchException>(msg)
+        }
+        ocore::ErrorKind::RangeNotSatisfied => {
+            PhpException::from_class::<RangeNotSatisfiedException>(msg)
+        }
+        _ => PhpException::from_class::<UnexpectedException>(msg),
+    }
+}
+
+/// Parse the scheme and make sure it's enabled in this build.
+fn parse_scheme(scheme: &str) -> ocore::Result<ocore::Scheme> {
+    let scheme = ocore::Scheme::from_str(scheme)?;
+    if !ocore::Scheme::enabled().contains(&scheme) {
+        return Err(ocore::Error::new(
+            ocore::ErrorKind::ConfigInvalid,
+            "scheme is not supported or not enabled",
+        )
+        .with_context("scheme", scheme));
+    }
+
+    Ok(scheme)
+}
+
+/// `Operator` is the entry for all public blocking APIs.
+///
+/// ```php
+/// $op = new OpenDAL\Operator("fs", ["root" => "/tmp"]);
+/// $op->write("test.txt", "Hello World");
+/// echo $op->read("test.txt");
+/// ```
+#[php_class(name = "OpenDAL\\Operator")]
+pub struct Operator(ocore::BlockingOperator);
+
+#[php_impl(rename_methods = "none")]
+impl Operator {
+    /// Create a new operator with the given scheme and an associative array of options.
+    pub fn __construct(scheme: String, config: Option<HashMap<String, String>>) -> PhpResult<Self> {
+        let scheme = parse_scheme(&scheme).map_err(format_php_err)?;
+        // BlockingLayer is added by `blocking()` for services without native blocking support.
+        let op = ocore::Operator::via_iter(scheme, config.unwrap_or_default())
+            .map_err(format_php_err)?
+            .blocking();
+
+        Ok(Operator(op))
+    }
+
+    /// Read the whole path into a binary string.
+    pub fn read(&self, path: &str) -> PhpResult<Binary<u8>> {
+        self.0
+            .read(path)
+            .map(|bs| Binary::from(bs.to_vec()))
+            .map_err(format_php_err)
+    }
+
+    /// Write the binary string into the given path.
+    pub fn write(&self, path: &str, content: Binary<u8>) -> PhpResult<()> {
+        self.0
+            .write(path, Vec::from(content))
+            .map_err(format_php_err)
+    }
+
+    /// Get the metadata of the given path.
+    pub fn stat(&self, path: &str) -> PhpResult<Metadata> {
+        self.0.stat(path).map(Metadata::new).map_err(format_php_err)
+    }
+
+    /// Check if the given path exists.
+    pub fn exists(&self, path: &str) -> PhpResult<bool> {
+        self.0.exists(path).map_err(format_php_err)
+    }
+
+    /// Create a dir at the given path, the path must end with `/`.
+    pub fn create_dir(&self, path: &str) -> PhpResult<()> {
+        self.0.create_dir(path).map_err(format_php_err)
+    }
+
+    /// List the paths of entries under the given dir, the path must end with `/`.
+    pub fn list(&self, path: &str) -> PhpResult<Vec<String>> {
+        self.0
+            .list(path)
+            .map(|entries| entries.into_iter().map(|e| e.path().to_string()).collect())
+            .map_err(format_php_err)
+    }
+
+    /// Delete the given path, deleting a non-existent path succeeds.
+    pub fn delete(&self, path: &str) -> PhpResult<()> {
+        self.0.delete(path).map_err(format_php_err)
+    }
+}
+
+/// Metadata of an entry, returned by `Operator::stat`.
+#[php_class(name = "OpenDAL\\Metadata")]
+pub struct Metadata {
+    #[prop]
+    pub content_disposition: Option<String>,
+    #[prop]
+    pub content_length: u64,
+    #[prop]
+    pub content_md5: Option<String>,
+    #[prop]
+    pub content_type: Option<String>,
+    #[prop]
+    pub etag: Option<String>,
+    /// Last modified time in RFC 3339 format.
+    #[prop]
+    pub last_modified: Option<String>,
+    #[prop]
+    pub is_file: bool,
+    #[prop]
+    pub is_dir: bool,
+}
+
+impl Metadata {
+    fn new(meta: ocore::Metadata) -> Self {
+        Self {
+            content_disposition: meta.content_disposition().map(|s| s.to_string()),
+            content_length: meta.content_length(),
+            content_md5: meta.content_md5().map(|s| s.to_string()),
+            content_type: meta.content_type().map(|s| s.to_string()),
+            etag: meta.etag().map(|s| s.to_string()),
+            last_modified: meta.last_modified().map(|t| t.to_rfc3339()),
+            is_file: meta.is_file(),
+            is_dir: meta.is_dir(),
+        }
+    }
+}
+
+#[php_module]
+pub fn get_module(module: ModuleBuilder) -> ModuleBuilder {
+    module
+}
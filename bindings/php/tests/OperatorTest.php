@@ -0,0 +1,101 @@
+<?php
+
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use OpenDAL\ConfigInvalidException;
+use OpenDAL\NotFoundException;
+use OpenDAL\Operator;
+use PHPUnit\Framework\TestCase;
+
+final class OperatorTest extends TestCase
+{
+    private Operator $op;
+
+    protected function setUp(): void
+    {
+        $this->op = new Operator("memory");
+    }
+
+    public function testWriteAndRead(): void
+    {
+        $this->op->write("sample", "Sample data for testing");
+
+        $this->assertSame("Sample data for testing", $this->op->read("sample"));
+    }
+
+    public function testReadBinary(): void
+    {
+        $content = "\x00\xff\x01\x80";
+        $this->op->write("binary", $content);
+
+        $this->assertSame($content, $this->op->read("binary"));
+    }
+
+    public function testStat(): void
+    {
+        $this->op->write("sample", "Sample data for testing");
+        $meta = $this->op->stat("sample");
+
+        $this->assertTrue($meta->is_file);
+        $this->assertFalse($meta->is_dir);
+        $this->assertSame(23, $meta->content_length);
+    }
+
+    public function testList(): void
+    {
+        $this->op->write("dir/a", "a");
+        $this->op->write("dir/b", "b");
+
+        $paths = $this->op->list("dir/");
+        sort($paths);
+        $this->assertSame(["dir/a", "dir/b"], $paths);
+    }
+
+    public function testDelete(): void
+    {
+        $this->op->write("sample", "Sample data for testing");
+        $this->assertTrue($this->op->exists("sample"));
+
+        $this->op->delete("sample");
+        $this->assertFalse($this->op->exists("sample"));
+    }
+
+    public function testFsWithOptions(): void
+    {
+        $root = sys_get_temp_dir() . "/opendal-php-" . uniqid();
+        $op = new Operator("fs", ["root" => $root]);
+        $op->write("sample", "Sample data for testing");
+
+        $this->assertFileExists($root . "/sample");
+        $op->delete("sample");
+    }
+
+    public function testNotFound(): void
+    {
+        $this->expectException(NotFoundException::class);
+        $this->op->read("not_exist");
+    }
+
+    public function testUnsupportedScheme(): void
+    {
+        $this->expectException(ConfigInvalidException::class);
+        new Operator("not_exist");
+    }
+}
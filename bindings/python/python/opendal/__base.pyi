@@ -21,7 +21,7 @@
 This file is generated by opendal/dev/generate/python.rs. `opendal.__base` doesn't exists.
 """
 
-from typing import overload, Literal, TypeAlias
+from typing import Any, overload, Literal, TypeAlias
 
 # `true`/`false`` in any case, for example, `true`/`True`/`TRUE` `false`/`False`/`FALSE`
 _bool: TypeAlias = str
@@ -842,4 +842,13 @@ class _Base:
 
 
     @overload
-    def __init__(self, scheme: str, /, **kwargs: str) -> None: ...
\ No newline at end of file
+    def __init__(
+        self,
+        scheme: str,
+        /,
+        *,
+        retry: dict[str, Any] = ...,
+        concurrent_limit: int = ...,
+        timeout: float = ...,
+        **kwargs: str,
+    ) -> None: ...
\ No newline at end of file
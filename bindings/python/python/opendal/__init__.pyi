@@ -45,6 +45,7 @@ class Operator(_Base):
     def list(self, path: str) -> Iterable[Entry]: ...
     def scan(self, path: str) -> Iterable[Entry]: ...
     def capability(self) -> Capability: ...
+    def info(self) -> OperatorInfo: ...
     def copy(self, source: str, target: str) -> None: ...
     def rename(self, source: str, target: str) -> None: ...
     def remove_all(self, path: str) -> None: ...
@@ -77,6 +78,7 @@ class AsyncOperator(_Base):
         self, path: str, expire_second: int
     ) -> PresignedRequest: ...
     def capability(self) -> Capability: ...
+    def info(self) -> OperatorInfo: ...
     async def copy(self, source: str, target: str) -> None: ...
     async def rename(self, source: str, target: str) -> None: ...
     async def remove_all(self, path: str) -> None: ...
@@ -171,6 +173,7 @@ class Capability:
     read_with_override_cache_control: bool
     read_with_override_content_disposition: bool
     read_with_override_content_type: bool
+    read_with_version: bool
 
     write: bool
     write_can_multi: bool
@@ -179,12 +182,18 @@ class Capability:
     write_with_content_type: bool
     write_with_content_disposition: bool
     write_with_cache_control: bool
+    write_with_content_encoding: bool
+    write_with_if_match: bool
+    write_with_if_none_match: bool
+    write_with_if_not_exists: bool
+    write_with_user_metadata: bool
     write_multi_max_size: Optional[int]
     write_multi_min_size: Optional[int]
     write_total_max_size: Optional[int]
 
     create_dir: bool
     delete: bool
+    delete_with_version: bool
     copy: bool
     rename: bool
 
@@ -193,11 +202,26 @@ class Capability:
     list_with_start_after: bool
     list_without_recursive: bool
     list_with_recursive: bool
+    list_with_versions: bool
+    list_with_deleted: bool
+    list_with_glob: bool
+    list_with_start_token: bool
 
     presign: bool
     presign_read: bool
     presign_stat: bool
     presign_write: bool
+    presign_write_multi: bool
+    presign_post: bool
+    presign_with_headers: bool
 
     shared: bool
     blocking: bool
+
+@final
+class OperatorInfo:
+    scheme: str
+    root: str
+    name: str
+    full_capability: Capability
+    native_capability: Capability
@@ -20,6 +20,7 @@ use pyo3::prelude::*;
 /// Capability is used to describe what operations are supported
 /// by current Operator.
 #[pyclass(get_all, module = "opendal")]
+#[derive(Clone)]
 pub struct Capability {
     /// If operator supports stat.
     pub stat: bool,
@@ -40,6 +41,8 @@ pub struct Capability {
     pub read_with_override_content_disposition: bool,
     /// if operator supports read with override content type.
     pub read_with_override_content_type: bool,
+    /// If operator supports read with version.
+    pub read_with_version: bool,
 
     /// If operator supports write.
     pub write: bool,
@@ -55,6 +58,16 @@ pub struct Capability {
     pub write_with_content_disposition: bool,
     /// If operator supports write with cache control.
     pub write_with_cache_control: bool,
+    /// If operator supports write with content encoding.
+    pub write_with_content_encoding: bool,
+    /// If operator supports write with if match.
+    pub write_with_if_match: bool,
+    /// If operator supports write with if none match.
+    pub write_with_if_none_match: bool,
+    /// If operator supports write with if not exists.
+    pub write_with_if_not_exists: bool,
+    /// If operator supports write with user metadata.
+    pub write_with_user_metadata: bool,
     /// write_multi_max_size is the max size that services support in write_multi.
     ///
     /// For example, AWS S3 supports 5GiB as max in write_multi.
@@ -73,6 +86,8 @@ pub struct Capability {
 
     /// If operator supports delete.
     pub delete: bool,
+    /// If operator supports delete with version.
+    pub delete_with_version: bool,
 
     /// If operator supports copy.
     pub copy: bool,
@@ -88,6 +103,14 @@ pub struct Capability {
     pub list_with_start_after: bool,
     /// If backend supports list without delimiter.
     pub list_with_recursive: bool,
+    /// If backend supports list with versions.
+    pub list_with_versions: bool,
+    /// If backend supports list with deleted.
+    pub list_with_deleted: bool,
+    /// If backend supports list with glob pattern.
+    pub list_with_glob: bool,
+    /// If backend supports list with start token.
+    pub list_with_start_token: bool,
 
     /// If operator supports presign.
     pub presign: bool,
@@ -97,6 +120,12 @@ pub struct Capability {
     pub presign_stat: bool,
     /// If operator supports presign write.
     pub presign_write: bool,
+    /// If operator supports presign multipart upload.
+    pub presign_write_multi: bool,
+    /// If operator supports presign post.
+    pub presign_post: bool,
+    /// If operator supports presign with extra signed headers.
+    pub presign_with_headers: bool,
 
     /// If operator supports shared.
     pub shared: bool,
@@ -118,6 +147,7 @@ impl Capability {
             read_with_override_content_disposition: capability
                 .read_with_override_content_disposition,
             read_with_override_content_type: capability.read_with_override_content_type,
+            read_with_version: capability.read_with_version,
             write: capability.write,
             write_can_multi: capability.write_can_multi,
             write_can_empty: capability.write_can_empty,
@@ -125,23 +155,73 @@ impl Capability {
             write_with_content_type: capability.write_with_content_type,
             write_with_content_disposition: capability.write_with_content_disposition,
             write_with_cache_control: capability.write_with_cache_control,
+            write_with_content_encoding: capability.write_with_content_encoding,
+            write_with_if_match: capability.write_with_if_match,
+            write_with_if_none_match: capability.write_with_if_none_match,
+            write_with_if_not_exists: capability.write_with_if_not_exists,
+            write_with_user_metadata: capability.write_with_user_metadata,
             write_multi_max_size: capability.write_multi_max_size,
             write_multi_min_size: capability.write_multi_min_size,
             write_total_max_size: capability.write_total_max_size,
             create_dir: capability.create_dir,
             delete: capability.delete,
+            delete_with_version: capability.delete_with_version,
             copy: capability.copy,
             rename: capability.rename,
             list: capability.list,
             list_with_limit: capability.list_with_limit,
             list_with_start_after: capability.list_with_start_after,
             list_with_recursive: capability.list_with_recursive,
+            list_with_versions: capability.list_with_versions,
+            list_with_deleted: capability.list_with_deleted,
+            list_with_glob: capability.list_with_glob,
+            list_with_start_token: capability.list_with_start_token,
             presign: capability.presign,
             presign_read: capability.presign_read,
             presign_stat: capability.presign_stat,
             presign_write: capability.presign_write,
+            presign_write_multi: capability.presign_write_multi,
+            presign_post: capability.presign_post,
+            presign_with_headers: capability.presign_with_headers,
             shared: capability.shared,
             blocking: capability.blocking,
         }
     }
 }
+
+/// OperatorInfo describes the service that current Operator is built on.
+#[pyclass(get_all, module = "opendal")]
+pub struct OperatorInfo {
+    /// Scheme of the underlying service, like `s3` or `fs`.
+    pub scheme: String,
+    /// Root path of the operator, always ends with `/`.
+    pub root: String,
+    /// Name of the backend, like bucket name for s3 or container name for azblob.
+    pub name: String,
+    /// Capability of the operator, including the ones provided by layers.
+    pub full_capability: Capability,
+    /// Capability natively supported by the underlying service.
+    pub native_capability: Capability,
+}
+
+impl OperatorInfo {
+    pub fn new(info: opendal::OperatorInfo) -> Self {
+        Self {
+            scheme: info.scheme().to_string(),
+            root: info.root().to_string(),
+            name: info.name().to_string(),
+            full_capability: Capability::new(info.full_capability()),
+            native_capability: Capability::new(info.native_capability()),
+        }
+    }
+}
+
+#[pymethods]
+impl OperatorInfo {
+    fn __repr__(&self) -> String {
+        format!(
+            "OperatorInfo(scheme=\"{}\", root=\"{}\", name=\"{}\")",
+            self.scheme, self.root, self.name
+        )
+    }
+}
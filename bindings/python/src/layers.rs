@@ -18,7 +18,9 @@
 use std::time::Duration;
 
 use opendal::Operator;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 use crate::*;
 
@@ -56,22 +58,14 @@ impl RetryLayer {
         max_delay: Option<f64>,
         min_delay: Option<f64>,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let mut retry = ocore::layers::RetryLayer::default();
-        if let Some(max_times) = max_times {
-            retry = retry.with_max_times(max_times);
-        }
-        if let Some(factor) = factor {
-            retry = retry.with_factor(factor);
-        }
-        if jitter {
-            retry = retry.with_jitter();
-        }
-        if let Some(max_delay) = max_delay {
-            retry = retry.with_max_delay(Duration::from_micros((max_delay * 1000000.0) as u64));
-        }
-        if let Some(min_delay) = min_delay {
-            retry = retry.with_min_delay(Duration::from_micros((min_delay * 1000000.0) as u64));
+        let retry = RetryOptions {
+            max_times,
+            factor,
+            jitter,
+            max_delay,
+            min_delay,
         }
+        .build();
 
         let retry_layer = Self(retry);
         let class = PyClassInitializer::from(Layer(Box::new(retry_layer.clone())))
@@ -103,3 +97,136 @@ impl ConcurrentLimitLayer {
         Ok(class)
     }
 }
+
+/// Layers that can be attached via keyword arguments while constructing
+/// `Operator` or `AsyncOperator`, for example:
+///
+/// ```python
+/// op = opendal.Operator("s3", retry={"max_times": 5}, concurrent_limit=64, timeout=30)
+/// ```
+#[derive(Clone, Default)]
+pub struct LayerOptions {
+    retry: Option<RetryOptions>,
+    concurrent_limit: Option<usize>,
+    timeout: Option<f64>,
+}
+
+#[derive(Clone, Default)]
+struct RetryOptions {
+    max_times: Option<usize>,
+    factor: Option<f32>,
+    jitter: bool,
+    max_delay: Option<f64>,
+    min_delay: Option<f64>,
+}
+
+impl LayerOptions {
+    pub fn new(
+        retry: Option<&Bound<PyDict>>,
+        concurrent_limit: Option<usize>,
+        timeout: Option<f64>,
+    ) -> PyResult<Self> {
+        let retry = match retry {
+            None => None,
+            Some(v) => {
+                let mut opts = RetryOptions::default();
+                for (key, value) in v.iter() {
+                    match key.extract::<String>()?.as_str() {
+                        "max_times" => opts.max_times = value.extract()?,
+                        "factor" => opts.factor = value.extract()?,
+                        "jitter" => opts.jitter = value.extract()?,
+                        "max_delay" => opts.max_delay = value.extract()?,
+                        "min_delay" => opts.min_delay = value.extract()?,
+                        key => {
+                            return Err(PyValueError::new_err(format!(
+                                "unsupported retry option: {key}"
+                            )))
+                        }
+                    }
+                }
+                Some(opts)
+            }
+        };
+
+        Ok(Self {
+            retry,
+            concurrent_limit,
+            timeout,
+        })
+    }
+
+    /// Apply configured layers to the operator.
+    ///
+    /// Timeout layer is placed before retry layer so that timed out
+    /// operations can be retried.
+    pub fn apply(&self, mut op: Operator) -> Operator {
+        if let Some(timeout) = self.timeout {
+            let timeout = Duration::from_micros((timeout * 1000000.0) as u64);
+            op = op.layer(
+                ocore::layers::TimeoutLayer::new()
+                    .with_timeout(timeout)
+                    .with_io_timeout(timeout),
+            );
+        }
+        if let Some(retry) = &self.retry {
+            op = op.layer(retry.build());
+        }
+        if let Some(limit) = self.concurrent_limit {
+            op = op.layer(ocore::layers::ConcurrentLimitLayer::new(limit));
+        }
+        op
+    }
+
+    /// Write the options back into kwargs so that operators can be pickled.
+    pub fn fill_kwargs(&self, kwargs: &Bound<PyDict>) -> PyResult<()> {
+        let py = kwargs.py();
+        if let Some(retry) = &self.retry {
+            let v = PyDict::new(py);
+            if let Some(max_times) = retry.max_times {
+                v.set_item("max_times", max_times)?;
+            }
+            if let Some(factor) = retry.factor {
+                v.set_item("factor", factor)?;
+            }
+            if retry.jitter {
+                v.set_item("jitter", true)?;
+            }
+            if let Some(max_delay) = retry.max_delay {
+                v.set_item("max_delay", max_delay)?;
+            }
+            if let Some(min_delay) = retry.min_delay {
+                v.set_item("min_delay", min_delay)?;
+            }
+            kwargs.set_item("retry", v)?;
+        }
+        if let Some(limit) = self.concurrent_limit {
+            kwargs.set_item("concurrent_limit", limit)?;
+        }
+        if let Some(timeout) = self.timeout {
+            kwargs.set_item("timeout", timeout)?;
+        }
+        Ok(())
+    }
+}
+
+impl RetryOptions {
+    fn build(&self) -> ocore::layers::RetryLayer {
+        let mut retry = ocore::layers::RetryLayer::default();
+        if let Some(max_times) = self.max_times {
+            retry = retry.with_max_times(max_times);
+        }
+        if let Some(factor) = self.factor {
+            retry = retry.with_factor(factor);
+        }
+        if self.jitter {
+            retry = retry.with_jitter();
+        }
+        if let Some(max_delay) = self.max_delay {
+            retry = retry.with_max_delay(Duration::from_micros((max_delay * 1000000.0) as u64));
+        }
+        if let Some(min_delay) = self.min_delay {
+            retry = retry.with_min_delay(Duration::from_micros((min_delay * 1000000.0) as u64));
+        }
+        retry
+    }
+}
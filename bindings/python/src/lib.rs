@@ -83,6 +83,7 @@ fn _opendal(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Metadata>()?;
     m.add_class::<PresignedRequest>()?;
     m.add_class::<Capability>()?;
+    m.add_class::<OperatorInfo>()?;
 
     m.add_class::<WriteOptions>()?;
 
@@ -31,8 +31,10 @@ use crate::*;
 fn build_operator(
     scheme: ocore::Scheme,
     map: HashMap<String, String>,
+    layers: &layers::LayerOptions,
 ) -> PyResult<ocore::Operator> {
     let mut op = ocore::Operator::via_iter(scheme, map).map_err(format_pyerr)?;
+    op = layers.apply(op);
     if !op.info().full_capability().blocking {
         let runtime = pyo3_async_runtimes::tokio::get_runtime();
         let _guard = runtime.enter();
@@ -46,18 +48,27 @@ fn build_operator(
 /// `Operator` is the entry for all public blocking APIs
 ///
 /// Create a new blocking `Operator` with the given `scheme` and options(`**kwargs`).
+///
+/// Core layers can be attached via `retry`, `concurrent_limit` and `timeout`.
 #[pyclass(module = "opendal")]
 pub struct Operator {
     core: ocore::BlockingOperator,
     __scheme: ocore::Scheme,
     __map: HashMap<String, String>,
+    __layers: layers::LayerOptions,
 }
 
 #[pymethods]
 impl Operator {
     #[new]
-    #[pyo3(signature = (scheme, *, **map))]
-    pub fn new(scheme: &str, map: Option<&Bound<PyDict>>) -> PyResult<Self> {
+    #[pyo3(signature = (scheme, *, retry = None, concurrent_limit = None, timeout = None, **map))]
+    pub fn new(
+        scheme: &str,
+        retry: Option<&Bound<PyDict>>,
+        concurrent_limit: Option<usize>,
+        timeout: Option<f64>,
+        map: Option<&Bound<PyDict>>,
+    ) -> PyResult<Self> {
         let scheme = ocore::Scheme::from_str(scheme)
             .map_err(|err| {
                 ocore::Error::new(ocore::ErrorKind::Unexpected, "unsupported scheme")
@@ -71,10 +82,13 @@ impl Operator {
             })
             .unwrap_or_default();
 
+        let layers = layers::LayerOptions::new(retry, concurrent_limit, timeout)?;
+
         Ok(Operator {
-            core: build_operator(scheme.clone(), map.clone())?.blocking(),
+            core: build_operator(scheme.clone(), map.clone(), &layers)?.blocking(),
             __scheme: scheme,
             __map: map,
+            __layers: layers,
         })
     }
 
@@ -85,6 +99,7 @@ impl Operator {
             core: op.blocking(),
             __scheme: self.__scheme.clone(),
             __map: self.__map.clone(),
+            __layers: self.__layers.clone(),
         })
     }
 
@@ -209,11 +224,16 @@ impl Operator {
         ))
     }
 
+    pub fn info(&self) -> PyResult<capability::OperatorInfo> {
+        Ok(capability::OperatorInfo::new(self.core.info()))
+    }
+
     pub fn to_async_operator(&self) -> PyResult<AsyncOperator> {
         Ok(AsyncOperator {
             core: self.core.clone().into(),
             __scheme: self.__scheme.clone(),
             __map: self.__map.clone(),
+            __layers: self.__layers.clone(),
         })
     }
 
@@ -234,7 +254,9 @@ impl Operator {
     fn __getnewargs_ex__(&self, py: Python) -> PyResult<PyObject> {
         let args = vec![self.__scheme.to_string()];
         let args = PyTuple::new(py, args)?.into_py_any(py)?;
-        let kwargs = self.__map.clone().into_py_any(py)?;
+        let kwargs = self.__map.clone().into_pyobject(py)?;
+        self.__layers.fill_kwargs(&kwargs)?;
+        let kwargs = kwargs.into_py_any(py)?;
         Ok(PyTuple::new(py, [args, kwargs])?.into_py_any(py)?)
     }
 }
@@ -242,18 +264,27 @@ impl Operator {
 /// `AsyncOperator` is the entry for all public async APIs
 ///
 /// Create a new `AsyncOperator` with the given `scheme` and options(`**kwargs`).
+///
+/// Core layers can be attached via `retry`, `concurrent_limit` and `timeout`.
 #[pyclass(module = "opendal")]
 pub struct AsyncOperator {
     core: ocore::Operator,
     __scheme: ocore::Scheme,
     __map: HashMap<String, String>,
+    __layers: layers::LayerOptions,
 }
 
 #[pymethods]
 impl AsyncOperator {
     #[new]
-    #[pyo3(signature = (scheme, *,  **map))]
-    pub fn new(scheme: &str, map: Option<&Bound<PyDict>>) -> PyResult<Self> {
+    #[pyo3(signature = (scheme, *, retry = None, concurrent_limit = None, timeout = None, **map))]
+    pub fn new(
+        scheme: &str,
+        retry: Option<&Bound<PyDict>>,
+        concurrent_limit: Option<usize>,
+        timeout: Option<f64>,
+        map: Option<&Bound<PyDict>>,
+    ) -> PyResult<Self> {
         let scheme = ocore::Scheme::from_str(scheme)
             .map_err(|err| {
                 ocore::Error::new(ocore::ErrorKind::Unexpected, "unsupported scheme")
@@ -267,10 +298,13 @@ impl AsyncOperator {
             })
             .unwrap_or_default();
 
+        let layers = layers::LayerOptions::new(retry, concurrent_limit, timeout)?;
+
         Ok(AsyncOperator {
-            core: build_operator(scheme.clone(), map.clone())?.into(),
+            core: build_operator(scheme.clone(), map.clone(), &layers)?.into(),
             __scheme: scheme,
             __map: map,
+            __layers: layers,
         })
     }
 
@@ -281,6 +315,7 @@ impl AsyncOperator {
             core: op,
             __scheme: self.__scheme.clone(),
             __map: self.__map.clone(),
+            __layers: self.__layers.clone(),
         })
     }
 
@@ -524,11 +559,16 @@ impl AsyncOperator {
         ))
     }
 
+    pub fn info(&self) -> PyResult<capability::OperatorInfo> {
+        Ok(capability::OperatorInfo::new(self.core.info()))
+    }
+
     pub fn to_operator(&self) -> PyResult<Operator> {
         Ok(Operator {
             core: self.core.clone().blocking(),
             __scheme: self.__scheme.clone(),
             __map: self.__map.clone(),
+            __layers: self.__layers.clone(),
         })
     }
 
@@ -553,7 +593,9 @@ impl AsyncOperator {
     fn __getnewargs_ex__(&self, py: Python) -> PyResult<PyObject> {
         let args = vec![self.__scheme.to_string()];
         let args = PyTuple::new(py, args)?.into_py_any(py)?;
-        let kwargs = self.__map.clone().into_py_any(py)?;
+        let kwargs = self.__map.clone().into_pyobject(py)?;
+        self.__layers.fill_kwargs(&kwargs)?;
+        let kwargs = kwargs.into_py_any(py)?;
         Ok(PyTuple::new(py, [args, kwargs])?.into_py_any(py)?)
     }
 }
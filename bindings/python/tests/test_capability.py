@@ -29,3 +29,10 @@ def test_capability_exception(service_name, operator):
     assert cap is not None
     with pytest.raises(AttributeError):
         cap.read_demo
+
+
+def test_operator_info(service_name, operator):
+    info = operator.info()
+    assert info.scheme == service_name
+    assert info.root.endswith("/")
+    assert info.full_capability.read == operator.capability().read
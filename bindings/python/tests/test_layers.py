@@ -0,0 +1,41 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+import pickle
+
+import opendal
+import pytest
+
+
+def test_operator_with_layer_options():
+    op = opendal.Operator(
+        "memory",
+        retry={"max_times": 5, "jitter": True},
+        concurrent_limit=64,
+        timeout=30,
+    )
+    op.write("test", b"Hello, World!")
+    assert op.read("test") == b"Hello, World!"
+
+    # Layer options should be kept after pickling.
+    deserialized = pickle.loads(pickle.dumps(op))
+    assert deserialized.info().scheme == "memory"
+
+
+def test_operator_with_invalid_retry_option():
+    with pytest.raises(ValueError):
+        opendal.Operator("memory", retry={"max_tries": 5})
@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use magnus::exception::ExceptionClass;
+use magnus::prelude::*;
+use magnus::value::Lazy;
+use magnus::Error;
+use magnus::RModule;
+use magnus::Ruby;
+
+use crate::*;
+
+/// Define `OpenDAL::<name>` as a subclass of `OpenDAL::Error`.
+macro_rules! define_error {
+    ($name:ident, $class:literal) => {
+        static $name: Lazy<ExceptionClass> = Lazy::new(|ruby| {
+            ruby.define_module("OpenDAL")
+                .expect("module OpenDAL must be defined")
+                .define_error($class, ruby.get_inner(&ERROR))
+                .expect("error class must be defined")
+        });
+    };
+}
+
+/// The base error of all errors returned by OpenDAL.
+static ERROR: Lazy<ExceptionClass> = Lazy::new(|ruby| {
+    ruby.define_module("OpenDAL")
+        .expect("module OpenDAL must be defined")
+        .define_error("Error", ruby.exception_standard_error())
+        .expect("error class must be defined")
+});
+
+define_error!(UNEXPECTED, "Unexpected");
+define_error!(UNSUPPORTED, "Unsupported");
+define_error!(CONFIG_INVALID, "ConfigInvalid");
+define_error!(NOT_FOUND, "NotFound");
+define_error!(PERMISSION_DENIED, "PermissionDenied");
+define_error!(IS_A_DIRECTORY, "IsADirectory");
+define_error!(NOT_A_DIRECTORY, "NotADirectory");
+define_error!(ALREADY_EXISTS, "AlreadyExists");
+define_error!(RATE_LIMITED, "RateLimited");
+define_error!(UNAVAILABLE, "Unavailable");
+define_error!(TIMED_OUT, "TimedOut");
+define_error!(IS_SAME_FILE, "IsSameFile");
+define_error!(CONDITION_NOT_MATCH, "ConditionNotMatch");
+define_error!(RANGE_NOT_SATISFIED, "RangeNotSatisfied");
+
+/// Convert an OpenDAL error into the matching ruby exception.
+pub fn format_magnus_error(ruby: &Ruby, err: ocore::Error) -> Error {
+    let class = match err.kind() {
+        ocore::ErrorKind::Unsupported => &UNSUPPORTED,
+        ocore::ErrorKind::ConfigInvalid => &CONFIG_INVALID,
+        ocore::ErrorKind::NotFound => &NOT_FOUND,
+        ocore::ErrorKind::PermissionDenied => &PERMISSION_DENIED,
+        ocore::ErrorKind::IsADirectory => &IS_A_DIRECTORY,
+        ocore::ErrorKind::NotADirectory => &NOT_A_DIRECTORY,
+        ocore::ErrorKind::AlreadyExists => &ALREADY_EXISTS,
+        ocore::ErrorKind::RateLimited => &RATE_LIMITED,
+        ocore::ErrorKind::Unavailable => &UNAVAILABLE,
+        ocore::ErrorKind::TimedOut => &TIMED_OUT,
+        ocore::ErrorKind::IsSameFile => &IS_SAME_FILE,
+        ocore::ErrorKind::ConditionNotMatch => &CONDITION_NOT_MATCH,
+        ocore::ErrorKind::RangeNotSatisfied => &RANGE_NOT_SATISFIED,
+        _ => &UNEXPECTED,
+    };
+    Error::new(ruby.get_inner(class), err.to_string())
+}
+
+pub fn include(ruby: &Ruby, _: &RModule) -> Result<(), Error> {
+    // Classes are defined lazily, force them so users can rescue them before any call fails.
+    for class in [
+        &ERROR,
+        &UNEXPECTED,
+        &UNSUPPORTED,
+        &CONFIG_INVALID,
+        &NOT_FOUND,
+        &PERMISSION_DENIED,
+        &IS_A_DIRECTORY,
+        &NOT_A_DIRECTORY,
+        &ALREADY_EXISTS,
+        &RATE_LIMITED,
+        &UNAVAILABLE,
+        &TIMED_OUT,
+        &IS_SAME_FILE,
+        &CONDITION_NOT_MATCH,
+        &RANGE_NOT_SATISFIED,
+    ] {
+        Lazy::force(class, ruby);
+    }
+    Ok(())
+}
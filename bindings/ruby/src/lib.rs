@@ -0,0 +1,47 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+// We will use `ocore::Xxx` to represents all types from opendal rust core.
+pub use ::opendal as ocore;
+use magnus::Error;
+use magnus::Ruby;
+
+mod errors;
+pub use errors::*;
+mod metadata;
+pub use metadata::*;
+mod operator;
+pub use operator::*;
+
+/// Apache OpenDAL™ Ruby binding
+///
+/// ```ruby
+/// require "opendal"
+///
+/// op = OpenDAL::Operator.new("fs", {"root" => "/tmp"})
+/// op.write("test.txt", "Hello World")
+/// puts op.read("test.txt")
+/// puts op.stat("test.txt").content_length
+/// ```
+#[magnus::init]
+fn init(ruby: &Ruby) -> Result<(), Error> {
+    let gem_module = ruby.define_module("OpenDAL")?;
+    errors::include(ruby, &gem_module)?;
+    metadata::include(ruby, &gem_module)?;
+    operator::include(ruby, &gem_module)?;
+    Ok(())
+}
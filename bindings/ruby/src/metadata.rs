@@ -0,0 +1,121 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use magnus::method;
+use magnus::prelude::*;
+use magnus::Error;
+use magnus::RModule;
+use magnus::Ruby;
+
+use crate::*;
+
+/// Metadata of an entry, returned by `Operator#stat` and `Entry#metadata`.
+#[magnus::wrap(class = "OpenDAL::Metadata", free_immediately, size)]
+pub struct Metadata(ocore::Metadata);
+
+impl Metadata {
+    pub fn new(meta: ocore::Metadata) -> Self {
+        Self(meta)
+    }
+
+    /// Content-Disposition of this object
+    pub fn content_disposition(&self) -> Option<&str> {
+        self.0.content_disposition()
+    }
+
+    /// Content length of this entry.
+    pub fn content_length(&self) -> u64 {
+        self.0.content_length()
+    }
+
+    /// Content MD5 of this entry.
+    pub fn content_md5(&self) -> Option<&str> {
+        self.0.content_md5()
+    }
+
+    /// Content Type of this entry.
+    pub fn content_type(&self) -> Option<&str> {
+        self.0.content_type()
+    }
+
+    /// ETag of this entry.
+    pub fn etag(&self) -> Option<&str> {
+        self.0.etag()
+    }
+
+    /// Last modified time of this entry in RFC 3339 format.
+    pub fn last_modified(&self) -> Option<String> {
+        self.0.last_modified().map(|t| t.to_rfc3339())
+    }
+
+    /// Returns `true` if this metadata is for a file.
+    pub fn is_file(&self) -> bool {
+        self.0.is_file()
+    }
+
+    /// Returns `true` if this metadata is for a directory.
+    pub fn is_dir(&self) -> bool {
+        self.0.is_dir()
+    }
+}
+
+/// Entry returned by `Operator#list`.
+#[magnus::wrap(class = "OpenDAL::Entry", free_immediately, size)]
+pub struct Entry(ocore::Entry);
+
+impl Entry {
+    pub fn new(entry: ocore::Entry) -> Self {
+        Self(entry)
+    }
+
+    /// Path of this entry, relative to the operator root.
+    pub fn path(&self) -> &str {
+        self.0.path()
+    }
+
+    /// Name of this entry, the last segment of the path.
+    pub fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    /// Metadata of this entry.
+    pub fn metadata(&self) -> Metadata {
+        Metadata::new(self.0.metadata().clone())
+    }
+}
+
+pub fn include(ruby: &Ruby, gem_module: &RModule) -> Result<(), Error> {
+    let class = gem_module.define_class("Metadata", ruby.class_object())?;
+    class.define_method(
+        "content_disposition",
+        method!(Metadata::content_disposition, 0),
+    )?;
+    class.define_method("content_length", method!(Metadata::content_length, 0))?;
+    class.define_method("content_md5", method!(Metadata::content_md5, 0))?;
+    class.define_method("content_type", method!(Metadata::content_type, 0))?;
+    class.define_method("etag", method!(Metadata::etag, 0))?;
+    class.define_method("last_modified", method!(Metadata::last_modified, 0))?;
+    class.define_method("file?", method!(Metadata::is_file, 0))?;
+    class.define_method("dir?", method!(Metadata::is_dir, 0))?;
+
+    let class = gem_module.define_class("Entry", ruby.class_object())?;
+    class.define_method("path", method!(Entry::path, 0))?;
+    class.define_method("name", method!(Entry::name, 0))?;
+    class.define_method("metadata", method!(Entry::metadata, 0))?;
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use magnus::function;
+use magnus::method;
+use magnus::prelude::*;
+use magnus::scan_args::scan_args;
+use magnus::Error;
+use magnus::RModule;
+use magnus::RString;
+use magnus::Ruby;
+use magnus::Value;
+
+use crate::*;
+
+/// Parse the scheme and make sure it's enabled in this build.
+fn parse_scheme(scheme: &str) -> ocore::Result<ocore::Scheme> {
+    let scheme = ocore::Scheme::from_str(scheme)?;
+    if !ocore::Scheme::enabled().contains(&scheme) {
+        return Err(ocore::Error::new(
+            ocore::ErrorKind::ConfigInvalid,
+            "scheme is not supported or not enabled",
+        )
+        .with_context("scheme", scheme));
+    }
+
+    Ok(scheme)
+}
+
+/// `Operator` is the entry for all public blocking APIs.
+///
+/// Create a new `Operator` with the given scheme and an optional hash of options:
+///
+/// ```ruby
+/// op = OpenDAL::Operator.new("s3", {"bucket" => "example", "region" => "us-east-1"})
+/// ```
+#[magnus::wrap(class = "OpenDAL::Operator", free_immediately, size)]
+pub struct Operator(ocore::BlockingOperator);
+
+impl Operator {
+    fn new(ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
+        let args =
+            scan_args::<(String,), (Option<Option<HashMap<String, String>>>,), (), (), (), ()>(
+                args,
+            )?;
+        let (scheme,) = args.required;
+        let (map,) = args.optional;
+
+        let scheme = parse_scheme(&scheme).map_err(|err| format_magnus_error(ruby, err))?;
+        // BlockingLayer is added by `blocking()` for services without native blocking support.
+        let op = ocore::Operator::via_iter(scheme, map.flatten().unwrap_or_default())
+            .map_err(|err| format_magnus_error(ruby, err))?
+            .blocking();
+
+        Ok(Operator(op))
+    }
+
+    /// Read the whole path into a binary string.
+    fn read(ruby: &Ruby, rb_self: &Self, path: String) -> Result<RString, Error> {
+        let buffer = rb_self
+            .0
+            .read(&path)
+            .map_err(|err| format_magnus_error(ruby, err))?;
+        Ok(ruby.str_from_slice(&buffer.to_vec()))
+    }
+
+    /// Write the content of the string into the given path.
+    fn write(ruby: &Ruby, rb_self: &Self, path: String, bs: RString) -> Result<(), Error> {
+        // Safety: the bytes are copied before any other ruby code could run.
+        let bs = unsafe { bs.as_slice() }.to_vec();
+        rb_self
+            .0
+            .write(&path, bs)
+            .map_err(|err| format_magnus_error(ruby, err))
+    }
+
+    /// Get the metadata of the given path.
+    fn stat(ruby: &Ruby, rb_self: &Self, path: String) -> Result<Metadata, Error> {
+        rb_self
+            .0
+            .stat(&path)
+            .map(Metadata::new)
+            .map_err(|err| format_magnus_error(ruby, err))
+    }
+
+    /// Check if the given path exists.
+    fn exists(ruby: &Ruby, rb_self: &Self, path: String) -> Result<bool, Error> {
+        rb_self
+            .0
+            .exists(&path)
+            .map_err(|err| format_magnus_error(ruby, err))
+    }
+
+    /// Create a dir at the given path, the path must end with `/`.
+    fn create_dir(ruby: &Ruby, rb_self: &Self, path: String) -> Result<(), Error> {
+        rb_self
+            .0
+            .create_dir(&path)
+            .map_err(|err| format_magnus_error(ruby, err))
+    }
+
+    /// List the entries under the given dir, the path must end with `/`.
+    fn list(ruby: &Ruby, rb_self: &Self, path: String) -> Result<Vec<Entry>, Error> {
+        rb_self
+            .0
+            .list(&path)
+            .map(|entries| entries.into_iter().map(Entry::new).collect())
+            .map_err(|err| format_magnus_error(ruby, err))
+    }
+
+    /// Delete the given path, deleting a non-existent path succeeds.
+    fn delete(ruby: &Ruby, rb_self: &Self, path: String) -> Result<(), Error> {
+        rb_self
+            .0
+            .delete(&path)
+            .map_err(|err| format_magnus_error(ruby, err))
+    }
+}
+
+pub fn include(ruby: &Ruby, gem_module: &RModule) -> Result<(), Error> {
+    let class = gem_module.define_class("Operator", ruby.class_object())?;
+    class.define_singleton_method("new", function!(Operator::new, -1))?;
+    class.define_method("read", method!(Operator::read, 1))?;
+    class.define_method("write", method!(Operator::write, 2))?;
+    class.define_method("stat", method!(Operator::stat, 1))?;
+    class.define_method("exist?", method!(Operator::exists, 1))?;
+    class.define_method("create_dir", method!(Operator::create_dir, 1))?;
+    class.define_method("list", method!(Operator::list, 1))?;
+    class.define_method("delete", method!(Operator::delete, 1))?;
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+# frozen_string_literal: true
+
+require "test_helper"
+require "tmpdir"
+
+class BlockingOpTest < Minitest::Test
+  def setup
+    @root = Dir.mktmpdir
+    @op = OpenDAL::Operator.new("fs", {"root" => @root})
+  end
+
+  def teardown
+    FileUtils.remove_entry(@root)
+  end
+
+  def test_write_and_read
+    @op.write("sample", "Sample data for testing")
+
+    assert_equal "Sample data for testing", @op.read("sample")
+  end
+
+  def test_read_binary
+    bytes = [0, 255, 1, 128].pack("C*")
+    @op.write("binary", bytes)
+
+    content = @op.read("binary")
+    assert_equal Encoding::BINARY, content.encoding
+    assert_equal bytes.b, content
+  end
+
+  def test_stat
+    @op.write("sample", "Sample data for testing")
+    meta = @op.stat("sample")
+
+    assert meta.file?
+    refute meta.dir?
+    assert_equal 23, meta.content_length
+  end
+
+  def test_list
+    @op.create_dir("dir/")
+    @op.write("dir/sample", "Sample data for testing")
+
+    entries = @op.list("dir/").map(&:path).sort
+    assert_equal ["dir/", "dir/sample"], entries
+  end
+
+  def test_delete
+    @op.write("sample", "Sample data for testing")
+    assert @op.exist?("sample")
+
+    @op.delete("sample")
+    refute @op.exist?("sample")
+  end
+
+  def test_default_options
+    op = OpenDAL::Operator.new("memory")
+    op.write("sample", "Sample data for testing")
+
+    assert_equal "Sample data for testing", op.read("sample")
+  end
+
+  def test_not_found
+    error = assert_raises(OpenDAL::NotFound) { @op.read("not_exist") }
+    assert_kind_of OpenDAL::Error, error
+  end
+
+  def test_unsupported_scheme
+    assert_raises(OpenDAL::ConfigInvalid) { OpenDAL::Operator.new("not_exist") }
+  end
+end
@@ -0,0 +1,53 @@
+// swift-tools-version: 5.7
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+import Foundation
+import PackageDescription
+
+// `libopendal_swift.a` is built by `cargo build` under the binding root.
+let packageRoot = URL(fileURLWithPath: #filePath).deletingLastPathComponent().path
+let libraryPath = ProcessInfo.processInfo.environment["OPENDAL_SWIFT_LIB_DIR"]
+    ?? "\(packageRoot)/../target/debug"
+
+let package = Package(
+    name: "OpenDAL",
+    platforms: [.macOS(.v10_15), .iOS(.v13)],
+    products: [
+        .library(name: "OpenDAL", targets: ["OpenDAL"])
+    ],
+    targets: [
+        .systemLibrary(name: "COpenDAL"),
+        .target(
+            name: "OpenDAL",
+            dependencies: ["COpenDAL"],
+            linkerSettings: [
+                .unsafeFlags(["-L\(libraryPath)"]),
+                .linkedFramework("CoreFoundation", .when(platforms: [.macOS, .iOS])),
+                .linkedFramework("Security", .when(platforms: [.macOS, .iOS])),
+                .linkedFramework("SystemConfiguration", .when(platforms: [.macOS, .iOS])),
+                .linkedLibrary("dl", .when(platforms: [.linux])),
+                .linkedLibrary("m", .when(platforms: [.linux])),
+                .linkedLibrary("pthread", .when(platforms: [.linux])),
+            ]
+        ),
+        .testTarget(
+            name: "OpenDALTests",
+            dependencies: ["OpenDAL"]
+        ),
+    ]
+)
@@ -0,0 +1,59 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+import COpenDAL
+
+/// The error thrown by `Operator`.
+public struct OperatorError: Error, CustomStringConvertible {
+    /// The kind of an `OperatorError`, matches `ErrorKind` of OpenDAL.
+    public enum Kind: Int32, Sendable {
+        case unexpected = 0
+        case unsupported = 1
+        case configInvalid = 2
+        case notFound = 3
+        case permissionDenied = 4
+        case isADirectory = 5
+        case notADirectory = 6
+        case alreadyExists = 7
+        case rateLimited = 8
+        case unavailable = 9
+        case timedOut = 10
+        case isSameFile = 11
+        case conditionNotMatch = 12
+        case rangeNotSatisfied = 13
+    }
+
+    public let kind: Kind
+    public let message: String
+
+    public var description: String {
+        message
+    }
+}
+
+/// Throw the error returned by the C ABI if there is one.
+func check(_ err: UnsafeMutablePointer<opendal_swift_error>?) throws {
+    guard let err = err else {
+        return
+    }
+    defer { opendal_swift_error_free(err) }
+
+    throw OperatorError(
+        kind: OperatorError.Kind(rawValue: err.pointee.kind) ?? .unexpected,
+        message: String(cString: err.pointee.message)
+    )
+}
@@ -0,0 +1,171 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+import COpenDAL
+import Foundation
+
+/// Metadata of an entry, returned by `Operator.stat`.
+public struct Metadata: Sendable {
+    public let isFile: Bool
+    public let isDirectory: Bool
+    public let contentLength: UInt64
+    public let contentType: String?
+    public let etag: String?
+    public let lastModified: Date?
+}
+
+/// `Operator` is the entry for all public APIs.
+///
+/// ```swift
+/// let op = try Operator(scheme: "fs", options: ["root": "/tmp"])
+/// try await op.write(Data("Hello World".utf8), to: "test.txt")
+/// let data = try await op.read("test.txt")
+/// ```
+///
+/// Calls are blocking in the native library, they run on a background queue
+/// so that they won't block the cooperative thread pool of swift concurrency.
+public final class Operator: @unchecked Sendable {
+    private static let queue = DispatchQueue(
+        label: "org.apache.opendal.operator",
+        attributes: .concurrent
+    )
+
+    private let nativeOp: OpaquePointer
+
+    /// Create a new operator with the given scheme and options.
+    ///
+    /// Throws `OperatorError` of `configInvalid` if the scheme is not supported.
+    public init(scheme: String, options: [String: String] = [:]) throws {
+        let keys = Array(options.keys)
+        let values = keys.map { options[$0]! }
+
+        var op: OpaquePointer?
+        try withCStrings(keys) { keys in
+            try withCStrings(values) { values in
+                try check(opendal_swift_operator_new(scheme, keys, values, UInt(options.count), &op))
+            }
+        }
+        nativeOp = op!
+    }
+
+    deinit {
+        opendal_swift_operator_free(nativeOp)
+    }
+
+    /// Read the whole path.
+    public func read(_ path: String) async throws -> Data {
+        try await run {
+            var bytes = opendal_swift_bytes(data: nil, len: 0)
+            try check(opendal_swift_operator_read(self.nativeOp, path, &bytes))
+            defer { opendal_swift_bytes_free(&bytes) }
+
+            guard let data = bytes.data else {
+                return Data()
+            }
+            return Data(bytes: data, count: Int(bytes.len))
+        }
+    }
+
+    /// Write the data into the given path.
+    public func write(_ data: Data, to path: String) async throws {
+        try await run {
+            try data.withUnsafeBytes { buf in
+                try check(
+                    opendal_swift_operator_write(
+                        self.nativeOp,
+                        path,
+                        buf.bindMemory(to: UInt8.self).baseAddress,
+                        UInt(buf.count)
+                    )
+                )
+            }
+        }
+    }
+
+    /// Get the metadata of the given path.
+    public func stat(_ path: String) async throws -> Metadata {
+        try await run {
+            var meta = opendal_swift_metadata()
+            try check(opendal_swift_operator_stat(self.nativeOp, path, &meta))
+            defer { opendal_swift_metadata_free(&meta) }
+
+            return Metadata(
+                isFile: meta.is_file,
+                isDirectory: meta.is_dir,
+                contentLength: meta.content_length,
+                contentType: meta.content_type.map { String(cString: $0) },
+                etag: meta.etag.map { String(cString: $0) },
+                lastModified: meta.has_last_modified
+                    ? Date(timeIntervalSince1970: Double(meta.last_modified_ms) / 1000)
+                    : nil
+            )
+        }
+    }
+
+    /// Check if the given path exists.
+    public func exists(_ path: String) async throws -> Bool {
+        try await run {
+            var exists = false
+            try check(opendal_swift_operator_exists(self.nativeOp, path, &exists))
+            return exists
+        }
+    }
+
+    /// Create a dir at the given path, the path must end with `/`.
+    public func createDir(_ path: String) async throws {
+        try await run {
+            try check(opendal_swift_operator_create_dir(self.nativeOp, path))
+        }
+    }
+
+    /// List the paths of entries under the given dir, the path must end with `/`.
+    public func list(_ path: String) async throws -> [String] {
+        try await run {
+            var strings = opendal_swift_strings(data: nil, len: 0)
+            try check(opendal_swift_operator_list(self.nativeOp, path, &strings))
+            defer { opendal_swift_strings_free(&strings) }
+
+            return (0..<Int(strings.len)).map { String(cString: strings.data![$0]!) }
+        }
+    }
+
+    /// Delete the given path, deleting a non-existent path succeeds.
+    public func delete(_ path: String) async throws {
+        try await run {
+            try check(opendal_swift_operator_delete(self.nativeOp, path))
+        }
+    }
+
+    private func run<T>(_ body: @escaping () throws -> T) async throws -> T {
+        try await withCheckedThrowingContinuation { continuation in
+            Operator.queue.async {
+                continuation.resume(with: Result { try body() })
+            }
+        }
+    }
+}
+
+/// Call `body` with the strings converted into an array of C strings.
+private func withCStrings<R>(
+    _ strings: [String],
+    _ body: (UnsafePointer<UnsafePointer<CChar>?>?) throws -> R
+) rethrows -> R {
+    let cStrings = strings.map { UnsafePointer(strdup($0)) }
+    defer { cStrings.forEach { free(UnsafeMutablePointer(mutating: $0)) } }
+
+    return try cStrings.withUnsafeBufferPointer { try body($0.baseAddress) }
+}
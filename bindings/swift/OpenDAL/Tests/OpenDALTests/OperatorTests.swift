@@ -0,0 +1,95 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+import Foundation
+import XCTest
+
+@testable import OpenDAL
+
+final class OperatorTests: XCTestCase {
+    var root: URL!
+    var op: Operator!
+
+    override func setUpWithError() throws {
+        root = FileManager.default.temporaryDirectory.appendingPathComponent(UUID().uuidString)
+        try FileManager.default.createDirectory(at: root, withIntermediateDirectories: true)
+        op = try Operator(scheme: "fs", options: ["root": root.path])
+    }
+
+    override func tearDownWithError() throws {
+        try FileManager.default.removeItem(at: root)
+    }
+
+    func testWriteAndRead() async throws {
+        let data = Data([0, 255, 1, 128])
+        try await op.write(data, to: "sample")
+
+        let content = try await op.read("sample")
+        XCTAssertEqual(content, data)
+    }
+
+    func testStat() async throws {
+        try await op.write(Data("Sample data for testing".utf8), to: "sample")
+
+        let meta = try await op.stat("sample")
+        XCTAssertTrue(meta.isFile)
+        XCTAssertFalse(meta.isDirectory)
+        XCTAssertEqual(meta.contentLength, 23)
+        XCTAssertNotNil(meta.lastModified)
+    }
+
+    func testList() async throws {
+        try await op.createDir("dir/")
+        try await op.write(Data("Sample data for testing".utf8), to: "dir/sample")
+
+        let paths = try await op.list("dir/").sorted()
+        XCTAssertEqual(paths, ["dir/", "dir/sample"])
+    }
+
+    func testDelete() async throws {
+        try await op.write(Data("Sample data for testing".utf8), to: "sample")
+        let existsBeforeDelete = try await op.exists("sample")
+        XCTAssertTrue(existsBeforeDelete)
+
+        try await op.delete("sample")
+        let existsAfterDelete = try await op.exists("sample")
+        XCTAssertFalse(existsAfterDelete)
+    }
+
+    func testDefaultOptions() async throws {
+        let op = try Operator(scheme: "memory")
+        try await op.write(Data("Sample data for testing".utf8), to: "sample")
+
+        let content = try await op.read("sample")
+        XCTAssertEqual(String(decoding: content, as: UTF8.self), "Sample data for testing")
+    }
+
+    func testNotFound() async throws {
+        do {
+            _ = try await op.read("not_exist")
+            XCTFail("read must fail")
+        } catch let err as OperatorError {
+            XCTAssertEqual(err.kind, .notFound)
+        }
+    }
+
+    func testUnsupportedScheme() throws {
+        XCTAssertThrowsError(try Operator(scheme: "not_exist")) { err in
+            XCTAssertEqual((err as? OperatorError)?.kind, .configInvalid)
+        }
+    }
+}
@@ -0,0 +1,118 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+/*
+ * The C ABI of `libopendal_swift`, keep it in sync with `src/lib.rs`.
+ *
+ * All fallible functions return NULL on success, or an error that must be
+ * freed by `opendal_swift_error_free`.
+ */
+
+#ifndef OPENDAL_SWIFT_H
+#define OPENDAL_SWIFT_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#define OPENDAL_SWIFT_ERROR_UNEXPECTED 0
+#define OPENDAL_SWIFT_ERROR_UNSUPPORTED 1
+#define OPENDAL_SWIFT_ERROR_CONFIG_INVALID 2
+#define OPENDAL_SWIFT_ERROR_NOT_FOUND 3
+#define OPENDAL_SWIFT_ERROR_PERMISSION_DENIED 4
+#define OPENDAL_SWIFT_ERROR_IS_A_DIRECTORY 5
+#define OPENDAL_SWIFT_ERROR_NOT_A_DIRECTORY 6
+#define OPENDAL_SWIFT_ERROR_ALREADY_EXISTS 7
+#define OPENDAL_SWIFT_ERROR_RATE_LIMITED 8
+#define OPENDAL_SWIFT_ERROR_UNAVAILABLE 9
+#define OPENDAL_SWIFT_ERROR_TIMED_OUT 10
+#define OPENDAL_SWIFT_ERROR_IS_SAME_FILE 11
+#define OPENDAL_SWIFT_ERROR_CONDITION_NOT_MATCH 12
+#define OPENDAL_SWIFT_ERROR_RANGE_NOT_SATISFIED 13
+
+typedef struct opendal_swift_operator opendal_swift_operator;
+
+typedef struct opendal_swift_error {
+  int32_t kind;
+  char *message;
+} opendal_swift_error;
+
+typedef struct opendal_swift_bytes {
+  uint8_t *data;
+  uintptr_t len;
+} opendal_swift_bytes;
+
+typedef struct opendal_swift_metadata {
+  bool is_file;
+  bool is_dir;
+  uint64_t content_length;
+  int64_t last_modified_ms;
+  bool has_last_modified;
+  char *content_type;
+  char *etag;
+} opendal_swift_metadata;
+
+typedef struct opendal_swift_strings {
+  char **data;
+  uintptr_t len;
+} opendal_swift_strings;
+
+opendal_swift_error *opendal_swift_operator_new(const char *scheme,
+                                                const char *const *keys,
+                                                const char *const *values,
+                                                uintptr_t len,
+                                                opendal_swift_operator **out);
+
+void opendal_swift_operator_free(opendal_swift_operator *op);
+
+opendal_swift_error *opendal_swift_operator_read(const opendal_swift_operator *op,
+                                                 const char *path,
+                                                 opendal_swift_bytes *out);
+
+opendal_swift_error *opendal_swift_operator_write(const opendal_swift_operator *op,
+                                                  const char *path,
+                                                  const uint8_t *data,
+                                                  uintptr_t len);
+
+opendal_swift_error *opendal_swift_operator_stat(const opendal_swift_operator *op,
+                                                 const char *path,
+                                                 opendal_swift_metadata *out);
+
+opendal_swift_error *opendal_swift_operator_exists(const opendal_swift_operator *op,
+                                                   const char *path,
+                                                   bool *out);
+
+opendal_swift_error *opendal_swift_operator_create_dir(const opendal_swift_operator *op,
+                                                       const char *path);
+
+opendal_swift_error *opendal_swift_operator_list(const opendal_swift_operator *op,
+                                                 const char *path,
+                                                 opendal_swift_strings *out);
+
+opendal_swift_error *opendal_swift_operator_delete(const opendal_swift_operator *op,
+                                                   const char *path);
+
+void opendal_swift_error_free(opendal_swift_error *err);
+
+void opendal_swift_bytes_free(opendal_swift_bytes *bs);
+
+void opendal_swift_metadata_free(opendal_swift_metadata *meta);
+
+void opendal_swift_strings_free(opendal_swift_strings *strings);
+
+#endif /* OPENDAL_SWIFT_H */
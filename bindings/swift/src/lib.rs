@@ -0,0 +1,407 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The C ABI used by the `OpenDAL` swift package, see `include/opendal_swift.h`.
+//!
+//! All functions are blocking, the swift package runs them off the
+//! cooperative thread pool to expose async APIs.
+
+#![allow(non_camel_case_types)]
+
+use std::collections::HashMap;
+use std::ffi::c_char;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::ptr;
+use std::str::FromStr;
+
+use opendal as ocore;
+
+/// The operator handle, it must be freed by `opendal_swift_operator_free`.
+pub struct opendal_swift_operator(ocore::BlockingOperator);
+
+/// The error returned by fallible functions, it must be freed by `opendal_swift_error_free`.
+#[repr(C)]
+pub struct opendal_swift_error {
+    /// The kind of this error, see `OPENDAL_SWIFT_ERROR_*` in the header.
+    pub kind: i32,
+    /// The message of this error.
+    pub message: *mut c_char,
+}
+
+/// The bytes returned by read, it must be freed by `opendal_swift_bytes_free`.
+#[repr(C)]
+pub struct opendal_swift_bytes {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+/// The metadata returned by stat, it must be freed by `opendal_swift_metadata_free`.
+#[repr(C)]
+pub struct opendal_swift_metadata {
+    pub is_file: bool,
+    pub is_dir: bool,
+    pub content_length: u64,
+    /// Last modified time in milliseconds since unix epoch, valid only if
+    /// `has_last_modified` is true.
+    pub last_modified_ms: i64,
+    pub has_last_modified: bool,
+    /// Null if not returned by service.
+    pub content_type: *mut c_char,
+    /// Null if not returned by service.
+    pub etag: *mut c_char,
+}
+
+/// The strings returned by list, it must be freed by `opendal_swift_strings_free`.
+#[repr(C)]
+pub struct opendal_swift_strings {
+    pub data: *mut *mut c_char,
+    pub len: usize,
+}
+
+fn new_error(err: ocore::Error) -> *mut opendal_swift_error {
+    let kind = match err.kind() {
+        ocore::ErrorKind::Unsupported => 1,
+        ocore::ErrorKind::ConfigInvalid => 2,
+        ocore::ErrorKind::NotFound => 3,
+        ocore::ErrorKind::PermissionDenied => 4,
+        ocore::ErrorKind::IsADirectory => 5,
+        ocore::ErrorKind::NotADirectory => 6,
+        ocore::ErrorKind::AlreadyExists => 7,
+        ocore::ErrorKind::RateLimited => 8,
+        ocore::ErrorKind::Unavailable => 9,
+        ocore::ErrorKind::TimedOut => 10,
+        ocore::ErrorKind::IsSameFile => 11,
+        ocore::ErrorKind::ConditionNotMatch => 12,
+        ocore::ErrorKind::RangeNotSatisfied => 13,
+        _ => 0,
+    };
+
+    Box::into_raw(Box::new(opendal_swift_error {
+        kind,
+        message: into_c_string(err.to_string()),
+    }))
+}
+
+fn into_c_string(s: String) -> *mut c_char {
+    // Strings from services won't contain nul bytes in practice, drop them
+    // instead of failing the whole call.
+    CString::new(s.replace('\0', ""))
+        .unwrap_or_default()
+        .into_raw()
+}
+
+unsafe fn free_c_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Convert the given c string into `&str`.
+unsafe fn as_str<'a>(s: *const c_char, name: &'static str) -> ocore::Result<&'a str> {
+    if s.is_null() {
+        return Err(
+            ocore::Error::new(ocore::ErrorKind::Unexpected, "argument must not be null")
+                .with_context("argument", name),
+        );
+    }
+    CStr::from_ptr(s).to_str().map_err(|err| {
+        ocore::Error::new(ocore::ErrorKind::Unexpected, "argument is not valid utf-8")
+            .with_context("argument", name)
+            .set_source(err)
+    })
+}
+
+/// Parse the scheme and make sure it's enabled in this build.
+fn parse_scheme(scheme: &str) -> ocore::Result<ocore::Scheme> {
+    let scheme = ocore::Scheme::from_str(scheme)?;
+    if !ocore::Scheme::enabled().contains(&scheme) {
+        return Err(ocore::Error::new(
+            ocore::ErrorKind::ConfigInvalid,
+            "scheme is not supported or not enabled",
+        )
+        .with_context("scheme", scheme));
+    }
+
+    Ok(scheme)
+}
+
+/// Convert the result into a nullable error pointer.
+fn into_error(res: ocore::Result<()>) -> *mut opendal_swift_error {
+    match res {
+        Ok(()) => ptr::null_mut(),
+        Err(err) => new_error(err),
+    }
+}
+
+/// Create a new operator with the given scheme and `len` pairs of options.
+///
+/// # Safety
+///
+/// - `scheme` must be a valid c string.
+/// - `keys` and `values` must point to `len` valid c strings, they can be null if `len` is 0.
+/// - `out` must be a valid pointer, the operator will be written into it on success.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_operator_new(
+    scheme: *const c_char,
+    keys: *const *const c_char,
+    values: *const *const c_char,
+    len: usize,
+    out: *mut *mut opendal_swift_operator,
+) -> *mut opendal_swift_error {
+    let build = || -> ocore::Result<ocore::BlockingOperator> {
+        let scheme = parse_scheme(as_str(scheme, "scheme")?)?;
+
+        let mut map = HashMap::with_capacity(len);
+        for i in 0..len {
+            let key = as_str(*keys.add(i), "keys")?;
+            let value = as_str(*values.add(i), "values")?;
+            map.insert(key.to_string(), value.to_string());
+        }
+
+        // BlockingLayer is added by `blocking()` for services without native blocking support.
+        Ok(ocore::Operator::via_iter(scheme, map)?.blocking())
+    };
+
+    into_error(build().map(|op| {
+        *out = Box::into_raw(Box::new(opendal_swift_operator(op)));
+    }))
+}
+
+/// Free the operator.
+///
+/// # Safety
+///
+/// `op` must be returned by `opendal_swift_operator_new` or null.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_operator_free(op: *mut opendal_swift_operator) {
+    if !op.is_null() {
+        drop(Box::from_raw(op));
+    }
+}
+
+/// Read the whole path into `out`.
+///
+/// # Safety
+///
+/// `op` must be a valid operator, `path` a valid c string and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_operator_read(
+    op: *const opendal_swift_operator,
+    path: *const c_char,
+    out: *mut opendal_swift_bytes,
+) -> *mut opendal_swift_error {
+    let op = &(*op).0;
+    into_error(as_str(path, "path").and_then(|path| {
+        let bs = op.read(path)?.to_vec().into_boxed_slice();
+        let len = bs.len();
+        *out = opendal_swift_bytes {
+            data: Box::into_raw(bs) as *mut u8,
+            len,
+        };
+        Ok(())
+    }))
+}
+
+/// Write `len` bytes at `data` into the given path.
+///
+/// # Safety
+///
+/// `op` must be a valid operator, `path` a valid c string and `data` must point to
+/// `len` bytes, it can be null if `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_operator_write(
+    op: *const opendal_swift_operator,
+    path: *const c_char,
+    data: *const u8,
+    len: usize,
+) -> *mut opendal_swift_error {
+    let op = &(*op).0;
+    let bs = if len == 0 {
+        Vec::new()
+    } else {
+        std::slice::from_raw_parts(data, len).to_vec()
+    };
+    into_error(as_str(path, "path").and_then(|path| op.write(path, bs)))
+}
+
+/// Get the metadata of the given path into `out`.
+///
+/// # Safety
+///
+/// `op` must be a valid operator, `path` a valid c string and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_operator_stat(
+    op: *const opendal_swift_operator,
+    path: *const c_char,
+    out: *mut opendal_swift_metadata,
+) -> *mut opendal_swift_error {
+    let op = &(*op).0;
+    into_error(as_str(path, "path").and_then(|path| {
+        let meta = op.stat(path)?;
+        let last_modified = meta.last_modified();
+        *out = opendal_swift_metadata {
+            is_file: meta.is_file(),
+            is_dir: meta.is_dir(),
+            content_length: meta.content_length(),
+            last_modified_ms: last_modified.map(|t| t.timestamp_millis()).unwrap_or(0),
+            has_last_modified: last_modified.is_some(),
+            content_type: meta
+                .content_type()
+                .map_or(ptr::null_mut(), |v| into_c_string(v.to_string())),
+            etag: meta
+                .etag()
+                .map_or(ptr::null_mut(), |v| into_c_string(v.to_string())),
+        };
+        Ok(())
+    }))
+}
+
+/// Check if the given path exists.
+///
+/// # Safety
+///
+/// `op` must be a valid operator, `path` a valid c string and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_operator_exists(
+    op: *const opendal_swift_operator,
+    path: *const c_char,
+    out: *mut bool,
+) -> *mut opendal_swift_error {
+    let op = &(*op).0;
+    into_error(as_str(path, "path").and_then(|path| {
+        *out = op.exists(path)?;
+        Ok(())
+    }))
+}
+
+/// Create a dir at the given path, the path must end with `/`.
+///
+/// # Safety
+///
+/// `op` must be a valid operator and `path` a valid c string.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_operator_create_dir(
+    op: *const opendal_swift_operator,
+    path: *const c_char,
+) -> *mut opendal_swift_error {
+    let op = &(*op).0;
+    into_error(as_str(path, "path").and_then(|path| op.create_dir(path)))
+}
+
+/// List the paths of entries under the given dir into `out`, the path must end with `/`.
+///
+/// # Safety
+///
+/// `op` must be a valid operator, `path` a valid c string and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_operator_list(
+    op: *const opendal_swift_operator,
+    path: *const c_char,
+    out: *mut opendal_swift_strings,
+) -> *mut opendal_swift_error {
+    let op = &(*op).0;
+    into_error(as_str(path, "path").and_then(|path| {
+        let paths: Box<[*mut c_char]> = op
+            .list(path)?
+            .into_iter()
+            .map(|entry| into_c_string(entry.path().to_string()))
+            .collect();
+        let len = paths.len();
+        *out = opendal_swift_strings {
+            data: Box::into_raw(paths) as *mut *mut c_char,
+            len,
+        };
+        Ok(())
+    }))
+}
+
+/// Delete the given path, deleting a non-existent path succeeds.
+///
+/// # Safety
+///
+/// `op` must be a valid operator and `path` a valid c string.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_operator_delete(
+    op: *const opendal_swift_operator,
+    path: *const c_char,
+) -> *mut opendal_swift_error {
+    let op = &(*op).0;
+    into_error(as_str(path, "path").and_then(|path| op.delete(path)))
+}
+
+/// Free the error.
+///
+/// # Safety
+///
+/// `err` must be returned by this library or null.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_error_free(err: *mut opendal_swift_error) {
+    if !err.is_null() {
+        let err = Box::from_raw(err);
+        free_c_string(err.message);
+    }
+}
+
+/// Free the bytes returned by `opendal_swift_operator_read`.
+///
+/// # Safety
+///
+/// `bs` must be filled by `opendal_swift_operator_read`.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_bytes_free(bs: *mut opendal_swift_bytes) {
+    let bs = &mut *bs;
+    if !bs.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+            bs.data, bs.len,
+        )));
+        bs.data = ptr::null_mut();
+        bs.len = 0;
+    }
+}
+
+/// Free the strings held by the metadata returned by `opendal_swift_operator_stat`.
+///
+/// # Safety
+///
+/// `meta` must be filled by `opendal_swift_operator_stat`.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_metadata_free(meta: *mut opendal_swift_metadata) {
+    let meta = &mut *meta;
+    free_c_string(meta.content_type);
+    free_c_string(meta.etag);
+    meta.content_type = ptr::null_mut();
+    meta.etag = ptr::null_mut();
+}
+
+/// Free the strings returned by `opendal_swift_operator_list`.
+///
+/// # Safety
+///
+/// `strings` must be filled by `opendal_swift_operator_list`.
+#[no_mangle]
+pub unsafe extern "C" fn opendal_swift_strings_free(strings: *mut opendal_swift_strings) {
+    let strings = &mut *strings;
+    if !strings.data.is_null() {
+        let data = Box::from_raw(ptr::slice_from_raw_parts_mut(strings.data, strings.len));
+        for s in data.iter() {
+            free_c_string(*s);
+        }
+        strings.data = ptr::null_mut();
+        strings.len = 0;
+    }
+}
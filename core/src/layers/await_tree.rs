@@ -230,6 +230,10 @@ impl<R: oio::List> oio::List for AwaitTreeWrapper<R> {
             .instrument_await(format!("opendal::{}", Operation::ListerNext))
             .await
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<R: oio::BlockingList> oio::BlockingList for AwaitTreeWrapper<R> {
@@ -15,9 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::future::Future;
 use std::sync::Arc;
+use std::sync::OnceLock;
 
 use tokio::runtime::Handle;
+use tokio::runtime::Runtime;
+use tokio::runtime::RuntimeFlavor;
 
 use crate::raw::*;
 use crate::*;
@@ -27,9 +31,38 @@ use crate::*;
 /// # Notes
 ///
 /// - Please only enable this layer when the underlying service does not support blocking.
+/// - [`Operator::blocking`] will add this layer automatically with [`BlockingLayer::new`]
+///   if the underlying service does not support blocking.
+/// - It's safe to call blocking operations inside async context, we will leave the runtime
+///   with [`tokio::task::block_in_place`] on multi-thread runtime, or run the operation in
+///   another thread on current-thread runtime.
 ///
 /// # Examples
 ///
+/// ## Without runtime
+///
+/// [`BlockingLayer::new`] will reuse current runtime if it's a multi-thread runtime, or
+/// fallback to a global runtime which is lazily created.
+///
+/// ```rust,no_run
+/// # use opendal::layers::BlockingLayer;
+/// # use opendal::services;
+/// # use opendal::BlockingOperator;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// fn main() -> Result<()> {
+///     let mut builder = services::S3::default().bucket("test").region("us-east-1");
+///
+///     let _: BlockingOperator = Operator::new(builder)?
+///         .layer(BlockingLayer::new())
+///         .finish()
+///         .blocking();
+///
+///     Ok(())
+/// }
+/// ```
+///
 /// ## In async context
 ///
 /// BlockingLayer will use current async context's runtime to handle the async calls.
@@ -132,7 +165,44 @@ pub struct BlockingLayer {
     handle: Handle,
 }
 
+impl Default for BlockingLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl BlockingLayer {
+    /// Create a new `BlockingLayer` with the current runtime's handle if it's a multi-thread
+    /// runtime, otherwise the global runtime will be used.
+    ///
+    /// The global runtime is created lazily with default configuration, use
+    /// [`BlockingLayer::set_global_handle`] to use another runtime instead.
+    pub fn new() -> Self {
+        let handle = match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => handle,
+            _ => global_handle(),
+        };
+
+        Self { handle }
+    }
+
+    /// Create a new `BlockingLayer` with given runtime's handle.
+    pub fn with_handle(handle: Handle) -> Self {
+        Self { handle }
+    }
+
+    /// Set the handle of the global runtime used by [`BlockingLayer::new`].
+    ///
+    /// Returns an error if the global runtime has been set or used.
+    pub fn set_global_handle(handle: Handle) -> Result<()> {
+        GLOBAL_HANDLE.set(handle).map_err(|_| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "global runtime of blocking layer has been initialized",
+            )
+        })
+    }
+
     /// Create a new `BlockingLayer` with the current runtime's handle
     pub fn create() -> Result<Self> {
         Ok(Self {
@@ -142,6 +212,50 @@ impl BlockingLayer {
     }
 }
 
+static GLOBAL_RUNTIME: OnceLock<Runtime> = OnceLock::new();
+static GLOBAL_HANDLE: OnceLock<Handle> = OnceLock::new();
+
+/// Get the handle of the global runtime, the runtime will be created if not set.
+fn global_handle() -> Handle {
+    GLOBAL_HANDLE
+        .get_or_init(|| {
+            GLOBAL_RUNTIME
+                .get_or_init(|| {
+                    tokio::runtime::Builder::new_multi_thread()
+                        .thread_name("opendal-blocking")
+                        .enable_all()
+                        .build()
+                        .expect("global runtime of blocking layer must be built")
+                })
+                .handle()
+                .clone()
+        })
+        .clone()
+}
+
+/// Run the future to completion with given handle.
+///
+/// `Handle::block_on` will panic inside async context, so we leave the runtime by
+/// `block_in_place` on multi-thread runtime. Current-thread runtime can't be left, so we
+/// have to run the future in another thread.
+fn block_on<F>(handle: &Handle, fut: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    let Ok(current) = Handle::try_current() else {
+        return handle.block_on(fut);
+    };
+
+    match current.runtime_flavor() {
+        RuntimeFlavor::MultiThread => tokio::task::block_in_place(|| handle.block_on(fut)),
+        _ => std::thread::scope(|s| match s.spawn(|| handle.block_on(fut)).join() {
+            Ok(v) => v,
+            Err(err) => std::panic::resume_unwind(err),
+        }),
+    }
+}
+
 impl<A: Access> Layer<A> for BlockingLayer {
     type LayeredAccess = BlockingAccessor<A>;
 
@@ -218,11 +332,11 @@ impl<A: Access> LayeredAccess for BlockingAccessor<A> {
     }
 
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
-        self.handle.block_on(self.inner.create_dir(path, args))
+        block_on(&self.handle, self.inner.create_dir(path, args))
     }
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
-        self.handle.block_on(async {
+        block_on(&self.handle, async {
             let (rp, reader) = self.inner.read(path, args).await?;
             let blocking_reader = Self::BlockingReader::new(self.handle.clone(), reader);
 
@@ -231,7 +345,7 @@ impl<A: Access> LayeredAccess for BlockingAccessor<A> {
     }
 
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
-        self.handle.block_on(async {
+        block_on(&self.handle, async {
             let (rp, writer) = self.inner.write(path, args).await?;
             let blocking_writer = Self::BlockingWriter::new(self.handle.clone(), writer);
             Ok((rp, blocking_writer))
@@ -239,19 +353,19 @@ impl<A: Access> LayeredAccess for BlockingAccessor<A> {
     }
 
     fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
-        self.handle.block_on(self.inner.copy(from, to, args))
+        block_on(&self.handle, self.inner.copy(from, to, args))
     }
 
     fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
-        self.handle.block_on(self.inner.rename(from, to, args))
+        block_on(&self.handle, self.inner.rename(from, to, args))
     }
 
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
-        self.handle.block_on(self.inner.stat(path, args))
+        block_on(&self.handle, self.inner.stat(path, args))
     }
 
     fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
-        self.handle.block_on(async {
+        block_on(&self.handle, async {
             let (rp, writer) = self.inner.delete().await?;
             let blocking_deleter = Self::BlockingDeleter::new(self.handle.clone(), writer);
             Ok((rp, blocking_deleter))
@@ -259,7 +373,7 @@ impl<A: Access> LayeredAccess for BlockingAccessor<A> {
     }
 
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
-        self.handle.block_on(async {
+        block_on(&self.handle, async {
             let (rp, lister) = self.inner.list(path, args).await?;
             let blocking_lister = Self::BlockingLister::new(self.handle.clone(), lister);
             Ok((rp, blocking_lister))
@@ -280,23 +394,23 @@ impl<I> BlockingWrapper<I> {
 
 impl<I: oio::Read + 'static> oio::BlockingRead for BlockingWrapper<I> {
     fn read(&mut self) -> Result<Buffer> {
-        self.handle.block_on(self.inner.read())
+        block_on(&self.handle, self.inner.read())
     }
 }
 
 impl<I: oio::Write + 'static> oio::BlockingWrite for BlockingWrapper<I> {
     fn write(&mut self, bs: Buffer) -> Result<()> {
-        self.handle.block_on(self.inner.write(bs))
+        block_on(&self.handle, self.inner.write(bs))
     }
 
     fn close(&mut self) -> Result<()> {
-        self.handle.block_on(self.inner.close())
+        block_on(&self.handle, self.inner.close())
     }
 }
 
 impl<I: oio::List> oio::BlockingList for BlockingWrapper<I> {
     fn next(&mut self) -> Result<Option<oio::Entry>> {
-        self.handle.block_on(self.inner.next())
+        block_on(&self.handle, self.inner.next())
     }
 }
 
@@ -306,7 +420,7 @@ impl<I: oio::Delete + 'static> oio::BlockingDelete for BlockingWrapper<I> {
     }
 
     fn flush(&mut self) -> Result<usize> {
-        self.handle.block_on(self.inner.flush())
+        block_on(&self.handle, self.inner.flush())
     }
 }
 
@@ -340,6 +454,30 @@ mod tests {
         assert!(layer.is_ok())
     }
 
+    #[test]
+    fn test_blocking_layer_new() {
+        let op = Operator::new(crate::services::Memory::default())
+            .unwrap()
+            .layer(BlockingLayer::new())
+            .finish()
+            .blocking();
+
+        op.write("test", "hello").unwrap();
+        assert_eq!(op.read("test").unwrap().to_vec(), b"hello");
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_blocking_in_current_thread_runtime() {
+        let op = Operator::new(crate::services::Memory::default())
+            .unwrap()
+            .layer(BlockingLayer::new())
+            .finish()
+            .blocking();
+
+        op.write("test", "hello").unwrap();
+        assert_eq!(op.read("test").unwrap().to_vec(), b"hello");
+    }
+
     #[test]
     fn test_blocking_layer_in_async_context() {
         // create and drop in an async context
@@ -30,11 +30,13 @@ use std::sync::Arc;
 ///
 /// There are two main differences between this checker with the `CorrectnessChecker`:
 /// 1. This checker provides additional checks for capabilities like write_with_content_type and
-///     list_with_versions, among others. These capabilities do not affect data integrity, even if
-///     the underlying storage services do not support them.
+///    list_with_versions, among others. These capabilities do not affect data integrity, even if
+///    the underlying storage services do not support them.
+///    It also checks `create_dir`, `copy`, `rename` and every kind of `presign` against the
+///    capability, so unsupported operations fail before reaching the service.
 ///
 /// 2. OpenDAL doesn't apply this checker by default. Users can enable this layer if they want to
-///     enforce stricter requirements.
+///    enforce stricter requirements.
 ///
 /// # examples
 ///
@@ -79,74 +81,267 @@ impl<A: Access> Debug for CapabilityAccessor<A> {
     }
 }
 
-impl<A: Access> LayeredAccess for CapabilityAccessor<A> {
-    type Inner = A;
-    type Reader = A::Reader;
-    type Writer = A::Writer;
-    type Lister = A::Lister;
-    type Deleter = A::Deleter;
-    type BlockingReader = A::BlockingReader;
-    type BlockingWriter = A::BlockingWriter;
-    type BlockingLister = A::BlockingLister;
-    type BlockingDeleter = A::BlockingDeleter;
+impl<A: Access> CapabilityAccessor<A> {
+    fn check_create_dir(&self, op: Operation) -> crate::Result<()> {
+        if !self.info.full_capability().create_dir {
+            return Err(new_unsupported_error(self.info.as_ref(), op, "create_dir"));
+        }
 
-    fn inner(&self) -> &Self::Inner {
-        &self.inner
+        Ok(())
     }
 
-    async fn read(&self, path: &str, args: OpRead) -> crate::Result<(RpRead, Self::Reader)> {
-        self.inner.read(path, args).await
+    fn check_read(&self, op: Operation, args: &OpRead) -> crate::Result<()> {
+        let capability = self.info.full_capability();
+        if !capability.read_with_override_content_type && args.override_content_type().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                op,
+                "override_content_type",
+            ));
+        }
+        if !capability.read_with_override_cache_control && args.override_cache_control().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                op,
+                "override_cache_control",
+            ));
+        }
+        if !capability.read_with_override_content_disposition
+            && args.override_content_disposition().is_some()
+        {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                op,
+                "override_content_disposition",
+            ));
+        }
+        if !capability.read_with_version && args.version().is_some() {
+            return Err(new_unsupported_error(self.info.as_ref(), op, "version"));
+        }
+
+        Ok(())
     }
 
-    async fn write(&self, path: &str, args: OpWrite) -> crate::Result<(RpWrite, Self::Writer)> {
+    fn check_write(&self, op: Operation, args: &OpWrite) -> crate::Result<()> {
         let capability = self.info.full_capability();
         if !capability.write_with_content_type && args.content_type().is_some() {
             return Err(new_unsupported_error(
                 self.info.as_ref(),
-                Operation::Write,
+                op,
                 "content_type",
             ));
         }
         if !capability.write_with_cache_control && args.cache_control().is_some() {
             return Err(new_unsupported_error(
                 self.info.as_ref(),
-                Operation::Write,
+                op,
                 "cache_control",
             ));
         }
         if !capability.write_with_content_disposition && args.content_disposition().is_some() {
             return Err(new_unsupported_error(
                 self.info.as_ref(),
-                Operation::Write,
+                op,
                 "content_disposition",
             ));
         }
+        if !capability.write_with_content_encoding && args.content_encoding().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                op,
+                "content_encoding",
+            ));
+        }
+        if !capability.write_with_if_match && args.if_match().is_some() {
+            return Err(new_unsupported_error(self.info.as_ref(), op, "if_match"));
+        }
+        if !capability.write_with_if_none_match && args.if_none_match().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                op,
+                "if_none_match",
+            ));
+        }
+        if !capability.write_with_if_not_exists && args.if_not_exists() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                op,
+                "if_not_exists",
+            ));
+        }
+        if !capability.write_with_user_metadata && args.user_metadata().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                op,
+                "user_metadata",
+            ));
+        }
 
-        self.inner.write(path, args).await
+        Ok(())
     }
 
-    async fn delete(&self) -> crate::Result<(RpDelete, Self::Deleter)> {
-        self.inner.delete().await
+    fn check_copy(&self, op: Operation) -> crate::Result<()> {
+        if !self.info.full_capability().copy {
+            return Err(new_unsupported_error(self.info.as_ref(), op, "copy"));
+        }
+
+        Ok(())
     }
 
-    async fn list(&self, path: &str, args: OpList) -> crate::Result<(RpList, Self::Lister)> {
+    fn check_rename(&self, op: Operation) -> crate::Result<()> {
+        if !self.info.full_capability().rename {
+            return Err(new_unsupported_error(self.info.as_ref(), op, "rename"));
+        }
+
+        Ok(())
+    }
+
+    fn check_stat(&self, op: Operation, args: &OpStat) -> crate::Result<()> {
+        let capability = self.info.full_capability();
+        if !capability.stat_with_override_content_type && args.override_content_type().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                op,
+                "override_content_type",
+            ));
+        }
+        if !capability.stat_with_override_cache_control && args.override_cache_control().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                op,
+                "override_cache_control",
+            ));
+        }
+        if !capability.stat_with_override_content_disposition
+            && args.override_content_disposition().is_some()
+        {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                op,
+                "override_content_disposition",
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn check_list(&self, op: Operation, args: &OpList) -> crate::Result<()> {
         let capability = self.info.full_capability();
         if !capability.list_with_versions && args.versions() {
+            return Err(new_unsupported_error(self.info.as_ref(), op, "version"));
+        }
+        if !capability.list_with_deleted && args.deleted() {
+            return Err(new_unsupported_error(self.info.as_ref(), op, "deleted"));
+        }
+        if !capability.list_with_start_after && args.start_after().is_some() {
+            return Err(new_unsupported_error(self.info.as_ref(), op, "start_after"));
+        }
+
+        Ok(())
+    }
+
+    fn check_presign(&self, args: &OpPresign) -> crate::Result<()> {
+        let capability = self.info.full_capability();
+        let (supported, name) = match args.operation() {
+            PresignOperation::Stat(_) => (capability.presign_stat, "stat"),
+            PresignOperation::Read(_) => (capability.presign_read, "read"),
+            PresignOperation::Write(_) => (capability.presign_write, "write"),
+            PresignOperation::InitiateMultipart(_)
+            | PresignOperation::UploadPart(_)
+            | PresignOperation::CompleteMultipart(_) => {
+                (capability.presign_write_multi, "write_multi")
+            }
+            PresignOperation::Post(_) => (capability.presign_post, "post"),
+        };
+        if !supported {
             return Err(new_unsupported_error(
                 self.info.as_ref(),
-                Operation::List,
-                "version",
+                Operation::Presign,
+                name,
             ));
         }
+        if !capability.presign_with_headers && !args.headers().is_empty() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::Presign,
+                "headers",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<A: Access> LayeredAccess for CapabilityAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type Writer = A::Writer;
+    type Lister = A::Lister;
+    type Deleter = A::Deleter;
+    type BlockingReader = A::BlockingReader;
+    type BlockingWriter = A::BlockingWriter;
+    type BlockingLister = A::BlockingLister;
+    type BlockingDeleter = A::BlockingDeleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> crate::Result<RpCreateDir> {
+        self.check_create_dir(Operation::CreateDir)?;
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> crate::Result<(RpRead, Self::Reader)> {
+        self.check_read(Operation::Read, &args)?;
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> crate::Result<(RpWrite, Self::Writer)> {
+        self.check_write(Operation::Write, &args)?;
+        self.inner.write(path, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> crate::Result<RpCopy> {
+        self.check_copy(Operation::Copy)?;
+        self.inner.copy(from, to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> crate::Result<RpRename> {
+        self.check_rename(Operation::Rename)?;
+        self.inner.rename(from, to, args).await
+    }
 
+    async fn stat(&self, path: &str, args: OpStat) -> crate::Result<RpStat> {
+        self.check_stat(Operation::Stat, &args)?;
+        self.inner.stat(path, args).await
+    }
+
+    async fn delete(&self) -> crate::Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> crate::Result<(RpList, Self::Lister)> {
+        self.check_list(Operation::List, &args)?;
         self.inner.list(path, args).await
     }
 
+    async fn presign(&self, path: &str, args: OpPresign) -> crate::Result<RpPresign> {
+        self.check_presign(&args)?;
+        self.inner.presign(path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> crate::Result<RpCreateDir> {
+        self.check_create_dir(Operation::BlockingCreateDir)?;
+        self.inner.blocking_create_dir(path, args)
+    }
+
     fn blocking_read(
         &self,
         path: &str,
         args: OpRead,
     ) -> crate::Result<(RpRead, Self::BlockingReader)> {
+        self.check_read(Operation::BlockingRead, &args)?;
         self.inner().blocking_read(path, args)
     }
 
@@ -155,32 +350,25 @@ impl<A: Access> LayeredAccess for CapabilityAccessor<A> {
         path: &str,
         args: OpWrite,
     ) -> crate::Result<(RpWrite, Self::BlockingWriter)> {
-        let capability = self.info.full_capability();
-        if !capability.write_with_content_type && args.content_type().is_some() {
-            return Err(new_unsupported_error(
-                self.info.as_ref(),
-                Operation::BlockingWrite,
-                "content_type",
-            ));
-        }
-        if !capability.write_with_cache_control && args.cache_control().is_some() {
-            return Err(new_unsupported_error(
-                self.info.as_ref(),
-                Operation::BlockingWrite,
-                "cache_control",
-            ));
-        }
-        if !capability.write_with_content_disposition && args.content_disposition().is_some() {
-            return Err(new_unsupported_error(
-                self.info.as_ref(),
-                Operation::BlockingWrite,
-                "content_disposition",
-            ));
-        }
-
+        self.check_write(Operation::BlockingWrite, &args)?;
         self.inner.blocking_write(path, args)
     }
 
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> crate::Result<RpCopy> {
+        self.check_copy(Operation::BlockingCopy)?;
+        self.inner.blocking_copy(from, to, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> crate::Result<RpRename> {
+        self.check_rename(Operation::BlockingRename)?;
+        self.inner.blocking_rename(from, to, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> crate::Result<RpStat> {
+        self.check_stat(Operation::BlockingStat, &args)?;
+        self.inner.blocking_stat(path, args)
+    }
+
     fn blocking_delete(&self) -> crate::Result<(RpDelete, Self::BlockingDeleter)> {
         self.inner.blocking_delete()
     }
@@ -190,21 +378,15 @@ impl<A: Access> LayeredAccess for CapabilityAccessor<A> {
         path: &str,
         args: OpList,
     ) -> crate::Result<(RpList, Self::BlockingLister)> {
-        let capability = self.info.full_capability();
-        if !capability.list_with_versions && args.versions() {
-            return Err(new_unsupported_error(
-                self.info.as_ref(),
-                Operation::BlockingList,
-                "version",
-            ));
-        }
-
+        self.check_list(Operation::BlockingList, &args)?;
         self.inner.blocking_list(path, args)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
     use crate::{Capability, ErrorKind, Operator};
 
@@ -237,6 +419,18 @@ mod tests {
         async fn list(&self, _: &str, _: OpList) -> crate::Result<(RpList, Self::Lister)> {
             Ok((RpList {}, Box::new(())))
         }
+
+        async fn copy(&self, _: &str, _: &str, _: OpCopy) -> crate::Result<RpCopy> {
+            Ok(RpCopy {})
+        }
+
+        async fn presign(&self, _: &str, _: OpPresign) -> crate::Result<RpPresign> {
+            Ok(RpPresign::new(PresignedRequest::new(
+                http::Method::GET,
+                "https://example.com".parse().unwrap(),
+                http::HeaderMap::new(),
+            )))
+        }
     }
 
     fn new_test_operator(capability: Capability) -> Operator {
@@ -263,6 +457,12 @@ mod tests {
             .await;
         assert!(res.is_err());
 
+        let res = op.writer_with("path").if_none_match("etag").await;
+        assert!(res.is_err());
+
+        let res = op.writer_with("path").if_not_exists(true).await;
+        assert!(res.is_err());
+
         let op = new_test_operator(Capability {
             write: true,
             write_with_content_type: true,
@@ -301,4 +501,57 @@ mod tests {
         let res = op.lister_with("path/").versions(true).await;
         assert!(res.is_ok())
     }
+
+    #[tokio::test]
+    async fn test_copy() {
+        let op = new_test_operator(Capability::default());
+        let res = op.copy("from", "to").await;
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            copy: true,
+            ..Default::default()
+        });
+        let res = op.copy("from", "to").await;
+        assert!(res.is_ok())
+    }
+
+    #[tokio::test]
+    async fn test_presign_with() {
+        let op = new_test_operator(Capability {
+            presign: true,
+            presign_read: true,
+            ..Default::default()
+        });
+        let res = op.presign_read("path", Duration::from_secs(60)).await;
+        assert!(res.is_ok());
+
+        let res = op.presign_write("path", Duration::from_secs(60)).await;
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert("x-amz-acl", "private".parse().unwrap());
+        let op = new_test_operator(Capability {
+            presign: true,
+            presign_write: true,
+            ..Default::default()
+        });
+        let res = op
+            .presign_write_with("path", Duration::from_secs(60))
+            .headers(headers.clone())
+            .await;
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            presign: true,
+            presign_write: true,
+            presign_with_headers: true,
+            ..Default::default()
+        });
+        let res = op
+            .presign_write_with("path", Duration::from_secs(60))
+            .headers(headers)
+            .await;
+        assert!(res.is_ok())
+    }
 }
@@ -21,6 +21,7 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use crate::raw::oio::FlatLister;
+use crate::raw::oio::GlobLister;
 use crate::raw::oio::PrefixLister;
 use crate::raw::*;
 use crate::*;
@@ -38,10 +39,22 @@ use crate::*;
 ///
 /// So far `CompleteLayer` will do the following things:
 ///
+/// ## Create Dir Completion
+///
+/// Not all services support create dir natively, but we can simulate it by
+/// writing an empty marker object if `write_can_empty` is supported.
+///
+/// If `recursive` is set, all missing parent dirs will be created from top
+/// to bottom as well.
+///
 /// ## Stat Completion
 ///
 /// Not all services support stat dir natively, but we can simulate it via list.
 ///
+/// Services that support `create_dir` may still miss the dir marker, for example
+/// `a/` while only `a/b/c` has been written. We will fallback to list in this case
+/// so that `stat` on a dir path behaves the same across services.
+///
 /// ## Read Completion
 ///
 /// OpenDAL requires all reader implements [`oio::Read`] and
@@ -87,6 +100,14 @@ use crate::*;
 /// If [`Capability`] `read_can_next` is true, we will use existing reader
 /// directly. Otherwise, we will use transform this reader as a stream.
 ///
+/// ## Concat Completion
+///
+/// Not all services support concat natively. If `emulate` is set by users, we
+/// will stream all sources into the target path in order. This requires
+/// transferring all data through the client, so it's opt-in. The emulation is
+/// only available for services that support `write_can_multi`, and the old
+/// content of the target path is kept in memory if it's one of the sources.
+///
 /// ## List Completion
 ///
 /// There are two styles of list, but not all services support both of
@@ -98,6 +119,11 @@ use crate::*;
 /// - If support `list_with_recursive`, return directly.
 /// - if not, wrap with [`FlatLister`].
 ///
+/// The same goes for glob filtering:
+///
+/// - If support `list_with_glob`, return directly.
+/// - if not, wrap with [`GlobLister`].
+///
 pub struct CompleteLayer;
 
 impl<A: Access> Layer<A> for CompleteLayer {
@@ -125,6 +151,16 @@ impl<A: Access> Debug for CompleteAccessor<A> {
 
 impl<A: Access> CompleteAccessor<A> {
     async fn complete_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        if args.recursive() {
+            for parent in parent_dirs(path) {
+                self.create_single_dir(parent, OpCreateDir::new()).await?;
+            }
+        }
+
+        self.create_single_dir(path, args).await
+    }
+
+    async fn create_single_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         let capability = self.info.full_capability();
         if capability.create_dir {
             return self.inner().create_dir(path, args).await;
@@ -140,6 +176,16 @@ impl<A: Access> CompleteAccessor<A> {
     }
 
     fn complete_blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        if args.recursive() {
+            for parent in parent_dirs(path) {
+                self.blocking_create_single_dir(parent, OpCreateDir::new())?;
+            }
+        }
+
+        self.blocking_create_single_dir(path, args)
+    }
+
+    fn blocking_create_single_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         let capability = self.info.full_capability();
         if capability.create_dir && capability.blocking {
             return self.inner().blocking_create_dir(path, args);
@@ -154,6 +200,75 @@ impl<A: Access> CompleteAccessor<A> {
         self.inner.blocking_create_dir(path, args)
     }
 
+    async fn complete_concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let capability = self.info.full_capability();
+        if capability.concat || !args.emulate() || !(capability.read && capability.write) {
+            return self.inner.concat(path, args).await;
+        }
+        // Services that can't accept multiple writes would need all sources in memory.
+        if !capability.write_can_multi {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "emulated concat requires write_can_multi",
+            ));
+        }
+
+        // Path could be one of sources, load it before being overwritten.
+        let mut existing = Vec::new();
+        if args.sources().iter().any(|v| v == path) {
+            let (_, mut r) = self.inner.read(path, OpRead::new()).await?;
+            loop {
+                let buf = oio::Read::read(&mut r).await?;
+                if buf.is_empty() {
+                    break;
+                }
+                existing.push(buf);
+            }
+        }
+
+        let (_, mut w) = self.inner.write(path, OpWrite::default()).await?;
+        let res = self
+            .emulate_concat(path, args.sources(), &existing, &mut w)
+            .await;
+        if res.is_err() {
+            // The write error is more important than the abort one.
+            let _ = oio::Write::abort(&mut w).await;
+        }
+        res.map(|_| RpConcat::default())
+    }
+
+    /// Stream every source into `w` in order.
+    ///
+    /// Only `existing` (the old content of `path`) is kept in memory, other
+    /// sources are written chunk by chunk as they are read.
+    async fn emulate_concat(
+        &self,
+        path: &str,
+        sources: &[String],
+        existing: &[Buffer],
+        w: &mut A::Writer,
+    ) -> Result<()> {
+        for source in sources {
+            if source == path {
+                for buf in existing {
+                    oio::Write::write(w, buf.clone()).await?;
+                }
+                continue;
+            }
+
+            let (_, mut r) = self.inner.read(source, OpRead::new()).await?;
+            loop {
+                let buf = oio::Read::read(&mut r).await?;
+                if buf.is_empty() {
+                    break;
+                }
+                oio::Write::write(w, buf).await?;
+            }
+        }
+
+        oio::Write::close(w).await
+    }
+
     async fn complete_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let capability = self.info.full_capability();
 
@@ -163,7 +278,14 @@ impl<A: Access> CompleteAccessor<A> {
 
         // Forward to inner if create_dir is supported.
         if path.ends_with('/') && capability.create_dir {
-            let meta = self.inner.stat(path, args).await?.into_metadata();
+            let meta = match self.inner.stat(path, args).await {
+                Ok(rp) => rp.into_metadata(),
+                // The dir marker could be missing while the dir has children.
+                Err(err) if err.kind() == ErrorKind::NotFound && capability.list_with_recursive => {
+                    return self.stat_dir_via_list(path).await;
+                }
+                Err(err) => return Err(err),
+            };
 
             if meta.is_file() {
                 return Err(Error::new(
@@ -177,25 +299,29 @@ impl<A: Access> CompleteAccessor<A> {
 
         // Otherwise, we can simulate stat dir via `list`.
         if path.ends_with('/') && capability.list_with_recursive {
-            let (_, mut l) = self
-                .inner
-                .list(path, OpList::default().with_recursive(true).with_limit(1))
-                .await?;
-
-            return if oio::List::next(&mut l).await?.is_some() {
-                Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
-            } else {
-                Err(Error::new(
-                    ErrorKind::NotFound,
-                    "the directory is not found",
-                ))
-            };
+            return self.stat_dir_via_list(path).await;
         }
 
         // Forward to underlying storage directly since we don't know how to handle stat dir.
         self.inner.stat(path, args).await
     }
 
+    async fn stat_dir_via_list(&self, path: &str) -> Result<RpStat> {
+        let (_, mut l) = self
+            .inner
+            .list(path, OpList::default().with_recursive(true).with_limit(1))
+            .await?;
+
+        if oio::List::next(&mut l).await?.is_some() {
+            Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
+        } else {
+            Err(Error::new(
+                ErrorKind::NotFound,
+                "the directory is not found",
+            ))
+        }
+    }
+
     fn complete_blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let capability = self.info.full_capability();
 
@@ -205,7 +331,14 @@ impl<A: Access> CompleteAccessor<A> {
 
         // Forward to inner if create dir is supported.
         if path.ends_with('/') && capability.create_dir {
-            let meta = self.inner.blocking_stat(path, args)?.into_metadata();
+            let meta = match self.inner.blocking_stat(path, args) {
+                Ok(rp) => rp.into_metadata(),
+                // The dir marker could be missing while the dir has children.
+                Err(err) if err.kind() == ErrorKind::NotFound && capability.list_with_recursive => {
+                    return self.blocking_stat_dir_via_list(path);
+                }
+                Err(err) => return Err(err),
+            };
 
             if meta.is_file() {
                 return Err(Error::new(
@@ -219,24 +352,39 @@ impl<A: Access> CompleteAccessor<A> {
 
         // Otherwise, we can simulate stat a dir path via `list`.
         if path.ends_with('/') && capability.list_with_recursive {
-            let (_, mut l) = self
-                .inner
-                .blocking_list(path, OpList::default().with_recursive(true).with_limit(1))?;
-
-            return if oio::BlockingList::next(&mut l)?.is_some() {
-                Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
-            } else {
-                Err(Error::new(
-                    ErrorKind::NotFound,
-                    "the directory is not found",
-                ))
-            };
+            return self.blocking_stat_dir_via_list(path);
         }
 
         // Forward to underlying storage directly since we don't know how to handle stat dir.
         self.inner.blocking_stat(path, args)
     }
 
+    fn blocking_stat_dir_via_list(&self, path: &str) -> Result<RpStat> {
+        let (_, mut l) = self
+            .inner
+            .blocking_list(path, OpList::default().with_recursive(true).with_limit(1))?;
+
+        if oio::BlockingList::next(&mut l)?.is_some() {
+            Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
+        } else {
+            Err(Error::new(
+                ErrorKind::NotFound,
+                "the directory is not found",
+            ))
+        }
+    }
+
+    /// Returns the glob pattern that needs to be applied on the client side.
+    fn client_side_glob(&self, args: &OpList) -> Option<String> {
+        let cap = self.info.full_capability();
+        // FlatLister doesn't forward args to the service, so we can only push down
+        // glob if recursive list is handled by the service too.
+        if cap.list_with_glob && (cap.list_with_recursive || !args.recursive()) {
+            return None;
+        }
+        args.glob().map(|v| v.to_string())
+    }
+
     async fn complete_list(
         &self,
         path: &str,
@@ -328,14 +476,31 @@ impl<A: Access> CompleteAccessor<A> {
     }
 }
 
+/// Returns all parent dirs of given path from top to bottom, root is excluded.
+///
+/// For example, `a/b/c/` returns `["a/", "a/b/"]`.
+fn parent_dirs(path: &str) -> Vec<&str> {
+    let mut parents = vec![];
+    let mut parent = get_parent(path);
+    while parent != "/" {
+        parents.push(parent);
+        parent = get_parent(parent);
+    }
+    parents.reverse();
+    parents
+}
+
 impl<A: Access> LayeredAccess for CompleteAccessor<A> {
     type Inner = A;
     type Reader = CompleteReader<A::Reader>;
     type BlockingReader = CompleteReader<A::BlockingReader>;
     type Writer = CompleteWriter<A::Writer>;
     type BlockingWriter = CompleteWriter<A::BlockingWriter>;
-    type Lister = CompleteLister<A, A::Lister>;
-    type BlockingLister = CompleteLister<A, A::BlockingLister>;
+    type Lister = TwoWays<CompleteLister<A, A::Lister>, GlobLister<CompleteLister<A, A::Lister>>>;
+    type BlockingLister = TwoWays<
+        CompleteLister<A, A::BlockingLister>,
+        GlobLister<CompleteLister<A, A::BlockingLister>>,
+    >;
     type Deleter = A::Deleter;
     type BlockingDeleter = A::BlockingDeleter;
 
@@ -350,6 +515,9 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
         if cap.list && cap.write_can_empty {
             cap.create_dir = true;
         }
+        if cap.list {
+            cap.list_with_glob = true;
+        }
         meta.into()
     }
 
@@ -380,13 +548,22 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
-        self.complete_list(path, args).await
+        let glob = self.client_side_glob(&args);
+        let (rp, p) = self.complete_list(path, args).await?;
+        match glob {
+            Some(glob) => Ok((rp, TwoWays::Two(GlobLister::new(p, &glob)))),
+            None => Ok((rp, TwoWays::One(p))),
+        }
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         self.inner.presign(path, args).await
     }
 
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        self.complete_concat(path, args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.complete_blocking_create_dir(path, args)
     }
@@ -413,7 +590,12 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
     }
 
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
-        self.complete_blocking_list(path, args)
+        let glob = self.client_side_glob(&args);
+        let (rp, p) = self.complete_blocking_list(path, args)?;
+        match glob {
+            Some(glob) => Ok((rp, TwoWays::Two(GlobLister::new(p, &glob)))),
+            None => Ok((rp, TwoWays::One(p))),
+        }
     }
 }
 
@@ -486,11 +668,15 @@ impl<R: oio::BlockingRead> oio::BlockingRead for CompleteReader<R> {
 
 pub struct CompleteWriter<W> {
     inner: Option<W>,
+    closed: bool,
 }
 
 impl<W> CompleteWriter<W> {
     pub fn new(inner: W) -> CompleteWriter<W> {
-        CompleteWriter { inner: Some(inner) }
+        CompleteWriter {
+            inner: Some(inner),
+            closed: false,
+        }
     }
 }
 
@@ -518,12 +704,18 @@ where
     }
 
     async fn close(&mut self) -> Result<()> {
-        let w = self.inner.as_mut().ok_or_else(|| {
-            Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
-        })?;
+        // Close is idempotent: closing a closed writer is a no-op.
+        if self.closed {
+            return Ok(());
+        }
+        let w = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| Error::new(ErrorKind::Unexpected, "writer has been aborted"))?;
 
         w.close().await?;
         self.inner = None;
+        self.closed = true;
 
         Ok(())
     }
@@ -553,12 +745,18 @@ where
     }
 
     fn close(&mut self) -> Result<()> {
-        let w = self.inner.as_mut().ok_or_else(|| {
-            Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
-        })?;
+        // Close is idempotent: closing a closed writer is a no-op.
+        if self.closed {
+            return Ok(());
+        }
+        let w = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| Error::new(ErrorKind::Unexpected, "writer has been aborted"))?;
 
         w.close()?;
         self.inner = None;
+        self.closed = true;
         Ok(())
     }
 }
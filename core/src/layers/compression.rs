@@ -0,0 +1,607 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io;
+use std::io::Write;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use flate2::write::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::raw::*;
+use crate::*;
+
+/// The algorithm used by [`CompressionLayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CompressionAlgorithm {
+    /// [Gzip](https://datatracker.ietf.org/doc/html/rfc1952), with extension `.gz`.
+    Gzip,
+    /// [Zstandard](https://datatracker.ietf.org/doc/html/rfc8878), with extension `.zst`.
+    Zstd,
+}
+
+impl CompressionAlgorithm {
+    const ALL: [CompressionAlgorithm; 2] = [CompressionAlgorithm::Gzip, CompressionAlgorithm::Zstd];
+
+    /// The default extension of files compressed by this algorithm.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CompressionAlgorithm::Gzip => ".gz",
+            CompressionAlgorithm::Zstd => ".zst",
+        }
+    }
+
+    /// Detect the algorithm from the extension of given path.
+    pub fn from_path(path: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|algo| path.ends_with(algo.extension()))
+    }
+
+    fn encoder(&self, level: Option<u32>) -> io::Result<Encoder> {
+        Ok(match self {
+            CompressionAlgorithm::Gzip => Encoder::Gzip(GzEncoder::new(
+                Vec::new(),
+                level.map(Compression::new).unwrap_or_default(),
+            )),
+            CompressionAlgorithm::Zstd => Encoder::Zstd(zstd::stream::write::Encoder::new(
+                Vec::new(),
+                level
+                    .map(|v| v as i32)
+                    .unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL),
+            )?),
+        })
+    }
+
+    fn decoder(&self) -> io::Result<Decoder> {
+        Ok(match self {
+            CompressionAlgorithm::Gzip => Decoder::Gzip(GzDecoder::new(Vec::new())),
+            CompressionAlgorithm::Zstd => {
+                Decoder::Zstd(zstd::stream::write::Decoder::new(Vec::new())?)
+            }
+        })
+    }
+}
+
+/// Compress files on write and decompress them on read transparently.
+///
+/// # Notes
+///
+/// Files are matched by their extension:
+///
+/// - While writing, files ending with the extension of the configured
+///   algorithm will be compressed. Use [`CompressionLayer::with_extension`]
+///   to compress files with another extension.
+/// - While reading, the algorithm is detected from the extension, files
+///   ending with the configured extension, `.gz` or `.zst` will be
+///   decompressed.
+///
+/// Other files are passed through untouched.
+///
+/// Compressed files can't be read by range natively, so range reads on them
+/// will decompress from the start and drop the bytes before range. `stat`
+/// decompresses the whole file to return the size of decompressed data,
+/// while `list` returns the size of compressed data.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::CompressionAlgorithm;
+/// # use opendal::layers::CompressionLayer;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # async fn test() -> Result<()> {
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(CompressionLayer::new(CompressionAlgorithm::Zstd))
+///     .finish();
+///
+/// // Stored with zstd compressed.
+/// op.write("data.json.zst", r#"{"hello": "world"}"#).await?;
+/// // Returns the decompressed content.
+/// let _ = op.read("data.json.zst").await?;
+/// Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompressionLayer {
+    algorithm: CompressionAlgorithm,
+    extension: String,
+    level: Option<u32>,
+}
+
+impl CompressionLayer {
+    /// Create a new compression layer with given algorithm.
+    pub fn new(algorithm: CompressionAlgorithm) -> Self {
+        Self {
+            algorithm,
+            extension: algorithm.extension().to_string(),
+            level: None,
+        }
+    }
+
+    /// Set the extension of files to compress, default to the extension of
+    /// the algorithm like `.gz`.
+    pub fn with_extension(mut self, extension: &str) -> Self {
+        self.extension = extension.to_string();
+        self
+    }
+
+    /// Set the compression level, default to the default level of the
+    /// algorithm.
+    pub fn with_level(mut self, level: u32) -> Self {
+        self.level = Some(level);
+        self
+    }
+}
+
+impl<A: Access> Layer<A> for CompressionLayer {
+    type LayeredAccess = CompressionAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        CompressionAccessor {
+            inner,
+            config: Arc::new(self.clone()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CompressionAccessor<A: Access> {
+    inner: A,
+    config: Arc<CompressionLayer>,
+}
+
+impl<A: Access> CompressionAccessor<A> {
+    /// Detect the algorithm to decompress given path.
+    fn detect(&self, path: &str) -> Option<CompressionAlgorithm> {
+        if path.ends_with(&self.config.extension) {
+            return Some(self.config.algorithm);
+        }
+        CompressionAlgorithm::from_path(path)
+    }
+
+    fn prepare_read(&self, path: &str, args: OpRead) -> Result<(OpRead, Option<Decompress>)> {
+        let Some(algo) = self.detect(path) else {
+            return Ok((args, None));
+        };
+
+        let range = args.range();
+        let decoder = algo.decoder().map_err(new_compression_error)?;
+        let decompress = Decompress {
+            decoder: Some(decoder),
+            skip: range.offset(),
+            remaining: range.size(),
+        };
+        Ok((args.with_range(BytesRange::default()), Some(decompress)))
+    }
+
+    /// Build the read args to decompress the whole file for stat.
+    fn prepare_stat(&self, path: &str, args: &OpStat) -> Result<(OpRead, Option<Decompress>)> {
+        let mut op = OpRead::new();
+        if let Some(v) = args.version() {
+            op = op.with_version(v);
+        }
+        self.prepare_read(path, op)
+    }
+
+    fn prepare_write(&self, path: &str, args: &OpWrite) -> Result<Option<Encoder>> {
+        if !path.ends_with(&self.config.extension) {
+            return Ok(None);
+        }
+        if args.append() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "append to compressed file is not supported",
+            ));
+        }
+
+        self.config
+            .algorithm
+            .encoder(self.config.level)
+            .map(Some)
+            .map_err(new_compression_error)
+    }
+}
+
+impl<A: Access> LayeredAccess for CompressionAccessor<A> {
+    type Inner = A;
+    type Reader = CompressionWrapper<A::Reader, Decompress>;
+    type BlockingReader = CompressionWrapper<A::BlockingReader, Decompress>;
+    type Writer = CompressionWrapper<A::Writer, Encoder>;
+    type BlockingWriter = CompressionWrapper<A::BlockingWriter, Encoder>;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+    type Deleter = A::Deleter;
+    type BlockingDeleter = A::BlockingDeleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn info(&self) -> Arc<AccessorInfo> {
+        let mut meta = (*self.inner.info()).clone();
+
+        let cap = meta.full_capability_mut();
+        // Compressed files can't be appended or uploaded by presigned requests.
+        cap.write_can_append = false;
+        cap.presign_write = false;
+        cap.presign_write_multi = false;
+
+        meta.into()
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let (args, decompress) = self.prepare_read(path, args)?;
+        let (rp, r) = self.inner.read(path, args).await?;
+
+        let rp = match decompress {
+            Some(_) => rp.with_size(None).with_range(None),
+            None => rp,
+        };
+        Ok((rp, CompressionWrapper::new(r, decompress)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let encoder = self.prepare_write(path, &args)?;
+        let (rp, w) = self.inner.write(path, args).await?;
+        Ok((rp, CompressionWrapper::new(w, encoder)))
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let (op, decompress) = self.prepare_stat(path, &args)?;
+        let meta = self.inner.stat(path, args).await?.into_metadata();
+        if decompress.is_none() || !meta.is_file() {
+            return Ok(RpStat::new(meta));
+        }
+
+        // The decompressed size is unknown until all data has been decompressed.
+        let (_, r) = self.inner.read(path, op).await?;
+        let mut r = CompressionWrapper::new(r, decompress);
+        let mut size = 0;
+        loop {
+            let bs = oio::Read::read(&mut r).await?;
+            if bs.is_empty() {
+                break;
+            }
+            size += bs.len() as u64;
+        }
+        Ok(RpStat::new(meta.with_content_length(size)))
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let (args, decompress) = self.prepare_read(path, args)?;
+        let (rp, r) = self.inner.blocking_read(path, args)?;
+
+        let rp = match decompress {
+            Some(_) => rp.with_size(None).with_range(None),
+            None => rp,
+        };
+        Ok((rp, CompressionWrapper::new(r, decompress)))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let encoder = self.prepare_write(path, &args)?;
+        let (rp, w) = self.inner.blocking_write(path, args)?;
+        Ok((rp, CompressionWrapper::new(w, encoder)))
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let (op, decompress) = self.prepare_stat(path, &args)?;
+        let meta = self.inner.blocking_stat(path, args)?.into_metadata();
+        if decompress.is_none() || !meta.is_file() {
+            return Ok(RpStat::new(meta));
+        }
+
+        let (_, r) = self.inner.blocking_read(path, op)?;
+        let mut r = CompressionWrapper::new(r, decompress);
+        let mut size = 0;
+        loop {
+            let bs = oio::BlockingRead::read(&mut r)?;
+            if bs.is_empty() {
+                break;
+            }
+            size += bs.len() as u64;
+        }
+        Ok(RpStat::new(meta.with_content_length(size)))
+    }
+
+    fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
+        self.inner.blocking_delete()
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+fn new_compression_error(err: io::Error) -> Error {
+    Error::new(ErrorKind::Unexpected, "compress or decompress failed").set_source(err)
+}
+
+pub enum Encoder {
+    Gzip(GzEncoder<Vec<u8>>),
+    Zstd(zstd::stream::write::Encoder<'static, Vec<u8>>),
+    /// The compression has been finished with the remaining output.
+    Finished(Bytes),
+}
+
+impl Encoder {
+    /// Compress given data and take the compressed output.
+    fn compress(&mut self, bs: Buffer) -> io::Result<Bytes> {
+        let output = match self {
+            Encoder::Gzip(e) => {
+                bs.into_iter().try_for_each(|b| e.write_all(&b))?;
+                e.get_mut()
+            }
+            Encoder::Zstd(e) => {
+                bs.into_iter().try_for_each(|b| e.write_all(&b))?;
+                e.get_mut()
+            }
+            Encoder::Finished(_) => return Err(io::Error::other("compression has been finished")),
+        };
+        Ok(Bytes::from(std::mem::take(output)))
+    }
+
+    /// Finish the compression and return the remaining output.
+    ///
+    /// The output is kept so that it could be returned again if writing it failed.
+    fn finish(&mut self) -> io::Result<Bytes> {
+        let output = match self {
+            Encoder::Gzip(e) => {
+                e.try_finish()?;
+                e.get_mut()
+            }
+            Encoder::Zstd(e) => {
+                e.do_finish()?;
+                e.get_mut()
+            }
+            Encoder::Finished(bs) => return Ok(bs.clone()),
+        };
+        let output = Bytes::from(std::mem::take(output));
+        *self = Encoder::Finished(output.clone());
+        Ok(output)
+    }
+}
+
+enum Decoder {
+    Gzip(GzDecoder<Vec<u8>>),
+    Zstd(zstd::stream::write::Decoder<'static, Vec<u8>>),
+}
+
+impl Decoder {
+    /// Decompress given data and take the decompressed output.
+    fn decompress(&mut self, bs: Buffer) -> io::Result<Bytes> {
+        let output = match self {
+            Decoder::Gzip(d) => {
+                bs.into_iter().try_for_each(|b| d.write_all(&b))?;
+                d.get_mut()
+            }
+            Decoder::Zstd(d) => {
+                bs.into_iter().try_for_each(|b| d.write_all(&b))?;
+                d.flush()?;
+                d.get_mut()
+            }
+        };
+        Ok(Bytes::from(std::mem::take(output)))
+    }
+
+    /// Finish the decompression and take the remaining output.
+    fn finish(self) -> io::Result<Bytes> {
+        let output = match self {
+            Decoder::Gzip(d) => d.finish()?,
+            Decoder::Zstd(mut d) => {
+                d.flush()?;
+                d.into_inner()
+            }
+        };
+        Ok(Bytes::from(output))
+    }
+}
+
+/// Decompress holds the state of a decompressing read.
+pub struct Decompress {
+    /// `None` if all data has been decompressed.
+    decoder: Option<Decoder>,
+    /// The bytes to skip before the range.
+    skip: u64,
+    /// The bytes that still need to be returned, `None` means read to the end.
+    remaining: Option<u64>,
+}
+
+impl Decompress {
+    /// Feed the data read from underlying storage, an empty buffer means EOF.
+    ///
+    /// Returns the bytes that within the range.
+    fn feed(&mut self, bs: Buffer) -> io::Result<Bytes> {
+        let mut output = match self.decoder.as_mut() {
+            None => return Ok(Bytes::new()),
+            Some(d) if !bs.is_empty() => d.decompress(bs)?,
+            Some(_) => self.decoder.take().expect("decoder must exist").finish()?,
+        };
+
+        let skip = self.skip.min(output.len() as u64);
+        output = output.slice(skip as usize..);
+        self.skip -= skip;
+
+        if let Some(remaining) = self.remaining.as_mut() {
+            output.truncate(output.len().min(*remaining as usize));
+            *remaining -= output.len() as u64;
+        }
+        Ok(output)
+    }
+
+    fn is_done(&self) -> bool {
+        self.decoder.is_none() || self.remaining == Some(0)
+    }
+}
+
+/// CompressionWrapper wraps readers and writers, `state` is `None` if the
+/// file doesn't need to be compressed or decompressed.
+pub struct CompressionWrapper<R, S> {
+    inner: R,
+    state: Option<S>,
+}
+
+impl<R, S> CompressionWrapper<R, S> {
+    fn new(inner: R, state: Option<S>) -> Self {
+        Self { inner, state }
+    }
+}
+
+impl<R: oio::Read> oio::Read for CompressionWrapper<R, Decompress> {
+    async fn read(&mut self) -> Result<Buffer> {
+        let Some(state) = self.state.as_mut() else {
+            return self.inner.read().await;
+        };
+
+        while !state.is_done() {
+            let bs = self.inner.read().await?;
+            let output = state.feed(bs).map_err(new_compression_error)?;
+            if !output.is_empty() {
+                return Ok(Buffer::from(output));
+            }
+        }
+        Ok(Buffer::new())
+    }
+}
+
+impl<R: oio::BlockingRead> oio::BlockingRead for CompressionWrapper<R, Decompress> {
+    fn read(&mut self) -> Result<Buffer> {
+        let Some(state) = self.state.as_mut() else {
+            return self.inner.read();
+        };
+
+        while !state.is_done() {
+            let bs = self.inner.read()?;
+            let output = state.feed(bs).map_err(new_compression_error)?;
+            if !output.is_empty() {
+                return Ok(Buffer::from(output));
+            }
+        }
+        Ok(Buffer::new())
+    }
+}
+
+impl<W: oio::Write> oio::Write for CompressionWrapper<W, Encoder> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let Some(encoder) = self.state.as_mut() else {
+            return self.inner.write(bs).await;
+        };
+
+        let output = encoder.compress(bs).map_err(new_compression_error)?;
+        if !output.is_empty() {
+            self.inner.write(Buffer::from(output)).await?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        if let Some(encoder) = self.state.as_mut() {
+            let output = encoder.finish().map_err(new_compression_error)?;
+            self.inner.write(Buffer::from(output)).await?;
+            // Only drop the state after output has been written, so that close could be retried.
+            self.state = None;
+        }
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.state = None;
+        self.inner.abort().await
+    }
+}
+
+impl<W: oio::BlockingWrite> oio::BlockingWrite for CompressionWrapper<W, Encoder> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        let Some(encoder) = self.state.as_mut() else {
+            return self.inner.write(bs);
+        };
+
+        let output = encoder.compress(bs).map_err(new_compression_error)?;
+        if !output.is_empty() {
+            self.inner.write(Buffer::from(output))?;
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        if let Some(encoder) = self.state.as_mut() {
+            let output = encoder.finish().map_err(new_compression_error)?;
+            self.inner.write(Buffer::from(output))?;
+            // Only drop the state after output has been written, so that close could be retried.
+            self.state = None;
+        }
+        self.inner.close()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_compression_layer() {
+        let content = "hello, world! ".repeat(1024);
+
+        for algo in CompressionAlgorithm::ALL {
+            let raw = Operator::new(Memory::default()).unwrap().finish();
+            let op = raw.clone().layer(CompressionLayer::new(algo));
+            let path = format!("test{}", algo.extension());
+
+            op.write(&path, content.clone()).await.unwrap();
+            let compressed = raw.stat(&path).await.unwrap().content_length();
+            assert!(compressed < content.len() as u64);
+            // Stat returns the size of decompressed data.
+            assert_eq!(
+                op.stat(&path).await.unwrap().content_length(),
+                content.len() as u64
+            );
+
+            assert_eq!(op.read(&path).await.unwrap().to_vec(), content.as_bytes());
+            assert_eq!(
+                op.read_with(&path).range(7..12).await.unwrap().to_vec(),
+                b"world"
+            );
+            // Reading to the end relies on the size from stat.
+            assert_eq!(
+                op.read_with(&path).chunk(1024).await.unwrap().to_vec(),
+                content.as_bytes()
+            );
+
+            // Files without extension are passed through.
+            op.write("plain", content.clone()).await.unwrap();
+            assert_eq!(
+                raw.read("plain").await.unwrap().to_vec(),
+                content.as_bytes()
+            );
+        }
+    }
+}
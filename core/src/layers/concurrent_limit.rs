@@ -290,6 +290,10 @@ impl<R: oio::List> oio::List for ConcurrentLimitWrapper<R> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         self.inner.next().await
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<R: oio::BlockingList> oio::BlockingList for ConcurrentLimitWrapper<R> {
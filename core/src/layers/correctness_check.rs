@@ -130,6 +130,15 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "if_unmodified_since",
             ));
         }
+        if !capability.read_with_server_side_encryption_customer_key
+            && args.server_side_encryption_customer_key().is_some()
+        {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::Read,
+                "server_side_encryption_customer_key",
+            ));
+        }
 
         self.inner.read(path, args).await
     }
@@ -150,6 +159,27 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "if_not_exists",
             ));
         }
+        if args.expire_after().is_some() && !capability.write_with_expire_after {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::Write,
+                "expire_after",
+            ));
+        }
+        if args.permissions().is_some() && !capability.write_with_permissions {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::Write,
+                "permissions",
+            ));
+        }
+        if args.progress().is_some() && !capability.write_with_resume {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::Write,
+                "resume",
+            ));
+        }
         if let Some(if_none_match) = args.if_none_match() {
             if !capability.write_with_if_none_match {
                 let mut err =
@@ -161,6 +191,25 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 return Err(err);
             }
         }
+        if !capability.write_with_server_side_encryption
+            && (args.server_side_encryption().is_some()
+                || args.server_side_encryption_aws_kms_key_id().is_some())
+        {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::Write,
+                "server_side_encryption",
+            ));
+        }
+        if !capability.write_with_server_side_encryption_customer_key
+            && args.server_side_encryption_customer_key().is_some()
+        {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::Write,
+                "server_side_encryption_customer_key",
+            ));
+        }
 
         self.inner.write(path, args).await
     }
@@ -202,6 +251,15 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "if_unmodified_since",
             ));
         }
+        if !capability.stat_with_server_side_encryption_customer_key
+            && args.server_side_encryption_customer_key().is_some()
+        {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::Stat,
+                "server_side_encryption_customer_key",
+            ));
+        }
 
         self.inner.stat(path, args).await
     }
@@ -214,6 +272,26 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let capability = self.info.full_capability();
+        if !capability.list_with_delimiter && args.has_custom_delimiter() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::List,
+                "delimiter",
+            ));
+        }
+        // Services without start token would silently list from the beginning.
+        if !capability.list_with_start_token && args.start_token().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::List,
+                "start_token",
+            ));
+        }
+        if let Some(glob) = args.glob() {
+            validate_glob(glob).map_err(|err| err.with_operation(Operation::List))?;
+        }
+
         self.inner.list(path, args).await
     }
 
@@ -226,6 +304,43 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "version",
             ));
         }
+        if !capability.read_with_if_match && args.if_match().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingRead,
+                "if_match",
+            ));
+        }
+        if !capability.read_with_if_none_match && args.if_none_match().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingRead,
+                "if_none_match",
+            ));
+        }
+        if !capability.read_with_if_modified_since && args.if_modified_since().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingRead,
+                "if_modified_since",
+            ));
+        }
+        if !capability.read_with_if_unmodified_since && args.if_unmodified_since().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingRead,
+                "if_unmodified_since",
+            ));
+        }
+        if !capability.read_with_server_side_encryption_customer_key
+            && args.server_side_encryption_customer_key().is_some()
+        {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingRead,
+                "server_side_encryption_customer_key",
+            ));
+        }
 
         self.inner.blocking_read(path, args)
     }
@@ -246,6 +361,27 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "if_not_exists",
             ));
         }
+        if args.expire_after().is_some() && !capability.write_with_expire_after {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::BlockingWrite,
+                "expire_after",
+            ));
+        }
+        if args.permissions().is_some() && !capability.write_with_permissions {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::BlockingWrite,
+                "permissions",
+            ));
+        }
+        if args.progress().is_some() && !capability.write_with_resume {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::BlockingWrite,
+                "resume",
+            ));
+        }
         if args.if_none_match().is_some() && !capability.write_with_if_none_match {
             return Err(new_unsupported_error(
                 self.info.as_ref(),
@@ -253,6 +389,25 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "if_none_match",
             ));
         }
+        if !capability.write_with_server_side_encryption
+            && (args.server_side_encryption().is_some()
+                || args.server_side_encryption_aws_kms_key_id().is_some())
+        {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingWrite,
+                "server_side_encryption",
+            ));
+        }
+        if !capability.write_with_server_side_encryption_customer_key
+            && args.server_side_encryption_customer_key().is_some()
+        {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingWrite,
+                "server_side_encryption_customer_key",
+            ));
+        }
 
         self.inner.blocking_write(path, args)
     }
@@ -266,6 +421,43 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "version",
             ));
         }
+        if !capability.stat_with_if_match && args.if_match().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingStat,
+                "if_match",
+            ));
+        }
+        if !capability.stat_with_if_none_match && args.if_none_match().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingStat,
+                "if_none_match",
+            ));
+        }
+        if !capability.stat_with_if_modified_since && args.if_modified_since().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingStat,
+                "if_modified_since",
+            ));
+        }
+        if !capability.stat_with_if_unmodified_since && args.if_unmodified_since().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingStat,
+                "if_unmodified_since",
+            ));
+        }
+        if !capability.stat_with_server_side_encryption_customer_key
+            && args.server_side_encryption_customer_key().is_some()
+        {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingStat,
+                "server_side_encryption_customer_key",
+            ));
+        }
 
         self.inner.blocking_stat(path, args)
     }
@@ -278,6 +470,26 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
     }
 
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        let capability = self.info.full_capability();
+        if !capability.list_with_delimiter && args.has_custom_delimiter() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingList,
+                "delimiter",
+            ));
+        }
+        // Services without start token would silently list from the beginning.
+        if !capability.list_with_start_token && args.start_token().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::BlockingList,
+                "start_token",
+            ));
+        }
+        if let Some(glob) = args.glob() {
+            validate_glob(glob).map_err(|err| err.with_operation(Operation::BlockingList))?;
+        }
+
         self.inner.blocking_list(path, args)
     }
 }
@@ -329,6 +541,8 @@ impl<T: oio::BlockingDelete> oio::BlockingDelete for CheckWrapper<T> {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
     use crate::raw::oio;
     use crate::{Capability, EntryMode, Metadata, Operator};
@@ -497,6 +711,156 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_write_with_server_side_encryption() {
+        let op = new_test_operator(Capability {
+            write: true,
+            ..Default::default()
+        });
+        let res = op
+            .write_with("path", "".as_bytes())
+            .server_side_encryption("aws:kms")
+            .await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let res = op
+            .write_with("path", "".as_bytes())
+            .server_side_encryption_customer_key("key")
+            .await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            write: true,
+            write_with_server_side_encryption: true,
+            write_with_server_side_encryption_customer_key: true,
+            ..Default::default()
+        });
+        let res = op
+            .write_with("path", "".as_bytes())
+            .server_side_encryption("aws:kms")
+            .server_side_encryption_aws_kms_key_id("key-id")
+            .await;
+        assert!(res.is_ok());
+
+        let res = op
+            .write_with("path", "".as_bytes())
+            .server_side_encryption_customer_key("key")
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_write_with_expire_after() {
+        let op = new_test_operator(Capability {
+            write: true,
+            ..Default::default()
+        });
+        let res = op
+            .write_with("path", "".as_bytes())
+            .expire_after(Duration::from_secs(60))
+            .await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            write: true,
+            write_with_expire_after: true,
+            ..Default::default()
+        });
+        let res = op
+            .write_with("path", "".as_bytes())
+            .expire_after(Duration::from_secs(60))
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_write_with_permissions() {
+        let op = new_test_operator(Capability {
+            write: true,
+            ..Default::default()
+        });
+        let res = op
+            .write_with("path", "".as_bytes())
+            .permissions(0o600)
+            .await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            write: true,
+            write_with_permissions: true,
+            ..Default::default()
+        });
+        let res = op
+            .write_with("path", "".as_bytes())
+            .permissions(0o600)
+            .await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_list_with_delimiter() {
+        let op = new_test_operator(Capability {
+            list: true,
+            list_with_recursive: true,
+            ..Default::default()
+        });
+        let res = op.list_with("dir/").delimiter("-").await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        // `""` and `"/"` are always allowed since they are the same as `recursive`.
+        let res = op.list_with("dir/").delimiter("").await;
+        assert!(res.is_ok());
+        let res = op.list_with("dir/").delimiter("/").await;
+        assert!(res.is_ok());
+
+        let op = new_test_operator(Capability {
+            list: true,
+            list_with_recursive: true,
+            list_with_delimiter: true,
+            ..Default::default()
+        });
+        let res = op.list_with("dir/").delimiter("-").await;
+        assert!(res.is_ok())
+    }
+
+    #[tokio::test]
+    async fn test_list_with_glob() {
+        let op = new_test_operator(Capability {
+            list: true,
+            ..Default::default()
+        });
+        let res = op.list_with("dir/").glob("dir/*.txt").await;
+        assert!(res.is_ok());
+
+        let res = op.list_with("dir/").glob("dir/[ab].txt").await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+    }
+
+    #[tokio::test]
+    async fn test_list_with_start_token() {
+        let op = new_test_operator(Capability {
+            list: true,
+            ..Default::default()
+        });
+        let res = op.list_with("dir/").start_token("token").await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            list: true,
+            list_with_start_token: true,
+            ..Default::default()
+        });
+        let res = op.list_with("dir/").start_token("token").await;
+        assert!(res.is_ok())
+    }
+
     #[tokio::test]
     async fn test_delete() {
         let op = new_test_operator(Capability {
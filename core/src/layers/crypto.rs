@@ -0,0 +1,865 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::AeadCore;
+use aes_gcm::aead::KeyInit;
+use aes_gcm::aead::OsRng;
+use aes_gcm::Aes256Gcm;
+use aes_gcm::Nonce;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Bytes;
+use bytes::BytesMut;
+
+use crate::raw::*;
+use crate::*;
+
+/// The size of plaintext in every encrypted chunk.
+const CHUNK_SIZE: usize = 64 * 1024;
+/// The size of the authentication tag appended to every encrypted chunk.
+const TAG_SIZE: usize = 16;
+/// The size of every encrypted chunk except the last one.
+const ENCRYPTED_CHUNK_SIZE: usize = CHUNK_SIZE + TAG_SIZE;
+/// The size of the random nonce prefix, the remaining 5 bytes of the nonce
+/// are the chunk index and the last chunk flag.
+const NONCE_PREFIX_SIZE: usize = 7;
+/// The size of data keys, we always use AES-256.
+const KEY_SIZE: usize = 32;
+/// The max number of unwrapped data keys cached by the accessor.
+const KEY_CACHE_SIZE: usize = 64;
+
+const META_KEY_ID: &str = "opendal-crypto-key-id";
+const META_WRAPPED_KEY: &str = "opendal-crypto-key";
+const META_NONCE: &str = "opendal-crypto-nonce";
+
+/// KeyProvider protects the data keys used by [`CryptoLayer`].
+///
+/// Every object is encrypted by a random data key, the data key will be
+/// wrapped by the key provider and stored in the object's user metadata.
+/// Users can implement this trait to wrap data keys with a KMS.
+pub trait KeyProvider: Send + Sync + 'static {
+    /// Wrap the data key, returns the id of the key used to wrap and the
+    /// wrapped data key.
+    fn wrap_key(&self, key: &[u8]) -> impl Future<Output = Result<(String, Vec<u8>)>> + MaybeSend;
+
+    /// Unwrap the data key which is wrapped by the key of given id.
+    fn unwrap_key(
+        &self,
+        key_id: &str,
+        wrapped_key: &[u8],
+    ) -> impl Future<Output = Result<Vec<u8>>> + MaybeSend;
+}
+
+/// StaticKeyProvider wraps data keys with a static AES-256 key.
+pub struct StaticKeyProvider {
+    key_id: String,
+    cipher: Aes256Gcm,
+}
+
+impl StaticKeyProvider {
+    /// Create a new static key provider with given key id and 32 bytes key.
+    ///
+    /// The key id is stored along with objects, so that we can tell which
+    /// key is used while rotating keys.
+    pub fn new(key_id: &str, key: &[u8]) -> Result<Self> {
+        let cipher = Aes256Gcm::new_from_slice(key).map_err(|_| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                format!("crypto key must be {KEY_SIZE} bytes"),
+            )
+            .with_context("key_id", key_id)
+        })?;
+
+        Ok(Self {
+            key_id: key_id.to_string(),
+            cipher,
+        })
+    }
+}
+
+impl Debug for StaticKeyProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaticKeyProvider")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KeyProvider for StaticKeyProvider {
+    async fn wrap_key(&self, key: &[u8]) -> Result<(String, Vec<u8>)> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let wrapped = self
+            .cipher
+            .encrypt(&nonce, key)
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "wrap data key failed"))?;
+
+        let mut v = nonce.to_vec();
+        v.extend_from_slice(&wrapped);
+        Ok((self.key_id.clone(), v))
+    }
+
+    async fn unwrap_key(&self, key_id: &str, wrapped_key: &[u8]) -> Result<Vec<u8>> {
+        if key_id != self.key_id {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "data key is wrapped by another key",
+            )
+            .with_context("expected", &self.key_id)
+            .with_context("actual", key_id));
+        }
+        if wrapped_key.len() < 12 {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "wrapped data key is too short",
+            ));
+        }
+
+        let (nonce, wrapped) = wrapped_key.split_at(12);
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), wrapped)
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "unwrap data key failed"))
+    }
+}
+
+/// Encrypt object contents on write and decrypt them on read, with
+/// client-side AES-256-GCM envelope encryption.
+///
+/// # Notes
+///
+/// Every object is encrypted by a random data key, which is wrapped by the
+/// [`KeyProvider`] and stored in the user metadata of the object together
+/// with the nonce. So the underlying service must support
+/// `write_with_user_metadata`, otherwise `write` will return `Unsupported`.
+///
+/// Contents are encrypted in chunks of 64 KiB, each chunk has its own
+/// authentication tag, so that neither write nor read needs to buffer the
+/// whole file, and range reads only need to fetch the chunks they cover.
+///
+/// - Objects without crypto metadata are returned as is while reading.
+/// - `stat` returns the size of plaintext, but the size of entries returned
+///   by `list` is the size of stored ciphertext.
+/// - `append`, `presign` and blocking operations are not supported.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::CryptoLayer;
+/// # use opendal::layers::StaticKeyProvider;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let key = StaticKeyProvider::new("key-2024", &[0; 32])?;
+///
+/// let _ = Operator::new(services::S3::default())?
+///     .layer(CryptoLayer::new(key))
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+pub struct CryptoLayer<K: KeyProvider> {
+    provider: Arc<K>,
+}
+
+impl<K: KeyProvider> Clone for CryptoLayer<K> {
+    fn clone(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+        }
+    }
+}
+
+impl<K: KeyProvider> CryptoLayer<K> {
+    /// Create a new crypto layer with given key provider.
+    pub fn new(provider: K) -> Self {
+        Self {
+            provider: Arc::new(provider),
+        }
+    }
+}
+
+impl<A: Access, K: KeyProvider> Layer<A> for CryptoLayer<K> {
+    type LayeredAccess = CryptoAccessor<A, K>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        CryptoAccessor {
+            inner,
+            provider: self.provider.clone(),
+            keys: Mutex::default(),
+        }
+    }
+}
+
+pub struct CryptoAccessor<A: Access, K: KeyProvider> {
+    inner: A,
+    provider: Arc<K>,
+    /// Recently unwrapped data keys, so that reading the same object again
+    /// (like every range read of a reader) doesn't need to unwrap its key again.
+    keys: Mutex<VecDeque<UnwrappedKey>>,
+}
+
+struct UnwrappedKey {
+    key_id: String,
+    wrapped_key: Vec<u8>,
+    key: Vec<u8>,
+}
+
+impl<A: Access, K: KeyProvider> Debug for CryptoAccessor<A, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CryptoAccessor")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access, K: KeyProvider> CryptoAccessor<A, K> {
+    /// Build the chunk cipher from the crypto metadata of the object.
+    ///
+    /// Returns `None` if the object is not encrypted.
+    async fn load_cipher(&self, meta: &Metadata) -> Result<Option<ChunkCipher>> {
+        let Some(user_metadata) = meta.user_metadata() else {
+            return Ok(None);
+        };
+        let (Some(key_id), Some(wrapped_key), Some(nonce)) = (
+            user_metadata.get(META_KEY_ID),
+            user_metadata.get(META_WRAPPED_KEY),
+            user_metadata.get(META_NONCE),
+        ) else {
+            return Ok(None);
+        };
+
+        let wrapped_key = BASE64_STANDARD.decode(wrapped_key).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "decode wrapped data key failed").set_source(err)
+        })?;
+        let nonce = BASE64_STANDARD.decode(nonce).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "decode crypto nonce failed").set_source(err)
+        })?;
+        let key = self.unwrap_key(key_id, &wrapped_key).await?;
+
+        ChunkCipher::new(&key, &nonce).map(Some)
+    }
+
+    /// Unwrap the data key via the cache of recently unwrapped keys.
+    async fn unwrap_key(&self, key_id: &str, wrapped_key: &[u8]) -> Result<Vec<u8>> {
+        {
+            let keys = self.keys.lock().unwrap();
+            if let Some(v) = keys
+                .iter()
+                .find(|v| v.key_id == key_id && v.wrapped_key == wrapped_key)
+            {
+                return Ok(v.key.clone());
+            }
+        }
+
+        let key = self.provider.unwrap_key(key_id, wrapped_key).await?;
+
+        let mut keys = self.keys.lock().unwrap();
+        if keys.len() >= KEY_CACHE_SIZE {
+            keys.pop_front();
+        }
+        keys.push_back(UnwrappedKey {
+            key_id: key_id.to_string(),
+            wrapped_key: wrapped_key.to_vec(),
+            key: key.clone(),
+        });
+        Ok(key)
+    }
+}
+
+impl<A: Access, K: KeyProvider> LayeredAccess for CryptoAccessor<A, K> {
+    type Inner = A;
+    type Reader = TwoWays<A::Reader, CryptoReader<A::Reader>>;
+    type BlockingReader = A::BlockingReader;
+    type Writer = CryptoWriter<A::Writer>;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+    type Deleter = A::Deleter;
+    type BlockingDeleter = A::BlockingDeleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn info(&self) -> Arc<AccessorInfo> {
+        let mut meta = (*self.inner.info()).clone();
+
+        let cap = meta.full_capability_mut();
+        cap.write_can_append = false;
+        cap.presign = false;
+        cap.presign_read = false;
+        cap.presign_stat = false;
+        cap.presign_write = false;
+        cap.presign_write_multi = false;
+        cap.presign_post = false;
+        cap.concat = false;
+        cap.blocking = false;
+
+        meta.into()
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let mut stat_args = OpStat::new();
+        if let Some(v) = args.if_match() {
+            stat_args = stat_args.with_if_match(v);
+        }
+        if let Some(v) = args.if_none_match() {
+            stat_args = stat_args.with_if_none_match(v);
+        }
+        if let Some(v) = args.if_modified_since() {
+            stat_args = stat_args.with_if_modified_since(v);
+        }
+        if let Some(v) = args.if_unmodified_since() {
+            stat_args = stat_args.with_if_unmodified_since(v);
+        }
+        if let Some(v) = args.version() {
+            stat_args = stat_args.with_version(v);
+        }
+        if let Some(v) = args.server_side_encryption_customer_key() {
+            stat_args = stat_args.with_server_side_encryption_customer_key(v);
+        }
+        let meta = self.inner.stat(path, stat_args).await?.into_metadata();
+
+        let Some(cipher) = self.load_cipher(&meta).await? else {
+            let (rp, r) = self.inner.read(path, args).await?;
+            return Ok((rp, TwoWays::One(r)));
+        };
+
+        let layout = ChunkLayout::new(meta.content_length())?;
+        let range = args.range();
+        let start = range.offset().min(layout.plain_size);
+        let end = match range.size() {
+            Some(size) => start.saturating_add(size).min(layout.plain_size),
+            None => layout.plain_size,
+        };
+
+        let first_chunk = start / CHUNK_SIZE as u64;
+        let end_chunk = end.div_ceil(CHUNK_SIZE as u64).max(first_chunk);
+        let offset = (first_chunk * ENCRYPTED_CHUNK_SIZE as u64).min(layout.encrypted_size);
+        let size = (end_chunk * ENCRYPTED_CHUNK_SIZE as u64)
+            .min(layout.encrypted_size)
+            .saturating_sub(offset);
+
+        let mut args = args;
+        *args.range_mut() = BytesRange::new(offset, Some(size));
+        let (rp, r) = self.inner.read(path, args).await?;
+
+        let r = CryptoReader {
+            inner: r,
+            cipher,
+            layout,
+            next_chunk: first_chunk,
+            buf: BytesMut::new(),
+            skip: (start - first_chunk * CHUNK_SIZE as u64) as usize,
+            remaining: end - start,
+        };
+        Ok((rp.with_size(Some(end - start)), TwoWays::Two(r)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        if args.append() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "append is not supported by crypto layer",
+            ));
+        }
+        if !self.inner.info().full_capability().write_with_user_metadata {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "crypto layer requires service to support write with user metadata",
+            ));
+        }
+
+        let key = Aes256Gcm::generate_key(OsRng);
+        let mut nonce = [0; NONCE_PREFIX_SIZE];
+        OsRng.fill_bytes(&mut nonce);
+        let (key_id, wrapped_key) = self.provider.wrap_key(&key).await?;
+
+        let mut user_metadata = args.user_metadata().cloned().unwrap_or_default();
+        user_metadata.insert(META_KEY_ID.to_string(), key_id);
+        user_metadata.insert(
+            META_WRAPPED_KEY.to_string(),
+            BASE64_STANDARD.encode(wrapped_key),
+        );
+        user_metadata.insert(META_NONCE.to_string(), BASE64_STANDARD.encode(nonce));
+
+        let (rp, w) = self
+            .inner
+            .write(path, args.with_user_metadata(user_metadata))
+            .await?;
+        Ok((rp, CryptoWriter::new(w, ChunkCipher::new(&key, &nonce)?)))
+    }
+
+    async fn concat(&self, _: &str, _: OpConcat) -> Result<RpConcat> {
+        // Both native and emulated concat join the encrypted content of
+        // sources, which can't be decrypted with a single key.
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "concat is not supported by crypto layer",
+        ))
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let meta = self.inner.stat(path, args).await?.into_metadata();
+        if meta.is_dir() || self.load_cipher(&meta).await?.is_none() {
+            return Ok(RpStat::new(meta));
+        }
+
+        let layout = ChunkLayout::new(meta.content_length())?;
+        Ok(RpStat::new(meta.with_content_length(layout.plain_size)))
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    async fn presign(&self, _: &str, _: OpPresign) -> Result<RpPresign> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "presign is not supported by crypto layer",
+        ))
+    }
+
+    fn blocking_read(&self, _: &str, _: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "blocking read is not supported by crypto layer",
+        ))
+    }
+
+    fn blocking_write(&self, _: &str, _: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "blocking write is not supported by crypto layer",
+        ))
+    }
+
+    fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
+        self.inner.blocking_delete()
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// ChunkCipher encrypts and decrypts chunks of an object.
+///
+/// The nonce of every chunk is `prefix || chunk index (u32 BE) || last flag`,
+/// so that chunks can't be reordered or truncated without being detected.
+struct ChunkCipher {
+    cipher: Aes256Gcm,
+    prefix: [u8; NONCE_PREFIX_SIZE],
+}
+
+impl ChunkCipher {
+    fn new(key: &[u8], prefix: &[u8]) -> Result<Self> {
+        let cipher = Aes256Gcm::new_from_slice(key)
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "data key must be 32 bytes"))?;
+        let prefix = prefix
+            .try_into()
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "crypto nonce has invalid length"))?;
+
+        Ok(Self { cipher, prefix })
+    }
+
+    fn nonce(&self, index: u64, last: bool) -> Result<[u8; 12]> {
+        let index = u32::try_from(index).map_err(|_| {
+            Error::new(ErrorKind::Unexpected, "object is too large to be encrypted")
+        })?;
+
+        let mut nonce = [0; 12];
+        nonce[..NONCE_PREFIX_SIZE].copy_from_slice(&self.prefix);
+        nonce[NONCE_PREFIX_SIZE..11].copy_from_slice(&index.to_be_bytes());
+        nonce[11] = last as u8;
+        Ok(nonce)
+    }
+
+    fn encrypt(&self, index: u64, last: bool, plaintext: &[u8]) -> Result<Bytes> {
+        let nonce = self.nonce(index, last)?;
+        self.cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map(Bytes::from)
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "encrypt chunk failed"))
+    }
+
+    fn decrypt(&self, index: u64, last: bool, ciphertext: &[u8]) -> Result<Bytes> {
+        let nonce = self.nonce(index, last)?;
+        self.cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext)
+            .map(Bytes::from)
+            .map_err(|_| {
+                Error::new(ErrorKind::Unexpected, "decrypt chunk failed")
+                    .with_context("chunk", index)
+            })
+    }
+}
+
+/// ChunkLayout describes how an encrypted object is split into chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ChunkLayout {
+    encrypted_size: u64,
+    plain_size: u64,
+    chunks: u64,
+}
+
+impl ChunkLayout {
+    fn new(encrypted_size: u64) -> Result<Self> {
+        // There is always at least one chunk, even for empty objects.
+        let chunks = encrypted_size.div_ceil(ENCRYPTED_CHUNK_SIZE as u64).max(1);
+        let last = encrypted_size - (chunks - 1) * ENCRYPTED_CHUNK_SIZE as u64;
+        if last < TAG_SIZE as u64 {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "encrypted object has invalid size")
+                    .with_context("size", encrypted_size),
+            );
+        }
+
+        Ok(Self {
+            encrypted_size,
+            plain_size: encrypted_size - chunks * TAG_SIZE as u64,
+            chunks,
+        })
+    }
+
+    /// The size of given encrypted chunk.
+    fn chunk_size(&self, index: u64) -> usize {
+        if index + 1 == self.chunks {
+            (self.encrypted_size - index * ENCRYPTED_CHUNK_SIZE as u64) as usize
+        } else {
+            ENCRYPTED_CHUNK_SIZE
+        }
+    }
+}
+
+pub struct CryptoReader<R> {
+    inner: R,
+    cipher: ChunkCipher,
+    layout: ChunkLayout,
+
+    next_chunk: u64,
+    buf: BytesMut,
+    /// The bytes to skip in the first decrypted chunk.
+    skip: usize,
+    /// The bytes of plaintext that still need to be returned.
+    remaining: u64,
+}
+
+impl<R: oio::Read> oio::Read for CryptoReader<R> {
+    async fn read(&mut self) -> Result<Buffer> {
+        while self.remaining > 0 {
+            let size = self.layout.chunk_size(self.next_chunk);
+            if self.buf.len() < size {
+                let bs = self.inner.read().await?;
+                if bs.is_empty() {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "encrypted object is truncated",
+                    ));
+                }
+                bs.into_iter().for_each(|b| self.buf.extend_from_slice(&b));
+                continue;
+            }
+
+            let chunk = self.buf.split_to(size);
+            let last = self.next_chunk + 1 == self.layout.chunks;
+            let mut plaintext = self.cipher.decrypt(self.next_chunk, last, &chunk)?;
+            self.next_chunk += 1;
+
+            let skip = self.skip.min(plaintext.len());
+            plaintext = plaintext.slice(skip..);
+            self.skip -= skip;
+            plaintext.truncate(plaintext.len().min(self.remaining as usize));
+            self.remaining -= plaintext.len() as u64;
+
+            if !plaintext.is_empty() {
+                return Ok(Buffer::from(plaintext));
+            }
+        }
+
+        Ok(Buffer::new())
+    }
+}
+
+pub struct CryptoWriter<W> {
+    inner: W,
+    cipher: ChunkCipher,
+
+    next_chunk: u64,
+    /// The plaintext that has not been encrypted, we always keep the last
+    /// chunk here until close, since it must be encrypted with last flag.
+    buf: Vec<u8>,
+    /// Whether the last chunk has been written.
+    finished: bool,
+}
+
+impl<W> CryptoWriter<W> {
+    fn new(inner: W, cipher: ChunkCipher) -> Self {
+        Self {
+            inner,
+            cipher,
+            next_chunk: 0,
+            buf: Vec::new(),
+            finished: false,
+        }
+    }
+}
+
+impl<W: oio::Write> oio::Write for CryptoWriter<W> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        if self.finished {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "crypto writer has been closed",
+            ));
+        }
+        if self.buf.len() + bs.len() <= CHUNK_SIZE {
+            bs.into_iter().for_each(|b| self.buf.extend_from_slice(&b));
+            return Ok(());
+        }
+
+        // Only update the state after chunks have been written, so that
+        // the failed write could be retried.
+        let mut data = self.buf.clone();
+        bs.into_iter().for_each(|b| data.extend_from_slice(&b));
+
+        let mut chunks = Vec::new();
+        let mut offset = 0;
+        while data.len() - offset > CHUNK_SIZE {
+            let index = self.next_chunk + chunks.len() as u64;
+            chunks.push(
+                self.cipher
+                    .encrypt(index, false, &data[offset..offset + CHUNK_SIZE])?,
+            );
+            offset += CHUNK_SIZE;
+        }
+
+        let n = chunks.len() as u64;
+        self.inner.write(Buffer::from(chunks)).await?;
+        self.next_chunk += n;
+        self.buf = data.split_off(offset);
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        // The last chunk must be written only once even if close is retried.
+        if !self.finished {
+            let chunk = self.cipher.encrypt(self.next_chunk, true, &self.buf)?;
+            self.inner.write(Buffer::from(chunk)).await?;
+            self.next_chunk += 1;
+            self.buf.clear();
+            self.finished = true;
+        }
+
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buf.clear();
+        self.inner.abort().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use rand::thread_rng;
+    use rand::RngCore;
+
+    use super::*;
+    use crate::raw::oio::Read;
+    use crate::raw::oio::Write;
+
+    #[derive(Default)]
+    struct VecWriter(Vec<u8>);
+
+    impl oio::Write for VecWriter {
+        async fn write(&mut self, bs: Buffer) -> Result<()> {
+            self.0.extend_from_slice(&bs.to_vec());
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn new_cipher() -> ChunkCipher {
+        ChunkCipher::new(&[1; KEY_SIZE], &[2; NONCE_PREFIX_SIZE]).unwrap()
+    }
+
+    async fn encrypt(content: &[u8]) -> Vec<u8> {
+        let mut w = CryptoWriter::new(VecWriter::default(), new_cipher());
+        for chunk in content.chunks(10000) {
+            w.write(Buffer::from(chunk.to_vec())).await.unwrap();
+        }
+        w.close().await.unwrap();
+        w.inner.0
+    }
+
+    async fn decrypt(encrypted: &[u8], start: u64, end: u64) -> Result<Vec<u8>> {
+        let layout = ChunkLayout::new(encrypted.len() as u64)?;
+        let first_chunk = start / CHUNK_SIZE as u64;
+        let offset = (first_chunk * ENCRYPTED_CHUNK_SIZE as u64) as usize;
+
+        let mut r = CryptoReader {
+            inner: Buffer::from(encrypted[offset..].to_vec()),
+            cipher: new_cipher(),
+            layout,
+            next_chunk: first_chunk,
+            buf: BytesMut::new(),
+            skip: (start - first_chunk * CHUNK_SIZE as u64) as usize,
+            remaining: end - start,
+        };
+
+        let mut v = Vec::new();
+        loop {
+            let bs = r.read().await?;
+            if bs.is_empty() {
+                return Ok(v);
+            }
+            v.extend_from_slice(&bs.to_vec());
+        }
+    }
+
+    #[test]
+    fn test_chunk_layout() {
+        assert_eq!(ChunkLayout::new(16).unwrap().plain_size, 0);
+        assert_eq!(
+            ChunkLayout::new(ENCRYPTED_CHUNK_SIZE as u64)
+                .unwrap()
+                .chunks,
+            1
+        );
+        assert_eq!(
+            ChunkLayout::new(ENCRYPTED_CHUNK_SIZE as u64 + 17)
+                .unwrap()
+                .plain_size,
+            CHUNK_SIZE as u64 + 1
+        );
+        assert!(ChunkLayout::new(ENCRYPTED_CHUNK_SIZE as u64 + 10).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_crypto_roundtrip() {
+        let mut content = vec![0; CHUNK_SIZE * 3 + 100];
+        thread_rng().fill_bytes(&mut content);
+        let size = content.len() as u64;
+
+        let encrypted = encrypt(&content).await;
+        assert_eq!(
+            ChunkLayout::new(encrypted.len() as u64).unwrap().plain_size,
+            size
+        );
+
+        for (start, end) in [
+            (0, size),
+            (1, 2),
+            (CHUNK_SIZE as u64 - 1, CHUNK_SIZE as u64 * 2 + 1),
+            (size - 10, size),
+        ] {
+            let actual = decrypt(&encrypted, start, end).await.unwrap();
+            assert_eq!(actual, &content[start as usize..end as usize]);
+        }
+
+        assert!(decrypt(&encrypt(b"").await, 0, 0).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_crypto_truncated() {
+        let content = vec![1; CHUNK_SIZE * 2];
+        let encrypted = encrypt(&content).await;
+
+        // Drop the last chunk, the first chunk is not flagged as last.
+        let truncated = &encrypted[..ENCRYPTED_CHUNK_SIZE];
+        let err = decrypt(truncated, 0, CHUNK_SIZE as u64).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+    }
+
+    #[tokio::test]
+    async fn test_crypto_close_twice() {
+        let mut w = CryptoWriter::new(VecWriter::default(), new_cipher());
+        w.write(Buffer::from(vec![1; 100])).await.unwrap();
+        w.close().await.unwrap();
+        w.close().await.unwrap();
+
+        assert_eq!(w.inner.0, encrypt(&[1; 100]).await);
+        assert!(w.write(Buffer::from(vec![1; 100])).await.is_err());
+    }
+
+    #[derive(Default)]
+    struct CountingKeyProvider(AtomicUsize);
+
+    impl KeyProvider for CountingKeyProvider {
+        async fn wrap_key(&self, key: &[u8]) -> Result<(String, Vec<u8>)> {
+            Ok(("test".to_string(), key.to_vec()))
+        }
+
+        async fn unwrap_key(&self, _: &str, wrapped_key: &[u8]) -> Result<Vec<u8>> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(wrapped_key.to_vec())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_key_cached() {
+        let inner = Operator::new(crate::services::Memory::default())
+            .unwrap()
+            .finish()
+            .into_inner();
+        let acc = CryptoLayer::new(CountingKeyProvider::default()).layer(inner);
+
+        for _ in 0..3 {
+            let key = acc.unwrap_key("test", &[1; KEY_SIZE]).await.unwrap();
+            assert_eq!(key, vec![1; KEY_SIZE]);
+        }
+        acc.unwrap_key("test", &[2; KEY_SIZE]).await.unwrap();
+        assert_eq!(acc.provider.0.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_static_key_provider() {
+        let provider = StaticKeyProvider::new("test", &[3; KEY_SIZE]).unwrap();
+        let (key_id, wrapped) = provider.wrap_key(&[4; KEY_SIZE]).await.unwrap();
+        assert_eq!(key_id, "test");
+        assert_eq!(
+            provider.unwrap_key(&key_id, &wrapped).await.unwrap(),
+            vec![4; KEY_SIZE]
+        );
+        assert!(provider.unwrap_key("other", &wrapped).await.is_err());
+    }
+}
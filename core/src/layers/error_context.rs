@@ -194,6 +194,24 @@ impl<A: Access> LayeredAccess for ErrorContextAccessor<A> {
         })
     }
 
+    async fn tag(&self, path: &str, args: OpTag) -> Result<RpTag> {
+        self.inner.tag(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Tag)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let sources = args.sources().len();
+        self.inner.concat(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Concat)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+                .with_context("sources", sources.to_string())
+        })
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.inner.blocking_create_dir(path, args).map_err(|err| {
             err.with_operation(Operation::BlockingCreateDir)
@@ -437,6 +455,10 @@ impl<T: oio::List> oio::List for ErrorContextWrapper<T> {
                     .with_context("listed", self.processed.to_string())
             })
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<T: oio::BlockingList> oio::BlockingList for ErrorContextWrapper<T> {
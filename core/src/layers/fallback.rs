@@ -0,0 +1,253 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use crate::raw::*;
+use crate::*;
+
+/// Fallback `read` and `stat` to other operators if the primary operator
+/// returns `NotFound` or `Unavailable`.
+///
+/// This is useful for cache-miss fallthrough (use the cache as primary and
+/// the origin as fallback) and read failover across regions.
+///
+/// # Notes
+///
+/// - Fallback operators are tried in the order they are added, the error of
+///   the last operator will be returned if all of them failed.
+/// - Only the errors returned while opening the reader will trigger fallback.
+///   Errors returned while reading data are returned as is.
+/// - Other operations like `write` and `list` are only served by the primary
+///   operator.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::FallbackLayer;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let origin = Operator::new(services::Memory::default())?.finish();
+///
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(FallbackLayer::new(origin))
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FallbackLayer {
+    fallbacks: Arc<Vec<Accessor>>,
+}
+
+impl FallbackLayer {
+    /// Create a new fallback layer with given fallback operator.
+    pub fn new(fallback: Operator) -> Self {
+        Self {
+            fallbacks: Arc::new(vec![fallback.into_inner()]),
+        }
+    }
+
+    /// Add another fallback operator, which will be tried after all existing
+    /// fallback operators failed.
+    pub fn push(mut self, fallback: Operator) -> Self {
+        Arc::make_mut(&mut self.fallbacks).push(fallback.into_inner());
+        self
+    }
+}
+
+impl Debug for FallbackLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FallbackLayer")
+            .field("fallbacks", &self.fallbacks)
+            .finish()
+    }
+}
+
+impl<A: Access> Layer<A> for FallbackLayer {
+    type LayeredAccess = FallbackAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        FallbackAccessor {
+            inner,
+            fallbacks: self.fallbacks.clone(),
+        }
+    }
+}
+
+/// Check if the error should fallback to the next operator.
+fn should_fallback(err: &Error) -> bool {
+    matches!(err.kind(), ErrorKind::NotFound | ErrorKind::Unavailable)
+}
+
+#[derive(Debug)]
+pub struct FallbackAccessor<A: Access> {
+    inner: A,
+    fallbacks: Arc<Vec<Accessor>>,
+}
+
+impl<A: Access> LayeredAccess for FallbackAccessor<A> {
+    type Inner = A;
+    type Reader = TwoWays<A::Reader, oio::Reader>;
+    type BlockingReader = TwoWays<A::BlockingReader, oio::BlockingReader>;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+    type Deleter = A::Deleter;
+    type BlockingDeleter = A::BlockingDeleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let mut err = match self.inner.read(path, args.clone()).await {
+            Ok((rp, r)) => return Ok((rp, TwoWays::One(r))),
+            Err(err) => err,
+        };
+
+        for fallback in self.fallbacks.iter() {
+            if !should_fallback(&err) {
+                break;
+            }
+            err = match fallback.read(path, args.clone()).await {
+                Ok((rp, r)) => return Ok((rp, TwoWays::Two(r))),
+                Err(err) => err,
+            };
+        }
+        Err(err)
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let mut err = match self.inner.stat(path, args.clone()).await {
+            Ok(rp) => return Ok(rp),
+            Err(err) => err,
+        };
+
+        for fallback in self.fallbacks.iter() {
+            if !should_fallback(&err) {
+                break;
+            }
+            err = match fallback.stat(path, args.clone()).await {
+                Ok(rp) => return Ok(rp),
+                Err(err) => err,
+            };
+        }
+        Err(err)
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let mut err = match self.inner.blocking_read(path, args.clone()) {
+            Ok((rp, r)) => return Ok((rp, TwoWays::One(r))),
+            Err(err) => err,
+        };
+
+        for fallback in self.fallbacks.iter() {
+            if !should_fallback(&err) {
+                break;
+            }
+            err = match fallback.blocking_read(path, args.clone()) {
+                Ok((rp, r)) => return Ok((rp, TwoWays::Two(r))),
+                Err(err) => err,
+            };
+        }
+        Err(err)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let mut err = match self.inner.blocking_stat(path, args.clone()) {
+            Ok(rp) => return Ok(rp),
+            Err(err) => err,
+        };
+
+        for fallback in self.fallbacks.iter() {
+            if !should_fallback(&err) {
+                break;
+            }
+            err = match fallback.blocking_stat(path, args.clone()) {
+                Ok(rp) => return Ok(rp),
+                Err(err) => err,
+            };
+        }
+        Err(err)
+    }
+
+    fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
+        self.inner.blocking_delete()
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_fallback_read() {
+        let first = Operator::new(Memory::default()).unwrap().finish();
+        let second = Operator::new(Memory::default()).unwrap().finish();
+        first.write("first", "from first").await.unwrap();
+        second.write("second", "from second").await.unwrap();
+
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(FallbackLayer::new(first).push(second))
+            .finish();
+        op.write("primary", "from primary").await.unwrap();
+
+        for (path, expected) in [
+            ("primary", "from primary"),
+            ("first", "from first"),
+            ("second", "from second"),
+        ] {
+            assert_eq!(op.read(path).await.unwrap().to_vec(), expected.as_bytes());
+            assert_eq!(
+                op.stat(path).await.unwrap().content_length(),
+                expected.len() as u64
+            );
+        }
+
+        let err = op.read("not_exist").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,527 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::raw::*;
+use crate::*;
+
+/// Hooks contains the callbacks that will be invoked by [`HooksLayer`].
+///
+/// All callbacks have default no-op implementations, users only need to
+/// implement the callbacks they care about.
+pub trait Hooks: Send + Sync + 'static {
+    /// Invoked before the operation starts.
+    fn before(&self, ctx: &HookContext<'_>) -> impl Future<Output = ()> + MaybeSend {
+        let _ = ctx;
+        async {}
+    }
+
+    /// Invoked after the operation finished, no matter it succeeded or not.
+    fn after(
+        &self,
+        ctx: &HookContext<'_>,
+        event: &HookEvent<'_>,
+    ) -> impl Future<Output = ()> + MaybeSend {
+        let _ = (ctx, event);
+        async {}
+    }
+}
+
+/// HookContext describes the operation that hooks are invoked for.
+#[derive(Debug, Clone, Copy)]
+pub struct HookContext<'a> {
+    scheme: Scheme,
+    operation: Operation,
+    path: &'a str,
+}
+
+impl HookContext<'_> {
+    /// The scheme of the underlying service.
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// The kind of this operation.
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// The path of this operation.
+    ///
+    /// For `copy` and `rename`, it's the source path. For `delete` which only
+    /// creates the deleter, it's empty.
+    pub fn path(&self) -> &str {
+        self.path
+    }
+}
+
+/// HookEvent is the outcome of an operation passed to [`Hooks::after`].
+#[derive(Debug, Clone, Copy)]
+pub struct HookEvent<'a> {
+    duration: Duration,
+    bytes: Option<usize>,
+    error: Option<&'a Error>,
+}
+
+impl HookEvent<'_> {
+    /// The time spent on this operation.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The bytes transferred by this operation.
+    ///
+    /// Only IO operations like `Reader::read` and `Writer::write` will
+    /// have bytes.
+    pub fn bytes(&self) -> Option<usize> {
+        self.bytes
+    }
+
+    /// The error returned by this operation if it failed.
+    pub fn error(&self) -> Option<&Error> {
+        self.error
+    }
+
+    /// Check if this operation succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Add hooks for every operation, so that applications can do auditing or
+/// accounting without implementing a full layer.
+///
+/// # Notes
+///
+/// Hooks are invoked for all async operations and IO operations on `Reader`,
+/// `Writer`, `Lister` and `Deleter`. As callbacks are async, blocking
+/// operations will not be hooked.
+///
+/// Operations are waiting for callbacks, please make sure they return quickly.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::HookContext;
+/// # use opendal::layers::HookEvent;
+/// # use opendal::layers::Hooks;
+/// # use opendal::layers::HooksLayer;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// struct AuditHooks;
+///
+/// impl Hooks for AuditHooks {
+///     async fn after(&self, ctx: &HookContext<'_>, event: &HookEvent<'_>) {
+///         println!(
+///             "{} {} took {:?}, bytes: {:?}, ok: {}",
+///             ctx.operation(),
+///             ctx.path(),
+///             event.duration(),
+///             event.bytes(),
+///             event.is_ok()
+///         );
+///     }
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(HooksLayer::new(AuditHooks))
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+pub struct HooksLayer<H: Hooks> {
+    hooks: Arc<H>,
+}
+
+impl<H: Hooks> Clone for HooksLayer<H> {
+    fn clone(&self) -> Self {
+        Self {
+            hooks: self.hooks.clone(),
+        }
+    }
+}
+
+impl<H: Hooks> HooksLayer<H> {
+    /// Create a new hooks layer with given hooks.
+    pub fn new(hooks: H) -> Self {
+        Self {
+            hooks: Arc::new(hooks),
+        }
+    }
+}
+
+impl<A: Access, H: Hooks> Layer<A> for HooksLayer<H> {
+    type LayeredAccess = HooksAccessor<A, H>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        HooksAccessor {
+            scheme: inner.info().scheme(),
+            inner,
+            hooks: self.hooks.clone(),
+        }
+    }
+}
+
+/// Run the future with hooks invoked before and after it.
+async fn with_hooks<H: Hooks, T>(
+    hooks: &H,
+    ctx: HookContext<'_>,
+    fut: impl Future<Output = Result<T>>,
+    bytes: impl FnOnce(&T) -> Option<usize>,
+) -> Result<T> {
+    hooks.before(&ctx).await;
+
+    let start = Instant::now();
+    let res = fut.await;
+    let event = HookEvent {
+        duration: start.elapsed(),
+        bytes: res.as_ref().ok().and_then(bytes),
+        error: res.as_ref().err(),
+    };
+    hooks.after(&ctx, &event).await;
+
+    res
+}
+
+pub struct HooksAccessor<A: Access, H: Hooks> {
+    inner: A,
+    hooks: Arc<H>,
+    scheme: Scheme,
+}
+
+impl<A: Access, H: Hooks> Debug for HooksAccessor<A, H> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HooksAccessor")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access, H: Hooks> HooksAccessor<A, H> {
+    async fn hook<T>(
+        &self,
+        operation: Operation,
+        path: &str,
+        fut: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let ctx = HookContext {
+            scheme: self.scheme,
+            operation,
+            path,
+        };
+        with_hooks(&*self.hooks, ctx, fut, |_| None).await
+    }
+
+    fn wrap<R>(&self, inner: R, path: &str) -> HooksWrapper<R, H> {
+        HooksWrapper {
+            inner,
+            hooks: self.hooks.clone(),
+            scheme: self.scheme,
+            path: path.to_string(),
+            queued: Vec::new(),
+            notified: 0,
+            pending: 0,
+            started: None,
+        }
+    }
+}
+
+impl<A: Access, H: Hooks> LayeredAccess for HooksAccessor<A, H> {
+    type Inner = A;
+    type Reader = HooksWrapper<A::Reader, H>;
+    type BlockingReader = A::BlockingReader;
+    type Writer = HooksWrapper<A::Writer, H>;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = HooksWrapper<A::Lister, H>;
+    type BlockingLister = A::BlockingLister;
+    type Deleter = HooksWrapper<A::Deleter, H>;
+    type BlockingDeleter = A::BlockingDeleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.hook(
+            Operation::CreateDir,
+            path,
+            self.inner.create_dir(path, args),
+        )
+        .await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let (rp, r) = self
+            .hook(Operation::Read, path, self.inner.read(path, args))
+            .await?;
+        Ok((rp, self.wrap(r, path)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let (rp, w) = self
+            .hook(Operation::Write, path, self.inner.write(path, args))
+            .await?;
+        Ok((rp, self.wrap(w, path)))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.hook(Operation::Copy, from, self.inner.copy(from, to, args))
+            .await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.hook(Operation::Rename, from, self.inner.rename(from, to, args))
+            .await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.hook(Operation::Stat, path, self.inner.stat(path, args))
+            .await
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        let (rp, d) = self
+            .hook(Operation::Delete, "", self.inner.delete())
+            .await?;
+        Ok((rp, self.wrap(d, "")))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let (rp, l) = self
+            .hook(Operation::List, path, self.inner.list(path, args))
+            .await?;
+        Ok((rp, self.wrap(l, path)))
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.hook(Operation::Presign, path, self.inner.presign(path, args))
+            .await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+
+    fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
+        self.inner.blocking_delete()
+    }
+}
+
+pub struct HooksWrapper<R, H: Hooks> {
+    inner: R,
+    hooks: Arc<H>,
+    scheme: Scheme,
+    path: String,
+    /// Paths that have been queued in the deleter but not confirmed deleted yet.
+    queued: Vec<String>,
+    /// The number of queued paths that `before` has been invoked for.
+    notified: usize,
+    /// The number of queued paths that flush hasn't reported as deleted yet.
+    pending: usize,
+    /// The time of the first flush for the queued paths.
+    started: Option<Instant>,
+}
+
+impl<R: oio::Read, H: Hooks> oio::Read for HooksWrapper<R, H> {
+    async fn read(&mut self) -> Result<Buffer> {
+        let ctx = HookContext {
+            scheme: self.scheme,
+            operation: Operation::ReaderRead,
+            path: &self.path,
+        };
+        with_hooks(&*self.hooks, ctx, self.inner.read(), |bs| Some(bs.len())).await
+    }
+}
+
+impl<R: oio::Write, H: Hooks> oio::Write for HooksWrapper<R, H> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let ctx = HookContext {
+            scheme: self.scheme,
+            operation: Operation::WriterWrite,
+            path: &self.path,
+        };
+        let size = bs.len();
+        with_hooks(&*self.hooks, ctx, self.inner.write(bs), |_| Some(size)).await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let ctx = HookContext {
+            scheme: self.scheme,
+            operation: Operation::WriterClose,
+            path: &self.path,
+        };
+        with_hooks(&*self.hooks, ctx, self.inner.close(), |_| None).await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let ctx = HookContext {
+            scheme: self.scheme,
+            operation: Operation::WriterAbort,
+            path: &self.path,
+        };
+        with_hooks(&*self.hooks, ctx, self.inner.abort(), |_| None).await
+    }
+}
+
+impl<R: oio::List, H: Hooks> oio::List for HooksWrapper<R, H> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let ctx = HookContext {
+            scheme: self.scheme,
+            operation: Operation::ListerNext,
+            path: &self.path,
+        };
+        with_hooks(&*self.hooks, ctx, self.inner.next(), |_| None).await
+    }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
+}
+
+impl<R: oio::Delete, H: Hooks> oio::Delete for HooksWrapper<R, H> {
+    /// `delete` only queues the request, hooks will be invoked for every
+    /// path while flushing.
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.inner.delete(path, args)?;
+        self.queued.push(path.to_string());
+        self.pending += 1;
+        Ok(())
+    }
+
+    /// Hooks of `DeleterFlush` are invoked for every flush with its result.
+    ///
+    /// The count returned by flush can't tell which paths have been deleted:
+    /// deleters may dedup, reorder or partially fail the queued paths. So
+    /// `after` of `DeleterDelete` is invoked for the queued paths only after
+    /// the inner deleter has nothing pending. Failures are reported by
+    /// `DeleterFlush` instead.
+    async fn flush(&mut self) -> Result<usize> {
+        for path in self.queued.iter().skip(self.notified) {
+            let ctx = HookContext {
+                scheme: self.scheme,
+                operation: Operation::DeleterDelete,
+                path,
+            };
+            self.hooks.before(&ctx).await;
+        }
+        self.notified = self.queued.len();
+        if !self.queued.is_empty() && self.started.is_none() {
+            self.started = Some(Instant::now());
+        }
+
+        let ctx = HookContext {
+            scheme: self.scheme,
+            operation: Operation::DeleterFlush,
+            path: "",
+        };
+        let n = with_hooks(&*self.hooks, ctx, self.inner.flush(), |_| None).await?;
+
+        // Flushing nothing means the inner deleter has processed all queued
+        // paths, which also covers duplicated paths that are deleted only once.
+        self.pending = self.pending.saturating_sub(n);
+        if self.queued.is_empty() || (n != 0 && self.pending != 0) {
+            return Ok(n);
+        }
+
+        let event = HookEvent {
+            duration: self.started.take().map(|v| v.elapsed()).unwrap_or_default(),
+            bytes: None,
+            error: None,
+        };
+        for path in self.queued.drain(..) {
+            let ctx = HookContext {
+                scheme: self.scheme,
+                operation: Operation::DeleterDelete,
+                path: &path,
+            };
+            self.hooks.after(&ctx, &event).await;
+        }
+        self.notified = 0;
+        self.pending = 0;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// The operation, path, bytes and whether it succeeded.
+    type Record = (Operation, String, Option<usize>, bool);
+
+    #[derive(Default)]
+    struct RecordHooks {
+        before: Mutex<Vec<Operation>>,
+        after: Mutex<Vec<Record>>,
+    }
+
+    impl Hooks for Arc<RecordHooks> {
+        async fn before(&self, ctx: &HookContext<'_>) {
+            self.before.lock().unwrap().push(ctx.operation());
+        }
+
+        async fn after(&self, ctx: &HookContext<'_>, event: &HookEvent<'_>) {
+            self.after.lock().unwrap().push((
+                ctx.operation(),
+                ctx.path().to_string(),
+                event.bytes(),
+                event.is_ok(),
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hooks_layer() {
+        let hooks = Arc::new(RecordHooks::default());
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(HooksLayer::new(hooks.clone()))
+            .finish();
+
+        op.write("test", "hello").await.unwrap();
+        assert!(op.stat("not_exist").await.is_err());
+        op.delete("test").await.unwrap();
+
+        let after = hooks.after.lock().unwrap().clone();
+        assert!(after.contains(&(Operation::WriterWrite, "test".to_string(), Some(5), true)));
+        assert!(after.contains(&(Operation::Stat, "not_exist".to_string(), None, false)));
+        assert!(after.contains(&(Operation::DeleterFlush, "".to_string(), None, true)));
+        assert!(after.contains(&(Operation::DeleterDelete, "test".to_string(), None, true)));
+        assert_eq!(hooks.before.lock().unwrap().len(), after.len());
+    }
+}
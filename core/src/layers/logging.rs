@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::sync::Arc;
@@ -37,6 +38,36 @@ use crate::*;
 ///   - `failed`: the operation returns an unexpected error.
 /// - The default log level while expected error happened is `Warn`.
 /// - The default log level while unexpected failure happened is `Error`.
+/// - Sensitive query params and headers like signatures and tokens will be
+///   redacted before logging.
+///
+/// # Customization
+///
+/// The default logging target is `opendal::services`, users can change the
+/// target and the log levels of errors and operations:
+///
+/// ```no_run
+/// # use log::Level;
+/// # use opendal::layers::LoggingLayer;
+/// # use opendal::raw::Operation;
+/// # use opendal::services;
+/// # use opendal::ErrorKind;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(
+///         LoggingLayer::default()
+///             .with_target("my_app::storage")
+///             // NotFound is expected in our app, log it at debug.
+///             .with_error_level(ErrorKind::NotFound, Level::Debug)
+///             .with_operation_level(Operation::Stat, Level::Trace),
+///     )
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
 ///
 /// # Examples
 ///
@@ -116,7 +147,7 @@ pub struct LoggingLayer<I = DefaultLoggingInterceptor> {
 impl Default for LoggingLayer {
     fn default() -> Self {
         Self {
-            logger: DefaultLoggingInterceptor,
+            logger: DefaultLoggingInterceptor::default(),
         }
     }
 }
@@ -126,6 +157,32 @@ impl LoggingLayer {
     pub fn new<I: LoggingInterceptor>(logger: I) -> LoggingLayer<I> {
         LoggingLayer { logger }
     }
+
+    /// Set the logging target, default to `opendal::services`.
+    pub fn with_target(mut self, target: &'static str) -> Self {
+        self.logger.target = target;
+        self
+    }
+
+    /// Set the log level for errors of given kind.
+    ///
+    /// By default, `Unexpected` errors are logged at `Error` and others at `Warn`.
+    pub fn with_error_level(mut self, kind: ErrorKind, level: Level) -> Self {
+        self.logger.error_levels.retain(|(k, _)| *k != kind);
+        self.logger.error_levels.push((kind, level));
+        self
+    }
+
+    /// Set the log level for non-error logs of given operation.
+    ///
+    /// By default, oneshot operations are logged at `Debug` and others at `Trace`.
+    pub fn with_operation_level(mut self, operation: Operation, level: Level) -> Self {
+        self.logger
+            .operation_levels
+            .retain(|(op, _)| *op != operation);
+        self.logger.operation_levels.push((operation, level));
+        self
+    }
 }
 
 impl<A: Access, I: LoggingInterceptor> Layer<A> for LoggingLayer<I> {
@@ -170,8 +227,54 @@ pub trait LoggingInterceptor: Debug + Clone + Send + Sync + Unpin + 'static {
 }
 
 /// The DefaultLoggingInterceptor will log the message by the standard logging macro.
-#[derive(Debug, Copy, Clone, Default)]
-pub struct DefaultLoggingInterceptor;
+#[derive(Debug, Clone)]
+pub struct DefaultLoggingInterceptor {
+    target: &'static str,
+    error_levels: Vec<(ErrorKind, Level)>,
+    operation_levels: Vec<(Operation, Level)>,
+}
+
+impl Default for DefaultLoggingInterceptor {
+    fn default() -> Self {
+        Self {
+            target: LOGGING_TARGET,
+            error_levels: vec![],
+            operation_levels: vec![],
+        }
+    }
+}
+
+impl DefaultLoggingInterceptor {
+    fn error_level(&self, kind: ErrorKind) -> Level {
+        if let Some((_, lvl)) = self.error_levels.iter().find(|(k, _)| *k == kind) {
+            return *lvl;
+        }
+
+        // Print error if it's unexpected, otherwise in warn.
+        if kind == ErrorKind::Unexpected {
+            Level::Error
+        } else {
+            Level::Warn
+        }
+    }
+
+    fn operation_level(&self, operation: Operation) -> Level {
+        if let Some((_, lvl)) = self
+            .operation_levels
+            .iter()
+            .find(|(op, _)| *op == operation)
+        {
+            return *lvl;
+        }
+
+        // Print debug message if operation is oneshot, otherwise in trace.
+        if operation.is_oneshot() {
+            Level::Debug
+        } else {
+            Level::Trace
+        }
+    }
+}
 
 impl LoggingInterceptor for DefaultLoggingInterceptor {
     #[inline]
@@ -184,15 +287,13 @@ impl LoggingInterceptor for DefaultLoggingInterceptor {
         err: Option<&Error>,
     ) {
         if let Some(err) = err {
-            // Print error if it's unexpected, otherwise in warn.
-            let lvl = if err.kind() == ErrorKind::Unexpected {
-                Level::Error
-            } else {
-                Level::Warn
-            };
+            let lvl = self.error_level(err.kind());
+            if !log::log_enabled!(target: self.target, lvl) {
+                return;
+            }
 
             log!(
-                target: LOGGING_TARGET,
+                target: self.target,
                 lvl,
                 "service={} name={} {}: {operation} {message} {}",
                 info.scheme(),
@@ -203,23 +304,17 @@ impl LoggingInterceptor for DefaultLoggingInterceptor {
                 // It's super sad that we can't bind `format_args!()` here.
                 // See: https://github.com/rust-lang/rust/issues/92698
                 if err.kind() != ErrorKind::Unexpected {
-                   format!("{err}")
+                   redact(&format!("{err}")).into_owned()
                 } else {
-                   format!("{err:?}")
+                   redact(&format!("{err:?}")).into_owned()
                 }
             );
+            return;
         }
 
-        // Print debug message if operation is oneshot, otherwise in trace.
-        let lvl = if operation.is_oneshot() {
-            Level::Debug
-        } else {
-            Level::Trace
-        };
-
         log!(
-            target: LOGGING_TARGET,
-            lvl,
+            target: self.target,
+            self.operation_level(operation),
             "service={} name={} {}: {operation} {message}",
             info.scheme(),
             info.name(),
@@ -234,15 +329,85 @@ impl Display for LoggingContext<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (i, (k, v)) in self.0.iter().enumerate() {
             if i > 0 {
-                write!(f, " {}={}", k, v)?;
+                write!(f, " {}={}", k, redact(v))?;
             } else {
-                write!(f, "{}={}", k, v)?;
+                write!(f, "{}={}", k, redact(v))?;
             }
         }
         Ok(())
     }
 }
 
+/// Query params and headers that may carry credentials, compared in lowercase.
+const SENSITIVE_KEYS: &[&str] = &[
+    "x-amz-signature",
+    "x-amz-credential",
+    "x-amz-security-token",
+    "x-goog-signature",
+    "x-goog-credential",
+    "x-oss-signature",
+    "x-oss-credential",
+    "x-oss-security-token",
+    "awsaccesskeyid",
+    "ossaccesskeyid",
+    "signature",
+    "sig",
+    "token",
+    "access_token",
+    "authorization",
+    "x-ms-copy-source-authorization",
+];
+
+/// Redact the values of sensitive query params and headers in input.
+///
+/// Query params like `?sig=xxx&se=yyy` will be logged as `?sig=***&se=yyy`,
+/// headers in debug output like `"authorization": "xxx"` will be logged as
+/// `"authorization": "***"`.
+fn redact(input: &str) -> Cow<'_, str> {
+    let lower = input.to_ascii_lowercase();
+    if !SENSITIVE_KEYS.iter().any(|k| lower.contains(k)) {
+        return Cow::Borrowed(input);
+    }
+
+    let bs = input.as_bytes();
+    let mut output = String::with_capacity(input.len());
+    let mut idx = 0;
+    while idx < bs.len() {
+        let Some(key) = SENSITIVE_KEYS.iter().find(|k| {
+            lower[idx..].starts_with(*k)
+                && (idx == 0 || matches!(bs[idx - 1], b'?' | b'&' | b'"' | b' '))
+        }) else {
+            let ch = input[idx..].chars().next().expect("must have next char");
+            output.push(ch);
+            idx += ch.len_utf8();
+            continue;
+        };
+
+        let end = idx + key.len();
+        output.push_str(&input[idx..end]);
+        // `key=value` in query or `"key": "value"` in headers.
+        let (value_start, value_end) = if input[end..].starts_with('=') {
+            let start = end + 1;
+            let len = input[start..]
+                .find(['&', '"', ' ', ',', '}', '\n'])
+                .unwrap_or(input.len() - start);
+            (start, start + len)
+        } else if input[end..].starts_with("\": \"") {
+            let start = end + 4;
+            let len = input[start..].find('"').unwrap_or(input.len() - start);
+            (start, start + len)
+        } else {
+            idx = end;
+            continue;
+        };
+        output.push_str(&input[end..value_start]);
+        output.push_str("***");
+        idx = value_end;
+    }
+
+    Cow::Owned(output)
+}
+
 #[derive(Clone, Debug)]
 pub struct LoggingAccessor<A: Access, I: LoggingInterceptor> {
     inner: A,
@@ -577,6 +742,40 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
             })
     }
 
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        self.logger.log(
+            &self.info,
+            Operation::Concat,
+            &[("path", path)],
+            "started",
+            None,
+        );
+
+        self.inner
+            .concat(path, args)
+            .await
+            .map(|v| {
+                self.logger.log(
+                    &self.info,
+                    Operation::Concat,
+                    &[("path", path)],
+                    "finished",
+                    None,
+                );
+                v
+            })
+            .map_err(|err| {
+                self.logger.log(
+                    &self.info,
+                    Operation::Concat,
+                    &[("path", path)],
+                    "failed",
+                    Some(&err),
+                );
+                err
+            })
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.logger.log(
             &self.info,
@@ -1238,6 +1437,10 @@ impl<P: oio::List, I: LoggingInterceptor> oio::List for LoggingLister<P, I> {
 
         res
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<P: oio::BlockingList, I: LoggingInterceptor> oio::BlockingList for LoggingLister<P, I> {
@@ -1509,3 +1712,35 @@ impl<D: oio::BlockingDelete, I: LoggingInterceptor> oio::BlockingDelete for Logg
         res
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        let cases = vec![
+            ("plain path", "path/to/file", "path/to/file"),
+            (
+                "azure sas",
+                "uri: https://a.blob.core.windows.net/c/p?sv=2021&sig=abc%2Fdef&se=2024",
+                "uri: https://a.blob.core.windows.net/c/p?sv=2021&sig=***&se=2024",
+            ),
+            (
+                "s3 presign",
+                "https://b.s3.amazonaws.com/k?X-Amz-Credential=AK%2F&X-Amz-Signature=ff00",
+                "https://b.s3.amazonaws.com/k?X-Amz-Credential=***&X-Amz-Signature=***",
+            ),
+            (
+                "header",
+                r#"headers: {"authorization": "Bearer xyz", "etag": "abc"}"#,
+                r#"headers: {"authorization": "***", "etag": "abc"}"#,
+            ),
+            ("not a param", "design=signed", "design=signed"),
+        ];
+
+        for (name, input, expected) in cases {
+            assert_eq!(redact(input), expected, "{name}");
+        }
+    }
+}
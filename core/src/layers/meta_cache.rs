@@ -0,0 +1,548 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use moka::sync::Cache;
+
+use crate::raw::*;
+use crate::*;
+
+/// Cache the metadata returned by `stat` to speed up stat-heavy workloads like
+/// FUSE mounts and web galleries.
+///
+/// # Notes
+///
+/// - Only `stat` without conditions, version and overrides will be cached.
+/// - Cached metadata will be invalidated by `write`, `delete`, `copy`, `rename`,
+///   `concat` and `create_dir` through the same operator (and its clones).
+///   Changes made by others will be visible after the entry expires.
+/// - Invalidating a dir path (ends with `/`) invalidates all paths under it.
+/// - A `stat` racing with an invalidation will not fill the cache, so stale
+///   metadata fetched before a change won't be cached after it.
+/// - Listing is not cached and doesn't update the cache.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// #
+/// # use opendal::layers::MetaCacheLayer;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(
+///         MetaCacheLayer::default()
+///             .with_ttl(Duration::from_secs(30))
+///             .with_capacity(100_000),
+///     )
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MetaCacheLayer {
+    ttl: Duration,
+    capacity: u64,
+}
+
+impl Default for MetaCacheLayer {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(60),
+            capacity: 10_000,
+        }
+    }
+}
+
+impl MetaCacheLayer {
+    /// Set the time to live of cached metadata.
+    ///
+    /// Default to 60 seconds.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Set the max count of cached paths.
+    ///
+    /// Default to 10000.
+    pub fn with_capacity(mut self, capacity: u64) -> Self {
+        self.capacity = capacity;
+        self
+    }
+}
+
+impl<A: Access> Layer<A> for MetaCacheLayer {
+    type LayeredAccess = MetaCacheAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        MetaCacheAccessor {
+            inner,
+            cache: Arc::new(MetaCache {
+                cache: Cache::builder()
+                    .max_capacity(self.capacity)
+                    .time_to_live(self.ttl)
+                    .support_invalidation_closures()
+                    .build(),
+                generation: Mutex::new(0),
+            }),
+        }
+    }
+}
+
+/// MetaCache is the cache of metadata shared by the accessor and its wrappers.
+struct MetaCache {
+    cache: Cache<String, Metadata>,
+    /// Generation is bumped on every invalidation. `stat` will only fill the
+    /// cache if no invalidation happened since it started.
+    generation: Mutex<u64>,
+}
+
+impl MetaCache {
+    fn get(&self, path: &str) -> Option<Metadata> {
+        self.cache.get(path)
+    }
+
+    fn generation(&self) -> u64 {
+        *self.generation.lock().expect("lock must be valid")
+    }
+
+    /// Insert the metadata if no invalidation happened since `generation`.
+    fn insert(&self, path: &str, meta: Metadata, generation: u64) {
+        let current = self.generation.lock().expect("lock must be valid");
+        if *current == generation {
+            self.cache.insert(path.to_string(), meta);
+        }
+    }
+
+    /// Invalidate the path, and all paths under it if it's a dir.
+    fn invalidate(&self, path: &str) {
+        let mut generation = self.generation.lock().expect("lock must be valid");
+        *generation += 1;
+
+        self.cache.invalidate(path);
+        if path.ends_with('/') {
+            let prefix = path.to_string();
+            // Only fails if invalidation closures are not supported, which
+            // is enabled while building.
+            let _ = self
+                .cache
+                .invalidate_entries_if(move |k, _| k.starts_with(&prefix));
+        }
+    }
+}
+
+/// Check if the stat could be served by cached metadata.
+fn is_cacheable(args: &OpStat) -> bool {
+    args.if_match().is_none()
+        && args.if_none_match().is_none()
+        && args.if_modified_since().is_none()
+        && args.if_unmodified_since().is_none()
+        && args.override_content_type().is_none()
+        && args.override_cache_control().is_none()
+        && args.override_content_disposition().is_none()
+        && args.version().is_none()
+        && args.server_side_encryption_customer_key().is_none()
+}
+
+pub struct MetaCacheAccessor<A: Access> {
+    inner: A,
+    cache: Arc<MetaCache>,
+}
+
+impl<A: Access> Debug for MetaCacheAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetaCacheAccessor")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> LayeredAccess for MetaCacheAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = MetaCacheWrapper<A::Writer>;
+    type BlockingWriter = MetaCacheWrapper<A::BlockingWriter>;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+    type Deleter = MetaCacheWrapper<A::Deleter>;
+    type BlockingDeleter = MetaCacheWrapper<A::BlockingDeleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.cache.invalidate(path);
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.cache.invalidate(path);
+        self.inner
+            .write(path, args)
+            .await
+            .map(|(rp, w)| (rp, MetaCacheWrapper::new(w, path, self.cache.clone())))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let res = self.inner.copy(from, to, args).await;
+        self.cache.invalidate(to);
+        res
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let res = self.inner.rename(from, to, args).await;
+        self.cache.invalidate(from);
+        self.cache.invalidate(to);
+        res
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let res = self.inner.concat(path, args).await;
+        self.cache.invalidate(path);
+        res
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        if !is_cacheable(&args) {
+            return self.inner.stat(path, args).await;
+        }
+        if let Some(meta) = self.cache.get(path) {
+            return Ok(RpStat::new(meta));
+        }
+
+        let generation = self.cache.generation();
+        let rp = self.inner.stat(path, args).await?;
+        self.cache
+            .insert(path, rp.clone().into_metadata(), generation);
+        Ok(rp)
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner
+            .delete()
+            .await
+            .map(|(rp, d)| (rp, MetaCacheWrapper::new(d, "", self.cache.clone())))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.cache.invalidate(path);
+        self.inner.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.cache.invalidate(path);
+        self.inner
+            .blocking_write(path, args)
+            .map(|(rp, w)| (rp, MetaCacheWrapper::new(w, path, self.cache.clone())))
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let res = self.inner.blocking_copy(from, to, args);
+        self.cache.invalidate(to);
+        res
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let res = self.inner.blocking_rename(from, to, args);
+        self.cache.invalidate(from);
+        self.cache.invalidate(to);
+        res
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        if !is_cacheable(&args) {
+            return self.inner.blocking_stat(path, args);
+        }
+        if let Some(meta) = self.cache.get(path) {
+            return Ok(RpStat::new(meta));
+        }
+
+        let generation = self.cache.generation();
+        let rp = self.inner.blocking_stat(path, args)?;
+        self.cache
+            .insert(path, rp.clone().into_metadata(), generation);
+        Ok(rp)
+    }
+
+    fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
+        self.inner
+            .blocking_delete()
+            .map(|(rp, d)| (rp, MetaCacheWrapper::new(d, "", self.cache.clone())))
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// MetaCacheWrapper invalidates cached metadata of the paths that are
+/// written or deleted by the inner writer or deleter.
+pub struct MetaCacheWrapper<R> {
+    inner: R,
+    path: String,
+    cache: Arc<MetaCache>,
+    /// Paths that have been queued in the deleter but not confirmed deleted yet.
+    queued: Vec<String>,
+    /// The number of queued paths that flush hasn't reported as deleted yet.
+    pending: usize,
+}
+
+impl<R> MetaCacheWrapper<R> {
+    fn new(inner: R, path: &str, cache: Arc<MetaCache>) -> Self {
+        Self {
+            inner,
+            path: path.to_string(),
+            cache,
+            queued: Vec::new(),
+            pending: 0,
+        }
+    }
+
+    fn queue(&mut self, path: &str) {
+        self.cache.invalidate(path);
+        self.queued.push(path.to_string());
+        self.pending += 1;
+    }
+
+    /// Invalidate the queued paths after a flush.
+    ///
+    /// The count returned by flush can't tell which paths have been deleted:
+    /// deleters may dedup, reorder or partially fail the queued paths. So all
+    /// queued paths are invalidated on every flush, and forgotten only after
+    /// the inner deleter has nothing pending.
+    fn invalidate_flushed(&mut self, res: &Result<usize>) {
+        for path in self.queued.iter() {
+            self.cache.invalidate(path);
+        }
+
+        let Ok(n) = res else {
+            return;
+        };
+        // Flushing nothing means the inner deleter has processed all queued paths.
+        self.pending = self.pending.saturating_sub(*n);
+        if *n == 0 || self.pending == 0 {
+            self.queued.clear();
+            self.pending = 0;
+        }
+    }
+}
+
+impl<R: oio::Write> oio::Write for MetaCacheWrapper<R> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.inner.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let res = self.inner.close().await;
+        self.cache.invalidate(&self.path);
+        res
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+impl<R: oio::BlockingWrite> oio::BlockingWrite for MetaCacheWrapper<R> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.inner.write(bs)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        let res = self.inner.close();
+        self.cache.invalidate(&self.path);
+        res
+    }
+}
+
+impl<R: oio::Delete> oio::Delete for MetaCacheWrapper<R> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.inner.delete(path, args)?;
+        self.queue(path);
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<usize> {
+        let res = self.inner.flush().await;
+        self.invalidate_flushed(&res);
+        res
+    }
+}
+
+impl<R: oio::BlockingDelete> oio::BlockingDelete for MetaCacheWrapper<R> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.inner.delete(path, args)?;
+        self.queue(path);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<usize> {
+        let res = self.inner.flush();
+        self.invalidate_flushed(&res);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// A batch deleter that fails to delete `b` the first time.
+    #[derive(Default)]
+    struct FlakyBatchDelete {
+        failed: AtomicBool,
+    }
+
+    impl oio::BatchDelete for FlakyBatchDelete {
+        async fn delete_once(&self, _: String, _: OpDelete) -> Result<()> {
+            Ok(())
+        }
+
+        async fn delete_batch(
+            &self,
+            batch: Vec<(String, OpDelete)>,
+        ) -> Result<oio::BatchDeleteResult> {
+            let mut result = oio::BatchDeleteResult::default();
+            for (path, args) in batch {
+                if path == "b" && !self.failed.swap(true, Ordering::SeqCst) {
+                    let err = Error::new(ErrorKind::Unexpected, "injected").set_temporary();
+                    result.failed.push((path, args, err));
+                } else {
+                    result.succeeded.push((path, args));
+                }
+            }
+            Ok(result)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meta_cache() {
+        let inner = Operator::new(Memory::default()).unwrap().finish();
+        let op = inner.clone().layer(MetaCacheLayer::default());
+
+        op.write("file", "hello").await.unwrap();
+        assert_eq!(op.stat("file").await.unwrap().content_length(), 5);
+
+        // Changes made by others are not visible before expiring.
+        inner.write("file", "hello, world").await.unwrap();
+        assert_eq!(op.stat("file").await.unwrap().content_length(), 5);
+
+        // Writes through the same operator invalidate the cache.
+        op.write("file", "hello, world!").await.unwrap();
+        assert_eq!(op.stat("file").await.unwrap().content_length(), 13);
+
+        op.delete("file").await.unwrap();
+        let err = op.stat("file").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_meta_cache_invalidate_dir() {
+        let inner = Operator::new(Memory::default()).unwrap().finish();
+        let op = inner.clone().layer(MetaCacheLayer::default());
+
+        op.write("dir/file", "hello").await.unwrap();
+        op.write("dirx", "hello").await.unwrap();
+        assert_eq!(op.stat("dir/file").await.unwrap().content_length(), 5);
+        assert_eq!(op.stat("dirx").await.unwrap().content_length(), 5);
+
+        inner.write("dir/file", "hello, world").await.unwrap();
+        inner.write("dirx", "hello, world").await.unwrap();
+        op.create_dir("dir/").await.unwrap();
+        assert_eq!(op.stat("dir/file").await.unwrap().content_length(), 12);
+        assert_eq!(op.stat("dirx").await.unwrap().content_length(), 5);
+    }
+
+    #[test]
+    fn test_meta_cache_insert_after_invalidate() {
+        let cache = MetaCache {
+            cache: Cache::builder().support_invalidation_closures().build(),
+            generation: Mutex::new(0),
+        };
+
+        let generation = cache.generation();
+        cache.invalidate("file");
+        cache.insert("file", Metadata::new(EntryMode::FILE), generation);
+        assert!(cache.get("file").is_none());
+
+        let generation = cache.generation();
+        cache.insert("file", Metadata::new(EntryMode::FILE), generation);
+        assert!(cache.get("file").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_meta_cache_expire() {
+        let inner = Operator::new(Memory::default()).unwrap().finish();
+        let op = inner
+            .clone()
+            .layer(MetaCacheLayer::default().with_ttl(Duration::from_millis(100)));
+
+        inner.write("file", "hello").await.unwrap();
+        assert_eq!(op.stat("file").await.unwrap().content_length(), 5);
+
+        inner.write("file", "hello, world").await.unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(op.stat("file").await.unwrap().content_length(), 12);
+    }
+
+    #[tokio::test]
+    async fn test_meta_cache_invalidate_partially_flushed() {
+        let cache = Arc::new(MetaCache {
+            cache: Cache::builder().support_invalidation_closures().build(),
+            generation: Mutex::new(0),
+        });
+        let inner = oio::BatchDeleter::new(FlakyBatchDelete::default());
+        let mut d = MetaCacheWrapper::new(inner, "", cache.clone());
+
+        for path in ["a", "b", "c"] {
+            oio::Delete::delete(&mut d, path, OpDelete::new()).unwrap();
+        }
+        assert_eq!(oio::Delete::flush(&mut d).await.unwrap(), 2);
+
+        // `b` still exists after the first flush, a stat could cache it.
+        cache.insert("b", Metadata::new(EntryMode::FILE), cache.generation());
+        assert_eq!(oio::Delete::flush(&mut d).await.unwrap(), 1);
+        assert!(cache.get("b").is_none());
+    }
+}
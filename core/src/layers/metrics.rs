@@ -124,6 +124,27 @@ impl observe::MetricsIntercept for MetricsInterceptor {
         histogram!(observe::METRIC_OPERATION_DURATION_SECONDS.name(), labels).record(duration)
     }
 
+    fn observe_operation_ttfb_seconds(
+        &self,
+        scheme: Scheme,
+        namespace: Arc<String>,
+        root: Arc<String>,
+        path: &str,
+        op: Operation,
+        duration: Duration,
+    ) {
+        let labels = OperationLabels {
+            scheme,
+            namespace,
+            root,
+            path,
+            operation: op,
+            error: None,
+        }
+        .into_labels(self.path_label_level);
+        histogram!(observe::METRIC_OPERATION_TTFB_SECONDS.name(), labels).record(duration)
+    }
+
     fn observe_operation_bytes(
         &self,
         scheme: Scheme,
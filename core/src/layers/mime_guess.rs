@@ -15,6 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
 use crate::raw::*;
 use crate::Result;
 
@@ -42,6 +46,17 @@ use crate::Result;
 /// when [mime_guess::from_path::first_raw](https://docs.rs/mime_guess/latest/mime_guess/struct.MimeGuess.html#method.first_raw)
 /// returns `None`).
 ///
+/// # Custom Mime
+///
+/// Users can set content types for extensions via [`MimeGuessLayer::with_mime`], which take
+/// precedence over the ones provided by `mime_guess`. Extensions are matched case-insensitively.
+///
+/// # Write
+///
+/// `content_type` will only be set on write if the service supports `write_with_content_type`,
+/// so the same layer can be used across services like s3, gcs, azblob and webdav without failing
+/// on services that can't store it.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -53,54 +68,91 @@ use crate::Result;
 ///
 /// # fn main() -> Result<()> {
 /// let _ = Operator::new(services::Memory::default())?
-///     .layer(MimeGuessLayer::default())
+///     .layer(
+///         MimeGuessLayer::default()
+///             .with_mime("wasm", "application/wasm")
+///             .with_mime("webmanifest", "application/manifest+json"),
+///     )
 ///     .finish();
 /// Ok(())
 /// # }
 /// ```
 #[derive(Debug, Clone, Default)]
 #[non_exhaustive]
-pub struct MimeGuessLayer {}
+pub struct MimeGuessLayer {
+    mimes: HashMap<String, String>,
+}
+
+impl MimeGuessLayer {
+    /// Set the content type for files with given extension like `wasm` or `.wasm`.
+    ///
+    /// Custom content types take precedence over the ones guessed by `mime_guess`.
+    pub fn with_mime(mut self, ext: &str, mime: &str) -> Self {
+        let ext = ext.trim_start_matches('.').to_ascii_lowercase();
+        self.mimes.insert(ext, mime.to_string());
+        self
+    }
+}
 
 impl<A: Access> Layer<A> for MimeGuessLayer {
     type LayeredAccess = MimeGuessAccessor<A>;
 
     fn layer(&self, inner: A) -> Self::LayeredAccess {
-        MimeGuessAccessor(inner)
+        let write_with_content_type = inner.info().full_capability().write_with_content_type;
+
+        MimeGuessAccessor {
+            inner,
+            mimes: Arc::new(self.mimes.clone()),
+            write_with_content_type,
+        }
     }
 }
 
 #[derive(Clone, Debug)]
-pub struct MimeGuessAccessor<A: Access>(A);
-
-fn mime_from_path(path: &str) -> Option<&str> {
-    mime_guess::from_path(path).first_raw()
+pub struct MimeGuessAccessor<A: Access> {
+    inner: A,
+    mimes: Arc<HashMap<String, String>>,
+    write_with_content_type: bool,
 }
 
-fn opwrite_with_mime(path: &str, op: OpWrite) -> OpWrite {
-    if op.content_type().is_some() {
-        return op;
-    }
+impl<A: Access> MimeGuessAccessor<A> {
+    fn mime_from_path<'a>(&'a self, path: &'a str) -> Option<&'a str> {
+        let custom = Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| self.mimes.get(&ext.to_ascii_lowercase()));
+        if let Some(mime) = custom {
+            return Some(mime);
+        }
 
-    if let Some(mime) = mime_from_path(path) {
-        return op.with_content_type(mime);
+        mime_guess::from_path(path).first_raw()
     }
 
-    op
-}
-
-fn rpstat_with_mime(path: &str, rp: RpStat) -> RpStat {
-    rp.map_metadata(|metadata| {
-        if metadata.content_type().is_some() {
-            return metadata;
+    fn opwrite_with_mime(&self, path: &str, op: OpWrite) -> OpWrite {
+        if !self.write_with_content_type || op.content_type().is_some() {
+            return op;
         }
 
-        if let Some(mime) = mime_from_path(path) {
-            return metadata.with_content_type(mime.into());
+        if let Some(mime) = self.mime_from_path(path) {
+            return op.with_content_type(mime);
         }
 
-        metadata
-    })
+        op
+    }
+
+    fn rpstat_with_mime(&self, path: &str, rp: RpStat) -> RpStat {
+        rp.map_metadata(|metadata| {
+            if metadata.content_type().is_some() {
+                return metadata;
+            }
+
+            if let Some(mime) = self.mime_from_path(path) {
+                return metadata.with_content_type(mime.into());
+            }
+
+            metadata
+        })
+    }
 }
 
 impl<A: Access> LayeredAccess for MimeGuessAccessor<A> {
@@ -115,7 +167,7 @@ impl<A: Access> LayeredAccess for MimeGuessAccessor<A> {
     type BlockingDeleter = A::BlockingDeleter;
 
     fn inner(&self) -> &Self::Inner {
-        &self.0
+        &self.inner
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
@@ -124,7 +176,7 @@ impl<A: Access> LayeredAccess for MimeGuessAccessor<A> {
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         self.inner()
-            .write(path, opwrite_with_mime(path, args))
+            .write(path, self.opwrite_with_mime(path, args))
             .await
     }
 
@@ -132,7 +184,7 @@ impl<A: Access> LayeredAccess for MimeGuessAccessor<A> {
         self.inner()
             .stat(path, args)
             .await
-            .map(|rp| rpstat_with_mime(path, rp))
+            .map(|rp| self.rpstat_with_mime(path, rp))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
@@ -149,13 +201,13 @@ impl<A: Access> LayeredAccess for MimeGuessAccessor<A> {
 
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
         self.inner()
-            .blocking_write(path, opwrite_with_mime(path, args))
+            .blocking_write(path, self.opwrite_with_mime(path, args))
     }
 
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         self.inner()
             .blocking_stat(path, args)
-            .map(|rp| rpstat_with_mime(path, rp))
+            .map(|rp| self.rpstat_with_mime(path, rp))
     }
 
     fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
@@ -223,6 +275,36 @@ mod tests {
         assert_eq!(entries[2].content_type(), Some(CUSTOM));
     }
 
+    #[tokio::test]
+    async fn test_custom_mime() {
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(
+                MimeGuessLayer::default()
+                    .with_mime(".asdfghjkl", CUSTOM)
+                    .with_mime("HTM", CUSTOM),
+            )
+            .finish();
+
+        op.write("test0.html", DATA).await.unwrap();
+        assert_eq!(
+            op.stat("test0.html").await.unwrap().content_type(),
+            Some(HTML)
+        );
+
+        op.write("test1.ASDFGHJKL", DATA).await.unwrap();
+        assert_eq!(
+            op.stat("test1.ASDFGHJKL").await.unwrap().content_type(),
+            Some(CUSTOM)
+        );
+
+        op.write("test2.htm", DATA).await.unwrap();
+        assert_eq!(
+            op.stat("test2.htm").await.unwrap().content_type(),
+            Some(CUSTOM)
+        );
+    }
+
     #[test]
     fn test_blocking() {
         let op = Operator::new(Memory::default())
@@ -0,0 +1,941 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::mem;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use bytes::Bytes;
+use tokio::sync::mpsc;
+
+use crate::raw::*;
+use crate::*;
+
+/// Policy to handle the failures of mirroring to the secondary operator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MirrorFailurePolicy {
+    /// Log the failure and continue, the primary operation will succeed.
+    #[default]
+    Log,
+    /// Fail the primary operation with the error returned by secondary.
+    ///
+    /// Writers will keep returning the error until they are aborted.
+    ///
+    /// In background, this policy only takes effect when the content of a
+    /// write exceeds the buffer limit, since the primary operation has
+    /// returned before mirroring.
+    Fail,
+}
+
+/// Mirror every successful write and delete to a secondary operator.
+///
+/// This is useful for migrating between storage providers without downtime:
+/// mirror all changes to the new storage while copying existing data, and
+/// switch to the new storage after they are in sync.
+///
+/// # Notes
+///
+/// `create_dir`, `write`, `copy`, `rename`, `concat` and `delete` will be mirrored,
+/// only after they succeeded on the primary operator. Reads are always served
+/// by the primary operator.
+///
+/// By default, operations are mirrored synchronously: writes are streamed to
+/// both operators and other operations are applied to secondary before
+/// returning. Failures on secondary are handled by [`MirrorFailurePolicy`].
+///
+/// Writers close primary before secondary, so that secondary never has content
+/// that primary doesn't have. The secondary writer is aborted if primary failed
+/// to close. Under [`MirrorFailurePolicy::Fail`], a write that failed to be
+/// mirrored while closing has been committed to primary, the error is returned
+/// to tell users that secondary is out of sync. Once a writer returned a mirror
+/// error, it must be aborted.
+///
+/// With [`MirrorLayer::with_background`], operations are sent to a bounded
+/// queue and applied by a background task, primary operations will wait if
+/// the queue is full. In this mode:
+///
+/// - The whole content of writes will be buffered in memory until the primary
+///   writer is closed. Writes larger than [`MirrorLayer::with_buffer_limit`]
+///   will not be mirrored and are handled by [`MirrorFailurePolicy`].
+/// - Failures on secondary will always be logged.
+/// - Pending operations will be dropped if the operator is dropped.
+///
+/// Blocking operations are always mirrored synchronously.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::MirrorFailurePolicy;
+/// # use opendal::layers::MirrorLayer;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let secondary = Operator::new(services::Memory::default())?.finish();
+///
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(MirrorLayer::new(secondary).with_failure_policy(MirrorFailurePolicy::Fail))
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MirrorLayer {
+    secondary: Accessor,
+    policy: MirrorFailurePolicy,
+    queue_size: Option<usize>,
+    buffer_limit: usize,
+    executor: Executor,
+}
+
+/// The default limit of content buffered by a writer while mirroring in background.
+const DEFAULT_BUFFER_LIMIT: usize = 64 * 1024 * 1024;
+
+impl MirrorLayer {
+    /// Create a new mirror layer that mirrors to given operator.
+    pub fn new(secondary: Operator) -> Self {
+        Self {
+            secondary: secondary.into_inner(),
+            policy: MirrorFailurePolicy::default(),
+            queue_size: None,
+            buffer_limit: DEFAULT_BUFFER_LIMIT,
+            executor: Executor::new(),
+        }
+    }
+
+    /// Set the policy to handle failures on secondary.
+    ///
+    /// Default to [`MirrorFailurePolicy::Log`].
+    pub fn with_failure_policy(mut self, policy: MirrorFailurePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Mirror operations in background with a bounded queue of given size.
+    pub fn with_background(mut self, queue_size: usize) -> Self {
+        self.queue_size = Some(queue_size.max(1));
+        self
+    }
+
+    /// Set the max size of content a writer buffers while mirroring in background.
+    ///
+    /// Default to 64 MiB.
+    pub fn with_buffer_limit(mut self, limit: usize) -> Self {
+        self.buffer_limit = limit;
+        self
+    }
+
+    /// Set the executor to run the background task.
+    ///
+    /// Default to the executor enabled by feature flags.
+    pub fn with_executor(mut self, executor: Executor) -> Self {
+        self.executor = executor;
+        self
+    }
+}
+
+impl Debug for MirrorLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MirrorLayer")
+            .field("secondary", &self.secondary)
+            .field("policy", &self.policy)
+            .field("queue_size", &self.queue_size)
+            .field("buffer_limit", &self.buffer_limit)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> Layer<A> for MirrorLayer {
+    type LayeredAccess = MirrorAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        MirrorAccessor {
+            inner,
+            core: Arc::new(MirrorCore {
+                secondary: self.secondary.clone(),
+                policy: self.policy,
+                queue_size: self.queue_size,
+                buffer_limit: self.buffer_limit,
+                executor: self.executor.clone(),
+                worker: OnceLock::new(),
+            }),
+        }
+    }
+}
+
+/// MirrorTask is an operation that succeeded on primary and needs to be
+/// applied to secondary.
+enum MirrorTask {
+    CreateDir(String),
+    Write(String, Box<OpWrite>, Buffer),
+    Copy(String, String, OpCopy),
+    Rename(String, String, OpRename),
+    Concat(String, OpConcat),
+    Delete(Vec<(String, OpDelete)>),
+}
+
+impl MirrorTask {
+    fn operation(&self) -> Operation {
+        match self {
+            MirrorTask::CreateDir(_) => Operation::CreateDir,
+            MirrorTask::Write(..) => Operation::Write,
+            MirrorTask::Copy(..) => Operation::Copy,
+            MirrorTask::Rename(..) => Operation::Rename,
+            MirrorTask::Concat(..) => Operation::Concat,
+            MirrorTask::Delete(_) => Operation::Delete,
+        }
+    }
+
+    fn path(&self) -> String {
+        match self {
+            MirrorTask::CreateDir(path)
+            | MirrorTask::Write(path, ..)
+            | MirrorTask::Concat(path, _) => path.clone(),
+            MirrorTask::Copy(from, to, _) | MirrorTask::Rename(from, to, _) => {
+                format!("{from} -> {to}")
+            }
+            MirrorTask::Delete(paths) => paths
+                .iter()
+                .map(|(p, _)| p.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+
+    async fn run(self, acc: &Accessor) -> Result<()> {
+        match self {
+            MirrorTask::CreateDir(path) => {
+                acc.create_dir(&path, OpCreateDir::new()).await?;
+            }
+            MirrorTask::Write(path, args, bs) => {
+                let (_, mut w) = acc.write(&path, *args).await?;
+                oio::Write::write(&mut w, bs).await?;
+                oio::Write::close(&mut w).await?;
+            }
+            MirrorTask::Copy(from, to, args) => {
+                acc.copy(&from, &to, args).await?;
+            }
+            MirrorTask::Rename(from, to, args) => {
+                acc.rename(&from, &to, args).await?;
+            }
+            MirrorTask::Concat(path, args) => {
+                acc.concat(&path, args).await?;
+            }
+            MirrorTask::Delete(paths) => {
+                let (_, mut d) = acc.delete().await?;
+                let mut queued = paths.len();
+                for (path, args) in paths {
+                    oio::Delete::delete(&mut d, &path, args)?;
+                }
+                while queued > 0 {
+                    let n = oio::Delete::flush(&mut d).await?;
+                    if n == 0 {
+                        break;
+                    }
+                    queued -= n.min(queued);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn run_blocking(self, acc: &Accessor) -> Result<()> {
+        match self {
+            MirrorTask::CreateDir(path) => {
+                acc.blocking_create_dir(&path, OpCreateDir::new())?;
+            }
+            MirrorTask::Write(path, args, bs) => {
+                let (_, mut w) = acc.blocking_write(&path, *args)?;
+                oio::BlockingWrite::write(&mut w, bs)?;
+                oio::BlockingWrite::close(&mut w)?;
+            }
+            MirrorTask::Copy(from, to, args) => {
+                acc.blocking_copy(&from, &to, args)?;
+            }
+            MirrorTask::Rename(from, to, args) => {
+                acc.blocking_rename(&from, &to, args)?;
+            }
+            MirrorTask::Concat(..) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "concat doesn't have a blocking version",
+                ));
+            }
+            MirrorTask::Delete(paths) => {
+                let (_, mut d) = acc.blocking_delete()?;
+                let mut queued = paths.len();
+                for (path, args) in paths {
+                    oio::BlockingDelete::delete(&mut d, &path, args)?;
+                }
+                while queued > 0 {
+                    let n = oio::BlockingDelete::flush(&mut d)?;
+                    if n == 0 {
+                        break;
+                    }
+                    queued -= n.min(queued);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// MirrorWorker applies mirror tasks in background.
+struct MirrorWorker {
+    sender: mpsc::Sender<MirrorTask>,
+    /// Hold the task to keep it running, the task will be canceled while dropping.
+    _task: Mutex<Task<()>>,
+}
+
+impl MirrorWorker {
+    fn new(secondary: Accessor, executor: &Executor, queue_size: usize) -> Self {
+        let (sender, mut receiver) = mpsc::channel::<MirrorTask>(queue_size);
+        let task = executor.execute(async move {
+            while let Some(task) = receiver.recv().await {
+                let (op, path) = (task.operation(), task.path());
+                if let Err(err) = task.run(&secondary).await {
+                    log::warn!("mirror {op} on {path} to secondary failed: {err}");
+                }
+            }
+        });
+
+        Self {
+            sender,
+            _task: Mutex::new(task),
+        }
+    }
+}
+
+struct MirrorCore {
+    secondary: Accessor,
+    policy: MirrorFailurePolicy,
+    queue_size: Option<usize>,
+    buffer_limit: usize,
+    executor: Executor,
+    worker: OnceLock<MirrorWorker>,
+}
+
+impl MirrorCore {
+    fn is_background(&self) -> bool {
+        self.queue_size.is_some()
+    }
+
+    /// Handle the error returned by secondary according to the policy.
+    fn handle(&self, op: Operation, path: &str, res: Result<()>) -> Result<()> {
+        let Err(err) = res else {
+            return Ok(());
+        };
+
+        match self.policy {
+            MirrorFailurePolicy::Fail => Err(err.with_context("mirror", "secondary")),
+            MirrorFailurePolicy::Log => {
+                log::warn!("mirror {op} on {path} to secondary failed: {err}");
+                Ok(())
+            }
+        }
+    }
+
+    async fn mirror(&self, task: MirrorTask) -> Result<()> {
+        if let Some(queue_size) = self.queue_size {
+            let worker = self.worker.get_or_init(|| {
+                MirrorWorker::new(self.secondary.clone(), &self.executor, queue_size)
+            });
+            if let Err(mpsc::error::SendError(task)) = worker.sender.send(task).await {
+                log::warn!(
+                    "mirror {} on {} to secondary failed: background task exited",
+                    task.operation(),
+                    task.path()
+                );
+            }
+            return Ok(());
+        }
+
+        let (op, path) = (task.operation(), task.path());
+        let res = task.run(&self.secondary).await;
+        self.handle(op, &path, res)
+    }
+
+    fn blocking_mirror(&self, task: MirrorTask) -> Result<()> {
+        let (op, path) = (task.operation(), task.path());
+        let res = task.run_blocking(&self.secondary);
+        self.handle(op, &path, res)
+    }
+}
+
+pub struct MirrorAccessor<A: Access> {
+    inner: A,
+    core: Arc<MirrorCore>,
+}
+
+impl<A: Access> Debug for MirrorAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MirrorAccessor")
+            .field("inner", &self.inner)
+            .field("secondary", &self.core.secondary)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> LayeredAccess for MirrorAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = MirrorWriter<A::Writer, oio::Writer>;
+    type BlockingWriter = MirrorWriter<A::BlockingWriter, oio::BlockingWriter>;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+    type Deleter = MirrorDeleter<A::Deleter>;
+    type BlockingDeleter = MirrorDeleter<A::BlockingDeleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let rp = self.inner.create_dir(path, args).await?;
+        self.core
+            .mirror(MirrorTask::CreateDir(path.to_string()))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let (rp, w) = self.inner.write(path, args.clone()).await?;
+
+        let secondary = if self.core.is_background() {
+            None
+        } else {
+            match self.core.secondary.write(path, args.clone()).await {
+                Ok((_, w)) => Some(w),
+                Err(err) => {
+                    self.core.handle(Operation::Write, path, Err(err))?;
+                    None
+                }
+            }
+        };
+
+        Ok((
+            rp,
+            MirrorWriter::new(w, secondary, self.core.clone(), path, args),
+        ))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let rp = self.inner.copy(from, to, args.clone()).await?;
+        self.core
+            .mirror(MirrorTask::Copy(from.to_string(), to.to_string(), args))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let rp = self.inner.rename(from, to, args.clone()).await?;
+        self.core
+            .mirror(MirrorTask::Rename(from.to_string(), to.to_string(), args))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let rp = self.inner.concat(path, args.clone()).await?;
+        self.core
+            .mirror(MirrorTask::Concat(path.to_string(), args))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        let (rp, d) = self.inner.delete().await?;
+        Ok((rp, MirrorDeleter::new(d, self.core.clone())))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let rp = self.inner.blocking_create_dir(path, args)?;
+        self.core
+            .blocking_mirror(MirrorTask::CreateDir(path.to_string()))?;
+        Ok(rp)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let (rp, w) = self.inner.blocking_write(path, args.clone())?;
+
+        let secondary = match self.core.secondary.blocking_write(path, args.clone()) {
+            Ok((_, w)) => Some(w),
+            Err(err) => {
+                self.core.handle(Operation::BlockingWrite, path, Err(err))?;
+                None
+            }
+        };
+
+        Ok((
+            rp,
+            MirrorWriter::new(w, secondary, self.core.clone(), path, args),
+        ))
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let rp = self.inner.blocking_copy(from, to, args.clone())?;
+        self.core
+            .blocking_mirror(MirrorTask::Copy(from.to_string(), to.to_string(), args))?;
+        Ok(rp)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let rp = self.inner.blocking_rename(from, to, args.clone())?;
+        self.core
+            .blocking_mirror(MirrorTask::Rename(from.to_string(), to.to_string(), args))?;
+        Ok(rp)
+    }
+
+    fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
+        let (rp, d) = self.inner.blocking_delete()?;
+        Ok((rp, MirrorDeleter::new(d, self.core.clone())))
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// MirrorWriter writes to primary and secondary at the same time, or buffers
+/// the content to mirror in background.
+pub struct MirrorWriter<W, S> {
+    inner: W,
+    /// The writer of secondary, `None` if mirroring in background or the
+    /// secondary failed and the failure is ignored.
+    secondary: Option<S>,
+    core: Arc<MirrorCore>,
+    path: String,
+    args: OpWrite,
+    /// The content to mirror in background, `None` if the content exceeds
+    /// the buffer limit.
+    buf: Option<Vec<Bytes>>,
+    buf_size: usize,
+    /// Whether mirroring failed under [`MirrorFailurePolicy::Fail`].
+    failed: bool,
+}
+
+impl<W, S> MirrorWriter<W, S> {
+    fn new(
+        inner: W,
+        secondary: Option<S>,
+        core: Arc<MirrorCore>,
+        path: &str,
+        args: OpWrite,
+    ) -> Self {
+        Self {
+            inner,
+            secondary,
+            core,
+            path: path.to_string(),
+            args,
+            buf: Some(Vec::new()),
+            buf_size: 0,
+            failed: false,
+        }
+    }
+
+    /// Return an error if mirroring has failed, the writer must be aborted.
+    fn check_failed(&self) -> Result<()> {
+        if self.failed {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "mirror to secondary has failed, the writer must be aborted",
+            )
+            .with_context("mirror", "secondary")
+            .with_context("path", &self.path));
+        }
+        Ok(())
+    }
+
+    /// Handle the error returned by secondary writer.
+    ///
+    /// The secondary writer will be dropped if the error is ignored, or kept
+    /// until the writer is aborted if the error is returned.
+    fn handle(&mut self, op: Operation, res: Result<()>) -> Result<()> {
+        if res.is_err() {
+            match self.core.policy {
+                MirrorFailurePolicy::Fail => self.failed = true,
+                MirrorFailurePolicy::Log => self.secondary = None,
+            }
+        }
+        self.core.handle(op, &self.path, res)
+    }
+
+    /// Buffer the content to mirror in background.
+    fn buffer(&mut self, bs: Buffer) -> Result<()> {
+        let Some(buf) = self.buf.as_mut() else {
+            return Ok(());
+        };
+
+        if self.buf_size + bs.len() <= self.core.buffer_limit {
+            self.buf_size += bs.len();
+            buf.extend(bs);
+            return Ok(());
+        }
+
+        self.buf = None;
+        self.buf_size = 0;
+        let err = Error::new(
+            ErrorKind::Unsupported,
+            "content exceeds the buffer limit of background mirroring",
+        )
+        .with_context("buffer_limit", self.core.buffer_limit.to_string());
+        self.handle(Operation::WriterWrite, Err(err))
+    }
+}
+
+impl<W: oio::Write> oio::Write for MirrorWriter<W, oio::Writer> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.check_failed()?;
+        self.inner.write(bs.clone()).await?;
+
+        if self.core.is_background() {
+            self.buffer(bs)?;
+        } else if let Some(w) = self.secondary.as_mut() {
+            let res = w.write(bs).await;
+            self.handle(Operation::WriterWrite, res)?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.check_failed()?;
+
+        if self.core.is_background() {
+            self.inner.close().await?;
+
+            let Some(buf) = self.buf.take() else {
+                return Ok(());
+            };
+            self.buf_size = 0;
+            let bs = Buffer::from(buf);
+            return self
+                .core
+                .mirror(MirrorTask::Write(
+                    self.path.clone(),
+                    Box::new(self.args.clone()),
+                    bs,
+                ))
+                .await;
+        }
+
+        // Primary is the source of truth, close it first so that secondary
+        // never has content that primary doesn't have.
+        if let Err(err) = self.inner.close().await {
+            if let Some(mut w) = self.secondary.take() {
+                // The content is not committed to secondary, it's safe to ignore the error.
+                let _ = w.abort().await;
+            }
+            return Err(err);
+        }
+        if let Some(w) = self.secondary.as_mut() {
+            let res = w.close().await;
+            self.handle(Operation::WriterClose, res)?;
+        }
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buf = None;
+        self.buf_size = 0;
+        self.failed = false;
+        if let Some(mut w) = self.secondary.take() {
+            // The content is not committed to secondary, it's safe to ignore the error.
+            let _ = w.abort().await;
+        }
+        self.inner.abort().await
+    }
+}
+
+impl<W: oio::BlockingWrite> oio::BlockingWrite for MirrorWriter<W, oio::BlockingWriter> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.check_failed()?;
+        self.inner.write(bs.clone())?;
+
+        if let Some(w) = self.secondary.as_mut() {
+            let res = w.write(bs);
+            self.handle(Operation::BlockingWriterWrite, res)?;
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.check_failed()?;
+
+        // Primary is the source of truth, close it first so that secondary
+        // never has content that primary doesn't have.
+        if let Err(err) = self.inner.close() {
+            // Drop the secondary writer without closing to discard the content.
+            self.secondary = None;
+            return Err(err);
+        }
+        if let Some(w) = self.secondary.as_mut() {
+            let res = w.close();
+            self.handle(Operation::BlockingWriterClose, res)?;
+        }
+        Ok(())
+    }
+}
+
+/// MirrorDeleter mirrors paths that have been deleted on primary after flush.
+///
+/// The count returned by flush can't tell which paths have been deleted:
+/// deleters may dedup, reorder or partially fail the queued paths. So the
+/// queued paths are mirrored together only after primary has nothing pending.
+pub struct MirrorDeleter<D> {
+    inner: D,
+    core: Arc<MirrorCore>,
+    queued: Vec<(String, OpDelete)>,
+    /// The number of queued paths that flush hasn't reported as deleted yet.
+    pending: usize,
+}
+
+impl<D> MirrorDeleter<D> {
+    fn new(inner: D, core: Arc<MirrorCore>) -> Self {
+        Self {
+            inner,
+            core,
+            queued: Vec::new(),
+            pending: 0,
+        }
+    }
+
+    fn queue(&mut self, path: &str, args: OpDelete) {
+        self.queued.push((path.to_string(), args));
+        self.pending += 1;
+    }
+
+    /// Take the queued paths if primary has deleted all of them after a flush
+    /// that deleted `n` paths.
+    ///
+    /// Flushing nothing means primary has processed all queued paths, which
+    /// also covers duplicated paths that are deleted only once.
+    fn take_deleted(&mut self, n: usize) -> Option<Vec<(String, OpDelete)>> {
+        self.pending = self.pending.saturating_sub(n);
+        if self.queued.is_empty() || (n != 0 && self.pending != 0) {
+            return None;
+        }
+
+        self.pending = 0;
+        Some(mem::take(&mut self.queued))
+    }
+}
+
+impl<D: oio::Delete> oio::Delete for MirrorDeleter<D> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.inner.delete(path, args.clone())?;
+        self.queue(path, args);
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<usize> {
+        let n = self.inner.flush().await?;
+
+        if let Some(deleted) = self.take_deleted(n) {
+            self.core.mirror(MirrorTask::Delete(deleted)).await?;
+        }
+        Ok(n)
+    }
+}
+
+impl<D: oio::BlockingDelete> oio::BlockingDelete for MirrorDeleter<D> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.inner.delete(path, args.clone())?;
+        self.queue(path, args);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<usize> {
+        let n = self.inner.flush()?;
+
+        if let Some(deleted) = self.take_deleted(n) {
+            self.core.blocking_mirror(MirrorTask::Delete(deleted))?;
+        }
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// A batch deleter that fails to delete `b` the first time.
+    #[derive(Default)]
+    struct FlakyBatchDelete {
+        failed: AtomicBool,
+    }
+
+    impl oio::BatchDelete for FlakyBatchDelete {
+        async fn delete_once(&self, _: String, _: OpDelete) -> Result<()> {
+            Ok(())
+        }
+
+        async fn delete_batch(
+            &self,
+            batch: Vec<(String, OpDelete)>,
+        ) -> Result<oio::BatchDeleteResult> {
+            let mut result = oio::BatchDeleteResult::default();
+            for (path, args) in batch {
+                if path == "b" && !self.failed.swap(true, Ordering::SeqCst) {
+                    let err = Error::new(ErrorKind::Unexpected, "injected").set_temporary();
+                    result.failed.push((path, args, err));
+                } else {
+                    result.succeeded.push((path, args));
+                }
+            }
+            Ok(result)
+        }
+    }
+
+    async fn new_deleter(
+        paths: &[&str],
+    ) -> (MirrorDeleter<oio::BatchDeleter<FlakyBatchDelete>>, Operator) {
+        let secondary = Operator::new(Memory::default()).unwrap().finish();
+        for path in paths {
+            secondary.write(path, "hello").await.unwrap();
+        }
+
+        let core = Arc::new(MirrorCore {
+            secondary: secondary.clone().into_inner(),
+            policy: MirrorFailurePolicy::Fail,
+            queue_size: None,
+            buffer_limit: DEFAULT_BUFFER_LIMIT,
+            executor: Executor::new(),
+            worker: OnceLock::new(),
+        });
+        let d = oio::BatchDeleter::new(FlakyBatchDelete::default());
+        (MirrorDeleter::new(d, core), secondary)
+    }
+
+    fn new_operator(layer: impl FnOnce(Operator) -> MirrorLayer) -> (Operator, Operator) {
+        let secondary = Operator::new(Memory::default()).unwrap().finish();
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(layer(secondary.clone()))
+            .finish();
+        (op, secondary)
+    }
+
+    #[tokio::test]
+    async fn test_mirror_sync() {
+        let (op, secondary) = new_operator(MirrorLayer::new);
+
+        op.write("file", "hello").await.unwrap();
+        assert_eq!(secondary.read("file").await.unwrap().to_vec(), b"hello");
+
+        op.delete("file").await.unwrap();
+        assert!(!secondary.exists("file").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mirror_background() {
+        let (op, secondary) = new_operator(|op| MirrorLayer::new(op).with_background(4));
+
+        let mut w = op.writer("file").await.unwrap();
+        w.write("hello, ").await.unwrap();
+        w.write("world").await.unwrap();
+        w.close().await.unwrap();
+
+        for _ in 0..100 {
+            if secondary.exists("file").await.unwrap() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(
+            secondary.read("file").await.unwrap().to_vec(),
+            b"hello, world"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mirror_background_exceeds_buffer_limit() {
+        let (op, secondary) = new_operator(|op| {
+            MirrorLayer::new(op)
+                .with_background(4)
+                .with_buffer_limit(8)
+                .with_failure_policy(MirrorFailurePolicy::Fail)
+        });
+
+        let mut w = op.writer("file").await.unwrap();
+        w.write("hello, ").await.unwrap();
+        assert!(w.write("world").await.is_err());
+        // The writer keeps failing until aborted.
+        assert!(w.close().await.is_err());
+        w.abort().await.unwrap();
+
+        assert!(!op.exists("file").await.unwrap());
+        assert!(!secondary.exists("file").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mirror_delete_partially_failed() {
+        let (mut d, secondary) = new_deleter(&["a", "b", "c"]).await;
+        for path in ["a", "b", "c"] {
+            oio::Delete::delete(&mut d, path, OpDelete::new()).unwrap();
+        }
+
+        // `b` is still pending on primary, nothing should be mirrored.
+        assert_eq!(oio::Delete::flush(&mut d).await.unwrap(), 2);
+        for path in ["a", "b", "c"] {
+            assert!(secondary.exists(path).await.unwrap());
+        }
+
+        assert_eq!(oio::Delete::flush(&mut d).await.unwrap(), 1);
+        for path in ["a", "b", "c"] {
+            assert!(!secondary.exists(path).await.unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mirror_delete_duplicated_paths() {
+        let (mut d, secondary) = new_deleter(&["a", "c"]).await;
+        for path in ["a", "a", "c"] {
+            oio::Delete::delete(&mut d, path, OpDelete::new()).unwrap();
+        }
+
+        // Duplicated paths are deleted only once, `c` must not be left behind.
+        assert_eq!(oio::Delete::flush(&mut d).await.unwrap(), 2);
+        assert_eq!(oio::Delete::flush(&mut d).await.unwrap(), 0);
+        for path in ["a", "c"] {
+            assert!(!secondary.exists(path).await.unwrap());
+        }
+    }
+}
@@ -32,6 +32,25 @@ pub use concurrent_limit::ConcurrentLimitLayer;
 mod immutable_index;
 pub use immutable_index::ImmutableIndexLayer;
 
+mod hooks;
+pub use hooks::HookContext;
+pub use hooks::HookEvent;
+pub use hooks::Hooks;
+pub use hooks::HooksLayer;
+
+mod mount;
+pub use mount::MountLayer;
+
+mod multi_bucket;
+pub use multi_bucket::MultiBucketLayer;
+
+mod fallback;
+pub use fallback::FallbackLayer;
+
+mod mirror;
+pub use mirror::MirrorFailurePolicy;
+pub use mirror::MirrorLayer;
+
 mod logging;
 pub use logging::LoggingInterceptor;
 pub use logging::LoggingLayer;
@@ -49,6 +68,11 @@ mod chaos;
 #[cfg(feature = "layers-chaos")]
 pub use chaos::ChaosLayer;
 
+#[cfg(feature = "layers-meta-cache")]
+mod meta_cache;
+#[cfg(feature = "layers-meta-cache")]
+pub use self::meta_cache::MetaCacheLayer;
+
 #[cfg(feature = "layers-metrics")]
 mod metrics;
 #[cfg(feature = "layers-metrics")]
@@ -102,6 +126,22 @@ mod throttle;
 #[cfg(feature = "layers-throttle")]
 pub use self::throttle::ThrottleLayer;
 
+#[cfg(feature = "layers-compression")]
+mod compression;
+#[cfg(feature = "layers-compression")]
+pub use self::compression::CompressionAlgorithm;
+#[cfg(feature = "layers-compression")]
+pub use self::compression::CompressionLayer;
+
+#[cfg(feature = "layers-crypto")]
+mod crypto;
+#[cfg(feature = "layers-crypto")]
+pub use self::crypto::CryptoLayer;
+#[cfg(feature = "layers-crypto")]
+pub use self::crypto::KeyProvider;
+#[cfg(feature = "layers-crypto")]
+pub use self::crypto::StaticKeyProvider;
+
 #[cfg(feature = "layers-await-tree")]
 mod await_tree;
 #[cfg(feature = "layers-await-tree")]
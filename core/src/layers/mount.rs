@@ -0,0 +1,679 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use crate::raw::*;
+use crate::*;
+
+/// Mount other operators under given paths, so that operations on these paths
+/// will be routed to the mounted operators.
+///
+/// This is useful for tiered storage setups, for example, mount a memory
+/// operator at `/hot/` and a s3 operator at `/cold/` on top of a fs operator.
+///
+/// # Notes
+///
+/// - Mount paths are always treated as directories, the longest mount path
+///   that matches will be used.
+/// - Paths will be relative to the mounted operator's root, `hot/abc` will be
+///   sent as `abc` to the operator mounted at `hot/`.
+/// - While listing a directory that contains mount points, mount points will
+///   be returned as directories and entries of the underlying operator under
+///   mount points will be hidden. Recursive listing will include entries of
+///   mounted operators.
+/// - `copy` and `rename` across different mount points are not supported.
+/// - The capability of the operator is decided by the underlying operator,
+///   every mounted operator still checks its own capability.
+///
+/// # Panics
+///
+/// `mount` panics if the given path is root.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::MountLayer;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let hot = Operator::new(services::Memory::default())?.finish();
+///
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(MountLayer::new().mount("/hot/", hot))
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Default, Clone)]
+pub struct MountLayer {
+    /// Mount points sorted by the length of path in descending order.
+    mounts: Vec<(String, Accessor)>,
+}
+
+impl MountLayer {
+    /// Create a new mount layer without any mount points.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mount the operator at given path.
+    ///
+    /// Mounting at the same path again will replace the previous one.
+    pub fn mount(mut self, path: &str, op: Operator) -> Self {
+        let mut path = normalize_path(path);
+        assert_ne!(path, "/", "mount path must not be root");
+        if !path.ends_with('/') {
+            path.push('/');
+        }
+
+        self.mounts.retain(|(p, _)| p != &path);
+        self.mounts.push((path, op.into_inner()));
+        self.mounts.sort_by_key(|(p, _)| Reverse(p.len()));
+        self
+    }
+}
+
+impl Debug for MountLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MountLayer")
+            .field(
+                "mounts",
+                &self.mounts.iter().map(|(p, _)| p).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<A: Access> Layer<A> for MountLayer {
+    type LayeredAccess = MountAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        MountAccessor {
+            inner,
+            mounts: Arc::new(self.mounts.clone()),
+        }
+    }
+}
+
+/// Strip the mount path from given path, the mount root will be `/`.
+fn strip_mount<'a>(mount: &str, path: &'a str) -> &'a str {
+    match &path[mount.len()..] {
+        "" => "/",
+        v => v,
+    }
+}
+
+pub struct MountAccessor<A: Access> {
+    inner: A,
+    mounts: Arc<Vec<(String, Accessor)>>,
+}
+
+impl<A: Access> Debug for MountAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MountAccessor")
+            .field("inner", &self.inner)
+            .field(
+                "mounts",
+                &self.mounts.iter().map(|(p, _)| p).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<A: Access> MountAccessor<A> {
+    /// Find the mount point of given path.
+    ///
+    /// Returns the mount path, the mounted accessor and the path relative to it.
+    fn route<'a>(&self, path: &'a str) -> Option<(&str, &Accessor, &'a str)> {
+        self.mounts
+            .iter()
+            .find(|(p, _)| path.starts_with(p.as_str()))
+            .map(|(p, acc)| (p.as_str(), acc, strip_mount(p, path)))
+    }
+
+    fn route_pair<'a>(
+        &self,
+        op: Operation,
+        from: &'a str,
+        to: &'a str,
+    ) -> Result<Option<(&Accessor, &'a str, &'a str)>> {
+        match (self.route(from), self.route(to)) {
+            (None, None) => Ok(None),
+            (Some((fp, acc, from)), Some((tp, _, to))) if fp == tp => Ok(Some((acc, from, to))),
+            _ => Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation across mount points is not supported",
+            )
+            .with_operation(op)
+            .with_context("from", from)
+            .with_context("to", to)),
+        }
+    }
+
+    /// Returns the mount points under given path and the directories that
+    /// should be returned while listing this path.
+    fn mounts_under(
+        &self,
+        path: &str,
+        recursive: bool,
+    ) -> (Vec<&(String, Accessor)>, BTreeSet<String>) {
+        let base = if path == "/" { "" } else { path };
+
+        let mut mounts = Vec::new();
+        let mut dirs = BTreeSet::new();
+        for m in self.mounts.iter() {
+            let Some(rest) = m.0.strip_prefix(base) else {
+                continue;
+            };
+            if rest.is_empty() {
+                continue;
+            }
+
+            for (idx, _) in rest.match_indices('/') {
+                dirs.insert(format!("{base}{}", &rest[..=idx]));
+                if !recursive {
+                    break;
+                }
+            }
+            mounts.push(m);
+        }
+        (mounts, dirs)
+    }
+}
+
+impl<A: Access> LayeredAccess for MountAccessor<A> {
+    type Inner = A;
+    type Reader = oio::Reader;
+    type BlockingReader = oio::BlockingReader;
+    type Writer = oio::Writer;
+    type BlockingWriter = oio::BlockingWriter;
+    type Lister = MountLister<oio::Lister>;
+    type BlockingLister = MountLister<oio::BlockingLister>;
+    type Deleter = MountDeleter<oio::Deleter>;
+    type BlockingDeleter = MountDeleter<oio::BlockingDeleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        match self.route(path) {
+            Some((_, acc, path)) => acc.create_dir(path, args).await,
+            None => self.inner.create_dir(path, args).await,
+        }
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        match self.route(path) {
+            Some((_, acc, path)) => acc.read(path, args).await,
+            None => {
+                let (rp, r) = self.inner.read(path, args).await?;
+                Ok((rp, Box::new(r)))
+            }
+        }
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        match self.route(path) {
+            Some((_, acc, path)) => acc.write(path, args).await,
+            None => {
+                let (rp, w) = self.inner.write(path, args).await?;
+                Ok((rp, Box::new(w)))
+            }
+        }
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        match self.route_pair(Operation::Copy, from, to)? {
+            Some((acc, from, to)) => acc.copy(from, to, args).await,
+            None => self.inner.copy(from, to, args).await,
+        }
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        match self.route_pair(Operation::Rename, from, to)? {
+            Some((acc, from, to)) => acc.rename(from, to, args).await,
+            None => self.inner.rename(from, to, args).await,
+        }
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match self.route(path) {
+            Some((_, acc, path)) => acc.stat(path, args).await,
+            None => self.inner.stat(path, args).await,
+        }
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        let mut deleters = Vec::with_capacity(self.mounts.len() + 1);
+        for (p, acc) in self.mounts.iter() {
+            let d = match acc.delete().await {
+                Ok((_, d)) => Some(d),
+                Err(err) if err.kind() == ErrorKind::Unsupported => None,
+                Err(err) => return Err(err),
+            };
+            deleters.push((p.clone(), d));
+        }
+        let (rp, d) = self.inner.delete().await?;
+        deleters.push((String::new(), Some(Box::new(d) as oio::Deleter)));
+
+        Ok((rp, MountDeleter::new(deleters)))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        if let Some((p, acc, path)) = self.route(path) {
+            let (rp, l) = acc.list(path, args).await?;
+            return Ok((
+                rp,
+                MountLister::new(vec![(p.to_string(), l)], vec![], BTreeSet::new()),
+            ));
+        }
+
+        let (mounts, dirs) = self.mounts_under(path, args.recursive());
+        let (rp, l) = self.inner.list(path, args.clone()).await?;
+        let mut listers = vec![(String::new(), Box::new(l) as oio::Lister)];
+        if args.recursive() {
+            for (p, acc) in mounts.iter() {
+                let (_, l) = acc.list("/", args.clone()).await?;
+                listers.push((p.clone(), l));
+            }
+        }
+        let shadowed = mounts.into_iter().map(|(p, _)| p.clone()).collect();
+
+        Ok((rp, MountLister::new(listers, shadowed, dirs)))
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        match self.route(path) {
+            Some((_, acc, path)) => acc.presign(path, args).await,
+            None => self.inner.presign(path, args).await,
+        }
+    }
+
+    async fn tag(&self, path: &str, args: OpTag) -> Result<RpTag> {
+        match self.route(path) {
+            Some((_, acc, path)) => acc.tag(path, args).await,
+            None => self.inner.tag(path, args).await,
+        }
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        // All sources must be in the same mount point as path.
+        let mut sources = Vec::with_capacity(args.sources().len());
+        for source in args.sources() {
+            match self.route_pair(Operation::Concat, source, path)? {
+                Some((_, source, _)) => sources.push(source.to_string()),
+                None => sources.push(source.clone()),
+            }
+        }
+
+        match self.route(path) {
+            Some((_, acc, path)) => acc.concat(path, OpConcat::new(sources)).await,
+            None => self.inner.concat(path, OpConcat::new(sources)).await,
+        }
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        match self.route(path) {
+            Some((_, acc, path)) => acc.blocking_create_dir(path, args),
+            None => self.inner.blocking_create_dir(path, args),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        match self.route(path) {
+            Some((_, acc, path)) => acc.blocking_read(path, args),
+            None => {
+                let (rp, r) = self.inner.blocking_read(path, args)?;
+                Ok((rp, Box::new(r)))
+            }
+        }
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        match self.route(path) {
+            Some((_, acc, path)) => acc.blocking_write(path, args),
+            None => {
+                let (rp, w) = self.inner.blocking_write(path, args)?;
+                Ok((rp, Box::new(w)))
+            }
+        }
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        match self.route_pair(Operation::BlockingCopy, from, to)? {
+            Some((acc, from, to)) => acc.blocking_copy(from, to, args),
+            None => self.inner.blocking_copy(from, to, args),
+        }
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        match self.route_pair(Operation::BlockingRename, from, to)? {
+            Some((acc, from, to)) => acc.blocking_rename(from, to, args),
+            None => self.inner.blocking_rename(from, to, args),
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match self.route(path) {
+            Some((_, acc, path)) => acc.blocking_stat(path, args),
+            None => self.inner.blocking_stat(path, args),
+        }
+    }
+
+    fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
+        let mut deleters = Vec::with_capacity(self.mounts.len() + 1);
+        for (p, acc) in self.mounts.iter() {
+            let d = match acc.blocking_delete() {
+                Ok((_, d)) => Some(d),
+                Err(err) if err.kind() == ErrorKind::Unsupported => None,
+                Err(err) => return Err(err),
+            };
+            deleters.push((p.clone(), d));
+        }
+        let (rp, d) = self.inner.blocking_delete()?;
+        deleters.push((String::new(), Some(Box::new(d) as oio::BlockingDeleter)));
+
+        Ok((rp, MountDeleter::new(deleters)))
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        if let Some((p, acc, path)) = self.route(path) {
+            let (rp, l) = acc.blocking_list(path, args)?;
+            return Ok((
+                rp,
+                MountLister::new(vec![(p.to_string(), l)], vec![], BTreeSet::new()),
+            ));
+        }
+
+        let (mounts, dirs) = self.mounts_under(path, args.recursive());
+        let (rp, l) = self.inner.blocking_list(path, args.clone())?;
+        let mut listers = vec![(String::new(), Box::new(l) as oio::BlockingLister)];
+        if args.recursive() {
+            for (p, acc) in mounts.iter() {
+                let (_, l) = acc.blocking_list("/", args.clone())?;
+                listers.push((p.clone(), l));
+            }
+        }
+        let shadowed = mounts.into_iter().map(|(p, _)| p.clone()).collect();
+
+        Ok((rp, MountLister::new(listers, shadowed, dirs)))
+    }
+}
+
+/// MountLister will list the underlying operator first, and then all mounted
+/// operators and directories of mount points.
+pub struct MountLister<L> {
+    /// Listers with the mount path to prepend, empty for the underlying operator.
+    listers: VecDeque<(String, L)>,
+    /// Mount paths that entries of the underlying operator under them are hidden.
+    shadowed: Vec<String>,
+    /// Directories of mount points that will be returned at the end.
+    dirs: BTreeSet<String>,
+}
+
+impl<L> MountLister<L> {
+    pub(super) fn new(
+        listers: Vec<(String, L)>,
+        shadowed: Vec<String>,
+        dirs: BTreeSet<String>,
+    ) -> Self {
+        Self {
+            listers: listers.into(),
+            shadowed,
+            dirs,
+        }
+    }
+
+    fn map_entry(
+        mount: &str,
+        shadowed: &[String],
+        dirs: &BTreeSet<String>,
+        mut entry: oio::Entry,
+    ) -> Option<oio::Entry> {
+        if mount.is_empty() {
+            if shadowed
+                .iter()
+                .any(|p| entry.path().starts_with(p.as_str()))
+            {
+                return None;
+            }
+        } else {
+            let path = match entry.path() {
+                "/" => mount.to_string(),
+                v => format!("{mount}{v}"),
+            };
+            entry.set_path(&path);
+        }
+
+        (!dirs.contains(entry.path())).then_some(entry)
+    }
+
+    fn next_dir(&mut self) -> Option<oio::Entry> {
+        self.dirs
+            .pop_first()
+            .map(|p| oio::Entry::new(&p, Metadata::new(EntryMode::DIR)))
+    }
+}
+
+impl<L: oio::List> oio::List for MountLister<L> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        while let Some((mount, lister)) = self.listers.front_mut() {
+            match lister.next().await? {
+                Some(entry) => {
+                    if let Some(entry) = Self::map_entry(mount, &self.shadowed, &self.dirs, entry) {
+                        return Ok(Some(entry));
+                    }
+                }
+                None => {
+                    self.listers.pop_front();
+                }
+            }
+        }
+
+        Ok(self.next_dir())
+    }
+
+    /// Continuation tokens are only forwarded while listing a single operator,
+    /// since tokens of different operators can't be merged into one.
+    fn continuation_token(&self) -> Option<String> {
+        if self.listers.len() != 1 || !self.shadowed.is_empty() || !self.dirs.is_empty() {
+            return None;
+        }
+        self.listers
+            .front()
+            .and_then(|(_, l)| l.continuation_token())
+    }
+}
+
+impl<L: oio::BlockingList> oio::BlockingList for MountLister<L> {
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        while let Some((mount, lister)) = self.listers.front_mut() {
+            match lister.next()? {
+                Some(entry) => {
+                    if let Some(entry) = Self::map_entry(mount, &self.shadowed, &self.dirs, entry) {
+                        return Ok(Some(entry));
+                    }
+                }
+                None => {
+                    self.listers.pop_front();
+                }
+            }
+        }
+
+        Ok(self.next_dir())
+    }
+}
+
+/// MountDeleter routes paths to the deleter of their mount points.
+pub struct MountDeleter<D> {
+    /// Deleters with the mount path and the count of queued paths. The
+    /// underlying operator is the last one with an empty mount path.
+    ///
+    /// Deleter is `None` if the mounted operator doesn't support delete.
+    deleters: Vec<(String, Option<D>, usize)>,
+}
+
+impl<D> MountDeleter<D> {
+    fn new(deleters: Vec<(String, Option<D>)>) -> Self {
+        Self {
+            deleters: deleters.into_iter().map(|(p, d)| (p, d, 0)).collect(),
+        }
+    }
+
+    fn route<'a>(&mut self, path: &'a str) -> Result<(&mut D, &mut usize, &'a str)> {
+        let (mount, deleter, queued) = self
+            .deleters
+            .iter_mut()
+            .find(|(p, _, _)| path.starts_with(p.as_str()))
+            .expect("underlying deleter must match all paths");
+        let rel = strip_mount(mount, path);
+
+        match deleter {
+            Some(d) => Ok((d, queued, rel)),
+            None => Err(Error::new(
+                ErrorKind::Unsupported,
+                "mounted operator doesn't support delete",
+            )
+            .with_context("mount", mount.as_str())
+            .with_context("path", path)),
+        }
+    }
+}
+
+impl<D: oio::Delete> oio::Delete for MountDeleter<D> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        let (d, queued, path) = self.route(path)?;
+        d.delete(path, args)?;
+        *queued += 1;
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<usize> {
+        for (_, d, queued) in self.deleters.iter_mut() {
+            let Some(d) = d else { continue };
+            if *queued == 0 {
+                continue;
+            }
+
+            let n = d.flush().await?;
+            *queued -= n.min(*queued);
+            return Ok(n);
+        }
+        Ok(0)
+    }
+}
+
+impl<D: oio::BlockingDelete> oio::BlockingDelete for MountDeleter<D> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        let (d, queued, path) = self.route(path)?;
+        d.delete(path, args)?;
+        *queued += 1;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<usize> {
+        for (_, d, queued) in self.deleters.iter_mut() {
+            let Some(d) = d else { continue };
+            if *queued == 0 {
+                continue;
+            }
+
+            let n = d.flush()?;
+            *queued -= n.min(*queued);
+            return Ok(n);
+        }
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    fn new_operator() -> (Operator, Operator) {
+        let hot = Operator::new(Memory::default()).unwrap().finish();
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(MountLayer::new().mount("/data/hot", hot.clone()))
+            .finish();
+        (op, hot)
+    }
+
+    #[tokio::test]
+    async fn test_mount_route() {
+        let (op, hot) = new_operator();
+
+        op.write("data/hot/file", "hot").await.unwrap();
+        op.write("data/file", "cold").await.unwrap();
+
+        assert_eq!(hot.read("file").await.unwrap().to_vec(), b"hot");
+        assert_eq!(op.read("data/hot/file").await.unwrap().to_vec(), b"hot");
+        assert!(!hot.exists("data/file").await.unwrap());
+
+        let err = op.rename("data/file", "data/hot/file2").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        op.delete("data/hot/file").await.unwrap();
+        assert!(!hot.exists("file").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mount_list() {
+        let (op, _) = new_operator();
+
+        op.write("data/hot/file", "hot").await.unwrap();
+        op.write("data/file", "cold").await.unwrap();
+
+        let paths: Vec<_> = op
+            .list("data/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        assert!(paths.contains(&"data/file".to_string()));
+        assert!(paths.contains(&"data/hot/".to_string()));
+        assert!(!paths.contains(&"data/hot/file".to_string()));
+
+        let paths: Vec<_> = op
+            .lister_with("/")
+            .recursive(true)
+            .await
+            .unwrap()
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(paths.contains(&"data/hot/file".to_string()));
+        assert_eq!(
+            paths.iter().filter(|p| p.as_str() == "data/hot/").count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,708 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use super::mount::MountLister;
+use crate::raw::*;
+use crate::*;
+
+type BucketFactory = dyn Fn(&str) -> Result<Operator> + Send + Sync;
+type BucketLister = dyn Fn() -> BoxedStaticFuture<Result<Vec<String>>> + Send + Sync;
+
+/// The default max number of bucket operators to keep.
+const DEFAULT_MAX_OPENED_BUCKETS: usize = 256;
+
+/// Expose multiple buckets as top-level directories, so that a whole account
+/// can be browsed via one operator.
+///
+/// Paths like `bucket/path/to/file` will be routed to `path/to/file` of the
+/// operator built for `bucket` by the given factory. Operators are built on
+/// the first access and cached, at most [`MultiBucketLayer::with_max_opened_buckets`]
+/// of them will be kept and the oldest one will be dropped when exceeded.
+///
+/// # Notes
+///
+/// - Listing the root returns buckets as directories. Buckets come from
+///   [`MultiBucketLayer::bucket`] and the bucket lister set by
+///   [`MultiBucketLayer::with_bucket_lister`]. Buckets that are only accessed
+///   by path are not listed. Blocking list doesn't call the bucket lister.
+/// - Creating or removing buckets is not supported.
+/// - `copy` and `rename` across different buckets are not supported.
+/// - The underlying operator is not used for any path, it's common to use
+///   [`services::Memory`] as the underlying operator.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::layers::MultiBucketLayer;
+/// # use opendal::services;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let layer = MultiBucketLayer::new(|bucket| {
+///     let builder = services::S3::default()
+///         .bucket(bucket)
+///         .region("us-east-1");
+///     Ok(Operator::new(builder)?.finish())
+/// })
+/// .bucket("logs")
+/// .bucket("images");
+///
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(layer)
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MultiBucketLayer {
+    factory: Arc<BucketFactory>,
+    lister: Option<Arc<BucketLister>>,
+    known: BTreeSet<String>,
+    max_opened: usize,
+}
+
+impl MultiBucketLayer {
+    /// Create a new layer with the factory to build operator for given bucket.
+    pub fn new(factory: impl Fn(&str) -> Result<Operator> + Send + Sync + 'static) -> Self {
+        Self {
+            factory: Arc::new(factory),
+            lister: None,
+            known: BTreeSet::new(),
+            max_opened: DEFAULT_MAX_OPENED_BUCKETS,
+        }
+    }
+
+    /// Add a known bucket that will be returned while listing the root.
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        let bucket = bucket.trim_matches('/');
+        if !bucket.is_empty() {
+            self.known.insert(bucket.to_string());
+        }
+        self
+    }
+
+    /// Set the lister to discover available buckets while listing the root,
+    /// for example, by calling the `ListBuckets` API of services.
+    pub fn with_bucket_lister(
+        mut self,
+        lister: impl Fn() -> BoxedStaticFuture<Result<Vec<String>>> + Send + Sync + 'static,
+    ) -> Self {
+        self.lister = Some(Arc::new(lister));
+        self
+    }
+
+    /// Set the max number of bucket operators to keep, 256 by default.
+    ///
+    /// The oldest opened operator will be dropped and built again on the next
+    /// access if exceeded.
+    pub fn with_max_opened_buckets(mut self, max: usize) -> Self {
+        self.max_opened = max.max(1);
+        self
+    }
+}
+
+impl Debug for MultiBucketLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiBucketLayer")
+            .field("buckets", &self.known)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> Layer<A> for MultiBucketLayer {
+    type LayeredAccess = MultiBucketAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        MultiBucketAccessor {
+            inner,
+            buckets: Arc::new(Buckets {
+                factory: self.factory.clone(),
+                lister: self.lister.clone(),
+                known: self.known.clone(),
+                max_opened: self.max_opened,
+                opened: Mutex::default(),
+            }),
+        }
+    }
+}
+
+struct Buckets {
+    factory: Arc<BucketFactory>,
+    lister: Option<Arc<BucketLister>>,
+    known: BTreeSet<String>,
+    max_opened: usize,
+    opened: Mutex<OpenedBuckets>,
+}
+
+/// Opened accessors of buckets, with the order they are opened.
+#[derive(Default)]
+struct OpenedBuckets {
+    accessors: HashMap<String, Accessor>,
+    order: VecDeque<String>,
+}
+
+impl Buckets {
+    /// Get the accessor of given bucket, build it if not opened.
+    fn get(&self, bucket: &str) -> Result<Accessor> {
+        let mut opened = self.opened.lock().expect("lock must be valid");
+        if let Some(acc) = opened.accessors.get(bucket) {
+            return Ok(acc.clone());
+        }
+
+        let acc = (self.factory)(bucket)
+            .map_err(|err| err.with_context("bucket", bucket))?
+            .into_inner();
+        while opened.order.len() >= self.max_opened {
+            let Some(oldest) = opened.order.pop_front() else {
+                break;
+            };
+            opened.accessors.remove(&oldest);
+        }
+        opened.accessors.insert(bucket.to_string(), acc.clone());
+        opened.order.push_back(bucket.to_string());
+        Ok(acc)
+    }
+
+    /// Returns the buckets that are configured without calling bucket lister.
+    fn known(&self) -> BTreeSet<String> {
+        self.known.clone()
+    }
+
+    async fn list(&self) -> Result<BTreeSet<String>> {
+        let mut buckets = self.known();
+        if let Some(lister) = &self.lister {
+            buckets.extend(lister().await?);
+        }
+        Ok(buckets)
+    }
+}
+
+/// Split the path into the bucket and the path inside bucket.
+///
+/// Returns `None` for the root.
+fn split_bucket(path: &str) -> Option<(&str, &str)> {
+    let path = path.trim_start_matches('/');
+    if path.is_empty() {
+        return None;
+    }
+
+    match path.split_once('/') {
+        None | Some((_, "")) => Some((path.trim_end_matches('/'), "/")),
+        Some((bucket, rest)) => Some((bucket, rest)),
+    }
+}
+
+fn bucket_root_error(op: Operation, path: &str) -> Error {
+    Error::new(
+        ErrorKind::Unsupported,
+        "operation on root or bucket itself is not supported",
+    )
+    .with_operation(op)
+    .with_context("path", path)
+}
+
+pub struct MultiBucketAccessor<A: Access> {
+    inner: A,
+    buckets: Arc<Buckets>,
+}
+
+impl<A: Access> Debug for MultiBucketAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiBucketAccessor")
+            .field("inner", &self.inner)
+            .field("buckets", &self.buckets.known)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> MultiBucketAccessor<A> {
+    /// Find the accessor of given path that must be inside a bucket.
+    fn route<'a>(&self, op: Operation, path: &'a str) -> Result<(Accessor, &'a str)> {
+        match split_bucket(path) {
+            Some((_, "/")) | None => Err(bucket_root_error(op, path)),
+            Some((bucket, path)) => Ok((self.buckets.get(bucket)?, path)),
+        }
+    }
+
+    fn route_pair<'a>(
+        &self,
+        op: Operation,
+        from: &'a str,
+        to: &'a str,
+    ) -> Result<(Accessor, &'a str, &'a str)> {
+        match (split_bucket(from), split_bucket(to)) {
+            (Some((fb, _)), Some((tb, _))) if fb != tb => Err(Error::new(
+                ErrorKind::Unsupported,
+                "operation across buckets is not supported",
+            )
+            .with_operation(op)
+            .with_context("from", from)
+            .with_context("to", to)),
+            _ => {
+                let (acc, from) = self.route(op, from)?;
+                let (_, to) = self.route(op, to)?;
+                Ok((acc, from, to))
+            }
+        }
+    }
+
+    fn root_lister<L>(buckets: BTreeSet<String>) -> MountLister<L> {
+        let dirs = buckets.into_iter().map(|b| format!("{b}/")).collect();
+        MountLister::new(vec![], vec![], dirs)
+    }
+}
+
+impl<A: Access> LayeredAccess for MultiBucketAccessor<A> {
+    type Inner = A;
+    type Reader = oio::Reader;
+    type BlockingReader = oio::BlockingReader;
+    type Writer = oio::Writer;
+    type BlockingWriter = oio::BlockingWriter;
+    type Lister = MountLister<oio::Lister>;
+    type BlockingLister = MountLister<oio::BlockingLister>;
+    type Deleter = MultiBucketDeleter<oio::Deleter>;
+    type BlockingDeleter = MultiBucketDeleter<oio::BlockingDeleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let (acc, path) = self.route(Operation::CreateDir, path)?;
+        acc.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let (acc, path) = self.route(Operation::Read, path)?;
+        acc.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let (acc, path) = self.route(Operation::Write, path)?;
+        acc.write(path, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let (acc, from, to) = self.route_pair(Operation::Copy, from, to)?;
+        acc.copy(from, to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let (acc, from, to) = self.route_pair(Operation::Rename, from, to)?;
+        acc.rename(from, to, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match split_bucket(path) {
+            None => Ok(RpStat::new(Metadata::new(EntryMode::DIR))),
+            Some((bucket, path)) => self.buckets.get(bucket)?.stat(path, args).await,
+        }
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        Ok((
+            RpDelete::default(),
+            MultiBucketDeleter::new(self.buckets.clone()),
+        ))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let Some((bucket, path)) = split_bucket(path) else {
+            let buckets = self.buckets.list().await?;
+            if !args.recursive() {
+                return Ok((RpList::default(), Self::root_lister(buckets)));
+            }
+
+            let mut listers = Vec::with_capacity(buckets.len());
+            for bucket in buckets.iter() {
+                let (_, l) = self.buckets.get(bucket)?.list("/", args.clone()).await?;
+                listers.push((format!("{bucket}/"), l));
+            }
+            let dirs = buckets.into_iter().map(|b| format!("{b}/")).collect();
+            return Ok((RpList::default(), MountLister::new(listers, vec![], dirs)));
+        };
+
+        let (rp, l) = self.buckets.get(bucket)?.list(path, args).await?;
+        Ok((
+            rp,
+            MountLister::new(vec![(format!("{bucket}/"), l)], vec![], BTreeSet::new()),
+        ))
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let (acc, path) = self.route(Operation::Presign, path)?;
+        acc.presign(path, args).await
+    }
+
+    async fn tag(&self, path: &str, args: OpTag) -> Result<RpTag> {
+        let (acc, path) = self.route(Operation::Tag, path)?;
+        acc.tag(path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        // All sources must be in the same bucket as path.
+        let mut sources = Vec::with_capacity(args.sources().len());
+        for source in args.sources() {
+            let (_, source, _) = self.route_pair(Operation::Concat, source, path)?;
+            sources.push(source.to_string());
+        }
+
+        let (acc, path) = self.route(Operation::Concat, path)?;
+        acc.concat(path, OpConcat::new(sources)).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let (acc, path) = self.route(Operation::BlockingCreateDir, path)?;
+        acc.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let (acc, path) = self.route(Operation::BlockingRead, path)?;
+        acc.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let (acc, path) = self.route(Operation::BlockingWrite, path)?;
+        acc.blocking_write(path, args)
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let (acc, from, to) = self.route_pair(Operation::BlockingCopy, from, to)?;
+        acc.blocking_copy(from, to, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let (acc, from, to) = self.route_pair(Operation::BlockingRename, from, to)?;
+        acc.blocking_rename(from, to, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match split_bucket(path) {
+            None => Ok(RpStat::new(Metadata::new(EntryMode::DIR))),
+            Some((bucket, path)) => self.buckets.get(bucket)?.blocking_stat(path, args),
+        }
+    }
+
+    fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
+        Ok((
+            RpDelete::default(),
+            MultiBucketDeleter::new(self.buckets.clone()),
+        ))
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        let Some((bucket, path)) = split_bucket(path) else {
+            let buckets = self.buckets.known();
+            if !args.recursive() {
+                return Ok((RpList::default(), Self::root_lister(buckets)));
+            }
+
+            let mut listers = Vec::with_capacity(buckets.len());
+            for bucket in buckets.iter() {
+                let (_, l) = self.buckets.get(bucket)?.blocking_list("/", args.clone())?;
+                listers.push((format!("{bucket}/"), l));
+            }
+            let dirs = buckets.into_iter().map(|b| format!("{b}/")).collect();
+            return Ok((RpList::default(), MountLister::new(listers, vec![], dirs)));
+        };
+
+        let (rp, l) = self.buckets.get(bucket)?.blocking_list(path, args)?;
+        Ok((
+            rp,
+            MountLister::new(vec![(format!("{bucket}/"), l)], vec![], BTreeSet::new()),
+        ))
+    }
+}
+
+/// MultiBucketDeleter routes paths to the deleter of their buckets.
+///
+/// Deleters of buckets are created on demand while flushing.
+pub struct MultiBucketDeleter<D> {
+    buckets: Arc<Buckets>,
+    /// Paths that haven't been sent to the deleter of their buckets.
+    pending: VecDeque<(String, String, OpDelete)>,
+    /// Deleters of buckets and the count of queued paths.
+    deleters: HashMap<String, (D, usize)>,
+}
+
+impl<D> MultiBucketDeleter<D> {
+    fn new(buckets: Arc<Buckets>) -> Self {
+        Self {
+            buckets,
+            pending: VecDeque::new(),
+            deleters: HashMap::new(),
+        }
+    }
+
+    fn push(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        match split_bucket(path) {
+            Some((_, "/")) | None => Err(bucket_root_error(Operation::Delete, path)),
+            Some((bucket, path)) => {
+                self.pending
+                    .push_back((bucket.to_string(), path.to_string(), args));
+                Ok(())
+            }
+        }
+    }
+
+    /// Flush the first deleter that has queued paths.
+    fn flush_queued(&mut self) -> Option<(&mut D, &mut usize)> {
+        self.deleters
+            .values_mut()
+            .find(|(_, queued)| *queued > 0)
+            .map(|(d, queued)| (d, queued))
+    }
+}
+
+impl oio::Delete for MultiBucketDeleter<oio::Deleter> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.push(path, args)
+    }
+
+    async fn flush(&mut self) -> Result<usize> {
+        while let Some((bucket, _, _)) = self.pending.front() {
+            if !self.deleters.contains_key(bucket) {
+                let (_, d) = self.buckets.get(bucket)?.delete().await?;
+                self.deleters.insert(bucket.clone(), (d, 0));
+            }
+
+            let (bucket, path, args) = self.pending.pop_front().expect("pending must exist");
+            let (d, queued) = self.deleters.get_mut(&bucket).expect("deleter must exist");
+            d.delete(&path, args)?;
+            *queued += 1;
+        }
+
+        let Some((d, queued)) = self.flush_queued() else {
+            return Ok(0);
+        };
+        let n = d.flush().await?;
+        *queued -= n.min(*queued);
+        Ok(n)
+    }
+}
+
+impl oio::BlockingDelete for MultiBucketDeleter<oio::BlockingDeleter> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
+        self.push(path, args)
+    }
+
+    fn flush(&mut self) -> Result<usize> {
+        while let Some((bucket, _, _)) = self.pending.front() {
+            if !self.deleters.contains_key(bucket) {
+                let (_, d) = self.buckets.get(bucket)?.blocking_delete()?;
+                self.deleters.insert(bucket.clone(), (d, 0));
+            }
+
+            let (bucket, path, args) = self.pending.pop_front().expect("pending must exist");
+            let (d, queued) = self.deleters.get_mut(&bucket).expect("deleter must exist");
+            d.delete(&path, args)?;
+            *queued += 1;
+        }
+
+        let Some((d, queued)) = self.flush_queued() else {
+            return Ok(0);
+        };
+        let n = d.flush()?;
+        *queued -= n.min(*queued);
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    fn new_operator() -> (Operator, Arc<Mutex<HashMap<String, Operator>>>) {
+        let ops: Arc<Mutex<HashMap<String, Operator>>> = Arc::default();
+
+        let factory_ops = ops.clone();
+        let layer = MultiBucketLayer::new(move |bucket| {
+            let op = Operator::new(Memory::default())?.finish();
+            factory_ops
+                .lock()
+                .unwrap()
+                .insert(bucket.to_string(), op.clone());
+            Ok(op)
+        })
+        .bucket("a");
+
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(layer)
+            .finish();
+        (op, ops)
+    }
+
+    #[test]
+    fn test_split_bucket() {
+        let cases = [
+            ("/", None),
+            ("a", Some(("a", "/"))),
+            ("a/", Some(("a", "/"))),
+            ("a/b", Some(("a", "b"))),
+            ("a/b/c/", Some(("a", "b/c/"))),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(split_bucket(path), expected, "{path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multi_bucket_route() {
+        let (op, ops) = new_operator();
+
+        op.write("a/file", "a").await.unwrap();
+        op.write("b/dir/file", "b").await.unwrap();
+
+        let a = ops.lock().unwrap().get("a").cloned().unwrap();
+        let b = ops.lock().unwrap().get("b").cloned().unwrap();
+        assert_eq!(a.read("file").await.unwrap().to_vec(), b"a");
+        assert_eq!(b.read("dir/file").await.unwrap().to_vec(), b"b");
+        assert_eq!(op.read("b/dir/file").await.unwrap().to_vec(), b"b");
+
+        let err = op.copy("a/file", "b/file").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        op.delete("b/dir/file").await.unwrap();
+        assert!(!b.exists("dir/file").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_multi_bucket_list() {
+        let (op, _) = new_operator();
+
+        op.write("b/dir/file", "b").await.unwrap();
+
+        // Buckets only accessed by path are not listed.
+        let paths: Vec<_> = op
+            .list("/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        assert_eq!(paths, vec!["a/".to_string()]);
+
+        let paths: Vec<_> = op
+            .list("b/dir/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        assert!(paths.contains(&"b/dir/file".to_string()));
+
+        let paths: Vec<_> = op
+            .lister_with("/")
+            .recursive(true)
+            .await
+            .unwrap()
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(!paths.contains(&"b/dir/file".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_multi_bucket_list_with_bucket_lister() {
+        let ops: Arc<Mutex<HashMap<String, Operator>>> = Arc::default();
+        let factory_ops = ops.clone();
+        let layer = MultiBucketLayer::new(move |bucket| {
+            let mut ops = factory_ops.lock().unwrap();
+            let op = ops
+                .entry(bucket.to_string())
+                .or_insert_with(|| Operator::new(Memory::default()).unwrap().finish());
+            Ok(op.clone())
+        })
+        .bucket("a")
+        .with_bucket_lister(|| Box::pin(async { Ok(vec!["b".to_string()]) }));
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(layer)
+            .finish();
+
+        op.write("b/dir/file", "b").await.unwrap();
+
+        let paths: Vec<_> = op
+            .lister_with("/")
+            .recursive(true)
+            .await
+            .unwrap()
+            .map_ok(|e| e.path().to_string())
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(paths.contains(&"a/".to_string()));
+        assert!(paths.contains(&"b/dir/file".to_string()));
+        assert_eq!(paths.iter().filter(|p| p.as_str() == "b/").count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_multi_bucket_max_opened() {
+        let built = Arc::new(Mutex::new(0));
+        let factory_built = built.clone();
+        let layer = MultiBucketLayer::new(move |_| {
+            *factory_built.lock().unwrap() += 1;
+            Ok(Operator::new(Memory::default())?.finish())
+        })
+        .with_max_opened_buckets(2);
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(layer)
+            .finish();
+
+        for bucket in ["a", "b", "a", "c", "a"] {
+            let _ = op.exists(&format!("{bucket}/file")).await.unwrap();
+        }
+        // `a` is dropped while opening `c` and built again.
+        assert_eq!(*built.lock().unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_multi_bucket_layer_clone() {
+        let layer =
+            MultiBucketLayer::new(|_| Ok(Operator::new(Memory::default())?.finish())).bucket("a");
+        let cloned = layer.clone().bucket("b");
+
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(cloned)
+            .finish();
+        let paths: Vec<_> = op
+            .list("/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        assert_eq!(paths, vec!["a/".to_string(), "b/".to_string()]);
+        assert_eq!(layer.known.len(), 1);
+    }
+}
@@ -57,6 +57,11 @@ pub static METRIC_OPERATION_DURATION_SECONDS: MetricMetadata = MetricMetadata {
     name: "operation_duration_seconds",
     help: "Histogram of time spent during opendal operations",
 };
+/// The metric metadata for the operation time to first byte in seconds.
+pub static METRIC_OPERATION_TTFB_SECONDS: MetricMetadata = MetricMetadata {
+    name: "operation_ttfb_seconds",
+    help: "Histogram of time to first byte during opendal read operations",
+};
 /// The metric metadata for the operation bytes.
 pub static METRIC_OPERATION_BYTES: MetricMetadata = MetricMetadata {
     name: "operation_bytes",
@@ -67,6 +72,11 @@ pub static METRIC_OPERATION_ERRORS_TOTAL: MetricMetadata = MetricMetadata {
     name: "operation_errors_total",
     help: "Error counter during opendal operations",
 };
+/// The metric metadata for the operations that are executing.
+pub static METRIC_OPERATION_EXECUTING: MetricMetadata = MetricMetadata {
+    name: "operation_executing",
+    help: "Gauge of opendal operations that are executing",
+};
 
 /// The metric label for the scheme like s3, fs, cos.
 pub static LABEL_SCHEME: &str = "scheme";
@@ -96,6 +106,23 @@ pub trait MetricsIntercept: Debug + Clone + Send + Sync + Unpin + 'static {
         duration: Duration,
     );
 
+    /// Observe the time to first byte in seconds of read operations.
+    ///
+    /// The duration is measured from the start of `read` until the first
+    /// non-empty buffer is returned by the reader.
+    ///
+    /// Default to do nothing.
+    fn observe_operation_ttfb_seconds(
+        &self,
+        _scheme: Scheme,
+        _namespace: Arc<String>,
+        _root: Arc<String>,
+        _path: &str,
+        _op: Operation,
+        _duration: Duration,
+    ) {
+    }
+
     /// Observe the operation bytes happened in IO like read and write.
     fn observe_operation_bytes(
         &self,
@@ -117,6 +144,22 @@ pub trait MetricsIntercept: Debug + Clone + Send + Sync + Unpin + 'static {
         op: Operation,
         error: ErrorKind,
     );
+
+    /// Observe the number of executing operations.
+    ///
+    /// `count` will be `1` while operation starts and `-1` while it's finished,
+    /// no matter it succeeds, fails or gets cancelled.
+    ///
+    /// Default to do nothing.
+    fn observe_operation_executing(
+        &self,
+        _scheme: Scheme,
+        _namespace: Arc<String>,
+        _root: Arc<String>,
+        _op: Operation,
+        _count: isize,
+    ) {
+    }
 }
 
 /// The metrics layer for opendal.
@@ -152,6 +195,46 @@ impl<A: Access, I: MetricsIntercept> Layer<A> for MetricsLayer<I> {
     }
 }
 
+/// Guard of an executing operation, which will be finished while dropped.
+struct ExecutingGuard<I: MetricsIntercept> {
+    interceptor: I,
+    scheme: Scheme,
+    namespace: Arc<String>,
+    root: Arc<String>,
+    op: Operation,
+}
+
+impl<I: MetricsIntercept> ExecutingGuard<I> {
+    fn new(
+        interceptor: I,
+        scheme: Scheme,
+        namespace: Arc<String>,
+        root: Arc<String>,
+        op: Operation,
+    ) -> Self {
+        interceptor.observe_operation_executing(scheme, namespace.clone(), root.clone(), op, 1);
+        Self {
+            interceptor,
+            scheme,
+            namespace,
+            root,
+            op,
+        }
+    }
+}
+
+impl<I: MetricsIntercept> Drop for ExecutingGuard<I> {
+    fn drop(&mut self) {
+        self.interceptor.observe_operation_executing(
+            self.scheme,
+            self.namespace.clone(),
+            self.root.clone(),
+            self.op,
+            -1,
+        );
+    }
+}
+
 /// The metrics accessor for opendal.
 #[derive(Clone)]
 pub struct MetricsAccessor<A: Access, I: MetricsIntercept> {
@@ -171,6 +254,18 @@ impl<A: Access, I: MetricsIntercept> Debug for MetricsAccessor<A, I> {
     }
 }
 
+impl<A: Access, I: MetricsIntercept> MetricsAccessor<A, I> {
+    fn executing(&self, op: Operation) -> ExecutingGuard<I> {
+        ExecutingGuard::new(
+            self.interceptor.clone(),
+            self.scheme,
+            self.namespace.clone(),
+            self.root.clone(),
+            op,
+        )
+    }
+}
+
 impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     type Inner = A;
     type Reader = MetricsWrapper<A::Reader, I>;
@@ -189,6 +284,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         let op = Operation::CreateDir;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         self.inner()
             .create_dir(path, args)
@@ -220,6 +316,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let op = Operation::Read;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         let (rp, reader) = self
             .inner
@@ -257,13 +354,15 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
                 self.namespace.clone(),
                 self.root.clone(),
                 path.to_string(),
-            ),
+            )
+            .with_ttfb_start(start),
         ))
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let op = Operation::Write;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         let (rp, writer) = self
             .inner
@@ -308,6 +407,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
         let op = Operation::Copy;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         self.inner()
             .copy(from, to, args)
@@ -339,6 +439,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
         let op = Operation::Rename;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         self.inner()
             .rename(from, to, args)
@@ -370,6 +471,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let op = Operation::Stat;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         self.inner()
             .stat(path, args)
@@ -401,6 +503,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
         let op = Operation::Delete;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         let (rp, writer) = self
             .inner
@@ -445,6 +548,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let op = Operation::List;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         let (rp, lister) = self
             .inner
@@ -489,6 +593,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let op = Operation::Presign;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         self.inner()
             .presign(path, args)
@@ -517,9 +622,42 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
             })
     }
 
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let op = Operation::Concat;
+
+        let _executing = self.executing(op);
+        let start = Instant::now();
+        self.inner()
+            .concat(path, args)
+            .await
+            .map(|v| {
+                self.interceptor.observe_operation_duration_seconds(
+                    self.scheme,
+                    self.namespace.clone(),
+                    self.root.clone(),
+                    path,
+                    op,
+                    start.elapsed(),
+                );
+                v
+            })
+            .map_err(move |err| {
+                self.interceptor.observe_operation_errors_total(
+                    self.scheme,
+                    self.namespace.clone(),
+                    self.root.clone(),
+                    path,
+                    op,
+                    err.kind(),
+                );
+                err
+            })
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         let op = Operation::BlockingCreateDir;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         self.inner()
             .blocking_create_dir(path, args)
@@ -550,6 +688,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
         let op = Operation::BlockingRead;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         let (rp, reader) = self
             .inner
@@ -586,13 +725,15 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
                 self.namespace.clone(),
                 self.root.clone(),
                 path.to_string(),
-            ),
+            )
+            .with_ttfb_start(start),
         ))
     }
 
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
         let op = Operation::BlockingWrite;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         let (rp, writer) = self
             .inner
@@ -636,6 +777,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
         let op = Operation::BlockingCopy;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         self.inner()
             .blocking_copy(from, to, args)
@@ -666,6 +808,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
         let op = Operation::BlockingRename;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         self.inner()
             .blocking_rename(from, to, args)
@@ -696,6 +839,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let op = Operation::BlockingStat;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         self.inner()
             .blocking_stat(path, args)
@@ -726,6 +870,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
         let op = Operation::BlockingDelete;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         let (rp, writer) = self
             .inner
@@ -769,6 +914,7 @@ impl<A: Access, I: MetricsIntercept> LayeredAccess for MetricsAccessor<A, I> {
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
         let op = Operation::BlockingList;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         let (rp, lister) = self
             .inner
@@ -818,6 +964,9 @@ pub struct MetricsWrapper<R, I: MetricsIntercept> {
     namespace: Arc<String>,
     root: Arc<String>,
     path: String,
+
+    /// The start time of read, which will be taken after the first byte observed.
+    ttfb_start: Option<Instant>,
 }
 
 impl<R, I: MetricsIntercept> MetricsWrapper<R, I> {
@@ -836,7 +985,39 @@ impl<R, I: MetricsIntercept> MetricsWrapper<R, I> {
             namespace,
             root,
             path,
+            ttfb_start: None,
+        }
+    }
+
+    fn with_ttfb_start(mut self, start: Instant) -> Self {
+        self.ttfb_start = Some(start);
+        self
+    }
+
+    fn observe_ttfb(&mut self, op: Operation, bs: &Buffer) {
+        if bs.is_empty() {
+            return;
         }
+        if let Some(start) = self.ttfb_start.take() {
+            self.interceptor.observe_operation_ttfb_seconds(
+                self.scheme,
+                self.namespace.clone(),
+                self.root.clone(),
+                &self.path,
+                op,
+                start.elapsed(),
+            );
+        }
+    }
+
+    fn executing(&self, op: Operation) -> ExecutingGuard<I> {
+        ExecutingGuard::new(
+            self.interceptor.clone(),
+            self.scheme,
+            self.namespace.clone(),
+            self.root.clone(),
+            op,
+        )
     }
 }
 
@@ -844,10 +1025,12 @@ impl<R: oio::Read, I: MetricsIntercept> oio::Read for MetricsWrapper<R, I> {
     async fn read(&mut self) -> Result<Buffer> {
         let op = Operation::ReaderRead;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.read().await {
             Ok(bs) => {
+                self.observe_ttfb(Operation::Read, &bs);
                 self.interceptor.observe_operation_bytes(
                     self.scheme,
                     self.namespace.clone(),
@@ -886,10 +1069,12 @@ impl<R: oio::BlockingRead, I: MetricsIntercept> oio::BlockingRead for MetricsWra
     fn read(&mut self) -> Result<Buffer> {
         let op = Operation::BlockingReaderRead;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.read() {
             Ok(bs) => {
+                self.observe_ttfb(Operation::BlockingRead, &bs);
                 self.interceptor.observe_operation_bytes(
                     self.scheme,
                     self.namespace.clone(),
@@ -928,6 +1113,7 @@ impl<R: oio::Write, I: MetricsIntercept> oio::Write for MetricsWrapper<R, I> {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
         let op = Operation::WriterWrite;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         let size = bs.len();
 
@@ -969,6 +1155,7 @@ impl<R: oio::Write, I: MetricsIntercept> oio::Write for MetricsWrapper<R, I> {
     async fn close(&mut self) -> Result<()> {
         let op = Operation::WriterClose;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.close().await {
@@ -999,6 +1186,7 @@ impl<R: oio::Write, I: MetricsIntercept> oio::Write for MetricsWrapper<R, I> {
     async fn abort(&mut self) -> Result<()> {
         let op = Operation::WriterAbort;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.abort().await {
@@ -1031,6 +1219,7 @@ impl<R: oio::BlockingWrite, I: MetricsIntercept> oio::BlockingWrite for MetricsW
     fn write(&mut self, bs: Buffer) -> Result<()> {
         let op = Operation::BlockingWriterWrite;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
         let size = bs.len();
 
@@ -1072,6 +1261,7 @@ impl<R: oio::BlockingWrite, I: MetricsIntercept> oio::BlockingWrite for MetricsW
     fn close(&mut self) -> Result<()> {
         let op = Operation::BlockingWriterClose;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.close() {
@@ -1104,6 +1294,7 @@ impl<R: oio::List, I: MetricsIntercept> oio::List for MetricsWrapper<R, I> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         let op = Operation::ListerNext;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.next().await {
@@ -1130,12 +1321,17 @@ impl<R: oio::List, I: MetricsIntercept> oio::List for MetricsWrapper<R, I> {
         );
         res
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<R: oio::BlockingList, I: MetricsIntercept> oio::BlockingList for MetricsWrapper<R, I> {
     fn next(&mut self) -> Result<Option<oio::Entry>> {
         let op = Operation::BlockingListerNext;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.next() {
@@ -1168,6 +1364,7 @@ impl<R: oio::Delete, I: MetricsIntercept> oio::Delete for MetricsWrapper<R, I> {
     fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
         let op = Operation::DeleterDelete;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.delete(path, args) {
@@ -1198,6 +1395,7 @@ impl<R: oio::Delete, I: MetricsIntercept> oio::Delete for MetricsWrapper<R, I> {
     async fn flush(&mut self) -> Result<usize> {
         let op = Operation::DeleterFlush;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.flush().await {
@@ -1230,6 +1428,7 @@ impl<R: oio::BlockingDelete, I: MetricsIntercept> oio::BlockingDelete for Metric
     fn delete(&mut self, path: &str, args: OpDelete) -> Result<()> {
         let op = Operation::BlockingDeleterDelete;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.delete(path, args) {
@@ -1260,6 +1459,7 @@ impl<R: oio::BlockingDelete, I: MetricsIntercept> oio::BlockingDelete for Metric
     fn flush(&mut self) -> Result<usize> {
         let op = Operation::BlockingDeleterFlush;
 
+        let _executing = self.executing(op);
         let start = Instant::now();
 
         let res = match self.inner.flush() {
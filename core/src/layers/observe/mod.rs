@@ -26,8 +26,10 @@
 //! | Metric Name                  | Type      | Description                                                  | Labels                                          |
 //! |------------------------------|-----------|--------------------------------------------------------------|-------------------------------------------------|
 //! | operation_duration_seconds   | Histogram | Histogram of time spent during opendal operations            | scheme, namespace, root, operation, path        |
+//! | operation_ttfb_seconds       | Histogram | Histogram of time to first byte during opendal reads         | scheme, namespace, root, operation, path        |
 //! | operation_bytes.             | Histogram | Histogram of the bytes transferred during opendal operations | scheme, operation, root, operation, path        |
 //! | operation_errors_total       | Counter   | Error counter during opendal operations                      | scheme, operation, root, operation, path, error |
+//! | operation_executing          | Gauge     | Gauge of opendal operations that are executing               | scheme, namespace, root, operation              |
 
 mod metrics;
 
@@ -44,6 +46,8 @@ pub use metrics::LABEL_SCHEME;
 pub use metrics::METRIC_OPERATION_BYTES;
 pub use metrics::METRIC_OPERATION_DURATION_SECONDS;
 pub use metrics::METRIC_OPERATION_ERRORS_TOTAL;
+pub use metrics::METRIC_OPERATION_EXECUTING;
+pub use metrics::METRIC_OPERATION_TTFB_SECONDS;
 
 /// Return the path label value according to the given `path` and `level`.
 ///
@@ -23,6 +23,7 @@ use prometheus::core::GenericCounterVec;
 use prometheus::exponential_buckets;
 use prometheus::histogram_opts;
 use prometheus::HistogramVec;
+use prometheus::IntGaugeVec;
 use prometheus::Opts;
 use prometheus::Registry;
 
@@ -170,6 +171,8 @@ impl PrometheusLayerBuilder {
 
     /// Set buckets for `operation_duration_seconds` histogram.
     ///
+    /// The buckets are also used by `operation_ttfb_seconds` histogram.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -318,6 +321,15 @@ impl PrometheusLayerBuilder {
             histogram_opts!(
                 observe::METRIC_OPERATION_DURATION_SECONDS.name(),
                 observe::METRIC_OPERATION_DURATION_SECONDS.help(),
+                self.operation_duration_seconds_buckets.clone()
+            ),
+            &labels,
+        )
+        .map_err(parse_prometheus_error)?;
+        let operation_ttfb_seconds = HistogramVec::new(
+            histogram_opts!(
+                observe::METRIC_OPERATION_TTFB_SECONDS.name(),
+                observe::METRIC_OPERATION_TTFB_SECONDS.help(),
                 self.operation_duration_seconds_buckets
             ),
             &labels,
@@ -343,21 +355,40 @@ impl PrometheusLayerBuilder {
         )
         .map_err(parse_prometheus_error)?;
 
+        // Path is not used as label of executing operations to avoid high cardinality.
+        let labels = OperationLabels::names(false, 0);
+        let operation_executing = IntGaugeVec::new(
+            Opts::new(
+                observe::METRIC_OPERATION_EXECUTING.name(),
+                observe::METRIC_OPERATION_EXECUTING.help(),
+            ),
+            &labels,
+        )
+        .map_err(parse_prometheus_error)?;
+
         registry
             .register(Box::new(operation_duration_seconds.clone()))
             .map_err(parse_prometheus_error)?;
+        registry
+            .register(Box::new(operation_ttfb_seconds.clone()))
+            .map_err(parse_prometheus_error)?;
         registry
             .register(Box::new(operation_bytes.clone()))
             .map_err(parse_prometheus_error)?;
         registry
             .register(Box::new(operation_errors_total.clone()))
             .map_err(parse_prometheus_error)?;
+        registry
+            .register(Box::new(operation_executing.clone()))
+            .map_err(parse_prometheus_error)?;
 
         Ok(PrometheusLayer {
             interceptor: PrometheusInterceptor {
                 operation_duration_seconds,
+                operation_ttfb_seconds,
                 operation_bytes,
                 operation_errors_total,
+                operation_executing,
                 path_label_level: self.path_label_level,
             },
         })
@@ -405,8 +436,10 @@ fn parse_prometheus_error(err: prometheus::Error) -> Error {
 #[derive(Clone, Debug)]
 pub struct PrometheusInterceptor {
     operation_duration_seconds: HistogramVec,
+    operation_ttfb_seconds: HistogramVec,
     operation_bytes: HistogramVec,
     operation_errors_total: GenericCounterVec<AtomicU64>,
+    operation_executing: IntGaugeVec,
     path_label_level: usize,
 }
 
@@ -435,6 +468,30 @@ impl observe::MetricsIntercept for PrometheusInterceptor {
             .observe(duration.as_secs_f64())
     }
 
+    fn observe_operation_ttfb_seconds(
+        &self,
+        scheme: Scheme,
+        namespace: Arc<String>,
+        root: Arc<String>,
+        path: &str,
+        op: Operation,
+        duration: Duration,
+    ) {
+        let labels = OperationLabels {
+            scheme,
+            namespace: &namespace,
+            root: &root,
+            operation: op,
+            error: None,
+            path,
+        }
+        .into_values(self.path_label_level);
+
+        self.operation_ttfb_seconds
+            .with_label_values(&labels)
+            .observe(duration.as_secs_f64())
+    }
+
     fn observe_operation_bytes(
         &self,
         scheme: Scheme,
@@ -480,6 +537,29 @@ impl observe::MetricsIntercept for PrometheusInterceptor {
 
         self.operation_errors_total.with_label_values(&labels).inc();
     }
+
+    fn observe_operation_executing(
+        &self,
+        scheme: Scheme,
+        namespace: Arc<String>,
+        root: Arc<String>,
+        op: Operation,
+        count: isize,
+    ) {
+        let labels = OperationLabels {
+            scheme,
+            namespace: &namespace,
+            root: &root,
+            operation: op,
+            error: None,
+            path: "",
+        }
+        .into_values(0);
+
+        self.operation_executing
+            .with_label_values(&labels)
+            .add(count as i64);
+    }
 }
 
 struct OperationLabels<'a> {
@@ -25,6 +25,7 @@ use prometheus_client::encoding::LabelSetEncoder;
 use prometheus_client::metrics::counter::Counter;
 use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::family::MetricConstructor;
+use prometheus_client::metrics::gauge::Gauge;
 use prometheus_client::metrics::histogram::exponential_buckets;
 use prometheus_client::metrics::histogram::Histogram;
 use prometheus_client::registry::Registry;
@@ -166,6 +167,8 @@ impl PrometheusClientLayerBuilder {
 
     /// Set buckets for `operation_duration_seconds` histogram.
     ///
+    /// The buckets are also used by `operation_ttfb_seconds` histogram.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -305,6 +308,10 @@ impl PrometheusClientLayerBuilder {
     /// ```
     pub fn register(self, registry: &mut Registry) -> PrometheusClientLayer {
         let operation_duration_seconds =
+            Family::<OperationLabels, Histogram, _>::new_with_constructor(HistogramConstructor {
+                buckets: self.operation_duration_seconds_buckets.clone(),
+            });
+        let operation_ttfb_seconds =
             Family::<OperationLabels, Histogram, _>::new_with_constructor(HistogramConstructor {
                 buckets: self.operation_duration_seconds_buckets,
             });
@@ -313,12 +320,18 @@ impl PrometheusClientLayerBuilder {
                 buckets: self.operation_bytes_buckets,
             });
         let operation_errors_total = Family::<OperationLabels, Counter>::default();
+        let operation_executing = Family::<OperationLabels, Gauge>::default();
 
         registry.register(
             observe::METRIC_OPERATION_DURATION_SECONDS.name(),
             observe::METRIC_OPERATION_DURATION_SECONDS.help(),
             operation_duration_seconds.clone(),
         );
+        registry.register(
+            observe::METRIC_OPERATION_TTFB_SECONDS.name(),
+            observe::METRIC_OPERATION_TTFB_SECONDS.help(),
+            operation_ttfb_seconds.clone(),
+        );
         registry.register(
             observe::METRIC_OPERATION_BYTES.name(),
             observe::METRIC_OPERATION_BYTES.help(),
@@ -331,12 +344,19 @@ impl PrometheusClientLayerBuilder {
             observe::METRIC_OPERATION_ERRORS_TOTAL.help(),
             operation_errors_total.clone(),
         );
+        registry.register(
+            observe::METRIC_OPERATION_EXECUTING.name(),
+            observe::METRIC_OPERATION_EXECUTING.help(),
+            operation_executing.clone(),
+        );
 
         PrometheusClientLayer {
             interceptor: PrometheusClientInterceptor {
                 operation_duration_seconds,
+                operation_ttfb_seconds,
                 operation_bytes,
                 operation_errors_total,
+                operation_executing,
                 path_label_level: self.path_label_level,
             },
         }
@@ -357,8 +377,10 @@ impl MetricConstructor<Histogram> for HistogramConstructor {
 #[derive(Clone, Debug)]
 pub struct PrometheusClientInterceptor {
     operation_duration_seconds: Family<OperationLabels, Histogram, HistogramConstructor>,
+    operation_ttfb_seconds: Family<OperationLabels, Histogram, HistogramConstructor>,
     operation_bytes: Family<OperationLabels, Histogram, HistogramConstructor>,
     operation_errors_total: Family<OperationLabels, Counter>,
+    operation_executing: Family<OperationLabels, Gauge>,
     path_label_level: usize,
 }
 
@@ -384,6 +406,27 @@ impl observe::MetricsIntercept for PrometheusClientInterceptor {
             .observe(duration.as_secs_f64())
     }
 
+    fn observe_operation_ttfb_seconds(
+        &self,
+        scheme: Scheme,
+        namespace: Arc<String>,
+        root: Arc<String>,
+        path: &str,
+        op: Operation,
+        duration: Duration,
+    ) {
+        self.operation_ttfb_seconds
+            .get_or_create(&OperationLabels {
+                scheme,
+                namespace,
+                root,
+                operation: op,
+                path: observe::path_label_value(path, self.path_label_level).map(Into::into),
+                error: None,
+            })
+            .observe(duration.as_secs_f64())
+    }
+
     fn observe_operation_bytes(
         &self,
         scheme: Scheme,
@@ -425,6 +468,27 @@ impl observe::MetricsIntercept for PrometheusClientInterceptor {
             })
             .inc();
     }
+
+    fn observe_operation_executing(
+        &self,
+        scheme: Scheme,
+        namespace: Arc<String>,
+        root: Arc<String>,
+        op: Operation,
+        count: isize,
+    ) {
+        // Path is not used as label of executing operations to avoid high cardinality.
+        self.operation_executing
+            .get_or_create(&OperationLabels {
+                scheme,
+                namespace,
+                root,
+                operation: op,
+                path: None,
+                error: None,
+            })
+            .inc_by(count as i64);
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
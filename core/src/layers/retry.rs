@@ -323,7 +323,13 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
             .await
             .map_err(|e| e.set_persistent())?;
 
-        let retry_reader = RetryReader::new(self.inner.clone(), path.to_string(), args, reader);
+        let retry_reader = RetryReader::new(
+            self.inner.clone(),
+            path.to_string(),
+            args,
+            rp.etag(),
+            reader,
+        );
         let retry_wrapper = RetryWrapper::new(retry_reader, self.notify.clone(), self.builder);
 
         Ok((rp, retry_wrapper))
@@ -376,6 +382,15 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
             .map_err(|e| e.set_persistent())
     }
 
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        { || self.inner.concat(path, args.clone()) }
+            .retry(self.builder)
+            .when(|e| e.is_temporary())
+            .notify(|err, dur| self.notify.intercept(err, dur))
+            .await
+            .map_err(|e| e.set_persistent())
+    }
+
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         { || self.inner.list(path, args.clone()) }
             .retry(self.builder)
@@ -403,7 +418,13 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
             .call()
             .map_err(|e| e.set_persistent())?;
 
-        let retry_reader = RetryReader::new(self.inner.clone(), path.to_string(), args, reader);
+        let retry_reader = RetryReader::new(
+            self.inner.clone(),
+            path.to_string(),
+            args,
+            rp.etag(),
+            reader,
+        );
         let retry_wrapper = RetryWrapper::new(retry_reader, self.notify.clone(), self.builder);
 
         Ok((rp, retry_wrapper))
@@ -478,8 +499,16 @@ pub struct RetryReader<A, R> {
     args: OpRead,
 }
 
-impl<A, R> RetryReader<A, R> {
-    fn new(inner: Arc<A>, path: String, args: OpRead, r: R) -> Self {
+impl<A: Access, R> RetryReader<A, R> {
+    fn new(inner: Arc<A>, path: String, mut args: OpRead, etag: Option<&str>, r: R) -> Self {
+        // Pin the re-read to the etag of the first response to avoid
+        // mixing data from different versions of the file.
+        if let Some(etag) = etag {
+            if args.if_match().is_none() && inner.info().full_capability().read_with_if_match {
+                args = args.with_if_match(etag);
+            }
+        }
+
         Self {
             inner,
             reader: Some(r),
@@ -718,6 +747,10 @@ impl<P: oio::List, I: RetryInterceptor> oio::List for RetryWrapper<P, I> {
         self.inner = Some(inner);
         res.map_err(|err| err.set_persistent())
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.as_ref().and_then(|p| p.continuation_token())
+    }
 }
 
 impl<P: oio::BlockingList, I: RetryInterceptor> oio::BlockingList for RetryWrapper<P, I> {
@@ -847,6 +880,7 @@ mod tests {
                 stat: true,
                 list: true,
                 list_with_recursive: true,
+                concat: true,
                 ..Default::default()
             });
 
@@ -888,6 +922,19 @@ mod tests {
             let lister = MockLister::default();
             Ok((RpList::default(), lister))
         }
+
+        async fn concat(&self, _: &str, _: OpConcat) -> Result<RpConcat> {
+            let mut attempt = self.attempt.lock().unwrap();
+            *attempt += 1;
+
+            match *attempt {
+                1 | 2 => Err(
+                    Error::new(ErrorKind::Unexpected, "retryable_error from concat")
+                        .set_temporary(),
+                ),
+                _ => Ok(RpConcat::default()),
+            }
+        }
     }
 
     #[derive(Debug, Clone, Default)]
@@ -1108,6 +1155,22 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[tokio::test]
+    async fn test_retry_concat() {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let builder = MockBuilder::default();
+        let op = Operator::new(builder.clone())
+            .unwrap()
+            .layer(RetryLayer::new())
+            .finish();
+
+        op.concat("file", &["part-0", "part-1"])
+            .await
+            .expect("concat must succeed");
+        assert_eq!(*builder.attempt.lock().unwrap(), 3);
+    }
+
     #[tokio::test]
     async fn test_retry_batch() {
         let _ = tracing_subscriber::fmt().with_test_writer().try_init();
@@ -100,9 +100,9 @@ use crate::*;
 ///
 /// # Implementation Notes
 ///
-/// TimeoutLayer is using [`tokio::time::timeout`] to implement timeout for operations. And IO
-/// Operations insides `reader`, `writer` will use `Pin<Box<tokio::time::Sleep>>` to track the
-/// timeout.
+/// TimeoutLayer races operations with a timer to implement timeout. The timer is backed by
+/// tokio on native targets and by browser timers on wasm32, so this layer works in browsers
+/// and edge runtimes too.
 ///
 /// This might introduce a bit overhead for IO operations, but it's the only way to implement
 /// timeout correctly. We used to implement timeout layer in zero cost way that only stores
@@ -188,7 +188,7 @@ pub struct TimeoutAccessor<A: Access> {
 
 impl<A: Access> TimeoutAccessor<A> {
     async fn timeout<F: Future<Output = Result<T>>, T>(&self, op: Operation, fut: F) -> Result<T> {
-        tokio::time::timeout(self.timeout, fut).await.map_err(|_| {
+        timeout(self.timeout, fut).await.ok_or_else(|| {
             Error::new(ErrorKind::Unexpected, "operation timeout reached")
                 .with_operation(op)
                 .with_context("timeout", self.timeout.as_secs_f64().to_string())
@@ -201,14 +201,12 @@ impl<A: Access> TimeoutAccessor<A> {
         op: Operation,
         fut: F,
     ) -> Result<T> {
-        tokio::time::timeout(self.io_timeout, fut)
-            .await
-            .map_err(|_| {
-                Error::new(ErrorKind::Unexpected, "io timeout reached")
-                    .with_operation(op)
-                    .with_context("timeout", self.io_timeout.as_secs_f64().to_string())
-                    .set_temporary()
-            })?
+        timeout(self.io_timeout, fut).await.ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "io timeout reached")
+                .with_operation(op)
+                .with_context("timeout", self.io_timeout.as_secs_f64().to_string())
+                .set_temporary()
+        })?
     }
 }
 
@@ -290,6 +288,16 @@ impl<A: Access> LayeredAccess for TimeoutAccessor<A> {
             .await
     }
 
+    async fn tag(&self, path: &str, args: OpTag) -> Result<RpTag> {
+        self.timeout(Operation::Tag, self.inner.tag(path, args))
+            .await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        self.timeout(Operation::Concat, self.inner.concat(path, args))
+            .await
+    }
+
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
         self.inner.blocking_read(path, args)
     }
@@ -324,7 +332,7 @@ impl Execute for TimeoutExecutor {
     }
 
     fn timeout(&self) -> Option<BoxedStaticFuture<()>> {
-        Some(Box::pin(tokio::time::sleep(self.timeout)))
+        Some(Box::pin(sleep(self.timeout)))
     }
 }
 
@@ -345,7 +353,7 @@ impl<R> TimeoutWrapper<R> {
         op: &'static str,
         fut: F,
     ) -> Result<T> {
-        tokio::time::timeout(timeout, fut).await.map_err(|_| {
+        crate::raw::timeout(timeout, fut).await.ok_or_else(|| {
             Error::new(ErrorKind::Unexpected, "io operation timeout reached")
                 .with_operation(op)
                 .with_context("timeout", timeout.as_secs_f64().to_string())
@@ -383,6 +391,10 @@ impl<R: oio::List> oio::List for TimeoutWrapper<R> {
         let fut = self.inner.next();
         Self::io_timeout(self.timeout, Operation::ListerNext.into_static(), fut).await
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<R: oio::Delete> oio::Delete for TimeoutWrapper<R> {
@@ -264,6 +264,44 @@ pub trait Access: Send + Sync + Debug + Unpin + 'static {
         )))
     }
 
+    /// Invoke the `tag` operation on the specified path.
+    ///
+    /// Require [`Capability::tag`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    fn tag(&self, path: &str, args: OpTag) -> impl Future<Output = Result<RpTag>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `concat` operation on the specified path.
+    ///
+    /// Require [`Capability::concat`]
+    ///
+    /// # Behavior
+    ///
+    /// - Build the object at `path` with the content of `sources` in order.
+    /// - `sources` will be kept as is after concat.
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    fn concat(
+        &self,
+        path: &str,
+        args: OpConcat,
+    ) -> impl Future<Output = Result<RpConcat>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
     /// Invoke the `blocking_create` operation on the specified path.
     ///
     /// This operation is the blocking version of [`Accessor::create_dir`]
@@ -432,6 +470,11 @@ pub trait AccessDyn: Send + Sync + Debug + Unpin {
         path: &'a str,
         args: OpPresign,
     ) -> BoxedFuture<'a, Result<RpPresign>>;
+    /// Dyn version of [`Accessor::tag`]
+    fn tag_dyn<'a>(&'a self, path: &'a str, args: OpTag) -> BoxedFuture<'a, Result<RpTag>>;
+    /// Dyn version of [`Accessor::concat`]
+    fn concat_dyn<'a>(&'a self, path: &'a str, args: OpConcat)
+        -> BoxedFuture<'a, Result<RpConcat>>;
     /// Dyn version of [`Accessor::blocking_create_dir`]
     fn blocking_create_dir_dyn(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir>;
     /// Dyn version of [`Accessor::blocking_stat`]
@@ -537,6 +580,18 @@ where
         Box::pin(self.presign(path, args))
     }
 
+    fn tag_dyn<'a>(&'a self, path: &'a str, args: OpTag) -> BoxedFuture<'a, Result<RpTag>> {
+        Box::pin(self.tag(path, args))
+    }
+
+    fn concat_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpConcat,
+    ) -> BoxedFuture<'a, Result<RpConcat>> {
+        Box::pin(self.concat(path, args))
+    }
+
     fn blocking_create_dir_dyn(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.blocking_create_dir(path, args)
     }
@@ -624,6 +679,14 @@ impl Access for dyn AccessDyn {
         self.presign_dyn(path, args).await
     }
 
+    async fn tag(&self, path: &str, args: OpTag) -> Result<RpTag> {
+        self.tag_dyn(path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        self.concat_dyn(path, args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.blocking_create_dir_dyn(path, args)
     }
@@ -765,6 +828,18 @@ impl<T: Access + ?Sized> Access for Arc<T> {
         async move { self.as_ref().presign(path, args).await }
     }
 
+    fn tag(&self, path: &str, args: OpTag) -> impl Future<Output = Result<RpTag>> + MaybeSend {
+        async move { self.as_ref().tag(path, args).await }
+    }
+
+    fn concat(
+        &self,
+        path: &str,
+        args: OpConcat,
+    ) -> impl Future<Output = Result<RpConcat>> + MaybeSend {
+        async move { self.as_ref().concat(path, args).await }
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.as_ref().blocking_create_dir(path, args)
     }
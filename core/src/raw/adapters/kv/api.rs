@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::future::ready;
 use std::ops::DerefMut;
+use std::time::Duration;
 
 use futures::Future;
 
@@ -48,6 +49,7 @@ pub struct ScanStdIter<I>(I);
     feature = "services-cloudflare-kv",
     feature = "services-etcd",
     feature = "services-nebula-graph",
+    feature = "services-redis",
     feature = "services-rocksdb",
     feature = "services-sled"
 ))]
@@ -118,6 +120,24 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
     /// Set a key into service.
     fn set(&self, path: &str, value: Buffer) -> impl Future<Output = Result<()>> + MaybeSend;
 
+    /// Set a key into service with an expire duration.
+    ///
+    /// Services that declare [`Capability::write_with_expire_after`] must implement this.
+    fn set_with_ttl(
+        &self,
+        path: &str,
+        value: Buffer,
+        ttl: Duration,
+    ) -> impl Future<Output = Result<()>> + MaybeSend {
+        let _ = (path, value, ttl);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::set_with_ttl")))
+    }
+
     /// The blocking version of set.
     fn blocking_set(&self, path: &str, value: Buffer) -> Result<()> {
         let _ = (path, value);
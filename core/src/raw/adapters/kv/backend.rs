@@ -16,6 +16,7 @@
 // under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 use std::vec::IntoIter;
 
 use super::{Adapter, Scan};
@@ -124,10 +125,14 @@ impl<S: Adapter> Access for Backend<S> {
         Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
+        check_expire_after(&args)?;
 
-        Ok((RpWrite::new(), KvWriter::new(self.kv.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            KvWriter::new(self.kv.clone(), p, args.expire_after()),
+        ))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
@@ -171,10 +176,14 @@ impl<S: Adapter> Access for Backend<S> {
         Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
     }
 
-    fn blocking_write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
         let p = build_abs_path(&self.root, path);
+        check_expire_after(&args)?;
 
-        Ok((RpWrite::new(), KvWriter::new(self.kv.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            KvWriter::new(self.kv.clone(), p, args.expire_after()),
+        ))
     }
 
     fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
@@ -270,17 +279,30 @@ impl oio::BlockingList for BlockingKvLister {
     }
 }
 
+/// Check the ttl of write, a ttl of zero can't be stored by kv services.
+fn check_expire_after(args: &OpWrite) -> Result<()> {
+    match args.expire_after() {
+        Some(ttl) if ttl.is_zero() => Err(Error::new(
+            ErrorKind::Unexpected,
+            "expire_after must be larger than zero",
+        )),
+        _ => Ok(()),
+    }
+}
+
 pub struct KvWriter<S> {
     kv: Arc<S>,
     path: String,
+    ttl: Option<Duration>,
     buffer: QueueBuf,
 }
 
 impl<S> KvWriter<S> {
-    fn new(kv: Arc<S>, path: String) -> Self {
+    fn new(kv: Arc<S>, path: String, ttl: Option<Duration>) -> Self {
         KvWriter {
             kv,
             path,
+            ttl,
             buffer: QueueBuf::new(),
         }
     }
@@ -299,7 +321,10 @@ impl<S: Adapter> oio::Write for KvWriter<S> {
 
     async fn close(&mut self) -> Result<()> {
         let buf = self.buffer.clone().collect();
-        self.kv.set(&self.path, buf).await
+        match self.ttl {
+            Some(ttl) => self.kv.set_with_ttl(&self.path, buf, ttl).await,
+            None => self.kv.set(&self.path, buf).await,
+        }
     }
 
     async fn abort(&mut self) -> Result<()> {
@@ -315,6 +340,13 @@ impl<S: Adapter> oio::BlockingWrite for KvWriter<S> {
     }
 
     fn close(&mut self) -> Result<()> {
+        if self.ttl.is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "kv adapter doesn't support blocking write with ttl",
+            ));
+        }
+
         let buf = self.buffer.clone().collect();
         self.kv.blocking_set(&self.path, buf)?;
         Ok(())
@@ -27,6 +27,7 @@ pub use api::Scan;
     feature = "services-cloudflare-kv",
     feature = "services-etcd",
     feature = "services-nebula-graph",
+    feature = "services-redis",
     feature = "services-rocksdb",
     feature = "services-sled"
 ))]
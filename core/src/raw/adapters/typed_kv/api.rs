@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use std::future::ready;
 use std::future::Future;
 use std::mem::size_of;
+use std::time::Duration;
 
 use chrono::Utc;
 
@@ -60,6 +61,34 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
     /// Set a value into adapter.
     fn blocking_set(&self, path: &str, value: Value) -> Result<()>;
 
+    /// Set a value into adapter which will be expired after `ttl`.
+    fn set_with_ttl(
+        &self,
+        path: &str,
+        value: Value,
+        ttl: Duration,
+    ) -> impl Future<Output = Result<()>> + MaybeSend {
+        let _ = (path, value, ttl);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "typed_kv adapter doesn't support this operation",
+        )
+        .with_operation("typed_kv::Adapter::set_with_ttl")))
+    }
+
+    /// Set a value into adapter which will be expired after `ttl`
+    /// in blocking way.
+    fn blocking_set_with_ttl(&self, path: &str, value: Value, ttl: Duration) -> Result<()> {
+        let _ = (path, value, ttl);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "typed_kv adapter doesn't support this operation",
+        )
+        .with_operation("typed_kv::Adapter::blocking_set_with_ttl"))
+    }
+
     /// Delete a value from adapter.
     fn delete(&self, path: &str) -> impl Future<Output = Result<()>> + MaybeSend;
 
@@ -130,6 +159,8 @@ pub struct Capability {
     pub delete: bool,
     /// If typed_kv operator supports scan natively.
     pub scan: bool,
+    /// If typed_kv operator supports set with ttl natively.
+    pub ttl: bool,
     /// If typed_kv operator supports shared access.
     pub shared: bool,
 }
@@ -150,6 +181,9 @@ impl Debug for Capability {
         if self.scan {
             s.push("Scan");
         }
+        if self.ttl {
+            s.push("Ttl");
+        }
         if self.shared {
             s.push("Shared");
         }
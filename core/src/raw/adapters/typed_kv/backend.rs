@@ -89,6 +89,10 @@ impl<S: Adapter> Access for Backend<S> {
             cap.list_with_recursive = true;
         }
 
+        if kv_cap.ttl {
+            cap.write_with_expire_after = true;
+        }
+
         if kv_cap.shared {
             cap.shared = true;
         }
@@ -300,7 +304,10 @@ impl<S: Adapter> oio::Write for KvWriter<S> {
                 value
             }
         };
-        self.kv.set(&self.path, value).await?;
+        match self.op.expire_after() {
+            Some(ttl) => self.kv.set_with_ttl(&self.path, value, ttl).await?,
+            None => self.kv.set(&self.path, value).await?,
+        }
         Ok(())
     }
 
@@ -329,7 +336,10 @@ impl<S: Adapter> oio::BlockingWrite for KvWriter<S> {
             }
         };
 
-        kv.blocking_set(&self.path, value)?;
+        match self.op.expire_after() {
+            Some(ttl) => kv.blocking_set_with_ttl(&self.path, value, ttl)?,
+            None => kv.blocking_set(&self.path, value)?,
+        }
         Ok(())
     }
 }
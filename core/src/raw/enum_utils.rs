@@ -99,6 +99,22 @@ impl<ONE: oio::List, TWO: oio::List> oio::List for TwoWays<ONE, TWO> {
             Self::Two(v) => v.next().await,
         }
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        match self {
+            Self::One(v) => v.continuation_token(),
+            Self::Two(v) => v.continuation_token(),
+        }
+    }
+}
+
+impl<ONE: oio::BlockingList, TWO: oio::BlockingList> oio::BlockingList for TwoWays<ONE, TWO> {
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        match self {
+            Self::One(v) => v.next(),
+            Self::Two(v) => v.next(),
+        }
+    }
 }
 
 /// ThreeWays is used to implement traits that based on three ways.
@@ -226,6 +242,15 @@ where
             Self::Four(v) => v.next().await,
         }
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        match self {
+            Self::One(v) => v.continuation_token(),
+            Self::Two(v) => v.continuation_token(),
+            Self::Three(v) => v.continuation_token(),
+            Self::Four(v) => v.continuation_token(),
+        }
+    }
 }
 
 impl<ONE, TWO, THREE, FOUR> oio::BlockingList for FourWays<ONE, TWO, THREE, FOUR>
@@ -20,7 +20,9 @@ use std::future::Future;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
 
+use futures::future::Either;
 use futures::poll;
 use futures::stream::FuturesOrdered;
 use futures::FutureExt;
@@ -61,6 +63,33 @@ impl<T: Send> MaybeSend for T {}
 #[cfg(target_arch = "wasm32")]
 impl<T> MaybeSend for T {}
 
+/// Sleep for the given duration.
+///
+/// We use tokio's timer on non-wasm32 target, and browser timers on wasm32 target since
+/// there is no tokio time driver in browsers and edge runtimes.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn sleep(dur: Duration) {
+    tokio::time::sleep(dur).await
+}
+/// Sleep for the given duration.
+///
+/// We use tokio's timer on non-wasm32 target, and browser timers on wasm32 target since
+/// there is no tokio time driver in browsers and edge runtimes.
+#[cfg(target_arch = "wasm32")]
+pub async fn sleep(dur: Duration) {
+    gloo_timers::future::sleep(dur).await
+}
+
+/// Await the future with a timeout, returns `None` if the timeout reached first.
+pub async fn timeout<F: Future>(dur: Duration, fut: F) -> Option<F::Output> {
+    let fut = std::pin::pin!(fut);
+    let sleep = std::pin::pin!(sleep(dur));
+    match futures::future::select(fut, sleep).await {
+        Either::Left((v, _)) => Some(v),
+        Either::Right(_) => None,
+    }
+}
+
 /// ConcurrentTasks is used to execute tasks concurrently.
 ///
 /// ConcurrentTasks has two generic types:
@@ -231,6 +260,12 @@ impl<I: Send + 'static, O: Send + 'static> ConcurrentTasks<I, O> {
         }
     }
 
+    /// Fetch the successful result from the result queue without waiting for
+    /// ongoing tasks.
+    pub fn try_next(&mut self) -> Option<O> {
+        self.results.pop_front()
+    }
+
     /// Fetch the successful result from the result queue.
     pub async fn next(&mut self) -> Option<Result<O>> {
         if self.errored {
@@ -16,6 +16,7 @@
 // under the License.
 
 use std::cmp::Ordering;
+use std::time::Duration;
 
 use futures::Stream;
 use futures::StreamExt;
@@ -35,6 +36,7 @@ pub struct HttpBody {
     stream: Box<dyn Stream<Item = Result<Buffer>> + Unpin + 'static>,
     size: Option<u64>,
     consumed: u64,
+    stall_timeout: Option<Duration>,
 }
 
 /// # Safety
@@ -58,6 +60,7 @@ impl HttpBody {
             stream: Box::new(stream),
             size,
             consumed: 0,
+            stall_timeout: None,
         }
     }
 
@@ -71,9 +74,23 @@ impl HttpBody {
             stream: Box::new(stream),
             size,
             consumed: 0,
+            stall_timeout: None,
         }
     }
 
+    /// Abort the body with a temporary error if no data arrives within given timeout.
+    ///
+    /// The timer resets after every successful read, so slow but progressing
+    /// transfers are not affected. With `RetryLayer` enabled, `RetryReader`
+    /// will reopen the file with a ranged request from the last consumed
+    /// offset. Readers created with `resume_times` will do the same after
+    /// retries are exhausted, so every resume starts a new round of retries
+    /// and the total attempts are multiplied.
+    pub fn with_stall_timeout(mut self, timeout: Duration) -> Self {
+        self.stall_timeout = Some(timeout);
+        self
+    }
+
     /// Check if the consumed data is equal to the expected content length.
     #[inline]
     fn check(&self) -> Result<()> {
@@ -105,7 +122,17 @@ impl HttpBody {
 
 impl oio::Read for HttpBody {
     async fn read(&mut self) -> Result<Buffer> {
-        match self.stream.next().await.transpose()? {
+        let next = match self.stall_timeout {
+            None => self.stream.next().await,
+            Some(dur) => timeout(dur, self.stream.next()).await.ok_or_else(|| {
+                Error::new(ErrorKind::TimedOut, "http response stalled")
+                    .with_context("stall_timeout", dur.as_secs_f64().to_string())
+                    .with_context("consumed", self.consumed.to_string())
+                    .set_temporary()
+            })?,
+        };
+
+        match next.transpose()? {
             Some(buf) => {
                 self.consumed += buf.len() as u64;
                 Ok(buf)
@@ -117,3 +144,23 @@ impl oio::Read for HttpBody {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_http_body_stall_timeout() {
+        let s = stream::iter([Ok(Buffer::from("hello"))]).chain(stream::pending());
+        let mut body = HttpBody::new(s, None).with_stall_timeout(Duration::from_millis(10));
+
+        let bs = body.read().await.unwrap();
+        assert_eq!(bs.to_bytes(), "hello");
+
+        let err = body.read().await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TimedOut);
+        assert!(err.is_temporary());
+    }
+}
@@ -16,8 +16,11 @@
 // under the License.
 
 use http::response::Parts;
+use http::StatusCode;
 use http::Uri;
 
+use super::parse_extended_request_id;
+use super::parse_request_id;
 use crate::Error;
 use crate::ErrorKind;
 
@@ -46,17 +49,56 @@ pub fn new_request_sign_error(err: anyhow::Error) -> Error {
         .set_source(err)
 }
 
+/// Parse the error kind and whether it's retryable from http status code.
+///
+/// Services should handle their special status codes first and fall back to
+/// this function, so that the same status is mapped consistently:
+///
+/// - `401`, `403` => `PermissionDenied`
+/// - `404` => `NotFound`
+/// - `304`, `412` => `ConditionNotMatch`
+/// - `416` => `RangeNotSatisfied`
+/// - `429` => `RateLimited`, retryable
+/// - `503` => `Unavailable`, retryable
+/// - `408`, `500`, `502`, `504` => `Unexpected`, retryable
+/// - others => `Unexpected`
+pub fn parse_http_status(status: StatusCode) -> (ErrorKind, bool) {
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
+        StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
+        StatusCode::NOT_MODIFIED | StatusCode::PRECONDITION_FAILED => {
+            (ErrorKind::ConditionNotMatch, false)
+        }
+        StatusCode::RANGE_NOT_SATISFIABLE => (ErrorKind::RangeNotSatisfied, false),
+        StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
+        StatusCode::SERVICE_UNAVAILABLE => (ErrorKind::Unavailable, true),
+        StatusCode::REQUEST_TIMEOUT
+        | StatusCode::INTERNAL_SERVER_ERROR
+        | StatusCode::BAD_GATEWAY
+        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    }
+}
+
 /// Add response context to error.
 ///
 /// This helper function will:
 ///
 /// - remove sensitive or useless headers from parts.
 /// - fetch uri if parts extensions contains `Uri`.
+/// - fetch request id if service returns it in headers.
 pub fn with_error_response_context(mut err: Error, mut parts: Parts) -> Error {
     if let Some(uri) = parts.extensions.get::<Uri>() {
         err = err.with_context("uri", uri.to_string());
     }
 
+    if let Some(request_id) = parse_request_id(&parts.headers) {
+        err = err.with_context("request_id", request_id);
+    }
+    if let Some(request_id) = parse_extended_request_id(&parts.headers) {
+        err = err.with_context("extended_request_id", request_id);
+    }
+
     // The following headers may contains sensitive information.
     parts.headers.remove("Set-Cookie");
     parts.headers.remove("WWW-Authenticate");
@@ -66,3 +108,44 @@ pub fn with_error_response_context(mut err: Error, mut parts: Parts) -> Error {
 
     err
 }
+
+#[cfg(test)]
+mod tests {
+    use http::Response;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_http_status() {
+        let cases = [
+            (StatusCode::NOT_FOUND, ErrorKind::NotFound, false),
+            (StatusCode::UNAUTHORIZED, ErrorKind::PermissionDenied, false),
+            (StatusCode::TOO_MANY_REQUESTS, ErrorKind::RateLimited, true),
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                ErrorKind::Unavailable,
+                true,
+            ),
+            (StatusCode::BAD_GATEWAY, ErrorKind::Unexpected, true),
+            (StatusCode::BAD_REQUEST, ErrorKind::Unexpected, false),
+        ];
+
+        for (status, kind, retryable) in cases {
+            assert_eq!(parse_http_status(status), (kind, retryable), "{status}");
+        }
+    }
+
+    #[test]
+    fn test_with_error_response_context_request_id() {
+        let (parts, _) = Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .header("x-amz-request-id", "4442587FB7D0A2F9")
+            .body(())
+            .unwrap()
+            .into_parts();
+
+        let err =
+            with_error_response_context(Error::new(ErrorKind::Unavailable, "slow down"), parts);
+        assert!(err.to_string().contains("request_id: 4442587FB7D0A2F9"));
+    }
+}
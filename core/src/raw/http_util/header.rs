@@ -76,6 +76,32 @@ pub fn parse_content_md5(headers: &HeaderMap) -> Result<Option<&str>> {
     parse_header_to_str(headers, "content-md5")
 }
 
+/// Parse content md5 from etag.
+///
+/// Services like s3 use the hex encoded md5 of content as etag for objects
+/// uploaded in a single request. This function converts such etag into the
+/// base64 encoded format used by `Content-MD5`.
+///
+/// Returns `None` if the etag is not a plain md5, for example the etag of
+/// multipart uploaded objects like `"9b2cf535f27731c974343645a3985328-2"`.
+///
+/// # Notes
+///
+/// Objects encrypted by SSE-KMS or SSE-C don't use md5 as etag, callers
+/// should check that before using this function.
+pub fn parse_content_md5_from_etag(etag: &str) -> Option<String> {
+    let etag = etag.trim_start_matches("W/").trim_matches('"');
+    if etag.len() != 32 {
+        return None;
+    }
+
+    let mut bs = [0u8; 16];
+    for (idx, b) in bs.iter_mut().enumerate() {
+        *b = u8::from_str_radix(etag.get(idx * 2..idx * 2 + 2)?, 16).ok()?;
+    }
+    Some(general_purpose::STANDARD.encode(bs))
+}
+
 /// Parse content type from header map.
 pub fn parse_content_type(headers: &HeaderMap) -> Result<Option<&str>> {
     parse_header_to_str(headers, CONTENT_TYPE)
@@ -105,6 +131,37 @@ pub fn parse_etag(headers: &HeaderMap) -> Result<Option<&str>> {
     parse_header_to_str(headers, ETAG)
 }
 
+/// Headers that services used to return the request id.
+const REQUEST_ID_HEADERS: &[&str] = &[
+    "x-amz-request-id",
+    "x-ms-request-id",
+    "x-oss-request-id",
+    "x-obs-request-id",
+    "x-cos-request-id",
+    "x-goog-request-id",
+    "x-guploader-request-id",
+    "x-request-id",
+];
+
+/// Headers that services used to return the extended request id.
+///
+/// Provider support teams usually require it along with the request id.
+const EXTENDED_REQUEST_ID_HEADERS: &[&str] = &["x-amz-id-2"];
+
+/// Parse the request id returned by services from header map.
+pub fn parse_request_id(headers: &HeaderMap) -> Option<&str> {
+    REQUEST_ID_HEADERS
+        .iter()
+        .find_map(|k| headers.get(*k).and_then(|v| v.to_str().ok()))
+}
+
+/// Parse the extended request id returned by services from header map.
+pub fn parse_extended_request_id(headers: &HeaderMap) -> Option<&str> {
+    EXTENDED_REQUEST_ID_HEADERS
+        .iter()
+        .find_map(|k| headers.get(*k).and_then(|v| v.to_str().ok()))
+}
+
 /// Parse Content-Disposition for header map
 pub fn parse_content_disposition(headers: &HeaderMap) -> Result<Option<&str>> {
     parse_header_to_str(headers, CONTENT_DISPOSITION)
@@ -273,6 +330,29 @@ pub fn build_header_value(v: &str) -> Result<HeaderValue> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_content_md5_from_etag() {
+        let cases = vec![
+            (
+                "plain md5",
+                "\"7c7704bf5bcfc00e9e4cfab1b9ab1772\"",
+                Some("fHcEv1vPwA6eTPqxuasXcg=="),
+            ),
+            (
+                "multipart etag",
+                "\"9b2cf535f27731c974343645a3985328-2\"",
+                None,
+            ),
+            ("invalid hex", "\"zz7704bf5bcfc00e9e4cfab1b9ab1772\"", None),
+            ("gcs etag", "CKWasoTgyPkCEAE=", None),
+        ];
+
+        for (name, input, expected) in cases {
+            let actual = parse_content_md5_from_etag(input);
+            assert_eq!(actual.as_deref(), expected, "{name}");
+        }
+    }
+
     /// Test cases is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html
     #[test]
     fn test_format_content_md5() {
@@ -353,4 +433,45 @@ mod tests {
             assert_eq!(actual, expected)
         }
     }
+
+    #[test]
+    fn test_parse_request_id() {
+        let cases = vec![
+            (
+                "s3",
+                vec![("x-amz-request-id", "4442587FB7D0A2F9")],
+                Some("4442587FB7D0A2F9"),
+            ),
+            (
+                "azure",
+                vec![("x-ms-request-id", "abc-123")],
+                Some("abc-123"),
+            ),
+            (
+                "gcs",
+                vec![("x-guploader-request-id", "ABg5-Uw")],
+                Some("ABg5-Uw"),
+            ),
+            (
+                "azure client request id is not the request id",
+                vec![("x-ms-client-request-id", "my-client-id")],
+                None,
+            ),
+            (
+                "gcs upload id is not the request id",
+                vec![("x-guploader-uploadid", "ABg5-Uw")],
+                None,
+            ),
+            ("none", vec![("content-type", "text/plain")], None),
+        ];
+
+        for (name, input, expected) in cases {
+            let mut headers = HeaderMap::new();
+            for (k, v) in input {
+                headers.insert(k, HeaderValue::from_static(v));
+            }
+
+            assert_eq!(parse_request_id(&headers), expected, "{name}");
+        }
+    }
 }
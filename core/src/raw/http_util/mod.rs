@@ -24,7 +24,9 @@
 
 mod client;
 pub use client::HttpClient;
+pub use client::HttpClientConfig;
 pub use client::HttpFetch;
+pub use client::HttpIntercept;
 
 /// temporary client used by several features
 #[allow(unused_imports)]
@@ -38,19 +40,23 @@ pub use header::build_header_value;
 pub use header::format_authorization_by_basic;
 pub use header::format_authorization_by_bearer;
 pub use header::format_content_md5;
+pub use header::parse_cache_control;
 pub use header::parse_content_disposition;
 pub use header::parse_content_encoding;
 pub use header::parse_content_length;
 pub use header::parse_content_md5;
+pub use header::parse_content_md5_from_etag;
 pub use header::parse_content_range;
 pub use header::parse_content_type;
 pub use header::parse_etag;
+pub use header::parse_extended_request_id;
 pub use header::parse_header_to_str;
 pub use header::parse_into_metadata;
 pub use header::parse_last_modified;
 pub use header::parse_location;
 pub use header::parse_multipart_boundary;
 pub use header::parse_prefixed_headers;
+pub use header::parse_request_id;
 
 mod uri;
 pub use uri::percent_decode_path;
@@ -60,6 +66,7 @@ mod error;
 pub use error::new_request_build_error;
 pub use error::new_request_credential_error;
 pub use error::new_request_sign_error;
+pub use error::parse_http_status;
 pub use error::with_error_response_context;
 
 mod bytes_range;
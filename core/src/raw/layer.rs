@@ -214,6 +214,18 @@ pub trait LayeredAccess: Send + Sync + Debug + Unpin + 'static {
         self.inner().presign(path, args)
     }
 
+    fn tag(&self, path: &str, args: OpTag) -> impl Future<Output = Result<RpTag>> + MaybeSend {
+        self.inner().tag(path, args)
+    }
+
+    fn concat(
+        &self,
+        path: &str,
+        args: OpConcat,
+    ) -> impl Future<Output = Result<RpConcat>> + MaybeSend {
+        self.inner().concat(path, args)
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.inner().blocking_create_dir(path, args)
     }
@@ -290,6 +302,14 @@ impl<L: LayeredAccess> Access for L {
         LayeredAccess::presign(self, path, args).await
     }
 
+    async fn tag(&self, path: &str, args: OpTag) -> Result<RpTag> {
+        LayeredAccess::tag(self, path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        LayeredAccess::concat(self, path, args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         LayeredAccess::blocking_create_dir(self, path, args)
     }
@@ -70,6 +70,8 @@ mod std_io_util;
 pub use std_io_util::*;
 
 mod futures_util;
+pub(crate) use futures_util::sleep;
+pub(crate) use futures_util::timeout;
 pub use futures_util::BoxedFuture;
 pub use futures_util::BoxedStaticFuture;
 pub use futures_util::ConcurrentFutures;
@@ -16,6 +16,7 @@
 // under the License.
 
 use std::future::Future;
+use std::ops::Deref;
 use std::ops::DerefMut;
 
 use crate::raw::oio::Entry;
@@ -32,6 +33,16 @@ pub trait List: Unpin + Send + Sync {
     /// `Ok(None)` means all pages have been returned. Any following call
     /// to `next` will always get the same result.
     fn next(&mut self) -> impl Future<Output = Result<Option<Entry>>> + MaybeSend;
+
+    /// Return the continuation token that can be used to resume the list
+    /// right after the entries that have been returned.
+    ///
+    /// Only listers backed by services with native continuation tokens will
+    /// return `Some`, and only at page boundaries. `None` means the token is
+    /// not available for now.
+    fn continuation_token(&self) -> Option<String> {
+        None
+    }
 }
 
 impl List for () {
@@ -47,22 +58,36 @@ impl<P: List> List for Option<P> {
             None => Ok(None),
         }
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.as_ref().and_then(|p| p.continuation_token())
+    }
 }
 
 pub trait ListDyn: Unpin + Send + Sync {
     fn next_dyn(&mut self) -> BoxedFuture<Result<Option<Entry>>>;
+
+    fn continuation_token_dyn(&self) -> Option<String>;
 }
 
 impl<T: List + ?Sized> ListDyn for T {
     fn next_dyn(&mut self) -> BoxedFuture<Result<Option<Entry>>> {
         Box::pin(self.next())
     }
+
+    fn continuation_token_dyn(&self) -> Option<String> {
+        self.continuation_token()
+    }
 }
 
 impl<T: ListDyn + ?Sized> List for Box<T> {
     async fn next(&mut self) -> Result<Option<Entry>> {
         self.deref_mut().next_dyn().await
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.deref().continuation_token_dyn()
+    }
 }
 
 /// BlockingList is the blocking version of [`List`].
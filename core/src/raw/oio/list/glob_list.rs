@@ -0,0 +1,89 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::raw::*;
+use crate::*;
+
+/// GlobLister is used to filter entries by glob pattern.
+///
+/// Entries are matched by their path without the trailing `/`, see
+/// [`glob_match`] for the supported syntax.
+///
+/// For example, if we have a lister that returns entries:
+///
+/// ```txt
+/// .
+/// ├── dir/a.txt
+/// └── dir/b.csv
+/// ```
+///
+/// We can use `GlobLister` with pattern `dir/*.txt` to keep only `dir/a.txt`.
+pub struct GlobLister<L> {
+    lister: L,
+    pattern: String,
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for GlobLister.
+unsafe impl<L> Sync for GlobLister<L> {}
+
+impl<L> GlobLister<L> {
+    /// Create a new glob lister
+    pub fn new(lister: L, pattern: &str) -> GlobLister<L> {
+        GlobLister {
+            lister,
+            pattern: pattern.to_string(),
+        }
+    }
+
+    fn is_match(&self, e: &oio::Entry) -> bool {
+        glob_match(&self.pattern, e.path().trim_end_matches('/'))
+    }
+}
+
+impl<L> oio::List for GlobLister<L>
+where
+    L: oio::List,
+{
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            match self.lister.next().await {
+                Ok(Some(e)) if !self.is_match(&e) => continue,
+                v => return v,
+            }
+        }
+    }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.lister.continuation_token()
+    }
+}
+
+impl<L> oio::BlockingList for GlobLister<L>
+where
+    L: oio::BlockingList,
+{
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            match self.lister.next() {
+                Ok(Some(e)) if !self.is_match(&e) => continue,
+                v => return v,
+            }
+        }
+    }
+}
@@ -34,3 +34,6 @@ pub use hierarchy_list::HierarchyLister;
 
 mod prefix_list;
 pub use prefix_list::PrefixLister;
+
+mod glob_list;
+pub use glob_list::GlobLister;
@@ -44,7 +44,7 @@ pub trait PageList: Send + Sync + Unpin + 'static {
 /// based on their needs.
 ///
 /// - Set `done` to `true` if all page have been fetched.
-/// - Update `token` if there is more page to fetch. `token` is not exposed to users, it's internal used only.
+/// - Update `token` if there is more page to fetch. `token` is opaque to users, they can only use it to resume the list.
 /// - Push back into the entries for each entry fetched from underlying storage.
 ///
 /// NOTE: `entries` is a `VecDeque` to avoid unnecessary memory allocation. Only `push_back` is allowed.
@@ -82,6 +82,17 @@ where
             },
         }
     }
+
+    /// Resume the list from given continuation token.
+    ///
+    /// The token must be returned by [`oio::List::continuation_token`] of
+    /// the same service.
+    pub fn with_start_token(mut self, token: Option<&str>) -> Self {
+        if let Some(token) = token {
+            self.ctx.token = token.to_string();
+        }
+        self
+    }
 }
 
 impl<L> oio::List for PageLister<L>
@@ -100,4 +111,12 @@ where
             self.inner.next_page(&mut self.ctx).await?;
         }
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        // Token is only valid after all entries of current page are consumed.
+        if self.ctx.done || !self.ctx.entries.is_empty() || self.ctx.token.is_empty() {
+            return None;
+        }
+        Some(self.ctx.token.clone())
+    }
 }
@@ -61,6 +61,10 @@ where
             }
         }
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.lister.continuation_token()
+    }
 }
 
 impl<L> oio::BlockingList for PrefixLister<L>
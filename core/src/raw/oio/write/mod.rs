@@ -23,6 +23,7 @@ pub use api::Writer;
 
 mod multipart_write;
 pub use multipart_write::MultipartPart;
+pub use multipart_write::MultipartProgress;
 pub use multipart_write::MultipartWrite;
 pub use multipart_write::MultipartWriter;
 
@@ -41,3 +42,7 @@ pub use block_write::BlockWriter;
 mod position_write;
 pub use position_write::PositionWrite;
 pub use position_write::PositionWriter;
+
+mod range_write;
+pub use range_write::RangeWrite;
+pub use range_write::RangeWriter;
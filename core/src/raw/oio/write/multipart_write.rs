@@ -15,7 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::VecDeque;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use futures::select;
 use futures::Future;
@@ -47,6 +49,19 @@ use crate::*;
 ///
 /// We will use `write_once` instead of starting a new multipart upload.
 ///
+/// # Cancellation
+///
+/// `MultipartWriter` is safe to be cancelled at any await point: `write` and
+/// `close` only update the internal state after the underlying call succeeded,
+/// so callers can retry them after the future has been dropped.
+///
+/// Once `close` succeeded, calling `close` again is a no-op.
+///
+/// If `MultipartWriter` is dropped with an ongoing multipart upload that has
+/// not been completed or aborted, it will try to call `abort_part` in
+/// background via the executor to avoid leaking uploaded parts. Uploads tracked
+/// by [`MultipartProgress`] will be kept instead so that they can be resumed.
+///
 /// # Requirements
 ///
 /// Services that implement `BlockWrite` must fulfill the following requirements:
@@ -106,7 +121,7 @@ pub trait MultipartWrite: Send + Sync + Unpin + 'static {
 /// - `part_number` is the index of the part, starting from 0.
 /// - `etag` is the `ETag` of the part.
 /// - `checksum` is the optional checksum of the part.
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MultipartPart {
     /// The number of the part, starting from 0.
     pub part_number: usize,
@@ -116,6 +131,43 @@ pub struct MultipartPart {
     pub checksum: Option<String>,
 }
 
+/// MultipartProgress shares the progress of a multipart upload between
+/// [`MultipartWriter`] and its caller, so that the upload can be persisted and
+/// resumed later.
+///
+/// Only contiguous parts starting from the first one are recorded, a part that
+/// finished out of order will be recorded after all parts before it finished.
+#[derive(Clone, Debug, Default)]
+pub struct MultipartProgress(Arc<Mutex<Option<UploadProgress>>>);
+
+impl MultipartProgress {
+    /// Create a new progress for a fresh upload.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a progress to resume the given upload.
+    pub fn resume(progress: UploadProgress) -> Self {
+        Self(Arc::new(Mutex::new(Some(progress))))
+    }
+
+    /// Get the snapshot of the progress, returns `None` if there is no ongoing upload.
+    pub fn get(&self) -> Option<UploadProgress> {
+        self.0.lock().unwrap().clone()
+    }
+
+    fn set(&self, progress: Option<UploadProgress>) {
+        *self.0.lock().unwrap() = progress;
+    }
+
+    fn push(&self, part: MultipartPart, size: u64) {
+        if let Some(progress) = self.0.lock().unwrap().as_mut() {
+            progress.parts.push(part.into());
+            progress.size += size;
+        }
+    }
+}
+
 struct WriteInput<W: MultipartWrite> {
     w: Arc<W>,
     executor: Executor,
@@ -132,8 +184,12 @@ pub struct MultipartWriter<W: MultipartWrite> {
 
     upload_id: Option<Arc<String>>,
     parts: Vec<MultipartPart>,
+    /// Sizes of the parts that have been submitted but not finished yet.
+    pending_sizes: VecDeque<u64>,
+    progress: Option<MultipartProgress>,
     cache: Option<Buffer>,
     next_part_number: usize,
+    completed: bool,
 
     tasks: ConcurrentTasks<WriteInput<W>, MultipartPart>,
 }
@@ -151,8 +207,11 @@ impl<W: MultipartWrite> MultipartWriter<W> {
             executor: executor.clone(),
             upload_id: None,
             parts: Vec::new(),
+            pending_sizes: VecDeque::new(),
+            progress: None,
             cache: None,
             next_part_number: 0,
+            completed: false,
 
             tasks: ConcurrentTasks::new(executor, concurrent, |input| {
                 Box::pin({
@@ -190,6 +249,32 @@ impl<W: MultipartWrite> MultipartWriter<W> {
         }
     }
 
+    /// Track the progress of the upload via given [`MultipartProgress`].
+    ///
+    /// If the progress is not empty, the writer will resume the upload from it
+    /// instead of starting a new one.
+    pub fn with_progress(mut self, progress: Option<MultipartProgress>) -> Self {
+        if let Some(resume) = progress.as_ref().and_then(|v| v.get()) {
+            self.upload_id = Some(Arc::new(resume.upload_id));
+            self.next_part_number = resume.parts.len();
+            self.parts = resume.parts.into_iter().map(MultipartPart::from).collect();
+        }
+        self.progress = progress;
+        self
+    }
+
+    /// Record the finished part, parts must be finished in order.
+    fn finish_part(&mut self, part: MultipartPart) {
+        let size = self
+            .pending_sizes
+            .pop_front()
+            .expect("finished part must be pending");
+        if let Some(progress) = &self.progress {
+            progress.push(part.clone(), size);
+        }
+        self.parts.push(part);
+    }
+
     fn fill_cache(&mut self, bs: Buffer) -> usize {
         let size = bs.len();
         assert!(self.cache.is_none());
@@ -198,21 +283,57 @@ impl<W: MultipartWrite> MultipartWriter<W> {
     }
 }
 
+impl<W: MultipartWrite> Drop for MultipartWriter<W> {
+    fn drop(&mut self) {
+        if self.completed {
+            return;
+        }
+        let Some(upload_id) = self.upload_id.take() else {
+            return;
+        };
+        if self.progress.is_some() {
+            log::debug!("multipart upload {upload_id} is kept on drop for resuming");
+            return;
+        }
+
+        self.tasks.clear();
+        let w = self.w.clone();
+        let detached = self.executor.detach(async move {
+            if let Err(err) = w.abort_part(&upload_id).await {
+                log::warn!("abort multipart upload {upload_id} on drop failed: {err}");
+            }
+        });
+        if !detached {
+            log::warn!("multipart upload has been dropped without close or abort, uploaded parts may be leaked");
+        }
+    }
+}
+
 impl<W> oio::Write for MultipartWriter<W>
 where
     W: MultipartWrite,
 {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
+        if self.completed {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "multipart upload has been completed",
+            ));
+        }
+
+        // Fill cache with the first write.
+        if self.cache.is_none() {
+            self.fill_cache(bs);
+            return Ok(());
+        }
+
         let upload_id = match self.upload_id.clone() {
             Some(v) => v,
             None => {
-                // Fill cache with the first write.
-                if self.cache.is_none() {
-                    self.fill_cache(bs);
-                    return Ok(());
-                }
-
                 let upload_id = self.w.initiate_part().await?;
+                if let Some(progress) = &self.progress {
+                    progress.set(Some(UploadProgress::new(&upload_id)));
+                }
                 let upload_id = Arc::new(upload_id);
                 self.upload_id = Some(upload_id.clone());
                 upload_id
@@ -220,6 +341,7 @@ where
         };
 
         let bytes = self.cache.clone().expect("pending write must exist");
+        let size = bytes.len() as u64;
         let part_number = self.next_part_number;
 
         self.tasks
@@ -231,13 +353,22 @@ where
                 bytes,
             })
             .await?;
+        self.pending_sizes.push_back(size);
         self.cache = None;
         self.next_part_number += 1;
         self.fill_cache(bs);
+
+        while let Some(part) = self.tasks.try_next() {
+            self.finish_part(part);
+        }
         Ok(())
     }
 
     async fn close(&mut self) -> Result<()> {
+        if self.completed {
+            return Ok(());
+        }
+
         let upload_id = match self.upload_id.clone() {
             Some(v) => v,
             None => {
@@ -248,11 +379,13 @@ where
                 // Call write_once if there is no upload_id.
                 self.w.write_once(size as u64, body).await?;
                 self.cache = None;
+                self.completed = true;
                 return Ok(());
             }
         };
 
         if let Some(cache) = self.cache.clone() {
+            let size = cache.len() as u64;
             let part_number = self.next_part_number;
 
             self.tasks
@@ -264,6 +397,7 @@ where
                     bytes: cache,
                 })
                 .await?;
+            self.pending_sizes.push_back(size);
             self.cache = None;
             self.next_part_number += 1;
         }
@@ -272,7 +406,7 @@ where
             let Some(result) = self.tasks.next().await.transpose()? else {
                 break;
             };
-            self.parts.push(result)
+            self.finish_part(result)
         }
 
         if self.parts.len() != self.next_part_number {
@@ -284,17 +418,34 @@ where
             .with_context("actual", self.parts.len())
             .with_context("upload_id", upload_id));
         }
-        self.w.complete_part(&upload_id, &self.parts).await
+        self.w.complete_part(&upload_id, &self.parts).await?;
+        self.completed = true;
+        if let Some(progress) = &self.progress {
+            progress.set(None);
+        }
+        Ok(())
     }
 
     async fn abort(&mut self) -> Result<()> {
+        if self.completed {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "multipart upload has been completed, can't abort",
+            ));
+        }
+
         let Some(upload_id) = self.upload_id.clone() else {
             return Ok(());
         };
 
         self.tasks.clear();
+        self.pending_sizes.clear();
         self.cache = None;
         self.w.abort_part(&upload_id).await?;
+        self.upload_id = None;
+        if let Some(progress) = &self.progress {
+            progress.set(None);
+        }
         Ok(())
     }
 }
@@ -318,6 +469,7 @@ mod tests {
         upload_id: String,
         part_numbers: Vec<usize>,
         length: u64,
+        aborted: bool,
     }
 
     impl TestWrite {
@@ -326,6 +478,7 @@ mod tests {
                 upload_id: uuid::Uuid::new_v4().to_string(),
                 part_numbers: Vec::new(),
                 length: 0,
+                aborted: false,
             };
 
             Arc::new(Mutex::new(v))
@@ -387,8 +540,9 @@ mod tests {
         }
 
         async fn abort_part(&self, upload_id: &str) -> Result<()> {
-            let test = self.lock().await;
+            let mut test = self.lock().await;
             assert_eq!(upload_id, test.upload_id);
+            test.aborted = true;
 
             Ok(())
         }
@@ -456,11 +610,85 @@ mod tests {
             }
         }
 
-        let actual_parts: Vec<_> = w.parts.into_iter().map(|v| v.part_number).collect();
+        // Close again after success must be a no-op.
+        w.close().await.expect("close again must succeed");
+
+        let actual_parts: Vec<_> = w.parts.iter().map(|v| v.part_number).collect();
         let expected_parts: Vec<_> = (0..1000).collect();
         assert_eq!(actual_parts, expected_parts);
 
         let actual_size = w.w.lock().await.length;
         assert_eq!(actual_size, total_size);
     }
+
+    async fn write_until_success(w: &mut MultipartWriter<Arc<Mutex<TestWrite>>>, bs: Buffer) {
+        while w.write(bs.clone()).await.is_err() {}
+    }
+
+    #[tokio::test]
+    async fn test_multipart_writer_abort_on_drop() {
+        let test = TestWrite::new();
+        let mut w = MultipartWriter::new(test.clone(), Some(Executor::new()), 4);
+
+        for _ in 0..3 {
+            write_until_success(&mut w, Buffer::from(vec![0; 16])).await;
+        }
+        assert!(w.upload_id.is_some());
+
+        // Drop the writer without close or abort.
+        drop(w);
+        sleep(Duration::from_millis(100)).await;
+
+        assert!(test.lock().await.aborted);
+    }
+
+    #[tokio::test]
+    async fn test_multipart_writer_no_abort_after_close() {
+        let test = TestWrite::new();
+        let mut w = MultipartWriter::new(test.clone(), Some(Executor::new()), 4);
+
+        for _ in 0..3 {
+            write_until_success(&mut w, Buffer::from(vec![0; 16])).await;
+        }
+        while w.close().await.is_err() {}
+        assert!(w.abort().await.is_err());
+
+        drop(w);
+        sleep(Duration::from_millis(100)).await;
+
+        assert!(!test.lock().await.aborted);
+    }
+
+    #[tokio::test]
+    async fn test_multipart_writer_resume() {
+        let test = TestWrite::new();
+        let progress = MultipartProgress::new();
+        let mut w = MultipartWriter::new(test.clone(), Some(Executor::new()), 1)
+            .with_progress(Some(progress.clone()));
+
+        for _ in 0..4 {
+            write_until_success(&mut w, Buffer::from(vec![0; 16])).await;
+        }
+        // The last write is still cached, only the first three parts are finished.
+        let snapshot = progress.get().expect("progress must exist");
+        assert_eq!(snapshot.upload_id, test.lock().await.upload_id);
+        assert_eq!(snapshot.parts.len(), 3);
+        assert_eq!(snapshot.size, 48);
+
+        // Resumable upload must be kept after drop.
+        drop(w);
+        sleep(Duration::from_millis(100)).await;
+        assert!(!test.lock().await.aborted);
+
+        let progress = MultipartProgress::resume(snapshot);
+        let mut w = MultipartWriter::new(test.clone(), Some(Executor::new()), 1)
+            .with_progress(Some(progress.clone()));
+        write_until_success(&mut w, Buffer::from(vec![0; 16])).await;
+        while w.close().await.is_err() {}
+
+        let actual_parts: Vec<_> = w.parts.iter().map(|v| v.part_number).collect();
+        assert_eq!(actual_parts, vec![0, 1, 2, 3]);
+        assert_eq!(test.lock().await.length, 64);
+        assert!(progress.get().is_none());
+    }
 }
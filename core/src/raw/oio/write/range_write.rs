@@ -0,0 +1,357 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Buf;
+use futures::Future;
+
+use crate::raw::*;
+use crate::*;
+
+/// RangeWrite is used to implement [`oio::Write`] based on range write,
+/// like the resumable upload of GCS. By implementing RangeWrite, services
+/// don't need to care about the details of uploading ranges.
+///
+/// # Architecture
+///
+/// The architecture after adopting [`RangeWrite`]:
+///
+/// - Services impl `RangeWrite`
+/// - `RangeWriter` impl `Write`
+/// - Expose `RangeWriter` as `Accessor::Writer`
+///
+/// # Notes
+///
+/// `RangeWrite` has an oneshot optimization when `write` has been called only once:
+///
+/// ```no_build
+/// w.write(bs).await?;
+/// w.close().await?;
+/// ```
+///
+/// We will use `write_once` instead of starting a new range write.
+///
+/// # Requirements
+///
+/// Services that implement `RangeWrite` must fulfill the following requirements:
+///
+/// - Must be a http service that could accept `AsyncBody`.
+/// - Need initialization before writing, and the initialization returns a location.
+/// - Ranges must be written in order, the last range must be written by `complete_range`.
+///
+/// Ranges are written one by one, so `concurrent` doesn't take effect for `RangeWriter`.
+pub trait RangeWrite: Send + Sync + Unpin + 'static {
+    /// write_once is used to write the data to underlying storage at once.
+    ///
+    /// RangeWriter will call this API when:
+    ///
+    /// - All the data has been written to the buffer and we can perform the upload at once.
+    fn write_once(&self, size: u64, body: Buffer) -> impl Future<Output = Result<()>> + MaybeSend;
+
+    /// initiate_range will start a range write and return the location
+    /// that following ranges will be written to.
+    fn initiate_range(&self) -> impl Future<Output = Result<String>> + MaybeSend;
+
+    /// write_range will write a range of data starting at `offset`.
+    ///
+    /// Returns the size of data that has been persisted by service, which
+    /// could be less than `size`. RangeWriter will write the rest again.
+    fn write_range(
+        &self,
+        location: &str,
+        offset: u64,
+        size: u64,
+        body: Buffer,
+    ) -> impl Future<Output = Result<u64>> + MaybeSend;
+
+    /// complete_range will write the last range and complete the range write.
+    ///
+    /// `body` could be empty if all data has been written by `write_range`.
+    fn complete_range(
+        &self,
+        location: &str,
+        offset: u64,
+        size: u64,
+        body: Buffer,
+    ) -> impl Future<Output = Result<()>> + MaybeSend;
+
+    /// abort_range will cancel the range write and purge all data.
+    fn abort_range(&self, location: &str) -> impl Future<Output = Result<()>> + MaybeSend;
+}
+
+/// RangeWriter will implements [`oio::Write`] based on range write.
+pub struct RangeWriter<W: RangeWrite> {
+    w: W,
+
+    location: Option<String>,
+    next_offset: u64,
+    cache: Option<Buffer>,
+}
+
+impl<W: RangeWrite> RangeWriter<W> {
+    /// Create a new RangeWriter.
+    pub fn new(inner: W) -> Self {
+        Self {
+            w: inner,
+            location: None,
+            next_offset: 0,
+            cache: None,
+        }
+    }
+
+    fn fill_cache(&mut self, bs: Buffer) -> usize {
+        let size = bs.len();
+        assert!(self.cache.is_none());
+        self.cache = Some(bs);
+        size
+    }
+}
+
+impl<W: RangeWrite> oio::Write for RangeWriter<W> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let location = match self.location.clone() {
+            Some(v) => v,
+            None => {
+                // Fill cache with the first write.
+                if self.cache.is_none() {
+                    self.fill_cache(bs);
+                    return Ok(());
+                }
+
+                let location = self.w.initiate_range().await?;
+                self.location = Some(location.clone());
+                location
+            }
+        };
+
+        let Some(mut bytes) = self.cache.clone() else {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "range writer has been closed or aborted",
+            ));
+        };
+        while !bytes.is_empty() {
+            let size = bytes.len() as u64;
+            let written = self
+                .w
+                .write_range(&location, self.next_offset, size, bytes.clone())
+                .await?;
+            if written == 0 {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "no data has been persisted by range write",
+                )
+                .set_temporary());
+            }
+            // Only update the state after range has been written, so that
+            // the failed range could be retried.
+            self.next_offset += written;
+            bytes.advance(written as usize);
+            self.cache = Some(bytes.clone());
+        }
+        self.cache = None;
+        self.fill_cache(bs);
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let body = self.cache.clone().unwrap_or_default();
+        let size = body.len() as u64;
+
+        match self.location.clone() {
+            Some(_) if self.cache.is_none() => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "range writer has been closed or aborted",
+                ));
+            }
+            Some(location) => {
+                self.w
+                    .complete_range(&location, self.next_offset, size, body)
+                    .await?;
+                self.next_offset += size;
+            }
+            // Call write_once if there is no location.
+            None => self.w.write_once(size, body).await?,
+        }
+
+        self.cache = None;
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let Some(location) = self.location.clone() else {
+            return Ok(());
+        };
+
+        self.cache = None;
+        self.w.abort_range(&location).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+    use rand::thread_rng;
+    use rand::Rng;
+    use rand::RngCore;
+    use tokio::sync::Mutex;
+
+    use super::*;
+    use crate::raw::oio::Write;
+
+    struct TestWrite {
+        length: u64,
+        bytes: Vec<u8>,
+        ranges: Vec<(u64, u64)>,
+        completed: bool,
+        /// Only persist up to this size for every range if set.
+        persist_limit: Option<u64>,
+    }
+
+    impl TestWrite {
+        pub fn new() -> Arc<Mutex<Self>> {
+            let v = Self {
+                length: 0,
+                bytes: Vec::new(),
+                ranges: Vec::new(),
+                completed: false,
+                persist_limit: None,
+            };
+
+            Arc::new(Mutex::new(v))
+        }
+    }
+
+    impl RangeWrite for Arc<Mutex<TestWrite>> {
+        async fn write_once(&self, size: u64, body: Buffer) -> Result<()> {
+            let mut test = self.lock().await;
+            test.length += size;
+            test.bytes.extend_from_slice(&body.to_vec());
+            test.completed = true;
+            Ok(())
+        }
+
+        async fn initiate_range(&self) -> Result<String> {
+            Ok(uuid::Uuid::new_v4().to_string())
+        }
+
+        async fn write_range(&self, _: &str, offset: u64, size: u64, body: Buffer) -> Result<u64> {
+            let mut test = self.lock().await;
+            assert_eq!(offset, test.length, "range must be written in order");
+            let size = test.persist_limit.map_or(size, |v| v.min(size));
+            test.length += size;
+            test.bytes
+                .extend_from_slice(&body.slice(..size as usize).to_vec());
+            test.ranges.push((offset, size));
+            Ok(size)
+        }
+
+        async fn complete_range(
+            &self,
+            _: &str,
+            offset: u64,
+            size: u64,
+            body: Buffer,
+        ) -> Result<()> {
+            let mut test = self.lock().await;
+            assert_eq!(offset, test.length, "range must be written in order");
+            test.length += size;
+            test.bytes.extend_from_slice(&body.to_vec());
+            test.ranges.push((offset, size));
+            test.completed = true;
+            Ok(())
+        }
+
+        async fn abort_range(&self, _: &str) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_range_writer_write_once() -> Result<()> {
+        let test = TestWrite::new();
+        let mut w = RangeWriter::new(test.clone());
+
+        w.write(Buffer::from("hello")).await?;
+        w.close().await?;
+
+        let test = test.lock().await;
+        assert!(test.completed);
+        assert!(test.ranges.is_empty());
+        assert_eq!(test.bytes, b"hello");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_range_writer_with_partial_persisted() -> Result<()> {
+        let test = TestWrite::new();
+        test.lock().await.persist_limit = Some(2);
+        let mut w = RangeWriter::new(test.clone());
+
+        w.write(Buffer::from("hello")).await?;
+        w.write(Buffer::from("world")).await?;
+        w.close().await?;
+
+        let test = test.lock().await;
+        assert!(test.completed);
+        assert_eq!(test.ranges, vec![(0, 2), (2, 2), (4, 1), (5, 5)]);
+        assert_eq!(test.bytes, b"helloworld");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_range_writer_write_after_abort() -> Result<()> {
+        let test = TestWrite::new();
+        let mut w = RangeWriter::new(test.clone());
+
+        w.write(Buffer::from("hello")).await?;
+        w.write(Buffer::from("world")).await?;
+        w.abort().await?;
+
+        let err = w.write(Buffer::from("again")).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        let err = w.close().await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_range_writer_with_random_chunks() -> Result<()> {
+        let mut rng = thread_rng();
+
+        let test = TestWrite::new();
+        let mut w = RangeWriter::new(test.clone());
+
+        let mut expected = Vec::new();
+        for _ in 0..rng.gen_range(2..10) {
+            let mut bs = vec![0; rng.gen_range(1..1024)];
+            rng.fill_bytes(&mut bs);
+            expected.extend_from_slice(&bs);
+            w.write(Buffer::from(bs)).await?;
+        }
+        w.close().await?;
+
+        let test = test.lock().await;
+        assert!(test.completed);
+        assert_eq!(test.length, expected.len() as u64);
+        assert_eq!(test.bytes, expected);
+        Ok(())
+    }
+}
@@ -57,6 +57,10 @@ pub enum Operation {
     ListerNext,
     /// Operation for [`crate::raw::Access::presign`]
     Presign,
+    /// Operation for [`crate::raw::Access::tag`]
+    Tag,
+    /// Operation for [`crate::raw::Access::concat`]
+    Concat,
     /// Operation for [`crate::raw::Access::blocking_create_dir`]
     BlockingCreateDir,
     /// Operation for [`crate::raw::Access::blocking_read`]
@@ -137,6 +141,8 @@ impl From<Operation> for &'static str {
             Operation::List => "list",
             Operation::ListerNext => "List::next",
             Operation::Presign => "presign",
+            Operation::Tag => "tag",
+            Operation::Concat => "concat",
             Operation::BlockingCreateDir => "blocking_create_dir",
             Operation::BlockingRead => "blocking_read",
             Operation::BlockingReaderRead => "BlockingReader::read",
@@ -29,13 +29,28 @@ use std::time::Duration;
 ///
 /// The path must be normalized.
 #[derive(Debug, Clone, Default)]
-pub struct OpCreateDir {}
+pub struct OpCreateDir {
+    recursive: bool,
+}
 
 impl OpCreateDir {
     /// Create a new `OpCreateDir`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Change the recursive flag of this create_dir operation.
+    ///
+    /// If recursive is true, all missing parent dirs will be created as well.
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Get the recursive flag of this create_dir operation.
+    pub fn recursive(&self) -> bool {
+        self.recursive
+    }
 }
 
 /// Args for `delete` operation.
@@ -97,6 +112,11 @@ pub struct OpList {
     ///
     /// Default to `false`.
     recursive: bool,
+    /// The delimiter is used to group entries by common prefixes.
+    ///
+    /// Only set while users specify a delimiter other than `""` and `"/"`,
+    /// which are represented by `recursive` instead.
+    delimiter: Option<String>,
     /// The concurrent of stat operations inside list operation.
     /// Users could use this to control the number of concurrent stat operation when metadata is unknown.
     ///
@@ -120,6 +140,19 @@ pub struct OpList {
     ///
     /// Default to `false`
     deleted: bool,
+    /// The glob is used to filter the entries returned by list operation.
+    ///
+    /// Only entries whose path (without the trailing `/`) match the pattern will be
+    /// returned. Services that support `list_with_glob` will filter at server side.
+    glob: Option<String>,
+    /// The start_token is used to resume the list operation from the continuation
+    /// token returned by previous list.
+    start_token: Option<String>,
+    /// The max_entries is the hard limit of entries returned by the whole list operation.
+    ///
+    /// Unlike `limit` which only controls the page size of each request, lister will
+    /// stop after `max_entries` entries have been returned.
+    max_entries: Option<usize>,
 }
 
 impl Default for OpList {
@@ -128,9 +161,13 @@ impl Default for OpList {
             limit: None,
             start_after: None,
             recursive: false,
+            delimiter: None,
             concurrent: 1,
             versions: false,
             deleted: false,
+            glob: None,
+            start_token: None,
+            max_entries: None,
         }
     }
 }
@@ -152,6 +189,17 @@ impl OpList {
         self.limit
     }
 
+    /// Change the max entries of this list operation.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// Get the max entries of list operation.
+    pub fn max_entries(&self) -> Option<usize> {
+        self.max_entries
+    }
+
     /// Change the start_after of this list operation.
     pub fn with_start_after(mut self, start_after: &str) -> Self {
         self.start_after = Some(start_after.into());
@@ -179,6 +227,45 @@ impl OpList {
         self.recursive
     }
 
+    /// Change the delimiter of this list operation.
+    ///
+    /// - `""` is the same as `with_recursive(true)`.
+    /// - `"/"` is the same as `with_recursive(false)`.
+    /// - Other delimiters will be pushed down to services that support `list_with_delimiter`.
+    pub fn with_delimiter(mut self, delimiter: &str) -> Self {
+        match delimiter {
+            "" => {
+                self.recursive = true;
+                self.delimiter = None;
+            }
+            "/" => {
+                self.recursive = false;
+                self.delimiter = None;
+            }
+            v => {
+                self.recursive = false;
+                self.delimiter = Some(v.to_string());
+            }
+        }
+        self
+    }
+
+    /// Get the delimiter that should be sent to services.
+    ///
+    /// Returns `""` for recursive list, the custom delimiter if set, or `"/"` otherwise.
+    pub fn delimiter(&self) -> &str {
+        if self.recursive {
+            ""
+        } else {
+            self.delimiter.as_deref().unwrap_or("/")
+        }
+    }
+
+    /// Check if this list operation uses a delimiter other than `""` and `"/"`.
+    pub fn has_custom_delimiter(&self) -> bool {
+        !self.recursive && self.delimiter.is_some()
+    }
+
     /// Change the concurrent of this list operation.
     ///
     /// The default concurrent is 1.
@@ -226,6 +313,106 @@ impl OpList {
     pub fn deleted(&self) -> bool {
         self.deleted
     }
+
+    /// Change the glob pattern of this list operation
+    pub fn with_glob(mut self, glob: &str) -> Self {
+        self.glob = Some(glob.into());
+        self
+    }
+
+    /// Get the glob pattern of this list operation
+    pub fn glob(&self) -> Option<&str> {
+        self.glob.as_deref()
+    }
+
+    /// Change the start_token of this list operation
+    pub fn with_start_token(mut self, start_token: &str) -> Self {
+        self.start_token = Some(start_token.into());
+        self
+    }
+
+    /// Get the start_token of this list operation
+    pub fn start_token(&self) -> Option<&str> {
+        self.start_token.as_deref()
+    }
+}
+
+/// Args for `tag` operation.
+///
+/// The path must be normalized.
+#[derive(Debug, Clone)]
+pub struct OpTag {
+    op: TagOperation,
+}
+
+impl OpTag {
+    /// Create a new `OpTag`.
+    pub fn new(op: TagOperation) -> Self {
+        Self { op }
+    }
+
+    /// Get operation from op.
+    pub fn operation(&self) -> &TagOperation {
+        &self.op
+    }
+
+    /// Consume OpTag into TagOperation.
+    pub fn into_operation(self) -> TagOperation {
+        self.op
+    }
+}
+
+/// Tag operation used by `tag`.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum TagOperation {
+    /// Get all tags of the object.
+    Get,
+    /// Replace all tags of the object with given tags.
+    Set(HashMap<String, String>),
+    /// Remove all tags of the object.
+    Delete,
+}
+
+/// Args for `concat` operation.
+///
+/// The path and sources must be normalized.
+#[derive(Debug, Clone, Default)]
+pub struct OpConcat {
+    sources: Vec<String>,
+    emulate: bool,
+}
+
+impl OpConcat {
+    /// Create a new `OpConcat` with the sources to concat in order.
+    pub fn new(sources: Vec<String>) -> Self {
+        Self {
+            sources,
+            emulate: false,
+        }
+    }
+
+    /// Set whether to emulate concat by reading sources and writing them back
+    /// if services don't support concat natively.
+    pub fn with_emulate(mut self, emulate: bool) -> Self {
+        self.emulate = emulate;
+        self
+    }
+
+    /// Get emulate from op.
+    pub fn emulate(&self) -> bool {
+        self.emulate
+    }
+
+    /// Get sources from op.
+    pub fn sources(&self) -> &[String] {
+        &self.sources
+    }
+
+    /// Consume OpConcat into sources.
+    pub fn into_sources(self) -> Vec<String> {
+        self.sources
+    }
 }
 
 /// Args for `presign` operation.
@@ -234,6 +421,7 @@ impl OpList {
 #[derive(Debug, Clone)]
 pub struct OpPresign {
     expire: Duration,
+    headers: http::HeaderMap,
 
     op: PresignOperation,
 }
@@ -244,6 +432,7 @@ impl OpPresign {
         Self {
             op: op.into(),
             expire,
+            headers: http::HeaderMap::new(),
         }
     }
 
@@ -257,6 +446,19 @@ impl OpPresign {
         self.expire
     }
 
+    /// Set the extra headers that should be included in the signed request.
+    ///
+    /// Clients must send the same headers while using the presigned request.
+    pub fn with_headers(mut self, headers: http::HeaderMap) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Get the extra headers that should be included in the signed request.
+    pub fn headers(&self) -> &http::HeaderMap {
+        &self.headers
+    }
+
     /// Consume OpPresign into (Duration, PresignOperation)
     pub fn into_parts(self) -> (Duration, PresignOperation) {
         (self.expire, self.op)
@@ -273,6 +475,14 @@ pub enum PresignOperation {
     Read(OpRead),
     /// Presign a write operation.
     Write(OpWrite),
+    /// Presign an operation to initiate a multipart upload.
+    InitiateMultipart(OpWrite),
+    /// Presign an operation to upload a part of an on-going multipart upload.
+    UploadPart(OpUploadPart),
+    /// Presign an operation to complete an on-going multipart upload.
+    CompleteMultipart(OpCompleteMultipart),
+    /// Presign a POST policy for browser based uploads via HTML forms.
+    Post(OpPresignPost),
 }
 
 impl From<OpStat> for PresignOperation {
@@ -293,6 +503,156 @@ impl From<OpWrite> for PresignOperation {
     }
 }
 
+impl From<OpUploadPart> for PresignOperation {
+    fn from(v: OpUploadPart) -> Self {
+        Self::UploadPart(v)
+    }
+}
+
+impl From<OpCompleteMultipart> for PresignOperation {
+    fn from(v: OpCompleteMultipart) -> Self {
+        Self::CompleteMultipart(v)
+    }
+}
+
+impl From<OpPresignPost> for PresignOperation {
+    fn from(v: OpPresignPost) -> Self {
+        Self::Post(v)
+    }
+}
+
+/// Args for presigning a POST policy.
+///
+/// All the options will be added as conditions of the policy, uploads that
+/// don't match them will be rejected by services.
+#[derive(Debug, Clone, Default)]
+pub struct OpPresignPost {
+    key_prefix: bool,
+    content_type: Option<String>,
+    content_type_prefix: Option<String>,
+    content_length_range: Option<(u64, u64)>,
+}
+
+impl OpPresignPost {
+    /// Create a new `OpPresignPost`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treat the path as a key prefix, so that clients can upload to any key
+    /// starting with it.
+    pub fn with_key_prefix(mut self, key_prefix: bool) -> Self {
+        self.key_prefix = key_prefix;
+        self
+    }
+
+    /// Check if the path should be treated as a key prefix.
+    pub fn key_prefix(&self) -> bool {
+        self.key_prefix
+    }
+
+    /// Set the content type that uploads must match exactly.
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Get the content type that uploads must match exactly.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Set the prefix that the content type of uploads must start with, like `image/`.
+    pub fn with_content_type_prefix(mut self, prefix: &str) -> Self {
+        self.content_type_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Get the prefix that the content type of uploads must start with.
+    pub fn content_type_prefix(&self) -> Option<&str> {
+        self.content_type_prefix.as_deref()
+    }
+
+    /// Set the inclusive range that the content length of uploads must be in.
+    pub fn with_content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.content_length_range = Some((min, max));
+        self
+    }
+
+    /// Get the inclusive range that the content length of uploads must be in.
+    pub fn content_length_range(&self) -> Option<(u64, u64)> {
+        self.content_length_range
+    }
+}
+
+/// Args for presigning an upload part operation.
+///
+/// The upload id is returned by the initiate multipart upload request.
+#[derive(Debug, Clone, Default)]
+pub struct OpUploadPart {
+    upload_id: String,
+    part_number: usize,
+    content_length: Option<u64>,
+}
+
+impl OpUploadPart {
+    /// Create a new `OpUploadPart`.
+    ///
+    /// The part number starts from 1.
+    pub fn new(upload_id: &str, part_number: usize) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            part_number,
+            content_length: None,
+        }
+    }
+
+    /// Get the upload id of this operation.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Get the part number of this operation.
+    pub fn part_number(&self) -> usize {
+        self.part_number
+    }
+
+    /// Set the content length of this part.
+    ///
+    /// The content length will be signed so that clients can only upload a part of exactly this size.
+    pub fn with_content_length(mut self, content_length: u64) -> Self {
+        self.content_length = Some(content_length);
+        self
+    }
+
+    /// Get the content length of this part.
+    pub fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+}
+
+/// Args for presigning a complete multipart upload operation.
+///
+/// The parts uploaded are sent by clients in the body of the presigned request.
+#[derive(Debug, Clone, Default)]
+pub struct OpCompleteMultipart {
+    upload_id: String,
+}
+
+impl OpCompleteMultipart {
+    /// Create a new `OpCompleteMultipart`.
+    pub fn new(upload_id: &str) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+        }
+    }
+
+    /// Get the upload id of this operation.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+}
+
 /// Args for `read` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpRead {
@@ -306,6 +666,7 @@ pub struct OpRead {
     override_content_disposition: Option<String>,
     version: Option<String>,
     executor: Option<Executor>,
+    server_side_encryption_customer_key: Option<String>,
 }
 
 impl OpRead {
@@ -443,6 +804,17 @@ impl OpRead {
     pub fn executor(&self) -> Option<&Executor> {
         self.executor.as_ref()
     }
+
+    /// Set the base64 encoded customer key used to decrypt the content (SSE-C).
+    pub fn with_server_side_encryption_customer_key(mut self, key: &str) -> Self {
+        self.server_side_encryption_customer_key = Some(key.to_string());
+        self
+    }
+
+    /// Get the base64 encoded customer key from option.
+    pub fn server_side_encryption_customer_key(&self) -> Option<&str> {
+        self.server_side_encryption_customer_key.as_deref()
+    }
 }
 
 /// Args for reader operation.
@@ -454,6 +826,8 @@ pub struct OpReader {
     chunk: Option<usize>,
     /// The gap size of each request.
     gap: Option<usize>,
+    /// The max times to resume a read request that failed with temporary error.
+    resume_times: usize,
 }
 
 impl Default for OpReader {
@@ -462,6 +836,7 @@ impl Default for OpReader {
             concurrent: 1,
             chunk: None,
             gap: None,
+            resume_times: 0,
         }
     }
 }
@@ -504,6 +879,17 @@ impl OpReader {
     pub fn gap(&self) -> Option<usize> {
         self.gap
     }
+
+    /// Set the resume times of the option
+    pub fn with_resume_times(mut self, resume_times: usize) -> Self {
+        self.resume_times = resume_times;
+        self
+    }
+
+    /// Get resume times from option
+    pub fn resume_times(&self) -> usize {
+        self.resume_times
+    }
 }
 
 /// Args for `stat` operation.
@@ -517,6 +903,7 @@ pub struct OpStat {
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    server_side_encryption_customer_key: Option<String>,
 }
 
 impl OpStat {
@@ -613,6 +1000,17 @@ impl OpStat {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set the base64 encoded customer key used to access the object (SSE-C).
+    pub fn with_server_side_encryption_customer_key(mut self, key: &str) -> Self {
+        self.server_side_encryption_customer_key = Some(key.to_string());
+        self
+    }
+
+    /// Get the base64 encoded customer key from option.
+    pub fn server_side_encryption_customer_key(&self) -> Option<&str> {
+        self.server_side_encryption_customer_key.as_deref()
+    }
 }
 
 /// Args for `write` operation.
@@ -629,6 +1027,12 @@ pub struct OpWrite {
     if_none_match: Option<String>,
     if_not_exists: bool,
     user_metadata: Option<HashMap<String, String>>,
+    server_side_encryption: Option<String>,
+    server_side_encryption_aws_kms_key_id: Option<String>,
+    server_side_encryption_customer_key: Option<String>,
+    expire_after: Option<Duration>,
+    permissions: Option<u32>,
+    progress: Option<oio::MultipartProgress>,
 }
 
 impl OpWrite {
@@ -757,6 +1161,46 @@ impl OpWrite {
         self.if_not_exists
     }
 
+    /// Set the expire duration of the option.
+    ///
+    /// The written object will be removed by service after this duration.
+    pub fn with_expire_after(mut self, expire: Duration) -> Self {
+        self.expire_after = Some(expire);
+        self
+    }
+
+    /// Get the expire duration from option.
+    pub fn expire_after(&self) -> Option<Duration> {
+        self.expire_after
+    }
+
+    /// Set the permissions of the option.
+    ///
+    /// The value is the POSIX permission bits like `0o644`.
+    pub fn with_permissions(mut self, permissions: u32) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    /// Get the permissions from option.
+    pub fn permissions(&self) -> Option<u32> {
+        self.permissions
+    }
+
+    /// Set the multipart progress of the option.
+    ///
+    /// The progress of multipart upload will be tracked by it, and the upload
+    /// will be resumed if the progress is not empty.
+    pub fn with_progress(mut self, progress: oio::MultipartProgress) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Get the multipart progress from option.
+    pub fn progress(&self) -> Option<&oio::MultipartProgress> {
+        self.progress.as_ref()
+    }
+
     /// Merge given executor into option.
     ///
     /// If executor has already been set, this will do nothing.
@@ -781,6 +1225,39 @@ impl OpWrite {
     pub fn user_metadata(&self) -> Option<&HashMap<String, String>> {
         self.user_metadata.as_ref()
     }
+
+    /// Set the server side encryption method of the op, like `AES256` or `aws:kms`.
+    pub fn with_server_side_encryption(mut self, v: &str) -> Self {
+        self.server_side_encryption = Some(v.to_string());
+        self
+    }
+
+    /// Get the server side encryption method from the op.
+    pub fn server_side_encryption(&self) -> Option<&str> {
+        self.server_side_encryption.as_deref()
+    }
+
+    /// Set the kms key id used by server side encryption of the op.
+    pub fn with_server_side_encryption_aws_kms_key_id(mut self, v: &str) -> Self {
+        self.server_side_encryption_aws_kms_key_id = Some(v.to_string());
+        self
+    }
+
+    /// Get the kms key id used by server side encryption from the op.
+    pub fn server_side_encryption_aws_kms_key_id(&self) -> Option<&str> {
+        self.server_side_encryption_aws_kms_key_id.as_deref()
+    }
+
+    /// Set the base64 encoded customer key used to encrypt the content (SSE-C).
+    pub fn with_server_side_encryption_customer_key(mut self, key: &str) -> Self {
+        self.server_side_encryption_customer_key = Some(key.to_string());
+        self
+    }
+
+    /// Get the base64 encoded customer key from the op.
+    pub fn server_side_encryption_customer_key(&self) -> Option<&str> {
+        self.server_side_encryption_customer_key.as_deref()
+    }
 }
 
 /// Args for `writer` operation.
@@ -817,6 +1294,47 @@ impl OpWriter {
     }
 }
 
+/// Args for streaming data between operator and async io, like `read_to_writer`.
+#[derive(Debug, Clone, Default)]
+pub struct OpTransfer {
+    buffer_size: Option<usize>,
+    verify_checksum: bool,
+}
+
+impl OpTransfer {
+    /// Create a new `OpTransfer`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the buffer size from op.
+    pub fn buffer_size(&self) -> Option<usize> {
+        self.buffer_size
+    }
+
+    /// Set the buffer size of op.
+    ///
+    /// The buffer size decides how many bytes will be held in memory for each step.
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = Some(buffer_size.max(1));
+        self
+    }
+
+    /// Get whether the checksum should be verified.
+    pub fn verify_checksum(&self) -> bool {
+        self.verify_checksum
+    }
+
+    /// Set whether the checksum should be verified.
+    ///
+    /// If enabled, the transferred data will be checked against the content
+    /// length and content md5 returned by service.
+    pub fn with_verify_checksum(mut self, verify: bool) -> Self {
+        self.verify_checksum = verify;
+        self
+    }
+}
+
 /// Args for `copy` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpCopy {}
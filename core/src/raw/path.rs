@@ -221,6 +221,122 @@ pub fn validate_path(path: &str, mode: EntryMode) -> bool {
     }
 }
 
+/// Check if the path matches the glob pattern.
+///
+/// - `?` matches any single character except `/`.
+/// - `*` matches any sequence of characters except `/`.
+/// - `**` as a whole path segment matches any sequence of characters, `**/`
+///   matches zero or more directories. Otherwise it's the same as `*`.
+///
+/// All other characters are matched literally, use [`validate_glob`] to reject
+/// patterns that services may treat differently.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    glob_match_bytes(pattern.as_bytes(), path.as_bytes())
+}
+
+/// Check if `**` at given index of pattern is a whole path segment.
+fn is_globstar(p: &[u8], idx: usize) -> bool {
+    p[idx..].starts_with(b"**")
+        && (idx == 0 || p[idx - 1] == b'/')
+        && matches!(p.get(idx + 2), None | Some(b'/'))
+}
+
+/// Match with star backtracking: only the latest `*` and `**` are recorded
+/// as backtrack points, so matching takes `O(pattern * path)` at most.
+///
+/// Since `*` never crosses `/` and `**` always starts at a directory, the
+/// latest `**` can reach every position that earlier stars could.
+fn glob_match_bytes(p: &[u8], s: &[u8]) -> bool {
+    let (mut pi, mut si) = (0, 0);
+    // The pattern index after `*` and the path index to restart.
+    let mut star: Option<(usize, usize)> = None;
+    // The pattern index after `**/` and the path index to restart.
+    let mut globstar: Option<(usize, usize)> = None;
+
+    while pi < p.len() || si < s.len() {
+        match p.get(pi) {
+            Some(b'*') if is_globstar(p, pi) => {
+                // Trailing `**` matches all the rest.
+                if pi + 2 == p.len() {
+                    return true;
+                }
+                globstar = Some((pi + 3, si));
+                star = None;
+                pi += 3;
+                continue;
+            }
+            Some(b'*') => {
+                star = Some((pi + 1, si));
+                pi += 1;
+                continue;
+            }
+            Some(b'?') if si < s.len() && s[si] != b'/' => {
+                pi += 1;
+                si += 1;
+                continue;
+            }
+            Some(c) if *c != b'?' && s.get(si) == Some(c) => {
+                pi += 1;
+                si += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        // Let the latest `*` consume one more character inside the directory.
+        if let Some((next, restart)) = star {
+            if restart < s.len() && s[restart] != b'/' {
+                star = Some((next, restart + 1));
+                (pi, si) = (next, restart + 1);
+                continue;
+            }
+        }
+
+        // Let the latest `**/` consume one more directory.
+        if let Some((next, restart)) = globstar {
+            if let Some(idx) = s[restart..].iter().position(|c| *c == b'/') {
+                globstar = Some((next, restart + idx + 1));
+                star = None;
+                (pi, si) = (next, restart + idx + 1);
+                continue;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Validate the glob pattern used by list.
+///
+/// Patterns are rejected if services like gcs may treat them differently
+/// from [`glob_match`]:
+///
+/// - `[...]` and `{a,b}` are pattern syntax for services, but matched literally.
+/// - `**` that is not a whole path segment like `a**b`.
+pub fn validate_glob(pattern: &str) -> Result<()> {
+    let p = pattern.as_bytes();
+    let mut invalid_globstar = false;
+    let mut idx = 0;
+    while idx < p.len() {
+        let stars = p[idx..].iter().take_while(|c| **c == b'*').count();
+        if stars > 2 || (stars == 2 && !is_globstar(p, idx)) {
+            invalid_globstar = true;
+        }
+        idx += stars.max(1);
+    }
+
+    if pattern.contains(['[', ']', '{', '}']) || invalid_globstar {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "glob pattern with `[...]`, `{...}` or `**` inside a path segment is not supported",
+        )
+        .with_context("glob", pattern));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,4 +493,46 @@ mod tests {
             assert_eq!(actual, expect, "{name}")
         }
     }
+
+    #[test]
+    fn test_glob_match() {
+        let cases = [
+            ("*.txt", "a.txt", true),
+            ("*.txt", "dir/a.txt", false),
+            ("**/*.txt", "a.txt", true),
+            ("**/*.txt", "dir/sub/a.txt", true),
+            ("dir/**", "dir/sub/a.txt", true),
+            ("dir/*", "dir/sub/a.txt", false),
+            ("dir/?.txt", "dir/a.txt", true),
+            ("dir/?.txt", "dir/ab.txt", false),
+            ("dir/**/a.txt", "dir/a.txt", true),
+            ("dir/**/a.txt", "dir/x/y/a.txt", true),
+            ("dir/**/a.txt", "dir/xa.txt", false),
+            ("**", "dir/sub/a.txt", true),
+            ("a*b*c", "axxbyyc", true),
+            ("a*b*c", "axxbyy/c", false),
+            ("**/a*b", "x/ay/b", false),
+            ("**/a*b", "x/ayb", true),
+        ];
+
+        for (pattern, path, expected) in cases {
+            assert_eq!(glob_match(pattern, path), expected, "{pattern} {path}");
+        }
+    }
+
+    #[test]
+    fn test_glob_match_pathological() {
+        let path = "a".repeat(1000);
+        assert!(!glob_match("*a*a*a*a*a*a*a*a*a*a*b", &path));
+        assert!(!glob_match("**/*a*a*a*a*a*a*a*a*b", &path));
+    }
+
+    #[test]
+    fn test_validate_glob() {
+        assert!(validate_glob("dir/**/*.txt").is_ok());
+        assert!(validate_glob("dir/[ab].txt").is_err());
+        assert!(validate_glob("dir/{a,b}.txt").is_err());
+        assert!(validate_glob("dir/a**b").is_err());
+        assert!(validate_glob("***").is_err());
+    }
 }
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+
 use http::Request;
 
 use crate::raw::*;
@@ -32,6 +34,30 @@ pub struct RpDelete {}
 #[derive(Debug, Clone, Default)]
 pub struct RpList {}
 
+/// Reply for `tag` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpTag {
+    tags: HashMap<String, String>,
+}
+
+impl RpTag {
+    /// Create a new reply for `tag`.
+    pub fn new(tags: HashMap<String, String>) -> Self {
+        RpTag { tags }
+    }
+
+    /// Consume reply to get the tags.
+    ///
+    /// Only [`TagOperation::Get`] will return tags, others return empty map.
+    pub fn into_tags(self) -> HashMap<String, String> {
+        self.tags
+    }
+}
+
+/// Reply for `concat` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpConcat {}
+
 /// Reply for `presign` operation.
 #[derive(Debug, Clone)]
 pub struct RpPresign {
@@ -56,6 +82,7 @@ pub struct PresignedRequest {
     method: http::Method,
     uri: http::Uri,
     headers: http::HeaderMap,
+    form_fields: Vec<(String, String)>,
 }
 
 impl PresignedRequest {
@@ -65,9 +92,24 @@ impl PresignedRequest {
             method,
             uri,
             headers,
+            form_fields: Vec::new(),
         }
     }
 
+    /// Set the form fields of this request.
+    pub fn with_form_fields(mut self, form_fields: Vec<(String, String)>) -> Self {
+        self.form_fields = form_fields;
+        self
+    }
+
+    /// Return request's form fields.
+    ///
+    /// Only presigned POST policy has form fields, they must be sent in a `multipart/form-data`
+    /// body before the `file` field.
+    pub fn form_fields(&self) -> &[(String, String)] {
+        &self.form_fields
+    }
+
     /// Return request's method.
     pub fn method(&self) -> &http::Method {
         &self.method
@@ -116,6 +158,11 @@ pub struct RpRead {
     /// It's ok to leave range as empty, but it's recommended to set range if possible. We will use
     /// this range as hint to do some optimization like avoid an extra stat or read.
     range: Option<BytesContentRange>,
+    /// Etag is the etag of the file returned by this read operation.
+    ///
+    /// It's ok to leave etag as empty, but it's recommended to set etag if possible. We will use
+    /// this etag to make sure resumed reads are served from the same version of the file.
+    etag: Option<String>,
 }
 
 impl RpRead {
@@ -151,6 +198,17 @@ impl RpRead {
         self.range = range;
         self
     }
+
+    /// Got the etag of the file returned by this read operation.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// Set the etag of the file returned by this read operation.
+    pub fn with_etag(mut self, etag: Option<String>) -> Self {
+        self.etag = etag;
+        self
+    }
 }
 
 /// Reply for `stat` operation.
@@ -233,6 +291,7 @@ mod tests {
 
                 headers
             },
+            form_fields: Vec::new(),
         };
 
         let req: Request<Buffer> = pr.into();
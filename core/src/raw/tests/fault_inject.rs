@@ -0,0 +1,371 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use crate::raw::*;
+use crate::*;
+
+/// FaultInjectLayer injects faults into the operator to test the resilience
+/// of upper layers like `RetryLayer`.
+///
+/// Unlike `ChaosLayer`, faults are generated by a seeded random generator so
+/// that the failures can be reproduced by the same seed.
+///
+/// Faults will be injected before calling the inner operations, so retrying
+/// the failed operation is always safe:
+///
+/// - Errors are temporary, with the configured kind.
+/// - Latencies are added before the operation.
+/// - Short reads return only part of the data read from inner reader, the
+///   remaining data will be returned by the following reads.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::raw::tests::FaultInjectLayer;
+/// # use opendal::layers::RetryLayer;
+/// # use opendal::services;
+/// # use opendal::ErrorKind;
+/// # use opendal::Operator;
+/// # use opendal::Result;
+///
+/// # fn main() -> Result<()> {
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(
+///         FaultInjectLayer::new(42)
+///             .with_error(ErrorKind::Unexpected, 0.3)
+///             .with_short_read(0.5),
+///     )
+///     .layer(RetryLayer::new())
+///     .finish();
+/// Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FaultInjectLayer {
+    seed: u64,
+    error: Option<(ErrorKind, f64)>,
+    latency: Option<(Duration, f64)>,
+    short_read: f64,
+}
+
+impl FaultInjectLayer {
+    /// Create a new layer that generates faults with given seed.
+    ///
+    /// No fault will be injected until configured.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            error: None,
+            latency: None,
+            short_read: 0.0,
+        }
+    }
+
+    /// Inject temporary errors of given kind with the ratio.
+    ///
+    /// # Panics
+    ///
+    /// Input ratio must in [0.0..=1.0]
+    pub fn with_error(mut self, kind: ErrorKind, ratio: f64) -> Self {
+        check_ratio(ratio);
+        self.error = Some((kind, ratio));
+        self
+    }
+
+    /// Inject latency with the ratio.
+    ///
+    /// # Panics
+    ///
+    /// Input ratio must in [0.0..=1.0]
+    pub fn with_latency(mut self, latency: Duration, ratio: f64) -> Self {
+        check_ratio(ratio);
+        self.latency = Some((latency, ratio));
+        self
+    }
+
+    /// Inject short reads with the ratio.
+    ///
+    /// # Panics
+    ///
+    /// Input ratio must in [0.0..=1.0]
+    pub fn with_short_read(mut self, ratio: f64) -> Self {
+        check_ratio(ratio);
+        self.short_read = ratio;
+        self
+    }
+}
+
+fn check_ratio(ratio: f64) {
+    assert!(
+        (0.0..=1.0).contains(&ratio),
+        "ratio must between 0.0 and 1.0"
+    );
+}
+
+impl<A: Access> Layer<A> for FaultInjectLayer {
+    type LayeredAccess = FaultInjectAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        FaultInjectAccessor {
+            inner,
+            injector: Injector {
+                rng: Arc::new(Mutex::new(StdRng::seed_from_u64(self.seed))),
+                error: self.error,
+                latency: self.latency,
+                short_read: self.short_read,
+            },
+        }
+    }
+}
+
+/// Injector decides which fault to inject, shared by accessor and readers.
+#[derive(Debug, Clone)]
+struct Injector {
+    rng: Arc<Mutex<StdRng>>,
+    error: Option<(ErrorKind, f64)>,
+    latency: Option<(Duration, f64)>,
+    short_read: f64,
+}
+
+impl Injector {
+    fn hit(&self, ratio: f64) -> bool {
+        ratio > 0.0 && self.rng.lock().unwrap().gen_bool(ratio)
+    }
+
+    fn latency(&self) -> Option<Duration> {
+        let (latency, ratio) = self.latency?;
+        self.hit(ratio).then_some(latency)
+    }
+
+    fn error(&self, op: impl Into<&'static str>) -> Result<()> {
+        let Some((kind, ratio)) = self.error else {
+            return Ok(());
+        };
+        if !self.hit(ratio) {
+            return Ok(());
+        }
+
+        Err(Error::new(kind, "fault injected")
+            .with_operation(op)
+            .set_temporary())
+    }
+
+    async fn inject(&self, op: impl Into<&'static str>) -> Result<()> {
+        if let Some(latency) = self.latency() {
+            sleep(latency).await;
+        }
+        self.error(op)
+    }
+
+    fn blocking_inject(&self, op: impl Into<&'static str>) -> Result<()> {
+        if let Some(latency) = self.latency() {
+            std::thread::sleep(latency);
+        }
+        self.error(op)
+    }
+
+    /// Split the buffer at a random position if short read is hit.
+    ///
+    /// Returns the part to return and the remaining part.
+    fn short_read(&self, buf: Buffer) -> (Buffer, Buffer) {
+        if buf.len() <= 1 || !self.hit(self.short_read) {
+            return (buf, Buffer::new());
+        }
+
+        let at = self.rng.lock().unwrap().gen_range(1..buf.len());
+        (buf.slice(..at), buf.slice(at..))
+    }
+}
+
+#[derive(Debug)]
+pub struct FaultInjectAccessor<A: Access> {
+    inner: A,
+    injector: Injector,
+}
+
+impl<A: Access> LayeredAccess for FaultInjectAccessor<A> {
+    type Inner = A;
+    type Reader = FaultInjectWrapper<A::Reader>;
+    type BlockingReader = FaultInjectWrapper<A::BlockingReader>;
+    type Writer = FaultInjectWrapper<A::Writer>;
+    type BlockingWriter = FaultInjectWrapper<A::BlockingWriter>;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+    type Deleter = A::Deleter;
+    type BlockingDeleter = A::BlockingDeleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.injector.inject(Operation::CreateDir).await?;
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.injector.inject(Operation::Read).await?;
+        self.inner
+            .read(path, args)
+            .await
+            .map(|(rp, r)| (rp, FaultInjectWrapper::new(r, self.injector.clone())))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.injector.inject(Operation::Write).await?;
+        self.inner
+            .write(path, args)
+            .await
+            .map(|(rp, w)| (rp, FaultInjectWrapper::new(w, self.injector.clone())))
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.injector.inject(Operation::Stat).await?;
+        self.inner.stat(path, args).await
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.injector.inject(Operation::Delete).await?;
+        self.inner.delete().await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.injector.inject(Operation::List).await?;
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.injector
+            .blocking_inject(Operation::BlockingCreateDir)?;
+        self.inner.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.injector.blocking_inject(Operation::BlockingRead)?;
+        self.inner
+            .blocking_read(path, args)
+            .map(|(rp, r)| (rp, FaultInjectWrapper::new(r, self.injector.clone())))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.injector.blocking_inject(Operation::BlockingWrite)?;
+        self.inner
+            .blocking_write(path, args)
+            .map(|(rp, w)| (rp, FaultInjectWrapper::new(w, self.injector.clone())))
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.injector.blocking_inject(Operation::BlockingStat)?;
+        self.inner.blocking_stat(path, args)
+    }
+
+    fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
+        self.injector.blocking_inject(Operation::BlockingDelete)?;
+        self.inner.blocking_delete()
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.injector.blocking_inject(Operation::BlockingList)?;
+        self.inner.blocking_list(path, args)
+    }
+}
+
+pub struct FaultInjectWrapper<R> {
+    inner: R,
+    injector: Injector,
+    /// Data that has been read from inner reader but not returned yet.
+    remaining: Buffer,
+}
+
+impl<R> FaultInjectWrapper<R> {
+    fn new(inner: R, injector: Injector) -> Self {
+        Self {
+            inner,
+            injector,
+            remaining: Buffer::new(),
+        }
+    }
+}
+
+impl<R: oio::Read> oio::Read for FaultInjectWrapper<R> {
+    async fn read(&mut self) -> Result<Buffer> {
+        self.injector.inject(Operation::ReaderRead).await?;
+
+        let buf = if self.remaining.is_empty() {
+            self.inner.read().await?
+        } else {
+            std::mem::take(&mut self.remaining)
+        };
+        let (buf, remaining) = self.injector.short_read(buf);
+        self.remaining = remaining;
+        Ok(buf)
+    }
+}
+
+impl<R: oio::BlockingRead> oio::BlockingRead for FaultInjectWrapper<R> {
+    fn read(&mut self) -> Result<Buffer> {
+        self.injector
+            .blocking_inject(Operation::BlockingReaderRead)?;
+
+        let buf = if self.remaining.is_empty() {
+            self.inner.read()?
+        } else {
+            std::mem::take(&mut self.remaining)
+        };
+        let (buf, remaining) = self.injector.short_read(buf);
+        self.remaining = remaining;
+        Ok(buf)
+    }
+}
+
+impl<R: oio::Write> oio::Write for FaultInjectWrapper<R> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.injector.inject(Operation::WriterWrite).await?;
+        self.inner.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.injector.inject(Operation::WriterClose).await?;
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+impl<R: oio::BlockingWrite> oio::BlockingWrite for FaultInjectWrapper<R> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.injector
+            .blocking_inject(Operation::BlockingWriterWrite)?;
+        self.inner.write(bs)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.injector
+            .blocking_inject(Operation::BlockingWriterClose)?;
+        self.inner.close()
+    }
+}
@@ -0,0 +1,310 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use futures::stream;
+use http::HeaderName;
+use http::HeaderValue;
+use http::Request;
+use http::Response;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::raw::oio::Read;
+use crate::raw::*;
+use crate::*;
+
+/// HttpFixtureMode controls how http fixtures are used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpFixtureMode {
+    /// Send requests to the real service and record the interactions into
+    /// fixture files.
+    Record,
+    /// Replay the interactions from fixture files without sending any
+    /// request.
+    Replay,
+}
+
+impl FromStr for HttpFixtureMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "record" => Ok(Self::Record),
+            "replay" => Ok(Self::Replay),
+            _ => Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "http fixture mode must be record or replay",
+            )
+            .with_context("mode", s)),
+        }
+    }
+}
+
+/// Check if http fixture is enabled by `OPENDAL_TEST_HTTP_FIXTURE`.
+pub fn is_http_fixture_enabled() -> bool {
+    env::var_os("OPENDAL_TEST_HTTP_FIXTURE").is_some()
+}
+
+struct HttpFixture {
+    mode: HttpFixtureMode,
+    dir: PathBuf,
+    state: Mutex<HttpFixtureState>,
+}
+
+/// HttpFixtureState tracks the running test case and its requests.
+#[derive(Default)]
+struct HttpFixtureState {
+    test: Option<String>,
+    counters: HashMap<String, usize>,
+}
+
+impl HttpFixture {
+    /// Get the path of fixture file for the next request with given identity.
+    fn next_path(&self, identity: &str) -> PathBuf {
+        let mut state = self.state.lock().unwrap();
+        let n = state.counters.entry(identity.to_string()).or_default();
+        *n += 1;
+        let n = *n;
+
+        let hash = Sha256::digest(identity.as_bytes());
+        let dir = match &state.test {
+            Some(test) => self.dir.join(test),
+            None => self.dir.clone(),
+        };
+        dir.join(format!("{hash:x}-{n}.json"))
+    }
+}
+
+/// HttpInteraction is the content of a fixture file.
+#[derive(Serialize, Deserialize)]
+struct HttpInteraction {
+    request: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    /// The base64 encoded response body.
+    body: String,
+}
+
+/// HttpFixtureFetcher records or replays http interactions via fixtures.
+///
+/// Use it via [`HttpClient::with`] to build the http client of services.
+///
+/// Every request is identified by its method, path and query without
+/// signatures. The `n`th request of the same identity sent by test case
+/// `test` will be stored in `{dir}/{test}/{sha256(identity)}-{n}.json`, so
+/// every test case can be replayed on its own as long as it sends the same
+/// requests in the same order. Test cases must run one by one since requests
+/// are assigned to the test case set by [`HttpFixtureFetcher::start_test`].
+///
+/// Credentials loaded by services themselves (like the token of gcs) are not
+/// covered by fixtures, please use anonymous access or static credentials
+/// while replaying.
+#[derive(Clone)]
+pub struct HttpFixtureFetcher {
+    fixture: Arc<HttpFixture>,
+    inner: reqwest::Client,
+}
+
+impl HttpFixtureFetcher {
+    /// Create a new fetcher that stores fixtures in given dir.
+    pub fn new(mode: HttpFixtureMode, dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        if mode == HttpFixtureMode::Record {
+            fs::create_dir_all(&dir)
+                .map_err(|err| new_std_io_error(err).with_context("dir", dir.to_string_lossy()))?;
+        }
+
+        Ok(Self {
+            fixture: Arc::new(HttpFixture {
+                mode,
+                dir,
+                state: Mutex::default(),
+            }),
+            inner: reqwest::Client::new(),
+        })
+    }
+
+    /// Assign all following requests to given test case.
+    pub fn start_test(&self, test: &str) {
+        let mut state = self.fixture.state.lock().unwrap();
+        state.test = Some(test.to_string());
+        state.counters.clear();
+    }
+
+    /// Assign all following requests to no test case, like the ones sent
+    /// while setting up or cleaning up.
+    pub fn finish_test(&self) {
+        let mut state = self.fixture.state.lock().unwrap();
+        state.test = None;
+        state.counters.clear();
+    }
+}
+
+impl HttpFetch for HttpFixtureFetcher {
+    async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+        let identity = request_identity(&req);
+        let path = self.fixture.next_path(&identity);
+
+        match self.fixture.mode {
+            HttpFixtureMode::Record => {
+                let (parts, mut body) = self.inner.fetch(req).await?.into_parts();
+                let buf = body.read_all().await?;
+
+                let interaction = HttpInteraction {
+                    request: identity,
+                    status: parts.status.as_u16(),
+                    headers: parts
+                        .headers
+                        .iter()
+                        .filter_map(|(k, v)| Some((k.to_string(), v.to_str().ok()?.to_string())))
+                        .collect(),
+                    body: general_purpose::STANDARD.encode(buf.to_bytes()),
+                };
+                let content =
+                    serde_json::to_vec_pretty(&interaction).map_err(new_json_serialize_error)?;
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir).map_err(|err| {
+                        new_std_io_error(err).with_context("dir", dir.to_string_lossy())
+                    })?;
+                }
+                fs::write(&path, content).map_err(|err| {
+                    new_std_io_error(err).with_context("path", path.to_string_lossy())
+                })?;
+
+                Ok(Response::from_parts(parts, new_http_body(buf)))
+            }
+            HttpFixtureMode::Replay => {
+                let content = fs::read(&path).map_err(|err| {
+                    Error::new(ErrorKind::Unexpected, "http fixture is not found")
+                        .with_context("request", &identity)
+                        .with_context("path", path.to_string_lossy())
+                        .set_source(err)
+                })?;
+                let interaction: HttpInteraction =
+                    serde_json::from_slice(&content).map_err(new_json_deserialize_error)?;
+                let body = general_purpose::STANDARD
+                    .decode(&interaction.body)
+                    .map_err(|err| {
+                        Error::new(ErrorKind::Unexpected, "http fixture body is invalid")
+                            .with_context("path", path.to_string_lossy())
+                            .set_source(err)
+                    })?;
+
+                let mut resp = Response::builder()
+                    .status(interaction.status)
+                    // Keep the same with responses built by http client.
+                    .extension(req.uri().clone());
+                for (k, v) in &interaction.headers {
+                    let (Ok(k), Ok(v)) = (HeaderName::from_str(k), HeaderValue::from_str(v)) else {
+                        continue;
+                    };
+                    resp = resp.header(k, v);
+                }
+                resp.body(new_http_body(Buffer::from(body)))
+                    .map_err(new_request_build_error)
+            }
+        }
+    }
+}
+
+/// Build the identity of request by its method, path and sorted query.
+///
+/// Query pairs that carry signatures or change over time are ignored.
+fn request_identity(req: &Request<Buffer>) -> String {
+    let mut query: Vec<&str> = req
+        .uri()
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|v| !v.is_empty() && !is_volatile_query(v))
+        .collect();
+    query.sort_unstable();
+
+    format!("{} {}?{}", req.method(), req.uri().path(), query.join("&"))
+}
+
+fn is_volatile_query(pair: &str) -> bool {
+    let name = pair.split('=').next().unwrap_or_default().to_lowercase();
+    name.starts_with("x-amz-")
+        || name.starts_with("x-goog-")
+        || matches!(
+            name.as_str(),
+            "signature" | "expires" | "awsaccesskeyid" | "ossaccesskeyid" | "sig" | "se" | "st"
+        )
+}
+
+fn new_http_body(buf: Buffer) -> HttpBody {
+    let size = buf.len() as u64;
+    let chunk = (!buf.is_empty()).then_some(Ok(buf));
+    HttpBody::new(stream::iter(chunk), Some(size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_identity() {
+        let req = Request::get(
+            "https://example.com/bucket/key?X-Amz-Signature=abc&versionId=1&acl&X-Amz-Date=now",
+        )
+        .body(Buffer::new())
+        .unwrap();
+
+        assert_eq!(request_identity(&req), "GET /bucket/key?acl&versionId=1");
+    }
+
+    #[test]
+    fn test_next_path_per_test() {
+        let fetcher = HttpFixtureFetcher::new(HttpFixtureMode::Replay, "/fixtures").unwrap();
+        let hash = format!("{:x}", Sha256::digest(b"GET /key?"));
+
+        fetcher.start_test("test_a");
+        let first = fetcher.fixture.next_path("GET /key?");
+        let second = fetcher.fixture.next_path("GET /key?");
+        fetcher.start_test("test_b");
+        let other = fetcher.fixture.next_path("GET /key?");
+        fetcher.finish_test();
+        let cleanup = fetcher.fixture.next_path("GET /key?");
+
+        assert_eq!(
+            first,
+            PathBuf::from(format!("/fixtures/test_a/{hash}-1.json"))
+        );
+        assert_eq!(
+            second,
+            PathBuf::from(format!("/fixtures/test_a/{hash}-2.json"))
+        );
+        assert_eq!(
+            other,
+            PathBuf::from(format!("/fixtures/test_b/{hash}-1.json"))
+        );
+        assert_eq!(cleanup, PathBuf::from(format!("/fixtures/{hash}-1.json")));
+    }
+}
@@ -25,6 +25,17 @@ mod write;
 pub use write::WriteAction;
 pub use write::WriteChecker;
 
+mod fault_inject;
+pub use fault_inject::FaultInjectLayer;
+
+mod http_fixture;
+pub use http_fixture::is_http_fixture_enabled;
+pub use http_fixture::HttpFixtureFetcher;
+pub use http_fixture::HttpFixtureMode;
+
 mod utils;
 pub use utils::init_test_service;
+pub use utils::init_test_service_with_http_client;
+pub use utils::seed_test_rng;
+pub use utils::test_rng;
 pub use utils::TEST_RUNTIME;
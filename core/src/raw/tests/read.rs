@@ -16,11 +16,11 @@
 // under the License.
 
 use bytes::Bytes;
-use rand::thread_rng;
 use rand::RngCore;
 use sha2::Digest;
 use sha2::Sha256;
 
+use super::test_rng;
 use crate::*;
 
 /// ReadAction represents a read action.
@@ -46,7 +46,7 @@ impl ReadChecker {
     /// It's by design that we use a random generator to generate the raw data. The content of data
     /// is not important, we only care about the correctness of the read process.
     pub fn new(size: usize) -> Self {
-        let mut rng = thread_rng();
+        let mut rng = test_rng();
         let mut data = vec![0; size];
         rng.fill_bytes(&mut data);
 
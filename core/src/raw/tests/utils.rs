@@ -15,12 +15,19 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::env;
 use std::str::FromStr;
 
 use once_cell::sync::Lazy;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use sha2::Digest;
+use sha2::Sha256;
 
+use super::is_http_fixture_enabled;
+use crate::raw::HttpClient;
 use crate::*;
 
 /// TEST_RUNTIME is the runtime used for running tests.
@@ -31,12 +38,46 @@ pub static TEST_RUNTIME: Lazy<tokio::runtime::Runtime> = Lazy::new(|| {
         .unwrap()
 });
 
+thread_local! {
+    static TEST_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/// Seed the random generator returned by [`test_rng`] in current thread.
+///
+/// The seed is derived from given name, like the name of test case, so that
+/// the generated paths and contents are the same across runs. It only takes
+/// effect while http fixture is enabled, otherwise the generator is reset to
+/// be seeded by entropy.
+pub fn seed_test_rng(name: &str) {
+    let rng = is_http_fixture_enabled()
+        .then(|| StdRng::from_seed(Sha256::digest(name.as_bytes()).into()));
+    TEST_RNG.with(|v| *v.borrow_mut() = rng);
+}
+
+/// Create a new random generator for testing.
+///
+/// Please refer to [`seed_test_rng`] for how to make it deterministic.
+pub fn test_rng() -> StdRng {
+    TEST_RNG.with(|v| match v.borrow_mut().as_mut() {
+        Some(rng) => StdRng::from_rng(rng).expect("seeded rng must be valid"),
+        None => StdRng::from_entropy(),
+    })
+}
+
 /// Init a service with given scheme.
 ///
 /// - Load scheme from `OPENDAL_TEST`
 /// - Construct a new Operator with given root.
 /// - Else, returns a `None` to represent no valid config for operator.
 pub fn init_test_service() -> Result<Option<Operator>> {
+    init_test_service_with_http_client(None)
+}
+
+/// Init a service with given scheme like [`init_test_service`], services
+/// that talk over http will use the given http client if set.
+pub fn init_test_service_with_http_client(
+    http_client: Option<HttpClient>,
+) -> Result<Option<Operator>> {
     let _ = dotenvy::dotenv();
 
     let scheme = if let Ok(v) = env::var("OPENDAL_TEST") {
@@ -57,7 +98,11 @@ pub fn init_test_service() -> Result<Option<Operator>> {
         .collect::<HashMap<String, String>>();
 
     // Use random root unless OPENDAL_DISABLE_RANDOM_ROOT is set to true.
-    let disable_random_root = env::var("OPENDAL_DISABLE_RANDOM_ROOT").unwrap_or_default() == "true";
+    //
+    // Random root is always disabled while using http fixture, otherwise the
+    // requests can't be replayed.
+    let disable_random_root = env::var("OPENDAL_DISABLE_RANDOM_ROOT").unwrap_or_default() == "true"
+        || is_http_fixture_enabled();
     if !disable_random_root {
         let root = format!(
             "{}{}/",
@@ -67,7 +112,11 @@ pub fn init_test_service() -> Result<Option<Operator>> {
         cfg.insert("root".to_string(), root);
     }
 
-    let op = Operator::via_iter(scheme, cfg).expect("must succeed");
+    let op = match http_client {
+        Some(client) => Operator::via_iter_with_http_client(scheme, cfg, client),
+        None => Operator::via_iter(scheme, cfg),
+    }
+    .expect("must succeed");
 
     #[cfg(feature = "layers-chaos")]
     let op = { op.layer(layers::ChaosLayer::new(0.1)) };
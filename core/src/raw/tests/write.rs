@@ -17,11 +17,12 @@
 
 use bytes::Bytes;
 use bytes::BytesMut;
-use rand::thread_rng;
 use rand::RngCore;
 use sha2::Digest;
 use sha2::Sha256;
 
+use super::test_rng;
+
 /// WriteAction represents a read action.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum WriteAction {
@@ -42,7 +43,7 @@ pub struct WriteChecker {
 impl WriteChecker {
     /// Create a new WriteChecker with given size.
     pub fn new(size: Vec<usize>) -> Self {
-        let mut rng = thread_rng();
+        let mut rng = test_rng();
 
         let mut chunks = Vec::with_capacity(size.len());
 
@@ -130,6 +130,10 @@ impl Builder for AliyunDriveBuilder {
     const SCHEME: Scheme = Scheme::AliyunDrive;
     type Config = AliyunDriveConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
@@ -180,7 +184,7 @@ impl Builder for AliyunDriveBuilder {
         debug!("backend use drive_type {:?}", drive_type);
 
         Ok(AliyunDriveBackend {
-            core: Arc::new(AliyunDriveCore {
+            core: Arc::new_cyclic(|core| AliyunDriveCore {
                 endpoint: "https://openapi.alipan.com".to_string(),
                 root,
                 drive_type,
@@ -189,7 +193,7 @@ impl Builder for AliyunDriveBuilder {
                     sign,
                 })),
                 client,
-                dir_lock: Arc::new(Mutex::new(())),
+                path_cache: PathCacher::new(AliyunDrivePathQuery::new(core.clone())).with_lock(),
             }),
         })
     }
@@ -270,6 +274,8 @@ impl Access for AliyunDriveBackend {
 
         let parent_file_id = self.core.ensure_dir_exists(get_parent(to)).await?;
         self.core.move_path(&file.file_id, &parent_file_id).await?;
+        self.core.invalidate_path(from).await;
+        self.core.invalidate_path(to).await;
 
         let from_name = get_basename(from);
         let to_name = get_basename(to);
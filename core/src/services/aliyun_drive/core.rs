@@ -17,6 +17,7 @@
 
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::sync::Weak;
 
 use bytes::Buf;
 use chrono::Utc;
@@ -66,7 +67,7 @@ pub struct AliyunDriveCore {
 
     pub signer: Arc<Mutex<AliyunDriveSigner>>,
     pub client: HttpClient,
-    pub dir_lock: Arc<Mutex<()>>,
+    pub path_cache: PathCacher<AliyunDrivePathQuery>,
 }
 
 impl Debug for AliyunDriveCore {
@@ -203,28 +204,42 @@ impl AliyunDriveCore {
     }
 
     pub async fn ensure_dir_exists(&self, path: &str) -> Result<String> {
-        let file_path = self.build_path(path, false);
-        if file_path == "/" {
-            return Ok("root".to_string());
-        }
-        let file_path = file_path.strip_suffix('/').unwrap_or(&file_path);
-        let paths = file_path.split('/').collect::<Vec<&str>>();
-        let mut parent: Option<String> = None;
-        for path in paths {
-            let _guard = self.dir_lock.lock().await;
-            let res = self
-                .create(
-                    parent.as_deref(),
-                    path,
-                    CreateType::Folder,
-                    CheckNameMode::Refuse,
-                )
-                .await?;
-            let output: CreateResponse =
-                serde_json::from_reader(res.reader()).map_err(new_json_deserialize_error)?;
-            parent = Some(output.file_id);
+        let path = build_abs_path(&self.root, path);
+        self.path_cache.ensure_dir(&path).await
+    }
+
+    /// Remove the cached file id of given path, must be called after the path
+    /// has been deleted or moved.
+    pub async fn invalidate_path(&self, path: &str) {
+        let path = build_abs_path(&self.root, path);
+        self.path_cache.remove(&path).await;
+    }
+
+    pub async fn search_by_name(
+        &self,
+        parent_file_id: &str,
+        name: &str,
+        is_dir: bool,
+    ) -> Result<Buffer> {
+        let (token, drive_id) = self.get_token_and_drive().await?;
+        let mut query = format!(
+            "parent_file_id = '{}' and name = '{}'",
+            parent_file_id,
+            name.replace('\'', "\\'")
+        );
+        if is_dir {
+            query.push_str(" and type = 'folder'");
         }
-        Ok(parent.expect("ensure_dir_exists must succeed"))
+        let body = serde_json::to_vec(&SearchRequest {
+            drive_id: &drive_id,
+            query: &query,
+            limit: 1,
+        })
+        .map_err(new_json_serialize_error)?;
+        let req = Request::post(format!("{}/adrive/v1.0/openFile/search", self.endpoint))
+            .body(Buffer::from(body))
+            .map_err(new_request_build_error)?;
+        self.send(req, token.as_deref()).await
     }
 
     pub async fn create_with_rapid_upload(
@@ -432,6 +447,64 @@ impl AliyunDriveCore {
     }
 }
 
+/// AliyunDrivePathQuery resolves file ids for [`PathCacher`].
+///
+/// It holds a weak reference to the core which owns the cache, the core
+/// must be alive while the cache is in use.
+pub struct AliyunDrivePathQuery {
+    core: Weak<AliyunDriveCore>,
+}
+
+impl AliyunDrivePathQuery {
+    pub fn new(core: Weak<AliyunDriveCore>) -> Self {
+        AliyunDrivePathQuery { core }
+    }
+
+    fn core(&self) -> Result<Arc<AliyunDriveCore>> {
+        self.core.upgrade().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "aliyun drive core has been dropped while querying path",
+            )
+        })
+    }
+}
+
+impl PathQuery for AliyunDrivePathQuery {
+    async fn root(&self) -> Result<String> {
+        Ok("root".to_string())
+    }
+
+    async fn query(&self, parent_id: &str, name: &str) -> Result<Option<String>> {
+        let core = self.core()?;
+        let is_dir = name.ends_with('/');
+        let res = core
+            .search_by_name(parent_id, name.trim_end_matches('/'), is_dir)
+            .await?;
+        let output: AliyunDriveFileList =
+            serde_json::from_reader(res.reader()).map_err(new_json_deserialize_error)?;
+
+        Ok(output.items.into_iter().next().map(|file| file.file_id))
+    }
+
+    async fn create_dir(&self, parent_id: &str, name: &str) -> Result<String> {
+        let core = self.core()?;
+        // `Refuse` returns the existing folder instead of creating a new one.
+        let res = core
+            .create(
+                Some(parent_id),
+                name.trim_end_matches('/'),
+                CreateType::Folder,
+                CheckNameMode::Refuse,
+            )
+            .await?;
+        let output: CreateResponse =
+            serde_json::from_reader(res.reader()).map_err(new_json_deserialize_error)?;
+
+        Ok(output.file_id)
+    }
+}
+
 pub struct RapidUpload {
     pub pre_hash: Option<String>,
     pub content_hash: Option<String>,
@@ -583,6 +656,13 @@ pub struct ListRequest<'a> {
     marker: Option<&'a str>,
 }
 
+#[derive(Serialize)]
+pub struct SearchRequest<'a> {
+    drive_id: &'a str,
+    query: &'a str,
+    limit: usize,
+}
+
 #[derive(Serialize)]
 pub struct CompleteRequest<'a> {
     drive_id: &'a str,
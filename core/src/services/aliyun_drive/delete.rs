@@ -42,6 +42,7 @@ impl oio::OneShotDelete for AliyunDriveDeleter {
             let file: AliyunDriveFile =
                 serde_json::from_reader(res.reader()).map_err(new_json_serialize_error)?;
             self.core.delete_path(&file.file_id).await?;
+            self.core.invalidate_path(&path).await;
         }
         Ok(())
     }
@@ -19,6 +19,7 @@ use bytes::Buf;
 use http::Response;
 use serde::Deserialize;
 
+use crate::raw::*;
 use crate::*;
 
 #[derive(Default, Debug, Deserialize)]
@@ -44,11 +45,12 @@ pub(super) fn parse_error(res: Response<Buffer>) -> Error {
         409 => (ErrorKind::AlreadyExists, false),
         429 => match code {
             Some(code) if code == "TooManyRequests" => (ErrorKind::RateLimited, true),
-            _ => (ErrorKind::Unexpected, false),
+            _ => parse_http_status(parts.status),
         },
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
     let mut err = Error::new(kind, message);
+    err = with_error_response_context(err, parts);
     if retryable {
         err = err.set_temporary();
     }
@@ -102,6 +102,10 @@ impl Builder for AlluxioBuilder {
     const SCHEME: Scheme = Scheme::Alluxio;
     type Config = AlluxioConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Builds the backend and returns the result of AlluxioBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
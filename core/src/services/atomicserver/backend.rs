@@ -36,7 +36,10 @@ use crate::*;
 impl Configurator for AtomicserverConfig {
     type Builder = AtomicserverBuilder;
     fn into_builder(self) -> Self::Builder {
-        AtomicserverBuilder { config: self }
+        AtomicserverBuilder {
+            config: self,
+            http_client: None,
+        }
     }
 }
 
@@ -44,6 +47,7 @@ impl Configurator for AtomicserverConfig {
 #[derive(Default)]
 pub struct AtomicserverBuilder {
     config: AtomicserverConfig,
+    http_client: Option<HttpClient>,
 }
 
 impl Debug for AtomicserverBuilder {
@@ -87,12 +91,27 @@ impl AtomicserverBuilder {
         self.config.parent_resource_id = Some(parent_resource_id.into());
         self
     }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
 }
 
 impl Builder for AtomicserverBuilder {
     const SCHEME: Scheme = Scheme::Atomicserver;
     type Config = AtomicserverConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         let root = normalize_root(
             self.config
@@ -117,14 +136,20 @@ impl Builder for AtomicserverBuilder {
             name: Some("agent".to_string()),
         };
 
+        let client = if let Some(client) = self.http_client {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Atomicserver)
+            })?
+        };
+
         Ok(AtomicserverBackend::new(Adapter {
             parent_resource_id,
             endpoint,
             agent,
-            client: HttpClient::new().map_err(|err| {
-                err.with_operation("Builder::build")
-                    .with_context("service", Scheme::Atomicserver)
-            })?,
+            client,
         })
         .with_normalized_root(root))
     }
@@ -22,6 +22,7 @@ use std::sync::Arc;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use bytes::Buf;
 use http::Response;
 use http::StatusCode;
 use log::debug;
@@ -33,6 +34,10 @@ use sha2::Sha256;
 
 use super::core::constants::X_MS_META_PREFIX;
 use super::core::AzblobCore;
+use super::core::BlobTags;
+use super::credential::ClientSecret;
+use super::credential::ServicePrincipalLoader;
+use super::credential::DEFAULT_AUTHORITY_HOST;
 use super::delete::AzblobDeleter;
 use super::error::parse_error;
 use super::lister::AzblobLister;
@@ -238,6 +243,58 @@ impl AzblobBuilder {
         self
     }
 
+    /// Set tenant_id of the Azure AD service principal.
+    ///
+    /// Service principal will be used to authenticate if `tenant_id`, `client_id` and
+    /// one of `client_secret` or `client_certificate_path` are set.
+    pub fn tenant_id(mut self, tenant_id: &str) -> Self {
+        if !tenant_id.is_empty() {
+            self.config.tenant_id = Some(tenant_id.to_string());
+        }
+
+        self
+    }
+
+    /// Set client_id of the Azure AD service principal.
+    pub fn client_id(mut self, client_id: &str) -> Self {
+        if !client_id.is_empty() {
+            self.config.client_id = Some(client_id.to_string());
+        }
+
+        self
+    }
+
+    /// Set client_secret of the Azure AD service principal.
+    pub fn client_secret(mut self, client_secret: &str) -> Self {
+        if !client_secret.is_empty() {
+            self.config.client_secret = Some(client_secret.to_string());
+        }
+
+        self
+    }
+
+    /// Set the path to the PEM encoded certificate of the Azure AD service principal.
+    ///
+    /// The file must contain both the certificate and its PKCS#8 private key.
+    pub fn client_certificate_path(mut self, path: &str) -> Self {
+        if !path.is_empty() {
+            self.config.client_certificate_path = Some(path.to_string());
+        }
+
+        self
+    }
+
+    /// Set the authority host of Azure AD.
+    ///
+    /// Default to `https://login.microsoftonline.com`.
+    pub fn authority_host(mut self, authority_host: &str) -> Self {
+        if !authority_host.is_empty() {
+            self.config.authority_host = Some(authority_host.to_string());
+        }
+
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -346,6 +403,10 @@ impl Builder for AzblobBuilder {
     const SCHEME: Scheme = Scheme::Azblob;
     type Config = AzblobConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
@@ -430,6 +491,42 @@ impl Builder for AzblobBuilder {
 
         let cred_loader = AzureStorageLoader::new(config_loader);
 
+        let service_principal = match (&self.config.tenant_id, &self.config.client_id) {
+            (Some(tenant_id), Some(client_id)) => {
+                let secret = if let Some(secret) = &self.config.client_secret {
+                    ClientSecret::Secret(secret.clone())
+                } else if let Some(path) = &self.config.client_certificate_path {
+                    let pem = std::fs::read_to_string(path).map_err(|err| {
+                        Error::new(ErrorKind::ConfigInvalid, "read client certificate failed")
+                            .with_operation("Builder::build")
+                            .with_context("service", Scheme::Azblob)
+                            .with_context("path", path)
+                            .set_source(err)
+                    })?;
+                    ClientSecret::Certificate(pem)
+                } else {
+                    return Err(Error::new(
+                        ErrorKind::ConfigInvalid,
+                        "client_secret or client_certificate_path is required for service principal",
+                    )
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Azblob));
+                };
+                let authority_host = self
+                    .config
+                    .authority_host
+                    .as_deref()
+                    .unwrap_or(DEFAULT_AUTHORITY_HOST);
+                Some(ServicePrincipalLoader::new(
+                    authority_host,
+                    tenant_id,
+                    client_id,
+                    secret,
+                ))
+            }
+            _ => None,
+        };
+
         let signer = AzureStorageSigner::new();
 
         Ok(AzblobBackend {
@@ -443,6 +540,7 @@ impl Builder for AzblobBuilder {
 
                 client,
                 loader: cred_loader,
+                service_principal,
                 signer,
             }),
             has_sas_token: self.config.sas_token.is_some(),
@@ -507,6 +605,7 @@ impl Access for AzblobBackend {
                 stat_has_content_range: true,
                 stat_has_etag: true,
                 stat_has_content_md5: true,
+                stat_has_user_metadata: true,
                 stat_has_last_modified: true,
                 stat_has_content_disposition: true,
 
@@ -527,17 +626,26 @@ impl Access for AzblobBackend {
                 write_with_if_not_exists: true,
                 write_with_if_none_match: true,
                 write_with_user_metadata: true,
+                // The max size of a block in block blob is 4000 MiB.
+                //
+                // It fits in `usize` on 32-bit targets too.
+                //
+                // ref: <https://learn.microsoft.com/en-us/rest/api/storageservices/put-block#remarks>
+                write_multi_max_size: Some(4000 * 1024 * 1024),
 
                 delete: true,
                 delete_max_size: Some(AZBLOB_BATCH_LIMIT),
 
                 copy: true,
+                concat: true,
 
                 list: true,
                 list_with_recursive: true,
+                list_with_start_token: true,
                 list_has_etag: true,
                 list_has_content_length: true,
                 list_has_content_md5: true,
+                list_has_user_metadata: true,
                 list_has_content_type: true,
                 list_has_last_modified: true,
 
@@ -546,6 +654,8 @@ impl Access for AzblobBackend {
                 presign_read: self.has_sas_token,
                 presign_write: self.has_sas_token,
 
+                tag: true,
+
                 shared: true,
 
                 ..Default::default()
@@ -564,6 +674,13 @@ impl Access for AzblobBackend {
                 let headers = resp.headers();
                 let mut meta = parse_into_metadata(path, headers)?;
 
+                // Blobs uploaded via put block list only carry md5 in this header.
+                if meta.content_md5().is_none() {
+                    if let Some(v) = parse_header_to_str(headers, "x-ms-blob-content-md5")? {
+                        meta.set_content_md5(v);
+                    }
+                }
+
                 let user_meta = parse_prefixed_headers(headers, X_MS_META_PREFIX);
                 if !user_meta.is_empty() {
                     meta.with_user_metadata(user_meta);
@@ -580,7 +697,10 @@ impl Access for AzblobBackend {
 
         let status = resp.status();
         match status {
-            StatusCode::OK | StatusCode::PARTIAL_CONTENT => Ok((RpRead::new(), resp.into_body())),
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let etag = parse_etag(resp.headers())?.map(String::from);
+                Ok((RpRead::new().with_etag(etag), resp.into_body()))
+            }
             _ => {
                 let (part, mut body) = resp.into_parts();
                 let buf = body.to_buffer().await?;
@@ -619,7 +739,10 @@ impl Access for AzblobBackend {
             args.limit(),
         );
 
-        Ok((RpList::default(), oio::PageLister::new(l)))
+        Ok((
+            RpList::default(),
+            oio::PageLister::new(l).with_start_token(args.start_token()),
+        ))
     }
 
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
@@ -633,7 +756,72 @@ impl Access for AzblobBackend {
         }
     }
 
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        // Stage every source as a block of path, and commit them in order.
+        let mut block_ids = Vec::with_capacity(args.sources().len());
+        for source in args.sources() {
+            let block_id = uuid::Uuid::new_v4();
+            let resp = self
+                .core
+                .azblob_put_block_from_url(path, block_id, source)
+                .await?;
+            if resp.status() != StatusCode::CREATED {
+                return Err(parse_error(resp));
+            }
+            block_ids.push(block_id);
+        }
+
+        let resp = self
+            .core
+            .azblob_complete_put_block_list(path, block_ids, &OpWrite::default())
+            .await?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => Ok(RpConcat::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn tag(&self, path: &str, args: OpTag) -> Result<RpTag> {
+        let tags = match args.into_operation() {
+            TagOperation::Get => {
+                let resp = self.core.azblob_get_blob_tags(path).await?;
+                if resp.status() != StatusCode::OK {
+                    return Err(parse_error(resp));
+                }
+
+                let bs = resp.into_body();
+                let out: BlobTags =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+                return Ok(RpTag::new(
+                    out.tag_set
+                        .tag
+                        .into_iter()
+                        .map(|t| (t.key, t.value))
+                        .collect(),
+                ));
+            }
+            TagOperation::Set(tags) => tags,
+            // Azblob doesn't have a delete tags API, set an empty tag set instead.
+            TagOperation::Delete => HashMap::new(),
+        };
+
+        let resp = self.core.azblob_set_blob_tags(path, tags).await?;
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(RpTag::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !args.headers().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "Azblob doesn't support presigned request with extra headers",
+            ));
+        }
+
         let mut req = match args.operation() {
             PresignOperation::Stat(v) => self.core.azblob_head_blob_request(path, v)?,
             PresignOperation::Read(v) => {
@@ -644,6 +832,12 @@ impl Access for AzblobBackend {
                 self.core
                     .azblob_put_blob_request(path, None, &OpWrite::default(), Buffer::new())?
             }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "Azblob doesn't support this presign operation",
+                ))
+            }
         };
 
         self.core.sign_query(&mut req).await?;
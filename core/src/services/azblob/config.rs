@@ -58,6 +58,22 @@ pub struct AzblobConfig {
     /// The sas token of Azblob service backend.
     pub sas_token: Option<String>,
 
+    /// The tenant id of the Azure AD service principal.
+    pub tenant_id: Option<String>,
+
+    /// The client id of the Azure AD service principal.
+    pub client_id: Option<String>,
+
+    /// The client secret of the Azure AD service principal.
+    pub client_secret: Option<String>,
+
+    /// The path to the PEM encoded certificate (with its PKCS#8 private key) of the
+    /// Azure AD service principal.
+    pub client_certificate_path: Option<String>,
+
+    /// The authority host of Azure AD, default to `https://login.microsoftonline.com`.
+    pub authority_host: Option<String>,
+
     /// The maximum batch operations of Azblob service backend.
     pub batch_max_operations: Option<usize>,
 }
@@ -79,6 +95,9 @@ impl Debug for AzblobConfig {
         if self.sas_token.is_some() {
             ds.field("sas_token", &"<redacted>");
         }
+        if self.client_secret.is_some() {
+            ds.field("client_secret", &"<redacted>");
+        }
 
         ds.finish()
     }
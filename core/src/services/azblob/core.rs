@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
@@ -42,6 +43,7 @@ use serde::Deserialize;
 use serde::Serialize;
 use uuid::Uuid;
 
+use super::credential::ServicePrincipalLoader;
 use crate::raw::*;
 use crate::*;
 
@@ -69,6 +71,8 @@ pub struct AzblobCore {
     pub encryption_algorithm: Option<HeaderValue>,
     pub client: HttpClient,
     pub loader: AzureStorageLoader,
+    /// Takes precedence over `loader` if the service principal is configured.
+    pub service_principal: Option<ServicePrincipalLoader>,
     pub signer: AzureStorageSigner,
 }
 
@@ -84,6 +88,13 @@ impl Debug for AzblobCore {
 
 impl AzblobCore {
     async fn load_credential(&self) -> Result<AzureStorageCredential> {
+        if let Some(loader) = &self.service_principal {
+            return loader
+                .load(GLOBAL_REQWEST_CLIENT.clone())
+                .await
+                .map_err(new_request_credential_error);
+        }
+
         let cred = self
             .loader
             .load()
@@ -455,6 +466,62 @@ impl AzblobCore {
         self.send(req).await
     }
 
+    /// Stage the content of `source` as a block of `path` without downloading it.
+    ///
+    /// ref: <https://learn.microsoft.com/en-us/rest/api/storageservices/put-block-from-url>
+    pub async fn azblob_put_block_from_url(
+        &self,
+        path: &str,
+        block_id: Uuid,
+        source: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let encoded_block_id: String =
+            percent_encode_path(&BASE64_STANDARD.encode(block_id.as_bytes()));
+        let url = format!(
+            "{}/{}/{}?comp=block&blockid={}",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p),
+            encoded_block_id,
+        );
+        let source = self.azblob_copy_source_url(source).await?;
+
+        let mut req = Request::put(&url);
+        // Set SSE headers.
+        req = self.insert_sse_headers(req);
+
+        let mut req = req
+            .header(constants::X_MS_COPY_SOURCE, source)
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Build the url of `path` that could be used as copy source.
+    ///
+    /// Private blobs used as the source of `Put Block From URL` must be authorized
+    /// via SAS, so the url is signed and the credential must support it.
+    async fn azblob_copy_source_url(&self, path: &str) -> Result<String> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/{}/{}",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::get(&url)
+            .body(())
+            .map_err(new_request_build_error)?;
+        self.sign_query(&mut req).await?;
+        Ok(req.uri().to_string())
+    }
+
     pub fn azblob_complete_put_block_list_request(
         &self,
         path: &str,
@@ -600,6 +667,60 @@ impl AzblobCore {
         self.send(req).await
     }
 
+    pub async fn azblob_get_blob_tags(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=tags",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Replace all tags of the blob, an empty `tags` will remove all tags.
+    pub async fn azblob_set_blob_tags(
+        &self,
+        path: &str,
+        tags: HashMap<String, String>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=tags",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut tag: Vec<BlobTag> = tags
+            .into_iter()
+            .map(|(key, value)| BlobTag { key, value })
+            .collect();
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let content = quick_xml::se::to_string(&BlobTags {
+            tag_set: BlobTagSet { tag },
+        })
+        .map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn azblob_list_blobs(
         &self,
         path: &str,
@@ -610,7 +731,7 @@ impl AzblobCore {
         let p = build_abs_path(&self.root, path);
 
         let mut url = format!(
-            "{}/{}?restype=container&comp=list",
+            "{}/{}?restype=container&comp=list&include=metadata",
             self.endpoint, self.container
         );
         if !p.is_empty() {
@@ -667,6 +788,26 @@ pub struct PutBlockListRequest {
     pub latest: Vec<String>,
 }
 
+/// Request and result of Set Blob Tags/Get Blob Tags.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tags", rename_all = "PascalCase")]
+pub struct BlobTags {
+    pub tag_set: BlobTagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BlobTagSet {
+    pub tag: Vec<BlobTag>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BlobTag {
+    pub key: String,
+    pub value: String,
+}
+
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct ListBlobsOutput {
@@ -693,6 +834,8 @@ pub struct BlobPrefix {
 pub struct Blob {
     pub properties: Properties,
     pub name: String,
+    /// User defined metadata, only returned while listing with `include=metadata`.
+    pub metadata: HashMap<String, String>,
 }
 
 #[derive(Default, Debug, Deserialize)]
@@ -770,6 +913,9 @@ mod tests {
                             <LeaseState>available</LeaseState>
                             <ServerEncrypted>true</ServerEncrypted>
                         </Properties>
+                        <Metadata>
+                            <owner>opendal</owner>
+                        </Metadata>
                         <OrMetadata />
                     </Blob>
                     <Blob>
@@ -872,6 +1018,11 @@ mod tests {
                 .collect::<Vec<String>>(),
             ["dir1/dir2/", "dir1/dir21/"]
         );
+        assert!(out.blobs.blob[0].metadata.is_empty());
+        assert_eq!(
+            out.blobs.blob[1].metadata.get("owner").map(String::as_str),
+            Some("opendal")
+        );
     }
 
     /// This case is copied from real environment for testing
@@ -933,4 +1084,44 @@ mod tests {
             vec!["1".to_string(), "2".to_string(), "3".to_string()]
         );
     }
+
+    /// This example is from https://learn.microsoft.com/en-us/rest/api/storageservices/get-blob-tags
+    #[test]
+    fn test_parse_blob_tags() {
+        let bs = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+            <Tags>
+              <TagSet>
+                <Tag>
+                  <Key>tag1</Key>
+                  <Value>value1</Value>
+                </Tag>
+                <Tag>
+                  <Key>tag2</Key>
+                  <Value>value2</Value>
+                </Tag>
+              </TagSet>
+            </Tags>";
+
+        let out: BlobTags = de::from_reader(Bytes::from(bs).reader()).expect("must success");
+        assert_eq!(
+            out.tag_set.tag,
+            vec![
+                BlobTag {
+                    key: "tag1".to_string(),
+                    value: "value1".to_string(),
+                },
+                BlobTag {
+                    key: "tag2".to_string(),
+                    value: "value2".to_string(),
+                },
+            ]
+        );
+
+        let actual = quick_xml::se::to_string(&out).expect("must succeed");
+        pretty_assertions::assert_eq!(
+            actual,
+            "<Tags><TagSet><Tag><Key>tag1</Key><Value>value1</Value></Tag>\
+             <Tag><Key>tag2</Key><Value>value2</Value></Tag></TagSet></Tags>"
+        );
+    }
 }
@@ -69,19 +69,25 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
         StatusCode::PRECONDITION_FAILED | StatusCode::NOT_MODIFIED | StatusCode::CONFLICT => {
             (ErrorKind::ConditionNotMatch, false)
         }
-        StatusCode::INTERNAL_SERVER_ERROR
-        | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
     let bs_content = bs.chunk();
-    let mut message = match de::from_reader::<_, AzblobError>(bs_content.reader()) {
-        Ok(azblob_err) => format!("{azblob_err:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let (mut message, mut code) = match de::from_reader::<_, AzblobError>(bs_content.reader()) {
+        Ok(azblob_err) => (format!("{azblob_err:?}"), Some(azblob_err.code)),
+        Err(_) => (String::from_utf8_lossy(&bs).into_owned(), None),
     };
 
+    // Azure always returns the error code in header, prefer it if body
+    // doesn't carry one.
+    if code.as_deref().unwrap_or_default().is_empty() {
+        code = parts
+            .headers
+            .get("x-ms-error-code")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+    }
+
     // If there is no body here, fill with error code.
     if message.is_empty() {
         if let Some(v) = parts.headers.get("x-ms-error-code") {
@@ -99,6 +105,10 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
 
     let mut err = Error::new(kind, &message);
 
+    if let Some(code) = code.filter(|v| !v.is_empty()) {
+        err = err.with_service_code(code);
+    }
+
     err = with_error_response_context(err, parts);
 
     if retryable {
@@ -88,7 +88,7 @@ impl oio::PageList for AzblobLister {
                 path = "/".to_string();
             }
 
-            let meta = Metadata::new(EntryMode::from_path(&path))
+            let mut meta = Metadata::new(EntryMode::from_path(&path))
                 // Keep fit with ETag header.
                 .with_etag(format!("\"{}\"", object.properties.etag.as_str()))
                 .with_content_length(object.properties.content_length)
@@ -97,6 +97,9 @@ impl oio::PageList for AzblobLister {
                 .with_last_modified(parse_datetime_from_rfc2822(
                     object.properties.last_modified.as_str(),
                 )?);
+            if !object.metadata.is_empty() {
+                meta.with_user_metadata(object.metadata);
+            }
 
             let de = oio::Entry::with(path, meta);
             ctx.entries.push_back(de);
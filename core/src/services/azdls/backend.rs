@@ -150,6 +150,10 @@ impl Builder for AzdlsBuilder {
     const SCHEME: Scheme = Scheme::Azdls;
     type Config = AzdlsConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
@@ -69,11 +69,7 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
         StatusCode::PRECONDITION_FAILED | StatusCode::CONFLICT => {
             (ErrorKind::ConditionNotMatch, false)
         }
-        StatusCode::INTERNAL_SERVER_ERROR
-        | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
     let mut message = match de::from_reader::<_, AzdlsError>(bs.clone().reader()) {
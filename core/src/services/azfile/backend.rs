@@ -143,6 +143,10 @@ impl Builder for AzfileBuilder {
     const SCHEME: Scheme = Scheme::Azfile;
     type Config = AzfileConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
@@ -136,6 +136,10 @@ impl Builder for B2Builder {
     const SCHEME: Scheme = Scheme::B2;
     type Config = B2Config;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Builds the backend and returns the result of B2Backend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
@@ -396,6 +400,13 @@ impl Access for B2Backend {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !args.headers().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "B2 doesn't support presigned request with extra headers",
+            ));
+        }
+
         match args.operation() {
             PresignOperation::Stat(_) => {
                 let resp = self
@@ -471,6 +482,10 @@ impl Access for B2Backend {
                     parts.headers,
                 )))
             }
+            _ => Err(Error::new(
+                ErrorKind::Unsupported,
+                "B2 doesn't support this presign operation",
+            )),
         }
     }
 }
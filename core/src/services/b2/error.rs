@@ -43,8 +43,7 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
         // Service b2 could return 403, show the authorization error
         401 => (ErrorKind::PermissionDenied, true),
         429 => (ErrorKind::RateLimited, true),
-        500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
     let (message, b2_err) = serde_json::from_reader::<_, B2Error>(bs.clone().reader())
@@ -114,6 +114,10 @@ impl Builder for ChainsafeBuilder {
     const SCHEME: Scheme = Scheme::Chainsafe;
     type Config = ChainsafeConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Builds the backend and returns the result of ChainsafeBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
@@ -51,8 +51,7 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
         // Service like Upyun could return 499 error with a message like:
         // Client Disconnect, we should retry it.
         499 => (ErrorKind::Unexpected, true),
-        500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
     let (message, _chainsafe_err) =
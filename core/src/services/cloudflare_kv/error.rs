@@ -36,11 +36,7 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
         StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, true),
         // Allowing retry for resource locked.
         StatusCode::LOCKED => (ErrorKind::Unexpected, true),
-        StatusCode::INTERNAL_SERVER_ERROR
-        | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
     let (message, err) = de::from_reader::<_, CfKvResponse>(bs.clone().reader())
@@ -158,6 +158,10 @@ impl Builder for CosBuilder {
     const SCHEME: Scheme = Scheme::Cos;
     type Config = CosConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
@@ -280,6 +284,7 @@ impl Access for CosBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_content_type: true,
                 write_with_cache_control: true,
                 write_with_content_disposition: true,
@@ -313,6 +318,7 @@ impl Access for CosBackend {
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_with_headers: true,
 
                 shared: true,
 
@@ -354,7 +360,8 @@ impl Access for CosBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let etag = parse_etag(resp.headers())?.map(String::from);
+                Ok((RpRead::default().with_etag(etag), resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -370,11 +377,10 @@ impl Access for CosBackend {
         let w = if args.append() {
             CosWriters::Two(oio::AppendWriter::new(writer))
         } else {
-            CosWriters::One(oio::MultipartWriter::new(
-                writer,
-                args.executor().cloned(),
-                args.concurrent(),
-            ))
+            CosWriters::One(
+                oio::MultipartWriter::new(writer, args.executor().cloned(), args.concurrent())
+                    .with_progress(args.progress().cloned()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -428,7 +434,17 @@ impl Access for CosBackend {
                 self.core
                     .cos_put_object_request(path, None, v, Buffer::new())?
             }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "Cos doesn't support this presign operation",
+                ))
+            }
         };
+
+        // Extra headers will be signed so that clients must send them as is.
+        req.headers_mut().extend(args.headers().clone());
+
         self.core.sign_query(&mut req, args.expire()).await?;
 
         // We don't need this request anymore, consume it directly.
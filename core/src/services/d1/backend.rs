@@ -132,12 +132,27 @@ impl D1Builder {
         }
         self
     }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
 }
 
 impl Builder for D1Builder {
     const SCHEME: Scheme = Scheme::D1;
     type Config = D1Config;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         let mut authorization = None;
         let config = self.config;
@@ -36,7 +36,10 @@ use crate::*;
 impl Configurator for DbfsConfig {
     type Builder = DbfsBuilder;
     fn into_builder(self) -> Self::Builder {
-        DbfsBuilder { config: self }
+        DbfsBuilder {
+            config: self,
+            http_client: None,
+        }
     }
 }
 
@@ -45,6 +48,7 @@ impl Configurator for DbfsConfig {
 #[derive(Default, Clone)]
 pub struct DbfsBuilder {
     config: DbfsConfig,
+    http_client: Option<HttpClient>,
 }
 
 impl Debug for DbfsBuilder {
@@ -93,12 +97,27 @@ impl DbfsBuilder {
         }
         self
     }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
 }
 
 impl Builder for DbfsBuilder {
     const SCHEME: Scheme = Scheme::Dbfs;
     type Config = DbfsConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Build a DbfsBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
@@ -124,7 +143,14 @@ impl Builder for DbfsBuilder {
             }
         };
 
-        let client = HttpClient::new()?;
+        let client = if let Some(client) = self.http_client {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Dbfs)
+            })?
+        };
         Ok(DbfsBackend {
             core: Arc::new(DbfsCore {
                 root,
@@ -124,6 +124,10 @@ impl Builder for DropboxBuilder {
     const SCHEME: Scheme = Scheme::Dropbox;
     type Config = DropboxConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         let root = normalize_root(&self.config.root.unwrap_or_default());
         let client = if let Some(client) = self.http_client {
@@ -20,13 +20,16 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use chrono::DateTime;
 use log::debug;
+use log::warn;
 
 use super::core::*;
 use super::delete::FsDeleter;
 use super::lister::FsLister;
 use super::reader::FsReader;
+use super::reader::FsReaders;
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+use super::uring::*;
 use super::writer::FsWriter;
 use super::writer::FsWriters;
 use crate::raw::*;
@@ -72,6 +75,42 @@ impl FsBuilder {
 
         self
     }
+
+    /// Enable atomic write via a temp file in the same dir of the target file.
+    ///
+    /// Data will be written to a hidden temp file which will be renamed to the target
+    /// file on close, so readers never see partially written files.
+    ///
+    /// # Notes
+    ///
+    /// - Ignored if `atomic_write_dir` is set.
+    /// - When append is enabled and the target file exists, we will append to it directly.
+    /// - Temp files of unfinished writes are skipped while listing.
+    pub fn enable_atomic_write(mut self) -> Self {
+        self.config.atomic_write = true;
+        self
+    }
+
+    /// Fsync the parent dir after the file is created or renamed while closing writers.
+    ///
+    /// The file content is always synced on close, syncing the parent dir makes sure the
+    /// file itself survives crashes too. It's a no-op on non-unix platforms.
+    pub fn enable_sync_dir(mut self) -> Self {
+        self.config.sync_dir = true;
+        self
+    }
+
+    /// Use io_uring for async reads and writes if possible.
+    ///
+    /// # Notes
+    ///
+    /// - Only works on Linux with the `services-fs-io-uring` feature enabled, tokio::fs will
+    ///   be used otherwise or if io_uring is not supported by the kernel.
+    /// - Appending writes will always use tokio::fs.
+    pub fn enable_io_uring(mut self) -> Self {
+        self.config.enable_io_uring = true;
+        self
+    }
 }
 
 impl Builder for FsBuilder {
@@ -146,11 +185,32 @@ impl Builder for FsBuilder {
             })
             .unwrap_or(Ok(None))?;
 
+        #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+        let uring = if self.config.enable_io_uring {
+            match UringDriver::new() {
+                Ok(driver) => Some(Arc::new(driver)),
+                Err(err) => {
+                    warn!("backend io_uring is not available, fallback to tokio::fs: {err}");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        #[cfg(not(all(target_os = "linux", feature = "services-fs-io-uring")))]
+        if self.config.enable_io_uring {
+            warn!("backend io_uring is not supported in this build, fallback to tokio::fs");
+        }
+
         Ok(FsBackend {
             core: Arc::new(FsCore {
                 root,
                 atomic_write_dir,
+                atomic_write: self.config.atomic_write,
+                sync_dir: self.config.sync_dir,
                 buf_pool: oio::PooledBuf::new(16).with_initial_capacity(256 * 1024),
+                #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+                uring,
             }),
         })
     }
@@ -163,7 +223,7 @@ pub struct FsBackend {
 }
 
 impl Access for FsBackend {
-    type Reader = FsReader<tokio::fs::File>;
+    type Reader = FsReaders;
     type Writer = FsWriters;
     type Lister = Option<FsLister<tokio::fs::ReadDir>>;
     type Deleter = oio::OneShotDeleter<FsDeleter>;
@@ -180,6 +240,8 @@ impl Access for FsBackend {
                 stat: true,
                 stat_has_content_length: true,
                 stat_has_last_modified: true,
+                stat_has_permissions: cfg!(unix),
+                stat_has_owner: cfg!(unix),
 
                 read: true,
 
@@ -187,6 +249,7 @@ impl Access for FsBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_permissions: cfg!(unix),
                 create_dir: true,
                 delete: true,
 
@@ -219,22 +282,7 @@ impl Access for FsBackend {
 
         let meta = tokio::fs::metadata(&p).await.map_err(new_std_io_error)?;
 
-        let mode = if meta.is_dir() {
-            EntryMode::DIR
-        } else if meta.is_file() {
-            EntryMode::FILE
-        } else {
-            EntryMode::Unknown
-        };
-        let m = Metadata::new(mode)
-            .with_content_length(meta.len())
-            .with_last_modified(
-                meta.modified()
-                    .map(DateTime::from)
-                    .map_err(new_std_io_error)?,
-            );
-
-        Ok(RpStat::new(m))
+        Ok(RpStat::new(parse_metadata(&meta)?))
     }
 
     /// # Notes
@@ -255,6 +303,17 @@ impl Access for FsBackend {
             .await
             .map_err(new_std_io_error)?;
 
+        #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+        if let Some(uring) = &self.core.uring {
+            let f = UringFile::new(uring.clone(), f.into_std().await, args.range().offset());
+            let r = FsReader::new(
+                self.core.clone(),
+                f,
+                args.range().size().unwrap_or(u64::MAX) as _,
+            );
+            return Ok((RpRead::new(), FsReaders::Two(r)));
+        }
+
         if args.range().offset() != 0 {
             use tokio::io::AsyncSeekExt;
 
@@ -268,6 +327,8 @@ impl Access for FsBackend {
             f,
             args.range().size().unwrap_or(u64::MAX) as _,
         );
+        #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+        let r = FsReaders::One(r);
         Ok((RpRead::new(), r))
     }
 
@@ -282,6 +343,23 @@ impl Access for FsBackend {
                 .ensure_write_abs_path(atomic_write_dir, &tmp_file_of(path))
                 .await?;
 
+            // If the target file exists, we should append to the end of it directly.
+            if op.append()
+                && tokio::fs::try_exists(&target_path)
+                    .await
+                    .map_err(new_std_io_error)?
+            {
+                (target_path, None)
+            } else {
+                (target_path, Some(tmp_path))
+            }
+        } else if self.core.atomic_write {
+            let target_path = self
+                .core
+                .ensure_write_abs_path(&self.core.root, path)
+                .await?;
+            let tmp_path = tmp_path_of(&target_path, path);
+
             // If the target file exists, we should append to the end of it directly.
             if op.append()
                 && tokio::fs::try_exists(&target_path)
@@ -314,7 +392,28 @@ impl Access for FsBackend {
             .await
             .map_err(new_std_io_error)?;
 
-        let w = FsWriter::new(target_path, tmp_path, f);
+        #[cfg(unix)]
+        if let Some(permissions) = op.permissions() {
+            f.set_permissions(permissions_of(permissions))
+                .await
+                .map_err(new_std_io_error)?;
+        }
+
+        #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+        if let (false, Some(uring)) = (op.append(), &self.core.uring) {
+            let f = UringFile::new(uring.clone(), f.into_std().await, 0);
+            let w = FsWriter::new(target_path, tmp_path, self.core.sync_dir, f);
+            return Ok((
+                RpWrite::default(),
+                FsWriters::Three(oio::PositionWriter::new(
+                    w,
+                    op.executor().cloned(),
+                    op.concurrent(),
+                )),
+            ));
+        }
+
+        let w = FsWriter::new(target_path, tmp_path, self.core.sync_dir, f);
 
         let w = if op.append() {
             FsWriters::One(w)
@@ -350,7 +449,7 @@ impl Access for FsBackend {
             }
         };
 
-        let rd = FsLister::new(&self.core.root, path, f);
+        let rd = FsLister::new(&self.core.root, path, f, self.core.atomic_write);
         Ok((RpList::default(), Some(rd)))
     }
 
@@ -401,22 +500,7 @@ impl Access for FsBackend {
 
         let meta = std::fs::metadata(p).map_err(new_std_io_error)?;
 
-        let mode = if meta.is_dir() {
-            EntryMode::DIR
-        } else if meta.is_file() {
-            EntryMode::FILE
-        } else {
-            EntryMode::Unknown
-        };
-        let m = Metadata::new(mode)
-            .with_content_length(meta.len())
-            .with_last_modified(
-                meta.modified()
-                    .map(DateTime::from)
-                    .map_err(new_std_io_error)?,
-            );
-
-        Ok(RpStat::new(m))
+        Ok(RpStat::new(parse_metadata(&meta)?))
     }
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
@@ -451,6 +535,22 @@ impl Access for FsBackend {
                 .core
                 .blocking_ensure_write_abs_path(atomic_write_dir, &tmp_file_of(path))?;
 
+            // If the target file exists, we should append to the end of it directly.
+            if op.append()
+                && Path::new(&target_path)
+                    .try_exists()
+                    .map_err(new_std_io_error)?
+            {
+                (target_path, None)
+            } else {
+                (target_path, Some(tmp_path))
+            }
+        } else if self.core.atomic_write {
+            let target_path = self
+                .core
+                .blocking_ensure_write_abs_path(&self.core.root, path)?;
+            let tmp_path = tmp_path_of(&target_path, path);
+
             // If the target file exists, we should append to the end of it directly.
             if op.append()
                 && Path::new(&target_path)
@@ -482,7 +582,16 @@ impl Access for FsBackend {
             .open(tmp_path.as_ref().unwrap_or(&target_path))
             .map_err(new_std_io_error)?;
 
-        Ok((RpWrite::new(), FsWriter::new(target_path, tmp_path, f)))
+        #[cfg(unix)]
+        if let Some(permissions) = op.permissions() {
+            f.set_permissions(permissions_of(permissions))
+                .map_err(new_std_io_error)?;
+        }
+
+        Ok((
+            RpWrite::new(),
+            FsWriter::new(target_path, tmp_path, self.core.sync_dir, f),
+        ))
     }
 
     fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
@@ -506,7 +615,7 @@ impl Access for FsBackend {
             }
         };
 
-        let rd = FsLister::new(&self.core.root, path, f);
+        let rd = FsLister::new(&self.core.root, path, f, self.core.atomic_write);
         Ok((RpList::default(), Some(rd)))
     }
 
@@ -559,4 +668,85 @@ mod tests {
             assert!(tmp_file.starts_with(expected_prefix));
         }
     }
+
+    #[test]
+    fn test_is_tmp_file_name() {
+        let tmp_path = tmp_path_of(Path::new("/abc/hello.txt"), "abc/hello.txt");
+        let name = tmp_path.file_name().unwrap().to_string_lossy();
+        assert!(is_tmp_file_name(&name));
+
+        let cases = vec!["hello.txt", ".hello.txt", ".gitignore", ".hello.not-a-uuid"];
+        for name in cases {
+            assert!(!is_tmp_file_name(name), "{name} should not be a temp file");
+        }
+    }
+
+    fn new_atomic_write_operator() -> (Operator, PathBuf) {
+        let root = std::env::temp_dir().join(format!("opendal-fs-{}", uuid::Uuid::new_v4()));
+        let op = Operator::new(
+            FsBuilder::default()
+                .root(&root.to_string_lossy())
+                .enable_atomic_write()
+                .enable_sync_dir(),
+        )
+        .unwrap()
+        .finish();
+        (op, root)
+    }
+
+    #[tokio::test]
+    async fn test_atomic_write_leaves_no_tmp_file() {
+        let (op, root) = new_atomic_write_operator();
+
+        let mut w = op.writer("dir/hello.txt").await.unwrap();
+        w.write("Hello, ").await.unwrap();
+        w.write("World!").await.unwrap();
+        // The target must not be visible before close.
+        assert!(!op.exists("dir/hello.txt").await.unwrap());
+        w.close().await.unwrap();
+
+        let content = op.read("dir/hello.txt").await.unwrap();
+        assert_eq!(content.to_vec(), b"Hello, World!");
+
+        let names: Vec<_> = std::fs::read_dir(root.join("dir"))
+            .unwrap()
+            .map(|de| de.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["hello.txt"]);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_atomic_write_hides_tmp_file_in_list() {
+        let (op, root) = new_atomic_write_operator();
+
+        op.write("dir/hello.txt", "Hello, World!").await.unwrap();
+        // Simulate an in-progress atomic write.
+        let tmp_path = tmp_path_of(&root.join("dir/hello.txt"), "dir/hello.txt");
+        std::fs::write(&tmp_path, "partial").unwrap();
+        std::fs::write(root.join("dir/.gitignore"), "*").unwrap();
+
+        let mut paths: Vec<_> = op
+            .list("dir/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        paths.sort();
+        assert_eq!(paths, vec!["dir/", "dir/.gitignore", "dir/hello.txt"]);
+
+        let mut paths: Vec<_> = op
+            .blocking()
+            .list("dir/")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        paths.sort();
+        assert_eq!(paths, vec!["dir/", "dir/.gitignore", "dir/hello.txt"]);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
 }
@@ -30,4 +30,17 @@ pub struct FsConfig {
 
     /// tmp dir for atomic write
     pub atomic_write_dir: Option<String>,
+
+    /// Write to a temp file in the same dir of the target file and rename it to the target
+    /// file on close. Ignored if `atomic_write_dir` is set.
+    pub atomic_write: bool,
+
+    /// Fsync the parent dir after the file is created or renamed while closing writers.
+    pub sync_dir: bool,
+
+    /// Use io_uring for async reads and writes if possible.
+    ///
+    /// Only works on Linux with the `services-fs-io-uring` feature enabled,
+    /// tokio::fs will be used otherwise.
+    pub enable_io_uring: bool,
 }
@@ -17,9 +17,14 @@
 
 use std::path::Path;
 use std::path::PathBuf;
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+use std::sync::Arc;
 
+use chrono::DateTime;
 use uuid::Uuid;
 
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+use super::uring::UringDriver;
 use crate::raw::*;
 use crate::*;
 
@@ -27,7 +32,12 @@ use crate::*;
 pub struct FsCore {
     pub root: PathBuf,
     pub atomic_write_dir: Option<PathBuf>,
+    pub atomic_write: bool,
+    pub sync_dir: bool,
     pub buf_pool: oio::PooledBuf,
+    /// The io_uring driver for async reads and writes, tokio::fs will be used if not available.
+    #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+    pub uring: Option<Arc<UringDriver>>,
 }
 
 impl FsCore {
@@ -93,3 +103,60 @@ pub fn tmp_file_of(path: &str) -> String {
 
     format!("{name}.{uuid}")
 }
+
+/// Build the temp path for atomic write in the same dir of target path.
+///
+/// The temp file is hidden to avoid being picked up by other tools.
+#[inline]
+pub fn tmp_path_of(target_path: &Path, path: &str) -> PathBuf {
+    target_path.with_file_name(format!(".{}", tmp_file_of(path)))
+}
+
+/// Check if the file name is a temp file built by [`tmp_path_of`].
+#[inline]
+pub fn is_tmp_file_name(name: &str) -> bool {
+    let Some(name) = name.strip_prefix('.') else {
+        return false;
+    };
+    match name.rsplit_once('.') {
+        Some((base, uuid)) => !base.is_empty() && Uuid::parse_str(uuid).is_ok(),
+        None => false,
+    }
+}
+
+/// Build the [`Metadata`] of an entry from the metadata returned by file system.
+pub fn parse_metadata(meta: &std::fs::Metadata) -> Result<Metadata> {
+    let mode = if meta.is_dir() {
+        EntryMode::DIR
+    } else if meta.is_file() {
+        EntryMode::FILE
+    } else {
+        EntryMode::Unknown
+    };
+    let m = Metadata::new(mode)
+        .with_content_length(meta.len())
+        .with_last_modified(
+            meta.modified()
+                .map(DateTime::from)
+                .map_err(new_std_io_error)?,
+        );
+
+    #[cfg(unix)]
+    let m = {
+        use std::os::unix::fs::MetadataExt;
+
+        m.with_permissions(meta.mode() & 0o7777)
+            .with_uid(meta.uid())
+            .with_gid(meta.gid())
+    };
+
+    Ok(m)
+}
+
+/// Build the permissions to apply on written file.
+#[cfg(unix)]
+pub fn permissions_of(mode: u32) -> std::fs::Permissions {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::Permissions::from_mode(mode & 0o7777)
+}
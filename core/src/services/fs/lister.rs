@@ -18,6 +18,7 @@
 use std::path::Path;
 use std::path::PathBuf;
 
+use super::core::is_tmp_file_name;
 use crate::raw::*;
 use crate::EntryMode;
 use crate::Metadata;
@@ -27,18 +28,25 @@ pub struct FsLister<P> {
     root: PathBuf,
 
     current_path: Option<String>,
+    /// Skip the temp files left by in-progress same-dir atomic writes.
+    hide_tmp_files: bool,
 
     rd: P,
 }
 
 impl<P> FsLister<P> {
-    pub fn new(root: &Path, path: &str, rd: P) -> Self {
+    pub fn new(root: &Path, path: &str, rd: P, hide_tmp_files: bool) -> Self {
         Self {
             root: root.to_owned(),
             current_path: Some(path.to_string()),
+            hide_tmp_files,
             rd,
         }
     }
+
+    fn should_skip(&self, name: &std::ffi::OsStr) -> bool {
+        self.hide_tmp_files && is_tmp_file_name(&name.to_string_lossy())
+    }
 }
 
 /// # Safety
@@ -54,8 +62,13 @@ impl oio::List for FsLister<tokio::fs::ReadDir> {
             return Ok(Some(e));
         }
 
-        let Some(de) = self.rd.next_entry().await.map_err(new_std_io_error)? else {
-            return Ok(None);
+        let de = loop {
+            let Some(de) = self.rd.next_entry().await.map_err(new_std_io_error)? else {
+                return Ok(None);
+            };
+            if !self.should_skip(&de.file_name()) {
+                break de;
+            }
         };
 
         let entry_path = de.path();
@@ -88,9 +101,14 @@ impl oio::BlockingList for FsLister<std::fs::ReadDir> {
             return Ok(Some(e));
         }
 
-        let de = match self.rd.next() {
-            Some(de) => de.map_err(new_std_io_error)?,
-            None => return Ok(None),
+        let de = loop {
+            let de = match self.rd.next() {
+                Some(de) => de.map_err(new_std_io_error)?,
+                None => return Ok(None),
+            };
+            if !self.should_skip(&de.file_name()) {
+                break de;
+            }
         };
 
         let entry_path = de.path();
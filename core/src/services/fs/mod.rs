@@ -23,6 +23,8 @@ mod delete;
 mod lister;
 #[cfg(feature = "services-fs")]
 mod reader;
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+mod uring;
 #[cfg(feature = "services-fs")]
 mod writer;
 
@@ -22,9 +22,16 @@ use tokio::io::AsyncReadExt;
 use tokio::io::ReadBuf;
 
 use super::core::*;
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+use super::uring::*;
 use crate::raw::*;
 use crate::*;
 
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+pub type FsReaders = TwoWays<FsReader<tokio::fs::File>, FsReader<UringFile>>;
+#[cfg(not(all(target_os = "linux", feature = "services-fs-io-uring")))]
+pub type FsReaders = FsReader<tokio::fs::File>;
+
 pub struct FsReader<F> {
     core: Arc<FsCore>,
     f: F,
@@ -119,3 +126,18 @@ impl oio::BlockingRead for FsReader<std::fs::File> {
         Ok(Buffer::from(frozen))
     }
 }
+
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+impl oio::Read for FsReader<UringFile> {
+    async fn read(&mut self) -> Result<Buffer> {
+        if self.read >= self.size {
+            return Ok(Buffer::new());
+        }
+
+        let size = (self.size - self.read).min(URING_BUFFER_SIZE);
+        let bs = self.f.read(size).await?;
+        self.read += bs.len();
+
+        Ok(Buffer::from(bs))
+    }
+}
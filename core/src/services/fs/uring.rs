@@ -0,0 +1,367 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io;
+use std::os::fd::AsRawFd;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::channel::oneshot;
+use io_uring::opcode;
+use io_uring::squeue;
+use io_uring::types;
+use io_uring::IoUring;
+use log::warn;
+
+use crate::raw::*;
+use crate::*;
+
+/// The count of submission queue entries, also the count of registered buffers.
+const URING_ENTRIES: u32 = 64;
+
+/// The size of each registered buffer, also the max size of every single read or write.
+pub const URING_BUFFER_SIZE: usize = 256 * 1024;
+
+enum Op {
+    Read {
+        offset: u64,
+        len: usize,
+        tx: oneshot::Sender<io::Result<Bytes>>,
+    },
+    Write {
+        offset: u64,
+        data: Bytes,
+        tx: oneshot::Sender<io::Result<usize>>,
+    },
+    Fsync {
+        tx: oneshot::Sender<io::Result<()>>,
+    },
+}
+
+struct Request {
+    /// Keep the file open until the request completes.
+    file: Arc<File>,
+    op: Op,
+}
+
+/// UringDriver performs file IO via io_uring on a dedicated worker thread.
+///
+/// Data will be copied between user buffers and buffers registered to the ring,
+/// so that the kernel doesn't need to map user pages for every request. If
+/// buffers can't be registered (for example, `RLIMIT_MEMLOCK` is too low), the
+/// same buffers will be used without registration.
+#[derive(Debug)]
+pub struct UringDriver {
+    tx: mpsc::Sender<Request>,
+}
+
+impl UringDriver {
+    /// Setup the ring and spawn the worker thread.
+    ///
+    /// Returns error if io_uring is not supported by current kernel.
+    pub fn new() -> io::Result<Self> {
+        let worker = Worker::new()?;
+        let (tx, rx) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("opendal-fs-uring".to_string())
+            .spawn(move || worker.run(rx))?;
+
+        Ok(Self { tx })
+    }
+
+    async fn send<T>(
+        &self,
+        file: &Arc<File>,
+        op: impl FnOnce(oneshot::Sender<io::Result<T>>) -> Op,
+    ) -> Result<T> {
+        let (tx, rx) = oneshot::channel();
+        let req = Request {
+            file: file.clone(),
+            op: op(tx),
+        };
+
+        self.tx.send(req).map_err(|_| worker_exited())?;
+        rx.await
+            .map_err(|_| worker_exited())?
+            .map_err(new_std_io_error)
+    }
+
+    /// Read at most [`URING_BUFFER_SIZE`] bytes at the offset.
+    pub async fn read_at(&self, file: &Arc<File>, offset: u64, len: usize) -> Result<Bytes> {
+        self.send(file, |tx| Op::Read { offset, len, tx }).await
+    }
+
+    /// Write all data in the buffer at the offset.
+    pub async fn write_all_at(&self, file: &Arc<File>, mut offset: u64, buf: Buffer) -> Result<()> {
+        let mut buf = buf.to_bytes();
+        while !buf.is_empty() {
+            let data = buf.slice(..buf.len().min(URING_BUFFER_SIZE));
+            let n = self.send(file, |tx| Op::Write { offset, data, tx }).await?;
+            if n == 0 {
+                return Err(new_std_io_error(io::ErrorKind::WriteZero.into()));
+            }
+
+            offset += n as u64;
+            buf = buf.slice(n..);
+        }
+        Ok(())
+    }
+
+    pub async fn fsync(&self, file: &Arc<File>) -> Result<()> {
+        self.send(file, |tx| Op::Fsync { tx }).await
+    }
+}
+
+fn worker_exited() -> Error {
+    Error::new(ErrorKind::Unexpected, "io_uring worker has exited").set_temporary()
+}
+
+struct Worker {
+    ring: IoUring,
+    registered: bool,
+    /// Buffers must not be moved or resized while the ring is alive.
+    buffers: Vec<Box<[u8]>>,
+    /// Free buffer slots, slot is also used as the user data of entries.
+    free: Vec<usize>,
+    inflight: Vec<Option<Request>>,
+    pending: VecDeque<Request>,
+}
+
+impl Worker {
+    fn new() -> io::Result<Self> {
+        let ring = IoUring::new(URING_ENTRIES)?;
+        let mut buffers: Vec<Box<[u8]>> = (0..URING_ENTRIES)
+            .map(|_| vec![0; URING_BUFFER_SIZE].into_boxed_slice())
+            .collect();
+
+        let iovecs: Vec<libc::iovec> = buffers
+            .iter_mut()
+            .map(|buf| libc::iovec {
+                iov_base: buf.as_mut_ptr() as *mut _,
+                iov_len: buf.len(),
+            })
+            .collect();
+        // SAFETY: buffers live as long as the ring and are never moved.
+        let registered = match unsafe { ring.submitter().register_buffers(&iovecs) } {
+            Ok(()) => true,
+            Err(err) => {
+                warn!("fs failed to register io_uring buffers, fallback to unregistered: {err}");
+                false
+            }
+        };
+
+        Ok(Self {
+            ring,
+            registered,
+            free: (0..buffers.len()).rev().collect(),
+            inflight: (0..buffers.len()).map(|_| None).collect(),
+            buffers,
+            pending: VecDeque::new(),
+        })
+    }
+
+    fn is_idle(&self) -> bool {
+        self.pending.is_empty() && self.free.len() == self.buffers.len()
+    }
+
+    fn run(mut self, rx: mpsc::Receiver<Request>) {
+        loop {
+            // Only block on the channel while nothing is in flight.
+            if self.is_idle() {
+                match rx.recv() {
+                    Ok(req) => self.pending.push_back(req),
+                    Err(_) => return,
+                }
+            }
+            while let Ok(req) = rx.try_recv() {
+                self.pending.push_back(req);
+            }
+            while !self.free.is_empty() {
+                let Some(req) = self.pending.pop_front() else {
+                    break;
+                };
+                self.push(req);
+            }
+
+            match self.ring.submit_and_wait(1) {
+                Ok(_) => {}
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => {
+                    warn!("fs io_uring worker exited for submit failed: {err}");
+                    // The kernel may still write into buffers of inflight requests.
+                    std::mem::forget(self.buffers);
+                    return;
+                }
+            }
+            self.complete();
+        }
+    }
+
+    fn push(&mut self, req: Request) {
+        let slot = self.free.pop().expect("free slot must exist");
+        let fd = types::Fd(req.file.as_raw_fd());
+        let buf = &mut self.buffers[slot];
+
+        let entry: squeue::Entry = match &req.op {
+            Op::Read { offset, len, .. } => {
+                let len = (*len).min(URING_BUFFER_SIZE) as u32;
+                if self.registered {
+                    opcode::ReadFixed::new(fd, buf.as_mut_ptr(), len, slot as u16)
+                        .offset(*offset)
+                        .build()
+                } else {
+                    opcode::Read::new(fd, buf.as_mut_ptr(), len)
+                        .offset(*offset)
+                        .build()
+                }
+            }
+            Op::Write { offset, data, .. } => {
+                let len = data.len().min(URING_BUFFER_SIZE);
+                buf[..len].copy_from_slice(&data[..len]);
+                if self.registered {
+                    opcode::WriteFixed::new(fd, buf.as_ptr(), len as u32, slot as u16)
+                        .offset(*offset)
+                        .build()
+                } else {
+                    opcode::Write::new(fd, buf.as_ptr(), len as u32)
+                        .offset(*offset)
+                        .build()
+                }
+            }
+            Op::Fsync { .. } => opcode::Fsync::new(fd).build(),
+        };
+
+        // SAFETY: the buffer and the file are kept alive until the entry completes.
+        unsafe {
+            self.ring
+                .submission()
+                .push(&entry.user_data(slot as u64))
+                .expect("submission queue must have room for every free slot");
+        }
+        self.inflight[slot] = Some(req);
+    }
+
+    fn complete(&mut self) {
+        let entries: Vec<_> = self
+            .ring
+            .completion()
+            .map(|e| (e.user_data() as usize, e.result()))
+            .collect();
+
+        for (slot, res) in entries {
+            let req = self.inflight[slot]
+                .take()
+                .expect("inflight request must exist");
+            let res = if res < 0 {
+                Err(io::Error::from_raw_os_error(-res))
+            } else {
+                Ok(res as usize)
+            };
+
+            // Callers may have been cancelled, ignore send errors.
+            match req.op {
+                Op::Read { tx, .. } => {
+                    let _ = tx.send(res.map(|n| Bytes::copy_from_slice(&self.buffers[slot][..n])));
+                }
+                Op::Write { tx, .. } => {
+                    let _ = tx.send(res);
+                }
+                Op::Fsync { tx } => {
+                    let _ = tx.send(res.map(|_| ()));
+                }
+            }
+            self.free.push(slot);
+        }
+    }
+}
+
+/// UringFile is a file that reads or writes via [`UringDriver`].
+pub struct UringFile {
+    driver: Arc<UringDriver>,
+    file: Arc<File>,
+    offset: u64,
+}
+
+impl UringFile {
+    pub fn new(driver: Arc<UringDriver>, file: File, offset: u64) -> Self {
+        Self {
+            driver,
+            file: Arc::new(file),
+            offset,
+        }
+    }
+
+    /// Read at most `len` bytes at current offset, and advance the offset.
+    pub async fn read(&mut self, len: usize) -> Result<Bytes> {
+        let bs = self.driver.read_at(&self.file, self.offset, len).await?;
+        self.offset += bs.len() as u64;
+        Ok(bs)
+    }
+
+    pub async fn write_all_at(&self, offset: u64, buf: Buffer) -> Result<()> {
+        self.driver.write_all_at(&self.file, offset, buf).await
+    }
+
+    pub async fn sync_all(&self) -> Result<()> {
+        self.driver.fsync(&self.file).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_uring_read_write() {
+        let Ok(driver) = UringDriver::new() else {
+            // io_uring is not supported by current kernel.
+            return;
+        };
+        let driver = Arc::new(driver);
+
+        let path = std::env::temp_dir().join(format!("opendal-uring-{}", uuid::Uuid::new_v4()));
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .read(true)
+            .write(true)
+            .open(&path)
+            .unwrap();
+        let mut f = UringFile::new(driver, file, 0);
+
+        let content: Vec<u8> = (0..URING_BUFFER_SIZE * 2 + 7).map(|i| i as u8).collect();
+        f.write_all_at(0, Buffer::from(content.clone()))
+            .await
+            .unwrap();
+        f.sync_all().await.unwrap();
+
+        let mut read = Vec::new();
+        loop {
+            let bs = f.read(URING_BUFFER_SIZE).await.unwrap();
+            if bs.is_empty() {
+                break;
+            }
+            read.extend_from_slice(&bs);
+        }
+        assert_eq!(read, content);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -17,33 +17,91 @@
 
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 
 use bytes::Buf;
 use tokio::io::AsyncWriteExt;
 
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+use super::uring::UringFile;
 use crate::raw::*;
 use crate::*;
 
+#[cfg(not(all(target_os = "linux", feature = "services-fs-io-uring")))]
 pub type FsWriters =
     TwoWays<FsWriter<tokio::fs::File>, oio::PositionWriter<FsWriter<tokio::fs::File>>>;
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+pub type FsWriters = ThreeWays<
+    FsWriter<tokio::fs::File>,
+    oio::PositionWriter<FsWriter<tokio::fs::File>>,
+    oio::PositionWriter<FsWriter<UringFile>>,
+>;
 
 pub struct FsWriter<F> {
     target_path: PathBuf,
     tmp_path: Option<PathBuf>,
+    sync_dir: bool,
 
     f: Option<F>,
 }
 
 impl<F> FsWriter<F> {
-    pub fn new(target_path: PathBuf, tmp_path: Option<PathBuf>, f: F) -> Self {
+    pub fn new(target_path: PathBuf, tmp_path: Option<PathBuf>, sync_dir: bool, f: F) -> Self {
         Self {
             target_path,
             tmp_path,
+            sync_dir,
 
             f: Some(f),
         }
     }
+
+    /// Move the temp file to the target path and persist the dir entry if needed.
+    ///
+    /// Must be called after the file content has been synced.
+    async fn finish(&self) -> Result<()> {
+        if let Some(tmp_path) = &self.tmp_path {
+            tokio::fs::rename(tmp_path, &self.target_path)
+                .await
+                .map_err(new_std_io_error)?;
+        }
+        if self.sync_dir {
+            let target_path = self.target_path.clone();
+            tokio::task::spawn_blocking(move || sync_parent_dir(&target_path))
+                .await
+                .map_err(new_task_join_error)??;
+        }
+        Ok(())
+    }
+
+    fn blocking_finish(&self) -> Result<()> {
+        if let Some(tmp_path) = &self.tmp_path {
+            std::fs::rename(tmp_path, &self.target_path).map_err(new_std_io_error)?;
+        }
+        if self.sync_dir {
+            sync_parent_dir(&self.target_path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Fsync the parent dir of the path so that the created or renamed entry survives crashes.
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> Result<()> {
+    let parent = path.parent().ok_or_else(|| {
+        Error::new(ErrorKind::Unexpected, "path should have parent but not")
+            .with_context("input", path.to_string_lossy())
+    })?;
+    File::open(parent)
+        .and_then(|f| f.sync_all())
+        .map_err(new_std_io_error)
+}
+
+/// Dirs can't be opened as files on non-unix platforms, skip it.
+#[cfg(not(unix))]
+fn sync_parent_dir(_: &Path) -> Result<()> {
+    Ok(())
 }
 
 /// # Safety
@@ -68,12 +126,7 @@ impl oio::Write for FsWriter<tokio::fs::File> {
         f.flush().await.map_err(new_std_io_error)?;
         f.sync_all().await.map_err(new_std_io_error)?;
 
-        if let Some(tmp_path) = &self.tmp_path {
-            tokio::fs::rename(tmp_path, &self.target_path)
-                .await
-                .map_err(new_std_io_error)?;
-        }
-        Ok(())
+        self.finish().await
     }
 
     async fn abort(&mut self) -> Result<()> {
@@ -84,7 +137,7 @@ impl oio::Write for FsWriter<tokio::fs::File> {
         } else {
             Err(Error::new(
                 ErrorKind::Unsupported,
-                "Fs doesn't support abort if atomic_write_dir is not set",
+                "Fs doesn't support abort if atomic write is not enabled",
             ))
         }
     }
@@ -105,10 +158,7 @@ impl oio::BlockingWrite for FsWriter<std::fs::File> {
     fn close(&mut self) -> Result<()> {
         if let Some(f) = self.f.take() {
             f.sync_all().map_err(new_std_io_error)?;
-
-            if let Some(tmp_path) = &self.tmp_path {
-                std::fs::rename(tmp_path, &self.target_path).map_err(new_std_io_error)?;
-            }
+            self.blocking_finish()?;
         }
 
         Ok(())
@@ -157,12 +207,35 @@ impl oio::PositionWrite for FsWriter<tokio::fs::File> {
         f.flush().map_err(new_std_io_error)?;
         f.sync_all().map_err(new_std_io_error)?;
 
+        self.finish().await
+    }
+
+    async fn abort(&self) -> Result<()> {
         if let Some(tmp_path) = &self.tmp_path {
-            tokio::fs::rename(tmp_path, &self.target_path)
+            tokio::fs::remove_file(tmp_path)
                 .await
-                .map_err(new_std_io_error)?;
+                .map_err(new_std_io_error)
+        } else {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "Fs doesn't support abort if atomic write is not enabled",
+            ))
         }
-        Ok(())
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+impl oio::PositionWrite for FsWriter<UringFile> {
+    async fn write_all_at(&self, offset: u64, buf: Buffer) -> Result<()> {
+        let f = self.f.as_ref().expect("FsWriter must be initialized");
+        f.write_all_at(offset, buf).await
+    }
+
+    async fn close(&self) -> Result<()> {
+        let f = self.f.as_ref().expect("FsWriter must be initialized");
+        f.sync_all().await?;
+
+        self.finish().await
     }
 
     async fn abort(&self) -> Result<()> {
@@ -173,7 +246,7 @@ impl oio::PositionWrite for FsWriter<tokio::fs::File> {
         } else {
             Err(Error::new(
                 ErrorKind::Unsupported,
-                "Fs doesn't support abort if atomic_write_dir is not set",
+                "Fs doesn't support abort if atomic write is not enabled",
             ))
         }
     }
@@ -37,6 +37,11 @@ pub struct GcsConfig {
     pub scope: Option<String>,
     /// Service Account for gcs.
     pub service_account: Option<String>,
+    /// The target service account to impersonate via IAM credentials API.
+    ///
+    /// The loaded credential must be granted `roles/iam.serviceAccountTokenCreator`
+    /// on the target service account.
+    pub impersonate_service_account: Option<String>,
     /// Credentials string for GCS service OAuth2 authentication.
     pub credential: Option<String>,
     /// Local path to credentials file for GCS service OAuth2 authentication.
@@ -57,6 +62,12 @@ pub struct GcsConfig {
     ///
     /// Takes precedence over `credential` and `credential_path`.
     pub token: Option<String>,
+    /// Upload chunks as temporary objects and compose them when writing with
+    /// `concurrent` larger than 1.
+    ///
+    /// Resumable uploads will be used and chunks will be uploaded in order if
+    /// not enabled.
+    pub enable_concurrent_compose: bool,
 }
 
 impl Debug for GcsConfig {
@@ -23,8 +23,11 @@ use std::time::Duration;
 
 use backon::ExponentialBuilder;
 use backon::Retryable;
-use bytes::Bytes;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use chrono::Utc;
 use http::header::CONTENT_LENGTH;
+use http::header::CONTENT_RANGE;
 use http::header::CONTENT_TYPE;
 use http::header::HOST;
 use http::header::IF_MATCH;
@@ -33,15 +36,25 @@ use http::header::IF_NONE_MATCH;
 use http::header::IF_UNMODIFIED_SINCE;
 use http::Request;
 use http::Response;
+use http::StatusCode;
+use log::warn;
 use once_cell::sync::Lazy;
 use reqsign::GoogleCredential;
 use reqsign::GoogleCredentialLoader;
 use reqsign::GoogleSigner;
 use reqsign::GoogleToken;
 use reqsign::GoogleTokenLoader;
+use rsa::pkcs1v15::SigningKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::signature::SignatureEncoding;
+use rsa::signature::Signer;
+use rsa::RsaPrivateKey;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::json;
+use sha2::Sha256;
 
+use super::error::parse_error;
 use super::uri::percent_encode_path;
 use crate::raw::*;
 use crate::*;
@@ -51,6 +64,11 @@ pub mod constants {
     pub const X_GOOG_ACL: &str = "x-goog-acl";
     pub const X_GOOG_STORAGE_CLASS: &str = "x-goog-storage-class";
     pub const X_GOOG_META_PREFIX: &str = "x-goog-meta-";
+
+    /// The max number of source objects in one compose request.
+    pub const GCS_COMPOSE_MAX_SOURCES: usize = 32;
+    /// The max number of requests in one batch request.
+    pub const GCS_BATCH_MAX_REQUESTS: usize = 100;
 }
 
 pub struct GcsCore {
@@ -64,6 +82,7 @@ pub struct GcsCore {
     pub token: Option<String>,
     pub scope: String,
     pub credential_loader: GoogleCredentialLoader,
+    pub service_account: Option<GcsServiceAccount>,
 
     pub predefined_acl: Option<String>,
     pub default_storage_class: Option<String>,
@@ -81,6 +100,22 @@ impl Debug for GcsCore {
     }
 }
 
+/// The key of a service account, which is used to sign POST policies.
+#[derive(Clone, Deserialize)]
+pub struct GcsServiceAccount {
+    pub client_email: String,
+    pub private_key: String,
+}
+
+impl GcsServiceAccount {
+    /// Parse the service account from the content of a credentials file.
+    ///
+    /// Returns `None` if the credential is not a service account key.
+    pub fn from_slice(content: &[u8]) -> Option<Self> {
+        serde_json::from_slice(content).ok()
+    }
+}
+
 static BACKOFF: Lazy<ExponentialBuilder> =
     Lazy::new(|| ExponentialBuilder::default().with_jitter());
 
@@ -169,6 +204,91 @@ impl GcsCore {
         Ok(())
     }
 
+    /// Build the form fields of a POST policy signed by the service account.
+    ///
+    /// Reference: <https://cloud.google.com/storage/docs/authentication/signatures#policy-document>
+    pub fn gcs_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+        expire: Duration,
+    ) -> Result<Vec<(String, String)>> {
+        let Some(sa) = &self.service_account else {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "presign post policy requires a service account credential",
+            ));
+        };
+
+        let now = Utc::now();
+        let date = now.format("%Y%m%d").to_string();
+        let expire = chrono::Duration::from_std(expire).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "expire of presign is out of range").set_source(err)
+        })?;
+        let expiration = (now + expire).format("%Y-%m-%dT%H:%M:%SZ").to_string();
+
+        let p = build_abs_path(&self.root, path);
+
+        let mut fields = Vec::new();
+        let mut conditions = vec![json!({ "bucket": self.bucket })];
+        if args.key_prefix() {
+            conditions.push(json!(["starts-with", "$key", p]));
+            fields.push(("key".to_string(), format!("{p}${{filename}}")));
+        } else {
+            conditions.push(json!({ "key": p }));
+            fields.push(("key".to_string(), p));
+        }
+        if let Some(v) = args.content_type() {
+            conditions.push(json!({ "Content-Type": v }));
+            fields.push(("Content-Type".to_string(), v.to_string()));
+        }
+        if let Some(v) = args.content_type_prefix() {
+            conditions.push(json!(["starts-with", "$Content-Type", v]));
+        }
+        if let Some((min, max)) = args.content_length_range() {
+            conditions.push(json!(["content-length-range", min, max]));
+        }
+
+        let goog_fields = [
+            ("x-goog-algorithm", "GOOG4-RSA-SHA256".to_string()),
+            (
+                "x-goog-credential",
+                format!("{}/{date}/auto/storage/goog4_request", sa.client_email),
+            ),
+            ("x-goog-date", now.format("%Y%m%dT%H%M%SZ").to_string()),
+        ];
+        for (k, v) in goog_fields {
+            conditions.push(json!({ k: v }));
+            fields.push((k.to_string(), v));
+        }
+
+        let policy = json!({
+            "expiration": expiration,
+            "conditions": conditions,
+        });
+        let policy = BASE64_STANDARD.encode(policy.to_string());
+
+        let private_key = RsaPrivateKey::from_pkcs8_pem(&sa.private_key).map_err(|err| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "private key of service account is invalid",
+            )
+            .set_source(err)
+        })?;
+        let signature = SigningKey::<Sha256>::new(private_key)
+            .sign(policy.as_bytes())
+            .to_bytes()
+            .iter()
+            .fold(String::new(), |mut s, b| {
+                let _ = write!(s, "{b:02x}");
+                s
+            });
+
+        fields.push(("policy".to_string(), policy));
+        fields.push(("x-goog-signature".to_string(), signature));
+        Ok(fields)
+    }
+
     #[inline]
     pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
         self.client.send(req).await
@@ -486,6 +606,125 @@ impl GcsCore {
         self.send(req).await
     }
 
+    /// Compose up to 32 source objects into the object at `path`.
+    ///
+    /// ref: <https://cloud.google.com/storage/docs/json_api/v1/objects/compose>
+    pub async fn gcs_compose_object(
+        &self,
+        path: &str,
+        sources: &[String],
+        op: &OpWrite,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/storage/v1/b/{}/o/{}/compose",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+
+        let mut query = Vec::new();
+        if let Some(acl) = &self.predefined_acl {
+            query.push(format!("destinationPredefinedAcl={acl}"));
+        }
+        if op.if_not_exists() {
+            query.push("ifGenerationMatch=0".to_string());
+        }
+        if !query.is_empty() {
+            write!(url, "?{}", query.join("&")).expect("write into string must succeed");
+        }
+
+        let request = ComposeRequest {
+            source_objects: sources
+                .iter()
+                .map(|v| ComposeSourceObject {
+                    name: build_abs_path(&self.root, v),
+                })
+                .collect(),
+            destination: InsertRequestMetadata {
+                storage_class: self.default_storage_class.as_deref(),
+                cache_control: op.cache_control(),
+                content_type: op.content_type(),
+                metadata: op.user_metadata(),
+            },
+        };
+        let content = serde_json::to_vec(&request).map_err(new_json_serialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/json; charset=UTF-8")
+            .body(Buffer::from(content))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Compose any number of source objects into the object at `path`.
+    ///
+    /// Sources more than [`GCS_COMPOSE_MAX_SOURCES`] will be composed into
+    /// temporary objects first, which will be removed once finished.
+    pub async fn gcs_compose(&self, path: &str, sources: Vec<String>, op: &OpWrite) -> Result<()> {
+        let mut tmp_objects = Vec::new();
+        let res = self
+            .gcs_compose_inner(path, sources, op, &mut tmp_objects)
+            .await;
+        self.gcs_remove_tmp_objects(tmp_objects).await;
+        res
+    }
+
+    async fn gcs_compose_inner(
+        &self,
+        path: &str,
+        mut sources: Vec<String>,
+        op: &OpWrite,
+        tmp_objects: &mut Vec<String>,
+    ) -> Result<()> {
+        while sources.len() > GCS_COMPOSE_MAX_SOURCES {
+            let mut composed = Vec::with_capacity(sources.len().div_ceil(GCS_COMPOSE_MAX_SOURCES));
+            for chunk in sources.chunks(GCS_COMPOSE_MAX_SOURCES) {
+                if chunk.len() == 1 {
+                    composed.push(chunk[0].clone());
+                    continue;
+                }
+
+                let tmp = build_compose_tmp_path(path, &uuid::Uuid::new_v4().to_string());
+                tmp_objects.push(tmp.clone());
+                let resp = self
+                    .gcs_compose_object(&tmp, chunk, &OpWrite::default())
+                    .await?;
+                if !resp.status().is_success() {
+                    return Err(parse_error(resp));
+                }
+                composed.push(tmp);
+            }
+            sources = composed;
+        }
+
+        let resp = self.gcs_compose_object(path, &sources, op).await?;
+        if !resp.status().is_success() {
+            return Err(parse_error(resp));
+        }
+        Ok(())
+    }
+
+    /// Remove the temporary objects created by compose.
+    ///
+    /// Failures are logged and ignored since they don't affect the result.
+    pub async fn gcs_remove_tmp_objects(&self, paths: Vec<String>) {
+        for chunk in paths.chunks(GCS_BATCH_MAX_REQUESTS) {
+            match self.gcs_delete_objects(chunk.to_vec()).await {
+                Ok(resp) if resp.status() == StatusCode::OK => {}
+                Ok(resp) => warn!(
+                    "remove gcs temporary objects failed: {:?}",
+                    parse_error(resp)
+                ),
+                Err(err) => warn!("remove gcs temporary objects failed: {err:?}"),
+            }
+        }
+    }
+
     pub async fn gcs_list_objects(
         &self,
         path: &str,
@@ -493,6 +732,7 @@ impl GcsCore {
         delimiter: &str,
         limit: Option<usize>,
         start_after: Option<String>,
+        glob: Option<&str>,
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
@@ -513,6 +753,11 @@ impl GcsCore {
             write!(url, "&startOffset={}", percent_encode_path(&start_after))
                 .expect("write into string must succeed");
         }
+        if let Some(glob) = glob {
+            let glob = build_abs_path(&self.root, glob);
+            write!(url, "&matchGlob={}", percent_encode_path(&glob))
+                .expect("write into string must succeed");
+        }
 
         if !page_token.is_empty() {
             // NOTE:
@@ -534,100 +779,112 @@ impl GcsCore {
         self.send(req).await
     }
 
-    pub async fn gcs_initiate_multipart_upload(&self, path: &str) -> Result<Response<Buffer>> {
-        let p = build_abs_path(&self.root, path);
-
-        let url = format!("{}/{}/{}?uploads", self.endpoint, self.bucket, p);
-
-        let mut req = Request::post(&url)
-            .header(CONTENT_LENGTH, 0)
-            .body(Buffer::new())
-            .map_err(new_request_build_error)?;
-
-        self.sign(&mut req).await?;
-        self.send(req).await
-    }
-
-    pub async fn gcs_upload_part(
+    /// Initiate a resumable upload session and returns the response
+    /// which carries the session URI in `Location` header.
+    ///
+    /// ref: <https://cloud.google.com/storage/docs/performing-resumable-uploads>
+    pub async fn gcs_initiate_resumable_upload(
         &self,
         path: &str,
-        upload_id: &str,
-        part_number: usize,
-        size: u64,
-        body: Buffer,
+        op: &OpWrite,
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
-            "{}/{}/{}?partNumber={}&uploadId={}",
+        let mut url = format!(
+            "{}/upload/storage/v1/b/{}/o?uploadType=resumable&name={}",
             self.endpoint,
             self.bucket,
-            percent_encode_path(&p),
-            part_number,
-            percent_encode_path(upload_id)
+            percent_encode_path(&p)
         );
 
-        let mut req = Request::put(&url);
+        if let Some(acl) = &self.predefined_acl {
+            write!(&mut url, "&predefinedAcl={}", acl).unwrap();
+        }
 
-        req = req.header(CONTENT_LENGTH, size);
+        if op.if_not_exists() {
+            write!(&mut url, "&ifGenerationMatch=0").unwrap();
+        }
 
-        let mut req = req.body(body).map_err(new_request_build_error)?;
+        let request_metadata = InsertRequestMetadata {
+            storage_class: self.default_storage_class.as_deref(),
+            cache_control: op.cache_control(),
+            content_type: op.content_type(),
+            metadata: op.user_metadata(),
+        };
+        let content = serde_json::to_vec(&request_metadata).map_err(new_json_serialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/json; charset=UTF-8")
+            .body(Buffer::from(content))
+            .map_err(new_request_build_error)?;
 
         self.sign(&mut req).await?;
         self.send(req).await
     }
 
-    pub async fn gcs_complete_multipart_upload(
+    /// Upload a chunk to the resumable upload session.
+    ///
+    /// The size of chunk must be a multiple of 256 KiB, and GCS will return
+    /// `308 Resume Incomplete` if succeed.
+    pub async fn gcs_upload_in_resumable_upload(
         &self,
-        path: &str,
-        upload_id: &str,
-        parts: Vec<CompleteMultipartUploadRequestPart>,
+        location: &str,
+        offset: u64,
+        size: u64,
+        body: Buffer,
     ) -> Result<Response<Buffer>> {
-        let p = build_abs_path(&self.root, path);
-
-        let url = format!(
-            "{}/{}/{}?uploadId={}",
-            self.endpoint,
-            self.bucket,
-            percent_encode_path(&p),
-            percent_encode_path(upload_id)
-        );
-
-        let req = Request::post(&url);
-
-        let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest { part: parts })
-            .map_err(new_xml_deserialize_error)?;
-        // Make sure content length has been set to avoid post with chunked encoding.
-        let req = req.header(CONTENT_LENGTH, content.len());
-        // Set content-type to `application/xml` to avoid mixed with form post.
-        let req = req.header(CONTENT_TYPE, "application/xml");
-
-        let mut req = req
-            .body(Buffer::from(Bytes::from(content)))
+        let mut req = Request::put(location)
+            .header(CONTENT_LENGTH, size)
+            .header(
+                CONTENT_RANGE,
+                BytesContentRange::default()
+                    .with_range(offset, offset + size - 1)
+                    .to_header(),
+            )
+            .body(body)
             .map_err(new_request_build_error)?;
 
         self.sign(&mut req).await?;
         self.send(req).await
     }
 
-    pub async fn gcs_abort_multipart_upload(
+    /// Upload the last chunk to the resumable upload session with the total
+    /// size so that GCS could finalize the object.
+    pub async fn gcs_complete_resumable_upload(
         &self,
-        path: &str,
-        upload_id: &str,
+        location: &str,
+        offset: u64,
+        size: u64,
+        body: Buffer,
     ) -> Result<Response<Buffer>> {
-        let p = build_abs_path(&self.root, path);
+        let range = if size == 0 {
+            BytesContentRange::default().with_size(offset)
+        } else {
+            BytesContentRange::default()
+                .with_range(offset, offset + size - 1)
+                .with_size(offset + size)
+        };
 
-        let url = format!(
-            "{}/{}/{}?uploadId={}",
-            self.endpoint,
-            self.bucket,
-            percent_encode_path(&p),
-            percent_encode_path(upload_id)
-        );
+        let mut req = Request::put(location)
+            .header(CONTENT_LENGTH, size)
+            .header(CONTENT_RANGE, range.to_header())
+            .body(body)
+            .map_err(new_request_build_error)?;
 
-        let mut req = Request::delete(&url)
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Cancel the resumable upload session.
+    ///
+    /// GCS will return `499 Client Closed Request` if succeed.
+    pub async fn gcs_abort_resumable_upload(&self, location: &str) -> Result<Response<Buffer>> {
+        let mut req = Request::delete(location)
+            .header(CONTENT_LENGTH, 0)
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
+
         self.sign(&mut req).await?;
         self.send(req).await
     }
@@ -654,6 +911,31 @@ impl InsertRequestMetadata<'_> {
             && self.metadata.is_none()
     }
 }
+
+/// Build the path of temporary object used by compose in the same dir of `path`.
+///
+/// The temporary object is hidden to avoid being picked up by other tools.
+pub fn build_compose_tmp_path(path: &str, id: &str) -> String {
+    let name = get_basename(path);
+    match get_parent(path) {
+        "/" => format!(".{name}.{id}"),
+        parent => format!("{parent}.{name}.{id}"),
+    }
+}
+
+/// Request JSON of GCS compose objects API.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposeRequest<'a> {
+    source_objects: Vec<ComposeSourceObject>,
+    destination: InsertRequestMetadata<'a>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComposeSourceObject {
+    name: String,
+}
+
 /// Response JSON from GCS list objects API.
 ///
 /// refer to https://cloud.google.com/storage/docs/json_api/v1/objects/list for details
@@ -679,37 +961,56 @@ pub struct ListResponseItem {
     // metadata
     pub etag: String,
     pub md5_hash: String,
+    pub crc32c: String,
     pub updated: String,
     pub content_type: String,
-}
-
-/// Result of CreateMultipartUpload
-#[derive(Default, Debug, Deserialize)]
-#[serde(default, rename_all = "PascalCase")]
-pub struct InitiateMultipartUploadResult {
-    pub upload_id: String,
-}
-
-/// Request of CompleteMultipartUploadRequest
-#[derive(Default, Debug, Serialize)]
-#[serde(default, rename = "CompleteMultipartUpload", rename_all = "PascalCase")]
-pub struct CompleteMultipartUploadRequest {
-    pub part: Vec<CompleteMultipartUploadRequestPart>,
-}
-
-#[derive(Clone, Default, Debug, Serialize)]
-#[serde(default, rename_all = "PascalCase")]
-pub struct CompleteMultipartUploadRequestPart {
-    #[serde(rename = "PartNumber")]
-    pub part_number: usize,
-    #[serde(rename = "ETag")]
-    pub etag: String,
+    pub metadata: HashMap<String, String>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_build_compose_tmp_path() {
+        let cases = vec![
+            ("file", "id", ".file.id"),
+            ("dir/file", "id", "dir/.file.id"),
+            ("dir/sub/file.txt", "id", "dir/sub/.file.txt.id"),
+        ];
+
+        for (path, id, expected) in cases {
+            assert_eq!(build_compose_tmp_path(path, id), expected, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_serialize_compose_request() {
+        let metadata = HashMap::from([("location".to_string(), "everywhere".to_string())]);
+        let req = ComposeRequest {
+            source_objects: vec![
+                ComposeSourceObject {
+                    name: "part-0".to_string(),
+                },
+                ComposeSourceObject {
+                    name: "part-1".to_string(),
+                },
+            ],
+            destination: InsertRequestMetadata {
+                content_type: Some("text/plain"),
+                storage_class: None,
+                cache_control: None,
+                metadata: Some(&metadata),
+            },
+        };
+
+        let content = serde_json::to_string(&req).expect("must success");
+        assert_eq!(
+            content,
+            r#"{"sourceObjects":[{"name":"part-0"},{"name":"part-1"}],"destination":{"contentType":"text/plain","metadata":{"location":"everywhere"}}}"#
+        );
+    }
+
     #[test]
     fn test_deserialize_list_response() {
         let content = r#"
@@ -769,6 +1070,7 @@ mod tests {
         assert_eq!(output.items[0].name, "1.png");
         assert_eq!(output.items[0].size, "56535");
         assert_eq!(output.items[0].md5_hash, "fHcEH1vPwA6eTPqxuasXcg==");
+        assert_eq!(output.items[0].crc32c, "j/un9g==");
         assert_eq!(output.items[0].etag, "CKWasoTgyPkCEAE=");
         assert_eq!(output.items[0].updated, "2022-08-15T11:33:34.866Z");
         assert_eq!(output.items[1].name, "2.png");
@@ -59,20 +59,24 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
             (ErrorKind::ConditionNotMatch, false)
         }
         StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
-        StatusCode::INTERNAL_SERVER_ERROR
-        | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
-    let message = match de::from_slice::<GcsErrorResponse>(&bs) {
-        Ok(gcs_err) => format!("{gcs_err:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let (message, code) = match de::from_slice::<GcsErrorResponse>(&bs) {
+        Ok(gcs_err) => {
+            // GCS carries the native error code in the `reason` of details.
+            let code = gcs_err.error.errors.first().map(|v| v.reason.clone());
+            (format!("{gcs_err:?}"), code)
+        }
+        Err(_) => (String::from_utf8_lossy(&bs).into_owned(), None),
     };
 
     let mut err = Error::new(kind, message);
 
+    if let Some(code) = code.filter(|v| !v.is_empty()) {
+        err = err.with_service_code(code);
+    }
+
     err = with_error_response_context(err, parts);
 
     if retryable {
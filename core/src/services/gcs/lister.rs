@@ -31,12 +31,14 @@ pub struct GcsLister {
     core: Arc<GcsCore>,
 
     path: String,
-    delimiter: &'static str,
+    delimiter: String,
     limit: Option<usize>,
 
     /// Filter results to objects whose names are lexicographically
     /// **equal to or after** startOffset
     start_after: Option<String>,
+    /// Filter results to objects whose names match the glob pattern.
+    glob: Option<String>,
 }
 
 impl GcsLister {
@@ -44,18 +46,19 @@ impl GcsLister {
     pub fn new(
         core: Arc<GcsCore>,
         path: &str,
-        recursive: bool,
+        delimiter: &str,
         limit: Option<usize>,
         start_after: Option<&str>,
+        glob: Option<&str>,
     ) -> Self {
-        let delimiter = if recursive { "" } else { "/" };
         Self {
             core,
 
             path: path.to_string(),
-            delimiter,
+            delimiter: delimiter.to_string(),
             limit,
             start_after: start_after.map(String::from),
+            glob: glob.map(String::from),
         }
     }
 }
@@ -67,13 +70,14 @@ impl oio::PageList for GcsLister {
             .gcs_list_objects(
                 &self.path,
                 &ctx.token,
-                self.delimiter,
+                &self.delimiter,
                 self.limit,
                 if ctx.token.is_empty() {
                     self.start_after.clone()
                 } else {
                     None
                 },
+                self.glob.as_deref(),
             )
             .await?;
 
@@ -92,10 +96,16 @@ impl oio::PageList for GcsLister {
         }
 
         for prefix in output.prefixes {
-            let de = oio::Entry::new(
-                &build_rel_path(&self.core.root, &prefix),
-                Metadata::new(EntryMode::DIR),
-            );
+            let path = build_rel_path(&self.core.root, &prefix);
+            // matchGlob is applied to object names, filter prefixes by ourselves
+            // to make sure dirs follow the same rule.
+            if let Some(glob) = &self.glob {
+                if !glob_match(glob, path.trim_end_matches('/')) {
+                    continue;
+                }
+            }
+
+            let de = oio::Entry::new(&path, Metadata::new(EntryMode::from_prefix(&path)));
 
             ctx.entries.push_back(de);
         }
@@ -113,7 +123,15 @@ impl oio::PageList for GcsLister {
             let mut meta = Metadata::new(EntryMode::from_path(&path));
 
             // set metadata fields
-            meta.set_content_md5(object.md5_hash.as_str());
+            if !object.md5_hash.is_empty() {
+                meta.set_content_md5(object.md5_hash.as_str());
+            }
+            if !object.crc32c.is_empty() {
+                meta.set_checksum_crc32c(object.crc32c.as_str());
+            }
+            if !object.metadata.is_empty() {
+                meta.with_user_metadata(object.metadata);
+            }
             meta.set_etag(object.etag.as_str());
 
             let size = object.size.parse().map_err(|e| {
@@ -18,16 +18,15 @@
 use std::sync::Arc;
 
 use bytes::Buf;
+use http::header::RANGE;
 use http::StatusCode;
 
-use super::core::CompleteMultipartUploadRequestPart;
-use super::core::GcsCore;
-use super::core::InitiateMultipartUploadResult;
+use super::core::*;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
-pub type GcsWriters = oio::MultipartWriter<GcsWriter>;
+pub type GcsWriters = TwoWays<oio::RangeWriter<GcsWriter>, oio::MultipartWriter<GcsComposeWriter>>;
 
 pub struct GcsWriter {
     core: Arc<GcsCore>,
@@ -45,9 +44,8 @@ impl GcsWriter {
     }
 }
 
-impl oio::MultipartWrite for GcsWriter {
-    async fn write_once(&self, _: u64, body: Buffer) -> Result<()> {
-        let size = body.len() as u64;
+impl oio::RangeWrite for GcsWriter {
+    async fn write_once(&self, size: u64, body: Buffer) -> Result<()> {
         let mut req = self.core.gcs_insert_object_request(
             &percent_encode_path(&self.path),
             Some(size),
@@ -67,86 +65,191 @@ impl oio::MultipartWrite for GcsWriter {
         }
     }
 
-    async fn initiate_part(&self) -> Result<String> {
+    async fn initiate_range(&self) -> Result<String> {
         let resp = self
             .core
-            .gcs_initiate_multipart_upload(&percent_encode_path(&self.path))
+            .gcs_initiate_resumable_upload(&self.path, &self.op)
             .await?;
 
         if !resp.status().is_success() {
             return Err(parse_error(resp));
         }
 
-        let buf = resp.into_body();
-        let upload_id: InitiateMultipartUploadResult =
-            quick_xml::de::from_reader(buf.reader()).map_err(new_xml_deserialize_error)?;
-        Ok(upload_id.upload_id)
+        let location = parse_location(resp.headers())?.ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "location not present in returning response",
+            )
+        })?;
+        Ok(location.to_string())
     }
 
-    async fn write_part(
+    async fn write_range(
         &self,
-        upload_id: &str,
-        part_number: usize,
+        location: &str,
+        offset: u64,
         size: u64,
         body: Buffer,
-    ) -> Result<oio::MultipartPart> {
-        // Gcs requires part number must between [1..=10000]
-        let part_number = part_number + 1;
+    ) -> Result<u64> {
+        let resp = self
+            .core
+            .gcs_upload_in_resumable_upload(location, offset, size, body)
+            .await?;
 
+        match resp.status().as_u16() {
+            // gcs returns 308 Resume Incomplete if the chunk has been received,
+            // the `Range` header carries the bytes that have been persisted.
+            //
+            // ref: <https://cloud.google.com/storage/docs/performing-resumable-uploads#chunked-upload>
+            308 => {
+                let Some(range) = parse_header_to_str(resp.headers(), RANGE)? else {
+                    return Ok(0);
+                };
+                let range = range.parse::<BytesRange>()?;
+                let end = range.offset() + range.size().unwrap_or_default();
+                Ok(end.saturating_sub(offset).min(size))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn complete_range(
+        &self,
+        location: &str,
+        offset: u64,
+        size: u64,
+        body: Buffer,
+    ) -> Result<()> {
         let resp = self
             .core
-            .gcs_upload_part(&self.path, upload_id, part_number, size, body)
+            .gcs_complete_resumable_upload(location, offset, size, body)
             .await?;
 
-        if !resp.status().is_success() {
-            return Err(parse_error(resp));
+        match resp.status() {
+            StatusCode::OK | StatusCode::CREATED => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn abort_range(&self, location: &str) -> Result<()> {
+        let resp = self.core.gcs_abort_resumable_upload(location).await?;
+
+        match resp.status().as_u16() {
+            // gcs returns 499 Client Closed Request if the session has been cancelled.
+            499 => Ok(()),
+            _ => Err(parse_error(resp)),
         }
+    }
+}
+
+/// GcsComposeWriter uploads parts as temporary objects concurrently, and
+/// composes them into the final object while completing.
+///
+/// ref: <https://cloud.google.com/storage/docs/composing-objects>
+pub struct GcsComposeWriter {
+    w: GcsWriter,
+}
+
+impl GcsComposeWriter {
+    pub fn new(core: Arc<GcsCore>, path: &str, op: OpWrite) -> Self {
+        GcsComposeWriter {
+            w: GcsWriter::new(core, path, op),
+        }
+    }
 
-        let etag = parse_etag(resp.headers())?
-            .ok_or_else(|| {
-                Error::new(
-                    ErrorKind::Unexpected,
-                    "ETag not present in returning response",
-                )
-            })?
-            .to_string();
-
-        Ok(oio::MultipartPart {
-            part_number,
-            etag,
-            checksum: None,
-        })
+    /// The prefix of all parts of given upload.
+    fn part_prefix(&self, upload_id: &str) -> String {
+        format!("{}.part-", build_compose_tmp_path(&self.w.path, upload_id))
+    }
+}
+
+impl oio::MultipartWrite for GcsComposeWriter {
+    async fn write_once(&self, size: u64, body: Buffer) -> Result<()> {
+        oio::RangeWrite::write_once(&self.w, size, body).await
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        // Parts are plain objects, there is nothing to initiate on gcs side.
+        Ok(uuid::Uuid::new_v4().to_string())
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: Buffer,
+    ) -> Result<oio::MultipartPart> {
+        let path = format!("{}{part_number}", self.part_prefix(upload_id));
+        let mut req =
+            self.w
+                .core
+                .gcs_insert_object_request(&path, Some(size), &OpWrite::default(), body)?;
+
+        self.w.core.sign(&mut req).await?;
+
+        let resp = self.w.core.send(req).await?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => Ok(oio::MultipartPart {
+                part_number,
+                etag: parse_etag(resp.headers())?.unwrap_or_default().to_string(),
+                checksum: None,
+            }),
+            _ => Err(parse_error(resp)),
+        }
     }
 
     async fn complete_part(&self, upload_id: &str, parts: &[oio::MultipartPart]) -> Result<()> {
-        let parts = parts
+        let prefix = self.part_prefix(upload_id);
+        let sources: Vec<String> = parts
             .iter()
-            .map(|p| CompleteMultipartUploadRequestPart {
-                part_number: p.part_number,
-                etag: p.etag.clone(),
-            })
+            .map(|p| format!("{prefix}{}", p.part_number))
             .collect();
 
-        let resp = self
+        self.w
             .core
-            .gcs_complete_multipart_upload(&self.path, upload_id, parts)
+            .gcs_compose(&self.w.path, sources.clone(), &self.w.op)
             .await?;
 
-        if !resp.status().is_success() {
-            return Err(parse_error(resp));
-        }
+        // Parts are useless once composed.
+        self.w.core.gcs_remove_tmp_objects(sources).await;
         Ok(())
     }
 
     async fn abort_part(&self, upload_id: &str) -> Result<()> {
-        let resp = self
-            .core
-            .gcs_abort_multipart_upload(&self.path, upload_id)
-            .await?;
-        match resp.status() {
-            // gcs returns code 204 if abort succeeds.
-            StatusCode::NO_CONTENT => Ok(()),
-            _ => Err(parse_error(resp)),
+        let prefix = self.part_prefix(upload_id);
+
+        // Parts could be uploaded concurrently, list them to make sure all
+        // of them are removed.
+        let mut paths = Vec::new();
+        let mut page_token = String::new();
+        loop {
+            let resp = self
+                .w
+                .core
+                .gcs_list_objects(&prefix, &page_token, "", None, None, None)
+                .await?;
+            if !resp.status().is_success() {
+                return Err(parse_error(resp));
+            }
+
+            let output: ListResponse = serde_json::from_reader(resp.into_body().reader())
+                .map_err(new_json_deserialize_error)?;
+            paths.extend(
+                output
+                    .items
+                    .into_iter()
+                    .map(|v| build_rel_path(&self.w.core.root, &v.name)),
+            );
+
+            match output.next_page_token {
+                Some(token) if !token.is_empty() => page_token = token,
+                _ => break,
+            }
         }
+
+        self.w.core.gcs_remove_tmp_objects(paths).await;
+        Ok(())
     }
 }
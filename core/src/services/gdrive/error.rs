@@ -40,13 +40,9 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
     let (mut kind, mut retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
-        StatusCode::INTERNAL_SERVER_ERROR
-        | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT
         // Gdrive sometimes return METHOD_NOT_ALLOWED for our requests for abuse detection.
-        | StatusCode::METHOD_NOT_ALLOWED => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        StatusCode::METHOD_NOT_ALLOWED => (ErrorKind::Unexpected, true),
+        _ => parse_http_status(parts.status),
     };
 
     let (message, gdrive_err) = serde_json::from_slice::<GdriveError>(bs.as_ref())
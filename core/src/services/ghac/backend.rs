@@ -152,6 +152,42 @@ impl GhacBuilder {
         self
     }
 
+    /// Set the scope that used as the namespace of cache keys.
+    ///
+    /// All keys will be prefixed with `<scope>/`, so that caches with different
+    /// scopes will not conflict with each other even if they share the same version.
+    pub fn scope(mut self, scope: &str) -> Self {
+        let scope = scope.trim_matches('/');
+        if !scope.is_empty() {
+            self.config.scope = Some(scope.to_string())
+        }
+        self
+    }
+
+    /// Set the github api url for ghac service.
+    ///
+    /// For GitHub Enterprise Server, this should be like `https://github.example.com/api/v3`.
+    ///
+    /// Default: the value of the `GITHUB_API_URL` environment variable, or `https://api.github.com`.
+    pub fn api_url(mut self, api_url: &str) -> Self {
+        if !api_url.is_empty() {
+            self.config.api_url = Some(api_url.trim_end_matches('/').to_string())
+        }
+        self
+    }
+
+    /// Set the github token for ghac service.
+    ///
+    /// This token is used to delete caches via github api.
+    ///
+    /// Default: the value of the `GITHUB_TOKEN` environment variable.
+    pub fn github_token(mut self, github_token: &str) -> Self {
+        if !github_token.is_empty() {
+            self.config.github_token = Some(github_token.to_string())
+        }
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -168,6 +204,10 @@ impl Builder for GhacBuilder {
     const SCHEME: Scheme = Scheme::Ghac;
     type Config = GhacConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", self);
 
@@ -183,10 +223,17 @@ impl Builder for GhacBuilder {
             })?
         };
 
+        let mut cache_url =
+            value_or_env(self.config.endpoint, ACTIONS_CACHE_URL, "Builder::build")?;
+        // Custom endpoints like GHES's could be provided without the trailing `/`.
+        if !cache_url.ends_with('/') {
+            cache_url.push('/');
+        }
+
         let backend = GhacBackend {
             root,
 
-            cache_url: value_or_env(self.config.endpoint, ACTIONS_CACHE_URL, "Builder::build")?,
+            cache_url,
             catch_token: value_or_env(
                 self.config.runtime_token,
                 ACTIONS_RUNTIME_TOKEN,
@@ -197,10 +244,18 @@ impl Builder for GhacBuilder {
                 .version
                 .clone()
                 .unwrap_or_else(|| "opendal".to_string()),
+            scope: self.config.scope,
 
-            api_url: env::var(GITHUB_API_URL)
-                .unwrap_or_else(|_| "https://api.github.com".to_string()),
-            api_token: env::var(GITHUB_TOKEN).unwrap_or_default(),
+            api_url: self
+                .config
+                .api_url
+                .or_else(|| env::var(GITHUB_API_URL).ok())
+                .unwrap_or_else(|| "https://api.github.com".to_string()),
+            api_token: self
+                .config
+                .github_token
+                .or_else(|| env::var(GITHUB_TOKEN).ok())
+                .unwrap_or_default(),
             repo: env::var(GITHUB_REPOSITORY).unwrap_or_default(),
 
             client,
@@ -219,6 +274,7 @@ pub struct GhacBackend {
     cache_url: String,
     catch_token: String,
     version: String,
+    scope: Option<String>,
 
     api_url: String,
     pub api_token: String,
@@ -366,14 +422,24 @@ impl Access for GhacBackend {
 }
 
 impl GhacBackend {
-    fn ghac_query(&self, path: &str) -> Result<Request<Buffer>> {
+    /// Build the cache key of given path, which will be prefixed by scope if set.
+    fn cache_key(&self, path: &str) -> String {
         let p = build_abs_path(&self.root, path);
 
+        match &self.scope {
+            Some(scope) => format!("{scope}/{p}"),
+            None => p,
+        }
+    }
+
+    fn ghac_query(&self, path: &str) -> Result<Request<Buffer>> {
+        let p = self.cache_key(path);
+
         let url = format!(
             "{}{CACHE_URL_BASE}/cache?keys={}&version={}",
             self.cache_url,
             percent_encode_path(&p),
-            self.version
+            percent_encode_path(&self.version)
         );
 
         let mut req = Request::get(&url);
@@ -396,7 +462,7 @@ impl GhacBackend {
     }
 
     fn ghac_reserve(&self, path: &str) -> Result<Request<Buffer>> {
-        let p = build_abs_path(&self.root, path);
+        let p = self.cache_key(path);
 
         let url = format!("{}{CACHE_URL_BASE}/caches", self.cache_url);
 
@@ -465,7 +531,7 @@ impl GhacBackend {
     }
 
     pub async fn ghac_delete(&self, path: &str) -> Result<Response<Buffer>> {
-        let p = build_abs_path(&self.root, path);
+        let p = self.cache_key(path);
 
         let url = format!(
             "{}/repos/{}/actions/caches?key={}",
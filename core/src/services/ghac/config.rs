@@ -33,4 +33,10 @@ pub struct GhacConfig {
     pub endpoint: Option<String>,
     /// The runtime token for ghac service.
     pub runtime_token: Option<String>,
+    /// The scope that used as the namespace of cache keys.
+    pub scope: Option<String>,
+    /// The github api url for ghac service, used for GitHub Enterprise Server.
+    pub api_url: Option<String>,
+    /// The github token for ghac service, used to delete caches.
+    pub github_token: Option<String>,
 }
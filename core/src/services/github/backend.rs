@@ -116,6 +116,10 @@ impl Builder for GithubBuilder {
     const SCHEME: Scheme = Scheme::Github;
     type Config = GithubConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Builds the backend and returns the result of GithubBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
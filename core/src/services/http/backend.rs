@@ -129,6 +129,10 @@ impl Builder for HttpBuilder {
     const SCHEME: Scheme = Scheme::Http;
     type Config = HttpConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
@@ -265,7 +269,8 @@ impl Access for HttpBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let etag = parse_etag(resp.headers())?.map(String::from);
+                Ok((RpRead::default().with_etag(etag), resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -276,6 +281,13 @@ impl Access for HttpBackend {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !args.headers().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "Http doesn't support presigned request with extra headers",
+            ));
+        }
+
         if self.has_authorization() {
             return Err(Error::new(
                 ErrorKind::Unsupported,
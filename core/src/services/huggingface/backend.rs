@@ -35,7 +35,10 @@ use crate::*;
 impl Configurator for HuggingfaceConfig {
     type Builder = HuggingfaceBuilder;
     fn into_builder(self) -> Self::Builder {
-        HuggingfaceBuilder { config: self }
+        HuggingfaceBuilder {
+            config: self,
+            http_client: None,
+        }
     }
 }
 
@@ -44,6 +47,7 @@ impl Configurator for HuggingfaceConfig {
 #[derive(Default, Clone)]
 pub struct HuggingfaceBuilder {
     config: HuggingfaceConfig,
+    http_client: Option<HttpClient>,
 }
 
 impl Debug for HuggingfaceBuilder {
@@ -123,12 +127,27 @@ impl HuggingfaceBuilder {
         }
         self
     }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
 }
 
 impl Builder for HuggingfaceBuilder {
     const SCHEME: Scheme = Scheme::Huggingface;
     type Config = HuggingfaceConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Build a HuggingfaceBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
@@ -169,7 +188,14 @@ impl Builder for HuggingfaceBuilder {
 
         let token = self.config.token.as_ref().cloned();
 
-        let client = HttpClient::new()?;
+        let client = if let Some(client) = self.http_client {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Huggingface)
+            })?
+        };
 
         Ok(HuggingfaceBackend {
             core: Arc::new(HuggingfaceCore {
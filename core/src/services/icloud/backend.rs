@@ -153,6 +153,10 @@ impl Builder for IcloudBuilder {
     const SCHEME: Scheme = Scheme::Icloud;
     type Config = IcloudConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         let root = normalize_root(&self.config.root.unwrap_or_default());
 
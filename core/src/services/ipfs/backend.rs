@@ -103,6 +103,10 @@ impl Builder for IpfsBuilder {
     const SCHEME: Scheme = Scheme::Ipfs;
     type Config = IpfsConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
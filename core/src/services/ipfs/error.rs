@@ -29,13 +29,9 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
     let (kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
-        StatusCode::INTERNAL_SERVER_ERROR
-        | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT
         // IPFS Gateway will return `408 REQUEST_TIMEOUT` while `ipfs resolve -r` failed.
-        | StatusCode::REQUEST_TIMEOUT => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        StatusCode::REQUEST_TIMEOUT => (ErrorKind::Unexpected, true),
+        _ => parse_http_status(parts.status),
     };
 
     let message = String::from_utf8_lossy(&bs);
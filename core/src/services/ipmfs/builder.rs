@@ -118,6 +118,10 @@ impl Builder for IpmfsBuilder {
     const SCHEME: Scheme = Scheme::Ipmfs;
     type Config = IpmfsConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         let root = normalize_root(&self.config.root.unwrap_or_default());
         debug!("backend use root {}", root);
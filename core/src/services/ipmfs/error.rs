@@ -60,10 +60,7 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
                 (ErrorKind::Unexpected, false)
             }
         }
-        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT => {
-            (ErrorKind::Unexpected, true)
-        }
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
     let message = match ipfs_error {
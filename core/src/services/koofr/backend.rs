@@ -134,6 +134,10 @@ impl Builder for KoofrBuilder {
     const SCHEME: Scheme = Scheme::Koofr;
     type Config = KoofrConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Builds the backend and returns the result of KoofrBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
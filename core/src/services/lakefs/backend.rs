@@ -39,7 +39,10 @@ use crate::*;
 impl Configurator for LakefsConfig {
     type Builder = LakefsBuilder;
     fn into_builder(self) -> Self::Builder {
-        LakefsBuilder { config: self }
+        LakefsBuilder {
+            config: self,
+            http_client: None,
+        }
     }
 }
 
@@ -48,6 +51,7 @@ impl Configurator for LakefsConfig {
 #[derive(Default, Clone)]
 pub struct LakefsBuilder {
     config: LakefsConfig,
+    http_client: Option<HttpClient>,
 }
 
 impl Debug for LakefsBuilder {
@@ -123,12 +127,27 @@ impl LakefsBuilder {
         }
         self
     }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
 }
 
 impl Builder for LakefsBuilder {
     const SCHEME: Scheme = Scheme::Lakefs;
     type Config = LakefsConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Build a LakefsBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
@@ -172,7 +191,14 @@ impl Builder for LakefsBuilder {
                 .with_context("service", Scheme::Lakefs)),
         }?;
 
-        let client = HttpClient::new()?;
+        let client = if let Some(client) = self.http_client {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Lakefs)
+            })?
+        };
 
         Ok(LakefsBackend {
             core: Arc::new(LakefsCore {
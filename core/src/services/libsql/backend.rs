@@ -45,7 +45,10 @@ use crate::*;
 impl Configurator for LibsqlConfig {
     type Builder = LibsqlBuilder;
     fn into_builder(self) -> Self::Builder {
-        LibsqlBuilder { config: self }
+        LibsqlBuilder {
+            config: self,
+            http_client: None,
+        }
     }
 }
 
@@ -53,6 +56,7 @@ impl Configurator for LibsqlConfig {
 #[derive(Default)]
 pub struct LibsqlBuilder {
     config: LibsqlConfig,
+    http_client: Option<HttpClient>,
 }
 
 impl Debug for LibsqlBuilder {
@@ -135,12 +139,27 @@ impl LibsqlBuilder {
         }
         self
     }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
 }
 
 impl Builder for LibsqlBuilder {
     const SCHEME: Scheme = Scheme::Libsql;
     type Config = LibsqlConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         let conn = self.get_connection_string()?;
 
@@ -167,10 +186,14 @@ impl Builder for LibsqlBuilder {
                 .as_str(),
         );
 
-        let client = HttpClient::new().map_err(|err| {
-            err.with_operation("Builder::build")
-                .with_context("service", Scheme::Libsql)
-        })?;
+        let client = if let Some(client) = self.http_client {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Libsql)
+            })?
+        };
 
         Ok(LibsqlBackend::new(Adapter {
             client,
@@ -18,6 +18,7 @@
 use std::time::Duration;
 
 use bb8::RunError;
+use chrono::Utc;
 use tokio::net::TcpStream;
 use tokio::sync::OnceCell;
 
@@ -206,6 +207,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                write_with_expire_after: true,
                 shared: true,
 
                 ..Default::default()
@@ -227,12 +229,24 @@ impl kv::Adapter for Adapter {
             &value.to_vec(),
             // Set expiration to 0 if ttl not set.
             self.default_ttl
-                .map(|v| v.as_secs() as u32)
+                .map(parse_expiration)
+                .transpose()?
                 .unwrap_or_default(),
         )
         .await
     }
 
+    async fn set_with_ttl(&self, key: &str, value: Buffer, ttl: Duration) -> Result<()> {
+        let mut conn = self.conn().await?;
+
+        conn.set(
+            &percent_encode_path(key),
+            &value.to_vec(),
+            parse_expiration(ttl)?,
+        )
+        .await
+    }
+
     async fn delete(&self, key: &str) -> Result<()> {
         let mut conn = self.conn().await?;
 
@@ -240,6 +254,28 @@ impl kv::Adapter for Adapter {
     }
 }
 
+/// The max expiration in seconds that memcached treats as relative, larger
+/// ones are treated as unix timestamp.
+const MAX_RELATIVE_EXPIRATION: u64 = 30 * 24 * 60 * 60;
+
+/// Convert ttl into the expiration of memcached.
+///
+/// - Sub-second ttl is rounded up, since `0` means never expire.
+/// - Ttl longer than 30 days is converted into unix timestamp.
+fn parse_expiration(ttl: Duration) -> Result<u32> {
+    let mut secs = ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0);
+    if secs > MAX_RELATIVE_EXPIRATION {
+        secs += Utc::now().timestamp() as u64;
+    }
+
+    u32::try_from(secs).map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "ttl is too large for memcached")
+            .with_context("service", Scheme::Memcached)
+            .with_context("ttl", format!("{ttl:?}"))
+            .set_source(err)
+    })
+}
+
 /// A `bb8::ManageConnection` for `memcache_async::ascii::Protocol`.
 #[derive(Clone, Debug)]
 struct MemcacheConnectionManager {
@@ -284,3 +320,25 @@ impl bb8::ManageConnection for MemcacheConnectionManager {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expiration() {
+        assert_eq!(parse_expiration(Duration::ZERO).unwrap(), 0);
+        assert_eq!(parse_expiration(Duration::from_millis(100)).unwrap(), 1);
+        assert_eq!(parse_expiration(Duration::from_millis(1500)).unwrap(), 2);
+        assert_eq!(
+            parse_expiration(Duration::from_secs(MAX_RELATIVE_EXPIRATION)).unwrap(),
+            MAX_RELATIVE_EXPIRATION as u32
+        );
+
+        let now = Utc::now().timestamp() as u64;
+        let v = parse_expiration(Duration::from_secs(MAX_RELATIVE_EXPIRATION + 1)).unwrap() as u64;
+        assert!(v > now + MAX_RELATIVE_EXPIRATION);
+
+        assert!(parse_expiration(Duration::from_secs(u64::from(u32::MAX) * 2)).is_err());
+    }
+}
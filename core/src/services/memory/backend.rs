@@ -17,6 +17,8 @@
 
 use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Mutex;
 
@@ -28,7 +30,10 @@ use crate::*;
 impl Configurator for MemoryConfig {
     type Builder = MemoryBuilder;
     fn into_builder(self) -> Self::Builder {
-        MemoryBuilder { config: self }
+        MemoryBuilder {
+            config: self,
+            stats: None,
+        }
     }
 }
 
@@ -37,6 +42,7 @@ impl Configurator for MemoryConfig {
 #[derive(Default)]
 pub struct MemoryBuilder {
     config: MemoryConfig,
+    stats: Option<MemoryStats>,
 }
 
 impl MemoryBuilder {
@@ -45,6 +51,44 @@ impl MemoryBuilder {
         self.config.root = Some(path.into());
         self
     }
+
+    /// Set the max total size in bytes of all entries.
+    ///
+    /// Entries will be evicted by the eviction policy once the limit is reached.
+    pub fn max_capacity(mut self, v: u64) -> Self {
+        if v != 0 {
+            self.config.max_capacity = Some(v);
+        }
+        self
+    }
+
+    /// Set the max number of entries.
+    ///
+    /// Entries will be evicted by the eviction policy once the limit is reached.
+    pub fn max_entries(mut self, v: usize) -> Self {
+        if v != 0 {
+            self.config.max_entries = Some(v);
+        }
+        self
+    }
+
+    /// Set the eviction policy, available values are `lru` (default) and `lfu`.
+    pub fn eviction_policy(mut self, policy: &str) -> Self {
+        self.config.eviction_policy = if policy.is_empty() {
+            None
+        } else {
+            Some(policy.to_string())
+        };
+        self
+    }
+
+    /// Set the stats handle to collect hits, misses and evictions of this service.
+    ///
+    /// The same handle can be cloned and read after the operator is built.
+    pub fn stats(mut self, stats: MemoryStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
 }
 
 impl Builder for MemoryBuilder {
@@ -52,8 +96,29 @@ impl Builder for MemoryBuilder {
     type Config = MemoryConfig;
 
     fn build(self) -> Result<impl Access> {
+        let policy = match self.config.eviction_policy.as_deref() {
+            None | Some("lru") => EvictionPolicy::Lru,
+            Some("lfu") => EvictionPolicy::Lfu,
+            Some(v) => {
+                return Err(
+                    Error::new(ErrorKind::ConfigInvalid, "eviction_policy is invalid")
+                        .with_context("service", Scheme::Memory)
+                        .with_context("eviction_policy", v),
+                )
+            }
+        };
+
         let adapter = Adapter {
-            inner: Arc::new(Mutex::new(BTreeMap::default())),
+            inner: Arc::new(Mutex::new(Cache::new(
+                policy,
+                // `0` means unbounded, the same as the builder methods.
+                self.config
+                    .max_capacity
+                    .filter(|v| *v != 0)
+                    .map(|v| v as usize),
+                self.config.max_entries.filter(|v| *v != 0),
+            ))),
+            stats: self.stats.unwrap_or_default(),
         };
 
         Ok(MemoryBackend::new(adapter).with_root(self.config.root.as_deref().unwrap_or_default()))
@@ -63,9 +128,159 @@ impl Builder for MemoryBuilder {
 /// Backend is used to serve `Accessor` support in memory.
 pub type MemoryBackend = typed_kv::Backend<Adapter>;
 
+/// MemoryStats records the hits, misses and evictions of memory service.
+#[derive(Clone, Default, Debug)]
+pub struct MemoryStats {
+    inner: Arc<MemoryStatsInner>,
+}
+
+#[derive(Default, Debug)]
+struct MemoryStatsInner {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+impl MemoryStats {
+    /// Create a new stats handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of lookups that found the entry.
+    pub fn hits(&self) -> u64 {
+        self.inner.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of lookups that didn't find the entry.
+    pub fn misses(&self) -> u64 {
+        self.inner.misses.load(Ordering::Relaxed)
+    }
+
+    /// Number of entries evicted to satisfy the limits.
+    pub fn evictions(&self) -> u64 {
+        self.inner.evictions.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EvictionPolicy {
+    /// Evict the least recently used entry.
+    Lru,
+    /// Evict the least frequently used entry, ties are broken by recency.
+    Lfu,
+}
+
+struct Entry {
+    value: typed_kv::Value,
+    /// Key of this entry in `Cache::order`.
+    rank: (u64, u64),
+}
+
+/// Cache is a BTreeMap with optional limits.
+///
+/// `order` indexes all entries by `(frequency, last access tick)`, the first one
+/// will be evicted first. Frequency is always `0` for LRU.
+struct Cache {
+    map: BTreeMap<String, Entry>,
+    order: BTreeMap<(u64, u64), String>,
+    tick: u64,
+    size: usize,
+
+    policy: EvictionPolicy,
+    max_capacity: Option<usize>,
+    max_entries: Option<usize>,
+}
+
+impl Cache {
+    fn new(
+        policy: EvictionPolicy,
+        max_capacity: Option<usize>,
+        max_entries: Option<usize>,
+    ) -> Self {
+        Self {
+            map: BTreeMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+            size: 0,
+
+            policy,
+            max_capacity,
+            max_entries,
+        }
+    }
+
+    fn next_rank(&mut self, freq: u64) -> (u64, u64) {
+        self.tick += 1;
+        match self.policy {
+            EvictionPolicy::Lru => (0, self.tick),
+            EvictionPolicy::Lfu => (freq, self.tick),
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<typed_kv::Value> {
+        let old = self.map.get(key)?.rank;
+        let rank = self.next_rank(old.0 + 1);
+
+        let key = self.order.remove(&old).expect("entry must be ranked");
+        let entry = self.map.get_mut(&key).expect("entry must exist");
+        entry.rank = rank;
+        let value = entry.value.clone();
+        self.order.insert(rank, key);
+
+        Some(value)
+    }
+
+    /// Insert the value and return the number of evicted entries.
+    fn insert(&mut self, key: &str, value: typed_kv::Value) -> Result<u64> {
+        let size = value.size();
+        if let Some(max_capacity) = self.max_capacity {
+            if size > max_capacity {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "value is larger than the max capacity of memory service",
+                )
+                .with_context("size", size.to_string())
+                .with_context("max_capacity", max_capacity.to_string()));
+            }
+        }
+
+        self.remove(key);
+        let rank = self.next_rank(1);
+        self.size += size;
+        self.order.insert(rank, key.to_string());
+        self.map.insert(key.to_string(), Entry { value, rank });
+
+        let mut evicted = 0;
+        while self.max_capacity.is_some_and(|v| self.size > v)
+            || self.max_entries.is_some_and(|v| self.map.len() > v)
+        {
+            let Some(victim) = self.order.values().find(|k| k.as_str() != key).cloned() else {
+                self.remove(key);
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "memory service is over limit but has no entry to evict",
+                ));
+            };
+            self.remove(&victim);
+            evicted += 1;
+        }
+
+        Ok(evicted)
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.map.remove(key) {
+            self.order.remove(&entry.rank);
+            self.size -= entry.value.size();
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Adapter {
-    inner: Arc<Mutex<BTreeMap<String, typed_kv::Value>>>,
+    inner: Arc<Mutex<Cache>>,
+    stats: MemoryStats,
 }
 
 impl Debug for Adapter {
@@ -84,6 +299,7 @@ impl typed_kv::Adapter for Adapter {
                 set: true,
                 delete: true,
                 scan: true,
+                ttl: false,
                 shared: false,
             },
         )
@@ -94,10 +310,15 @@ impl typed_kv::Adapter for Adapter {
     }
 
     fn blocking_get(&self, path: &str) -> Result<Option<typed_kv::Value>> {
-        match self.inner.lock().unwrap().get(path) {
-            None => Ok(None),
-            Some(bs) => Ok(Some(bs.to_owned())),
-        }
+        let value = self.inner.lock().unwrap().get(path);
+        let counter = if value.is_some() {
+            &self.stats.inner.hits
+        } else {
+            &self.stats.inner.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        Ok(value)
     }
 
     async fn set(&self, path: &str, value: typed_kv::Value) -> Result<()> {
@@ -105,7 +326,13 @@ impl typed_kv::Adapter for Adapter {
     }
 
     fn blocking_set(&self, path: &str, value: typed_kv::Value) -> Result<()> {
-        self.inner.lock().unwrap().insert(path.to_string(), value);
+        let evicted = self.inner.lock().unwrap().insert(path, value)?;
+        if evicted > 0 {
+            self.stats
+                .inner
+                .evictions
+                .fetch_add(evicted, Ordering::Relaxed);
+        }
 
         Ok(())
     }
@@ -128,11 +355,11 @@ impl typed_kv::Adapter for Adapter {
         let inner = self.inner.lock().unwrap();
 
         if path.is_empty() {
-            return Ok(inner.keys().cloned().collect());
+            return Ok(inner.map.keys().cloned().collect());
         }
 
         let mut keys = Vec::new();
-        for (key, _) in inner.range(path.to_string()..) {
+        for (key, _) in inner.map.range(path.to_string()..) {
             if !key.starts_with(path) {
                 break;
             }
@@ -144,9 +371,29 @@ impl typed_kv::Adapter for Adapter {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use super::*;
     use crate::raw::adapters::typed_kv::{Adapter, Value};
 
+    fn new_adapter(
+        policy: EvictionPolicy,
+        max_capacity: Option<usize>,
+        max_entries: Option<usize>,
+    ) -> super::Adapter {
+        super::Adapter {
+            inner: Arc::new(Mutex::new(Cache::new(policy, max_capacity, max_entries))),
+            stats: MemoryStats::new(),
+        }
+    }
+
+    fn new_file(size: usize) -> Value {
+        Value {
+            metadata: Metadata::new(EntryMode::FILE).with_content_length(size as u64),
+            value: Buffer::from(vec![0; size]),
+        }
+    }
+
     #[test]
     fn test_accessor_metadata_name() {
         let b1 = MemoryBuilder::default().build().unwrap();
@@ -158,9 +405,7 @@ mod tests {
 
     #[test]
     fn test_blocking_scan() {
-        let adapter = super::Adapter {
-            inner: Arc::new(Mutex::new(BTreeMap::default())),
-        };
+        let adapter = new_adapter(EvictionPolicy::Lru, None, None);
 
         adapter.blocking_set("aaa/bbb/", Value::new_dir()).unwrap();
         adapter.blocking_set("aab/bbb/", Value::new_dir()).unwrap();
@@ -176,4 +421,82 @@ mod tests {
             assert!(path.starts_with("aab"));
         }
     }
+
+    #[test]
+    fn test_invalid_eviction_policy() {
+        let res = MemoryBuilder::default().eviction_policy("fifo").build();
+        assert_eq!(res.err().unwrap().kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let adapter = new_adapter(EvictionPolicy::Lru, None, Some(2));
+
+        adapter.blocking_set("a", new_file(1)).unwrap();
+        adapter.blocking_set("b", new_file(1)).unwrap();
+        // Touch `a` so that `b` becomes the least recently used one.
+        assert!(adapter.blocking_get("a").unwrap().is_some());
+        adapter.blocking_set("c", new_file(1)).unwrap();
+
+        assert!(adapter.blocking_get("a").unwrap().is_some());
+        assert!(adapter.blocking_get("b").unwrap().is_none());
+        assert!(adapter.blocking_get("c").unwrap().is_some());
+
+        assert_eq!(adapter.stats.hits(), 3);
+        assert_eq!(adapter.stats.misses(), 1);
+        assert_eq!(adapter.stats.evictions(), 1);
+    }
+
+    #[test]
+    fn test_lfu_eviction() {
+        let adapter = new_adapter(EvictionPolicy::Lfu, None, Some(2));
+
+        adapter.blocking_set("a", new_file(1)).unwrap();
+        adapter.blocking_set("b", new_file(1)).unwrap();
+        adapter.blocking_get("a").unwrap();
+        adapter.blocking_get("a").unwrap();
+        adapter.blocking_get("b").unwrap();
+        // `c` is the least frequently used one but should not evict itself.
+        adapter.blocking_set("c", new_file(1)).unwrap();
+        adapter.blocking_set("d", new_file(1)).unwrap();
+
+        assert!(adapter.blocking_get("a").unwrap().is_some());
+        assert!(adapter.blocking_get("b").unwrap().is_none());
+        assert!(adapter.blocking_get("c").unwrap().is_none());
+        assert!(adapter.blocking_get("d").unwrap().is_some());
+        assert_eq!(adapter.stats.evictions(), 2);
+    }
+
+    #[test]
+    fn test_max_capacity() {
+        let size = new_file(100).size();
+        let adapter = new_adapter(EvictionPolicy::Lru, Some(size * 2), None);
+
+        adapter.blocking_set("a", new_file(100)).unwrap();
+        adapter.blocking_set("b", new_file(100)).unwrap();
+        // Overwrite should not count the old value.
+        adapter.blocking_set("b", new_file(100)).unwrap();
+        assert_eq!(adapter.stats.evictions(), 0);
+
+        adapter.blocking_set("c", new_file(100)).unwrap();
+        assert_eq!(adapter.stats.evictions(), 1);
+        assert!(adapter.blocking_get("a").unwrap().is_none());
+
+        let err = adapter.blocking_set("d", new_file(1000)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_zero_limits_from_config() {
+        let map = HashMap::from([
+            ("max_capacity".to_string(), "0".to_string()),
+            ("max_entries".to_string(), "0".to_string()),
+        ]);
+        let op = Operator::via_iter(Scheme::Memory, map).unwrap().blocking();
+
+        op.write("a", "hello").unwrap();
+        op.write("b", "world").unwrap();
+        assert_eq!(op.read("a").unwrap().to_vec(), b"hello");
+        assert_eq!(op.read("b").unwrap().to_vec(), b"world");
+    }
 }
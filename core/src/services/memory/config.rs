@@ -27,4 +27,12 @@ use serde::Serialize;
 pub struct MemoryConfig {
     /// root of the backend.
     pub root: Option<String>,
+    /// Max total size in bytes of all entries, unbounded if not set or `0`.
+    pub max_capacity: Option<u64>,
+    /// Max number of entries, unbounded if not set or `0`.
+    pub max_entries: Option<usize>,
+    /// Eviction policy used when the limits are reached.
+    ///
+    /// Available values are `lru` (default) and `lfu`.
+    pub eviction_policy: Option<String>,
 }
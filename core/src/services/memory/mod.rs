@@ -19,6 +19,8 @@
 mod backend;
 #[cfg(feature = "services-memory")]
 pub use backend::MemoryBuilder as Memory;
+#[cfg(feature = "services-memory")]
+pub use backend::MemoryStats;
 
 mod config;
 pub use config::MemoryConfig;
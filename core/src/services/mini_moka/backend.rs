@@ -143,6 +143,7 @@ impl typed_kv::Adapter for Adapter {
                 set: true,
                 delete: true,
                 scan: true,
+                ttl: false,
                 shared: false,
             },
         )
@@ -157,6 +157,9 @@ pub use onedrive::*;
 mod oss;
 pub use oss::*;
 
+mod ozone;
+pub use ozone::*;
+
 mod pcloud;
 pub use pcloud::*;
 
@@ -166,6 +169,9 @@ pub use self::persy::*;
 mod postgresql;
 pub use self::postgresql::*;
 
+mod rados;
+pub use self::rados::*;
+
 mod redb;
 pub use self::redb::*;
 
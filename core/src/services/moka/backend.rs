@@ -18,10 +18,12 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::time::Duration;
+use std::time::Instant;
 
 use log::debug;
 use moka::sync::CacheBuilder;
 use moka::sync::SegmentedCache;
+use moka::Expiry;
 
 use crate::raw::adapters::typed_kv;
 use crate::raw::*;
@@ -109,10 +111,12 @@ impl Builder for MokaBuilder {
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
-        let mut builder: CacheBuilder<String, typed_kv::Value, _> =
+        let mut builder: CacheBuilder<String, Entry, _> =
             SegmentedCache::builder(self.config.num_segments.unwrap_or(1));
         // Use entries' bytes as capacity weigher.
-        builder = builder.weigher(|k, v| (k.len() + v.size()) as u32);
+        builder = builder.weigher(|k, v| (k.len() + v.value.size()) as u32);
+        // Honor the per-entry ttl set by `write_with().expire_after()`.
+        builder = builder.expire_after(EntryExpiry);
         if let Some(v) = &self.config.name {
             builder = builder.name(v);
         }
@@ -142,9 +146,37 @@ impl Builder for MokaBuilder {
 /// Backend is used to serve `Accessor` support in moka.
 pub type MokaBackend = typed_kv::Backend<Adapter>;
 
+/// Entry stored in moka along with its optional ttl.
+#[derive(Clone)]
+struct Entry {
+    value: typed_kv::Value,
+    ttl: Option<Duration>,
+}
+
+/// EntryExpiry expires entries by the ttl they carry.
+///
+/// Entries without ttl will only be expired by `time_to_live` and `time_to_idle`.
+struct EntryExpiry;
+
+impl Expiry<String, Entry> for EntryExpiry {
+    fn expire_after_create(&self, _: &String, entry: &Entry, _: Instant) -> Option<Duration> {
+        entry.ttl
+    }
+
+    fn expire_after_update(
+        &self,
+        _: &String,
+        entry: &Entry,
+        _: Instant,
+        _: Option<Duration>,
+    ) -> Option<Duration> {
+        entry.ttl
+    }
+}
+
 #[derive(Clone)]
 pub struct Adapter {
-    inner: SegmentedCache<String, typed_kv::Value>,
+    inner: SegmentedCache<String, Entry>,
 }
 
 impl Debug for Adapter {
@@ -166,6 +198,7 @@ impl typed_kv::Adapter for Adapter {
                 set: true,
                 delete: true,
                 scan: true,
+                ttl: true,
                 shared: false,
             },
         )
@@ -178,7 +211,7 @@ impl typed_kv::Adapter for Adapter {
     fn blocking_get(&self, path: &str) -> Result<Option<typed_kv::Value>> {
         match self.inner.get(path) {
             None => Ok(None),
-            Some(bs) => Ok(Some(bs)),
+            Some(entry) => Ok(Some(entry.value)),
         }
     }
 
@@ -187,7 +220,29 @@ impl typed_kv::Adapter for Adapter {
     }
 
     fn blocking_set(&self, path: &str, value: typed_kv::Value) -> Result<()> {
-        self.inner.insert(path.to_string(), value);
+        self.inner
+            .insert(path.to_string(), Entry { value, ttl: None });
+
+        Ok(())
+    }
+
+    async fn set_with_ttl(&self, path: &str, value: typed_kv::Value, ttl: Duration) -> Result<()> {
+        self.blocking_set_with_ttl(path, value, ttl)
+    }
+
+    fn blocking_set_with_ttl(
+        &self,
+        path: &str,
+        value: typed_kv::Value,
+        ttl: Duration,
+    ) -> Result<()> {
+        self.inner.insert(
+            path.to_string(),
+            Entry {
+                value,
+                ttl: Some(ttl),
+            },
+        );
 
         Ok(())
     }
@@ -142,6 +142,10 @@ impl Builder for ObsBuilder {
     const SCHEME: Scheme = Scheme::Obs;
     type Config = ObsConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
@@ -281,6 +285,7 @@ impl Access for ObsBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_content_type: true,
                 write_with_cache_control: true,
                 // The min multipart size of OBS is 5 MiB.
@@ -308,6 +313,7 @@ impl Access for ObsBackend {
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_with_headers: true,
 
                 shared: true,
 
@@ -360,7 +366,8 @@ impl Access for ObsBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let etag = parse_etag(resp.headers())?.map(String::from);
+                Ok((RpRead::default().with_etag(etag), resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -376,11 +383,10 @@ impl Access for ObsBackend {
         let w = if args.append() {
             ObsWriters::Two(oio::AppendWriter::new(writer))
         } else {
-            ObsWriters::One(oio::MultipartWriter::new(
-                writer,
-                args.executor().cloned(),
-                args.concurrent(),
-            ))
+            ObsWriters::One(
+                oio::MultipartWriter::new(writer, args.executor().cloned(), args.concurrent())
+                    .with_progress(args.progress().cloned()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -420,7 +426,17 @@ impl Access for ObsBackend {
                 self.core
                     .obs_put_object_request(path, None, v, Buffer::new())?
             }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "Obs doesn't support this presign operation",
+                ))
+            }
         };
+
+        // Extra headers will be signed so that clients must send them as is.
+        req.headers_mut().extend(args.headers().clone());
+
         self.core.sign_query(&mut req, args.expire()).await?;
 
         // We don't need this request anymore, consume it directly.
@@ -17,8 +17,11 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::mem;
 use std::sync::Arc;
 
+use bytes::Buf;
+use bytes::Bytes;
 use http::Response;
 use http::StatusCode;
 use http::Uri;
@@ -26,8 +29,11 @@ use log::debug;
 use reqsign::AliyunConfig;
 use reqsign::AliyunLoader;
 use reqsign::AliyunOssSigner;
+use tokio::sync::Mutex;
 
 use super::core::*;
+use super::credential::EcsRamRoleLoader;
+use super::credential::OssCredentialLoad;
 use super::delete::OssDeleter;
 use super::error::parse_error;
 use super::lister::{OssLister, OssListers, OssObjectVersionsLister};
@@ -38,12 +44,15 @@ use crate::services::OssConfig;
 use crate::*;
 
 const DEFAULT_BATCH_MAX_OPERATIONS: usize = 1000;
+/// OSS requires every part except the last one to be at least 100 KiB.
+const MIN_PART_SIZE: u64 = 100 * 1024;
 
 impl Configurator for OssConfig {
     type Builder = OssBuilder;
     fn into_builder(self) -> Self::Builder {
         OssBuilder {
             config: self,
+            customized_credential_load: None,
             http_client: None,
         }
     }
@@ -54,6 +63,8 @@ impl Configurator for OssConfig {
 #[derive(Default)]
 pub struct OssBuilder {
     config: OssConfig,
+
+    customized_credential_load: Option<Box<dyn OssCredentialLoad>>,
     http_client: Option<HttpClient>,
 }
 
@@ -145,6 +156,43 @@ impl OssBuilder {
         self
     }
 
+    /// Set security_token for this backend.
+    ///
+    /// The security token is required while using STS temporary credentials.
+    /// Static tokens can't be refreshed, please use `customized_credential_load`
+    /// or `ecs_ram_role` for long-running services.
+    pub fn security_token(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.security_token = Some(v.to_string())
+        }
+
+        self
+    }
+
+    /// Set ecs_ram_role for this backend.
+    ///
+    /// STS credentials of the RAM role attached to the ECS instance will be loaded
+    /// from the ECS metadata service, and refreshed before they expire.
+    pub fn ecs_ram_role(mut self, role: &str) -> Self {
+        if !role.is_empty() {
+            self.config.ecs_ram_role = Some(role.to_string())
+        }
+
+        self
+    }
+
+    /// Adding a customized credential load for service.
+    ///
+    /// The loader will be called again once the loaded credential is expired, so
+    /// STS temporary credentials can be refreshed by users.
+    ///
+    /// If customized_credential_load has been set, we will ignore all other
+    /// credential load methods.
+    pub fn customized_credential_load(mut self, cred: Box<dyn OssCredentialLoad>) -> Self {
+        self.customized_credential_load = Some(cred);
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -312,6 +360,10 @@ impl Builder for OssBuilder {
     const SCHEME: Scheme = Scheme::Oss;
     type Config = OssConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
@@ -368,6 +420,10 @@ impl Builder for OssBuilder {
             cfg.access_key_secret = Some(v);
         }
 
+        if let Some(v) = self.config.security_token {
+            cfg.security_token = Some(v);
+        }
+
         if let Some(v) = self.config.role_arn {
             cfg.role_arn = Some(v);
         }
@@ -400,6 +456,14 @@ impl Builder for OssBuilder {
 
         let loader = AliyunLoader::new(GLOBAL_REQWEST_CLIENT.clone(), cfg);
 
+        let customized_credential_load =
+            match self.customized_credential_load {
+                Some(v) => Some(v),
+                None => self.config.ecs_ram_role.as_deref().map(|role| {
+                    Box::new(EcsRamRoleLoader::new(role)) as Box<dyn OssCredentialLoad>
+                }),
+            };
+
         let signer = AliyunOssSigner::new(bucket);
 
         let delete_max_size = self
@@ -418,6 +482,8 @@ impl Builder for OssBuilder {
                 enable_versioning: self.config.enable_versioning,
                 signer,
                 loader,
+                customized_credential_load,
+                credential: Mutex::default(),
                 client,
                 server_side_encryption,
                 server_side_encryption_key_id,
@@ -433,6 +499,49 @@ pub struct OssBackend {
     core: Arc<OssCore>,
 }
 
+impl OssBackend {
+    /// Read the `range` of `source` into memory.
+    async fn read_source(&self, source: &str, range: BytesRange) -> Result<Buffer> {
+        let resp = self
+            .core
+            .oss_get_object(source, &OpRead::new().with_range(range))
+            .await?;
+        let (part, mut body) = resp.into_parts();
+        let buf = body.to_buffer().await?;
+        match part.status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => Ok(buf),
+            _ => Err(parse_error(Response::from_parts(part, buf))),
+        }
+    }
+
+    /// Upload `body` as a part of the multipart upload.
+    async fn upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        body: Buffer,
+    ) -> Result<MultipartUploadPart> {
+        let size = body.len() as u64;
+        let resp = self
+            .core
+            .oss_upload_part_request(path, upload_id, part_number, false, size, body)
+            .await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let etag = parse_etag(resp.headers())?
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::Unexpected,
+                    "ETag not present in returning response",
+                )
+            })?
+            .to_string();
+        Ok(MultipartUploadPart { part_number, etag })
+    }
+}
+
 impl Access for OssBackend {
     type Reader = HttpBody;
     type Writer = OssWriters;
@@ -477,6 +586,7 @@ impl Access for OssBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_cache_control: true,
                 write_with_content_type: true,
                 write_with_content_disposition: true,
@@ -502,11 +612,13 @@ impl Access for OssBackend {
                 delete_max_size: Some(self.core.delete_max_size),
 
                 copy: true,
+                concat: true,
 
                 list: true,
                 list_with_limit: true,
                 list_with_start_after: true,
                 list_with_recursive: true,
+                list_with_delimiter: true,
                 list_has_etag: true,
                 list_has_content_md5: true,
                 list_with_versions: self.core.enable_versioning,
@@ -518,6 +630,7 @@ impl Access for OssBackend {
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_with_headers: true,
 
                 shared: true,
 
@@ -554,7 +667,8 @@ impl Access for OssBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let etag = parse_etag(resp.headers())?.map(String::from);
+                Ok((RpRead::default().with_etag(etag), resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -570,11 +684,10 @@ impl Access for OssBackend {
         let w = if args.append() {
             OssWriters::Two(oio::AppendWriter::new(writer))
         } else {
-            OssWriters::One(oio::MultipartWriter::new(
-                writer,
-                args.executor().cloned(),
-                args.concurrent(),
-            ))
+            OssWriters::One(
+                oio::MultipartWriter::new(writer, args.executor().cloned(), args.concurrent())
+                    .with_progress(args.progress().cloned()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -598,7 +711,7 @@ impl Access for OssBackend {
             TwoWays::One(oio::PageLister::new(OssLister::new(
                 self.core.clone(),
                 path,
-                args.recursive(),
+                args.delimiter(),
                 args.limit(),
                 args.start_after(),
             )))
@@ -607,6 +720,115 @@ impl Access for OssBackend {
         Ok((RpList::default(), l))
     }
 
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        // OSS requires part number must between [1..=10000]
+        if args.sources().len() > 10000 {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "oss can't concat more than 10000 sources",
+            ));
+        }
+
+        let mut sizes = Vec::with_capacity(args.sources().len());
+        for source in args.sources() {
+            let resp = self.core.oss_head_object(source, &OpStat::new()).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+            sizes.push(parse_content_length(resp.headers())?.unwrap_or_default());
+        }
+
+        let resp = self
+            .core
+            .oss_initiate_upload(path, None, None, None, false)
+            .await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let result: InitiateMultipartUploadResult =
+            quick_xml::de::from_reader(resp.into_body().reader())
+                .map_err(new_xml_deserialize_error)?;
+        let upload_id = result.upload_id;
+
+        let res = async {
+            let mut parts = Vec::with_capacity(args.sources().len());
+            // Sources smaller than MIN_PART_SIZE can't be copied as a part, they are
+            // downloaded and merged with the following data into one uploaded part.
+            let mut pending: Vec<Bytes> = Vec::new();
+            let mut pending_size = 0;
+
+            for (idx, (source, size)) in args.sources().iter().zip(sizes).enumerate() {
+                let is_last = idx + 1 == args.sources().len();
+
+                let mut offset = 0;
+                if pending_size > 0 || (size < MIN_PART_SIZE && !is_last) {
+                    // Only download the bytes needed to fill the part if the rest
+                    // is still large enough to be copied.
+                    let needed = MIN_PART_SIZE - pending_size;
+                    offset = if size > needed && (is_last || size - needed >= MIN_PART_SIZE) {
+                        needed
+                    } else {
+                        size
+                    };
+                    if offset > 0 {
+                        let buf = self
+                            .read_source(source, BytesRange::new(0, Some(offset)))
+                            .await?;
+                        pending.extend(buf);
+                        pending_size += offset;
+                    }
+
+                    if pending_size >= MIN_PART_SIZE || is_last {
+                        let body: Buffer = mem::take(&mut pending).into_iter().collect();
+                        let part_number = parts.len() + 1;
+                        parts.push(self.upload_part(path, &upload_id, part_number, body).await?);
+                        pending_size = 0;
+                    }
+                }
+
+                if offset < size {
+                    let part_number = parts.len() + 1;
+                    let range = BytesRange::new(offset, Some(size - offset));
+                    let resp = self
+                        .core
+                        .oss_upload_part_copy(path, &upload_id, part_number, source, range)
+                        .await?;
+                    if resp.status() != StatusCode::OK {
+                        return Err(parse_error(resp));
+                    }
+                    let result: CopyPartResult =
+                        quick_xml::de::from_reader(resp.into_body().reader())
+                            .map_err(new_xml_deserialize_error)?;
+                    parts.push(MultipartUploadPart {
+                        part_number,
+                        etag: result.etag,
+                    });
+                }
+            }
+
+            // Multipart upload requires at least one part even if all sources are empty.
+            if parts.is_empty() {
+                parts.push(self.upload_part(path, &upload_id, 1, Buffer::new()).await?);
+            }
+
+            let resp = self
+                .core
+                .oss_complete_multipart_upload_request(path, &upload_id, false, parts)
+                .await?;
+            match resp.status() {
+                StatusCode::OK => Ok(RpConcat::default()),
+                _ => Err(parse_error(resp)),
+            }
+        }
+        .await;
+
+        if res.is_err() {
+            // The concat error is more important than the abort one.
+            let _ = self.core.oss_abort_multipart_upload(path, &upload_id).await;
+        }
+        res
+    }
+
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
         let resp = self.core.oss_copy_object(from, to).await?;
         let status = resp.status();
@@ -626,8 +848,17 @@ impl Access for OssBackend {
                 self.core
                     .oss_put_object_request(path, None, v, Buffer::new(), true)?
             }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "Oss doesn't support this presign operation",
+                ))
+            }
         };
 
+        // Extra headers will be signed so that clients must send them as is.
+        req.headers_mut().extend(args.headers().clone());
+
         self.core.sign_query(&mut req, args.expire()).await?;
 
         // We don't need this request anymore, consume it directly.
@@ -52,6 +52,13 @@ pub struct OssConfig {
     pub access_key_id: Option<String>,
     /// Access key secret for oss.
     pub access_key_secret: Option<String>,
+    /// Security token for oss, used with STS temporary credentials.
+    pub security_token: Option<String>,
+    /// The RAM role attached to the ECS instance.
+    ///
+    /// If `ecs_ram_role` is set, we will load STS credentials of this role from
+    /// the ECS metadata service and refresh them before they expire.
+    pub ecs_ram_role: Option<String>,
     /// The size of max batch operations.
     #[deprecated(
         since = "0.52.0",
@@ -41,7 +41,9 @@ use reqsign::AliyunLoader;
 use reqsign::AliyunOssSigner;
 use serde::Deserialize;
 use serde::Serialize;
+use tokio::sync::Mutex;
 
+use super::credential::OssCredentialLoad;
 use crate::raw::*;
 use crate::services::oss::core::constants::X_OSS_FORBID_OVERWRITE;
 use crate::*;
@@ -77,6 +79,10 @@ pub struct OssCore {
 
     pub client: HttpClient,
     pub loader: AliyunLoader,
+    /// Takes precedence over `loader` if set.
+    pub customized_credential_load: Option<Box<dyn OssCredentialLoad>>,
+    /// Credential loaded by `customized_credential_load`.
+    pub credential: Mutex<Option<AliyunCredential>>,
     pub signer: AliyunOssSigner,
     pub delete_max_size: usize,
 }
@@ -94,11 +100,27 @@ impl Debug for OssCore {
 
 impl OssCore {
     async fn load_credential(&self) -> Result<Option<AliyunCredential>> {
-        let cred = self
-            .loader
-            .load()
-            .await
-            .map_err(new_request_credential_error)?;
+        let cred = match &self.customized_credential_load {
+            Some(loader) => {
+                let mut lock = self.credential.lock().await;
+                match lock.as_ref() {
+                    Some(cred) if cred.is_valid() => Some(cred.clone()),
+                    _ => {
+                        let cred = loader
+                            .load_credential(GLOBAL_REQWEST_CLIENT.clone())
+                            .await
+                            .map_err(new_request_credential_error)?;
+                        lock.clone_from(&cred);
+                        cred
+                    }
+                }
+            }
+            None => self
+                .loader
+                .load()
+                .await
+                .map_err(new_request_credential_error)?,
+        };
 
         if let Some(cred) = cred {
             Ok(Some(cred))
@@ -665,6 +687,39 @@ impl OssCore {
         self.send(req).await
     }
 
+    /// Copy the `range` of `source` object as a part of the multipart upload.
+    ///
+    /// ref: <https://www.alibabacloud.com/help/en/oss/developer-reference/uploadpartcopy>
+    pub async fn oss_upload_part_copy(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        source: &str,
+        range: BytesRange,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let source = build_abs_path(&self.root, source);
+
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+        let source = format!("/{}/{}", self.bucket, percent_encode_path(&source));
+
+        let mut req = Request::put(&url).header("x-oss-copy-source", source);
+        if !range.is_full() {
+            req = req.header("x-oss-copy-source-range", range.to_header());
+        }
+        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn oss_complete_multipart_upload_request(
         &self,
         path: &str,
@@ -777,6 +832,14 @@ pub struct MultipartUploadPart {
     pub etag: String,
 }
 
+/// Result of UploadPartCopy.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
 #[derive(Default, Debug, Serialize)]
 #[serde(default, rename = "CompleteMultipartUpload", rename_all = "PascalCase")]
 pub struct CompleteMultipartUploadRequest {
@@ -0,0 +1,146 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use reqsign::AliyunCredential;
+use serde::Deserialize;
+
+use crate::raw::*;
+
+/// The metadata endpoint of ECS instances.
+const ECS_METADATA_ENDPOINT: &str = "http://100.100.100.200";
+
+/// OssCredentialLoad is used to load credentials for oss by users.
+///
+/// It's useful for services that obtain STS temporary credentials by themselves:
+/// the loader will be called again once the returned credential is expired.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait OssCredentialLoad: 'static + Send + Sync {
+    /// Load a credential, returns `None` if no credential is available.
+    async fn load_credential(
+        &self,
+        client: reqwest::Client,
+    ) -> anyhow::Result<Option<AliyunCredential>>;
+}
+
+/// EcsRamRoleLoader loads STS credentials of the RAM role attached to the ECS
+/// instance from the [instance metadata](https://www.alibabacloud.com/help/en/ecs/user-guide/attach-an-instance-ram-role-to-an-ecs-instance).
+pub struct EcsRamRoleLoader {
+    role_name: String,
+}
+
+impl EcsRamRoleLoader {
+    /// Create a new loader for the given RAM role.
+    pub fn new(role_name: &str) -> Self {
+        Self {
+            role_name: role_name.to_string(),
+        }
+    }
+
+    /// Fetch the metadata token in security hardening mode.
+    ///
+    /// Returns `None` if the instance doesn't support it.
+    async fn load_metadata_token(&self, client: &reqwest::Client) -> Option<String> {
+        let resp = client
+            .put(format!("{ECS_METADATA_ENDPOINT}/latest/api/token"))
+            .header("X-aliyun-ecs-metadata-token-ttl-seconds", "21600")
+            .send()
+            .await
+            .ok()?;
+        if !resp.status().is_success() {
+            return None;
+        }
+        resp.text().await.ok()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl OssCredentialLoad for EcsRamRoleLoader {
+    async fn load_credential(
+        &self,
+        client: reqwest::Client,
+    ) -> anyhow::Result<Option<AliyunCredential>> {
+        let url = format!(
+            "{ECS_METADATA_ENDPOINT}/latest/meta-data/ram/security-credentials/{}",
+            percent_encode_path(&self.role_name)
+        );
+        let mut req = client.get(&url);
+        if let Some(token) = self.load_metadata_token(&client).await {
+            req = req.header("X-aliyun-ecs-metadata-token", token);
+        }
+
+        let resp = req.send().await?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let content = resp.text().await?;
+            return Err(anyhow!(
+                "request to ecs metadata for ram role failed with {status}: {content}"
+            ));
+        }
+
+        let resp: EcsRamRoleCredential = serde_json::from_slice(&resp.bytes().await?)?;
+        if resp.code != "Success" {
+            return Err(anyhow!(
+                "ecs metadata returns unexpected code for ram role: {}",
+                resp.code
+            ));
+        }
+
+        Ok(Some(AliyunCredential {
+            access_key_id: resp.access_key_id,
+            access_key_secret: resp.access_key_secret,
+            security_token: Some(resp.security_token),
+            expires_in: Some(parse_datetime_from_rfc3339(&resp.expiration)?),
+        }))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EcsRamRoleCredential {
+    code: String,
+    access_key_id: String,
+    access_key_secret: String,
+    security_token: String,
+    expiration: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_ecs_ram_role_credential() {
+        let content = r#"{
+  "AccessKeyId" : "STS.L4aBSCSJVMuKg5U1****",
+  "AccessKeySecret" : "wyLTSmsyPGP1ohvvw8xYgB29dlGI8KMiH2pK****",
+  "Expiration" : "2017-11-01T05:20:01Z",
+  "SecurityToken" : "CAISjgJ1q6Ft5B2yfSjIr5****",
+  "LastUpdated" : "2017-10-31T23:20:01Z",
+  "Code" : "Success"
+}"#;
+
+        let cred: EcsRamRoleCredential = serde_json::from_str(content).unwrap();
+        assert_eq!(cred.code, "Success");
+        assert_eq!(cred.access_key_id, "STS.L4aBSCSJVMuKg5U1****");
+        assert_eq!(cred.security_token, "CAISjgJ1q6Ft5B2yfSjIr5****");
+        assert_eq!(cred.expiration, "2017-11-01T05:20:01Z");
+    }
+}
@@ -45,20 +45,20 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
         StatusCode::PRECONDITION_FAILED | StatusCode::NOT_MODIFIED | StatusCode::CONFLICT => {
             (ErrorKind::ConditionNotMatch, false)
         }
-        StatusCode::INTERNAL_SERVER_ERROR
-        | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
-    let message = match de::from_reader::<_, OssError>(bs.clone().reader()) {
-        Ok(oss_err) => format!("{oss_err:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let (message, code) = match de::from_reader::<_, OssError>(bs.clone().reader()) {
+        Ok(oss_err) => (format!("{oss_err:?}"), Some(oss_err.code)),
+        Err(_) => (String::from_utf8_lossy(&bs).into_owned(), None),
     };
 
     let mut err = Error::new(kind, message);
 
+    if let Some(code) = code.filter(|v| !v.is_empty()) {
+        err = err.with_service_code(code);
+    }
+
     err = with_error_response_context(err, parts);
 
     if retryable {
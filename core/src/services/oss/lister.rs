@@ -32,7 +32,7 @@ pub struct OssLister {
     core: Arc<OssCore>,
 
     path: String,
-    delimiter: &'static str,
+    delimiter: String,
     limit: Option<usize>,
     /// Filter results to objects whose names are lexicographically
     /// **equal to or after** startOffset
@@ -43,15 +43,14 @@ impl OssLister {
     pub fn new(
         core: Arc<OssCore>,
         path: &str,
-        recursive: bool,
+        delimiter: &str,
         limit: Option<usize>,
         start_after: Option<&str>,
     ) -> Self {
-        let delimiter = if recursive { "" } else { "/" };
         Self {
             core,
             path: path.to_string(),
-            delimiter,
+            delimiter: delimiter.to_string(),
             limit,
             start_after: start_after.map(String::from),
         }
@@ -65,7 +64,7 @@ impl oio::PageList for OssLister {
             .oss_list_object(
                 &self.path,
                 &ctx.token,
-                self.delimiter,
+                &self.delimiter,
                 self.limit,
                 if ctx.token.is_empty() {
                     self.start_after.clone()
@@ -88,10 +87,8 @@ impl oio::PageList for OssLister {
         ctx.token = output.next_continuation_token.unwrap_or_default();
 
         for prefix in output.common_prefixes {
-            let de = oio::Entry::new(
-                &build_rel_path(&self.core.root, &prefix.prefix),
-                Metadata::new(EntryMode::DIR),
-            );
+            let path = build_rel_path(&self.core.root, &prefix.prefix);
+            let de = oio::Entry::new(&path, Metadata::new(EntryMode::from_prefix(&path)));
             ctx.entries.push_back(de);
         }
 
@@ -126,13 +123,13 @@ pub struct OssObjectVersionsLister {
     prefix: String,
     args: OpList,
 
-    delimiter: &'static str,
+    delimiter: String,
     abs_start_after: Option<String>,
 }
 
 impl OssObjectVersionsLister {
     pub fn new(core: Arc<OssCore>, path: &str, args: OpList) -> Self {
-        let delimiter = if args.recursive() { "" } else { "/" };
+        let delimiter = args.delimiter().to_string();
         let abs_start_after = args
             .start_after()
             .map(|start_after| build_abs_path(&core.root, start_after));
@@ -162,7 +159,7 @@ impl oio::PageList for OssObjectVersionsLister {
             .core
             .oss_list_object_versions(
                 &self.prefix,
-                self.delimiter,
+                &self.delimiter,
                 self.args.limit(),
                 key_marker,
                 version_id_marker,
@@ -194,10 +191,8 @@ impl oio::PageList for OssObjectVersionsLister {
         );
 
         for prefix in output.common_prefixes {
-            let de = oio::Entry::new(
-                &build_rel_path(&self.core.root, &prefix.prefix),
-                Metadata::new(EntryMode::DIR),
-            );
+            let path = build_rel_path(&self.core.root, &prefix.prefix);
+            let de = oio::Entry::new(&path, Metadata::new(EntryMode::from_prefix(&path)));
             ctx.entries.push_back(de);
         }
 
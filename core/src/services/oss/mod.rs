@@ -18,6 +18,10 @@
 #[cfg(feature = "services-oss")]
 mod core;
 #[cfg(feature = "services-oss")]
+mod credential;
+#[cfg(feature = "services-oss")]
+pub use credential::OssCredentialLoad;
+#[cfg(feature = "services-oss")]
 mod delete;
 #[cfg(feature = "services-oss")]
 mod error;
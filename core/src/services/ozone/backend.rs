@@ -0,0 +1,280 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use log::debug;
+
+use crate::raw::*;
+use crate::services::OzoneConfig;
+use crate::services::S3;
+use crate::*;
+
+/// The default endpoint of ozone S3 gateway.
+const DEFAULT_ENDPOINT: &str = "http://127.0.0.1:9878";
+/// The S3 gateway doesn't check region, but we still need one to sign requests.
+const DEFAULT_REGION: &str = "us-east-1";
+
+impl Configurator for OzoneConfig {
+    type Builder = OzoneBuilder;
+    fn into_builder(self) -> Self::Builder {
+        OzoneBuilder {
+            config: self,
+            http_client: None,
+        }
+    }
+}
+
+/// [Apache Ozone](https://ozone.apache.org/) support via its S3 gateway.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct OzoneBuilder {
+    config: OzoneConfig,
+    http_client: Option<HttpClient>,
+}
+
+impl Debug for OzoneBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_struct("OzoneBuilder");
+        d.field("config", &self.config);
+        d.finish_non_exhaustive()
+    }
+}
+
+impl OzoneBuilder {
+    /// Set root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set bucket name of this backend.
+    pub fn bucket(mut self, bucket: &str) -> Self {
+        self.config.bucket = bucket.to_string();
+
+        self
+    }
+
+    /// Set endpoint of the ozone S3 gateway.
+    ///
+    /// Default to `http://127.0.0.1:9878`.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        if !endpoint.is_empty() {
+            self.config.endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        }
+
+        self
+    }
+
+    /// Set region of this backend.
+    ///
+    /// Default to `us-east-1`.
+    pub fn region(mut self, region: &str) -> Self {
+        if !region.is_empty() {
+            self.config.region = Some(region.to_string());
+        }
+
+        self
+    }
+
+    /// Set access_key_id of this backend.
+    pub fn access_key_id(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.access_key_id = Some(v.to_string());
+        }
+
+        self
+    }
+
+    /// Set secret_access_key of this backend.
+    pub fn secret_access_key(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.secret_access_key = Some(v.to_string());
+        }
+
+        self
+    }
+
+    /// Allow sending requests without signing when credential is not set.
+    ///
+    /// This is useful for clusters without security enabled.
+    pub fn allow_anonymous(mut self) -> Self {
+        self.config.allow_anonymous = true;
+        self
+    }
+
+    /// Enable virtual host style so that opendal will send API requests
+    /// in virtual host style instead of path style.
+    ///
+    /// The S3 gateway only supports it when `ozone.s3g.domain.name` is set.
+    pub fn enable_virtual_host_style(mut self) -> Self {
+        self.config.enable_virtual_host_style = true;
+        self
+    }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+}
+
+impl Builder for OzoneBuilder {
+    const SCHEME: Scheme = Scheme::Ozone;
+    type Config = OzoneConfig;
+
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
+    fn build(self) -> Result<impl Access> {
+        debug!("backend build started: {:?}", self);
+
+        if self.config.bucket.is_empty() {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "bucket is empty")
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Ozone));
+        }
+
+        let mut builder = S3::default()
+            .root(self.config.root.as_deref().unwrap_or_default())
+            .bucket(&self.config.bucket)
+            .endpoint(self.config.endpoint.as_deref().unwrap_or(DEFAULT_ENDPOINT))
+            .region(self.config.region.as_deref().unwrap_or(DEFAULT_REGION))
+            // Ozone is not running on AWS, don't load config and credential from it.
+            .disable_config_load()
+            .disable_ec2_metadata()
+            // The S3 gateway doesn't support response overrides in HeadObject
+            // and conditional writes.
+            .disable_stat_with_override()
+            .disable_write_with_if_match();
+
+        if let Some(v) = &self.config.access_key_id {
+            builder = builder.access_key_id(v);
+        }
+        if let Some(v) = &self.config.secret_access_key {
+            builder = builder.secret_access_key(v);
+        }
+        if self.config.allow_anonymous {
+            builder = builder.allow_anonymous();
+        }
+        if self.config.enable_virtual_host_style {
+            builder = builder.enable_virtual_host_style();
+        }
+        if let Some(client) = self.http_client {
+            builder = builder.http_client(client);
+        }
+
+        Ok(OzoneBackend {
+            inner: builder.build()?,
+        })
+    }
+}
+
+/// Backend for ozone services.
+///
+/// All requests are forwarded to the S3 gateway, only the capability is
+/// adjusted to what the gateway actually supports.
+#[derive(Debug)]
+pub struct OzoneBackend<A: Access> {
+    inner: A,
+}
+
+impl<A: Access> LayeredAccess for OzoneBackend<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type Writer = A::Writer;
+    type Lister = A::Lister;
+    type Deleter = A::Deleter;
+    type BlockingReader = A::BlockingReader;
+    type BlockingWriter = A::BlockingWriter;
+    type BlockingLister = A::BlockingLister;
+    type BlockingDeleter = A::BlockingDeleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn info(&self) -> Arc<AccessorInfo> {
+        let mut info = self.inner.info().as_ref().clone();
+
+        let mut cap = info.native_capability();
+        // Ozone doesn't support conditional writes.
+        cap.write_with_if_not_exists = false;
+        // Ozone doesn't support bucket versioning and object tagging.
+        cap.stat_has_version = false;
+        cap.tag = false;
+        // Ozone encrypts data by bucket encryption keys, SSE headers are not supported.
+        cap.write_with_server_side_encryption = false;
+        cap.write_with_server_side_encryption_customer_key = false;
+        cap.read_with_server_side_encryption_customer_key = false;
+        cap.stat_with_server_side_encryption_customer_key = false;
+        // Ozone only groups common prefixes by `/`.
+        cap.list_with_delimiter = false;
+        // Ozone doesn't support POST policy uploads.
+        cap.presign_post = false;
+
+        info.set_scheme(Scheme::Ozone).set_native_capability(cap);
+        info.into()
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_delete(&self) -> Result<(RpDelete, Self::BlockingDeleter)> {
+        self.inner.blocking_delete()
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
@@ -0,0 +1,71 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Config for Apache Ozone support via its S3 gateway.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct OzoneConfig {
+    /// Root for ozone.
+    pub root: Option<String>,
+    /// Bucket for ozone.
+    ///
+    /// Buckets under the `s3v` volume are exposed by the S3 gateway directly,
+    /// other buckets need to be linked into `s3v` first.
+    pub bucket: String,
+    /// Endpoint of the ozone S3 gateway.
+    ///
+    /// Default to `http://127.0.0.1:9878`.
+    pub endpoint: Option<String>,
+    /// Region for ozone.
+    ///
+    /// The S3 gateway doesn't check region, default to `us-east-1`.
+    pub region: Option<String>,
+    /// Access key id for ozone.
+    pub access_key_id: Option<String>,
+    /// Secret access key for ozone.
+    pub secret_access_key: Option<String>,
+    /// Allow sending requests without signing, which is useful for
+    /// clusters without security enabled.
+    pub allow_anonymous: bool,
+    /// Enable virtual host style so that opendal will send API requests
+    /// in virtual host style instead of path style.
+    ///
+    /// The S3 gateway only supports it when `ozone.s3g.domain.name` is set.
+    pub enable_virtual_host_style: bool,
+}
+
+impl Debug for OzoneConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OzoneConfig")
+            .field("root", &self.root)
+            .field("bucket", &self.bucket)
+            .field("endpoint", &self.endpoint)
+            .field("region", &self.region)
+            .field("access_key_id", &"<redacted>")
+            .field("secret_access_key", &"<redacted>")
+            .field("allow_anonymous", &self.allow_anonymous)
+            .field("enable_virtual_host_style", &self.enable_virtual_host_style)
+            .finish()
+    }
+}
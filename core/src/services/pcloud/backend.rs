@@ -130,6 +130,10 @@ impl Builder for PcloudBuilder {
     const SCHEME: Scheme = Scheme::Pcloud;
     type Config = PcloudConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Builds the backend and returns the result of PcloudBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
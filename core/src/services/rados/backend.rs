@@ -0,0 +1,240 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use tokio::task;
+
+use super::core::RadosCore;
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::services::RadosConfig;
+use crate::Result;
+use crate::*;
+
+impl Configurator for RadosConfig {
+    type Builder = RadosBuilder;
+    fn into_builder(self) -> Self::Builder {
+        RadosBuilder { config: self }
+    }
+}
+
+/// Ceph RADOS service support via librados.
+#[doc = include_str!("docs.md")]
+#[derive(Clone, Default)]
+pub struct RadosBuilder {
+    config: RadosConfig,
+}
+
+impl Debug for RadosBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadosBuilder")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl RadosBuilder {
+    /// Set the pool to store objects in.
+    pub fn pool(mut self, pool: &str) -> Self {
+        if !pool.is_empty() {
+            self.config.pool = Some(pool.to_string());
+        }
+        self
+    }
+
+    /// Set the user id to connect to the cluster, without the `client.` prefix.
+    ///
+    /// default: "admin"
+    pub fn user(mut self, user: &str) -> Self {
+        if !user.is_empty() {
+            self.config.user = Some(user.to_string());
+        }
+        self
+    }
+
+    /// Set the path to `ceph.conf`.
+    pub fn conf_file(mut self, path: &str) -> Self {
+        if !path.is_empty() {
+            self.config.conf_file = Some(path.to_string());
+        }
+        self
+    }
+
+    /// Set the monitor addresses of the cluster.
+    pub fn mon_host(mut self, mon_host: &str) -> Self {
+        if !mon_host.is_empty() {
+            self.config.mon_host = Some(mon_host.to_string());
+        }
+        self
+    }
+
+    /// Set the secret key of the user.
+    pub fn key(mut self, key: &str) -> Self {
+        if !key.is_empty() {
+            self.config.key = Some(key.to_string());
+        }
+        self
+    }
+
+    /// set the working directory, all operations will be performed under it.
+    ///
+    /// default: "/"
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+}
+
+impl Builder for RadosBuilder {
+    const SCHEME: Scheme = Scheme::Rados;
+    type Config = RadosConfig;
+
+    fn build(self) -> Result<impl Access> {
+        let pool = self.config.pool.as_deref().ok_or_else(|| {
+            Error::new(ErrorKind::ConfigInvalid, "pool is required but not set")
+                .with_context("service", Scheme::Rados)
+        })?;
+        let user = self.config.user.as_deref().unwrap_or("admin");
+
+        let core = RadosCore::connect(
+            user,
+            self.config.conf_file.as_deref(),
+            self.config.mon_host.as_deref(),
+            self.config.key.as_deref(),
+            pool,
+        )
+        .map_err(|e| {
+            e.with_context("service", Scheme::Rados)
+                .with_context("pool", pool)
+        })?;
+
+        let root = normalize_root(
+            self.config
+                .root
+                .clone()
+                .unwrap_or_else(|| "/".to_string())
+                .as_str(),
+        );
+
+        Ok(RadosBackend::new(Adapter {
+            core: Arc::new(core),
+        })
+        .with_normalized_root(root))
+    }
+}
+
+/// Backend for rados services.
+pub type RadosBackend = kv::Backend<Adapter>;
+
+#[derive(Clone)]
+pub struct Adapter {
+    core: Arc<RadosCore>,
+}
+
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Adapter");
+        ds.field("pool", &self.core.pool());
+        ds.finish()
+    }
+}
+
+impl kv::Adapter for Adapter {
+    type Scanner = kv::Scanner;
+
+    fn info(&self) -> kv::Info {
+        kv::Info::new(
+            Scheme::Rados,
+            self.core.pool(),
+            Capability {
+                read: true,
+                write: true,
+                list: true,
+                blocking: true,
+                shared: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Buffer>> {
+        let cloned_self = self.clone();
+        let cloned_path = path.to_string();
+
+        task::spawn_blocking(move || cloned_self.blocking_get(cloned_path.as_str()))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    fn blocking_get(&self, path: &str) -> Result<Option<Buffer>> {
+        let result = self.core.read(path)?;
+        Ok(result.map(Buffer::from))
+    }
+
+    async fn set(&self, path: &str, value: Buffer) -> Result<()> {
+        let cloned_self = self.clone();
+        let cloned_path = path.to_string();
+
+        task::spawn_blocking(move || cloned_self.blocking_set(cloned_path.as_str(), value))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    fn blocking_set(&self, path: &str, value: Buffer) -> Result<()> {
+        self.core.write_full(path, &value.to_bytes())?;
+        // Insert into index after the object has been written, so that
+        // list will never return paths that can't be read.
+        self.core.index_insert(path)
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let cloned_self = self.clone();
+        let cloned_path = path.to_string();
+
+        task::spawn_blocking(move || cloned_self.blocking_delete(cloned_path.as_str()))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    fn blocking_delete(&self, path: &str) -> Result<()> {
+        self.core.remove(path)?;
+        self.core.index_remove(path)
+    }
+
+    async fn scan(&self, path: &str) -> Result<Self::Scanner> {
+        let cloned_self = self.clone();
+        let cloned_path = path.to_string();
+
+        let res = task::spawn_blocking(move || cloned_self.blocking_scan(cloned_path.as_str()))
+            .await
+            .map_err(new_task_join_error)??;
+
+        Ok(Box::new(kv::ScanStdIter::new(res.into_iter().map(Ok))))
+    }
+
+    fn blocking_scan(&self, path: &str) -> Result<Vec<String>> {
+        self.core.index_scan(path)
+    }
+}
@@ -0,0 +1,61 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Config for Ceph RADOS service.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct RadosConfig {
+    /// The pool to store objects in.
+    pub pool: Option<String>,
+    /// The user id to connect to the cluster, without the `client.` prefix.
+    ///
+    /// default is "admin"
+    pub user: Option<String>,
+    /// The path to `ceph.conf`.
+    ///
+    /// If both `conf_file` and `mon_host` are not set, librados will search
+    /// the default locations like `/etc/ceph/ceph.conf`.
+    pub conf_file: Option<String>,
+    /// The monitor addresses of the cluster, for example `10.0.0.1,10.0.0.2`.
+    pub mon_host: Option<String>,
+    /// The secret key of the user.
+    pub key: Option<String>,
+    /// the working directory of the service. Can be "/path/to/dir"
+    ///
+    /// default is "/"
+    pub root: Option<String>,
+}
+
+impl Debug for RadosConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadosConfig")
+            .field("pool", &self.pool)
+            .field("user", &self.user)
+            .field("conf_file", &self.conf_file)
+            .field("mon_host", &self.mon_host)
+            .field("key", &"<redacted>")
+            .field("root", &self.root)
+            .finish()
+    }
+}
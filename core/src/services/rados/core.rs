@@ -0,0 +1,349 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ffi::c_char;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io;
+use std::ptr;
+
+use ceph::rados::*;
+
+use crate::*;
+
+/// The object that keeps all written paths in its omap.
+///
+/// RADOS can't list objects by prefix, so we maintain this index to
+/// support list.
+const INDEX_OBJECT: &str = "opendal.index";
+/// The max keys returned by one omap read.
+const INDEX_PAGE_SIZE: u64 = 1000;
+
+pub struct RadosCore {
+    pool: String,
+    index: CString,
+
+    cluster: rados_t,
+    ioctx: rados_ioctx_t,
+}
+
+// librados handles are thread safe, operations can be issued on them
+// from multiple threads concurrently.
+unsafe impl Send for RadosCore {}
+unsafe impl Sync for RadosCore {}
+
+impl Debug for RadosCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadosCore")
+            .field("pool", &self.pool)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for RadosCore {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.ioctx.is_null() {
+                rados_ioctx_destroy(self.ioctx);
+            }
+            if !self.cluster.is_null() {
+                rados_shutdown(self.cluster);
+            }
+        }
+    }
+}
+
+impl RadosCore {
+    /// Connect to the cluster and open the given pool.
+    pub fn connect(
+        user: &str,
+        conf_file: Option<&str>,
+        mon_host: Option<&str>,
+        key: Option<&str>,
+        pool: &str,
+    ) -> Result<Self> {
+        let user = to_cstring(user)?;
+
+        let mut core = RadosCore {
+            pool: pool.to_string(),
+            index: to_cstring(INDEX_OBJECT)?,
+            cluster: ptr::null_mut(),
+            ioctx: ptr::null_mut(),
+        };
+
+        let ret = unsafe { rados_create(&mut core.cluster, user.as_ptr()) };
+        check(ret, "rados_create")?;
+
+        match conf_file {
+            Some(v) => {
+                let v = to_cstring(v)?;
+                let ret = unsafe { rados_conf_read_file(core.cluster, v.as_ptr()) };
+                check(ret, "rados_conf_read_file")?;
+            }
+            // Search the default locations only if users don't configure
+            // the monitors directly.
+            None if mon_host.is_none() => {
+                let ret = unsafe { rados_conf_read_file(core.cluster, ptr::null()) };
+                check(ret, "rados_conf_read_file")?;
+            }
+            None => {}
+        }
+        if let Some(v) = mon_host {
+            core.conf_set("mon_host", v)?;
+        }
+        if let Some(v) = key {
+            core.conf_set("key", v)?;
+        }
+
+        let ret = unsafe { rados_connect(core.cluster) };
+        check(ret, "rados_connect")?;
+
+        let pool = to_cstring(pool)?;
+        let ret = unsafe { rados_ioctx_create(core.cluster, pool.as_ptr(), &mut core.ioctx) };
+        check(ret, "rados_ioctx_create")?;
+
+        Ok(core)
+    }
+
+    fn conf_set(&self, option: &str, value: &str) -> Result<()> {
+        let option = to_cstring(option)?;
+        let value = to_cstring(value)?;
+        let ret = unsafe { rados_conf_set(self.cluster, option.as_ptr(), value.as_ptr()) };
+        check(ret, "rados_conf_set")
+    }
+
+    pub fn pool(&self) -> &str {
+        &self.pool
+    }
+
+    /// Read the whole object, returns `None` if it doesn't exist.
+    pub fn read(&self, oid: &str) -> Result<Option<Vec<u8>>> {
+        let oid = to_cstring(oid)?;
+
+        let mut size: u64 = 0;
+        let mut mtime = 0;
+        let ret = unsafe { rados_stat(self.ioctx, oid.as_ptr(), &mut size, &mut mtime) };
+        if is_not_found(ret) {
+            return Ok(None);
+        }
+        check(ret, "rados_stat")?;
+
+        let mut buf = vec![0; size as usize];
+        let mut filled = 0;
+        while filled < buf.len() {
+            let ret = unsafe {
+                rados_read(
+                    self.ioctx,
+                    oid.as_ptr(),
+                    buf[filled..].as_mut_ptr().cast(),
+                    buf.len() - filled,
+                    filled as u64,
+                )
+            };
+            if is_not_found(ret) {
+                return Ok(None);
+            }
+            check(ret, "rados_read")?;
+            // The object has been truncated by others.
+            if ret == 0 {
+                break;
+            }
+            filled += ret as usize;
+        }
+        buf.truncate(filled);
+
+        Ok(Some(buf))
+    }
+
+    /// Replace the whole object with given content.
+    pub fn write_full(&self, oid: &str, bs: &[u8]) -> Result<()> {
+        let oid = to_cstring(oid)?;
+
+        let ret =
+            unsafe { rados_write_full(self.ioctx, oid.as_ptr(), bs.as_ptr().cast(), bs.len()) };
+        check(ret, "rados_write_full")
+    }
+
+    /// Remove the object, it's ok if the object doesn't exist.
+    pub fn remove(&self, oid: &str) -> Result<()> {
+        let oid = to_cstring(oid)?;
+
+        let ret = unsafe { rados_remove(self.ioctx, oid.as_ptr()) };
+        if is_not_found(ret) {
+            return Ok(());
+        }
+        check(ret, "rados_remove")
+    }
+
+    /// Add the path into the index.
+    pub fn index_insert(&self, path: &str) -> Result<()> {
+        let key = to_cstring(path)?;
+        let val = CString::default();
+        let keys = [key.as_ptr()];
+        let vals = [val.as_ptr()];
+        let lens = [0];
+
+        let ret = unsafe {
+            let op = rados_create_write_op();
+            rados_write_op_omap_set(op, keys.as_ptr(), vals.as_ptr(), lens.as_ptr(), 1);
+            let ret =
+                rados_write_op_operate(op, self.ioctx, self.index.as_ptr(), ptr::null_mut(), 0);
+            rados_release_write_op(op);
+            ret
+        };
+        check(ret, "rados_write_op_omap_set")
+    }
+
+    /// Remove the path from the index.
+    pub fn index_remove(&self, path: &str) -> Result<()> {
+        let key = to_cstring(path)?;
+        let keys = [key.as_ptr()];
+
+        let ret = unsafe {
+            let op = rados_create_write_op();
+            rados_write_op_omap_rm_keys(op, keys.as_ptr(), 1);
+            let ret =
+                rados_write_op_operate(op, self.ioctx, self.index.as_ptr(), ptr::null_mut(), 0);
+            rados_release_write_op(op);
+            ret
+        };
+        // The index will be created by the first write.
+        if is_not_found(ret) {
+            return Ok(());
+        }
+        check(ret, "rados_write_op_omap_rm_keys")
+    }
+
+    /// Scan all paths in the index that start with given prefix.
+    pub fn index_scan(&self, prefix: &str) -> Result<Vec<String>> {
+        let prefix = to_cstring(prefix)?;
+        let mut start_after = CString::default();
+        let mut res = Vec::new();
+
+        loop {
+            let mut iter: rados_omap_iter_t = ptr::null_mut();
+            let mut rval = 0;
+
+            let op = unsafe { rados_create_read_op() };
+            let ret = unsafe {
+                rados_read_op_omap_get_vals(
+                    op,
+                    start_after.as_ptr(),
+                    prefix.as_ptr(),
+                    INDEX_PAGE_SIZE,
+                    &mut iter,
+                    &mut rval,
+                );
+                rados_read_op_operate(op, self.ioctx, self.index.as_ptr(), 0)
+            };
+            let page = if ret < 0 {
+                Err(ret)
+            } else if rval < 0 {
+                Err(rval)
+            } else {
+                collect_omap_keys(iter)
+            };
+            unsafe {
+                if !iter.is_null() {
+                    rados_omap_get_end(iter);
+                }
+                rados_release_read_op(op);
+            }
+
+            let page = match page {
+                Ok(page) => page,
+                // Nothing has been written yet.
+                Err(ret) if is_not_found(ret) => return Ok(res),
+                Err(ret) => return Err(parse_rados_error(ret, "rados_read_op_omap_get_vals")),
+            };
+
+            let done = (page.len() as u64) < INDEX_PAGE_SIZE;
+            res.extend(page);
+            match res.last() {
+                Some(last) if !done => start_after = to_cstring(last)?,
+                _ => return Ok(res),
+            }
+        }
+    }
+}
+
+/// Collect all keys from the omap iterator.
+fn collect_omap_keys(iter: rados_omap_iter_t) -> std::result::Result<Vec<String>, i32> {
+    let mut keys = Vec::new();
+
+    loop {
+        let mut key: *mut c_char = ptr::null_mut();
+        let mut val: *mut c_char = ptr::null_mut();
+        let mut len = 0;
+
+        let ret = unsafe { rados_omap_get_next(iter, &mut key, &mut val, &mut len) };
+        if ret < 0 {
+            return Err(ret);
+        }
+        // Reach the end of iterator.
+        if key.is_null() {
+            return Ok(keys);
+        }
+
+        let key = unsafe { CStr::from_ptr(key) };
+        keys.push(key.to_string_lossy().to_string());
+    }
+}
+
+fn to_cstring(v: &str) -> Result<CString> {
+    CString::new(v).map_err(|e| {
+        Error::new(ErrorKind::Unexpected, "value contains nul byte")
+            .with_context("value", v)
+            .set_source(e)
+    })
+}
+
+fn is_not_found(ret: i32) -> bool {
+    ret < 0 && io::Error::from_raw_os_error(-ret).kind() == io::ErrorKind::NotFound
+}
+
+fn check(ret: i32, op: &'static str) -> Result<()> {
+    if ret < 0 {
+        return Err(parse_rados_error(ret, op));
+    }
+
+    Ok(())
+}
+
+/// librados returns negative errno on failure.
+fn parse_rados_error(ret: i32, op: &'static str) -> Error {
+    let err = io::Error::from_raw_os_error(-ret);
+
+    let (kind, retryable) = match err.kind() {
+        io::ErrorKind::NotFound => (ErrorKind::NotFound, false),
+        io::ErrorKind::PermissionDenied => (ErrorKind::PermissionDenied, false),
+        io::ErrorKind::AlreadyExists => (ErrorKind::AlreadyExists, false),
+        io::ErrorKind::TimedOut => (ErrorKind::TimedOut, true),
+        io::ErrorKind::Interrupted => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let mut err = Error::new(kind, "got rados error")
+        .with_context("operation", op)
+        .set_source(err);
+    if retryable {
+        err = err.set_temporary();
+    }
+    err
+}
@@ -28,6 +28,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::path::PathBuf;
 use std::time::Duration;
+use std::vec;
 use tokio::sync::OnceCell;
 
 use crate::raw::adapters::kv;
@@ -327,7 +328,7 @@ impl Adapter {
 }
 
 impl kv::Adapter for Adapter {
-    type Scanner = ();
+    type Scanner = kv::ScanStdIter<vec::IntoIter<Result<String>>>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -336,6 +337,9 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                write_with_expire_after: true,
+                // SCAN can't iterate keys across nodes in cluster mode.
+                list: self.cluster_client.is_none(),
                 shared: true,
 
                 ..Default::default()
@@ -356,6 +360,13 @@ impl kv::Adapter for Adapter {
         Ok(())
     }
 
+    async fn set_with_ttl(&self, key: &str, value: Buffer, ttl: Duration) -> Result<()> {
+        let mut conn = self.conn().await?;
+        let value = value.to_vec();
+        conn.set(key, value, Some(ttl)).await?;
+        Ok(())
+    }
+
     async fn delete(&self, key: &str) -> Result<()> {
         let mut conn = self.conn().await?;
         conn.delete(key).await?;
@@ -367,4 +378,12 @@ impl kv::Adapter for Adapter {
         conn.append(key, value).await?;
         Ok(())
     }
+
+    async fn scan(&self, path: &str) -> Result<Self::Scanner> {
+        let mut conn = self.conn().await?;
+        let keys = conn.scan(path).await?;
+        Ok(kv::ScanStdIter::new(
+            keys.into_iter().map(Ok).collect::<Vec<_>>().into_iter(),
+        ))
+    }
 }
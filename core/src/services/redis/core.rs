@@ -57,13 +57,15 @@ impl RedisConnection {
     ) -> crate::Result<()> {
         let value = value.to_vec();
         if let Some(ttl) = ttl {
+            // Use milliseconds and round it up, since redis rejects expire time of `0`.
+            let ttl = (ttl.as_nanos().div_ceil(1_000_000) as u64).max(1);
             match self {
                 RedisConnection::Normal(ref mut conn) => conn
-                    .set_ex(key, value, ttl.as_secs())
+                    .pset_ex(key, value, ttl)
                     .await
                     .map_err(format_redis_error)?,
                 RedisConnection::Cluster(ref mut conn) => conn
-                    .set_ex(key, value, ttl.as_secs())
+                    .pset_ex(key, value, ttl)
                     .await
                     .map_err(format_redis_error)?,
             }
@@ -94,6 +96,48 @@ impl RedisConnection {
         Ok(())
     }
 
+    /// Scan all keys that start with `prefix` via `SCAN`.
+    ///
+    /// Only supported for standalone redis, since `SCAN` only iterates the
+    /// keys of the node it's sent to.
+    pub async fn scan(&mut self, prefix: &str) -> crate::Result<Vec<String>> {
+        let conn = match self {
+            RedisConnection::Normal(ref mut conn) => conn,
+            RedisConnection::Cluster(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "scan is not supported for redis cluster",
+                ))
+            }
+        };
+
+        let pattern = format!("{}*", escape_glob_pattern(prefix));
+        let mut keys = Vec::new();
+        let mut cursor: u64 = 0;
+        loop {
+            let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+                .cursor_arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(1000)
+                .query_async(conn)
+                .await
+                .map_err(format_redis_error)?;
+            keys.extend(batch);
+
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+
+        // SCAN could return duplicated keys while rehashing.
+        keys.sort_unstable();
+        keys.dedup();
+        Ok(keys)
+    }
+
     pub async fn append(&mut self, key: &str, value: &[u8]) -> crate::Result<()> {
         match self {
             RedisConnection::Normal(ref mut conn) => {
@@ -161,8 +205,31 @@ impl bb8::ManageConnection for RedisConnectionManager {
     }
 }
 
+/// Escape the special chars of redis glob-style pattern.
+fn escape_glob_pattern(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
 pub fn format_redis_error(e: RedisError) -> Error {
     Error::new(ErrorKind::Unexpected, e.category())
         .set_source(e)
         .set_temporary()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_glob_pattern() {
+        assert_eq!(escape_glob_pattern("a/b/"), "a/b/");
+        assert_eq!(escape_glob_pattern("a*b?[c]\\"), "a\\*b\\?\\[c\\]\\\\");
+    }
+}
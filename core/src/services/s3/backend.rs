@@ -22,10 +22,14 @@ use std::fmt::Write;
 use std::str::FromStr;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use std::sync::RwLock;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use bytes::Buf;
 use constants::X_AMZ_META_PREFIX;
+use futures::StreamExt;
+use http::Request;
 use http::Response;
 use http::StatusCode;
 use log::debug;
@@ -39,10 +43,14 @@ use reqsign::AwsCredentialLoad;
 use reqsign::AwsDefaultLoader;
 use reqsign::AwsV4Signer;
 use reqwest::Url;
+use tokio::sync::Mutex;
 
 use super::core::*;
+use super::credential::SsoCredentialLoader;
 use super::delete::S3Deleter;
+use super::error::from_s3_error;
 use super::error::parse_error;
+use super::error::S3Error;
 use super::lister::{S3Lister, S3Listers, S3ObjectVersionsLister};
 use super::writer::S3Writer;
 use super::writer::S3Writers;
@@ -63,6 +71,13 @@ static ENDPOINT_TEMPLATES: Lazy<HashMap<&'static str, &'static str>> = Lazy::new
 });
 
 const DEFAULT_BATCH_MAX_OPERATIONS: usize = 1000;
+/// The min part size of S3 multipart upload.
+const MIN_COPY_PART_SIZE: u64 = 5 * 1024 * 1024;
+/// The max size of object that could be copied by `CopyObject`, also the max part size
+/// of S3 multipart upload.
+const MAX_COPY_PART_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+/// The max number of parts of S3 multipart upload.
+const MAX_COPY_PARTS: u64 = 10000;
 
 impl Configurator for S3Config {
     type Builder = S3Builder;
@@ -206,6 +221,35 @@ impl S3Builder {
         self
     }
 
+    /// Set web_identity_token_file for this backend.
+    ///
+    /// If `web_identity_token_file` is set along with `role_arn`, we will
+    /// assume role with web identity (a.k.a, EKS IRSA) and refresh the
+    /// credential before it expires.
+    pub fn web_identity_token_file(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.web_identity_token_file = Some(v.to_string())
+        }
+
+        self
+    }
+
+    /// Set sso_profile for this backend.
+    ///
+    /// If `sso_profile` is set, we will load credentials from the given AWS
+    /// SSO profile and refresh them before they expire. Users need to login
+    /// via `aws sso login --profile <sso_profile>` first.
+    ///
+    /// If `role_arn` is also set, SSO credentials will be used to assume the
+    /// role. It can't be used with `web_identity_token_file` and `role_arn`.
+    pub fn sso_profile(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.sso_profile = Some(v.to_string())
+        }
+
+        self
+    }
+
     /// Set default storage_class for this backend.
     ///
     /// Available values:
@@ -226,6 +270,21 @@ impl S3Builder {
         self
     }
 
+    /// Set request_payer for this backend.
+    ///
+    /// If set, OpenDAL will send `x-amz-request-payer` with this value in all
+    /// requests, which is required to access requester pays buckets like
+    /// public genomics datasets.
+    ///
+    /// Available values: `requester`.
+    pub fn request_payer(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.request_payer = Some(v.to_string())
+        }
+
+        self
+    }
+
     /// Set server_side_encryption for this backend.
     ///
     /// Available values: `AES256`, `aws:kms`.
@@ -415,6 +474,10 @@ impl S3Builder {
 
     /// Allow anonymous will allow opendal to send request without signing
     /// when credential is not loaded.
+    ///
+    /// This is useful to read public buckets (for example, open data registries)
+    /// without configuring dummy keys. Credentials will still be used if they
+    /// can be loaded; failing to load them falls back to anonymous access.
     pub fn allow_anonymous(mut self) -> Self {
         self.config.allow_anonymous = true;
         self
@@ -430,6 +493,19 @@ impl S3Builder {
         self
     }
 
+    /// Enable automatic region detection for this backend.
+    ///
+    /// - If region is not configured, opendal will start with `us-east-1` instead of
+    ///   returning an error.
+    /// - If S3 responds with `301 PermanentRedirect` or `400 AuthorizationHeaderMalformed`,
+    ///   opendal will discover the correct region via `x-amz-bucket-region` (sending a
+    ///   `HeadBucket` if needed), cache it on the backend and resend the request to the
+    ///   correct region. Other errors never trigger region detection.
+    pub fn enable_auto_detect_region(mut self) -> Self {
+        self.config.enable_auto_detect_region = true;
+        self
+    }
+
     /// Disable stat with override so that opendal will not send stat request with override queries.
     ///
     /// For example, R2 doesn't support stat with `response_content_type` query.
@@ -465,6 +541,30 @@ impl S3Builder {
         self
     }
 
+    /// Create the bucket if it doesn't exist before the first write.
+    ///
+    /// This is useful for local development and CI with services like minio,
+    /// which would fail with `NoSuchBucket` otherwise. The bucket will be
+    /// created with default settings, please create it manually for production.
+    pub fn enable_create_bucket(mut self) -> Self {
+        self.config.enable_create_bucket = true;
+        self
+    }
+
+    /// Enable S3 Express One Zone support for directory buckets.
+    ///
+    /// The bucket name must be in the format of `{base_name}--{zone_id}--x-s3`, for example
+    /// `mybucket--usw2-az1--x-s3`. Requests will be sent to the zonal endpoint
+    /// `https://{bucket}.s3express-{zone_id}.{region}.amazonaws.com` and signed with session
+    /// credentials created via `CreateSession`, which are refreshed automatically.
+    ///
+    /// Directory buckets don't support versioning, tagging, presign and `start_after` in list,
+    /// and list results are not returned in lexicographical order.
+    pub fn enable_s3_express(mut self) -> Self {
+        self.config.enable_s3_express = true;
+        self
+    }
+
     /// Check if `bucket` is valid
     /// `bucket` must be not empty and if `enable_virtual_host_style` is true
     /// it couldn't contain dot(.) character
@@ -547,6 +647,25 @@ impl S3Builder {
         self
     }
 
+    /// Set the part size of multipart copy.
+    ///
+    /// Objects rejected by `CopyObject` for their size will be copied via `UploadPartCopy`
+    /// with parts of this size. Must be between 5 MiB and 5 GiB, default to 5 GiB.
+    pub fn copy_part_size(mut self, copy_part_size: u64) -> Self {
+        self.config.copy_part_size = Some(copy_part_size);
+
+        self
+    }
+
+    /// Set the number of concurrent `UploadPartCopy` requests of multipart copy.
+    ///
+    /// Default to 1.
+    pub fn copy_concurrent(mut self, copy_concurrent: usize) -> Self {
+        self.config.copy_concurrent = Some(copy_concurrent);
+
+        self
+    }
+
     /// Set checksum algorithm of this backend.
     /// This is necessary when writing to AWS S3 Buckets with Object Lock enabled for example.
     ///
@@ -684,6 +803,10 @@ impl Builder for S3Builder {
     const SCHEME: Scheme = Scheme::S3;
     type Config = S3Config;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(mut self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
@@ -708,6 +831,13 @@ impl Builder for S3Builder {
             ),
         };
 
+        let request_payer = match &self.config.request_payer {
+            None => None,
+            Some(v) => Some(
+                build_header_value(v).map_err(|err| err.with_context("key", "request_payer"))?,
+            ),
+        };
+
         let server_side_encryption = match &self.config.server_side_encryption {
             None => None,
             Some(v) => Some(
@@ -774,12 +904,18 @@ impl Builder for S3Builder {
         }
 
         if cfg.region.is_none() {
-            return Err(Error::new(
-                ErrorKind::ConfigInvalid,
-                "region is missing. Please find it by S3::detect_region() or set them in env.",
-            )
-            .with_operation("Builder::build")
-            .with_context("service", Scheme::S3));
+            if !self.config.enable_auto_detect_region {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "region is missing. Please find it by S3::detect_region() or set them in env.",
+                )
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::S3));
+            }
+
+            // Start with the default region, the correct one will be detected while sending
+            // requests.
+            cfg.region = Some("us-east-1".to_string());
         }
 
         let region = cfg.region.to_owned().unwrap();
@@ -788,10 +924,36 @@ impl Builder for S3Builder {
         // Retain the user's endpoint if it exists; otherwise, try loading it from the environment.
         self.config.endpoint = self.config.endpoint.or_else(|| cfg.endpoint_url.clone());
 
+        // Directory buckets can only be accessed via the zonal endpoint in virtual host style.
+        if self.config.enable_s3_express {
+            let zone_id = parse_s3_express_zone_id(&self.config.bucket).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "The bucket name of s3 express must be {base_name}--{zone_id}--x-s3",
+                )
+                .with_context("service", Scheme::S3)
+                .with_context("bucket", &self.config.bucket)
+            })?;
+            if self.config.endpoint.is_none() {
+                self.config.endpoint = Some(format!(
+                    "https://s3express-{zone_id}.{region}.amazonaws.com"
+                ));
+            }
+            self.config.enable_virtual_host_style = true;
+            self.config.enable_auto_detect_region = false;
+        }
+
         // Building endpoint.
         let endpoint = self.build_endpoint(&region);
         debug!("backend use endpoint: {endpoint}");
 
+        // Only endpoints built from templates can be rebuilt with the detected region.
+        let endpoint_template = if self.config.enable_auto_detect_region {
+            Some(self.build_endpoint("{region}")).filter(|v| v.contains("{region}"))
+        } else {
+            None
+        };
+
         // Setting all value from user input if available.
         if let Some(v) = self.config.access_key_id {
             cfg.access_key_id = Some(v)
@@ -802,6 +964,9 @@ impl Builder for S3Builder {
         if let Some(v) = self.config.session_token {
             cfg.session_token = Some(v)
         }
+        if let Some(v) = self.config.web_identity_token_file.clone() {
+            cfg.web_identity_token_file = Some(v)
+        }
 
         let client = if let Some(client) = self.http_client {
             client
@@ -818,11 +983,51 @@ impl Builder for S3Builder {
             loader = Some(v);
         }
 
+        // Web identity doesn't use any source credential, sso_profile would be
+        // ignored silently.
+        if self.config.sso_profile.is_some()
+            && self.config.role_arn.is_some()
+            && self.config.web_identity_token_file.is_some()
+        {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "sso_profile can't be used with role_arn and web_identity_token_file",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", Scheme::S3));
+        }
+
+        // If sso_profile is set, we will use it as source credential loader.
+        let sso_loader = self
+            .config
+            .sso_profile
+            .as_deref()
+            .map(|profile| SsoCredentialLoader::new(&cfg.config_file, profile));
+
+        // If role_arn is set along with web_identity_token_file, default loader
+        // will assume role with web identity for us.
+        if let (Some(role_arn), Some(_)) =
+            (&self.config.role_arn, &self.config.web_identity_token_file)
+        {
+            cfg.role_arn = Some(role_arn.clone());
+            if let Some(name) = &self.config.role_session_name {
+                cfg.role_session_name = name.clone();
+            }
+            loader = Some(Box::new(AwsDefaultLoader::new(
+                GLOBAL_REQWEST_CLIENT.clone(),
+                cfg.clone(),
+            )));
+        }
         // If role_arn is set, we must use AssumeRoleLoad.
-        if let Some(role_arn) = self.config.role_arn {
-            // use current env as source credential loader.
-            let default_loader =
-                AwsDefaultLoader::new(GLOBAL_REQWEST_CLIENT.clone().clone(), cfg.clone());
+        else if let Some(role_arn) = self.config.role_arn {
+            // use sso profile or current env as source credential loader.
+            let source_loader: Box<dyn AwsCredentialLoad> = match sso_loader {
+                Some(v) => Box::new(v),
+                None => Box::new(AwsDefaultLoader::new(
+                    GLOBAL_REQWEST_CLIENT.clone(),
+                    cfg.clone(),
+                )),
+            };
 
             // Build the config for assume role.
             let mut assume_role_cfg = AwsConfig {
@@ -841,7 +1046,7 @@ impl Builder for S3Builder {
             let assume_role_loader = AwsAssumeRoleLoader::new(
                 GLOBAL_REQWEST_CLIENT.clone().clone(),
                 assume_role_cfg,
-                Box::new(default_loader),
+                source_loader,
             )
             .map_err(|err| {
                 Error::new(
@@ -852,6 +1057,8 @@ impl Builder for S3Builder {
                 .set_source(err)
             })?;
             loader = Some(Box::new(assume_role_loader));
+        } else if let Some(sso_loader) = sso_loader {
+            loader = Some(Box::new(sso_loader));
         }
         // If loader is not set, we will use default loader.
         let loader = match loader {
@@ -867,17 +1074,38 @@ impl Builder for S3Builder {
             }
         };
 
-        let signer = AwsV4Signer::new("s3", &region);
+        let signer = if self.config.enable_s3_express {
+            AwsV4Signer::new("s3express", &region)
+        } else {
+            AwsV4Signer::new("s3", &region)
+        };
 
         let delete_max_size = self
             .config
             .delete_max_size
             .unwrap_or(DEFAULT_BATCH_MAX_OPERATIONS);
 
+        let copy_part_size = self.config.copy_part_size.unwrap_or(MAX_COPY_PART_SIZE);
+        if !(MIN_COPY_PART_SIZE..=MAX_COPY_PART_SIZE).contains(&copy_part_size) {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "copy_part_size must be between 5 MiB and 5 GiB",
+            )
+            .with_context("service", Scheme::S3)
+            .with_context("copy_part_size", copy_part_size.to_string()));
+        }
+        let copy_concurrent = self.config.copy_concurrent.unwrap_or(1).max(1);
+
         Ok(S3Backend {
             core: Arc::new(S3Core {
                 bucket: bucket.to_string(),
-                endpoint,
+                location: RwLock::new(Arc::new(S3Location {
+                    region: region.clone(),
+                    endpoint,
+                    signer,
+                })),
+                endpoint_template,
+                enable_auto_detect_region: self.config.enable_auto_detect_region,
                 root,
                 server_side_encryption,
                 server_side_encryption_aws_kms_key_id,
@@ -885,16 +1113,22 @@ impl Builder for S3Builder {
                 server_side_encryption_customer_key,
                 server_side_encryption_customer_key_md5,
                 default_storage_class,
+                request_payer,
                 allow_anonymous: self.config.allow_anonymous,
                 disable_stat_with_override: self.config.disable_stat_with_override,
                 enable_versioning: self.config.enable_versioning,
-                signer,
                 loader,
                 credential_loaded: AtomicBool::new(false),
                 client,
                 checksum_algorithm,
                 delete_max_size,
+                copy_part_size,
+                copy_concurrent,
                 disable_write_with_if_match: self.config.disable_write_with_if_match,
+                enable_create_bucket: self.config.enable_create_bucket,
+                bucket_created: AtomicBool::new(false),
+                enable_s3_express: self.config.enable_s3_express,
+                s3_express_session: Mutex::new(None),
             }),
         })
     }
@@ -917,6 +1151,10 @@ impl Access for S3Backend {
     type BlockingDeleter = ();
 
     fn info(&self) -> Arc<AccessorInfo> {
+        // Directory buckets don't support versioning, tagging, presign and sse-c.
+        let express = self.core.enable_s3_express;
+        let versioning = self.core.enable_versioning && !express;
+
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::S3)
             .set_root(&self.core.root)
@@ -931,13 +1169,14 @@ impl Access for S3Backend {
                 stat_with_override_cache_control: !self.core.disable_stat_with_override,
                 stat_with_override_content_disposition: !self.core.disable_stat_with_override,
                 stat_with_override_content_type: !self.core.disable_stat_with_override,
-                stat_with_version: self.core.enable_versioning,
+                stat_with_version: versioning,
+                stat_with_server_side_encryption_customer_key: !express,
                 stat_has_cache_control: true,
                 stat_has_content_length: true,
                 stat_has_content_type: true,
                 stat_has_content_range: true,
                 stat_has_etag: true,
-                stat_has_content_md5: true,
+                stat_has_content_md5: !express,
                 stat_has_last_modified: true,
                 stat_has_content_disposition: true,
                 stat_has_user_metadata: true,
@@ -951,17 +1190,21 @@ impl Access for S3Backend {
                 read_with_override_cache_control: true,
                 read_with_override_content_disposition: true,
                 read_with_override_content_type: true,
-                read_with_version: self.core.enable_versioning,
+                read_with_version: versioning,
+                read_with_server_side_encryption_customer_key: !express,
 
                 write: true,
                 write_can_empty: true,
                 write_can_multi: true,
+                write_with_resume: true,
                 write_with_cache_control: true,
                 write_with_content_type: true,
                 write_with_content_encoding: true,
-                write_with_if_match: !self.core.disable_write_with_if_match,
+                write_with_if_match: !self.core.disable_write_with_if_match && !express,
                 write_with_if_not_exists: true,
                 write_with_user_metadata: true,
+                write_with_server_side_encryption: true,
+                write_with_server_side_encryption_customer_key: !express,
 
                 // The min multipart size of S3 is 5 MiB.
                 //
@@ -978,25 +1221,31 @@ impl Access for S3Backend {
 
                 delete: true,
                 delete_max_size: Some(self.core.delete_max_size),
-                delete_with_version: self.core.enable_versioning,
+                delete_with_version: versioning,
 
                 copy: true,
 
                 list: true,
                 list_with_limit: true,
-                list_with_start_after: true,
+                list_with_start_after: !express,
                 list_with_recursive: true,
-                list_with_versions: self.core.enable_versioning,
-                list_with_deleted: self.core.enable_versioning,
+                list_with_delimiter: true,
+                list_with_start_token: true,
+                list_with_versions: versioning,
+                list_with_deleted: versioning,
                 list_has_etag: true,
-                list_has_content_md5: true,
                 list_has_content_length: true,
                 list_has_last_modified: true,
 
-                presign: true,
-                presign_stat: true,
-                presign_read: true,
-                presign_write: true,
+                presign: !express,
+                presign_stat: !express,
+                presign_read: !express,
+                presign_write: !express,
+                presign_write_multi: !express,
+                presign_post: !express,
+                presign_with_headers: !express,
+
+                tag: !express,
 
                 shared: true,
 
@@ -1025,6 +1274,32 @@ impl Access for S3Backend {
                     meta.set_version(v);
                 }
 
+                // Etag is the md5 of content unless the object is encrypted by
+                // SSE-KMS or SSE-C.
+                let sse = parse_header_to_str(headers, constants::X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+                let sse_c = parse_header_to_str(
+                    headers,
+                    constants::X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
+                )?;
+                if meta.content_md5().is_none()
+                    && !self.core.enable_s3_express
+                    && sse_c.is_none()
+                    && !sse.is_some_and(|v| v.starts_with("aws:kms"))
+                {
+                    if let Some(v) = meta.etag().and_then(parse_content_md5_from_etag) {
+                        meta.set_content_md5(&v);
+                    }
+                }
+
+                // Only returned while checksum mode is enabled or the object is
+                // uploaded with additional checksums.
+                if let Some(v) = parse_header_to_str(headers, "x-amz-checksum-crc32c")? {
+                    meta.set_checksum_crc32c(v);
+                }
+                if let Some(v) = parse_header_to_str(headers, "x-amz-checksum-sha256")? {
+                    meta.set_checksum_sha256(v);
+                }
+
                 Ok(RpStat::new(meta))
             }
             _ => Err(parse_error(resp)),
@@ -1037,7 +1312,8 @@ impl Access for S3Backend {
         let status = resp.status();
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let etag = parse_etag(resp.headers())?.map(String::from);
+                Ok((RpRead::default().with_etag(etag), resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -1048,11 +1324,14 @@ impl Access for S3Backend {
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.core.s3_ensure_bucket().await?;
+
         let concurrent = args.concurrent();
         let executor = args.executor().cloned();
+        let progress = args.progress().cloned();
         let writer = S3Writer::new(self.core.clone(), path, args);
 
-        let w = oio::MultipartWriter::new(writer, executor, concurrent);
+        let w = oio::MultipartWriter::new(writer, executor, concurrent).with_progress(progress);
 
         Ok((RpWrite::default(), w))
     }
@@ -1065,35 +1344,99 @@ impl Access for S3Backend {
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let start_token = args.start_token().map(|v| v.to_string());
         let l = if args.versions() || args.deleted() {
-            TwoWays::Two(PageLister::new(S3ObjectVersionsLister::new(
-                self.core.clone(),
-                path,
-                args,
-            )))
+            TwoWays::Two(
+                PageLister::new(S3ObjectVersionsLister::new(self.core.clone(), path, args))
+                    .with_start_token(start_token.as_deref()),
+            )
         } else {
-            TwoWays::One(PageLister::new(S3Lister::new(
-                self.core.clone(),
-                path,
-                args,
-            )))
+            TwoWays::One(
+                PageLister::new(S3Lister::new(self.core.clone(), path, args))
+                    .with_start_token(start_token.as_deref()),
+            )
         };
 
         Ok((RpList::default(), l))
     }
 
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
+        self.core.s3_ensure_bucket().await?;
+
         let resp = self.core.s3_copy_object(from, to).await?;
+        if resp.status() == StatusCode::OK {
+            return Ok(RpCopy::default());
+        }
 
-        let status = resp.status();
+        // `CopyObject` can only copy objects up to 5 GiB, switch to multipart
+        // copy only if the service rejects the source for its size.
+        let err = parse_error(resp);
+        if !matches!(
+            err.service_code(),
+            Some("InvalidRequest" | "EntityTooLarge")
+        ) {
+            return Err(err);
+        }
 
-        match status {
-            StatusCode::OK => Ok(RpCopy::default()),
-            _ => Err(parse_error(resp)),
+        let resp = self.core.s3_head_object(from, OpStat::default()).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let size = parse_content_length(resp.headers())?.unwrap_or_default();
+        if size <= MAX_COPY_PART_SIZE {
+            return Err(err);
+        }
+
+        self.copy_multipart(from, to, size, resp.headers()).await?;
+        Ok(RpCopy::default())
+    }
+
+    async fn tag(&self, path: &str, args: OpTag) -> Result<RpTag> {
+        match args.into_operation() {
+            TagOperation::Get => {
+                let resp = self.core.s3_get_object_tagging(path).await?;
+                if resp.status() != StatusCode::OK {
+                    return Err(parse_error(resp));
+                }
+
+                let bs = resp.into_body();
+                let out: Tagging =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+                Ok(RpTag::new(
+                    out.tag_set
+                        .tag
+                        .into_iter()
+                        .map(|t| (t.key, t.value))
+                        .collect(),
+                ))
+            }
+            TagOperation::Set(tags) => {
+                let resp = self.core.s3_put_object_tagging(path, tags).await?;
+                match resp.status() {
+                    StatusCode::OK => Ok(RpTag::default()),
+                    _ => Err(parse_error(resp)),
+                }
+            }
+            TagOperation::Delete => {
+                let resp = self.core.s3_delete_object_tagging(path).await?;
+                match resp.status() {
+                    StatusCode::OK | StatusCode::NO_CONTENT => Ok(RpTag::default()),
+                    _ => Err(parse_error(resp)),
+                }
+            }
         }
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if self.core.enable_s3_express {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "presign is not supported by s3 express directory buckets",
+            ));
+        }
+
+        let headers = args.headers().clone();
         let (expire, op) = args.into_parts();
 
         // We will not send this request out, just for signing.
@@ -1103,12 +1446,45 @@ impl Access for S3Backend {
                 self.core
                     .s3_get_object_request(path, BytesRange::default(), &v)?
             }
-            PresignOperation::Write(_) => {
+            PresignOperation::Write(v) => {
                 self.core
-                    .s3_put_object_request(path, None, &OpWrite::default(), Buffer::new())?
+                    .s3_put_object_request(path, None, &v, Buffer::new())?
+            }
+            PresignOperation::InitiateMultipart(v) => {
+                self.core.s3_initiate_multipart_upload_request(path, &v)?
+            }
+            PresignOperation::UploadPart(v) => self.core.s3_upload_part_request(
+                path,
+                UploadPartArgs {
+                    upload_id: v.upload_id(),
+                    part_number: v.part_number(),
+                    size: v.content_length(),
+                    checksum: None,
+                    sse: SseArgs::default(),
+                },
+                Buffer::new(),
+            )?,
+            PresignOperation::CompleteMultipart(v) => self
+                .core
+                .s3_complete_multipart_upload_request(path, v.upload_id(), &OpWrite::default())?,
+            PresignOperation::Post(v) => {
+                // POST policy is signed inside the form fields instead of the request.
+                let fields = self.core.s3_presign_post(path, &v, expire).await?;
+                let req = Request::post(format!("{}/", self.core.endpoint()))
+                    .body(Buffer::new())
+                    .map_err(new_request_build_error)?;
+                let (parts, _) = req.into_parts();
+
+                return Ok(RpPresign::new(
+                    PresignedRequest::new(parts.method, parts.uri, parts.headers)
+                        .with_form_fields(fields),
+                ));
             }
         };
 
+        // Extra headers will be signed so that clients must send them as is.
+        req.headers_mut().extend(headers);
+
         self.core.sign_query(&mut req, expire).await?;
 
         // We don't need this request anymore, consume it directly.
@@ -1122,6 +1498,205 @@ impl Access for S3Backend {
     }
 }
 
+impl S3Backend {
+    /// Copy object via `UploadPartCopy` with parts of `copy_part_size`.
+    ///
+    /// All parts are pinned to the etag of the source returned by `headers`,
+    /// so the copy fails instead of mixing different versions of `from`.
+    async fn copy_multipart(
+        &self,
+        from: &str,
+        to: &str,
+        size: u64,
+        headers: &http::HeaderMap,
+    ) -> Result<()> {
+        // Keep the metadata of source object like `CopyObject` does.
+        let mut op = OpWrite::default();
+        if let Some(v) = parse_content_type(headers)? {
+            op = op.with_content_type(v);
+        }
+        if let Some(v) = parse_content_disposition(headers)? {
+            op = op.with_content_disposition(v);
+        }
+        if let Some(v) = parse_content_encoding(headers)? {
+            op = op.with_content_encoding(v);
+        }
+        if let Some(v) = parse_cache_control(headers)? {
+            op = op.with_cache_control(v);
+        }
+        let user_meta = parse_prefixed_headers(headers, X_AMZ_META_PREFIX);
+        if !user_meta.is_empty() {
+            op = op.with_user_metadata(user_meta);
+        }
+        let etag = parse_etag(headers)?.map(|v| v.to_string());
+        let tags = self.copy_source_tags(from, headers).await?;
+
+        let mut req = self.core.s3_initiate_multipart_upload_request(to, &op)?;
+        // S3 only returns the storage class for objects not in `STANDARD`.
+        if let Some(v) = headers.get(constants::X_AMZ_STORAGE_CLASS) {
+            req.headers_mut()
+                .insert(constants::X_AMZ_STORAGE_CLASS, v.clone());
+        }
+        self.core.sign(&mut req).await?;
+        let resp = self.core.send(req).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let result: InitiateMultipartUploadResult =
+            quick_xml::de::from_reader(resp.into_body().reader())
+                .map_err(new_xml_deserialize_error)?;
+        let upload_id = result.upload_id;
+
+        match self
+            .copy_parts(from, to, &upload_id, size, etag.as_deref())
+            .await
+        {
+            Ok(parts) => {
+                let resp = self
+                    .core
+                    .s3_complete_multipart_upload(to, &upload_id, parts, &op)
+                    .await?;
+                if resp.status() != StatusCode::OK {
+                    return Err(parse_error(resp));
+                }
+                // S3 might return error for status code 200.
+                let (parts, body) = resp.into_parts();
+                let maybe_error: S3Error =
+                    quick_xml::de::from_reader(body.reader()).map_err(new_xml_deserialize_error)?;
+                if !maybe_error.code.is_empty() {
+                    return Err(from_s3_error(maybe_error, parts));
+                }
+            }
+            Err(err) => {
+                // Abort the upload to avoid leaving parts behind, the copy error
+                // is more important than the abort one.
+                if let Err(abort_err) = self.core.s3_abort_multipart_upload(to, &upload_id).await {
+                    warn!("abort multipart copy of {to} failed: {abort_err:?}");
+                }
+                return Err(err);
+            }
+        }
+
+        // `CompleteMultipartUpload` doesn't accept tags, set them afterwards.
+        if !tags.is_empty() {
+            let resp = self.core.s3_put_object_tagging(to, tags).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetch the tags of `from` if the source has any.
+    async fn copy_source_tags(
+        &self,
+        from: &str,
+        headers: &http::HeaderMap,
+    ) -> Result<HashMap<String, String>> {
+        let count = headers
+            .get(constants::X_AMZ_TAGGING_COUNT)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or_default();
+        if count == 0 {
+            return Ok(HashMap::new());
+        }
+
+        let resp = self.core.s3_get_object_tagging(from).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let out: Tagging = quick_xml::de::from_reader(resp.into_body().reader())
+            .map_err(new_xml_deserialize_error)?;
+        Ok(out
+            .tag_set
+            .tag
+            .into_iter()
+            .map(|t| (t.key, t.value))
+            .collect())
+    }
+
+    /// Copy all parts of `from` into the upload concurrently.
+    async fn copy_parts(
+        &self,
+        from: &str,
+        to: &str,
+        upload_id: &str,
+        size: u64,
+        etag: Option<&str>,
+    ) -> Result<Vec<CompleteMultipartUploadRequestPart>> {
+        // Enlarge the part size to make sure the parts won't exceed the limit.
+        let part_size = self.core.copy_part_size.max(size.div_ceil(MAX_COPY_PARTS));
+
+        let mut tasks: ConcurrentFutures<
+            BoxedStaticFuture<Result<CompleteMultipartUploadRequestPart>>,
+        > = ConcurrentFutures::new(self.core.copy_concurrent);
+        let mut parts = Vec::with_capacity(size.div_ceil(part_size) as usize);
+        let mut offset = 0;
+        let mut part_number = 0;
+
+        loop {
+            if offset < size && tasks.has_remaining() {
+                let n = part_size.min(size - offset);
+                let range = BytesRange::new(offset, Some(n));
+                offset += n;
+                // AWS S3 requires part number must between [1..=10000]
+                part_number += 1;
+
+                let core = self.core.clone();
+                let (from, to, upload_id) =
+                    (from.to_string(), to.to_string(), upload_id.to_string());
+                let etag = etag.map(|v| v.to_string());
+                tasks.push_back(Box::pin(async move {
+                    let resp = core
+                        .s3_upload_part_copy(
+                            &from,
+                            &to,
+                            &upload_id,
+                            part_number,
+                            range,
+                            etag.as_deref(),
+                        )
+                        .await?;
+                    if resp.status() != StatusCode::OK {
+                        return Err(parse_error(resp));
+                    }
+
+                    // S3 might return error for status code 200.
+                    let (parts, body) = resp.into_parts();
+                    let result: CopyPartResult = quick_xml::de::from_reader(body.clone().reader())
+                        .map_err(new_xml_deserialize_error)?;
+                    if result.etag.is_empty() {
+                        let err: S3Error = quick_xml::de::from_reader(body.reader())
+                            .map_err(new_xml_deserialize_error)?;
+                        return Err(from_s3_error(err, parts));
+                    }
+
+                    Ok(CompleteMultipartUploadRequestPart {
+                        part_number,
+                        etag: result.etag,
+                        checksum_crc32c: result.checksum_crc32c,
+                    })
+                }));
+                continue;
+            }
+
+            match tasks.next().await {
+                Some(part) => parts.push(part?),
+                None => return Ok(parts),
+            }
+        }
+    }
+}
+
+/// Parse the zone id from the name of S3 Express directory bucket.
+///
+/// The bucket name is in the format of `{base_name}--{zone_id}--x-s3`.
+fn parse_s3_express_zone_id(bucket: &str) -> Option<&str> {
+    let (_, zone_id) = bucket.strip_suffix("--x-s3")?.rsplit_once("--")?;
+    (!zone_id.is_empty()).then_some(zone_id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1147,6 +1722,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_build_sso_profile_with_web_identity() {
+        let err = S3Builder::default()
+            .bucket("test")
+            .region("us-east-1")
+            .disable_config_load()
+            .role_arn("arn:aws:iam::123456789012:role/test")
+            .web_identity_token_file("/tmp/token")
+            .sso_profile("dev")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
     #[test]
     fn test_build_endpoint() {
         let _ = tracing_subscriber::fmt().with_test_writer().try_init();
@@ -1181,6 +1770,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_s3_express_zone_id() {
+        let cases = vec![
+            ("mybucket--usw2-az1--x-s3", Some("usw2-az1")),
+            ("my--bucket--use1-az4--x-s3", Some("use1-az4")),
+            ("mybucket--x-s3", None),
+            ("mybucket----x-s3", None),
+            ("mybucket", None),
+        ];
+
+        for (bucket, expected) in cases {
+            assert_eq!(parse_s3_express_zone_id(bucket), expected, "{bucket}");
+        }
+    }
+
+    #[test]
+    fn test_build_endpoint_template() {
+        let b = S3Builder::default().bucket("test");
+        assert_eq!(
+            b.build_endpoint("{region}"),
+            "https://s3.{region}.amazonaws.com/test"
+        );
+
+        // Custom endpoints can't be rebuilt with region.
+        let b = S3Builder::default()
+            .bucket("test")
+            .endpoint("http://127.0.0.1:9000");
+        assert!(!b.build_endpoint("{region}").contains("{region}"));
+    }
+
     #[tokio::test]
     async fn test_detect_region() {
         let cases = vec![
@@ -87,6 +87,24 @@ pub struct S3Config {
     pub external_id: Option<String>,
     /// role_session_name for this backend.
     pub role_session_name: Option<String>,
+    /// web_identity_token_file for this backend.
+    ///
+    /// If `web_identity_token_file` is set along with `role_arn`, we will
+    /// assume role with web identity (a.k.a, EKS IRSA) instead of assume role
+    /// with already known config.
+    ///
+    /// It's not required to set this option if `AWS_WEB_IDENTITY_TOKEN_FILE`
+    /// and `AWS_ROLE_ARN` have been set in env.
+    pub web_identity_token_file: Option<String>,
+    /// sso_profile for this backend.
+    ///
+    /// If `sso_profile` is set, we will load credentials from the given AWS
+    /// SSO profile in aws config file. Users need to login via
+    /// `aws sso login --profile <sso_profile>` first.
+    ///
+    /// If `role_arn` is also set, SSO credentials will be used to assume the
+    /// role. It can't be used with `web_identity_token_file` and `role_arn`.
+    pub sso_profile: Option<String>,
     /// Disable config load so that opendal will not load config from
     /// environment.
     ///
@@ -102,6 +120,10 @@ pub struct S3Config {
     pub disable_ec2_metadata: bool,
     /// Allow anonymous will allow opendal to send request without signing
     /// when credential is not loaded.
+    ///
+    /// This is useful to read public buckets (for example, open data registries)
+    /// without configuring dummy keys. Credentials will still be used if they
+    /// can be loaded; failing to load them falls back to anonymous access.
     pub allow_anonymous: bool,
     /// server_side_encryption for this backend.
     ///
@@ -150,12 +172,28 @@ pub struct S3Config {
     ///
     /// S3 compatible services don't support all of them
     pub default_storage_class: Option<String>,
+    /// request_payer for this backend.
+    ///
+    /// If set, OpenDAL will send `x-amz-request-payer` with this value in all
+    /// requests, which is required to access requester pays buckets.
+    ///
+    /// Available values: `requester`.
+    pub request_payer: Option<String>,
     /// Enable virtual host style so that opendal will send API requests
     /// in virtual host style instead of path style.
     ///
     /// - By default, opendal will send API to `https://s3.us-east-1.amazonaws.com/bucket_name`
     /// - Enabled, opendal will send API to `https://bucket_name.s3.us-east-1.amazonaws.com`
     pub enable_virtual_host_style: bool,
+    /// Enable automatic region detection for this backend.
+    ///
+    /// - If region is not configured, opendal will start with `us-east-1` instead of
+    ///   returning an error.
+    /// - If S3 responds with `301 PermanentRedirect` or `400 AuthorizationHeaderMalformed`,
+    ///   opendal will discover the correct region via `x-amz-bucket-region` (sending a
+    ///   `HeadBucket` if needed), cache it on the backend and resend the request to the
+    ///   correct region. Other errors never trigger region detection.
+    pub enable_auto_detect_region: bool,
     /// Set maximum batch operations of this backend.
     ///
     /// Some compatible services have a limit on the number of operations in a batch request.
@@ -174,6 +212,17 @@ pub struct S3Config {
     ///
     /// Please tune this value based on services' document.
     pub delete_max_size: Option<usize>,
+    /// Set the part size of multipart copy.
+    ///
+    /// Objects rejected by `CopyObject` for their size (larger than 5 GiB) will be
+    /// copied via `UploadPartCopy` with parts of this size.
+    ///
+    /// Must be between 5 MiB and 5 GiB, default to 5 GiB.
+    pub copy_part_size: Option<u64>,
+    /// Set the number of concurrent `UploadPartCopy` requests of multipart copy.
+    ///
+    /// Default to 1.
+    pub copy_concurrent: Option<usize>,
     /// Disable stat with override so that opendal will not send stat request with override queries.
     ///
     /// For example, R2 doesn't support stat with `response_content_type` query.
@@ -188,6 +237,17 @@ pub struct S3Config {
     ///
     /// For example, Ceph RADOS S3 doesn't support write with if match.
     pub disable_write_with_if_match: bool,
+    /// Create the bucket if it doesn't exist before the first write.
+    ///
+    /// This is useful for local development and CI with services like minio.
+    /// The bucket will be created with default settings.
+    pub enable_create_bucket: bool,
+    /// Enable S3 Express One Zone support for directory buckets.
+    ///
+    /// The bucket name must be in the format of `{base_name}--{zone_id}--x-s3`. Requests
+    /// will be sent to the zonal endpoint and signed by the session credentials created
+    /// via `CreateSession`.
+    pub enable_s3_express: bool,
 }
 
 impl Debug for S3Config {
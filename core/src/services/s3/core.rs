@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Display;
@@ -22,12 +23,19 @@ use std::fmt::Formatter;
 use std::fmt::Write;
 use std::sync::atomic;
 use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::sync::RwLock;
 use std::time::Duration;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use bytes::Buf;
 use bytes::Bytes;
+use chrono::Utc;
 use constants::X_AMZ_META_PREFIX;
+use hmac::Hmac;
+use hmac::Mac;
+use http::header::AUTHORIZATION;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
 use http::header::CONTENT_ENCODING;
@@ -40,17 +48,28 @@ use http::header::{HeaderName, IF_MODIFIED_SINCE, IF_UNMODIFIED_SINCE};
 use http::HeaderValue;
 use http::Request;
 use http::Response;
+use http::StatusCode;
+use md5::Digest;
+use md5::Md5;
 use reqsign::AwsCredential;
 use reqsign::AwsCredentialLoad;
 use reqsign::AwsV4Signer;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::json;
+use sha2::Sha256;
+use tokio::sync::Mutex;
 
+use super::error::from_s3_error;
+use super::error::parse_error;
+use super::error::S3Error;
 use crate::raw::*;
 use crate::*;
 
 pub mod constants {
     pub const X_AMZ_COPY_SOURCE: &str = "x-amz-copy-source";
+    pub const X_AMZ_COPY_SOURCE_RANGE: &str = "x-amz-copy-source-range";
+    pub const X_AMZ_COPY_SOURCE_IF_MATCH: &str = "x-amz-copy-source-if-match";
 
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION: &str = "x-amz-server-side-encryption";
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
@@ -62,6 +81,10 @@ pub mod constants {
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID: &str =
         "x-amz-server-side-encryption-aws-kms-key-id";
     pub const X_AMZ_STORAGE_CLASS: &str = "x-amz-storage-class";
+    pub const X_AMZ_REQUEST_PAYER: &str = "x-amz-request-payer";
+    pub const X_AMZ_DELETE_MARKER: &str = "x-amz-delete-marker";
+    pub const X_AMZ_S3SESSION_TOKEN: &str = "x-amz-s3session-token";
+    pub const X_AMZ_TAGGING_COUNT: &str = "x-amz-tagging-count";
 
     pub const X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
         "x-amz-copy-source-server-side-encryption-customer-algorithm";
@@ -79,9 +102,22 @@ pub mod constants {
     pub const S3_QUERY_VERSION_ID: &str = "versionId";
 }
 
+/// The region related states of a bucket.
+///
+/// They will be replaced together once a new region has been detected.
+pub struct S3Location {
+    pub region: String,
+    pub endpoint: String,
+    pub signer: AwsV4Signer,
+}
+
 pub struct S3Core {
     pub bucket: String,
-    pub endpoint: String,
+    pub location: RwLock<Arc<S3Location>>,
+    /// The endpoint template with `{region}` placeholder, used to rebuild endpoint
+    /// for the detected region.
+    pub endpoint_template: Option<String>,
+    pub enable_auto_detect_region: bool,
     pub root: String,
     pub server_side_encryption: Option<HeaderValue>,
     pub server_side_encryption_aws_kms_key_id: Option<HeaderValue>,
@@ -89,37 +125,81 @@ pub struct S3Core {
     pub server_side_encryption_customer_key: Option<HeaderValue>,
     pub server_side_encryption_customer_key_md5: Option<HeaderValue>,
     pub default_storage_class: Option<HeaderValue>,
+    pub request_payer: Option<HeaderValue>,
     pub allow_anonymous: bool,
     pub disable_stat_with_override: bool,
     pub enable_versioning: bool,
 
-    pub signer: AwsV4Signer,
     pub loader: Box<dyn AwsCredentialLoad>,
     pub credential_loaded: AtomicBool,
     pub client: HttpClient,
     pub delete_max_size: usize,
+    pub copy_part_size: u64,
+    pub copy_concurrent: usize,
     pub checksum_algorithm: Option<ChecksumAlgorithm>,
     pub disable_write_with_if_match: bool,
+    pub enable_create_bucket: bool,
+    /// Whether the bucket has been checked or created by this backend.
+    pub bucket_created: AtomicBool,
+    /// Whether the bucket is a S3 Express One Zone directory bucket.
+    pub enable_s3_express: bool,
+    /// The cached session credential of S3 Express, refreshed before it expires.
+    ///
+    /// The lock is held while refreshing so that concurrent requests share the
+    /// same `CreateSession` call.
+    pub s3_express_session: Mutex<Option<AwsCredential>>,
 }
 
 impl Debug for S3Core {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("S3Core")
             .field("bucket", &self.bucket)
-            .field("endpoint", &self.endpoint)
+            .field("endpoint", &self.endpoint())
             .field("root", &self.root)
             .finish_non_exhaustive()
     }
 }
 
 impl S3Core {
+    /// Get the current region related states.
+    pub fn location(&self) -> Arc<S3Location> {
+        self.location.read().expect("lock must be valid").clone()
+    }
+
+    /// Get the endpoint of current region.
+    pub fn endpoint(&self) -> String {
+        self.location().endpoint.clone()
+    }
+
+    /// The service name used while signing requests.
+    pub fn signing_name(&self) -> &'static str {
+        if self.enable_s3_express {
+            "s3express"
+        } else {
+            "s3"
+        }
+    }
+
     /// If credential is not found, we will not sign the request.
     async fn load_credential(&self) -> Result<Option<AwsCredential>> {
-        let cred = self
+        let cred = match self
             .loader
             .load_credential(GLOBAL_REQWEST_CLIENT.clone())
             .await
-            .map_err(new_request_credential_error)?;
+        {
+            Ok(cred) => cred,
+            // Users allow anonymous access and no credential has ever been loaded: fallback
+            // to anonymous access instead of failing, for example, while the ec2 metadata
+            // service is not reachable outside AWS.
+            Err(err)
+                if self.allow_anonymous
+                    && !self.credential_loaded.load(atomic::Ordering::Relaxed) =>
+            {
+                log::debug!("load s3 credential failed, fallback to anonymous access: {err:?}");
+                None
+            }
+            Err(err) => return Err(new_request_credential_error(err)),
+        };
 
         if let Some(cred) = cred {
             // Update credential_loaded to true if we have load credential successfully.
@@ -150,13 +230,31 @@ impl S3Core {
     }
 
     pub async fn sign<T>(&self, req: &mut Request<T>) -> Result<()> {
+        self.insert_request_payer_header(req);
+
         let cred = if let Some(cred) = self.load_credential().await? {
             cred
         } else {
             return Ok(());
         };
 
-        self.signer
+        // Directory buckets are accessed with session credentials, whose token is
+        // carried by `x-amz-s3session-token` instead of `x-amz-security-token`.
+        let cred = if self.enable_s3_express {
+            let mut session = self.load_s3_express_session(&cred).await?;
+            if let Some(token) = session.session_token.take() {
+                req.headers_mut().insert(
+                    HeaderName::from_static(constants::X_AMZ_S3SESSION_TOKEN),
+                    build_header_value(&token)?,
+                );
+            }
+            session
+        } else {
+            cred
+        };
+
+        self.location()
+            .signer
             .sign(req, &cred)
             .map_err(new_request_sign_error)?;
 
@@ -172,13 +270,16 @@ impl S3Core {
     }
 
     pub async fn sign_query<T>(&self, req: &mut Request<T>, duration: Duration) -> Result<()> {
+        self.insert_request_payer_header(req);
+
         let cred = if let Some(cred) = self.load_credential().await? {
             cred
         } else {
             return Ok(());
         };
 
-        self.signer
+        self.location()
+            .signer
             .sign_query(req, duration, &cred)
             .map_err(new_request_sign_error)?;
 
@@ -193,39 +294,318 @@ impl S3Core {
         Ok(())
     }
 
+    /// Load the session credential of the S3 Express directory bucket.
+    ///
+    /// Sessions are valid for 5 minutes, we will create a new one via `CreateSession`
+    /// if the cached session is going to expire in 1 minute. Only one request will
+    /// refresh the session at a time, others will wait and reuse the new session.
+    ///
+    /// Reference: <https://docs.aws.amazon.com/AmazonS3/latest/API/API_CreateSession.html>
+    async fn load_s3_express_session(&self, cred: &AwsCredential) -> Result<AwsCredential> {
+        let mut cached = self.s3_express_session.lock().await;
+        if let Some(session) = cached.as_ref() {
+            if session
+                .expires_in
+                .is_some_and(|v| v > Utc::now() + chrono::Duration::seconds(60))
+            {
+                return Ok(session.clone());
+            }
+        }
+
+        let url = format!("{}/?session", self.endpoint());
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+        self.location()
+            .signer
+            .sign(&mut req, cred)
+            .map_err(new_request_sign_error)?;
+        req.headers_mut().remove(HOST);
+
+        let resp = self.client.send(req).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+
+        let output: CreateSessionOutput = quick_xml::de::from_reader(resp.into_body().reader())
+            .map_err(new_xml_deserialize_error)?;
+        let session = AwsCredential {
+            access_key_id: output.credentials.access_key_id,
+            secret_access_key: output.credentials.secret_access_key,
+            session_token: Some(output.credentials.session_token),
+            expires_in: Some(parse_datetime_from_rfc3339(&output.credentials.expiration)?),
+        };
+
+        *cached = Some(session.clone());
+        Ok(session)
+    }
+
+    /// Build the form fields of a POST policy signed by AWS Signature Version 4.
+    ///
+    /// Reference: <https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-HTTPPOSTConstructPolicy.html>
+    pub async fn s3_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+        expire: Duration,
+    ) -> Result<Vec<(String, String)>> {
+        let Some(cred) = self.load_credential().await? else {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "presign post policy requires valid credential",
+            ));
+        };
+        let location = self.location();
+        let region = &location.region;
+
+        let now = Utc::now();
+        let date = now.format("%Y%m%d").to_string();
+        let expire = chrono::Duration::from_std(expire).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "expire of presign is out of range").set_source(err)
+        })?;
+        let expiration = (now + expire).format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+
+        let p = build_abs_path(&self.root, path);
+
+        let mut fields = Vec::new();
+        let mut conditions = vec![json!({ "bucket": self.bucket })];
+        if args.key_prefix() {
+            conditions.push(json!(["starts-with", "$key", p]));
+            fields.push(("key".to_string(), format!("{p}${{filename}}")));
+        } else {
+            conditions.push(json!({ "key": p }));
+            fields.push(("key".to_string(), p));
+        }
+        if let Some(v) = args.content_type() {
+            conditions.push(json!({ "Content-Type": v }));
+            fields.push(("Content-Type".to_string(), v.to_string()));
+        }
+        if let Some(v) = args.content_type_prefix() {
+            conditions.push(json!(["starts-with", "$Content-Type", v]));
+        }
+        if let Some((min, max)) = args.content_length_range() {
+            conditions.push(json!(["content-length-range", min, max]));
+        }
+
+        let mut amz_fields = vec![
+            ("x-amz-algorithm", "AWS4-HMAC-SHA256".to_string()),
+            (
+                "x-amz-credential",
+                format!("{}/{date}/{}/s3/aws4_request", cred.access_key_id, region),
+            ),
+            ("x-amz-date", now.format("%Y%m%dT%H%M%SZ").to_string()),
+        ];
+        if let Some(token) = &cred.session_token {
+            amz_fields.push(("x-amz-security-token", token.to_string()));
+        }
+        for (k, v) in amz_fields {
+            conditions.push(json!({ k: v }));
+            fields.push((k.to_string(), v));
+        }
+
+        let policy = json!({
+            "expiration": expiration,
+            "conditions": conditions,
+        });
+        let policy = BASE64_STANDARD.encode(policy.to_string());
+
+        let signing_key = aws_v4_signing_key(&cred.secret_access_key, &date, region, "s3");
+        let signature =
+            hmac_sha256(&signing_key, policy.as_bytes())
+                .iter()
+                .fold(String::new(), |mut s, b| {
+                    let _ = write!(s, "{b:02x}");
+                    s
+                });
+
+        fields.push(("policy".to_string(), policy));
+        fields.push(("x-amz-signature".to_string(), signature));
+        Ok(fields)
+    }
+
+    /// Set `x-amz-request-payer` for all requests if request payer is configured.
+    ///
+    /// The header must be set before signing so that it will be signed too.
+    fn insert_request_payer_header<T>(&self, req: &mut Request<T>) {
+        if let Some(v) = &self.request_payer {
+            req.headers_mut().insert(
+                HeaderName::from_static(constants::X_AMZ_REQUEST_PAYER),
+                v.clone(),
+            );
+        }
+    }
+
     #[inline]
     pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
-        self.client.send(req).await
+        if !self.enable_auto_detect_region {
+            return self.client.send(req).await;
+        }
+
+        // Keep a copy of the request so that we can resend it to the correct region.
+        let mut retry = Request::builder()
+            .method(req.method().clone())
+            .uri(req.uri().clone())
+            .version(req.version())
+            .body(req.body().clone())
+            .map_err(new_request_build_error)?;
+        *retry.headers_mut() = req.headers().clone();
+
+        let resp = self.client.send(req).await?;
+        let Some(region) = self.detect_region_mismatch(&resp).await else {
+            return Ok(resp);
+        };
+
+        let old = self.location();
+        let new = self.update_region(&region);
+        log::debug!(
+            "s3 bucket region changed from {} to {}",
+            old.region,
+            new.region
+        );
+
+        // Rebuild the request with the new endpoint and sign it again.
+        let uri = retry.uri().to_string();
+        let uri = match uri.strip_prefix(&old.endpoint) {
+            Some(rest) => format!("{}{rest}", new.endpoint),
+            None => uri,
+        };
+        *retry.uri_mut() = uri.parse().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "rebuild request uri failed").set_source(err)
+        })?;
+        for key in [
+            AUTHORIZATION.as_str(),
+            "x-amz-date",
+            "x-amz-content-sha256",
+            "x-amz-security-token",
+        ] {
+            retry.headers_mut().remove(key);
+        }
+        self.sign(&mut retry).await?;
+
+        self.client.send(retry).await
+    }
+
+    /// Check if the response tells us that the bucket lives in another region.
+    ///
+    /// Returns the correct region if we found a mismatch.
+    async fn detect_region_mismatch(&self, resp: &Response<Buffer>) -> Option<String> {
+        if !is_region_mismatch(resp) {
+            return None;
+        }
+
+        let region = match parse_bucket_region(resp.headers()) {
+            Some(v) => v,
+            None => {
+                // Send a `HeadBucket` to get the region of bucket.
+                let req = Request::head(self.endpoint()).body(Buffer::new()).ok()?;
+                let resp = self
+                    .client
+                    .send(req)
+                    .await
+                    .map_err(|err| log::warn!("detect s3 bucket region failed: {err:?}"))
+                    .ok()?;
+                parse_bucket_region(resp.headers())?
+            }
+        };
+
+        (region != self.location().region).then_some(region)
+    }
+
+    /// Update the region of this bucket and return the new location.
+    fn update_region(&self, region: &str) -> Arc<S3Location> {
+        let mut location = self.location.write().expect("lock must be valid");
+        // Another request could have updated the region already.
+        if location.region != region {
+            let endpoint = match &self.endpoint_template {
+                Some(template) => template.replace("{region}", region),
+                None => location.endpoint.clone(),
+            };
+            *location = Arc::new(S3Location {
+                region: region.to_string(),
+                endpoint,
+                signer: AwsV4Signer::new(self.signing_name(), region),
+            });
+        }
+        location.clone()
     }
 
     /// # Note
     ///
     /// header like X_AMZ_SERVER_SIDE_ENCRYPTION doesn't need to set while
     /// get or stat.
+    ///
+    /// Settings in `args` take precedence over the ones configured in builder.
     pub fn insert_sse_headers(
         &self,
         mut req: http::request::Builder,
         is_write: bool,
-    ) -> http::request::Builder {
+        args: SseArgs<'_>,
+    ) -> Result<http::request::Builder> {
         if is_write {
-            if let Some(v) = &self.server_side_encryption {
-                let mut v = v.clone();
-                v.set_sensitive(true);
-
+            // Request level kms key id implies `aws:kms`.
+            let server_side_encryption = args
+                .server_side_encryption
+                .or(args.aws_kms_key_id.map(|_| "aws:kms"));
+            if let Some(v) = server_side_encryption {
                 req = req.header(
                     HeaderName::from_static(constants::X_AMZ_SERVER_SIDE_ENCRYPTION),
-                    v,
-                )
+                    sensitive_header_value(v)?,
+                );
+                if let Some(v) = args.aws_kms_key_id {
+                    req = req.header(
+                        HeaderName::from_static(
+                            constants::X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID,
+                        ),
+                        sensitive_header_value(v)?,
+                    );
+                }
+            } else if args.customer_key.is_none() {
+                if let Some(v) = &self.server_side_encryption {
+                    let mut v = v.clone();
+                    v.set_sensitive(true);
+
+                    req = req.header(
+                        HeaderName::from_static(constants::X_AMZ_SERVER_SIDE_ENCRYPTION),
+                        v,
+                    )
+                }
+                if let Some(v) = &self.server_side_encryption_aws_kms_key_id {
+                    let mut v = v.clone();
+                    v.set_sensitive(true);
+
+                    req = req.header(
+                        HeaderName::from_static(
+                            constants::X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID,
+                        ),
+                        v,
+                    )
+                }
             }
-            if let Some(v) = &self.server_side_encryption_aws_kms_key_id {
-                let mut v = v.clone();
-                v.set_sensitive(true);
+        }
 
-                req = req.header(
-                    HeaderName::from_static(constants::X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID),
-                    v,
+        if let Some(key) = args.customer_key {
+            let decoded = BASE64_STANDARD.decode(key).map_err(|err| {
+                Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "server_side_encryption_customer_key is not valid base64",
                 )
-            }
+                .set_source(err)
+            })?;
+            let key_md5 = BASE64_STANDARD.encode(Md5::digest(&decoded).as_slice());
+
+            req = req.header(
+                HeaderName::from_static(constants::X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM),
+                sensitive_header_value("AES256")?,
+            );
+            req = req.header(
+                HeaderName::from_static(constants::X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY),
+                sensitive_header_value(key)?,
+            );
+            req = req.header(
+                HeaderName::from_static(constants::X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5),
+                sensitive_header_value(&key_md5)?,
+            );
+            return Ok(req);
         }
 
         if let Some(v) = &self.server_side_encryption_customer_algorithm {
@@ -256,7 +636,7 @@ impl S3Core {
             )
         }
 
-        req
+        Ok(req)
     }
     pub fn calculate_checksum(&self, body: &Buffer) -> Option<String> {
         match self.checksum_algorithm {
@@ -295,7 +675,7 @@ impl S3Core {
     pub fn s3_head_object_request(&self, path: &str, args: OpStat) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let mut url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        let mut url = format!("{}/{}", self.endpoint(), percent_encode_path(&p));
 
         // Add query arguments to the URL based on response overrides
         let mut query_args = Vec::new();
@@ -333,7 +713,7 @@ impl S3Core {
 
         let mut req = Request::head(&url);
 
-        req = self.insert_sse_headers(req, false);
+        req = self.insert_sse_headers(req, false, SseArgs::from(&args))?;
 
         if let Some(if_none_match) = args.if_none_match() {
             req = req.header(IF_NONE_MATCH, if_none_match);
@@ -369,7 +749,7 @@ impl S3Core {
         let p = build_abs_path(&self.root, path);
 
         // Construct headers to add to the request
-        let mut url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        let mut url = format!("{}/{}", self.endpoint(), percent_encode_path(&p));
 
         // Add query arguments to the URL based on response overrides
         let mut query_args = Vec::new();
@@ -435,7 +815,7 @@ impl S3Core {
 
         // Set SSE headers.
         // TODO: how will this work with presign?
-        req = self.insert_sse_headers(req, false);
+        req = self.insert_sse_headers(req, false, SseArgs::from(args))?;
 
         let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
 
@@ -464,7 +844,7 @@ impl S3Core {
     ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        let url = format!("{}/{}", self.endpoint(), percent_encode_path(&p));
 
         let mut req = Request::put(&url);
 
@@ -509,7 +889,7 @@ impl S3Core {
         }
 
         // Set SSE headers.
-        req = self.insert_sse_headers(req, true);
+        req = self.insert_sse_headers(req, true, SseArgs::from(args))?;
 
         // Calculate Checksum.
         if let Some(checksum) = self.calculate_checksum(&body) {
@@ -534,7 +914,7 @@ impl S3Core {
     pub async fn s3_delete_object(&self, path: &str, args: &OpDelete) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let mut url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        let mut url = format!("{}/{}", self.endpoint(), percent_encode_path(&p));
 
         let mut query_args = Vec::new();
 
@@ -564,13 +944,80 @@ impl S3Core {
         let to = build_abs_path(&self.root, to);
 
         let source = format!("{}/{}", self.bucket, percent_encode_path(&from));
-        let target = format!("{}/{}", self.endpoint, percent_encode_path(&to));
+        let target = format!("{}/{}", self.endpoint(), percent_encode_path(&to));
 
         let mut req = Request::put(&target);
 
         // Set SSE headers.
-        req = self.insert_sse_headers(req, true);
+        req = self.insert_sse_headers(req, true, SseArgs::default())?;
+
+        req = self.insert_copy_source_sse_headers(req);
+
+        let mut req = req
+            .header(constants::X_AMZ_COPY_SOURCE, &source)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    /// Copy a range of `from` as a part of the multipart upload of `to`.
+    ///
+    /// The copy fails with `412` if `if_match` is set and doesn't match the
+    /// etag of `from`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn s3_upload_part_copy(
+        &self,
+        from: &str,
+        to: &str,
+        upload_id: &str,
+        part_number: usize,
+        range: BytesRange,
+        if_match: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+
+        let source = format!("{}/{}", self.bucket, percent_encode_path(&from));
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint(),
+            percent_encode_path(&to),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+
+        let mut req = Request::put(&url);
+
+        // Set SSE headers.
+        //
+        // Only SSE-C headers are accepted by `UploadPartCopy`, the encryption
+        // method has been specified while initiating the upload.
+        req = self.insert_sse_headers(req, false, SseArgs::default())?;
+        req = self.insert_copy_source_sse_headers(req);
+
+        if let Some(if_match) = if_match {
+            req = req.header(constants::X_AMZ_COPY_SOURCE_IF_MATCH, if_match);
+        }
+
+        let mut req = req
+            .header(constants::X_AMZ_COPY_SOURCE, &source)
+            .header(constants::X_AMZ_COPY_SOURCE_RANGE, range.to_header())
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
 
+    /// Insert the SSE-C headers used to decrypt the copy source.
+    fn insert_copy_source_sse_headers(
+        &self,
+        mut req: http::request::Builder,
+    ) -> http::request::Builder {
         if let Some(v) = &self.server_side_encryption_customer_algorithm {
             let mut v = v.clone();
             v.set_sensitive(true);
@@ -607,14 +1054,7 @@ impl S3Core {
             )
         }
 
-        let mut req = req
-            .header(constants::X_AMZ_COPY_SOURCE, &source)
-            .body(Buffer::new())
-            .map_err(new_request_build_error)?;
-
-        self.sign(&mut req).await?;
-
-        self.send(req).await
+        req
     }
 
     pub async fn s3_list_objects(
@@ -627,7 +1067,7 @@ impl S3Core {
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let mut url = format!("{}?list-type=2", self.endpoint);
+        let mut url = format!("{}?list-type=2", self.endpoint());
         if !p.is_empty() {
             write!(url, "&prefix={}", percent_encode_path(&p))
                 .expect("write into string must succeed");
@@ -664,14 +1104,14 @@ impl S3Core {
         self.send(req).await
     }
 
-    pub async fn s3_initiate_multipart_upload(
+    pub fn s3_initiate_multipart_upload_request(
         &self,
         path: &str,
         args: &OpWrite,
-    ) -> Result<Response<Buffer>> {
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}?uploads", self.endpoint, percent_encode_path(&p));
+        let url = format!("{}/{}?uploads", self.endpoint(), percent_encode_path(&p));
 
         let mut req = Request::post(&url);
 
@@ -700,12 +1140,22 @@ impl S3Core {
         }
 
         // Set SSE headers.
-        let req = self.insert_sse_headers(req, true);
+        let req = self.insert_sse_headers(req, true, SseArgs::from(args))?;
 
-        // Set SSE headers.
+        // Set checksum headers.
         let req = self.insert_checksum_type_header(req);
 
-        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    pub async fn s3_initiate_multipart_upload(
+        &self,
+        path: &str,
+        args: &OpWrite,
+    ) -> Result<Response<Buffer>> {
+        let mut req = self.s3_initiate_multipart_upload_request(path, args)?;
 
         self.sign(&mut req).await?;
 
@@ -715,30 +1165,32 @@ impl S3Core {
     pub fn s3_upload_part_request(
         &self,
         path: &str,
-        upload_id: &str,
-        part_number: usize,
-        size: u64,
+        part: UploadPartArgs<'_>,
         body: Buffer,
-        checksum: Option<String>,
     ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
             "{}/{}?partNumber={}&uploadId={}",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
-            part_number,
-            percent_encode_path(upload_id)
+            part.part_number,
+            percent_encode_path(part.upload_id)
         );
 
         let mut req = Request::put(&url);
 
-        req = req.header(CONTENT_LENGTH, size);
+        if let Some(size) = part.size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
 
         // Set SSE headers.
-        req = self.insert_sse_headers(req, true);
+        //
+        // Only SSE-C headers are accepted by `UploadPart`, the encryption
+        // method has been specified while initiating the upload.
+        req = self.insert_sse_headers(req, true, part.sse)?;
 
-        if let Some(checksum) = checksum {
+        if let Some(checksum) = part.checksum {
             // Set Checksum header.
             req = self.insert_checksum_header(req, &checksum);
         }
@@ -749,17 +1201,18 @@ impl S3Core {
         Ok(req)
     }
 
-    pub async fn s3_complete_multipart_upload(
+    /// Build the request to complete a multipart upload without the parts in body.
+    pub fn s3_complete_multipart_upload_request(
         &self,
         path: &str,
         upload_id: &str,
-        parts: Vec<CompleteMultipartUploadRequestPart>,
-    ) -> Result<Response<Buffer>> {
+        args: &OpWrite,
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
             "{}/{}?uploadId={}",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
             percent_encode_path(upload_id)
         );
@@ -767,18 +1220,29 @@ impl S3Core {
         let req = Request::post(&url);
 
         // Set SSE headers.
-        let req = self.insert_sse_headers(req, true);
+        let req = self.insert_sse_headers(req, true, SseArgs::customer_key_only(args))?;
+
+        req.body(Buffer::new()).map_err(new_request_build_error)
+    }
+
+    pub async fn s3_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: Vec<CompleteMultipartUploadRequestPart>,
+        args: &OpWrite,
+    ) -> Result<Response<Buffer>> {
+        let mut req = self.s3_complete_multipart_upload_request(path, upload_id, args)?;
 
         let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest { part: parts })
             .map_err(new_xml_deserialize_error)?;
         // Make sure content length has been set to avoid post with chunked encoding.
-        let req = req.header(CONTENT_LENGTH, content.len());
+        req.headers_mut()
+            .insert(CONTENT_LENGTH, HeaderValue::from(content.len()));
         // Set content-type to `application/xml` to avoid mixed with form post.
-        let req = req.header(CONTENT_TYPE, "application/xml");
-
-        let mut req = req
-            .body(Buffer::from(Bytes::from(content)))
-            .map_err(new_request_build_error)?;
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/xml"));
+        *req.body_mut() = Buffer::from(Bytes::from(content));
 
         self.sign(&mut req).await?;
 
@@ -795,7 +1259,7 @@ impl S3Core {
 
         let url = format!(
             "{}/{}?uploadId={}",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
             percent_encode_path(upload_id)
         );
@@ -811,7 +1275,7 @@ impl S3Core {
         &self,
         paths: Vec<(String, OpDelete)>,
     ) -> Result<Response<Buffer>> {
-        let url = format!("{}/?delete", self.endpoint);
+        let url = format!("{}/?delete", self.endpoint());
 
         let req = Request::post(&url);
 
@@ -852,7 +1316,7 @@ impl S3Core {
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, prefix);
 
-        let mut url = format!("{}?versions", self.endpoint);
+        let mut url = format!("{}?versions", self.endpoint());
         if !p.is_empty() {
             write!(url, "&prefix={}", percent_encode_path(p.as_str()))
                 .expect("write into string must succeed");
@@ -885,6 +1349,221 @@ impl S3Core {
 
         self.send(req).await
     }
+
+    pub async fn s3_head_bucket(&self) -> Result<Response<Buffer>> {
+        let mut req = Request::head(self.endpoint())
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn s3_create_bucket(&self) -> Result<Response<Buffer>> {
+        let region = self.location().region.clone();
+
+        let req = Request::put(self.endpoint());
+
+        // Buckets are created in `us-east-1` if location constraint is absent,
+        // and some services like minio reject the constraint they don't know.
+        let mut req = if region == "us-east-1" || region == "auto" {
+            req.header(CONTENT_LENGTH, 0).body(Buffer::new())
+        } else {
+            let content = quick_xml::se::to_string(&CreateBucketConfiguration {
+                location_constraint: region,
+            })
+            .map_err(new_xml_deserialize_error)?;
+
+            req.header(CONTENT_LENGTH, content.len())
+                .header(CONTENT_TYPE, "application/xml")
+                .body(Buffer::from(Bytes::from(content)))
+        }
+        .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    /// Create the bucket if it doesn't exist while `enable_create_bucket` is set.
+    ///
+    /// The bucket will only be checked once by every backend.
+    pub async fn s3_ensure_bucket(&self) -> Result<()> {
+        if !self.enable_create_bucket || self.bucket_created.load(atomic::Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        let resp = self.s3_head_bucket().await?;
+        match resp.status() {
+            StatusCode::OK => {}
+            StatusCode::NOT_FOUND => {
+                let resp = self.s3_create_bucket().await?;
+                match resp.status() {
+                    StatusCode::OK => {}
+                    // The bucket could be created by others concurrently.
+                    StatusCode::CONFLICT => {
+                        let (parts, body) = resp.into_parts();
+                        let s3_err: S3Error =
+                            quick_xml::de::from_reader(body.reader()).unwrap_or_default();
+                        if s3_err.code != "BucketAlreadyOwnedByYou" {
+                            return Err(from_s3_error(s3_err, parts));
+                        }
+                    }
+                    _ => return Err(parse_error(resp)),
+                }
+            }
+            _ => return Err(parse_error(resp)),
+        }
+
+        self.bucket_created.store(true, atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    pub async fn s3_get_object_tagging(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint(), percent_encode_path(&p));
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn s3_put_object_tagging(
+        &self,
+        path: &str,
+        tags: HashMap<String, String>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint(), percent_encode_path(&p));
+
+        let req = Request::put(&url);
+
+        let mut tag: Vec<Tag> = tags
+            .into_iter()
+            .map(|(key, value)| Tag { key, value })
+            .collect();
+        // Keep the order stable so that the content-md5 is reproducible.
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let content = quick_xml::se::to_string(&Tagging {
+            tag_set: TagSet { tag },
+        })
+        .map_err(new_xml_deserialize_error)?;
+
+        let req = req.header(CONTENT_LENGTH, content.len());
+        let req = req.header(CONTENT_TYPE, "application/xml");
+        // Set content-md5 as required by API.
+        let req = req.header("CONTENT-MD5", format_content_md5(content.as_bytes()));
+
+        let mut req = req
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn s3_delete_object_tagging(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint(), percent_encode_path(&p));
+
+        let mut req = Request::delete(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+}
+
+/// Server side encryption settings carried by a request.
+///
+/// They take precedence over the settings configured in builder.
+#[derive(Default, Clone, Copy)]
+pub struct SseArgs<'a> {
+    pub server_side_encryption: Option<&'a str>,
+    pub aws_kms_key_id: Option<&'a str>,
+    pub customer_key: Option<&'a str>,
+}
+
+impl<'a> SseArgs<'a> {
+    /// Only keep the customer key of given args, used by requests that don't
+    /// accept encryption method.
+    pub fn customer_key_only(args: &'a OpWrite) -> Self {
+        Self {
+            customer_key: args.server_side_encryption_customer_key(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Args of a part to upload by `UploadPart`.
+pub struct UploadPartArgs<'a> {
+    pub upload_id: &'a str,
+    pub part_number: usize,
+    pub size: Option<u64>,
+    pub checksum: Option<String>,
+    /// Only the customer key is accepted, see [`SseArgs::customer_key_only`].
+    pub sse: SseArgs<'a>,
+}
+
+impl<'a> From<&'a OpRead> for SseArgs<'a> {
+    fn from(args: &'a OpRead) -> Self {
+        Self {
+            customer_key: args.server_side_encryption_customer_key(),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> From<&'a OpStat> for SseArgs<'a> {
+    fn from(args: &'a OpStat) -> Self {
+        Self {
+            customer_key: args.server_side_encryption_customer_key(),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> From<&'a OpWrite> for SseArgs<'a> {
+    fn from(args: &'a OpWrite) -> Self {
+        Self {
+            server_side_encryption: args.server_side_encryption(),
+            aws_kms_key_id: args.server_side_encryption_aws_kms_key_id(),
+            customer_key: args.server_side_encryption_customer_key(),
+        }
+    }
+}
+
+/// Derive the signing key of AWS Signature Version 4.
+fn aws_v4_signing_key(secret: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    [date, region, service, "aws4_request"]
+        .iter()
+        .fold(format!("AWS4{secret}").into_bytes(), |key, v| {
+            hmac_sha256(&key, v.as_bytes())
+        })
+}
+
+fn hmac_sha256(key: &[u8], content: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("hmac should accept key of any size");
+    mac.update(content);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn sensitive_header_value(v: &str) -> Result<HeaderValue> {
+    let mut v = build_header_value(v)?;
+    v.set_sensitive(true);
+    Ok(v)
 }
 
 /// Result of CreateMultipartUpload
@@ -894,6 +1573,16 @@ pub struct InitiateMultipartUploadResult {
     pub upload_id: String,
 }
 
+/// Result of UploadPartCopy
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    #[serde(rename = "ChecksumCRC32C")]
+    pub checksum_crc32c: Option<String>,
+}
+
 /// Request of CompleteMultipartUploadRequest
 #[derive(Default, Debug, Serialize)]
 #[serde(default, rename = "CompleteMultipartUpload", rename_all = "PascalCase")]
@@ -977,6 +1666,53 @@ pub struct DeleteObjectsResultError {
     pub message: String,
 }
 
+/// Request of CreateBucket.
+#[derive(Default, Debug, Serialize)]
+#[serde(
+    default,
+    rename = "CreateBucketConfiguration",
+    rename_all = "PascalCase"
+)]
+pub struct CreateBucketConfiguration {
+    pub location_constraint: String,
+}
+
+/// Request and result of PutObjectTagging/GetObjectTagging.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tagging", rename_all = "PascalCase")]
+pub struct Tagging {
+    pub tag_set: TagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct TagSet {
+    pub tag: Vec<Tag>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Tag {
+    pub key: String,
+    pub value: String,
+}
+
+/// Output of CreateSession for S3 Express directory buckets.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CreateSessionOutput {
+    pub credentials: CreateSessionCredentials,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CreateSessionCredentials {
+    pub session_token: String,
+    pub secret_access_key: String,
+    pub access_key_id: String,
+    pub expiration: String,
+}
+
 /// Output of ListBucket/ListObjects.
 ///
 /// ## Note
@@ -1066,6 +1802,34 @@ impl Display for ChecksumAlgorithm {
     }
 }
 
+/// Check if the response could be caused by sending request to the wrong region.
+///
+/// S3 returns `301 PermanentRedirect` or `400 AuthorizationHeaderMalformed` for
+/// requests sent to the wrong region. Responses of `HEAD` requests don't have
+/// body, so we rely on `x-amz-bucket-region` for them. Other errors must not
+/// trigger region detection.
+fn is_region_mismatch(resp: &Response<Buffer>) -> bool {
+    match resp.status() {
+        StatusCode::MOVED_PERMANENTLY => true,
+        StatusCode::BAD_REQUEST => {
+            parse_bucket_region(resp.headers()).is_some()
+                || quick_xml::de::from_reader::<_, S3Error>(resp.body().clone().reader())
+                    .map(|err| err.code == "AuthorizationHeaderMalformed")
+                    .unwrap_or_default()
+        }
+        _ => false,
+    }
+}
+
+/// Parse the `x-amz-bucket-region` header.
+fn parse_bucket_region(headers: &http::HeaderMap) -> Option<String> {
+    headers
+        .get("x-amz-bucket-region")
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| !v.is_empty())
+        .map(|v| v.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Buf;
@@ -1073,6 +1837,62 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_parse_bucket_region() {
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(parse_bucket_region(&headers), None);
+
+        headers.insert("x-amz-bucket-region", HeaderValue::from_static(""));
+        assert_eq!(parse_bucket_region(&headers), None);
+
+        headers.insert("x-amz-bucket-region", HeaderValue::from_static("eu-west-1"));
+        assert_eq!(parse_bucket_region(&headers).as_deref(), Some("eu-west-1"));
+    }
+
+    #[test]
+    fn test_is_region_mismatch() {
+        let resp = |status: StatusCode, body: &'static str| {
+            Response::builder()
+                .status(status)
+                .body(Buffer::from(Bytes::from_static(body.as_bytes())))
+                .unwrap()
+        };
+
+        assert!(is_region_mismatch(&resp(StatusCode::MOVED_PERMANENTLY, "")));
+        assert!(is_region_mismatch(&resp(
+            StatusCode::BAD_REQUEST,
+            "<Error><Code>AuthorizationHeaderMalformed</Code><Region>eu-west-1</Region></Error>"
+        )));
+        assert!(!is_region_mismatch(&resp(
+            StatusCode::BAD_REQUEST,
+            "<Error><Code>InvalidArgument</Code></Error>"
+        )));
+        assert!(!is_region_mismatch(&resp(StatusCode::BAD_REQUEST, "")));
+        assert!(!is_region_mismatch(&resp(StatusCode::FORBIDDEN, "")));
+
+        let mut with_header = resp(StatusCode::BAD_REQUEST, "");
+        with_header
+            .headers_mut()
+            .insert("x-amz-bucket-region", HeaderValue::from_static("eu-west-1"));
+        assert!(is_region_mismatch(&with_header));
+    }
+
+    /// This example is from https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv-create-signed-request.html
+    #[test]
+    fn test_aws_v4_signing_key() {
+        let key = aws_v4_signing_key(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20150830",
+            "us-east-1",
+            "iam",
+        );
+
+        assert_eq!(
+            key.iter().map(|b| format!("{b:02x}")).collect::<String>(),
+            "c4afb1cc5771d871763a393e44b703571b55cc28424d1a5e86da6ed3c154a4b9"
+        );
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_CreateMultipartUpload.html#API_CreateMultipartUpload_Examples
     #[test]
     fn test_deserialize_initiate_multipart_upload_result() {
@@ -1094,6 +1914,23 @@ mod tests {
         )
     }
 
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_UploadPartCopy.html#API_UploadPartCopy_Examples
+    #[test]
+    fn test_deserialize_copy_part_result() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <CopyPartResult>
+              <LastModified>2009-10-28T22:32:00</LastModified>
+              <ETag>"9b2cf535f27731c974343645a3985328"</ETag>
+            </CopyPartResult>"#,
+        );
+
+        let out: CopyPartResult = quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(out.etag, "\"9b2cf535f27731c974343645a3985328\"");
+        assert_eq!(out.checksum_crc32c, None);
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_CompleteMultipartUpload.html#API_CompleteMultipartUpload_Examples
     #[test]
     fn test_serialize_complete_multipart_upload_request() {
@@ -1140,6 +1977,25 @@ mod tests {
         )
     }
 
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_CreateBucket.html#API_CreateBucket_Examples
+    #[test]
+    fn test_serialize_create_bucket_configuration() {
+        let req = CreateBucketConfiguration {
+            location_constraint: "eu-west-1".to_string(),
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            r#"<CreateBucketConfiguration>
+             <LocationConstraint>eu-west-1</LocationConstraint>
+            </CreateBucketConfiguration>"#
+                // Cleanup space and new line
+                .replace([' ', '\n'], "")
+        )
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html#API_DeleteObjects_Examples
     #[test]
     fn test_serialize_delete_objects_request() {
@@ -1174,6 +2030,78 @@ mod tests {
         )
     }
 
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_CreateSession.html#API_CreateSession_ResponseSyntax
+    #[test]
+    fn test_deserialize_create_session_output() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <CreateSessionResult>
+              <Credentials>
+                <SessionToken>token</SessionToken>
+                <SecretAccessKey>secret</SecretAccessKey>
+                <AccessKeyId>access</AccessKeyId>
+                <Expiration>2024-01-01T00:05:00Z</Expiration>
+              </Credentials>
+            </CreateSessionResult>"#,
+        );
+
+        let out: CreateSessionOutput =
+            quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(
+            out.credentials,
+            CreateSessionCredentials {
+                session_token: "token".to_string(),
+                secret_access_key: "secret".to_string(),
+                access_key_id: "access".to_string(),
+                expiration: "2024-01-01T00:05:00Z".to_string(),
+            }
+        );
+    }
+
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObjectTagging.html#API_GetObjectTagging_Examples
+    #[test]
+    fn test_deserialize_tagging() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <Tagging xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <TagSet>
+                 <Tag>
+                   <Key>tag1</Key>
+                   <Value>val1</Value>
+                 </Tag>
+                 <Tag>
+                   <Key>tag2</Key>
+                   <Value>val2</Value>
+                 </Tag>
+              </TagSet>
+            </Tagging>"#,
+        );
+
+        let out: Tagging = quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(
+            out.tag_set.tag,
+            vec![
+                Tag {
+                    key: "tag1".to_string(),
+                    value: "val1".to_string(),
+                },
+                Tag {
+                    key: "tag2".to_string(),
+                    value: "val2".to_string(),
+                }
+            ]
+        );
+
+        let actual = quick_xml::se::to_string(&out).expect("must succeed");
+        assert_eq!(
+            actual,
+            "<Tagging><TagSet><Tag><Key>tag1</Key><Value>val1</Value></Tag>\
+             <Tag><Key>tag2</Key><Value>val2</Value></Tag></TagSet></Tagging>"
+        );
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html#API_DeleteObjects_Examples
     #[test]
     fn test_deserialize_delete_objects_result() {
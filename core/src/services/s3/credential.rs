@@ -0,0 +1,286 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::env;
+use std::fs;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::Utc;
+use ini::Ini;
+use reqsign::AwsCredential;
+use reqsign::AwsCredentialLoad;
+use serde::Deserialize;
+use sha1::Digest;
+use sha1::Sha1;
+use tokio::sync::Mutex;
+
+use crate::raw::*;
+
+/// SsoCredentialLoader loads credentials from an AWS SSO (IAM Identity Center) profile.
+///
+/// Users need to login via `aws sso login --profile <profile>` first. The
+/// cached access token will be used to fetch role credentials from the SSO
+/// portal, and credentials will be fetched again before they expire.
+pub struct SsoCredentialLoader {
+    config_file: String,
+    profile: String,
+
+    credential: Mutex<Option<AwsCredential>>,
+}
+
+impl SsoCredentialLoader {
+    /// Create a new loader for the given profile in `config_file`.
+    pub fn new(config_file: &str, profile: &str) -> Self {
+        Self {
+            config_file: config_file.to_string(),
+            profile: profile.to_string(),
+            credential: Mutex::default(),
+        }
+    }
+
+    async fn load(&self, client: reqwest::Client) -> anyhow::Result<AwsCredential> {
+        let content = fs::read_to_string(expand_homedir(&self.config_file))?;
+        let profile = SsoProfile::parse(&content, &self.profile)?;
+        let token = profile.load_token()?;
+
+        let url = format!(
+            "https://portal.sso.{}.amazonaws.com/federation/credentials?account_id={}&role_name={}",
+            profile.region,
+            percent_encode_path(&profile.account_id),
+            percent_encode_path(&profile.role_name),
+        );
+        let resp = client
+            .get(&url)
+            .header("x-amz-sso_bearer_token", token)
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let content = resp.text().await?;
+            return Err(anyhow!(
+                "request to AWS SSO portal failed with {status}: {content}"
+            ));
+        }
+
+        let resp: GetRoleCredentialsResponse = serde_json::from_slice(&resp.bytes().await?)?;
+        let cred = resp.role_credentials;
+        Ok(AwsCredential {
+            access_key_id: cred.access_key_id,
+            secret_access_key: cred.secret_access_key,
+            session_token: Some(cred.session_token),
+            expires_in: Some(parse_datetime_from_from_timestamp_millis(cred.expiration)?),
+        })
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl AwsCredentialLoad for SsoCredentialLoader {
+    async fn load_credential(
+        &self,
+        client: reqwest::Client,
+    ) -> anyhow::Result<Option<AwsCredential>> {
+        let mut lock = self.credential.lock().await;
+        if let Some(cred) = lock.as_ref() {
+            if cred.is_valid() {
+                return Ok(Some(cred.clone()));
+            }
+        }
+
+        let cred = self.load(client).await?;
+        *lock = Some(cred.clone());
+        Ok(Some(cred))
+    }
+}
+
+/// SSO related settings of a profile in aws config file.
+#[derive(Debug, PartialEq, Eq)]
+struct SsoProfile {
+    /// The key used to locate the token cache, which is `sso_session` if
+    /// present, otherwise the legacy `sso_start_url`.
+    cache_key: String,
+    region: String,
+    account_id: String,
+    role_name: String,
+}
+
+impl SsoProfile {
+    fn parse(content: &str, profile: &str) -> anyhow::Result<Self> {
+        let conf = Ini::load_from_str(content)?;
+
+        let section_name = if profile == "default" {
+            "default".to_string()
+        } else {
+            format!("profile {profile}")
+        };
+        let section = conf
+            .section(Some(section_name.as_str()))
+            .ok_or_else(|| anyhow!("profile {profile} not found in aws config"))?;
+        let get = |key: &str| {
+            section
+                .get(key)
+                .map(|v| v.to_string())
+                .ok_or_else(|| anyhow!("{key} is not set in profile {profile}"))
+        };
+
+        let account_id = get("sso_account_id")?;
+        let role_name = get("sso_role_name")?;
+
+        // The new format puts start url and region in a `sso-session` section.
+        let (cache_key, region) = match section.get("sso_session") {
+            Some(session) => {
+                let session_section = conf
+                    .section(Some(format!("sso-session {session}")))
+                    .ok_or_else(|| anyhow!("sso-session {session} not found in aws config"))?;
+                let region = session_section
+                    .get("sso_region")
+                    .ok_or_else(|| anyhow!("sso_region is not set in sso-session {session}"))?;
+                (session.to_string(), region.to_string())
+            }
+            None => (get("sso_start_url")?, get("sso_region")?),
+        };
+
+        Ok(Self {
+            cache_key,
+            region,
+            account_id,
+            role_name,
+        })
+    }
+
+    /// Load the access token cached by `aws sso login`.
+    fn load_token(&self) -> anyhow::Result<String> {
+        let path = expand_homedir(&format!(
+            "~/.aws/sso/cache/{}.json",
+            sso_cache_file_name(&self.cache_key)
+        ));
+        let content = fs::read(&path)?;
+        let token: SsoToken = serde_json::from_slice(&content)?;
+
+        let expires_at = parse_datetime_from_rfc3339(&token.expires_at)?;
+        if expires_at <= Utc::now() {
+            return Err(anyhow!(
+                "sso token in {path} is expired, please run `aws sso login` again"
+            ));
+        }
+        Ok(token.access_token)
+    }
+}
+
+/// The cache file name is the hex encoded sha1 of the session name or start url.
+fn sso_cache_file_name(key: &str) -> String {
+    format!("{:x}", Sha1::digest(key.as_bytes()))
+}
+
+fn expand_homedir(path: &str) -> String {
+    let Some(rest) = path.strip_prefix("~/") else {
+        return path.to_string();
+    };
+    match env::var("HOME").or_else(|_| env::var("USERPROFILE")) {
+        Ok(home) => format!("{home}/{rest}"),
+        Err(_) => path.to_string(),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SsoToken {
+    access_token: String,
+    expires_at: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GetRoleCredentialsResponse {
+    role_credentials: RoleCredentials,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RoleCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: String,
+    expiration: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sso_profile_legacy() {
+        let content = r#"
+[profile dev]
+sso_start_url = https://example.awsapps.com/start
+sso_region = us-east-1
+sso_account_id = 123456789012
+sso_role_name = ReadOnly
+"#;
+
+        let profile = SsoProfile::parse(content, "dev").unwrap();
+        assert_eq!(
+            profile,
+            SsoProfile {
+                cache_key: "https://example.awsapps.com/start".to_string(),
+                region: "us-east-1".to_string(),
+                account_id: "123456789012".to_string(),
+                role_name: "ReadOnly".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_sso_profile_with_session() {
+        let content = r#"
+[default]
+sso_session = my-sso
+sso_account_id = 123456789012
+sso_role_name = Admin
+
+[sso-session my-sso]
+sso_start_url = https://example.awsapps.com/start
+sso_region = eu-west-1
+"#;
+
+        let profile = SsoProfile::parse(content, "default").unwrap();
+        assert_eq!(
+            profile,
+            SsoProfile {
+                cache_key: "my-sso".to_string(),
+                region: "eu-west-1".to_string(),
+                account_id: "123456789012".to_string(),
+                role_name: "Admin".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_sso_profile_missing() {
+        let content = "[profile other]\nregion = us-east-1\n";
+        assert!(SsoProfile::parse(content, "dev").is_err());
+    }
+
+    #[test]
+    fn test_sso_cache_file_name() {
+        assert_eq!(
+            sso_cache_file_name("https://d-abc123.awsapps.com/start"),
+            "40a89917e3175433e361b710a9d43528d7f1890a"
+        );
+    }
+}
@@ -21,6 +21,7 @@ use http::Response;
 use quick_xml::de;
 use serde::Deserialize;
 
+use super::core::constants::X_AMZ_DELETE_MARKER;
 use crate::raw::*;
 use crate::*;
 
@@ -39,15 +40,24 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
     let (parts, body) = resp.into_parts();
     let bs = body.to_bytes();
 
+    // S3 returns `404` if the current version is a delete marker, and `405`
+    // if the given version is a delete marker. Both of them mean the object
+    // doesn't exist from user's view.
+    let is_delete_marker = parts
+        .headers
+        .get(X_AMZ_DELETE_MARKER)
+        .map(|v| v.as_bytes() == b"true")
+        .unwrap_or_default();
+
     let (mut kind, mut retryable) = match parts.status.as_u16() {
         403 => (ErrorKind::PermissionDenied, false),
         404 => (ErrorKind::NotFound, false),
+        405 if is_delete_marker => (ErrorKind::NotFound, false),
         304 | 412 => (ErrorKind::ConditionNotMatch, false),
         // Service like R2 could return 499 error with a message like:
         // Client Disconnect, we should retry it.
         499 => (ErrorKind::Unexpected, true),
-        500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
     let body_content = bs.chunk();
@@ -55,12 +65,22 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
         .map(|s3_err| (format!("{s3_err:?}"), Some(s3_err)))
         .unwrap_or_else(|_| (String::from_utf8_lossy(&bs).into_owned(), None));
 
+    let mut code = None;
     if let Some(s3_err) = s3_err {
         (kind, retryable) = parse_s3_error_code(s3_err.code.as_str()).unwrap_or((kind, retryable));
+        code = Some(s3_err.code).filter(|v| !v.is_empty());
     }
 
     let mut err = Error::new(kind, message);
 
+    if let Some(code) = code {
+        err = err.with_service_code(code);
+    }
+
+    if is_delete_marker {
+        err = err.with_context("delete_marker", "true");
+    }
+
     err = with_error_response_context(err, parts);
 
     if retryable {
@@ -76,6 +96,10 @@ pub(crate) fn from_s3_error(s3_error: S3Error, parts: Parts) -> Error {
         parse_s3_error_code(s3_error.code.as_str()).unwrap_or((ErrorKind::Unexpected, false));
     let mut err = Error::new(kind, format!("{s3_error:?}"));
 
+    if !s3_error.code.is_empty() {
+        err = err.with_service_code(s3_error.code);
+    }
+
     err = with_error_response_context(err, parts);
 
     if retryable {
@@ -113,7 +137,7 @@ pub fn parse_s3_error_code(code: &str) -> Option<(ErrorKind, bool)> {
         // ServiceUnavailable is considered a retryable error because it typically
         // indicates a temporary issue with the service or server, such as high load,
         // maintenance, or an internal problem.
-        "ServiceUnavailable" => Some((ErrorKind::Unexpected, true)),
+        "ServiceUnavailable" => Some((ErrorKind::Unavailable, true)),
         _ => None,
     }
 }
@@ -163,4 +187,49 @@ mod tests {
         let out: S3Error = de::from_reader(bs.reader()).expect("must success");
         assert_eq!(out, S3Error::default());
     }
+
+    #[test]
+    fn test_parse_error_delete_marker() {
+        let resp = Response::builder()
+            .status(405)
+            .header(X_AMZ_DELETE_MARKER, "true")
+            .body(Buffer::new())
+            .unwrap();
+
+        let err = parse_error(resp);
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert!(!err.is_temporary());
+
+        let resp = Response::builder().status(405).body(Buffer::new()).unwrap();
+
+        let err = parse_error(resp);
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+    }
+
+    #[test]
+    fn test_parse_error_service_code() {
+        let bs = bytes::Bytes::from(
+            r#"
+<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>SlowDown</Code>
+  <Message>Please reduce your request rate.</Message>
+  <RequestId>4442587FB7D0A2F9</RequestId>
+</Error>
+"#,
+        );
+        let resp = Response::builder()
+            .status(503)
+            .body(Buffer::from(bs))
+            .unwrap();
+
+        let err = parse_error(resp);
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert_eq!(err.service_code(), Some("SlowDown"));
+
+        let resp = Response::builder().status(404).body(Buffer::new()).unwrap();
+
+        let err = parse_error(resp);
+        assert_eq!(err.service_code(), None);
+    }
 }
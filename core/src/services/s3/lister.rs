@@ -37,13 +37,13 @@ pub struct S3Lister {
     path: String,
     args: OpList,
 
-    delimiter: &'static str,
+    delimiter: String,
     abs_start_after: Option<String>,
 }
 
 impl S3Lister {
     pub fn new(core: Arc<S3Core>, path: &str, args: OpList) -> Self {
-        let delimiter = if args.recursive() { "" } else { "/" };
+        let delimiter = args.delimiter().to_string();
         let abs_start_after = args
             .start_after()
             .map(|start_after| build_abs_path(&core.root, start_after));
@@ -66,7 +66,7 @@ impl oio::PageList for S3Lister {
             .s3_list_objects(
                 &self.path,
                 &ctx.token,
-                self.delimiter,
+                &self.delimiter,
                 self.args.limit(),
                 // start after should only be set for the first page.
                 if ctx.token.is_empty() {
@@ -106,10 +106,8 @@ impl oio::PageList for S3Lister {
         ctx.token = output.next_continuation_token.clone().unwrap_or_default();
 
         for prefix in output.common_prefixes {
-            let de = oio::Entry::new(
-                &build_rel_path(&self.core.root, &prefix.prefix),
-                Metadata::new(EntryMode::DIR),
-            );
+            let path = build_rel_path(&self.core.root, &prefix.prefix);
+            let de = oio::Entry::new(&path, Metadata::new(EntryMode::from_prefix(&path)));
 
             ctx.entries.push_back(de);
         }
@@ -123,8 +121,9 @@ impl oio::PageList for S3Lister {
             let mut meta = Metadata::new(EntryMode::from_path(&path));
             meta.set_is_current(true);
             if let Some(etag) = &object.etag {
+                // Etag is not the md5 of content for objects encrypted by SSE-KMS
+                // or SSE-C, which can't be told from list results.
                 meta.set_etag(etag);
-                meta.set_content_md5(etag.trim_matches('"'));
             }
             meta.set_content_length(object.size);
 
@@ -147,13 +146,13 @@ pub struct S3ObjectVersionsLister {
     prefix: String,
     args: OpList,
 
-    delimiter: &'static str,
+    delimiter: String,
     abs_start_after: Option<String>,
 }
 
 impl S3ObjectVersionsLister {
     pub fn new(core: Arc<S3Core>, path: &str, args: OpList) -> Self {
-        let delimiter = if args.recursive() { "" } else { "/" };
+        let delimiter = args.delimiter().to_string();
         let abs_start_after = args
             .start_after()
             .map(|start_after| build_abs_path(&core.root, start_after));
@@ -183,7 +182,7 @@ impl oio::PageList for S3ObjectVersionsLister {
             .core
             .s3_list_object_versions(
                 &self.prefix,
-                self.delimiter,
+                &self.delimiter,
                 self.args.limit(),
                 key_marker,
                 version_id_marker,
@@ -215,10 +214,8 @@ impl oio::PageList for S3ObjectVersionsLister {
         );
 
         for prefix in output.common_prefixes {
-            let de = oio::Entry::new(
-                &build_rel_path(&self.core.root, &prefix.prefix),
-                Metadata::new(EntryMode::DIR),
-            );
+            let path = build_rel_path(&self.core.root, &prefix.prefix);
+            let de = oio::Entry::new(&path, Metadata::new(EntryMode::from_prefix(&path)));
             ctx.entries.push_back(de);
         }
 
@@ -245,7 +242,6 @@ impl oio::PageList for S3ObjectVersionsLister {
             )?);
             if let Some(etag) = version_object.etag {
                 meta.set_etag(&etag);
-                meta.set_content_md5(etag.trim_matches('"'));
             }
 
             let entry = oio::Entry::new(&path, meta);
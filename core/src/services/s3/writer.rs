@@ -99,11 +99,14 @@ impl oio::MultipartWrite for S3Writer {
 
         let mut req = self.core.s3_upload_part_request(
             &self.path,
-            upload_id,
-            part_number,
-            size,
+            UploadPartArgs {
+                upload_id,
+                part_number,
+                size: Some(size),
+                checksum: checksum.clone(),
+                sse: SseArgs::customer_key_only(&self.op),
+            },
             body,
-            checksum.clone(),
         )?;
 
         self.core.sign(&mut req).await?;
@@ -154,7 +157,7 @@ impl oio::MultipartWrite for S3Writer {
 
         let resp = self
             .core
-            .s3_complete_multipart_upload(&self.path, upload_id, parts)
+            .s3_complete_multipart_upload(&self.path, upload_id, parts, &self.op)
             .await?;
 
         let status = resp.status();
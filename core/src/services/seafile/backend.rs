@@ -143,6 +143,10 @@ impl Builder for SeafileBuilder {
     const SCHEME: Scheme = Scheme::Seafile;
     type Config = SeafileConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Builds the backend and returns the result of SeafileBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
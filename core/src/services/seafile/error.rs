@@ -38,7 +38,7 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
         403 => (ErrorKind::PermissionDenied, false),
         404 => (ErrorKind::NotFound, false),
         520 => (ErrorKind::Unexpected, false),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
     let (message, _seafile_err) = serde_json::from_reader::<_, SeafileError>(bs.clone().reader())
@@ -19,12 +19,16 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use bytes::Buf;
+use http::Request;
 use http::Response;
 use http::StatusCode;
 use log::debug;
 
 use super::core::*;
+use super::delete::SupabaseDeleter;
 use super::error::parse_error;
+use super::lister::SupabaseLister;
 use super::writer::*;
 use crate::raw::*;
 use crate::services::SupabaseConfig;
@@ -112,6 +116,10 @@ impl Builder for SupabaseBuilder {
     const SCHEME: Scheme = Scheme::Supabase;
     type Config = SupabaseConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         let root = normalize_root(&self.config.root.unwrap_or_default());
         debug!("backend use root {}", &root);
@@ -147,9 +155,8 @@ pub struct SupabaseBackend {
 impl Access for SupabaseBackend {
     type Reader = HttpBody;
     type Writer = oio::OneShotWriter<SupabaseWriter>;
-    // todo: implement Lister to support list
-    type Lister = ();
-    type Deleter = ();
+    type Lister = oio::PageLister<SupabaseLister>;
+    type Deleter = oio::OneShotDeleter<SupabaseDeleter>;
     type BlockingReader = ();
     type BlockingWriter = ();
     type BlockingLister = ();
@@ -167,6 +174,15 @@ impl Access for SupabaseBackend {
 
                 write: true,
 
+                delete: true,
+
+                list: true,
+                list_with_limit: true,
+
+                presign: true,
+                presign_read: true,
+                presign_write: true,
+
                 shared: true,
 
                 ..Default::default()
@@ -216,4 +232,71 @@ impl Access for SupabaseBackend {
             oio::OneShotWriter::new(SupabaseWriter::new(self.core.clone(), path, args)),
         ))
     }
+
+    async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+        Ok((
+            RpDelete::default(),
+            oio::OneShotDeleter::new(SupabaseDeleter::new(self.core.clone())),
+        ))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let l = SupabaseLister::new(self.core.clone(), path, args.limit());
+
+        Ok((RpList::default(), oio::PageLister::new(l)))
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !args.headers().is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "Supabase doesn't support presigned request with extra headers",
+            ));
+        }
+
+        let url = match args.operation() {
+            PresignOperation::Read(_) => {
+                let resp = self.core.supabase_sign_object(path, args.expire()).await?;
+                if resp.status() != StatusCode::OK {
+                    return Err(parse_error(resp));
+                }
+
+                let output: SignObjectResponse = serde_json::from_reader(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?;
+                format!("{}/storage/v1{}", self.core.endpoint, output.signed_url)
+            }
+            PresignOperation::Write(_) => {
+                let resp = self.core.supabase_sign_upload_object(path).await?;
+                if resp.status() != StatusCode::OK {
+                    return Err(parse_error(resp));
+                }
+
+                let output: SignUploadObjectResponse =
+                    serde_json::from_reader(resp.into_body().reader())
+                        .map_err(new_json_deserialize_error)?;
+                format!("{}/storage/v1{}", self.core.endpoint, output.url)
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "operation is not supported",
+                ))
+            }
+        };
+
+        let req = match args.operation() {
+            PresignOperation::Write(_) => Request::put(url),
+            _ => Request::get(url),
+        };
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        // We don't need this request anymore, consume it directly.
+        let (parts, _) = req.into_parts();
+
+        Ok(RpPresign::new(PresignedRequest::new(
+            parts.method,
+            parts.uri,
+            parts.headers,
+        )))
+    }
 }
@@ -16,16 +16,24 @@
 // under the License.
 
 use std::fmt::Debug;
+use std::time::Duration;
 
+use bytes::Bytes;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
+use http::header::RANGE;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::raw::*;
 use crate::*;
 
+/// The header to overwrite existing objects while uploading.
+const X_UPSERT: &str = "x-upsert";
+
 pub struct SupabaseCore {
     pub root: String,
     pub bucket: String,
@@ -94,7 +102,8 @@ impl SupabaseCore {
             percent_encode_path(&p)
         );
 
-        let mut req = Request::post(&url);
+        // Overwrite the object if it exists already.
+        let mut req = Request::post(&url).header(X_UPSERT, "true");
 
         if let Some(size) = size {
             req = req.header(CONTENT_LENGTH, size)
@@ -112,7 +121,7 @@ impl SupabaseCore {
     pub fn supabase_get_object_public_request(
         &self,
         path: &str,
-        _: BytesRange,
+        range: BytesRange,
     ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let url = format!(
@@ -122,7 +131,11 @@ impl SupabaseCore {
             percent_encode_path(&p)
         );
 
-        let req = Request::get(&url);
+        let mut req = Request::get(&url);
+
+        if !range.is_full() {
+            req = req.header(RANGE, range.to_header());
+        }
 
         req.body(Buffer::new()).map_err(new_request_build_error)
     }
@@ -130,7 +143,7 @@ impl SupabaseCore {
     pub fn supabase_get_object_auth_request(
         &self,
         path: &str,
-        _: BytesRange,
+        range: BytesRange,
     ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let url = format!(
@@ -140,7 +153,11 @@ impl SupabaseCore {
             percent_encode_path(&p)
         );
 
-        let req = Request::get(&url);
+        let mut req = Request::get(&url);
+
+        if !range.is_full() {
+            req = req.header(RANGE, range.to_header());
+        }
 
         req.body(Buffer::new()).map_err(new_request_build_error)
     }
@@ -200,6 +217,87 @@ impl SupabaseCore {
             .body(Buffer::new())
             .map_err(new_request_build_error)
     }
+
+    pub fn supabase_delete_object_request(&self, path: &str) -> Result<Request<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/storage/v1/object/{}/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+
+        Request::delete(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)
+    }
+
+    pub fn supabase_list_objects_request(
+        &self,
+        path: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Request<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/storage/v1/object/list/{}", self.endpoint, self.bucket);
+
+        let body = ListObjectsRequest {
+            prefix: p.trim_end_matches('/').to_string(),
+            limit,
+            offset,
+            sort_by: ListObjectsSortBy {
+                column: "name".to_string(),
+                order: "asc".to_string(),
+            },
+        };
+        let bs = serde_json::to_vec(&body).map_err(new_json_serialize_error)?;
+
+        Request::post(&url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(CONTENT_LENGTH, bs.len())
+            .body(Buffer::from(Bytes::from(bs)))
+            .map_err(new_request_build_error)
+    }
+
+    pub fn supabase_sign_object_request(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> Result<Request<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/storage/v1/object/sign/{}/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+
+        let body = SignObjectRequest {
+            expires_in: expire.as_secs().max(1),
+        };
+        let bs = serde_json::to_vec(&body).map_err(new_json_serialize_error)?;
+
+        Request::post(&url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(CONTENT_LENGTH, bs.len())
+            .body(Buffer::from(Bytes::from(bs)))
+            .map_err(new_request_build_error)
+    }
+
+    pub fn supabase_sign_upload_object_request(&self, path: &str) -> Result<Request<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/storage/v1/object/upload/sign/{}/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+
+        Request::post(&url)
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)
+    }
 }
 
 // core utils
@@ -241,4 +339,90 @@ impl SupabaseCore {
         self.sign(&mut req)?;
         self.send(req).await
     }
+
+    pub async fn supabase_delete_object(&self, path: &str) -> Result<Response<Buffer>> {
+        let mut req = self.supabase_delete_object_request(path)?;
+        self.sign(&mut req)?;
+        self.send(req).await
+    }
+
+    pub async fn supabase_list_objects(
+        &self,
+        path: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Response<Buffer>> {
+        let mut req = self.supabase_list_objects_request(path, limit, offset)?;
+        self.sign(&mut req)?;
+        self.send(req).await
+    }
+
+    pub async fn supabase_sign_object(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> Result<Response<Buffer>> {
+        let mut req = self.supabase_sign_object_request(path, expire)?;
+        self.sign(&mut req)?;
+        self.send(req).await
+    }
+
+    pub async fn supabase_sign_upload_object(&self, path: &str) -> Result<Response<Buffer>> {
+        let mut req = self.supabase_sign_upload_object_request(path)?;
+        self.sign(&mut req)?;
+        self.send(req).await
+    }
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListObjectsRequest {
+    pub prefix: String,
+    pub limit: usize,
+    pub offset: usize,
+    pub sort_by: ListObjectsSortBy,
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct ListObjectsSortBy {
+    pub column: String,
+    pub order: String,
+}
+
+/// The entry returned by list, folders don't have `id` and `metadata`.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct ListObjectsEntry {
+    pub name: String,
+    pub id: Option<String>,
+    pub metadata: Option<ObjectMetadata>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ObjectMetadata {
+    pub e_tag: Option<String>,
+    pub size: Option<u64>,
+    pub mimetype: Option<String>,
+    pub cache_control: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignObjectRequest {
+    pub expires_in: u64,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct SignObjectResponse {
+    #[serde(rename = "signedURL")]
+    pub signed_url: String,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct SignUploadObjectResponse {
+    pub url: String,
 }
@@ -38,10 +38,7 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
     let bs = body.to_bytes();
 
     // Check HTTP status code first/
-    let (mut kind, mut retryable) = match parts.status.as_u16() {
-        500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
-    };
+    let (mut kind, mut retryable) = parse_http_status(parts.status);
 
     // Than extrace the error message.
     let (message, _) = from_slice::<SupabaseError>(&bs)
@@ -64,8 +61,16 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
 
 // Return the error kind and whether it is retryable
 fn parse_supabase_error(err: &SupabaseError) -> (ErrorKind, bool) {
-    let code = err.status_code.parse::<u16>().unwrap();
-    let status_code = StatusCode::from_u16(code).unwrap();
+    // The status code in body could be missing or malformed, fallback
+    // to `Unexpected` in this case.
+    let Some(status_code) = err
+        .status_code
+        .parse::<u16>()
+        .ok()
+        .and_then(|code| StatusCode::from_u16(code).ok())
+    else {
+        return (ErrorKind::Unexpected, false);
+    };
     match status_code {
         StatusCode::CONFLICT => (ErrorKind::AlreadyExists, false),
         StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
@@ -73,10 +78,6 @@ fn parse_supabase_error(err: &SupabaseError) -> (ErrorKind, bool) {
         StatusCode::PRECONDITION_FAILED | StatusCode::NOT_MODIFIED => {
             (ErrorKind::ConditionNotMatch, false)
         }
-        StatusCode::INTERNAL_SERVER_ERROR
-        | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(status_code),
     }
 }
@@ -0,0 +1,118 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use bytes::Buf;
+use http::StatusCode;
+
+use super::core::*;
+use super::error::parse_error;
+use crate::raw::*;
+use crate::*;
+
+/// Supabase creates this placeholder object while creating an empty folder.
+const EMPTY_FOLDER_PLACEHOLDER: &str = ".emptyFolderPlaceholder";
+
+pub struct SupabaseLister {
+    core: Arc<SupabaseCore>,
+
+    path: String,
+    limit: usize,
+}
+
+impl SupabaseLister {
+    pub fn new(core: Arc<SupabaseCore>, path: &str, limit: Option<usize>) -> Self {
+        Self {
+            core,
+            path: path.to_string(),
+            limit: limit.unwrap_or(1000),
+        }
+    }
+}
+
+impl oio::PageList for SupabaseLister {
+    async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        let offset = if ctx.token.is_empty() {
+            0
+        } else {
+            ctx.token.parse::<usize>().map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "list offset is invalid")
+                    .with_context("token", &ctx.token)
+                    .set_source(err)
+            })?
+        };
+
+        let resp = self
+            .core
+            .supabase_list_objects(&self.path, self.limit, offset)
+            .await?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+
+        let bs = resp.into_body();
+        let entries: Vec<ListObjectsEntry> =
+            serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
+
+        if entries.len() < self.limit {
+            ctx.done = true;
+        }
+        ctx.token = (offset + entries.len()).to_string();
+
+        let dir = if self.path == "/" { "" } else { &self.path };
+
+        for entry in entries {
+            if entry.name == EMPTY_FOLDER_PLACEHOLDER {
+                continue;
+            }
+
+            // Folders are returned without id.
+            if entry.id.is_none() {
+                let path = format!("{dir}{}/", entry.name);
+                ctx.entries
+                    .push_back(oio::Entry::new(&path, Metadata::new(EntryMode::DIR)));
+                continue;
+            }
+
+            let path = format!("{dir}{}", entry.name);
+            let mut meta = Metadata::new(EntryMode::FILE);
+            if let Some(m) = entry.metadata {
+                if let Some(v) = m.size {
+                    meta.set_content_length(v);
+                }
+                if let Some(v) = m.mimetype {
+                    meta.set_content_type(&v);
+                }
+                if let Some(v) = m.e_tag {
+                    meta.set_etag(&v);
+                }
+                if let Some(v) = m.cache_control {
+                    meta.set_cache_control(&v);
+                }
+                if let Some(v) = m.last_modified {
+                    meta.set_last_modified(parse_datetime_from_rfc3339(&v)?);
+                }
+            }
+
+            ctx.entries.push_back(oio::Entry::new(&path, meta));
+        }
+
+        Ok(())
+    }
+}
@@ -18,8 +18,12 @@
 #[cfg(feature = "services-supabase")]
 mod core;
 #[cfg(feature = "services-supabase")]
+mod delete;
+#[cfg(feature = "services-supabase")]
 mod error;
 #[cfg(feature = "services-supabase")]
+mod lister;
+#[cfg(feature = "services-supabase")]
 mod writer;
 
 #[cfg(feature = "services-supabase")]
@@ -35,7 +35,10 @@ use crate::*;
 impl Configurator for SwiftConfig {
     type Builder = SwiftBuilder;
     fn into_builder(self) -> Self::Builder {
-        SwiftBuilder { config: self }
+        SwiftBuilder {
+            config: self,
+            http_client: None,
+        }
     }
 }
 
@@ -46,6 +49,7 @@ impl Configurator for SwiftConfig {
 #[derive(Default, Clone)]
 pub struct SwiftBuilder {
     config: SwiftConfig,
+    http_client: Option<HttpClient>,
 }
 
 impl Debug for SwiftBuilder {
@@ -110,12 +114,27 @@ impl SwiftBuilder {
         }
         self
     }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
 }
 
 impl Builder for SwiftBuilder {
     const SCHEME: Scheme = Scheme::Swift;
     type Config = SwiftConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Build a SwiftBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
@@ -153,7 +172,14 @@ impl Builder for SwiftBuilder {
 
         let token = self.config.token.unwrap_or_default();
 
-        let client = HttpClient::new()?;
+        let client = if let Some(client) = self.http_client {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Swift)
+            })?
+        };
 
         Ok(SwiftBackend {
             core: Arc::new(SwiftCore {
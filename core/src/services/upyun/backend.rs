@@ -126,6 +126,10 @@ impl Builder for UpyunBuilder {
     const SCHEME: Scheme = Scheme::Upyun;
     type Config = UpyunConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Builds the backend and returns the result of UpyunBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
@@ -104,6 +104,10 @@ impl Builder for VercelBlobBuilder {
     const SCHEME: Scheme = Scheme::VercelBlob;
     type Config = VercelBlobConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Builds the backend and returns the result of VercelBlobBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
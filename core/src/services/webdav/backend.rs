@@ -116,6 +116,32 @@ impl WebdavBuilder {
         self
     }
 
+    /// Acquire an exclusive lock before write and delete.
+    ///
+    /// The lock token will be passed via `If` header, so that other
+    /// clients (like Nextcloud/ownCloud users) can't modify the file
+    /// at the same time. The lock will be released after write closed
+    /// or aborted.
+    ///
+    /// default: disabled
+    pub fn enable_lock(mut self) -> Self {
+        self.config.enable_lock = true;
+        self
+    }
+
+    /// Set the timeout of lock in seconds.
+    ///
+    /// Lock will be refreshed before writing if half of the timeout has
+    /// elapsed since it's acquired.
+    ///
+    /// default: 600
+    pub fn lock_timeout(mut self, timeout: u64) -> Self {
+        if timeout > 0 {
+            self.config.lock_timeout = Some(timeout);
+        }
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -132,6 +158,10 @@ impl Builder for WebdavBuilder {
     const SCHEME: Scheme = Scheme::Webdav;
     type Config = WebdavConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
@@ -181,6 +211,8 @@ impl Builder for WebdavBuilder {
             server_path,
             authorization,
             disable_copy: self.config.disable_copy,
+            enable_lock: self.config.enable_lock,
+            lock_timeout: self.config.lock_timeout.unwrap_or(DEFAULT_LOCK_TIMEOUT),
             root,
             client,
         });
@@ -204,7 +236,7 @@ impl Debug for WebdavBackend {
 
 impl Access for WebdavBackend {
     type Reader = HttpBody;
-    type Writer = oio::OneShotWriter<WebdavWriter>;
+    type Writer = WebdavWriter;
     type Lister = oio::PageLister<WebdavLister>;
     type Deleter = oio::OneShotDeleter<WebdavDeleter>;
     type BlockingReader = ();
@@ -227,6 +259,7 @@ impl Access for WebdavBackend {
 
                 write: true,
                 write_can_empty: true,
+                write_with_content_type: true,
 
                 create_dir: true,
                 delete: true,
@@ -282,9 +315,15 @@ impl Access for WebdavBackend {
         // Ensure parent path exists
         self.core.webdav_mkcol(get_parent(path)).await?;
 
+        let lock = if self.core.enable_lock {
+            Some(self.core.webdav_lock(path).await?)
+        } else {
+            None
+        };
+
         Ok((
             RpWrite::default(),
-            oio::OneShotWriter::new(WebdavWriter::new(self.core.clone(), args, path.to_string())),
+            WebdavWriter::new(self.core.clone(), args, path.to_string(), lock),
         ))
     }
 
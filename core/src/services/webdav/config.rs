@@ -38,6 +38,10 @@ pub struct WebdavConfig {
     pub root: Option<String>,
     /// WebDAV Service doesn't support copy.
     pub disable_copy: bool,
+    /// Acquire an exclusive lock before write and delete.
+    pub enable_lock: bool,
+    /// The timeout of lock in seconds, default to 600.
+    pub lock_timeout: Option<u64>,
 }
 
 impl Debug for WebdavConfig {
@@ -46,7 +50,9 @@ impl Debug for WebdavConfig {
 
         d.field("endpoint", &self.endpoint)
             .field("username", &self.username)
-            .field("root", &self.root);
+            .field("root", &self.root)
+            .field("enable_lock", &self.enable_lock)
+            .field("lock_timeout", &self.lock_timeout);
 
         d.finish_non_exhaustive()
     }
@@ -21,6 +21,8 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 
 use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
 use http::header;
 use http::Request;
 use http::Response;
@@ -67,12 +69,55 @@ static HEADER_DESTINATION: &str = "Destination";
 ///
 /// reference: [RFC4918: 10.6.  Overwrite Header](https://datatracker.ietf.org/doc/html/rfc4918#section-10.6)
 static HEADER_OVERWRITE: &str = "Overwrite";
+/// The header to submit the lock token of the resource.
+///
+/// Requests that modify a locked resource must carry the lock token
+/// in the form of `(<lock-token>)`.
+///
+/// reference: [RFC4918: 10.4.  If Header](https://datatracker.ietf.org/doc/html/rfc4918#section-10.4)
+static HEADER_IF: &str = "If";
+/// The header to return the lock token while `LOCK` and specify the lock
+/// to remove while `UNLOCK`.
+///
+/// reference: [RFC4918: 10.5.  Lock-Token Header](https://datatracker.ietf.org/doc/html/rfc4918#section-10.5)
+static HEADER_LOCK_TOKEN: &str = "Lock-Token";
+/// The header to specify the timeout of the lock, like `Second-600`.
+///
+/// reference: [RFC4918: 10.7.  Timeout Request Header](https://datatracker.ietf.org/doc/html/rfc4918#section-10.7)
+static HEADER_TIMEOUT: &str = "Timeout";
+
+/// The request to acquire an exclusive write lock.
+///
+/// reference: [RFC4918: 9.10. LOCK Method](https://datatracker.ietf.org/doc/html/rfc4918#section-9.10)
+static LOCK_REQUEST: &str = r#"<?xml version="1.0" encoding="utf-8" ?><D:lockinfo xmlns:D="DAV:"><D:lockscope><D:exclusive/></D:lockscope><D:locktype><D:write/></D:locktype><D:owner>opendal</D:owner></D:lockinfo>"#;
+
+/// The default timeout of lock in seconds.
+pub const DEFAULT_LOCK_TIMEOUT: u64 = 600;
+
+/// WebdavLock is a lock acquired by `LOCK` method.
+pub struct WebdavLock {
+    /// The lock token without the surrounding `<>`.
+    pub token: String,
+    /// Whether an empty resource has been created by server for this lock.
+    pub created: bool,
+    acquired_at: DateTime<Utc>,
+}
+
+impl WebdavLock {
+    /// Returns true if half of the timeout has elapsed since the lock
+    /// has been acquired or refreshed.
+    pub fn should_refresh(&self, timeout: u64) -> bool {
+        (Utc::now() - self.acquired_at).num_seconds() >= (timeout / 2) as i64
+    }
+}
 
 pub struct WebdavCore {
     pub endpoint: String,
     pub server_path: String,
     pub root: String,
     pub disable_copy: bool,
+    pub enable_lock: bool,
+    pub lock_timeout: u64,
     pub authorization: Option<String>,
 
     pub client: HttpClient,
@@ -159,6 +204,7 @@ impl WebdavCore {
         path: &str,
         size: Option<u64>,
         args: &OpWrite,
+        lock_token: Option<&str>,
         body: Buffer,
     ) -> Result<Response<Buffer>> {
         let path = build_rooted_abs_path(&self.root, path);
@@ -170,6 +216,10 @@ impl WebdavCore {
             req = req.header(header::AUTHORIZATION, v)
         }
 
+        if let Some(token) = lock_token {
+            req = req.header(HEADER_IF, format!("(<{token}>)"))
+        }
+
         if let Some(v) = size {
             req = req.header(header::CONTENT_LENGTH, v)
         }
@@ -187,7 +237,11 @@ impl WebdavCore {
         self.client.send(req).await
     }
 
-    pub async fn webdav_delete(&self, path: &str) -> Result<Response<Buffer>> {
+    pub async fn webdav_delete(
+        &self,
+        path: &str,
+        lock_token: Option<&str>,
+    ) -> Result<Response<Buffer>> {
         let path = build_rooted_abs_path(&self.root, path);
         let url = format!("{}{}", self.endpoint, percent_encode_path(&path));
 
@@ -197,11 +251,107 @@ impl WebdavCore {
             req = req.header(header::AUTHORIZATION, auth.clone())
         }
 
+        if let Some(token) = lock_token {
+            req = req.header(HEADER_IF, format!("(<{token}>)"))
+        }
+
         let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
 
         self.client.send(req).await
     }
 
+    /// Acquire an exclusive write lock on the given path.
+    ///
+    /// If the resource doesn't exist, an empty resource will be created by server.
+    pub async fn webdav_lock(&self, path: &str) -> Result<WebdavLock> {
+        let path = build_rooted_abs_path(&self.root, path);
+        let url = format!("{}{}", self.endpoint, percent_encode_path(&path));
+
+        let mut req = Request::builder().method("LOCK").uri(&url);
+
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+
+        req = req.header(header::CONTENT_TYPE, "application/xml");
+        req = req.header(header::CONTENT_LENGTH, LOCK_REQUEST.len());
+        req = req.header(HEADER_DEPTH, "0");
+        req = req.header(HEADER_TIMEOUT, format!("Second-{}", self.lock_timeout));
+
+        let req = req
+            .body(Buffer::from(Bytes::from(LOCK_REQUEST)))
+            .map_err(new_request_build_error)?;
+
+        let resp = self.client.send(req).await?;
+        if !resp.status().is_success() {
+            return Err(parse_error(resp));
+        }
+
+        let token = parse_header_to_str(resp.headers(), HEADER_LOCK_TOKEN)?
+            .map(|v| v.trim().trim_start_matches('<').trim_end_matches('>'))
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::Unexpected,
+                    "lock token not present in returning response",
+                )
+            })?;
+
+        Ok(WebdavLock {
+            token: token.to_string(),
+            created: resp.status() == StatusCode::CREATED,
+            acquired_at: Utc::now(),
+        })
+    }
+
+    /// Refresh the lock so that it won't be expired.
+    ///
+    /// reference: [RFC4918: 9.10.2. Refreshing Locks](https://datatracker.ietf.org/doc/html/rfc4918#section-9.10.2)
+    pub async fn webdav_refresh_lock(&self, path: &str, lock: &mut WebdavLock) -> Result<()> {
+        let path = build_rooted_abs_path(&self.root, path);
+        let url = format!("{}{}", self.endpoint, percent_encode_path(&path));
+
+        let mut req = Request::builder().method("LOCK").uri(&url);
+
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+
+        req = req.header(HEADER_IF, format!("(<{}>)", lock.token));
+        req = req.header(HEADER_TIMEOUT, format!("Second-{}", self.lock_timeout));
+
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        let resp = self.client.send(req).await?;
+        if !resp.status().is_success() {
+            return Err(parse_error(resp));
+        }
+        lock.acquired_at = Utc::now();
+        Ok(())
+    }
+
+    /// Release the lock.
+    pub async fn webdav_unlock(&self, path: &str, lock: &WebdavLock) -> Result<()> {
+        let path = build_rooted_abs_path(&self.root, path);
+        let url = format!("{}{}", self.endpoint, percent_encode_path(&path));
+
+        let mut req = Request::builder().method("UNLOCK").uri(&url);
+
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+
+        req = req.header(HEADER_LOCK_TOKEN, format!("<{}>", lock.token));
+
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        let resp = self.client.send(req).await?;
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     pub async fn webdav_copy(&self, from: &str, to: &str) -> Result<Response<Buffer>> {
         // Check if source file exists.
         let _ = self.webdav_stat(from).await?;
@@ -460,6 +610,22 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_lock_should_refresh() {
+        let lock = WebdavLock {
+            token: "opaquelocktoken:e71d4fae-5dec-22d6-fea5-00a0c91e6be4".to_string(),
+            created: false,
+            acquired_at: Utc::now(),
+        };
+        assert!(!lock.should_refresh(600));
+
+        let lock = WebdavLock {
+            acquired_at: Utc::now() - chrono::TimeDelta::try_seconds(300).unwrap(),
+            ..lock
+        };
+        assert!(lock.should_refresh(600));
+    }
+
     #[test]
     fn test_propstat() {
         let xml = r#"<D:propstat>
@@ -19,6 +19,7 @@ use super::core::*;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
+use http::Response;
 use http::StatusCode;
 use std::sync::Arc;
 
@@ -34,12 +35,36 @@ impl WebdavDeleter {
 
 impl oio::OneShotDelete for WebdavDeleter {
     async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
-        let resp = self.core.webdav_delete(&path).await?;
+        if !self.core.enable_lock {
+            let resp = self.core.webdav_delete(&path, None).await?;
+            return parse_delete_response(resp);
+        }
+
+        // Skip locking if the resource doesn't exist, since `LOCK` will
+        // create an empty resource for it.
+        match self.core.webdav_stat(&path).await {
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+            Ok(_) => {}
+        }
 
-        let status = resp.status();
-        match status {
-            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => Ok(()),
-            _ => Err(parse_error(resp)),
+        let lock = self.core.webdav_lock(&path).await?;
+        let res = match self.core.webdav_delete(&path, Some(&lock.token)).await {
+            Ok(resp) => parse_delete_response(resp),
+            Err(err) => Err(err),
+        };
+        // Locks on the resource are removed by server after delete succeeded.
+        if res.is_err() {
+            let _ = self.core.webdav_unlock(&path, &lock).await;
         }
+        res
+    }
+}
+
+fn parse_delete_response(resp: Response<Buffer>) -> Result<()> {
+    let status = resp.status();
+    match status {
+        StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => Ok(()),
+        _ => Err(parse_error(resp)),
     }
 }
@@ -24,24 +24,35 @@ use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
+/// WebdavWriter buffers the content and uploads it by a single `PUT` while closing.
+///
+/// If lock is enabled, the lock is refreshed while the writer is in use and
+/// only released after the content has been uploaded.
 pub struct WebdavWriter {
     core: Arc<WebdavCore>,
 
     op: OpWrite,
     path: String,
+    lock: Option<WebdavLock>,
+    buffer: Option<Buffer>,
 }
 
 impl WebdavWriter {
-    pub fn new(core: Arc<WebdavCore>, op: OpWrite, path: String) -> Self {
-        WebdavWriter { core, op, path }
+    pub fn new(core: Arc<WebdavCore>, op: OpWrite, path: String, lock: Option<WebdavLock>) -> Self {
+        WebdavWriter {
+            core,
+            op,
+            path,
+            lock,
+            buffer: None,
+        }
     }
-}
 
-impl oio::OneShotWrite for WebdavWriter {
-    async fn write_once(&self, bs: Buffer) -> Result<()> {
+    async fn put(&self, bs: Buffer) -> Result<()> {
+        let lock_token = self.lock.as_ref().map(|lock| lock.token.as_str());
         let resp = self
             .core
-            .webdav_put(&self.path, Some(bs.len() as u64), &self.op, bs)
+            .webdav_put(&self.path, Some(bs.len() as u64), &self.op, lock_token, bs)
             .await?;
 
         let status = resp.status();
@@ -51,4 +62,62 @@ impl oio::OneShotWrite for WebdavWriter {
             _ => Err(parse_error(resp)),
         }
     }
+
+    /// Refresh the lock in case it's going to expire.
+    async fn refresh_lock(&mut self) -> Result<()> {
+        if let Some(lock) = &mut self.lock {
+            if lock.should_refresh(self.core.lock_timeout) {
+                self.core.webdav_refresh_lock(&self.path, lock).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl oio::Write for WebdavWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        if self.buffer.is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "WebdavWriter doesn't support multiple write",
+            ));
+        }
+
+        self.refresh_lock().await?;
+        self.buffer = Some(bs);
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.refresh_lock().await?;
+
+        // Keep the lock if put failed, so that close can be retried.
+        self.put(self.buffer.clone().unwrap_or_default()).await?;
+
+        if let Some(lock) = &self.lock {
+            self.core.webdav_unlock(&self.path, lock).await?;
+            self.lock = None;
+        }
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buffer = None;
+
+        let Some(lock) = self.lock.take() else {
+            return Ok(());
+        };
+        // Remove the empty resource created by `LOCK`, the lock is removed along with it.
+        if !lock.created {
+            return self.core.webdav_unlock(&self.path, &lock).await;
+        }
+        let resp = self
+            .core
+            .webdav_delete(&self.path, Some(&lock.token))
+            .await?;
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK | StatusCode::NOT_FOUND => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
 }
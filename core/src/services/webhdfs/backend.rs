@@ -46,7 +46,10 @@ const WEBHDFS_DEFAULT_ENDPOINT: &str = "http://127.0.0.1:9870";
 impl Configurator for WebhdfsConfig {
     type Builder = WebhdfsBuilder;
     fn into_builder(self) -> Self::Builder {
-        WebhdfsBuilder { config: self }
+        WebhdfsBuilder {
+            config: self,
+            http_client: None,
+        }
     }
 }
 
@@ -55,6 +58,7 @@ impl Configurator for WebhdfsConfig {
 #[derive(Default, Clone)]
 pub struct WebhdfsBuilder {
     config: WebhdfsConfig,
+    http_client: Option<HttpClient>,
 }
 
 impl Debug for WebhdfsBuilder {
@@ -138,12 +142,27 @@ impl WebhdfsBuilder {
         };
         self
     }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
 }
 
 impl Builder for WebhdfsBuilder {
     const SCHEME: Scheme = Scheme::Webhdfs;
     type Config = WebhdfsConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// build the backend
     ///
     /// # Note
@@ -174,7 +193,14 @@ impl Builder for WebhdfsBuilder {
 
         let auth = self.config.delegation.map(|dt| format!("delegation={dt}"));
 
-        let client = HttpClient::new()?;
+        let client = if let Some(client) = self.http_client {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Webhdfs)
+            })?
+        };
 
         let backend = WebhdfsBackend {
             root,
@@ -427,7 +453,7 @@ impl WebhdfsBackend {
             percent_encode_path(&p),
         );
         if !start_after.is_empty() {
-            url += format!("&startAfter={}", start_after).as_str();
+            url += format!("&startAfter={}", percent_encode_path(start_after)).as_str();
         }
         if let Some(auth) = &self.auth {
             url += format!("&{auth}").as_str();
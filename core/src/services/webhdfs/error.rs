@@ -53,11 +53,7 @@ pub(super) fn parse_error_msg(parts: Parts, body: &str) -> Error {
         // passing invalid arguments will return BAD_REQUEST
         // should be un-retryable
         StatusCode::BAD_REQUEST => (ErrorKind::Unexpected, false),
-        StatusCode::INTERNAL_SERVER_ERROR
-        | StatusCode::BAD_GATEWAY
-        | StatusCode::SERVICE_UNAVAILABLE
-        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        _ => parse_http_status(parts.status),
     };
 
     let message = match serde_json::from_str::<WebHdfsErrorWrapper>(body) {
@@ -15,11 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use bytes::Buf;
 use http::StatusCode;
 use uuid::Uuid;
 
 use super::backend::WebhdfsBackend;
 use super::error::parse_error;
+use super::message::FileStatusWrapper;
 use crate::raw::*;
 use crate::*;
 
@@ -142,21 +144,21 @@ impl oio::BlockWrite for WebhdfsWriter {
 
 impl oio::AppendWrite for WebhdfsWriter {
     async fn offset(&self) -> Result<u64> {
-        Ok(0)
-    }
-
-    async fn append(&self, _offset: u64, size: u64, body: Buffer) -> Result<()> {
         let resp = self.backend.webhdfs_get_file_status(&self.path).await?;
 
         let status = resp.status();
 
-        let location;
-
         match status {
             StatusCode::OK => {
-                location = self.backend.webhdfs_init_append_request(&self.path).await?;
+                let bs = resp.into_body();
+                let file_status = serde_json::from_reader::<_, FileStatusWrapper>(bs.reader())
+                    .map_err(new_json_deserialize_error)?
+                    .file_status;
+
+                Ok(file_status.length)
             }
             StatusCode::NOT_FOUND => {
+                // Create an empty file so that all following writes could be appended.
                 let req = self
                     .backend
                     .webhdfs_create_object_request(&self.path, None, &self.op, Buffer::new())
@@ -167,14 +169,17 @@ impl oio::AppendWrite for WebhdfsWriter {
                 let status = resp.status();
 
                 match status {
-                    StatusCode::CREATED | StatusCode::OK => {
-                        location = self.backend.webhdfs_init_append_request(&self.path).await?;
-                    }
-                    _ => return Err(parse_error(resp)),
+                    StatusCode::CREATED | StatusCode::OK => Ok(0),
+                    _ => Err(parse_error(resp)),
                 }
             }
-            _ => return Err(parse_error(resp)),
+            _ => Err(parse_error(resp)),
         }
+    }
+
+    async fn append(&self, _offset: u64, size: u64, body: Buffer) -> Result<()> {
+        // The file has been created in `offset`, append to it directly.
+        let location = self.backend.webhdfs_init_append_request(&self.path).await?;
 
         let req = self.backend.webhdfs_append_request(&location, size, body)?;
 
@@ -104,6 +104,10 @@ impl Builder for YandexDiskBuilder {
     const SCHEME: Scheme = Scheme::YandexDisk;
     type Config = YandexDiskConfig;
 
+    fn with_http_client(self, client: HttpClient) -> Self {
+        self.http_client(client)
+    }
+
     /// Builds the backend and returns the result of YandexDiskBackend.
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
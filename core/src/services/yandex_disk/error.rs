@@ -43,8 +43,8 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
         // We should retry it when we get 423 error.
         423 => (ErrorKind::RateLimited, true),
         499 => (ErrorKind::Unexpected, true),
-        503 | 507 => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        507 => (ErrorKind::Unexpected, true),
+        _ => parse_http_status(parts.status),
     };
 
     let (message, _yandex_disk_err) = de::from_reader::<_, YandexDiskError>(bs.clone().reader())
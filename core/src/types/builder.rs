@@ -56,6 +56,14 @@ pub trait Builder: Default + 'static {
 
     /// Consume the accessor builder to build a service.
     fn build(self) -> Result<impl Access>;
+
+    /// Set the http client used by this service.
+    ///
+    /// Services that don't talk over http will ignore it.
+    fn with_http_client(self, client: HttpClient) -> Self {
+        let _ = client;
+        self
+    }
 }
 
 /// Dummy implementation of builder
@@ -82,6 +82,8 @@ pub struct Capability {
     pub stat_with_override_content_type: bool,
     /// Indicates if versions stat operations are supported.
     pub stat_with_version: bool,
+    /// Indicates if customer provided encryption key (SSE-C) is supported during stat operations.
+    pub stat_with_server_side_encryption_customer_key: bool,
     /// Indicates whether cache control information is available in stat response
     pub stat_has_cache_control: bool,
     /// Indicates whether content disposition information is available in stat response
@@ -90,6 +92,10 @@ pub struct Capability {
     pub stat_has_content_length: bool,
     /// Indicates whether content MD5 checksum is available in stat response
     pub stat_has_content_md5: bool,
+    /// Indicates whether CRC32C checksum is available in stat response
+    pub stat_has_checksum_crc32c: bool,
+    /// Indicates whether SHA256 checksum is available in stat response
+    pub stat_has_checksum_sha256: bool,
     /// Indicates whether content range information is available in stat response
     pub stat_has_content_range: bool,
     /// Indicates whether content type information is available in stat response
@@ -104,6 +110,10 @@ pub struct Capability {
     pub stat_has_version: bool,
     /// Indicates whether user-defined metadata is available in stat response
     pub stat_has_user_metadata: bool,
+    /// Indicates whether POSIX permission bits are available in stat response
+    pub stat_has_permissions: bool,
+    /// Indicates whether owner uid and gid are available in stat response
+    pub stat_has_owner: bool,
 
     /// Indicates if the operator supports read operations.
     pub read: bool,
@@ -123,6 +133,8 @@ pub struct Capability {
     pub read_with_override_content_type: bool,
     /// Indicates if versions read operations are supported.
     pub read_with_version: bool,
+    /// Indicates if customer provided encryption key (SSE-C) is supported during read operations.
+    pub read_with_server_side_encryption_customer_key: bool,
 
     /// Indicates if the operator supports write operations.
     pub write: bool,
@@ -148,12 +160,25 @@ pub struct Capability {
     pub write_with_if_not_exists: bool,
     /// Indicates if custom user metadata can be attached during write operations.
     pub write_with_user_metadata: bool,
+    /// Indicates if server side encryption method and key id can be specified during write operations.
+    pub write_with_server_side_encryption: bool,
+    /// Indicates if customer provided encryption key (SSE-C) can be specified during write operations.
+    pub write_with_server_side_encryption_customer_key: bool,
+    /// Indicates if an expire duration (TTL) can be attached during write operations.
+    pub write_with_expire_after: bool,
+    /// Indicates if POSIX permission bits can be specified during write operations.
+    pub write_with_permissions: bool,
+    /// Indicates if multipart uploads can be tracked and resumed across writers.
+    pub write_with_resume: bool,
     /// Maximum size supported for multipart uploads.
     /// For example, AWS S3 supports up to 5GiB per part in multipart uploads.
     pub write_multi_max_size: Option<usize>,
     /// Minimum size required for multipart uploads (except for the last part).
     /// For example, AWS S3 requires at least 5MiB per part.
     pub write_multi_min_size: Option<usize>,
+    /// Alignment required for multipart uploads (except for the last part).
+    /// For example, GCS requires chunks of resumable uploads to be multiples of 256KiB.
+    pub write_multi_align_size: Option<usize>,
     /// Maximum total size supported for write operations.
     /// For example, Cloudflare D1 has a 1MB total size limit.
     pub write_total_max_size: Option<usize>,
@@ -182,6 +207,8 @@ pub struct Capability {
     pub list_with_start_after: bool,
     /// Indicates if recursive listing is supported.
     pub list_with_recursive: bool,
+    /// Indicates if listing with custom delimiter is supported.
+    pub list_with_delimiter: bool,
     /// Indicates if versions listing is supported.
     #[deprecated(since = "0.51.1", note = "use with_versions instead")]
     pub list_with_version: bool,
@@ -189,6 +216,10 @@ pub struct Capability {
     pub list_with_versions: bool,
     /// Indicates if listing with deleted files included is supported.
     pub list_with_deleted: bool,
+    /// Indicates if list operations support filtering entries by glob pattern.
+    pub list_with_glob: bool,
+    /// Indicates if list operations support resuming from a continuation token.
+    pub list_with_start_token: bool,
     /// Indicates whether cache control information is available in list response
     pub list_has_cache_control: bool,
     /// Indicates whether content disposition information is available in list response
@@ -197,6 +228,8 @@ pub struct Capability {
     pub list_has_content_length: bool,
     /// Indicates whether content MD5 checksum is available in list response
     pub list_has_content_md5: bool,
+    /// Indicates whether CRC32C checksum is available in list response
+    pub list_has_checksum_crc32c: bool,
     /// Indicates whether content range information is available in list response
     pub list_has_content_range: bool,
     /// Indicates whether content type information is available in list response
@@ -218,6 +251,19 @@ pub struct Capability {
     pub presign_stat: bool,
     /// Indicates if presigned URLs for write operations are supported.
     pub presign_write: bool,
+    /// Indicates if presigned URLs for multipart upload operations are supported, including
+    /// initiating, uploading a part and completing a multipart upload.
+    pub presign_write_multi: bool,
+    /// Indicates if presigned POST policies for browser based uploads are supported.
+    pub presign_post: bool,
+    /// Indicates if extra headers can be included in the signature of presigned requests.
+    pub presign_with_headers: bool,
+
+    /// Indicates if object tagging (get, set and delete tags) is supported.
+    pub tag: bool,
+
+    /// Indicates if concatenating existing objects into a new one is supported.
+    pub concat: bool,
 
     /// Indicate if the operator supports shared access.
     pub shared: bool,
@@ -163,8 +163,16 @@ impl ReadGenerator {
         };
 
         let args = self.ctx.args.clone().with_range(range);
-        let (_, r) = self.ctx.acc.read(&self.ctx.path, args).await?;
-        Ok(Some(r))
+        let (rp, r) = self.ctx.acc.read(&self.ctx.path, args).await?;
+        if self.ctx.options.resume_times() == 0 {
+            return Ok(Some(r));
+        }
+        Ok(Some(Box::new(ResumableReader::new(
+            self.ctx.clone(),
+            range,
+            rp.etag(),
+            r,
+        ))))
     }
 
     /// Generate next blocking reader.
@@ -174,17 +182,321 @@ impl ReadGenerator {
         };
 
         let args = self.ctx.args.clone().with_range(range);
-        let (_, r) = self.ctx.acc.blocking_read(&self.ctx.path, args)?;
-        Ok(Some(r))
+        let (rp, r) = self.ctx.acc.blocking_read(&self.ctx.path, args)?;
+        if self.ctx.options.resume_times() == 0 {
+            return Ok(Some(r));
+        }
+        Ok(Some(Box::new(ResumableReader::new(
+            self.ctx.clone(),
+            range,
+            rp.etag(),
+            r,
+        ))))
+    }
+}
+
+/// ResumableReader will reissue a ranged read from the already delivered offset
+/// if the underlying reader failed with a temporary error.
+///
+/// `RetryLayer` marks temporary errors that are still failing after retry as
+/// persistent, we resume on them too since the ranged re-read is issued as a
+/// brand new request instead of polling the broken reader again. Permanent
+/// errors like `NotFound` or `ConditionNotMatch` are never resumed.
+///
+/// The resume count is reset after every successful read, so that long downloads
+/// can survive multiple transient failures as long as they keep making progress.
+///
+/// If the service supports `read_with_if_match`, resumed reads are pinned to the
+/// etag returned by the first response, so bytes from different versions of the
+/// file will never be spliced into one read.
+pub struct ResumableReader<R> {
+    ctx: Arc<ReadContext>,
+
+    range: BytesRange,
+    if_match: Option<String>,
+    reader: Option<R>,
+    resumed: usize,
+}
+
+impl<R> ResumableReader<R> {
+    /// Create a new ResumableReader for given range.
+    fn new(ctx: Arc<ReadContext>, range: BytesRange, etag: Option<&str>, reader: R) -> Self {
+        let if_match = match ctx.args.if_match() {
+            Some(_) => None,
+            None if ctx.acc.info().full_capability().read_with_if_match => etag.map(String::from),
+            None => None,
+        };
+
+        Self {
+            ctx,
+            range,
+            if_match,
+            reader: Some(reader),
+            resumed: 0,
+        }
+    }
+
+    /// Build the args to resume reading from the delivered offset.
+    fn resume_args(&self) -> OpRead {
+        let args = self.ctx.args.clone().with_range(self.range);
+        match &self.if_match {
+            Some(etag) => args.with_if_match(etag),
+            None => args,
+        }
+    }
+
+    /// Check if we should resume reading after given error.
+    fn should_resume(&mut self, err: &Error) -> bool {
+        if !(err.is_temporary() || err.is_persistent())
+            || self.resumed >= self.ctx.options.resume_times()
+        {
+            return false;
+        }
+
+        self.resumed += 1;
+        self.reader = None;
+        true
+    }
+
+    /// Update the range after data has been delivered.
+    fn advance(&mut self, buf: &Buffer) {
+        if !buf.is_empty() {
+            self.range.advance(buf.len() as u64);
+            self.resumed = 0;
+        }
+    }
+}
+
+impl oio::Read for ResumableReader<oio::Reader> {
+    async fn read(&mut self) -> Result<Buffer> {
+        loop {
+            let res = match self.reader.as_mut() {
+                Some(r) => r.read().await,
+                None => {
+                    let args = self.resume_args();
+                    match self.ctx.acc.read(&self.ctx.path, args).await {
+                        Ok((_, r)) => {
+                            self.reader = Some(r);
+                            continue;
+                        }
+                        Err(err) => Err(err),
+                    }
+                }
+            };
+
+            match res {
+                Ok(buf) => {
+                    self.advance(&buf);
+                    return Ok(buf);
+                }
+                Err(err) if self.should_resume(&err) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+impl oio::BlockingRead for ResumableReader<oio::BlockingReader> {
+    fn read(&mut self) -> Result<Buffer> {
+        loop {
+            let res = match self.reader.as_mut() {
+                Some(r) => r.read(),
+                None => {
+                    let args = self.resume_args();
+                    match self.ctx.acc.blocking_read(&self.ctx.path, args) {
+                        Ok((_, r)) => {
+                            self.reader = Some(r);
+                            continue;
+                        }
+                        Err(err) => Err(err),
+                    }
+                }
+            };
+
+            match res {
+                Ok(buf) => {
+                    self.advance(&buf);
+                    return Ok(buf);
+                }
+                Err(err) if self.should_resume(&err) => continue,
+                Err(err) => return Err(err),
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Mutex;
+    use std::time::Duration;
 
     use bytes::Bytes;
 
     use super::*;
+    use crate::layers::RetryLayer;
+    use crate::layers::TypeEraseLayer;
+    use crate::raw::oio::Read;
+
+    /// FlakyService fails the first `fails` read requests after delivering half of the data.
+    ///
+    /// The failure is temporary unless `kind` is set.
+    #[derive(Debug, Default)]
+    struct FlakyService {
+        fails: usize,
+        kind: Option<ErrorKind>,
+        opened: AtomicUsize,
+        if_match: Arc<Mutex<Vec<Option<String>>>>,
+    }
+
+    impl FlakyService {
+        fn new(fails: usize) -> Self {
+            Self {
+                fails,
+                ..Default::default()
+            }
+        }
+    }
+
+    impl Access for FlakyService {
+        type Reader = FlakyReader;
+        type Writer = oio::Writer;
+        type Lister = oio::Lister;
+        type Deleter = oio::Deleter;
+        type BlockingReader = oio::BlockingReader;
+        type BlockingWriter = oio::BlockingWriter;
+        type BlockingLister = oio::BlockingLister;
+        type BlockingDeleter = oio::BlockingDeleter;
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            let mut info = AccessorInfo::default();
+            info.set_native_capability(Capability {
+                read: true,
+                read_with_if_match: true,
+                ..Default::default()
+            });
+            info.into()
+        }
+
+        async fn read(&self, _: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            self.if_match
+                .lock()
+                .unwrap()
+                .push(args.if_match().map(String::from));
+
+            let offset = args.range().offset() as usize;
+            let reader = FlakyReader {
+                data: Bytes::from_static(b"HelloWorld").slice(offset..),
+                fail: self.opened.fetch_add(1, Ordering::SeqCst) < self.fails,
+                kind: self.kind,
+            };
+            Ok((RpRead::new().with_etag(Some("v1".to_string())), reader))
+        }
+    }
+
+    struct FlakyReader {
+        data: Bytes,
+        fail: bool,
+        kind: Option<ErrorKind>,
+    }
+
+    impl oio::Read for FlakyReader {
+        async fn read(&mut self) -> Result<Buffer> {
+            if self.fail && self.data.len() < 10 {
+                return Err(match self.kind {
+                    Some(kind) => Error::new(kind, "file changed"),
+                    None => Error::new(ErrorKind::Unexpected, "connection reset").set_temporary(),
+                });
+            }
+            let n = if self.fail { 5 } else { self.data.len() };
+            Ok(Buffer::from(self.data.split_to(n)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_next_reader_with_resume() -> Result<()> {
+        let ctx = Arc::new(ReadContext::new(
+            Arc::new(TypeEraseLayer.layer(FlakyService::new(1))),
+            "test".to_string(),
+            OpRead::new(),
+            OpReader::new().with_resume_times(1),
+        ));
+        let mut generator = ReadGenerator::new(ctx, 0, Some(10));
+        let mut r = generator.next_reader().await?.unwrap();
+        let buf = r.read_all().await?;
+        pretty_assertions::assert_eq!(buf.to_bytes(), "HelloWorld");
+
+        let ctx = Arc::new(ReadContext::new(
+            Arc::new(TypeEraseLayer.layer(FlakyService::new(1))),
+            "test".to_string(),
+            OpRead::new(),
+            OpReader::new(),
+        ));
+        let mut generator = ReadGenerator::new(ctx, 0, Some(10));
+        let mut r = generator.next_reader().await?.unwrap();
+        let err = r.read_all().await.unwrap_err();
+        assert!(err.is_temporary());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_next_reader_with_resume_after_retry() -> Result<()> {
+        let srv = FlakyService::new(2);
+        let if_match = srv.if_match.clone();
+        // The reopen inside RetryLayer fails again, so the error is marked as persistent.
+        let acc = RetryLayer::new()
+            .with_max_times(1)
+            .with_min_delay(Duration::from_millis(1))
+            .layer(srv);
+
+        let ctx = Arc::new(ReadContext::new(
+            Arc::new(TypeEraseLayer.layer(acc)),
+            "test".to_string(),
+            OpRead::new(),
+            OpReader::new().with_resume_times(1),
+        ));
+        let mut generator = ReadGenerator::new(ctx, 0, Some(10));
+        let mut r = generator.next_reader().await?.unwrap();
+        let buf = r.read_all().await?;
+        pretty_assertions::assert_eq!(buf.to_bytes(), "HelloWorld");
+        pretty_assertions::assert_eq!(
+            *if_match.lock().unwrap(),
+            vec![None, Some("v1".to_string()), Some("v1".to_string())]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_next_reader_without_resume_on_permanent_error() -> Result<()> {
+        for kind in [ErrorKind::NotFound, ErrorKind::ConditionNotMatch] {
+            let srv = FlakyService {
+                fails: 2,
+                kind: Some(kind),
+                ..Default::default()
+            };
+            let if_match = srv.if_match.clone();
+            // RetryLayer must not turn permanent errors into resumable ones.
+            let acc = RetryLayer::new()
+                .with_max_times(1)
+                .with_min_delay(Duration::from_millis(1))
+                .layer(srv);
+
+            let ctx = Arc::new(ReadContext::new(
+                Arc::new(TypeEraseLayer.layer(acc)),
+                "test".to_string(),
+                OpRead::new(),
+                OpReader::new().with_resume_times(1),
+            ));
+            let mut generator = ReadGenerator::new(ctx, 0, Some(10));
+            let mut r = generator.next_reader().await?.unwrap();
+            let err = r.read_all().await.unwrap_err();
+            assert_eq!(err.kind(), kind);
+            assert!(!err.is_temporary() && !err.is_persistent());
+            pretty_assertions::assert_eq!(if_match.lock().unwrap().len(), 1);
+        }
+        Ok(())
+    }
 
     #[tokio::test]
     async fn test_next_reader() -> Result<()> {
@@ -75,7 +75,8 @@ impl WriteContext {
     fn calculate_chunk_size(&self) -> (Option<usize>, bool) {
         let cap = self.accessor().info().full_capability();
 
-        let exact = self.options().chunk().is_some();
+        // Chunks must be written in exact size if service requires alignment.
+        let exact = self.options().chunk().is_some() || cap.write_multi_align_size.is_some();
         let chunk_size = self
             .options()
             .chunk()
@@ -87,6 +88,14 @@ impl WriteContext {
                 if let Some(v) = cap.write_multi_min_size {
                     size = size.max(v);
                 }
+                if let Some(v) = cap.write_multi_align_size {
+                    size = size.div_ceil(v) * v;
+                    if let Some(max) = cap.write_multi_max_size {
+                        if size > max {
+                            size = max / v * v;
+                        }
+                    }
+                }
 
                 size
             });
@@ -132,6 +141,11 @@ impl WriteGenerator<oio::Writer> {
     }
 }
 
+/// # Cancellation
+///
+/// The buffered data will only be dropped after the underlying writer has accepted
+/// it, so the futures returned by `write` and `close` can be dropped at any await
+/// point without losing data that has been buffered before.
 impl WriteGenerator<oio::Writer> {
     /// Write the entire buffer into writer.
     pub async fn write(&mut self, mut bs: Buffer) -> Result<usize> {
@@ -154,9 +168,10 @@ impl WriteGenerator<oio::Writer> {
         // - write buffer + bs directly.
         if !self.exact {
             let fill_size = bs.len();
-            self.buffer.push(bs);
-            let buf = self.buffer.take().collect();
-            self.w.write_dyn(buf).await?;
+            let mut buf = self.buffer.clone();
+            buf.push(bs);
+            self.w.write_dyn(buf.collect()).await?;
+            self.buffer.clear();
             return Ok(fill_size);
         }
 
@@ -167,8 +182,9 @@ impl WriteGenerator<oio::Writer> {
         // Action:
         // - write existing buffer in chunk_size to make more rooms for writing data.
         if self.buffer.len() >= chunk_size {
-            let buf = self.buffer.take().collect();
+            let buf = self.buffer.clone().collect();
             self.w.write_dyn(buf).await?;
+            self.buffer.clear();
         }
 
         // Condition
@@ -190,8 +206,9 @@ impl WriteGenerator<oio::Writer> {
                 break;
             }
 
-            let buf = self.buffer.take().collect();
+            let buf = self.buffer.clone().collect();
             self.w.write_dyn(buf).await?;
+            self.buffer.clear();
         }
 
         self.w.close().await
@@ -290,6 +307,7 @@ mod tests {
     use bytes::Buf;
     use bytes::BufMut;
     use bytes::Bytes;
+    use futures::FutureExt;
     use log::debug;
     use pretty_assertions::assert_eq;
     use rand::thread_rng;
@@ -324,6 +342,69 @@ mod tests {
         }
     }
 
+    /// PendingOnceWriter will be pending forever on the first write.
+    struct PendingOnceWriter {
+        buf: Arc<Mutex<Vec<u8>>>,
+        pending: bool,
+    }
+
+    impl Write for PendingOnceWriter {
+        async fn write(&mut self, bs: Buffer) -> Result<()> {
+            if self.pending {
+                self.pending = false;
+                futures::future::pending::<()>().await;
+            }
+
+            let mut buf = self.buf.lock().await;
+            buf.put(bs);
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_buf_writer_cancel_safe() -> Result<()> {
+        let buf = Arc::new(Mutex::new(vec![]));
+        let mut w = WriteGenerator::new(
+            Box::new(PendingOnceWriter {
+                buf: buf.clone(),
+                pending: true,
+            }),
+            Some(10),
+            false,
+        );
+
+        let mut rng = thread_rng();
+        let mut expected = vec![0; 15];
+        rng.fill_bytes(&mut expected);
+
+        // The first 5 bytes will be buffered.
+        let n = w
+            .write(Bytes::copy_from_slice(&expected[..5]).into())
+            .await?;
+        assert_eq!(n, 5);
+
+        // Flush will be pending, drop the future to cancel it.
+        let bs: Buffer = Bytes::copy_from_slice(&expected[5..]).into();
+        assert!(w.write(bs.clone()).now_or_never().is_none());
+
+        // Retry the write, the buffered data must not be lost.
+        let n = w.write(bs).await?;
+        assert_eq!(n, 10);
+        w.close().await?;
+
+        let buf = buf.lock().await;
+        assert_eq!(*buf, expected);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_exact_buf_writer_short_write() -> Result<()> {
         let _ = tracing_subscriber::fmt()
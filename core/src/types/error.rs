@@ -69,6 +69,14 @@ pub enum ErrorKind {
     AlreadyExists,
     /// Requests that sent to this path is over the limit, please slow down.
     RateLimited,
+    /// The service is unavailable for now, for example, it's overloaded or under maintenance.
+    ///
+    /// Errors of this kind are usually temporary and can be retried later.
+    Unavailable,
+    /// The operation timed out, for example, the response stalled without data.
+    ///
+    /// Errors of this kind are usually temporary and can be retried later.
+    TimedOut,
     /// The given file paths are same.
     IsSameFile,
     /// The condition of this operation is not match.
@@ -113,6 +121,8 @@ impl From<ErrorKind> for &'static str {
             ErrorKind::NotADirectory => "NotADirectory",
             ErrorKind::AlreadyExists => "AlreadyExists",
             ErrorKind::RateLimited => "RateLimited",
+            ErrorKind::Unavailable => "Unavailable",
+            ErrorKind::TimedOut => "TimedOut",
             ErrorKind::IsSameFile => "IsSameFile",
             ErrorKind::ConditionNotMatch => "ConditionNotMatch",
             ErrorKind::RangeNotSatisfied => "RangeNotSatisfied",
@@ -120,6 +130,9 @@ impl From<ErrorKind> for &'static str {
     }
 }
 
+/// The context key of the native error code returned by services.
+const SERVICE_CODE_KEY: &str = "service_code";
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum ErrorStatus {
     /// Permanent means without external changes, the error never changes.
@@ -339,6 +352,17 @@ impl Error {
         self
     }
 
+    /// Set the native error code returned by the underlying service.
+    ///
+    /// For example, `NoSuchKey` or `SlowDown` returned by s3.
+    ///
+    /// The code is kept in context with key `service_code`.
+    pub fn with_service_code(mut self, code: impl Into<String>) -> Self {
+        self.context.retain(|(k, _)| *k != SERVICE_CODE_KEY);
+        self.context.push((SERVICE_CODE_KEY, code.into()));
+        self
+    }
+
     /// Set source for error.
     ///
     /// # Notes
@@ -386,8 +410,13 @@ impl Error {
     /// Set persistent status for error.
     ///
     /// By setting persistent, we indicate the retry should be stopped.
+    ///
+    /// Only temporary errors will be marked as persistent, permanent errors
+    /// are kept as is.
     pub fn set_persistent(mut self) -> Self {
-        self.status = ErrorStatus::Persistent;
+        if self.status == ErrorStatus::Temporary {
+            self.status = ErrorStatus::Persistent;
+        }
         self
     }
 
@@ -400,6 +429,40 @@ impl Error {
     pub fn is_temporary(&self) -> bool {
         self.status == ErrorStatus::Temporary
     }
+
+    /// Check if this error is persistent, which means it used to be temporary
+    /// but still failed after retry.
+    pub(crate) fn is_persistent(&self) -> bool {
+        self.status == ErrorStatus::Persistent
+    }
+
+    /// Return the native error code returned by the underlying service if
+    /// available.
+    ///
+    /// The code is service specific, for example, s3 could return `NoSuchKey`,
+    /// `SlowDown` or `AccessDenied`. Users can use it to handle errors more
+    /// precisely than [`ErrorKind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// if let Err(e) = op.read("test_file").await {
+    ///     if e.service_code() == Some("SlowDown") {
+    ///         println!("request has been throttled")
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn service_code(&self) -> Option<&str> {
+        self.context
+            .iter()
+            .find(|(k, _)| *k == SERVICE_CODE_KEY)
+            .map(|(_, v)| v.as_str())
+    }
 }
 
 impl From<Error> for io::Error {
@@ -407,6 +470,7 @@ impl From<Error> for io::Error {
         let kind = match err.kind() {
             ErrorKind::NotFound => io::ErrorKind::NotFound,
             ErrorKind::PermissionDenied => io::ErrorKind::PermissionDenied,
+            ErrorKind::TimedOut => io::ErrorKind::TimedOut,
             _ => io::ErrorKind::Other,
         };
 
@@ -461,4 +525,13 @@ Source:
 "#
         )
     }
+
+    #[test]
+    fn test_error_service_code() {
+        let err = Error::new(ErrorKind::RateLimited, "please slow down");
+        assert_eq!(err.service_code(), None);
+
+        let err = err.with_service_code("SlowDown").with_operation("Read");
+        assert_eq!(err.service_code(), Some("SlowDown"));
+    }
 }
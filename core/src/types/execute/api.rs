@@ -64,12 +64,27 @@ pub trait Execute: Send + Sync + 'static {
     fn timeout(&self) -> Option<BoxedStaticFuture<()>> {
         None
     }
+
+    /// Check if this executor is able to run futures in background right now.
+    ///
+    /// OpenDAL will check this before spawning best-effort cleanup tasks (for example,
+    /// aborting an unfinished multipart upload while dropping a writer) so that dropping
+    /// never panics.
+    ///
+    /// Default implementation returns `true`.
+    fn is_available(&self) -> bool {
+        true
+    }
 }
 
 impl Execute for () {
     fn execute(&self, _: BoxedStaticFuture<()>) {
         panic!("concurrent tasks executed with no executor has been enabled")
     }
+
+    fn is_available(&self) -> bool {
+        false
+    }
 }
 
 /// Task is generated by Executor that represents an executing task.
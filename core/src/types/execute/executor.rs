@@ -93,4 +93,19 @@ impl Executor {
         self.executor.execute(Box::pin(fut));
         Task::new(handle)
     }
+
+    /// Run given future in background and forget about it.
+    ///
+    /// Returns `false` without running the future if the executor is not available. This is
+    /// designed for best-effort cleanups in `Drop` which must never panic.
+    pub(crate) fn detach<F>(&self, f: F) -> bool
+    where
+        F: Future<Output = ()> + MaybeSend + 'static,
+    {
+        if !self.executor.is_available() {
+            return false;
+        }
+        self.executor.execute(Box::pin(f));
+        true
+    }
 }
@@ -27,6 +27,11 @@ impl Execute for TokioExecutor {
     fn execute(&self, f: BoxedStaticFuture<()>) {
         let _handle = tokio::task::spawn(f);
     }
+
+    /// `tokio::task::spawn` panics outside of a tokio runtime.
+    fn is_available(&self) -> bool {
+        tokio::runtime::Handle::try_current().is_ok()
+    }
 }
 
 #[cfg(test)]
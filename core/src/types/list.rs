@@ -35,6 +35,8 @@ pub struct Lister {
 
     fut: Option<BoxedStaticFuture<(oio::Lister, Result<Option<oio::Entry>>)>>,
     errored: bool,
+    /// The number of entries that can still be returned, `None` means no limit.
+    remaining: Option<usize>,
 }
 
 /// # Safety
@@ -45,23 +47,129 @@ unsafe impl Sync for Lister {}
 impl Lister {
     /// Create a new lister.
     pub(crate) async fn create(acc: Accessor, path: &str, args: OpList) -> Result<Self> {
-        let (_, lister) = acc.list(path, args).await?;
+        let remaining = args.max_entries();
+        let (_, lister) = acc.list(path, cap_page_size(args)).await?;
 
         Ok(Self {
             lister: Some(lister),
 
             fut: None,
             errored: false,
+            remaining,
         })
     }
+
+    /// Fetch the next page of entries along with the continuation token.
+    ///
+    /// The continuation token is opaque, users can persist it and resume the
+    /// list later by [`FutureLister::start_token`](crate::operator_futures::FutureLister::start_token).
+    /// `None` token means there are no more entries to list.
+    ///
+    /// Services without native continuation tokens will return all remaining
+    /// entries in one page.
+    ///
+    /// # Notes
+    ///
+    /// Please don't mix `next_page` with polling `Lister` as a stream, or the
+    /// returned token may not cover the entries returned by stream.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Operator;
+    /// # use opendal::Result;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut lister = op.lister_with("dir/").chunk(1000).await?;
+    /// loop {
+    ///     let (entries, token) = lister.next_page().await?;
+    ///     // Handle entries and checkpoint token here.
+    ///     if token.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn next_page(&mut self) -> Result<(Vec<Entry>, Option<String>)> {
+        let mut entries = Vec::new();
+
+        if self.errored || self.remaining == Some(0) {
+            return Ok((entries, None));
+        }
+
+        // Finish the pending future created by stream first.
+        let mut lister = match (self.lister.take(), self.fut.take()) {
+            (Some(lister), _) => lister,
+            (None, Some(fut)) => {
+                let (lister, res) = fut.await;
+                match res {
+                    Ok(Some(oe)) => {
+                        entries.push(oe.into_entry());
+                        if self.consume() {
+                            return Ok((entries, None));
+                        }
+                    }
+                    Ok(None) => return Ok((entries, None)),
+                    Err(err) => {
+                        self.errored = true;
+                        return Err(err);
+                    }
+                }
+                lister
+            }
+            (None, None) => return Ok((entries, None)),
+        };
+
+        loop {
+            match lister.next_dyn().await {
+                Ok(Some(oe)) => {
+                    entries.push(oe.into_entry());
+                    if self.consume() {
+                        return Ok((entries, None));
+                    }
+                    if let Some(token) = lister.continuation_token_dyn() {
+                        self.lister = Some(lister);
+                        return Ok((entries, Some(token)));
+                    }
+                }
+                Ok(None) => return Ok((entries, None)),
+                Err(err) => {
+                    self.errored = true;
+                    return Err(err);
+                }
+            }
+        }
+    }
+
+    /// Consume one entry from remaining, returns `true` if limit has been reached.
+    fn consume(&mut self) -> bool {
+        match self.remaining.as_mut() {
+            Some(n) => {
+                *n = n.saturating_sub(1);
+                *n == 0
+            }
+            None => false,
+        }
+    }
+}
+
+/// Cap the page size to max entries so that we don't fetch entries that
+/// won't be returned.
+fn cap_page_size(args: OpList) -> OpList {
+    match (args.max_entries(), args.limit()) {
+        (Some(max), Some(limit)) if limit <= max => args,
+        // Page size must be at least 1.
+        (Some(max), _) => args.with_limit(max.max(1)),
+        (None, _) => args,
+    }
 }
 
 impl Stream for Lister {
     type Item = Result<Entry>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        // Returns `None` if we have errored.
-        if self.errored {
+        // Returns `None` if we have errored or reached the max entries.
+        if self.errored || self.remaining == Some(0) {
             return Poll::Ready(None);
         }
 
@@ -79,7 +187,13 @@ impl Stream for Lister {
             self.fut = None;
 
             return match entry {
-                Ok(Some(oe)) => Poll::Ready(Some(Ok(oe.into_entry()))),
+                Ok(Some(oe)) => {
+                    if self.consume() {
+                        // Drop the underlying lister since no more entries are needed.
+                        self.lister = None;
+                    }
+                    Poll::Ready(Some(Ok(oe.into_entry())))
+                }
                 Ok(None) => {
                     self.lister = None;
                     Poll::Ready(None)
@@ -105,6 +219,8 @@ impl Stream for Lister {
 pub struct BlockingLister {
     lister: oio::BlockingLister,
     errored: bool,
+    /// The number of entries that can still be returned, `None` means no limit.
+    remaining: Option<usize>,
 }
 
 /// # Safety
@@ -115,11 +231,13 @@ unsafe impl Sync for BlockingLister {}
 impl BlockingLister {
     /// Create a new lister.
     pub(crate) fn create(acc: Accessor, path: &str, args: OpList) -> Result<Self> {
-        let (_, lister) = acc.blocking_list(path, args)?;
+        let remaining = args.max_entries();
+        let (_, lister) = acc.blocking_list(path, cap_page_size(args))?;
 
         Ok(Self {
             lister,
             errored: false,
+            remaining,
         })
     }
 }
@@ -128,13 +246,18 @@ impl Iterator for BlockingLister {
     type Item = Result<Entry>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // Returns `None` if we have errored.
-        if self.errored {
+        // Returns `None` if we have errored or reached the max entries.
+        if self.errored || self.remaining == Some(0) {
             return None;
         }
 
         match self.lister.next() {
-            Ok(Some(entry)) => Some(Ok(entry.into_entry())),
+            Ok(Some(entry)) => {
+                if let Some(n) = self.remaining.as_mut() {
+                    *n -= 1;
+                }
+                Some(Ok(entry.into_entry()))
+            }
             Ok(None) => None,
             Err(err) => {
                 self.errored = true;
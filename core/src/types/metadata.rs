@@ -64,6 +64,11 @@ pub struct Metadata {
     etag: Option<String>,
     last_modified: Option<DateTime<Utc>>,
     version: Option<String>,
+    checksum_crc32c: Option<String>,
+    checksum_sha256: Option<String>,
+    permissions: Option<u32>,
+    uid: Option<u32>,
+    gid: Option<u32>,
 
     user_metadata: Option<HashMap<String, String>>,
 }
@@ -87,6 +92,11 @@ impl Metadata {
             etag: None,
             content_disposition: None,
             version: None,
+            checksum_crc32c: None,
+            checksum_sha256: None,
+            permissions: None,
+            uid: None,
+            gid: None,
             user_metadata: None,
         }
     }
@@ -259,6 +269,107 @@ impl Metadata {
         self
     }
 
+    /// CRC32C checksum of this entry.
+    ///
+    /// The value is the base64 encoded big-endian CRC32C of the whole content, which is the
+    /// same format used by `x-goog-hash` and `x-amz-checksum-crc32c`.
+    pub fn checksum_crc32c(&self) -> Option<&str> {
+        self.checksum_crc32c.as_deref()
+    }
+
+    /// Set CRC32C checksum of this entry.
+    pub fn set_checksum_crc32c(&mut self, v: &str) -> &mut Self {
+        self.checksum_crc32c = Some(v.to_string());
+        self
+    }
+
+    /// Set CRC32C checksum of this entry.
+    pub fn with_checksum_crc32c(mut self, v: String) -> Self {
+        self.checksum_crc32c = Some(v);
+        self
+    }
+
+    /// SHA256 checksum of this entry.
+    ///
+    /// The value is the base64 encoded SHA256 digest, which is the same format used
+    /// by `x-amz-checksum-sha256`.
+    ///
+    /// For objects uploaded via multipart, services like s3 may return the checksum
+    /// of checksums instead of the digest of whole content.
+    pub fn checksum_sha256(&self) -> Option<&str> {
+        self.checksum_sha256.as_deref()
+    }
+
+    /// Set SHA256 checksum of this entry.
+    pub fn set_checksum_sha256(&mut self, v: &str) -> &mut Self {
+        self.checksum_sha256 = Some(v.to_string());
+        self
+    }
+
+    /// Set SHA256 checksum of this entry.
+    pub fn with_checksum_sha256(mut self, v: String) -> Self {
+        self.checksum_sha256 = Some(v);
+        self
+    }
+
+    /// Permissions of this entry.
+    ///
+    /// The value is the POSIX permission bits like `0o644`, including the
+    /// setuid, setgid and sticky bits. The file type bits are not included,
+    /// use [`Metadata::mode`] instead.
+    ///
+    /// Only services backed by a POSIX-like file system (like `fs`) will
+    /// return this value.
+    pub fn permissions(&self) -> Option<u32> {
+        self.permissions
+    }
+
+    /// Set permissions of this entry.
+    pub fn set_permissions(&mut self, v: u32) -> &mut Self {
+        self.permissions = Some(v);
+        self
+    }
+
+    /// Set permissions of this entry.
+    pub fn with_permissions(mut self, v: u32) -> Self {
+        self.permissions = Some(v);
+        self
+    }
+
+    /// User ID of the owner of this entry.
+    pub fn uid(&self) -> Option<u32> {
+        self.uid
+    }
+
+    /// Set user ID of the owner of this entry.
+    pub fn set_uid(&mut self, v: u32) -> &mut Self {
+        self.uid = Some(v);
+        self
+    }
+
+    /// Set user ID of the owner of this entry.
+    pub fn with_uid(mut self, v: u32) -> Self {
+        self.uid = Some(v);
+        self
+    }
+
+    /// Group ID of the owner of this entry.
+    pub fn gid(&self) -> Option<u32> {
+        self.gid
+    }
+
+    /// Set group ID of the owner of this entry.
+    pub fn set_gid(&mut self, v: u32) -> &mut Self {
+        self.gid = Some(v);
+        self
+    }
+
+    /// Set group ID of the owner of this entry.
+    pub fn with_gid(mut self, v: u32) -> Self {
+        self.gid = Some(v);
+        self
+    }
+
     /// Content Type of this entry.
     ///
     /// Content Type is defined by [RFC 9110](https://httpwg.org/specs/rfc9110.html#field.content-type).
@@ -433,4 +544,96 @@ impl Metadata {
         self.user_metadata = Some(data);
         self
     }
+
+    /// Check if given field is set in this metadata.
+    ///
+    /// Metadata returned by `list` may only contain part of the fields, use this
+    /// to check if the field is provided by the service instead of relying on the
+    /// default value like `0` returned by [`Metadata::content_length`].
+    pub fn contains(&self, field: MetadataField) -> bool {
+        match field {
+            MetadataField::Mode => self.mode != EntryMode::Unknown,
+            MetadataField::CacheControl => self.cache_control.is_some(),
+            MetadataField::ContentDisposition => self.content_disposition.is_some(),
+            MetadataField::ContentLength => self.content_length.is_some(),
+            MetadataField::ContentMd5 => self.content_md5.is_some(),
+            MetadataField::ContentRange => self.content_range.is_some(),
+            MetadataField::ContentType => self.content_type.is_some(),
+            MetadataField::ContentEncoding => self.content_encoding.is_some(),
+            MetadataField::Etag => self.etag.is_some(),
+            MetadataField::LastModified => self.last_modified.is_some(),
+            MetadataField::Version => self.version.is_some(),
+            MetadataField::ChecksumCrc32c => self.checksum_crc32c.is_some(),
+            MetadataField::ChecksumSha256 => self.checksum_sha256.is_some(),
+            MetadataField::Permissions => self.permissions.is_some(),
+            MetadataField::Uid => self.uid.is_some(),
+            MetadataField::Gid => self.gid.is_some(),
+            MetadataField::UserMetadata => self.user_metadata.is_some(),
+        }
+    }
+}
+
+/// MetadataField is the field of [`Metadata`].
+///
+/// It's used to check which fields are provided by services, and to
+/// fetch the missing fields on demand via [`Operator::metadata`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetadataField {
+    /// [`Metadata::mode`]
+    Mode,
+    /// [`Metadata::cache_control`]
+    CacheControl,
+    /// [`Metadata::content_disposition`]
+    ContentDisposition,
+    /// [`Metadata::content_length`]
+    ContentLength,
+    /// [`Metadata::content_md5`]
+    ContentMd5,
+    /// [`Metadata::content_range`]
+    ContentRange,
+    /// [`Metadata::content_type`]
+    ContentType,
+    /// [`Metadata::content_encoding`]
+    ContentEncoding,
+    /// [`Metadata::etag`]
+    Etag,
+    /// [`Metadata::last_modified`]
+    LastModified,
+    /// [`Metadata::version`]
+    Version,
+    /// [`Metadata::checksum_crc32c`]
+    ChecksumCrc32c,
+    /// [`Metadata::checksum_sha256`]
+    ChecksumSha256,
+    /// [`Metadata::permissions`]
+    Permissions,
+    /// [`Metadata::uid`]
+    Uid,
+    /// [`Metadata::gid`]
+    Gid,
+    /// [`Metadata::user_metadata`]
+    UserMetadata,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_contains() {
+        let meta = Metadata::new(EntryMode::FILE)
+            .with_content_length(0)
+            .with_etag("etag".to_string());
+
+        assert!(meta.contains(MetadataField::Mode));
+        assert!(meta.contains(MetadataField::ContentLength));
+        assert!(meta.contains(MetadataField::Etag));
+        assert!(!meta.contains(MetadataField::LastModified));
+        assert!(!meta.contains(MetadataField::Permissions));
+        assert!(meta
+            .with_permissions(0o644)
+            .contains(MetadataField::Permissions));
+        assert!(!Metadata::new(EntryMode::Unknown).contains(MetadataField::Mode));
+    }
 }
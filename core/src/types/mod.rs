@@ -26,6 +26,7 @@ pub use entry::Entry;
 
 mod metadata;
 pub use metadata::Metadata;
+pub use metadata::MetadataField;
 
 mod read;
 pub use read::*;
@@ -43,6 +44,9 @@ mod list;
 pub use list::BlockingLister;
 pub use list::Lister;
 
+mod walk;
+pub use walk::Walker;
+
 mod delete;
 pub use delete::*;
 
@@ -53,9 +57,16 @@ mod operator;
 pub use operator::operator_functions;
 pub use operator::operator_futures;
 pub use operator::BlockingOperator;
+pub use operator::Diagnosis;
+pub use operator::DiagnosisStatus;
+pub use operator::ListOptions;
 pub use operator::Operator;
 pub use operator::OperatorBuilder;
+pub use operator::OperatorConfig;
+pub use operator::OperatorDefaults;
 pub use operator::OperatorInfo;
+pub use operator::ReadOptions;
+pub use operator::WriteOptions;
 
 mod builder;
 pub use builder::Builder;
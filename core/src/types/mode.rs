@@ -49,6 +49,19 @@ impl EntryMode {
             EntryMode::FILE
         }
     }
+
+    /// Create entry mode from given common prefix returned by list.
+    ///
+    /// Prefixes grouped by a custom delimiter are not dirs, so we can't tell
+    /// their mode unless they end with `/`.
+    #[allow(dead_code)]
+    pub(crate) fn from_prefix(path: &str) -> Self {
+        if path.ends_with('/') {
+            EntryMode::DIR
+        } else {
+            EntryMode::Unknown
+        }
+    }
 }
 
 impl Default for EntryMode {
@@ -248,6 +248,42 @@ impl BlockingOperator {
         ))
     }
 
+    /// Get the metadata of given entry with required fields.
+    ///
+    /// Metadata returned by `list` may only contain part of the fields. This function
+    /// returns the metadata in entry directly if all required fields are provided by
+    /// `list`, otherwise it will send a `stat` request to fetch them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// use opendal::MetadataField;
+    ///
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// for entry in op.lister("dir/")? {
+    ///     let entry = entry?;
+    ///     let meta = op.metadata(&entry, &[MetadataField::ContentLength])?;
+    ///     println!("{} {}", entry.path(), meta.content_length());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn metadata(&self, entry: &Entry, fields: &[MetadataField]) -> Result<Metadata> {
+        let meta = entry.metadata();
+        if fields.iter().all(|field| meta.contains(*field)) {
+            return Ok(meta.clone());
+        }
+
+        match meta.version() {
+            Some(version) if self.info().full_capability().stat_with_version => {
+                self.stat_with(entry.path()).version(version).call()
+            }
+            _ => self.stat(entry.path()),
+        }
+    }
+
     /// Check if this path exists or not.
     ///
     /// # Example
@@ -333,6 +369,48 @@ impl BlockingOperator {
         Ok(())
     }
 
+    /// Create a dir at given path with extra options.
+    ///
+    /// # Options
+    ///
+    /// ## `recursive`
+    ///
+    /// Services that emulate dirs by marker objects (like s3) will only create the
+    /// marker of given path. Set `recursive` to `true` to create markers for all
+    /// missing parent dirs as well, so that `stat` on every parent returns a dir.
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::BlockingOperator;
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// op.create_dir_with("path/to/dir/").recursive(true).call()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_dir_with(&self, path: &str) -> FunctionCreateDir {
+        let path = normalize_path(path);
+
+        FunctionCreateDir(OperatorFunction::new(
+            self.inner().clone(),
+            path,
+            OpCreateDir::new(),
+            |inner, path, args| {
+                if !validate_path(&path, EntryMode::DIR) {
+                    return Err(Error::new(
+                        ErrorKind::NotADirectory,
+                        "the path trying to create should end with `/`",
+                    )
+                    .with_operation("create_dir")
+                    .with_context("service", inner.info().scheme())
+                    .with_context("path", &path));
+                }
+
+                inner.blocking_create_dir(&path, args)?;
+                Ok(())
+            },
+        ))
+    }
+
     /// Read the whole path into a bytes.
     ///
     /// This function will allocate a new bytes internally. For more precise memory control or
@@ -394,6 +472,18 @@ impl BlockingOperator {
         ))
     }
 
+    /// Read the whole path into a bytes with typed [`ReadOptions`].
+    ///
+    /// This is the same as [`BlockingOperator::read_with`] but takes all options at once.
+    ///
+    /// Blocking read is sequential, `concurrent` larger than `1` will return
+    /// [`ErrorKind::Unsupported`].
+    pub fn read_opts(&self, path: &str, opts: ReadOptions) -> Result<Buffer> {
+        let range = BytesRange::new(opts.offset.unwrap_or_default(), opts.size);
+        let r = self.reader_opts(path, opts)?;
+        r.read(range.to_range())
+    }
+
     /// Create a new reader which can read the whole path.
     ///
     /// # Examples
@@ -450,6 +540,38 @@ impl BlockingOperator {
         ))
     }
 
+    /// Create a new reader with typed [`ReadOptions`].
+    ///
+    /// This is the same as [`BlockingOperator::reader_with`] but takes all options at once.
+    ///
+    /// Blocking read is sequential, `concurrent` larger than `1` will return
+    /// [`ErrorKind::Unsupported`].
+    pub fn reader_opts(&self, path: &str, opts: ReadOptions) -> Result<BlockingReader> {
+        let path = normalize_path(path);
+        let (args, options) = opts.apply(OpRead::default(), OpReader::default());
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "reader path is a directory")
+                    .with_operation("BlockingOperator::reader_opts")
+                    .with_context("service", self.info().scheme().into_static())
+                    .with_context("path", &path),
+            );
+        }
+        if options.concurrent() > 1 {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "concurrent read is not supported by blocking operator",
+            )
+            .with_operation("BlockingOperator::reader_opts")
+            .with_context("service", self.info().scheme().into_static())
+            .with_context("path", &path));
+        }
+
+        let context = ReadContext::new(self.inner().clone(), path, args, options);
+        Ok(BlockingReader::new(context))
+    }
+
     /// Write bytes into given path.
     ///
     /// # Notes
@@ -639,6 +761,18 @@ impl BlockingOperator {
         ))
     }
 
+    /// Write bytes into path with typed [`WriteOptions`].
+    ///
+    /// This is the same as [`BlockingOperator::write_with`] but takes all options at once.
+    pub fn write_opts(&self, path: &str, bs: impl Into<Buffer>, opts: WriteOptions) -> Result<()> {
+        let mut f = self.write_with(path, bs);
+        f.0 = f.0.map_args(|(args, options, bs)| {
+            let (args, options) = opts.apply(args, options);
+            (args, options, bs)
+        });
+        f.call()
+    }
+
     /// Write multiple bytes into given path.
     ///
     /// # Notes
@@ -708,6 +842,15 @@ impl BlockingOperator {
         ))
     }
 
+    /// Create a writer with typed [`WriteOptions`].
+    ///
+    /// This is the same as [`BlockingOperator::writer_with`] but takes all options at once.
+    pub fn writer_opts(&self, path: &str, opts: WriteOptions) -> Result<BlockingWriter> {
+        let mut f = self.writer_with(path);
+        f.0 = f.0.map_args(|(args, options)| opts.apply(args, options));
+        f.call()
+    }
+
     /// Delete given path.
     ///
     /// # Notes
@@ -995,6 +1138,15 @@ impl BlockingOperator {
         ))
     }
 
+    /// List entries in given path with typed [`ListOptions`].
+    ///
+    /// This is the same as [`BlockingOperator::list_with`] but takes all options at once.
+    pub fn list_opts(&self, path: &str, opts: ListOptions) -> Result<Vec<Entry>> {
+        let mut f = self.list_with(path);
+        f.0 = f.0.map_args(|args| opts.apply(args));
+        f.call()
+    }
+
     /// List entries that starts with given `path` in parent dir.
     ///
     /// This function will create a new [`BlockingLister`] to list entries. Users can stop listing
@@ -1056,7 +1208,7 @@ impl BlockingOperator {
     /// # fn test(op: BlockingOperator) -> Result<()> {
     /// let mut ds = op
     ///     .lister_with("path/to/dir/")
-    ///     .limit(10)
+    ///     .chunk(10)
     ///     .start_after("start")
     ///     .call()?;
     /// for entry in ds {
@@ -1110,6 +1262,15 @@ impl BlockingOperator {
             |inner, path, args| BlockingLister::create(inner, &path, args),
         ))
     }
+
+    /// Create a lister with typed [`ListOptions`].
+    ///
+    /// This is the same as [`BlockingOperator::lister_with`] but takes all options at once.
+    pub fn lister_opts(&self, path: &str, opts: ListOptions) -> Result<BlockingLister> {
+        let mut f = self.lister_with(path);
+        f.0 = f.0.map_args(|args| opts.apply(args));
+        f.call()
+    }
 }
 
 impl From<BlockingOperator> for Operator {
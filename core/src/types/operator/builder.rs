@@ -101,6 +101,14 @@ impl Operator {
     ///
     /// `from_iter` generates a `OperatorBuilder` which allows adding layer in zero-cost way.
     ///
+    /// Keys with `http_` prefix like `http_connect_timeout` will be parsed as
+    /// [`HttpClientConfig`] and used to build the http client for services
+    /// that talk over http.
+    ///
+    /// Only `default_user_agent` of [`OperatorDefaults`] will be used here,
+    /// other defaults require layers with dynamic dispatch and are applied by
+    /// [`Operator::via_iter`] or [`Operator::with_defaults`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -127,7 +135,31 @@ impl Operator {
     pub fn from_iter<B: Builder>(
         iter: impl IntoIterator<Item = (String, String)>,
     ) -> Result<OperatorBuilder<impl Access>> {
-        let builder = B::Config::from_iter(iter)?.into_builder();
+        Self::from_map_with_http_client::<B>(iter.into_iter().collect(), None)
+    }
+
+    /// Create a new operator from given map, the http client will be used
+    /// instead of building from `http_` options if given.
+    fn from_map_with_http_client<B: Builder>(
+        map: HashMap<String, String>,
+        http_client: Option<HttpClient>,
+    ) -> Result<OperatorBuilder<impl Access>> {
+        let mut builder = B::Config::from_iter(map.clone())?.into_builder();
+        if let Some(client) = http_client {
+            let acc = builder.with_http_client(client).build()?;
+            return Ok(OperatorBuilder::new(acc));
+        }
+        let mut http_cfg = HttpClientConfig::from_prefixed_iter(map.clone())?;
+        if let Some(user_agent) =
+            OperatorDefaults::from_prefixed_iter(map)?.and_then(|v| v.full_user_agent())
+        {
+            http_cfg
+                .get_or_insert_with(HttpClientConfig::default)
+                .user_agent = Some(user_agent);
+        }
+        if let Some(cfg) = http_cfg {
+            builder = builder.with_http_client(HttpClient::with_config(cfg)?);
+        }
         let acc = builder.build()?;
         Ok(OperatorBuilder::new(acc))
     }
@@ -138,6 +170,9 @@ impl Operator {
     ///
     /// `via_iter` generates a `Operator` which allows building operator without generic type.
     ///
+    /// Keys with `default_` prefix like `default_retry_max_times` will be parsed as
+    /// [`OperatorDefaults`] and applied to the built operator.
+    ///
     /// # Examples
     ///
     /// ```
@@ -160,140 +195,197 @@ impl Operator {
     ///     Ok(())
     /// }
     /// ```
-    #[allow(unused_variables, unreachable_code)]
     pub fn via_iter(
         scheme: Scheme,
         iter: impl IntoIterator<Item = (String, String)>,
     ) -> Result<Operator> {
+        Self::via_map_with_http_client(scheme, iter.into_iter().collect(), None)
+    }
+
+    /// Create a new operator via given scheme and iterator of config value
+    /// with given http client in dynamic dispatch.
+    ///
+    /// # Notes
+    ///
+    /// This is the same as [`Operator::via_iter`], except that services that
+    /// talk over http will use the given client. Keys with `http_` prefix
+    /// and `default_user_agent` will be ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::raw::HttpClient;
+    /// use opendal::Operator;
+    /// use opendal::Scheme;
+    /// async fn test() -> Result<()> {
+    ///     let client = HttpClient::new()?;
+    ///     let map = [("root".to_string(), "/tmp".to_string())];
+    ///
+    ///     let op: Operator = Operator::via_iter_with_http_client(Scheme::Fs, map, client)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn via_iter_with_http_client(
+        scheme: Scheme,
+        iter: impl IntoIterator<Item = (String, String)>,
+        http_client: HttpClient,
+    ) -> Result<Operator> {
+        Self::via_map_with_http_client(scheme, iter.into_iter().collect(), Some(http_client))
+    }
+
+    /// Build an operator of given builder from map.
+    #[allow(dead_code)]
+    fn build<B: Builder>(
+        map: HashMap<String, String>,
+        http_client: Option<HttpClient>,
+    ) -> Result<Operator> {
+        Ok(Self::from_map_with_http_client::<B>(map, http_client)?.finish())
+    }
+
+    #[allow(unused_variables, unreachable_code)]
+    fn via_map_with_http_client(
+        scheme: Scheme,
+        map: HashMap<String, String>,
+        http_client: Option<HttpClient>,
+    ) -> Result<Operator> {
+        let defaults = OperatorDefaults::from_prefixed_iter(map.clone())?;
+        let iter = map;
+
         let op = match scheme {
             #[cfg(feature = "services-aliyun-drive")]
-            Scheme::AliyunDrive => Self::from_iter::<services::AliyunDrive>(iter)?.finish(),
+            Scheme::AliyunDrive => Self::build::<services::AliyunDrive>(iter, http_client)?,
             #[cfg(feature = "services-atomicserver")]
-            Scheme::Atomicserver => Self::from_iter::<services::Atomicserver>(iter)?.finish(),
+            Scheme::Atomicserver => Self::build::<services::Atomicserver>(iter, http_client)?,
             #[cfg(feature = "services-alluxio")]
-            Scheme::Alluxio => Self::from_iter::<services::Alluxio>(iter)?.finish(),
+            Scheme::Alluxio => Self::build::<services::Alluxio>(iter, http_client)?,
             #[cfg(feature = "services-compfs")]
-            Scheme::Compfs => Self::from_iter::<services::Compfs>(iter)?.finish(),
+            Scheme::Compfs => Self::build::<services::Compfs>(iter, http_client)?,
             #[cfg(feature = "services-upyun")]
-            Scheme::Upyun => Self::from_iter::<services::Upyun>(iter)?.finish(),
+            Scheme::Upyun => Self::build::<services::Upyun>(iter, http_client)?,
             #[cfg(feature = "services-koofr")]
-            Scheme::Koofr => Self::from_iter::<services::Koofr>(iter)?.finish(),
+            Scheme::Koofr => Self::build::<services::Koofr>(iter, http_client)?,
             #[cfg(feature = "services-yandex-disk")]
-            Scheme::YandexDisk => Self::from_iter::<services::YandexDisk>(iter)?.finish(),
+            Scheme::YandexDisk => Self::build::<services::YandexDisk>(iter, http_client)?,
             #[cfg(feature = "services-pcloud")]
-            Scheme::Pcloud => Self::from_iter::<services::Pcloud>(iter)?.finish(),
+            Scheme::Pcloud => Self::build::<services::Pcloud>(iter, http_client)?,
             #[cfg(feature = "services-chainsafe")]
-            Scheme::Chainsafe => Self::from_iter::<services::Chainsafe>(iter)?.finish(),
+            Scheme::Chainsafe => Self::build::<services::Chainsafe>(iter, http_client)?,
             #[cfg(feature = "services-azblob")]
-            Scheme::Azblob => Self::from_iter::<services::Azblob>(iter)?.finish(),
+            Scheme::Azblob => Self::build::<services::Azblob>(iter, http_client)?,
             #[cfg(feature = "services-azdls")]
-            Scheme::Azdls => Self::from_iter::<services::Azdls>(iter)?.finish(),
+            Scheme::Azdls => Self::build::<services::Azdls>(iter, http_client)?,
             #[cfg(feature = "services-azfile")]
-            Scheme::Azfile => Self::from_iter::<services::Azfile>(iter)?.finish(),
+            Scheme::Azfile => Self::build::<services::Azfile>(iter, http_client)?,
             #[cfg(feature = "services-b2")]
-            Scheme::B2 => Self::from_iter::<services::B2>(iter)?.finish(),
+            Scheme::B2 => Self::build::<services::B2>(iter, http_client)?,
             #[cfg(feature = "services-cacache")]
-            Scheme::Cacache => Self::from_iter::<services::Cacache>(iter)?.finish(),
+            Scheme::Cacache => Self::build::<services::Cacache>(iter, http_client)?,
             #[cfg(feature = "services-cos")]
-            Scheme::Cos => Self::from_iter::<services::Cos>(iter)?.finish(),
+            Scheme::Cos => Self::build::<services::Cos>(iter, http_client)?,
             #[cfg(feature = "services-d1")]
-            Scheme::D1 => Self::from_iter::<services::D1>(iter)?.finish(),
+            Scheme::D1 => Self::build::<services::D1>(iter, http_client)?,
             #[cfg(feature = "services-dashmap")]
-            Scheme::Dashmap => Self::from_iter::<services::Dashmap>(iter)?.finish(),
+            Scheme::Dashmap => Self::build::<services::Dashmap>(iter, http_client)?,
             #[cfg(feature = "services-dropbox")]
-            Scheme::Dropbox => Self::from_iter::<services::Dropbox>(iter)?.finish(),
+            Scheme::Dropbox => Self::build::<services::Dropbox>(iter, http_client)?,
             #[cfg(feature = "services-etcd")]
-            Scheme::Etcd => Self::from_iter::<services::Etcd>(iter)?.finish(),
+            Scheme::Etcd => Self::build::<services::Etcd>(iter, http_client)?,
             #[cfg(feature = "services-foundationdb")]
-            Scheme::Foundationdb => Self::from_iter::<services::Foundationdb>(iter)?.finish(),
+            Scheme::Foundationdb => Self::build::<services::Foundationdb>(iter, http_client)?,
             #[cfg(feature = "services-fs")]
-            Scheme::Fs => Self::from_iter::<services::Fs>(iter)?.finish(),
+            Scheme::Fs => Self::build::<services::Fs>(iter, http_client)?,
             #[cfg(feature = "services-ftp")]
-            Scheme::Ftp => Self::from_iter::<services::Ftp>(iter)?.finish(),
+            Scheme::Ftp => Self::build::<services::Ftp>(iter, http_client)?,
             #[cfg(feature = "services-gcs")]
-            Scheme::Gcs => Self::from_iter::<services::Gcs>(iter)?.finish(),
+            Scheme::Gcs => Self::build::<services::Gcs>(iter, http_client)?,
             #[cfg(feature = "services-ghac")]
-            Scheme::Ghac => Self::from_iter::<services::Ghac>(iter)?.finish(),
+            Scheme::Ghac => Self::build::<services::Ghac>(iter, http_client)?,
             #[cfg(feature = "services-gridfs")]
-            Scheme::Gridfs => Self::from_iter::<services::Gridfs>(iter)?.finish(),
+            Scheme::Gridfs => Self::build::<services::Gridfs>(iter, http_client)?,
             #[cfg(feature = "services-github")]
-            Scheme::Github => Self::from_iter::<services::Github>(iter)?.finish(),
+            Scheme::Github => Self::build::<services::Github>(iter, http_client)?,
             #[cfg(feature = "services-hdfs")]
-            Scheme::Hdfs => Self::from_iter::<services::Hdfs>(iter)?.finish(),
+            Scheme::Hdfs => Self::build::<services::Hdfs>(iter, http_client)?,
             #[cfg(feature = "services-http")]
-            Scheme::Http => Self::from_iter::<services::Http>(iter)?.finish(),
+            Scheme::Http => Self::build::<services::Http>(iter, http_client)?,
             #[cfg(feature = "services-huggingface")]
-            Scheme::Huggingface => Self::from_iter::<services::Huggingface>(iter)?.finish(),
+            Scheme::Huggingface => Self::build::<services::Huggingface>(iter, http_client)?,
             #[cfg(feature = "services-ipfs")]
-            Scheme::Ipfs => Self::from_iter::<services::Ipfs>(iter)?.finish(),
+            Scheme::Ipfs => Self::build::<services::Ipfs>(iter, http_client)?,
             #[cfg(feature = "services-ipmfs")]
-            Scheme::Ipmfs => Self::from_iter::<services::Ipmfs>(iter)?.finish(),
+            Scheme::Ipmfs => Self::build::<services::Ipmfs>(iter, http_client)?,
             #[cfg(feature = "services-icloud")]
-            Scheme::Icloud => Self::from_iter::<services::Icloud>(iter)?.finish(),
+            Scheme::Icloud => Self::build::<services::Icloud>(iter, http_client)?,
             #[cfg(feature = "services-libsql")]
-            Scheme::Libsql => Self::from_iter::<services::Libsql>(iter)?.finish(),
+            Scheme::Libsql => Self::build::<services::Libsql>(iter, http_client)?,
             #[cfg(feature = "services-memcached")]
-            Scheme::Memcached => Self::from_iter::<services::Memcached>(iter)?.finish(),
+            Scheme::Memcached => Self::build::<services::Memcached>(iter, http_client)?,
             #[cfg(feature = "services-memory")]
-            Scheme::Memory => Self::from_iter::<services::Memory>(iter)?.finish(),
+            Scheme::Memory => Self::build::<services::Memory>(iter, http_client)?,
             #[cfg(feature = "services-mini-moka")]
-            Scheme::MiniMoka => Self::from_iter::<services::MiniMoka>(iter)?.finish(),
+            Scheme::MiniMoka => Self::build::<services::MiniMoka>(iter, http_client)?,
             #[cfg(feature = "services-moka")]
-            Scheme::Moka => Self::from_iter::<services::Moka>(iter)?.finish(),
+            Scheme::Moka => Self::build::<services::Moka>(iter, http_client)?,
             #[cfg(feature = "services-monoiofs")]
-            Scheme::Monoiofs => Self::from_iter::<services::Monoiofs>(iter)?.finish(),
+            Scheme::Monoiofs => Self::build::<services::Monoiofs>(iter, http_client)?,
             #[cfg(feature = "services-mysql")]
-            Scheme::Mysql => Self::from_iter::<services::Mysql>(iter)?.finish(),
+            Scheme::Mysql => Self::build::<services::Mysql>(iter, http_client)?,
             #[cfg(feature = "services-obs")]
-            Scheme::Obs => Self::from_iter::<services::Obs>(iter)?.finish(),
+            Scheme::Obs => Self::build::<services::Obs>(iter, http_client)?,
             #[cfg(feature = "services-onedrive")]
-            Scheme::Onedrive => Self::from_iter::<services::Onedrive>(iter)?.finish(),
+            Scheme::Onedrive => Self::build::<services::Onedrive>(iter, http_client)?,
             #[cfg(feature = "services-postgresql")]
-            Scheme::Postgresql => Self::from_iter::<services::Postgresql>(iter)?.finish(),
+            Scheme::Postgresql => Self::build::<services::Postgresql>(iter, http_client)?,
             #[cfg(feature = "services-gdrive")]
-            Scheme::Gdrive => Self::from_iter::<services::Gdrive>(iter)?.finish(),
+            Scheme::Gdrive => Self::build::<services::Gdrive>(iter, http_client)?,
             #[cfg(feature = "services-oss")]
-            Scheme::Oss => Self::from_iter::<services::Oss>(iter)?.finish(),
+            Scheme::Oss => Self::build::<services::Oss>(iter, http_client)?,
+            #[cfg(feature = "services-ozone")]
+            Scheme::Ozone => Self::build::<services::Ozone>(iter, http_client)?,
             #[cfg(feature = "services-persy")]
-            Scheme::Persy => Self::from_iter::<services::Persy>(iter)?.finish(),
+            Scheme::Persy => Self::build::<services::Persy>(iter, http_client)?,
             #[cfg(feature = "services-redis")]
-            Scheme::Redis => Self::from_iter::<services::Redis>(iter)?.finish(),
+            Scheme::Redis => Self::build::<services::Redis>(iter, http_client)?,
             #[cfg(feature = "services-rocksdb")]
-            Scheme::Rocksdb => Self::from_iter::<services::Rocksdb>(iter)?.finish(),
+            Scheme::Rocksdb => Self::build::<services::Rocksdb>(iter, http_client)?,
             #[cfg(feature = "services-s3")]
-            Scheme::S3 => Self::from_iter::<services::S3>(iter)?.finish(),
+            Scheme::S3 => Self::build::<services::S3>(iter, http_client)?,
             #[cfg(feature = "services-seafile")]
-            Scheme::Seafile => Self::from_iter::<services::Seafile>(iter)?.finish(),
+            Scheme::Seafile => Self::build::<services::Seafile>(iter, http_client)?,
             #[cfg(feature = "services-sftp")]
-            Scheme::Sftp => Self::from_iter::<services::Sftp>(iter)?.finish(),
+            Scheme::Sftp => Self::build::<services::Sftp>(iter, http_client)?,
             #[cfg(feature = "services-sled")]
-            Scheme::Sled => Self::from_iter::<services::Sled>(iter)?.finish(),
+            Scheme::Sled => Self::build::<services::Sled>(iter, http_client)?,
             #[cfg(feature = "services-sqlite")]
-            Scheme::Sqlite => Self::from_iter::<services::Sqlite>(iter)?.finish(),
+            Scheme::Sqlite => Self::build::<services::Sqlite>(iter, http_client)?,
             #[cfg(feature = "services-supabase")]
-            Scheme::Supabase => Self::from_iter::<services::Supabase>(iter)?.finish(),
+            Scheme::Supabase => Self::build::<services::Supabase>(iter, http_client)?,
             #[cfg(feature = "services-swift")]
-            Scheme::Swift => Self::from_iter::<services::Swift>(iter)?.finish(),
+            Scheme::Swift => Self::build::<services::Swift>(iter, http_client)?,
             #[cfg(feature = "services-tikv")]
-            Scheme::Tikv => Self::from_iter::<services::Tikv>(iter)?.finish(),
+            Scheme::Tikv => Self::build::<services::Tikv>(iter, http_client)?,
             #[cfg(feature = "services-vercel-artifacts")]
-            Scheme::VercelArtifacts => Self::from_iter::<services::VercelArtifacts>(iter)?.finish(),
+            Scheme::VercelArtifacts => Self::build::<services::VercelArtifacts>(iter, http_client)?,
             #[cfg(feature = "services-vercel-blob")]
-            Scheme::VercelBlob => Self::from_iter::<services::VercelBlob>(iter)?.finish(),
+            Scheme::VercelBlob => Self::build::<services::VercelBlob>(iter, http_client)?,
             #[cfg(feature = "services-webdav")]
-            Scheme::Webdav => Self::from_iter::<services::Webdav>(iter)?.finish(),
+            Scheme::Webdav => Self::build::<services::Webdav>(iter, http_client)?,
             #[cfg(feature = "services-webhdfs")]
-            Scheme::Webhdfs => Self::from_iter::<services::Webhdfs>(iter)?.finish(),
+            Scheme::Webhdfs => Self::build::<services::Webhdfs>(iter, http_client)?,
+            #[cfg(feature = "services-rados")]
+            Scheme::Rados => Self::build::<services::Rados>(iter, http_client)?,
             #[cfg(feature = "services-redb")]
-            Scheme::Redb => Self::from_iter::<services::Redb>(iter)?.finish(),
+            Scheme::Redb => Self::build::<services::Redb>(iter, http_client)?,
             #[cfg(feature = "services-mongodb")]
-            Scheme::Mongodb => Self::from_iter::<services::Mongodb>(iter)?.finish(),
+            Scheme::Mongodb => Self::build::<services::Mongodb>(iter, http_client)?,
             #[cfg(feature = "services-hdfs-native")]
-            Scheme::HdfsNative => Self::from_iter::<services::HdfsNative>(iter)?.finish(),
+            Scheme::HdfsNative => Self::build::<services::HdfsNative>(iter, http_client)?,
             #[cfg(feature = "services-lakefs")]
-            Scheme::Lakefs => Self::from_iter::<services::Lakefs>(iter)?.finish(),
+            Scheme::Lakefs => Self::build::<services::Lakefs>(iter, http_client)?,
             #[cfg(feature = "services-nebula-graph")]
-            Scheme::NebulaGraph => Self::from_iter::<services::NebulaGraph>(iter)?.finish(),
+            Scheme::NebulaGraph => Self::build::<services::NebulaGraph>(iter, http_client)?,
             v => {
                 return Err(Error::new(
                     ErrorKind::Unsupported,
@@ -303,6 +395,10 @@ impl Operator {
             }
         };
 
+        let op = match defaults {
+            Some(defaults) => op.with_defaults(&defaults),
+            None => op,
+        };
         Ok(op)
     }
 
@@ -391,6 +487,10 @@ impl Operator {
     ///
     /// It's always recommended to use `OperatorBuilder::layer()` instead.
     ///
+    /// Settings of current operator like [`Operator::limit`],
+    /// [`Operator::default_executor`] and [`Operator::default_read_chunk`]
+    /// will be kept.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -410,9 +510,8 @@ impl Operator {
     /// ```
     #[must_use]
     pub fn layer<L: Layer<Accessor>>(self, layer: L) -> Self {
-        Self::from_inner(Arc::new(
-            TypeEraseLayer.layer(layer.layer(self.into_inner())),
-        ))
+        let accessor = Arc::new(TypeEraseLayer.layer(layer.layer(self.inner().clone())));
+        self.with_inner(accessor)
     }
 }
 
@@ -517,3 +616,22 @@ impl<A: Access> OperatorBuilder<A> {
         Operator::from_inner(Arc::new(ob.accessor) as Accessor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "services-memory")]
+    fn test_operator_layer_keeps_settings() {
+        let op = Operator::new(services::Memory::default())
+            .unwrap()
+            .finish()
+            .with_limit(8)
+            .with_default_read_chunk(1024)
+            .layer(LoggingLayer::default());
+
+        assert_eq!(op.limit(), 8);
+        assert_eq!(op.default_read_chunk(), Some(1024));
+    }
+}
@@ -0,0 +1,616 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use serde::de;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::*;
+
+/// OperatorConfig is the unified config of all services.
+///
+/// It's internally tagged by `scheme` and can be deserialized from any
+/// self-describing format like JSON, YAML or TOML. This is useful for
+/// applications that store connection profiles in config files.
+///
+/// Unlike [`Operator::via_iter`], values are typed and unknown fields will be
+/// rejected instead of being ignored silently.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use opendal::Operator;
+/// use opendal::OperatorConfig;
+///
+/// fn test() -> Result<()> {
+///     let cfg: OperatorConfig = serde_json::from_str(
+///         r#"{"scheme": "s3", "bucket": "test", "region": "us-east-1"}"#,
+///     )?;
+///
+///     // Build an `Operator` to start operating the storage.
+///     let op: Operator = Operator::via_config(cfg)?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "scheme", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum OperatorConfig {
+    /// Config for [AliyunDrive][crate::services::AliyunDrive] service.
+    AliyunDrive(services::AliyunDriveConfig),
+    /// Config for [Alluxio][crate::services::Alluxio] service.
+    Alluxio(services::AlluxioConfig),
+    /// Config for [Atomicserver][crate::services::Atomicserver] service.
+    Atomicserver(services::AtomicserverConfig),
+    /// Config for [Azblob][crate::services::Azblob] service.
+    Azblob(services::AzblobConfig),
+    /// Config for [Azdls][crate::services::Azdls] service.
+    Azdls(services::AzdlsConfig),
+    /// Config for [Azfile][crate::services::Azfile] service.
+    Azfile(services::AzfileConfig),
+    /// Config for [B2][crate::services::B2] service.
+    B2(services::B2Config),
+    /// Config for [Cacache][crate::services::Cacache] service.
+    Cacache(services::CacacheConfig),
+    /// Config for [Chainsafe][crate::services::Chainsafe] service.
+    Chainsafe(services::ChainsafeConfig),
+    /// Config for [CloudflareKv][crate::services::CloudflareKv] service.
+    CloudflareKv(services::CloudflareKvConfig),
+    /// Config for [Compfs][crate::services::Compfs] service.
+    Compfs(services::CompfsConfig),
+    /// Config for [Cos][crate::services::Cos] service.
+    Cos(services::CosConfig),
+    /// Config for [D1][crate::services::D1] service.
+    D1(services::D1Config),
+    /// Config for [Dashmap][crate::services::Dashmap] service.
+    Dashmap(services::DashmapConfig),
+    /// Config for [Dbfs][crate::services::Dbfs] service.
+    Dbfs(services::DbfsConfig),
+    /// Config for [Dropbox][crate::services::Dropbox] service.
+    Dropbox(services::DropboxConfig),
+    /// Config for [Etcd][crate::services::Etcd] service.
+    Etcd(services::EtcdConfig),
+    /// Config for [Foundationdb][crate::services::Foundationdb] service.
+    Foundationdb(services::FoundationdbConfig),
+    /// Config for [Fs][crate::services::Fs] service.
+    Fs(services::FsConfig),
+    /// Config for [Ftp][crate::services::Ftp] service.
+    Ftp(services::FtpConfig),
+    /// Config for [Gcs][crate::services::Gcs] service.
+    Gcs(services::GcsConfig),
+    /// Config for [Gdrive][crate::services::Gdrive] service.
+    Gdrive(services::GdriveConfig),
+    /// Config for [Ghac][crate::services::Ghac] service.
+    Ghac(services::GhacConfig),
+    /// Config for [Github][crate::services::Github] service.
+    Github(services::GithubConfig),
+    /// Config for [Gridfs][crate::services::Gridfs] service.
+    Gridfs(services::GridfsConfig),
+    /// Config for [Hdfs][crate::services::Hdfs] service.
+    Hdfs(services::HdfsConfig),
+    /// Config for [HdfsNative][crate::services::HdfsNative] service.
+    HdfsNative(services::HdfsNativeConfig),
+    /// Config for [Http][crate::services::Http] service.
+    Http(services::HttpConfig),
+    /// Config for [Huggingface][crate::services::Huggingface] service.
+    Huggingface(services::HuggingfaceConfig),
+    /// Config for [Icloud][crate::services::Icloud] service.
+    Icloud(services::IcloudConfig),
+    /// Config for [Ipfs][crate::services::Ipfs] service.
+    Ipfs(services::IpfsConfig),
+    /// Config for [Ipmfs][crate::services::Ipmfs] service.
+    Ipmfs(services::IpmfsConfig),
+    /// Config for [Koofr][crate::services::Koofr] service.
+    Koofr(services::KoofrConfig),
+    /// Config for [Lakefs][crate::services::Lakefs] service.
+    Lakefs(services::LakefsConfig),
+    /// Config for [Libsql][crate::services::Libsql] service.
+    Libsql(services::LibsqlConfig),
+    /// Config for [Memcached][crate::services::Memcached] service.
+    Memcached(services::MemcachedConfig),
+    /// Config for [Memory][crate::services::Memory] service.
+    Memory(services::MemoryConfig),
+    /// Config for [MiniMoka][crate::services::MiniMoka] service.
+    MiniMoka(services::MiniMokaConfig),
+    /// Config for [Moka][crate::services::Moka] service.
+    Moka(services::MokaConfig),
+    /// Config for [Mongodb][crate::services::Mongodb] service.
+    Mongodb(services::MongodbConfig),
+    /// Config for [Monoiofs][crate::services::Monoiofs] service.
+    Monoiofs(services::MonoiofsConfig),
+    /// Config for [Mysql][crate::services::Mysql] service.
+    Mysql(services::MysqlConfig),
+    /// Config for [NebulaGraph][crate::services::NebulaGraph] service.
+    NebulaGraph(services::NebulaGraphConfig),
+    /// Config for [Obs][crate::services::Obs] service.
+    Obs(services::ObsConfig),
+    /// Config for [Onedrive][crate::services::Onedrive] service.
+    Onedrive(services::OnedriveConfig),
+    /// Config for [Oss][crate::services::Oss] service.
+    Oss(services::OssConfig),
+    /// Config for [Ozone][crate::services::Ozone] service.
+    Ozone(services::OzoneConfig),
+    /// Config for [Pcloud][crate::services::Pcloud] service.
+    Pcloud(services::PcloudConfig),
+    /// Config for [Persy][crate::services::Persy] service.
+    Persy(services::PersyConfig),
+    /// Config for [Postgresql][crate::services::Postgresql] service.
+    Postgresql(services::PostgresqlConfig),
+    /// Config for [Rados][crate::services::Rados] service.
+    Rados(services::RadosConfig),
+    /// Config for [Redb][crate::services::Redb] service.
+    Redb(services::RedbConfig),
+    /// Config for [Redis][crate::services::Redis] service.
+    Redis(services::RedisConfig),
+    /// Config for [Rocksdb][crate::services::Rocksdb] service.
+    Rocksdb(services::RocksdbConfig),
+    /// Config for [S3][crate::services::S3] service.
+    S3(services::S3Config),
+    /// Config for [Seafile][crate::services::Seafile] service.
+    Seafile(services::SeafileConfig),
+    /// Config for [Sftp][crate::services::Sftp] service.
+    Sftp(services::SftpConfig),
+    /// Config for [Sled][crate::services::Sled] service.
+    Sled(services::SledConfig),
+    /// Config for [Sqlite][crate::services::Sqlite] service.
+    Sqlite(services::SqliteConfig),
+    /// Config for [Supabase][crate::services::Supabase] service.
+    Supabase(services::SupabaseConfig),
+    /// Config for [Surrealdb][crate::services::Surrealdb] service.
+    Surrealdb(services::SurrealdbConfig),
+    /// Config for [Swift][crate::services::Swift] service.
+    Swift(services::SwiftConfig),
+    /// Config for [Tikv][crate::services::Tikv] service.
+    Tikv(services::TikvConfig),
+    /// Config for [Upyun][crate::services::Upyun] service.
+    Upyun(services::UpyunConfig),
+    /// Config for [VercelArtifacts][crate::services::VercelArtifacts] service.
+    VercelArtifacts(services::VercelArtifactsConfig),
+    /// Config for [VercelBlob][crate::services::VercelBlob] service.
+    VercelBlob(services::VercelBlobConfig),
+    /// Config for [Webdav][crate::services::Webdav] service.
+    Webdav(services::WebdavConfig),
+    /// Config for [Webhdfs][crate::services::Webhdfs] service.
+    Webhdfs(services::WebhdfsConfig),
+    /// Config for [YandexDisk][crate::services::YandexDisk] service.
+    YandexDisk(services::YandexDiskConfig),
+}
+
+impl OperatorConfig {
+    /// Get the scheme of this config.
+    pub fn scheme(&self) -> Scheme {
+        match self {
+            OperatorConfig::AliyunDrive(_) => Scheme::AliyunDrive,
+            OperatorConfig::Alluxio(_) => Scheme::Alluxio,
+            OperatorConfig::Atomicserver(_) => Scheme::Atomicserver,
+            OperatorConfig::Azblob(_) => Scheme::Azblob,
+            OperatorConfig::Azdls(_) => Scheme::Azdls,
+            OperatorConfig::Azfile(_) => Scheme::Azfile,
+            OperatorConfig::B2(_) => Scheme::B2,
+            OperatorConfig::Cacache(_) => Scheme::Cacache,
+            OperatorConfig::Chainsafe(_) => Scheme::Chainsafe,
+            OperatorConfig::CloudflareKv(_) => Scheme::CloudflareKv,
+            OperatorConfig::Compfs(_) => Scheme::Compfs,
+            OperatorConfig::Cos(_) => Scheme::Cos,
+            OperatorConfig::D1(_) => Scheme::D1,
+            OperatorConfig::Dashmap(_) => Scheme::Dashmap,
+            OperatorConfig::Dbfs(_) => Scheme::Dbfs,
+            OperatorConfig::Dropbox(_) => Scheme::Dropbox,
+            OperatorConfig::Etcd(_) => Scheme::Etcd,
+            OperatorConfig::Foundationdb(_) => Scheme::Foundationdb,
+            OperatorConfig::Fs(_) => Scheme::Fs,
+            OperatorConfig::Ftp(_) => Scheme::Ftp,
+            OperatorConfig::Gcs(_) => Scheme::Gcs,
+            OperatorConfig::Gdrive(_) => Scheme::Gdrive,
+            OperatorConfig::Ghac(_) => Scheme::Ghac,
+            OperatorConfig::Github(_) => Scheme::Github,
+            OperatorConfig::Gridfs(_) => Scheme::Gridfs,
+            OperatorConfig::Hdfs(_) => Scheme::Hdfs,
+            OperatorConfig::HdfsNative(_) => Scheme::HdfsNative,
+            OperatorConfig::Http(_) => Scheme::Http,
+            OperatorConfig::Huggingface(_) => Scheme::Huggingface,
+            OperatorConfig::Icloud(_) => Scheme::Icloud,
+            OperatorConfig::Ipfs(_) => Scheme::Ipfs,
+            OperatorConfig::Ipmfs(_) => Scheme::Ipmfs,
+            OperatorConfig::Koofr(_) => Scheme::Koofr,
+            OperatorConfig::Lakefs(_) => Scheme::Lakefs,
+            OperatorConfig::Libsql(_) => Scheme::Libsql,
+            OperatorConfig::Memcached(_) => Scheme::Memcached,
+            OperatorConfig::Memory(_) => Scheme::Memory,
+            OperatorConfig::MiniMoka(_) => Scheme::MiniMoka,
+            OperatorConfig::Moka(_) => Scheme::Moka,
+            OperatorConfig::Mongodb(_) => Scheme::Mongodb,
+            OperatorConfig::Monoiofs(_) => Scheme::Monoiofs,
+            OperatorConfig::Mysql(_) => Scheme::Mysql,
+            OperatorConfig::NebulaGraph(_) => Scheme::NebulaGraph,
+            OperatorConfig::Obs(_) => Scheme::Obs,
+            OperatorConfig::Onedrive(_) => Scheme::Onedrive,
+            OperatorConfig::Oss(_) => Scheme::Oss,
+            OperatorConfig::Ozone(_) => Scheme::Ozone,
+            OperatorConfig::Pcloud(_) => Scheme::Pcloud,
+            OperatorConfig::Persy(_) => Scheme::Persy,
+            OperatorConfig::Postgresql(_) => Scheme::Postgresql,
+            OperatorConfig::Rados(_) => Scheme::Rados,
+            OperatorConfig::Redb(_) => Scheme::Redb,
+            OperatorConfig::Redis(_) => Scheme::Redis,
+            OperatorConfig::Rocksdb(_) => Scheme::Rocksdb,
+            OperatorConfig::S3(_) => Scheme::S3,
+            OperatorConfig::Seafile(_) => Scheme::Seafile,
+            OperatorConfig::Sftp(_) => Scheme::Sftp,
+            OperatorConfig::Sled(_) => Scheme::Sled,
+            OperatorConfig::Sqlite(_) => Scheme::Sqlite,
+            OperatorConfig::Supabase(_) => Scheme::Supabase,
+            OperatorConfig::Surrealdb(_) => Scheme::Surrealdb,
+            OperatorConfig::Swift(_) => Scheme::Swift,
+            OperatorConfig::Tikv(_) => Scheme::Tikv,
+            OperatorConfig::Upyun(_) => Scheme::Upyun,
+            OperatorConfig::VercelArtifacts(_) => Scheme::VercelArtifacts,
+            OperatorConfig::VercelBlob(_) => Scheme::VercelBlob,
+            OperatorConfig::Webdav(_) => Scheme::Webdav,
+            OperatorConfig::Webhdfs(_) => Scheme::Webhdfs,
+            OperatorConfig::YandexDisk(_) => Scheme::YandexDisk,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OperatorConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut map = Map::<String, Value>::deserialize(deserializer)?;
+        let scheme = match map.remove("scheme") {
+            Some(Value::String(v)) => v,
+            Some(v) => {
+                return Err(de::Error::custom(format!(
+                    "scheme must be a string, but got {v}"
+                )))
+            }
+            None => return Err(de::Error::missing_field("scheme")),
+        };
+        // Scheme::from_str never fails, unknown schemes will be returned as `Custom`.
+        let scheme = scheme.parse::<Scheme>().map_err(de::Error::custom)?;
+
+        let cfg = match scheme {
+            Scheme::AliyunDrive => OperatorConfig::AliyunDrive(deserialize_config(scheme, map)?),
+            Scheme::Alluxio => OperatorConfig::Alluxio(deserialize_config(scheme, map)?),
+            Scheme::Atomicserver => OperatorConfig::Atomicserver(deserialize_config(scheme, map)?),
+            Scheme::Azblob => OperatorConfig::Azblob(deserialize_config(scheme, map)?),
+            Scheme::Azdls => OperatorConfig::Azdls(deserialize_config(scheme, map)?),
+            Scheme::Azfile => OperatorConfig::Azfile(deserialize_config(scheme, map)?),
+            Scheme::B2 => OperatorConfig::B2(deserialize_config(scheme, map)?),
+            Scheme::Cacache => OperatorConfig::Cacache(deserialize_config(scheme, map)?),
+            Scheme::Chainsafe => OperatorConfig::Chainsafe(deserialize_config(scheme, map)?),
+            Scheme::CloudflareKv => OperatorConfig::CloudflareKv(deserialize_config(scheme, map)?),
+            Scheme::Compfs => OperatorConfig::Compfs(deserialize_config(scheme, map)?),
+            Scheme::Cos => OperatorConfig::Cos(deserialize_config(scheme, map)?),
+            Scheme::D1 => OperatorConfig::D1(deserialize_config(scheme, map)?),
+            Scheme::Dashmap => OperatorConfig::Dashmap(deserialize_config(scheme, map)?),
+            Scheme::Dbfs => OperatorConfig::Dbfs(deserialize_config(scheme, map)?),
+            Scheme::Dropbox => OperatorConfig::Dropbox(deserialize_config(scheme, map)?),
+            Scheme::Etcd => OperatorConfig::Etcd(deserialize_config(scheme, map)?),
+            Scheme::Foundationdb => OperatorConfig::Foundationdb(deserialize_config(scheme, map)?),
+            Scheme::Fs => OperatorConfig::Fs(deserialize_config(scheme, map)?),
+            Scheme::Ftp => OperatorConfig::Ftp(deserialize_config(scheme, map)?),
+            Scheme::Gcs => OperatorConfig::Gcs(deserialize_config(scheme, map)?),
+            Scheme::Gdrive => OperatorConfig::Gdrive(deserialize_config(scheme, map)?),
+            Scheme::Ghac => OperatorConfig::Ghac(deserialize_config(scheme, map)?),
+            Scheme::Github => OperatorConfig::Github(deserialize_config(scheme, map)?),
+            Scheme::Gridfs => OperatorConfig::Gridfs(deserialize_config(scheme, map)?),
+            Scheme::Hdfs => OperatorConfig::Hdfs(deserialize_config(scheme, map)?),
+            Scheme::HdfsNative => OperatorConfig::HdfsNative(deserialize_config(scheme, map)?),
+            Scheme::Http => OperatorConfig::Http(deserialize_config(scheme, map)?),
+            Scheme::Huggingface => OperatorConfig::Huggingface(deserialize_config(scheme, map)?),
+            Scheme::Icloud => OperatorConfig::Icloud(deserialize_config(scheme, map)?),
+            Scheme::Ipfs => OperatorConfig::Ipfs(deserialize_config(scheme, map)?),
+            Scheme::Ipmfs => OperatorConfig::Ipmfs(deserialize_config(scheme, map)?),
+            Scheme::Koofr => OperatorConfig::Koofr(deserialize_config(scheme, map)?),
+            Scheme::Lakefs => OperatorConfig::Lakefs(deserialize_config(scheme, map)?),
+            Scheme::Libsql => OperatorConfig::Libsql(deserialize_config(scheme, map)?),
+            Scheme::Memcached => OperatorConfig::Memcached(deserialize_config(scheme, map)?),
+            Scheme::Memory => OperatorConfig::Memory(deserialize_config(scheme, map)?),
+            Scheme::MiniMoka => OperatorConfig::MiniMoka(deserialize_config(scheme, map)?),
+            Scheme::Moka => OperatorConfig::Moka(deserialize_config(scheme, map)?),
+            Scheme::Mongodb => OperatorConfig::Mongodb(deserialize_config(scheme, map)?),
+            Scheme::Monoiofs => OperatorConfig::Monoiofs(deserialize_config(scheme, map)?),
+            Scheme::Mysql => OperatorConfig::Mysql(deserialize_config(scheme, map)?),
+            Scheme::NebulaGraph => OperatorConfig::NebulaGraph(deserialize_config(scheme, map)?),
+            Scheme::Obs => OperatorConfig::Obs(deserialize_config(scheme, map)?),
+            Scheme::Onedrive => OperatorConfig::Onedrive(deserialize_config(scheme, map)?),
+            Scheme::Oss => OperatorConfig::Oss(deserialize_config(scheme, map)?),
+            Scheme::Ozone => OperatorConfig::Ozone(deserialize_config(scheme, map)?),
+            Scheme::Pcloud => OperatorConfig::Pcloud(deserialize_config(scheme, map)?),
+            Scheme::Persy => OperatorConfig::Persy(deserialize_config(scheme, map)?),
+            Scheme::Postgresql => OperatorConfig::Postgresql(deserialize_config(scheme, map)?),
+            Scheme::Rados => OperatorConfig::Rados(deserialize_config(scheme, map)?),
+            Scheme::Redb => OperatorConfig::Redb(deserialize_config(scheme, map)?),
+            Scheme::Redis => OperatorConfig::Redis(deserialize_config(scheme, map)?),
+            Scheme::Rocksdb => OperatorConfig::Rocksdb(deserialize_config(scheme, map)?),
+            Scheme::S3 => OperatorConfig::S3(deserialize_config(scheme, map)?),
+            Scheme::Seafile => OperatorConfig::Seafile(deserialize_config(scheme, map)?),
+            Scheme::Sftp => OperatorConfig::Sftp(deserialize_config(scheme, map)?),
+            Scheme::Sled => OperatorConfig::Sled(deserialize_config(scheme, map)?),
+            Scheme::Sqlite => OperatorConfig::Sqlite(deserialize_config(scheme, map)?),
+            Scheme::Supabase => OperatorConfig::Supabase(deserialize_config(scheme, map)?),
+            Scheme::Surrealdb => OperatorConfig::Surrealdb(deserialize_config(scheme, map)?),
+            Scheme::Swift => OperatorConfig::Swift(deserialize_config(scheme, map)?),
+            Scheme::Tikv => OperatorConfig::Tikv(deserialize_config(scheme, map)?),
+            Scheme::Upyun => OperatorConfig::Upyun(deserialize_config(scheme, map)?),
+            Scheme::VercelArtifacts => {
+                OperatorConfig::VercelArtifacts(deserialize_config(scheme, map)?)
+            }
+            Scheme::VercelBlob => OperatorConfig::VercelBlob(deserialize_config(scheme, map)?),
+            Scheme::Webdav => OperatorConfig::Webdav(deserialize_config(scheme, map)?),
+            Scheme::Webhdfs => OperatorConfig::Webhdfs(deserialize_config(scheme, map)?),
+            Scheme::YandexDisk => OperatorConfig::YandexDisk(deserialize_config(scheme, map)?),
+            v => {
+                return Err(de::Error::custom(format!(
+                    "scheme `{v}` doesn't have a config"
+                )))
+            }
+        };
+        Ok(cfg)
+    }
+}
+
+/// Deserialize the config of given scheme and reject unknown fields.
+///
+/// Service configs ignore unknown fields so that `from_iter` can accept
+/// shared options like `http_connect_timeout`, so we check them here by
+/// comparing with the fields of the default config.
+fn deserialize_config<C, E>(scheme: Scheme, map: Map<String, Value>) -> Result<C, E>
+where
+    C: Default + Serialize + DeserializeOwned,
+    E: de::Error,
+{
+    if let Value::Object(fields) = serde_json::to_value(C::default()).map_err(E::custom)? {
+        if let Some(key) = map.keys().find(|k| !fields.contains_key(k.as_str())) {
+            return Err(E::custom(format!(
+                "unknown field `{key}` for scheme `{scheme}`"
+            )));
+        }
+    }
+
+    serde_json::from_value(Value::Object(map)).map_err(E::custom)
+}
+
+impl Operator {
+    /// Create a new operator from [`OperatorConfig`] in dynamic dispatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::services::MemoryConfig;
+    /// use opendal::Operator;
+    /// use opendal::OperatorConfig;
+    ///
+    /// fn test() -> Result<()> {
+    ///     let cfg = OperatorConfig::Memory(MemoryConfig::default());
+    ///
+    ///     // Build an `Operator` to start operating the storage.
+    ///     let op: Operator = Operator::via_config(cfg)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[allow(unused_variables, unreachable_code, unreachable_patterns)]
+    pub fn via_config(cfg: OperatorConfig) -> Result<Operator> {
+        let op = match cfg {
+            #[cfg(feature = "services-aliyun-drive")]
+            OperatorConfig::AliyunDrive(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-alluxio")]
+            OperatorConfig::Alluxio(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-atomicserver")]
+            OperatorConfig::Atomicserver(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-azblob")]
+            OperatorConfig::Azblob(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-azdls")]
+            OperatorConfig::Azdls(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-azfile")]
+            OperatorConfig::Azfile(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-b2")]
+            OperatorConfig::B2(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-cacache")]
+            OperatorConfig::Cacache(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-chainsafe")]
+            OperatorConfig::Chainsafe(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-cloudflare-kv")]
+            OperatorConfig::CloudflareKv(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-compfs")]
+            OperatorConfig::Compfs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-cos")]
+            OperatorConfig::Cos(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-d1")]
+            OperatorConfig::D1(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-dashmap")]
+            OperatorConfig::Dashmap(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-dbfs")]
+            OperatorConfig::Dbfs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-dropbox")]
+            OperatorConfig::Dropbox(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-etcd")]
+            OperatorConfig::Etcd(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-foundationdb")]
+            OperatorConfig::Foundationdb(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-fs")]
+            OperatorConfig::Fs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-ftp")]
+            OperatorConfig::Ftp(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-gcs")]
+            OperatorConfig::Gcs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-gdrive")]
+            OperatorConfig::Gdrive(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-ghac")]
+            OperatorConfig::Ghac(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-github")]
+            OperatorConfig::Github(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-gridfs")]
+            OperatorConfig::Gridfs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-hdfs")]
+            OperatorConfig::Hdfs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-hdfs-native")]
+            OperatorConfig::HdfsNative(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-http")]
+            OperatorConfig::Http(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-huggingface")]
+            OperatorConfig::Huggingface(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-icloud")]
+            OperatorConfig::Icloud(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-ipfs")]
+            OperatorConfig::Ipfs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-ipmfs")]
+            OperatorConfig::Ipmfs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-koofr")]
+            OperatorConfig::Koofr(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-lakefs")]
+            OperatorConfig::Lakefs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-libsql")]
+            OperatorConfig::Libsql(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-memcached")]
+            OperatorConfig::Memcached(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-memory")]
+            OperatorConfig::Memory(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-mini-moka")]
+            OperatorConfig::MiniMoka(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-moka")]
+            OperatorConfig::Moka(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-mongodb")]
+            OperatorConfig::Mongodb(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-monoiofs")]
+            OperatorConfig::Monoiofs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-mysql")]
+            OperatorConfig::Mysql(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-nebula-graph")]
+            OperatorConfig::NebulaGraph(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-obs")]
+            OperatorConfig::Obs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-onedrive")]
+            OperatorConfig::Onedrive(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-oss")]
+            OperatorConfig::Oss(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-ozone")]
+            OperatorConfig::Ozone(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-pcloud")]
+            OperatorConfig::Pcloud(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-persy")]
+            OperatorConfig::Persy(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-postgresql")]
+            OperatorConfig::Postgresql(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-rados")]
+            OperatorConfig::Rados(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-redb")]
+            OperatorConfig::Redb(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-redis")]
+            OperatorConfig::Redis(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-rocksdb")]
+            OperatorConfig::Rocksdb(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-s3")]
+            OperatorConfig::S3(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-seafile")]
+            OperatorConfig::Seafile(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-sftp")]
+            OperatorConfig::Sftp(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-sled")]
+            OperatorConfig::Sled(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-sqlite")]
+            OperatorConfig::Sqlite(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-supabase")]
+            OperatorConfig::Supabase(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-surrealdb")]
+            OperatorConfig::Surrealdb(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-swift")]
+            OperatorConfig::Swift(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-tikv")]
+            OperatorConfig::Tikv(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-upyun")]
+            OperatorConfig::Upyun(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-vercel-artifacts")]
+            OperatorConfig::VercelArtifacts(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-vercel-blob")]
+            OperatorConfig::VercelBlob(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-webdav")]
+            OperatorConfig::Webdav(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-webhdfs")]
+            OperatorConfig::Webhdfs(cfg) => Self::from_config(cfg)?.finish(),
+            #[cfg(feature = "services-yandex-disk")]
+            OperatorConfig::YandexDisk(cfg) => Self::from_config(cfg)?.finish(),
+            v => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "scheme is not enabled or supported",
+                )
+                .with_context("scheme", v.scheme()))
+            }
+        };
+
+        Ok(op)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::S3Config;
+
+    #[test]
+    fn test_deserialize_operator_config() {
+        let cfg: OperatorConfig = serde_json::from_str(
+            r#"{"scheme": "s3", "bucket": "test", "region": "us-east-1", "disable_config_load": true}"#,
+        )
+        .unwrap();
+
+        let expected = S3Config {
+            bucket: "test".to_string(),
+            region: Some("us-east-1".to_string()),
+            disable_config_load: true,
+            ..Default::default()
+        };
+        assert_eq!(cfg, OperatorConfig::S3(expected));
+        assert_eq!(cfg.scheme(), Scheme::S3);
+    }
+
+    #[test]
+    fn test_deserialize_operator_config_round_trip() {
+        let cfg: OperatorConfig =
+            serde_json::from_str(r#"{"scheme": "memory", "root": "/tmp"}"#).unwrap();
+        let value = serde_json::to_value(&cfg).unwrap();
+        assert_eq!(value["scheme"], "memory");
+
+        let decoded: OperatorConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(decoded, cfg);
+    }
+
+    #[test]
+    fn test_deserialize_operator_config_unknown_field() {
+        let err = serde_json::from_str::<OperatorConfig>(r#"{"scheme": "s3", "buckt": "test"}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown field `buckt`"));
+    }
+
+    #[test]
+    fn test_deserialize_operator_config_invalid() {
+        assert!(serde_json::from_str::<OperatorConfig>(r#"{"bucket": "test"}"#).is_err());
+        assert!(serde_json::from_str::<OperatorConfig>(r#"{"scheme": "unknown"}"#).is_err());
+        assert!(
+            serde_json::from_str::<OperatorConfig>(r#"{"scheme": "s3", "bucket": 1}"#).is_err()
+        );
+    }
+}
@@ -0,0 +1,191 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::layers::RetryLayer;
+use crate::layers::TimeoutLayer;
+use crate::raw::*;
+use crate::*;
+
+/// OperatorDefaults contains service-agnostic defaults for an [`Operator`].
+///
+/// It allows users to set timeout, retry, user agent and read chunk size
+/// without stacking layers manually. Users can pass these options along with
+/// service's config via [`Operator::via_iter`] by adding a `default_` prefix
+/// to the field name, for example `default_retry_max_times=3`.
+///
+/// All timeouts are in seconds.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use opendal::services::Memory;
+/// use opendal::Operator;
+/// use opendal::OperatorDefaults;
+///
+/// # fn test() -> Result<()> {
+/// let mut defaults = OperatorDefaults::default();
+/// defaults.timeout = Some(30);
+/// defaults.retry_max_times = Some(3);
+///
+/// let op = Operator::new(Memory::default())?
+///     .finish()
+///     .with_defaults(&defaults);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct OperatorDefaults {
+    /// The suffix that appended to the `User-Agent` of http requests.
+    ///
+    /// The user agent will be `opendal/<version> <suffix>`, services that
+    /// set their own user agent are not affected.
+    pub user_agent: Option<String>,
+    /// The default chunk size of read, users can still override it via
+    /// `chunk` of [`Operator::read_with`] and [`Operator::reader_with`].
+    pub read_chunk: Option<usize>,
+    /// Timeout for non-io operations like `stat` and `delete`.
+    ///
+    /// [`TimeoutLayer`] will be applied if `timeout` or `io_timeout` is set.
+    pub timeout: Option<u64>,
+    /// Timeout for io operations like `Reader::read` and `Writer::write`.
+    pub io_timeout: Option<u64>,
+    /// Max retry times of temporary errors.
+    ///
+    /// [`RetryLayer`] will be applied if it's set.
+    pub retry_max_times: Option<usize>,
+}
+
+impl OperatorDefaults {
+    /// The prefix of keys that belong to operator defaults.
+    pub const KEY_PREFIX: &'static str = "default_";
+
+    /// Parse operator defaults from an iterator of key-value pairs.
+    ///
+    /// Only keys starting with [`Self::KEY_PREFIX`] will be used, other keys are
+    /// ignored. Returns `None` if there are no default options at all.
+    pub fn from_prefixed_iter(
+        iter: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Option<Self>> {
+        let map: HashMap<String, String> = iter
+            .into_iter()
+            .filter_map(|(k, v)| k.strip_prefix(Self::KEY_PREFIX).map(|k| (k.to_string(), v)))
+            .collect();
+        if map.is_empty() {
+            return Ok(None);
+        }
+
+        let cfg = Self::deserialize(ConfigDeserializer::new(map)).map_err(|err| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "failed to deserialize operator defaults",
+            )
+            .set_source(err)
+        })?;
+        Ok(Some(cfg))
+    }
+
+    /// Build the full user agent with the configured suffix.
+    pub(crate) fn full_user_agent(&self) -> Option<String> {
+        self.user_agent
+            .as_ref()
+            .map(|suffix| format!("opendal/{VERSION} {suffix}"))
+    }
+
+    /// Apply the defaults to given operator.
+    pub(crate) fn apply(&self, mut op: Operator) -> Operator {
+        if self.timeout.is_some() || self.io_timeout.is_some() {
+            let mut layer = TimeoutLayer::new();
+            if let Some(v) = self.timeout {
+                layer = layer.with_timeout(Duration::from_secs(v));
+            }
+            if let Some(v) = self.io_timeout {
+                layer = layer.with_io_timeout(Duration::from_secs(v));
+            }
+            op = op.layer(layer);
+        }
+        // Retry must be applied after timeout so that timed out requests
+        // could be retried.
+        if let Some(v) = self.retry_max_times {
+            op = op.layer(RetryLayer::new().with_max_times(v));
+        }
+        if let Some(v) = self.read_chunk {
+            op = op.with_default_read_chunk(v);
+        }
+        op
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_defaults_from_prefixed_iter() {
+        let cfg = OperatorDefaults::from_prefixed_iter([
+            ("root".to_string(), "/tmp".to_string()),
+            ("default_timeout".to_string(), "30".to_string()),
+            ("default_retry_max_times".to_string(), "3".to_string()),
+            ("default_user_agent".to_string(), "my-app/1.0".to_string()),
+        ])
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            cfg,
+            OperatorDefaults {
+                user_agent: Some("my-app/1.0".to_string()),
+                timeout: Some(30),
+                retry_max_times: Some(3),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            cfg.full_user_agent(),
+            Some(format!("opendal/{VERSION} my-app/1.0"))
+        );
+    }
+
+    #[test]
+    fn test_operator_defaults_from_prefixed_iter_without_default_keys() {
+        let cfg = OperatorDefaults::from_prefixed_iter([("root".to_string(), "/tmp".to_string())])
+            .unwrap();
+        assert!(cfg.is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "services-memory")]
+    fn test_operator_defaults_apply() {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        assert_eq!(op.default_read_chunk(), None);
+
+        let defaults = OperatorDefaults {
+            read_chunk: Some(4 * 1024 * 1024),
+            ..Default::default()
+        };
+        let op = op.with_defaults(&defaults);
+        assert_eq!(op.default_read_chunk(), Some(4 * 1024 * 1024));
+    }
+}
@@ -0,0 +1,200 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::*;
+
+/// Status of a single check in [`Diagnosis`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DiagnosisStatus {
+    /// The check passed.
+    Passed,
+    /// The check is skipped because it's not supported by service or not requested.
+    Skipped,
+    /// The check failed with this error.
+    Failed(Error),
+}
+
+impl DiagnosisStatus {
+    /// Check if this check passed.
+    pub fn is_passed(&self) -> bool {
+        matches!(self, DiagnosisStatus::Passed)
+    }
+
+    /// Get the error if this check failed.
+    pub fn error(&self) -> Option<&Error> {
+        match self {
+            DiagnosisStatus::Failed(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    pub(super) fn from_result<T>(res: Result<T>) -> Self {
+        match res {
+            Ok(_) => DiagnosisStatus::Passed,
+            Err(err) => DiagnosisStatus::Failed(err),
+        }
+    }
+
+    /// Check if this check got a response from service.
+    ///
+    /// Errors like `Unexpected` could be caused by network failures, so we don't
+    /// treat them as a response.
+    fn is_responded(&self) -> bool {
+        match self {
+            DiagnosisStatus::Passed => true,
+            DiagnosisStatus::Skipped => false,
+            DiagnosisStatus::Failed(err) => !matches!(
+                err.kind(),
+                ErrorKind::Unexpected | ErrorKind::ConfigInvalid | ErrorKind::Unsupported
+            ),
+        }
+    }
+}
+
+/// Diagnosis is the structured result of [`Operator::diagnose`].
+///
+/// It contains the status of every check, and provides helpers to tell
+/// which part of the configuration is wrong.
+#[derive(Debug)]
+pub struct Diagnosis {
+    pub(super) stat: DiagnosisStatus,
+    pub(super) list: DiagnosisStatus,
+    pub(super) write: DiagnosisStatus,
+    pub(super) delete: DiagnosisStatus,
+}
+
+impl Diagnosis {
+    /// Status of `stat` on root.
+    pub fn stat(&self) -> &DiagnosisStatus {
+        &self.stat
+    }
+
+    /// Status of `list` on root with only the first entry fetched.
+    pub fn list(&self) -> &DiagnosisStatus {
+        &self.list
+    }
+
+    /// Status of writing a tiny file under root.
+    pub fn write(&self) -> &DiagnosisStatus {
+        &self.write
+    }
+
+    /// Status of deleting the tiny file written by the write check.
+    pub fn delete(&self) -> &DiagnosisStatus {
+        &self.delete
+    }
+
+    fn checks(&self) -> [&DiagnosisStatus; 4] {
+        [&self.stat, &self.list, &self.write, &self.delete]
+    }
+
+    /// Check if the endpoint is reachable.
+    ///
+    /// The endpoint is reachable if any check passed or failed with an error
+    /// returned by service like `PermissionDenied`.
+    pub fn is_reachable(&self) -> bool {
+        self.checks().iter().any(|v| v.is_responded())
+    }
+
+    /// Check if the credential is accepted by service.
+    ///
+    /// The credential is valid if any check passed, or the endpoint is reachable
+    /// and none of the checks failed with `PermissionDenied`.
+    pub fn is_authenticated(&self) -> bool {
+        if self.checks().iter().any(|v| v.is_passed()) {
+            return true;
+        }
+
+        self.is_reachable()
+            && !self
+                .checks()
+                .iter()
+                .any(|v| v.error().map(|err| err.kind()) == Some(ErrorKind::PermissionDenied))
+    }
+
+    /// Check if we have permission to read.
+    pub fn can_read(&self) -> bool {
+        self.stat.is_passed()
+    }
+
+    /// Check if we have permission to list.
+    pub fn can_list(&self) -> bool {
+        self.list.is_passed()
+    }
+
+    /// Check if we have permission to write and delete.
+    pub fn can_write(&self) -> bool {
+        self.write.is_passed() && self.delete.is_passed()
+    }
+
+    /// Check if all checks are passed or skipped.
+    pub fn is_ok(&self) -> bool {
+        self.checks()
+            .iter()
+            .all(|v| !matches!(v, DiagnosisStatus::Failed(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnosis_permission_denied() {
+        let d = Diagnosis {
+            stat: DiagnosisStatus::Failed(Error::new(ErrorKind::PermissionDenied, "denied")),
+            list: DiagnosisStatus::Failed(Error::new(ErrorKind::PermissionDenied, "denied")),
+            write: DiagnosisStatus::Skipped,
+            delete: DiagnosisStatus::Skipped,
+        };
+
+        assert!(d.is_reachable());
+        assert!(!d.is_authenticated());
+        assert!(!d.can_read());
+        assert!(!d.is_ok());
+    }
+
+    #[test]
+    fn test_diagnosis_unreachable() {
+        let d = Diagnosis {
+            stat: DiagnosisStatus::Failed(Error::new(ErrorKind::Unexpected, "send http request")),
+            list: DiagnosisStatus::Failed(Error::new(ErrorKind::Unexpected, "send http request")),
+            write: DiagnosisStatus::Skipped,
+            delete: DiagnosisStatus::Skipped,
+        };
+
+        assert!(!d.is_reachable());
+        assert!(!d.is_authenticated());
+    }
+
+    #[test]
+    fn test_diagnosis_read_only() {
+        let d = Diagnosis {
+            stat: DiagnosisStatus::Passed,
+            list: DiagnosisStatus::Passed,
+            write: DiagnosisStatus::Failed(Error::new(ErrorKind::PermissionDenied, "denied")),
+            delete: DiagnosisStatus::Skipped,
+        };
+
+        assert!(d.is_reachable());
+        assert!(d.is_authenticated());
+        assert!(d.can_read());
+        assert!(d.can_list());
+        assert!(!d.can_write());
+    }
+}
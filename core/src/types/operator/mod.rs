@@ -27,8 +27,25 @@ pub use blocking_operator::BlockingOperator;
 mod builder;
 pub use builder::OperatorBuilder;
 
+mod config;
+pub use config::OperatorConfig;
+
+mod defaults;
+pub use defaults::OperatorDefaults;
+
+mod options;
+pub use options::ListOptions;
+pub use options::ReadOptions;
+pub use options::WriteOptions;
+
 mod metadata;
 pub use metadata::OperatorInfo;
 
+mod diagnosis;
+pub use diagnosis::Diagnosis;
+pub use diagnosis::DiagnosisStatus;
+
 pub mod operator_functions;
 pub mod operator_futures;
+
+mod transfer;
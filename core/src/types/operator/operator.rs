@@ -15,13 +15,20 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::future::Future;
 use std::time::Duration;
 
+use bytes::Bytes;
+use futures::AsyncRead;
+use futures::AsyncReadExt;
+use futures::AsyncWrite;
+use futures::AsyncWriteExt;
 use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
 
+use super::transfer::*;
 use super::BlockingOperator;
 use crate::operator_futures::*;
 use crate::raw::oio::DeleteDyn;
@@ -115,6 +122,10 @@ pub struct Operator {
 
     /// The default executor that used to run futures in background.
     default_executor: Option<Executor>,
+    /// The max concurrent tasks of batch operations like `remove_all`, `copy_all_to` and `walk`.
+    limit: usize,
+    /// The default chunk size of read.
+    default_read_chunk: Option<usize>,
 }
 
 /// # Operator basic API.
@@ -129,6 +140,8 @@ impl Operator {
         Self {
             accessor,
             default_executor: None,
+            limit: 1,
+            default_read_chunk: None,
         }
     }
 
@@ -137,19 +150,33 @@ impl Operator {
         self.accessor
     }
 
+    /// Replace the inner accessor while keeping other settings like limit,
+    /// default executor and default read chunk.
+    pub(crate) fn with_inner(mut self, accessor: Accessor) -> Self {
+        self.accessor = accessor;
+        self
+    }
+
     /// Get current operator's limit.
-    /// Limit is usually the maximum size of data that operator will handle in one operation.
-    #[deprecated(note = "limit is no-op for now", since = "0.52.0")]
+    ///
+    /// Limit is the max number of concurrent tasks that batch operations like
+    /// [`Operator::remove_all`], [`Operator::copy_all_to`] and [`Operator::walk`]
+    /// will run.
     pub fn limit(&self) -> usize {
-        0
+        self.limit
     }
 
-    /// Specify the batch limit.
+    /// Specify the limit of concurrent tasks for batch operations.
+    ///
+    /// Tasks are executed by the default executor (see [`Operator::with_default_executor`]),
+    /// and all pending tasks will be cancelled together once the batch operation
+    /// returns an error or is dropped.
     ///
-    /// Default: 1000
-    #[deprecated(note = "limit is no-op for now", since = "0.52.0")]
-    pub fn with_limit(&self, _: usize) -> Self {
-        self.clone()
+    /// Default: 1, which means batch operations run sequentially.
+    pub fn with_limit(&self, limit: usize) -> Self {
+        let mut op = self.clone();
+        op.limit = limit.max(1);
+        op
     }
 
     /// Get the default executor.
@@ -164,6 +191,44 @@ impl Operator {
         op
     }
 
+    /// Get the default chunk size of read.
+    pub fn default_read_chunk(&self) -> Option<usize> {
+        self.default_read_chunk
+    }
+
+    /// Specify the default chunk size of read.
+    ///
+    /// It will be used by [`Operator::read_with`] and [`Operator::reader_with`]
+    /// if `chunk` is not set.
+    pub fn with_default_read_chunk(&self, chunk: usize) -> Self {
+        let mut op = self.clone();
+        op.default_read_chunk = Some(chunk);
+        op
+    }
+
+    /// Apply the given [`OperatorDefaults`] to this operator.
+    ///
+    /// Layers like [`TimeoutLayer`](crate::layers::TimeoutLayer) and
+    /// [`RetryLayer`](crate::layers::RetryLayer) will be added with dynamic
+    /// dispatch if their options are set.
+    ///
+    /// # Notes
+    ///
+    /// `user_agent` is applied while building the http client, so it only
+    /// takes effect via [`Operator::via_iter`] and [`Operator::from_iter`].
+    #[must_use]
+    pub fn with_defaults(self, defaults: &OperatorDefaults) -> Self {
+        defaults.apply(self)
+    }
+
+    /// Build the default reader options of this operator.
+    fn default_op_reader(&self) -> OpReader {
+        match self.default_read_chunk {
+            Some(chunk) => OpReader::default().with_chunk(chunk),
+            None => OpReader::default(),
+        }
+    }
+
     /// Get information of underlying accessor.
     ///
     /// # Examples
@@ -185,7 +250,17 @@ impl Operator {
     /// Create a new blocking operator.
     ///
     /// This operation is nearly no cost.
+    ///
+    /// If the underlying service doesn't support blocking natively and the `layers-blocking`
+    /// feature is enabled, [`BlockingLayer`](crate::layers::BlockingLayer) will be added
+    /// automatically, which reuses current runtime or a lazily created global runtime.
     pub fn blocking(&self) -> BlockingOperator {
+        #[cfg(feature = "layers-blocking")]
+        if !self.info().full_capability().blocking {
+            let op = self.clone().layer(crate::layers::BlockingLayer::new());
+            return BlockingOperator::from_inner(op.into_inner());
+        }
+
         BlockingOperator::from_inner(self.accessor.clone())
     }
 }
@@ -215,6 +290,111 @@ impl Operator {
         }
     }
 
+    /// Diagnose this operator to find out which part of the configuration is wrong.
+    ///
+    /// Unlike [`Operator::check`], this function will run all checks and return a
+    /// structured [`Diagnosis`] instead of the first error we met:
+    ///
+    /// - `stat` on root
+    /// - `list` on root with only the first entry fetched
+    /// - write and delete a tiny file under root, only if enabled via [`Operator::diagnose_with`]
+    ///
+    /// Checks not supported by the service will be skipped.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let diagnosis = op.diagnose().await?;
+    /// if !diagnosis.is_reachable() {
+    ///     println!("endpoint is not reachable: {:?}", diagnosis.stat().error());
+    /// } else if !diagnosis.is_authenticated() {
+    ///     println!("credential is invalid");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn diagnose(&self) -> Result<Diagnosis> {
+        self.diagnose_with().await
+    }
+
+    /// Diagnose this operator with extra options.
+    ///
+    /// # Options
+    ///
+    /// ## `write`
+    ///
+    /// Write a tiny file under root and delete it to check write permissions.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let diagnosis = op.diagnose_with().write(true).await?;
+    /// println!("can write: {}", diagnosis.can_write());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diagnose_with(&self) -> FutureDiagnose<impl Future<Output = Result<Diagnosis>>> {
+        OperatorFuture::new(
+            self.inner().clone(),
+            "/".to_string(),
+            false,
+            |inner, path, write| async move {
+                let op = Operator::from_inner(inner);
+                let cap = op.info().full_capability();
+
+                let stat = if cap.stat {
+                    match op.stat(&path).await {
+                        // Root not found still means we have permission to read.
+                        Err(err) if err.kind() == ErrorKind::NotFound => DiagnosisStatus::Passed,
+                        res => DiagnosisStatus::from_result(res),
+                    }
+                } else {
+                    DiagnosisStatus::Skipped
+                };
+
+                let list = if cap.list {
+                    let res = match op.lister(&path).await {
+                        Ok(mut lister) => match lister.next().await {
+                            Some(Err(err)) => Err(err),
+                            _ => Ok(()),
+                        },
+                        Err(err) => Err(err),
+                    };
+                    match res {
+                        Err(err) if err.kind() == ErrorKind::NotFound => DiagnosisStatus::Passed,
+                        res => DiagnosisStatus::from_result(res),
+                    }
+                } else {
+                    DiagnosisStatus::Skipped
+                };
+
+                let (write, delete) = if write && cap.write && cap.delete {
+                    let path = format!(".opendal-diagnose-{}", uuid::Uuid::new_v4());
+                    match op.write(&path, "opendal").await {
+                        Ok(_) => (
+                            DiagnosisStatus::Passed,
+                            DiagnosisStatus::from_result(op.delete(&path).await),
+                        ),
+                        Err(err) => (DiagnosisStatus::Failed(err), DiagnosisStatus::Skipped),
+                    }
+                } else {
+                    (DiagnosisStatus::Skipped, DiagnosisStatus::Skipped)
+                };
+
+                Ok(Diagnosis {
+                    stat,
+                    list,
+                    write,
+                    delete,
+                })
+            },
+        )
+    }
+
     /// Get given path's metadata.
     ///
     /// # Notes
@@ -418,6 +598,45 @@ impl Operator {
         )
     }
 
+    /// Get the metadata of given entry with required fields.
+    ///
+    /// Metadata returned by `list` may only contain part of the fields. This function
+    /// returns the metadata in entry directly if all required fields are provided by
+    /// `list`, otherwise it will send a `stat` request to fetch them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::MetadataField;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut lister = op.lister("dir/").await?;
+    /// while let Some(entry) = lister.try_next().await? {
+    ///     let meta = op
+    ///         .metadata(&entry, &[MetadataField::ContentLength, MetadataField::Etag])
+    ///         .await?;
+    ///     println!("{} {} {:?}", entry.path(), meta.content_length(), meta.etag());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn metadata(&self, entry: &Entry, fields: &[MetadataField]) -> Result<Metadata> {
+        let meta = entry.metadata();
+        if fields.iter().all(|field| meta.contains(*field)) {
+            return Ok(meta.clone());
+        }
+
+        match meta.version() {
+            Some(version) if self.info().full_capability().stat_with_version => {
+                self.stat_with(entry.path()).version(version).await
+            }
+            _ => self.stat(entry.path()).await,
+        }
+    }
+
     /// Check if this path exists or not.
     ///
     /// # Example
@@ -512,6 +731,48 @@ impl Operator {
         Ok(())
     }
 
+    /// Create a dir at given path with extra options.
+    ///
+    /// # Options
+    ///
+    /// ## `recursive`
+    ///
+    /// Services that emulate dirs by marker objects (like s3) will only create the
+    /// marker of given path. Set `recursive` to `true` to create markers for all
+    /// missing parent dirs as well, so that `stat` on every parent returns a dir.
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.create_dir_with("path/to/dir/").recursive(true).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_dir_with(&self, path: &str) -> FutureCreateDir<impl Future<Output = Result<()>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpCreateDir::default(),
+            |inner, path, args| async move {
+                if !validate_path(&path, EntryMode::DIR) {
+                    return Err(Error::new(
+                        ErrorKind::NotADirectory,
+                        "the path trying to create should end with `/`",
+                    )
+                    .with_operation("create_dir")
+                    .with_context("service", inner.info().scheme())
+                    .with_context("path", &path));
+                }
+
+                inner.create_dir(&path, args).await?;
+                Ok(())
+            },
+        )
+    }
+
     /// Read the whole path into a bytes.
     ///
     /// # Notes
@@ -588,7 +849,7 @@ impl Operator {
             path,
             (
                 OpRead::default().merge_executor(self.default_executor.clone()),
-                OpReader::default(),
+                self.default_op_reader(),
             ),
             |inner, path, (args, options)| async move {
                 if !validate_path(&path, EntryMode::FILE) {
@@ -609,6 +870,31 @@ impl Operator {
         )
     }
 
+    /// Read the whole path into a bytes with typed [`ReadOptions`].
+    ///
+    /// This is the same as [`Operator::read_with`] but takes all options at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// use opendal::Operator;
+    /// use opendal::ReadOptions;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut opts = ReadOptions::default();
+    /// opts.offset = Some(0);
+    /// opts.size = Some(1024);
+    /// let bs = op.read_opts("path/to/file", opts).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_opts(&self, path: &str, opts: ReadOptions) -> Result<Buffer> {
+        self.read_with(path)
+            .map(|(args, options)| opts.apply(args, options))
+            .await
+    }
+
     /// Create a new reader which can read the whole path.
     ///
     /// # Notes
@@ -675,7 +961,7 @@ impl Operator {
             path,
             (
                 OpRead::default().merge_executor(self.default_executor.clone()),
-                OpReader::default(),
+                self.default_op_reader(),
             ),
             |inner, path, (args, options)| async move {
                 if !validate_path(&path, EntryMode::FILE) {
@@ -693,6 +979,15 @@ impl Operator {
         )
     }
 
+    /// Create a new reader with typed [`ReadOptions`].
+    ///
+    /// This is the same as [`Operator::reader_with`] but takes all options at once.
+    pub async fn reader_opts(&self, path: &str, opts: ReadOptions) -> Result<Reader> {
+        self.reader_with(path)
+            .map(|(args, options)| opts.apply(args, options))
+            .await
+    }
+
     /// Write bytes into path.
     ///
     /// # Notes
@@ -951,6 +1246,25 @@ impl Operator {
                     );
                 }
 
+                if let Some(progress) = args.progress().and_then(|v| v.get()) {
+                    if let Some((idx, part)) = progress
+                        .parts
+                        .iter()
+                        .enumerate()
+                        .find(|(idx, part)| part.part_number != *idx)
+                    {
+                        return Err(Error::new(
+                            ErrorKind::Unexpected,
+                            "resumed parts must be contiguous and start from 0",
+                        )
+                        .with_operation("Operator::writer")
+                        .with_context("path", &path)
+                        .with_context("upload_id", &progress.upload_id)
+                        .with_context("expected", idx)
+                        .with_context("actual", part.part_number));
+                    }
+                }
+
                 let context = WriteContext::new(inner, path, args, options);
                 let w = Writer::new(context).await?;
                 Ok(w)
@@ -958,6 +1272,15 @@ impl Operator {
         )
     }
 
+    /// Create a writer with typed [`WriteOptions`].
+    ///
+    /// This is the same as [`Operator::writer_with`] but takes all options at once.
+    pub async fn writer_opts(&self, path: &str, opts: WriteOptions) -> Result<Writer> {
+        self.writer_with(path)
+            .map(|(args, options)| opts.apply(args, options))
+            .await
+    }
+
     /// Write data with extra options.
     ///
     /// # Notes
@@ -1038,146 +1361,554 @@ impl Operator {
         )
     }
 
-    /// Delete the given path.
-    ///
-    /// # Notes
+    /// Write bytes into path with typed [`WriteOptions`].
     ///
-    /// - Deleting a file that does not exist won't return errors.
+    /// This is the same as [`Operator::write_with`] but takes all options at once.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use anyhow::Result;
-    /// # use futures::io;
-    /// # use opendal::Operator;
+    /// # use opendal::Result;
+    /// use opendal::Operator;
+    /// use opendal::WriteOptions;
+    ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// op.delete("test").await?;
+    /// let mut opts = WriteOptions::default();
+    /// opts.content_type = Some("text/plain".to_string());
+    /// op.write_opts("path/to/file", vec![0; 4096], opts).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete(&self, path: &str) -> Result<()> {
-        self.delete_with(path).await
+    pub async fn write_opts(
+        &self,
+        path: &str,
+        bs: impl Into<Buffer>,
+        opts: WriteOptions,
+    ) -> Result<()> {
+        self.write_with(path, bs)
+            .map(|(args, options, bs)| {
+                let (args, options) = opts.apply(args, options);
+                (args, options, bs)
+            })
+            .await
     }
 
-    /// Delete the given path with extra options.
+    /// Read the whole file at path into the given writer, returns the size of read data.
     ///
     /// # Notes
     ///
-    /// - Deleting a file that does not exist won't return errors.
-    ///
-    /// # Options
-    ///
-    /// ## `version`
-    ///
-    /// Set `version` for this `delete` request.
+    /// [`Operator::read_to_writer`] is a simplified version of [`Operator::read_to_writer_with`]
+    /// without additional options.
     ///
-    /// remove a specific version of the given path.
+    /// Data is streamed in chunks, so the whole file won't be loaded into memory.
+    /// The writer will be flushed but not closed.
     ///
-    /// If the version doesn't exist, OpenDAL will not return errors.
+    /// # Examples
     ///
     /// ```
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    ///
-    /// # async fn test(op: Operator, version: &str) -> Result<()> {
-    /// op.delete_with("path/to/file").version(version).await?;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = futures::io::Cursor::new(Vec::new());
+    /// let n = op.read_to_writer("path/to/file", &mut w).await?;
     /// # Ok(())
     /// # }
-    ///```
+    /// ```
+    pub async fn read_to_writer<W>(&self, path: &str, w: W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        self.read_to_writer_with(path, w).await
+    }
+
+    /// Read the whole file at path into the given writer with extra options.
+    ///
+    /// # Options
+    ///
+    /// Visit [`FutureReadToWriter`] for all available options.
+    ///
+    /// - [`buffer_size`](./operator_futures/type.FutureReadToWriter.html#method.buffer_size): Sets the size of each chunk fetched from service.
+    /// - [`verify_checksum`](./operator_futures/type.FutureReadToWriter.html#method.verify_checksum): Verifies the data against the content length and content md5.
+    /// - [`version`](./operator_futures/type.FutureReadToWriter.html#method.version): Sets the version to read.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use anyhow::Result;
-    /// # use futures::io;
+    /// # use opendal::Result;
     /// # use opendal::Operator;
-    ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// op.delete_with("test").await?;
+    /// let mut w = futures::io::Cursor::new(Vec::new());
+    /// let n = op
+    ///     .read_to_writer_with("path/to/file", &mut w)
+    ///     .buffer_size(4 * 1024 * 1024)
+    ///     .verify_checksum(true)
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn delete_with(&self, path: &str) -> FutureDelete<impl Future<Output = Result<()>>> {
+    pub fn read_to_writer_with<W>(
+        &self,
+        path: &str,
+        w: W,
+    ) -> FutureReadToWriter<W, impl Future<Output = Result<u64>>>
+    where
+        W: AsyncWrite + Unpin,
+    {
         let path = normalize_path(path);
 
         OperatorFuture::new(
             self.inner().clone(),
             path,
-            OpDelete::default(),
-            |inner, path, args| async move {
-                let (_, mut deleter) = inner.delete_dyn().await?;
-                deleter.delete_dyn(&path, args)?;
-                deleter.flush_dyn().await?;
-                Ok(())
+            (
+                OpRead::default().merge_executor(self.default_executor.clone()),
+                OpTransfer::default(),
+                w,
+            ),
+            |inner, path, (args, options, mut w)| async move {
+                let (mut s, meta) = open_source(inner, path.clone(), args, &options)
+                    .await
+                    .map_err(|err| err.with_operation("Operator::read_to_writer"))?;
+
+                let mut checksum = Checksum::new(options.verify_checksum());
+                while let Some(bs) = s.try_next().await.map_err(new_std_io_error)? {
+                    checksum.update(&bs);
+                    w.write_all(&bs).await.map_err(new_std_io_error)?;
+                }
+                w.flush().await.map_err(new_std_io_error)?;
+
+                match meta {
+                    Some(meta) => checksum.verify(&meta, &path),
+                    None => Ok(checksum.size()),
+                }
             },
         )
     }
 
-    /// Delete an infallible iterator of paths.
+    /// Write all data from the given reader into path, returns the size of written data.
     ///
-    /// Also see:
+    /// # Notes
     ///
-    /// - [`Operator::delete_try_iter`]: delete an fallible iterator of paths.
-    /// - [`Operator::delete_stream`]: delete an infallible stream of paths.
-    /// - [`Operator::delete_try_stream`]: delete an fallible stream of paths.
-    pub async fn delete_iter<I, D>(&self, iter: I) -> Result<()>
-    where
-        I: IntoIterator<Item = D>,
-        D: IntoDeleteInput,
-    {
-        let mut deleter = self.deleter().await?;
-        deleter.delete_iter(iter).await?;
-        deleter.close().await?;
-        Ok(())
-    }
-
-    /// Delete a fallible iterator of paths.
+    /// [`Operator::write_from_reader`] is a simplified version of [`Operator::write_from_reader_with`]
+    /// without additional options.
     ///
-    /// Also see:
+    /// Data is streamed in chunks, so the whole input won't be loaded into memory.
+    /// The file will only be committed after all data is read from the reader.
     ///
-    /// - [`Operator::delete_iter`]: delete an infallible iterator of paths.
-    /// - [`Operator::delete_stream`]: delete an infallible stream of paths.
-    /// - [`Operator::delete_try_stream`]: delete an fallible stream of paths.
-    pub async fn delete_try_iter<I, D>(&self, try_iter: I) -> Result<()>
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let r = futures::io::Cursor::new(vec![0; 4096]);
+    /// let n = op.write_from_reader("path/to/file", r).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_from_reader<R>(&self, path: &str, r: R) -> Result<u64>
     where
-        I: IntoIterator<Item = Result<D>>,
-        D: IntoDeleteInput,
+        R: AsyncRead + Unpin,
     {
-        let mut deleter = self.deleter().await?;
-        deleter.delete_try_iter(try_iter).await?;
-        deleter.close().await?;
-        Ok(())
+        self.write_from_reader_with(path, r).await
     }
 
-    /// Delete an infallible stream of paths.
+    /// Write all data from the given reader into path with extra options.
     ///
-    /// Also see:
+    /// # Options
     ///
-    /// - [`Operator::delete_iter`]: delete an infallible iterator of paths.
-    /// - [`Operator::delete_try_iter`]: delete an fallible iterator of paths.
-    /// - [`Operator::delete_try_stream`]: delete an fallible stream of paths.
-    pub async fn delete_stream<S, D>(&self, stream: S) -> Result<()>
-    where
-        S: Stream<Item = D>,
-        D: IntoDeleteInput,
-    {
-        let mut deleter = self.deleter().await?;
-        deleter.delete_stream(stream).await?;
-        deleter.close().await?;
-        Ok(())
-    }
-
-    /// Delete an fallible stream of paths.
+    /// Visit [`FutureWriteFromReader`] for all available options.
     ///
-    /// Also see:
+    /// - [`buffer_size`](./operator_futures/type.FutureWriteFromReader.html#method.buffer_size): Sets the size of each chunk read from the reader.
+    /// - [`verify_checksum`](./operator_futures/type.FutureWriteFromReader.html#method.verify_checksum): Verifies the written data against the content length and content md5.
+    /// - [`chunk`](./operator_futures/type.FutureWriteFromReader.html#method.chunk): Sets chunk size of the underlying writer.
+    /// - [`concurrent`](./operator_futures/type.FutureWriteFromReader.html#method.concurrent): Sets concurrent of the underlying writer.
+    /// - [`content_type`](./operator_futures/type.FutureWriteFromReader.html#method.content_type): Sets content type of the written file.
     ///
-    /// - [`Operator::delete_iter`]: delete an infallible iterator of paths.
-    /// - [`Operator::delete_try_iter`]: delete an fallible iterator of paths.
-    /// - [`Operator::delete_stream`]: delete an infallible stream of paths.
-    pub async fn delete_try_stream<S, D>(&self, try_stream: S) -> Result<()>
-    where
-        S: Stream<Item = Result<D>>,
-        D: IntoDeleteInput,
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let r = futures::io::Cursor::new(vec![0; 4096]);
+    /// let n = op
+    ///     .write_from_reader_with("path/to/file", r)
+    ///     .chunk(8 * 1024 * 1024)
+    ///     .concurrent(4)
+    ///     .verify_checksum(true)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_reader_with<R>(
+        &self,
+        path: &str,
+        r: R,
+    ) -> FutureWriteFromReader<R, impl Future<Output = Result<u64>>>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (
+                OpWrite::default().merge_executor(self.default_executor.clone()),
+                OpWriter::default(),
+                OpTransfer::default(),
+                r,
+            ),
+            |inner, path, (args, options, transfer, mut r)| async move {
+                if !validate_path(&path, EntryMode::FILE) {
+                    return Err(
+                        Error::new(ErrorKind::IsADirectory, "write path is a directory")
+                            .with_operation("Operator::write_from_reader")
+                            .with_context("service", inner.info().scheme().into_static())
+                            .with_context("path", &path),
+                    );
+                }
+
+                let context = WriteContext::new(inner.clone(), path.clone(), args, options);
+                let mut w = Writer::new(context).await?;
+
+                let mut checksum = Checksum::new(transfer.verify_checksum());
+                let mut buf = vec![0; transfer.buffer_size().unwrap_or(DEFAULT_BUFFER_SIZE)];
+                loop {
+                    let n = match r.read(&mut buf).await {
+                        Ok(0) => break,
+                        Ok(n) => n,
+                        Err(err) => {
+                            // Ignore the abort error since not all services support it.
+                            let _ = w.abort().await;
+                            return Err(
+                                new_std_io_error(err).with_operation("Operator::write_from_reader")
+                            );
+                        }
+                    };
+                    checksum.update(&buf[..n]);
+                    w.write(Bytes::copy_from_slice(&buf[..n])).await?;
+                }
+                w.close().await?;
+
+                if transfer.verify_checksum() {
+                    let meta = inner.stat(&path, OpStat::new()).await?.into_metadata();
+                    checksum.verify(&meta, &path)
+                } else {
+                    Ok(checksum.size())
+                }
+            },
+        )
+    }
+
+    /// Copy the file at `from` into `to` of the target operator, returns the size of copied data.
+    ///
+    /// # Notes
+    ///
+    /// Unlike [`Operator::copy`] which relies on the server side copy of service, data
+    /// is streamed through the client. So it works across different operators and services.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator, target: Operator) -> Result<()> {
+    /// let n = op.copy_to("path/to/file", &target, "path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_to(&self, from: &str, target: &Operator, to: &str) -> Result<u64> {
+        self.copy_to_with(from, target, to).await
+    }
+
+    /// Copy the file at `from` into `to` of the target operator with extra options.
+    ///
+    /// # Options
+    ///
+    /// Visit [`FutureCopyTo`] for all available options.
+    ///
+    /// - [`buffer_size`](./operator_futures/type.FutureCopyTo.html#method.buffer_size): Sets the size of each chunk fetched from the source.
+    /// - [`verify_checksum`](./operator_futures/type.FutureCopyTo.html#method.verify_checksum): Verifies the data against the content length and content md5 of the source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator, target: Operator) -> Result<()> {
+    /// let n = op
+    ///     .copy_to_with("path/to/file", &target, "path/to/file")
+    ///     .verify_checksum(true)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_to_with(
+        &self,
+        from: &str,
+        target: &Operator,
+        to: &str,
+    ) -> FutureCopyTo<impl Future<Output = Result<u64>>> {
+        let from = normalize_path(from);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            from,
+            (OpTransfer::default(), target.clone(), normalize_path(to)),
+            |inner, from, (options, target, to)| async move {
+                let (mut s, meta) = open_source(inner, from.clone(), OpRead::default(), &options)
+                    .await
+                    .map_err(|err| err.with_operation("Operator::copy_to"))?;
+
+                let mut w = target.writer(&to).await?;
+                let mut checksum = Checksum::new(options.verify_checksum());
+                while let Some(bs) = s.try_next().await.map_err(new_std_io_error)? {
+                    checksum.update(&bs);
+                    w.write(bs).await?;
+                }
+                w.close().await?;
+
+                match meta {
+                    Some(meta) => checksum.verify(&meta, &from),
+                    None => Ok(checksum.size()),
+                }
+            },
+        )
+    }
+
+    /// Copy all files under dir `from` into dir `to` of the target operator
+    /// recursively, returns the total size of copied data.
+    ///
+    /// # Notes
+    ///
+    /// - Files are copied via [`Operator::copy_to`] with at most [`Operator::limit`]
+    ///   concurrent tasks, all pending tasks will be cancelled once an error is met.
+    /// - Dirs are only created if the target service supports `create_dir`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator, target: Operator) -> Result<()> {
+    /// let n = op
+    ///     .with_limit(8)
+    ///     .copy_all_to("path/to/dir/", &target, "path/to/dir/")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_all_to(&self, from: &str, target: &Operator, to: &str) -> Result<u64> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+        if !from.ends_with('/') || !to.ends_with('/') {
+            return Err(
+                Error::new(ErrorKind::NotADirectory, "copy_all_to path must be a dir")
+                    .with_operation("Operator::copy_all_to")
+                    .with_context("from", &from)
+                    .with_context("to", &to),
+            );
+        }
+
+        // Root is normalized as `/` while listed paths don't start with `/`.
+        let target_path = |path: &str| -> Option<String> {
+            let rel = if from == "/" {
+                path
+            } else {
+                path.strip_prefix(from.as_str())?
+            };
+            if rel.is_empty() {
+                return None;
+            }
+            Some(if to == "/" {
+                rel.to_string()
+            } else {
+                format!("{to}{rel}")
+            })
+        };
+
+        let create_dir = target.info().full_capability().create_dir;
+        let executor = self.default_executor.clone().unwrap_or_default();
+        let mut tasks = ConcurrentTasks::new(
+            executor,
+            self.limit,
+            |(op, target, from, to): (Operator, Operator, String, String)| {
+                Box::pin(async move {
+                    let res = op.copy_to(&from, &target, &to).await;
+                    ((op, target, from, to), res)
+                })
+            },
+        );
+
+        let mut size = 0;
+        let mut lister = self.lister_with(&from).recursive(true).await?;
+        while let Some(entry) = lister.try_next().await? {
+            let Some(path) = target_path(entry.path()) else {
+                continue;
+            };
+            if entry.metadata().is_dir() {
+                if create_dir {
+                    target.create_dir(&path).await?;
+                }
+                continue;
+            }
+
+            tasks
+                .execute((self.clone(), target.clone(), entry.path().to_string(), path))
+                .await?;
+            // Drain finished results so that they won't pile up.
+            while let Some(n) = tasks.try_next() {
+                size += n;
+            }
+        }
+        while let Some(n) = tasks.next().await {
+            size += n?;
+        }
+
+        Ok(size)
+    }
+
+    /// Delete the given path.
+    ///
+    /// # Notes
+    ///
+    /// - Deleting a file that does not exist won't return errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use futures::io;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.delete("test").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, path: &str) -> Result<()> {
+        self.delete_with(path).await
+    }
+
+    /// Delete the given path with extra options.
+    ///
+    /// # Notes
+    ///
+    /// - Deleting a file that does not exist won't return errors.
+    ///
+    /// # Options
+    ///
+    /// ## `version`
+    ///
+    /// Set `version` for this `delete` request.
+    ///
+    /// remove a specific version of the given path.
+    ///
+    /// If the version doesn't exist, OpenDAL will not return errors.
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator, version: &str) -> Result<()> {
+    /// op.delete_with("path/to/file").version(version).await?;
+    /// # Ok(())
+    /// # }
+    ///```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use futures::io;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.delete_with("test").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_with(&self, path: &str) -> FutureDelete<impl Future<Output = Result<()>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpDelete::default(),
+            |inner, path, args| async move {
+                let (_, mut deleter) = inner.delete_dyn().await?;
+                deleter.delete_dyn(&path, args)?;
+                deleter.flush_dyn().await?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Delete an infallible iterator of paths.
+    ///
+    /// Also see:
+    ///
+    /// - [`Operator::delete_try_iter`]: delete an fallible iterator of paths.
+    /// - [`Operator::delete_stream`]: delete an infallible stream of paths.
+    /// - [`Operator::delete_try_stream`]: delete an fallible stream of paths.
+    pub async fn delete_iter<I, D>(&self, iter: I) -> Result<()>
+    where
+        I: IntoIterator<Item = D>,
+        D: IntoDeleteInput,
+    {
+        let mut deleter = self.deleter().await?;
+        deleter.delete_iter(iter).await?;
+        deleter.close().await?;
+        Ok(())
+    }
+
+    /// Delete a fallible iterator of paths.
+    ///
+    /// Also see:
+    ///
+    /// - [`Operator::delete_iter`]: delete an infallible iterator of paths.
+    /// - [`Operator::delete_stream`]: delete an infallible stream of paths.
+    /// - [`Operator::delete_try_stream`]: delete an fallible stream of paths.
+    pub async fn delete_try_iter<I, D>(&self, try_iter: I) -> Result<()>
+    where
+        I: IntoIterator<Item = Result<D>>,
+        D: IntoDeleteInput,
+    {
+        let mut deleter = self.deleter().await?;
+        deleter.delete_try_iter(try_iter).await?;
+        deleter.close().await?;
+        Ok(())
+    }
+
+    /// Delete an infallible stream of paths.
+    ///
+    /// Also see:
+    ///
+    /// - [`Operator::delete_iter`]: delete an infallible iterator of paths.
+    /// - [`Operator::delete_try_iter`]: delete an fallible iterator of paths.
+    /// - [`Operator::delete_try_stream`]: delete an fallible stream of paths.
+    pub async fn delete_stream<S, D>(&self, stream: S) -> Result<()>
+    where
+        S: Stream<Item = D>,
+        D: IntoDeleteInput,
+    {
+        let mut deleter = self.deleter().await?;
+        deleter.delete_stream(stream).await?;
+        deleter.close().await?;
+        Ok(())
+    }
+
+    /// Delete an fallible stream of paths.
+    ///
+    /// Also see:
+    ///
+    /// - [`Operator::delete_iter`]: delete an infallible iterator of paths.
+    /// - [`Operator::delete_try_iter`]: delete an fallible iterator of paths.
+    /// - [`Operator::delete_stream`]: delete an infallible stream of paths.
+    pub async fn delete_try_stream<S, D>(&self, try_stream: S) -> Result<()>
+    where
+        S: Stream<Item = Result<D>>,
+        D: IntoDeleteInput,
     {
         let mut deleter = self.deleter().await?;
         deleter.delete_try_stream(try_stream).await?;
@@ -1294,8 +2025,55 @@ impl Operator {
             Err(e) => return Err(e),
         };
 
-        let lister = self.lister_with(path).recursive(true).await?;
-        self.delete_try_stream(lister).await?;
+        // Use batch delete if possible, it's much cheaper than deleting concurrently.
+        let batch = self.info().full_capability().delete_max_size.unwrap_or(1) > 1;
+        if self.limit <= 1 || batch {
+            let lister = self.lister_with(path).recursive(true).await?;
+            self.delete_try_stream(lister).await?;
+            return Ok(());
+        }
+
+        self.remove_all_concurrently(path).await
+    }
+
+    /// Remove all files under path with `limit` concurrent tasks.
+    ///
+    /// Dirs are removed after all files, deepest first, so that services like
+    /// fs won't complain about deleting non-empty dirs.
+    async fn remove_all_concurrently(&self, path: &str) -> Result<()> {
+        let executor = self.default_executor.clone().unwrap_or_default();
+        let mut tasks =
+            ConcurrentTasks::new(executor, self.limit, |(op, path): (Operator, String)| {
+                Box::pin(async move {
+                    let res = op.delete(&path).await;
+                    ((op, path), res)
+                })
+            });
+
+        let mut dirs = Vec::new();
+        let mut lister = self.lister_with(path).recursive(true).await?;
+        while let Some(entry) = lister.try_next().await? {
+            if entry.metadata().is_dir() {
+                dirs.push(entry.path().to_string());
+                continue;
+            }
+
+            tasks
+                .execute((self.clone(), entry.path().to_string()))
+                .await?;
+            // Drain finished results so that they won't pile up.
+            while tasks.has_result() {
+                tasks.next().await.transpose()?;
+            }
+        }
+        while let Some(res) = tasks.next().await {
+            res?;
+        }
+
+        dirs.sort_unstable_by_key(|d| std::cmp::Reverse(d.len()));
+        for dir in dirs {
+            self.delete(&dir).await?;
+        }
         Ok(())
     }
 
@@ -1510,6 +2288,28 @@ impl Operator {
         )
     }
 
+    /// List entries in given path with typed [`ListOptions`].
+    ///
+    /// This is the same as [`Operator::list_with`] but takes all options at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::ListOptions;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut opts = ListOptions::default();
+    /// opts.recursive = true;
+    /// let entries = op.list_opts("path/to/dir/", opts).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_opts(&self, path: &str, opts: ListOptions) -> Result<Vec<Entry>> {
+        self.list_with(path).map(|args| opts.apply(args)).await
+    }
+
     /// List entries that starts with given `path` in parent dir.
     ///
     /// This function will create a new [`Lister`] to list entries. Users can stop
@@ -1610,39 +2410,268 @@ impl Operator {
     /// # }
     /// ```
     ///
-    /// # Examples
+    /// # Examples
+    ///
+    /// ## List all files recursively
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::EntryMode;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut lister = op.lister_with("path/to/dir/").recursive(true).await?;
+    /// while let Some(mut entry) = lister.try_next().await? {
+    ///     match entry.metadata().mode() {
+    ///         EntryMode::FILE => {
+    ///             println!("Handling file {}", entry.path())
+    ///         }
+    ///         EntryMode::DIR => {
+    ///             println!("Handling dir {}", entry.path())
+    ///         }
+    ///         EntryMode::Unknown => continue,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lister_with(&self, path: &str) -> FutureLister<impl Future<Output = Result<Lister>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpList::default(),
+            |inner, path, args| async move { Lister::create(inner, &path, args).await },
+        )
+    }
+
+    /// Create a lister with typed [`ListOptions`].
+    ///
+    /// This is the same as [`Operator::lister_with`] but takes all options at once.
+    pub async fn lister_opts(&self, path: &str, opts: ListOptions) -> Result<Lister> {
+        self.lister_with(path).map(|args| opts.apply(args)).await
+    }
+
+    /// Walk the directory tree under given path.
+    ///
+    /// Returns a [`Walker`] which implements `Stream<Item = Result<Entry>>`, users can
+    /// filter entries by glob patterns, limit the depth and list directories concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut walker = op
+    ///     .walk("path/to/dir/")
+    ///     .include("**/*.parquet")
+    ///     .exclude("path/to/dir/_tmp")
+    ///     .max_depth(3)
+    ///     .concurrent(8);
+    /// while let Some(entry) = walker.try_next().await? {
+    ///     println!("{}", entry.path());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn walk(&self, path: &str) -> Walker {
+        Walker::new(self.clone(), path)
+    }
+}
+
+/// Operator tag API.
+impl Operator {
+    /// Get all tags of the given object.
+    ///
+    /// Tags are key-value pairs attached to the object which can be changed
+    /// without rewriting the object's content.
+    ///
+    /// # Notes
+    ///
+    /// This operation requires [`Capability::tag`], returns [`ErrorKind::Unsupported`]
+    /// if services don't support it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let tags = op.tags("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tags(&self, path: &str) -> Result<HashMap<String, String>> {
+        let path = normalize_path(path);
+
+        let rp = self
+            .inner()
+            .tag(&path, OpTag::new(TagOperation::Get))
+            .await?;
+        Ok(rp.into_tags())
+    }
+
+    /// Replace all tags of the given object with `tags`.
+    ///
+    /// # Notes
+    ///
+    /// Existing tags that are not present in `tags` will be removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.set_tags(
+    ///     "path/to/file",
+    ///     HashMap::from([("project".to_string(), "opendal".to_string())]),
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_tags(&self, path: &str, tags: HashMap<String, String>) -> Result<()> {
+        let path = normalize_path(path);
+
+        self.inner()
+            .tag(&path, OpTag::new(TagOperation::Set(tags)))
+            .await?;
+        Ok(())
+    }
+
+    /// Remove all tags of the given object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.delete_tags("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_tags(&self, path: &str) -> Result<()> {
+        let path = normalize_path(path);
+
+        self.inner()
+            .tag(&path, OpTag::new(TagOperation::Delete))
+            .await?;
+        Ok(())
+    }
+}
+
+/// Operator concat API.
+impl Operator {
+    /// Concat the content of `sources` in order into the file at `path`.
+    ///
+    /// # Notes
+    ///
+    /// - `path` and all `sources` must be files.
+    /// - `path` will be overwritten if it exists, and it could be one of `sources`.
+    /// - `sources` will be kept as is after concat.
+    ///
+    /// This operation requires [`Capability::concat`], returns [`ErrorKind::Unsupported`]
+    /// if services don't support it. Use [`Operator::concat_with`] with `emulate` to
+    /// fallback to read and write for other services.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.concat("path/to/file", &["path/to/part-0", "path/to/part-1"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn concat(&self, path: &str, sources: &[&str]) -> Result<()> {
+        self.concat_with(path, sources).await
+    }
+
+    /// Concat the content of `sources` in order into the file at `path` with extra options.
+    ///
+    /// # Options
+    ///
+    /// ## `emulate`
     ///
-    /// ## List all files recursively
+    /// Set `emulate` to `true` to read all sources and write them into `path` if
+    /// services don't support concat natively. All data will be transferred through
+    /// the client in this case, and the old content of `path` will be held in memory
+    /// if `path` is one of `sources`.
+    ///
+    /// Emulation requires [`Capability::write_can_multi`] to stream sources into
+    /// `path`, returns [`ErrorKind::Unsupported`] otherwise.
+    ///
+    /// # Example
     ///
     /// ```
     /// # use anyhow::Result;
-    /// use futures::TryStreamExt;
-    /// use opendal::EntryMode;
     /// use opendal::Operator;
+    ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut lister = op.lister_with("path/to/dir/").recursive(true).await?;
-    /// while let Some(mut entry) = lister.try_next().await? {
-    ///     match entry.metadata().mode() {
-    ///         EntryMode::FILE => {
-    ///             println!("Handling file {}", entry.path())
-    ///         }
-    ///         EntryMode::DIR => {
-    ///             println!("Handling dir {}", entry.path())
-    ///         }
-    ///         EntryMode::Unknown => continue,
-    ///     }
-    /// }
+    /// op.concat_with("path/to/file", &["path/to/part-0", "path/to/part-1"])
+    ///     .emulate(true)
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn lister_with(&self, path: &str) -> FutureLister<impl Future<Output = Result<Lister>>> {
+    pub fn concat_with(
+        &self,
+        path: &str,
+        sources: &[&str],
+    ) -> FutureConcat<impl Future<Output = Result<()>>> {
         let path = normalize_path(path);
+        let sources = sources.iter().map(|v| normalize_path(v)).collect();
 
         OperatorFuture::new(
             self.inner().clone(),
             path,
-            OpList::default(),
-            |inner, path, args| async move { Lister::create(inner, &path, args).await },
+            OpConcat::new(sources),
+            |inner, path, args| async move {
+                if !validate_path(&path, EntryMode::FILE) {
+                    return Err(Error::new(ErrorKind::IsADirectory, "path is a directory")
+                        .with_operation("Operator::concat")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("path", path));
+                }
+
+                if args.sources().is_empty() {
+                    return Err(
+                        Error::new(ErrorKind::Unexpected, "sources must not be empty")
+                            .with_operation("Operator::concat")
+                            .with_context("service", inner.info().scheme())
+                            .with_context("path", path),
+                    );
+                }
+
+                if let Some(source) = args
+                    .sources()
+                    .iter()
+                    .find(|v| !validate_path(v, EntryMode::FILE))
+                {
+                    return Err(
+                        Error::new(ErrorKind::IsADirectory, "source path is a directory")
+                            .with_operation("Operator::concat")
+                            .with_context("service", inner.info().scheme())
+                            .with_context("source", source),
+                    );
+                }
+
+                inner.concat(&path, args).await?;
+                Ok(())
+            },
         )
     }
 }
@@ -1947,6 +2976,30 @@ impl Operator {
     ///     Ok(())
     /// }
     /// ```
+    ///
+    /// ## `headers`
+    ///
+    /// Include extra headers in the signed request. Clients must send the same headers
+    /// while using the presigned request.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use http::HeaderMap;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let mut headers = HeaderMap::new();
+    ///     headers.insert("x-amz-meta-owner", "opendal".parse()?);
+    ///     let signed_req = op
+    ///         .presign_write_with("test", Duration::from_secs(3600))
+    ///         .headers(headers)
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn presign_write_with(
         &self,
         path: &str,
@@ -1957,7 +3010,285 @@ impl Operator {
         OperatorFuture::new(
             self.inner().clone(),
             path,
-            (OpWrite::default(), expire),
+            (OpWrite::default(), expire, http::HeaderMap::new()),
+            |inner, path, (args, dur, headers)| async move {
+                let op = OpPresign::new(args, dur).with_headers(headers);
+                let rp = inner.presign(&path, op).await?;
+                Ok(rp.into_presigned_request())
+            },
+        )
+    }
+
+    /// Presign an operation to initiate a multipart upload.
+    ///
+    /// Together with [`Self::presign_upload_part`], clients could upload parts of a large file
+    /// directly to the storage in parallel without credentials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_initiate_multipart("test.txt", Duration::from_secs(3600))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `POST`
+    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/test.txt?uploads&X-Amz-Algorithm=AWS4-HMAC-SHA256&...`
+    ///
+    /// The upload id is returned in the response body of the signed request.
+    pub async fn presign_initiate_multipart(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        self.presign_initiate_multipart_with(path, expire).await
+    }
+
+    /// Presign an operation to initiate a multipart upload with extra options.
+    ///
+    /// Options like `content_type` will be applied to the file after the upload completed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_initiate_multipart_with("test.csv", Duration::from_secs(3600))
+    ///         .content_type("text/csv")
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn presign_initiate_multipart_with(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> FuturePresignWrite<impl Future<Output = Result<PresignedRequest>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (OpWrite::default(), expire, http::HeaderMap::new()),
+            |inner, path, (args, dur, headers)| async move {
+                let op = OpPresign::new(PresignOperation::InitiateMultipart(args), dur)
+                    .with_headers(headers);
+                let rp = inner.presign(&path, op).await?;
+                Ok(rp.into_presigned_request())
+            },
+        )
+    }
+
+    /// Presign an operation to upload a part of an on-going multipart upload.
+    ///
+    /// `upload_id` is returned by the request presigned by [`Self::presign_initiate_multipart`],
+    /// and `part_number` starts from 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_upload_part("test.txt", "upload_id", 1, Duration::from_secs(3600))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `PUT`
+    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/test.txt?partNumber=1&uploadId=upload_id&X-Amz-Algorithm=AWS4-HMAC-SHA256&...`
+    pub async fn presign_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        self.presign_upload_part_with(path, upload_id, part_number, expire)
+            .await
+    }
+
+    /// Presign an operation to upload a part of an on-going multipart upload with extra options.
+    ///
+    /// # Options
+    ///
+    /// ## `content_length`
+    ///
+    /// Sign the content length of this part, so that clients can only upload exactly this size.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_upload_part_with("test.txt", "upload_id", 1, Duration::from_secs(3600))
+    ///         .content_length(8 * 1024 * 1024)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn presign_upload_part_with(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        expire: Duration,
+    ) -> FuturePresignUploadPart<impl Future<Output = Result<PresignedRequest>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (
+                OpUploadPart::new(upload_id, part_number),
+                expire,
+                http::HeaderMap::new(),
+            ),
+            |inner, path, (args, dur, headers)| async move {
+                let op = OpPresign::new(args, dur).with_headers(headers);
+                let rp = inner.presign(&path, op).await?;
+                Ok(rp.into_presigned_request())
+            },
+        )
+    }
+
+    /// Presign an operation to complete an on-going multipart upload.
+    ///
+    /// Clients must send the uploaded parts in the body of the presigned request, like the
+    /// `CompleteMultipartUpload` xml document of S3 which lists the part numbers and etags
+    /// returned by the requests presigned by [`Self::presign_upload_part`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_complete_multipart("test.txt", "upload_id", Duration::from_secs(3600))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `POST`
+    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/test.txt?uploadId=upload_id&X-Amz-Algorithm=AWS4-HMAC-SHA256&...`
+    pub async fn presign_complete_multipart(
+        &self,
+        path: &str,
+        upload_id: &str,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpCompleteMultipart::new(upload_id), expire);
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign a POST policy for browser based uploads via HTML forms.
+    ///
+    /// The returned request contains form fields, which must be sent in a `multipart/form-data`
+    /// body before the `file` field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_post("test.txt", Duration::from_secs(3600))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `POST`
+    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/`
+    /// - `signed_req.form_fields()`: `[("key", "test.txt"), ("policy", "<base64-policy>"), ("x-amz-signature", "<signature-value>"), ...]`
+    ///
+    /// We can upload file via `curl` or HTML forms without credential:
+    ///
+    /// ```shell
+    /// curl -X POST "https://s3.amazonaws.com/examplebucket/" -F "key=test.txt" -F "policy=<base64-policy>" ... -F "file=@test.txt"
+    /// ```
+    pub async fn presign_post(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        self.presign_post_with(path, expire).await
+    }
+
+    /// Presign a POST policy with extra conditions.
+    ///
+    /// # Options
+    ///
+    /// ## `key_prefix`
+    ///
+    /// Treat the path as a key prefix, clients can upload to any key starting with it by
+    /// changing the `key` field. The `key` field is `<prefix>${filename}` by default.
+    ///
+    /// ## `content_type` and `content_type_prefix`
+    ///
+    /// Restrict the content type of uploads to exactly match the given value or start with
+    /// the given prefix.
+    ///
+    /// ## `content_length_range`
+    ///
+    /// Restrict the content length of uploads to be in the given inclusive range.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_post_with("avatars/", Duration::from_secs(3600))
+    ///         .key_prefix(true)
+    ///         .content_type_prefix("image/")
+    ///         .content_length_range(1, 10 * 1024 * 1024)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn presign_post_with(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> FuturePresignPost<impl Future<Output = Result<PresignedRequest>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (OpPresignPost::default(), expire),
             |inner, path, (args, dur)| async move {
                 let op = OpPresign::new(args, dur);
                 let rp = inner.presign(&path, op).await?;
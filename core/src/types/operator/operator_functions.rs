@@ -19,7 +19,12 @@
 //!
 //! By using functions, users can add more options for operation.
 
+use std::collections::HashMap;
 use std::ops::RangeBounds;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
 
 use crate::raw::*;
 use crate::*;
@@ -49,7 +54,7 @@ impl<T, R> OperatorFunction<T, R> {
         }
     }
 
-    fn map_args(self, f: impl FnOnce(T) -> T) -> Self {
+    pub(crate) fn map_args(self, f: impl FnOnce(T) -> T) -> Self {
         Self {
             inner: self.inner,
             path: self.path,
@@ -133,6 +138,75 @@ impl FunctionWrite {
         self
     }
 
+    /// Set the expire duration of option.
+    ///
+    /// Check [`Capability::write_with_expire_after`] before using this feature.
+    pub fn expire_after(mut self, v: Duration) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_expire_after(v), options, bs));
+        self
+    }
+
+    /// Set the permissions of option.
+    ///
+    /// Check [`Capability::write_with_permissions`] before using this feature.
+    pub fn permissions(mut self, v: u32) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_permissions(v), options, bs));
+        self
+    }
+
+    /// Set the content encoding of option
+    pub fn content_encoding(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_content_encoding(v), options, bs));
+        self
+    }
+
+    /// Set the If-Match for this operation.
+    ///
+    /// Check [`Capability::write_with_if_match`] before using this feature.
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_if_match(v), options, bs));
+        self
+    }
+
+    /// Set the If-None-Match for this operation.
+    ///
+    /// Check [`Capability::write_with_if_none_match`] before using this feature.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_if_none_match(v), options, bs));
+        self
+    }
+
+    /// Only write if the file doesn't exist.
+    ///
+    /// Check [`Capability::write_with_if_not_exists`] before using this feature.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_if_not_exists(v), options, bs));
+        self
+    }
+
+    /// Set the user defined metadata of option.
+    ///
+    /// Check [`Capability::write_with_user_metadata`] before using this feature.
+    pub fn user_metadata(mut self, data: impl IntoIterator<Item = (String, String)>) -> Self {
+        let data = HashMap::from_iter(data);
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_user_metadata(data), options, bs));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<()> {
@@ -216,6 +290,75 @@ impl FunctionWriter {
         self
     }
 
+    /// Set the expire duration of option.
+    ///
+    /// Check [`Capability::write_with_expire_after`] before using this feature.
+    pub fn expire_after(mut self, v: Duration) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_expire_after(v), options));
+        self
+    }
+
+    /// Set the permissions of option.
+    ///
+    /// Check [`Capability::write_with_permissions`] before using this feature.
+    pub fn permissions(mut self, v: u32) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_permissions(v), options));
+        self
+    }
+
+    /// Set the content encoding of option
+    pub fn content_encoding(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_content_encoding(v), options));
+        self
+    }
+
+    /// Set the If-Match for this operation.
+    ///
+    /// Check [`Capability::write_with_if_match`] before using this feature.
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_if_match(v), options));
+        self
+    }
+
+    /// Set the If-None-Match for this operation.
+    ///
+    /// Check [`Capability::write_with_if_none_match`] before using this feature.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_if_none_match(v), options));
+        self
+    }
+
+    /// Only write if the file doesn't exist.
+    ///
+    /// Check [`Capability::write_with_if_not_exists`] before using this feature.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_if_not_exists(v), options));
+        self
+    }
+
+    /// Set the user defined metadata of option.
+    ///
+    /// Check [`Capability::write_with_user_metadata`] before using this feature.
+    pub fn user_metadata(mut self, data: impl IntoIterator<Item = (String, String)>) -> Self {
+        let data = HashMap::from_iter(data);
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_user_metadata(data), options));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<BlockingWriter> {
@@ -223,6 +366,28 @@ impl FunctionWriter {
     }
 }
 
+/// Function that generated by [`BlockingOperator::create_dir_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FunctionCreateDir(pub(crate) OperatorFunction<OpCreateDir, ()>);
+
+impl FunctionCreateDir {
+    /// Create all missing parent dirs as well.
+    ///
+    /// This is useful for services that emulate dirs by marker objects, like s3,
+    /// whose parent dirs won't be created implicitly.
+    pub fn recursive(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_recursive(v));
+        self
+    }
+
+    /// Call the function to consume all the input and generate a
+    /// result.
+    pub fn call(self) -> Result<()> {
+        self.0.call()
+    }
+}
+
 /// Function that generated by [`BlockingOperator::delete_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
@@ -248,15 +413,29 @@ impl FunctionDelete {
 pub struct FunctionList(pub(crate) OperatorFunction<OpList, Vec<Entry>>);
 
 impl FunctionList {
-    /// The limit passed to underlying service to specify the max results
+    /// Set the page size passed to underlying service to specify the max results
     /// that could return per-request.
     ///
-    /// Users could use this to control the memory usage of list operation.
-    pub fn limit(mut self, v: usize) -> Self {
+    /// See [`FutureLister::chunk`](crate::operator_futures::FutureLister::chunk) for details.
+    pub fn chunk(mut self, v: usize) -> Self {
         self.0 = self.0.map_args(|args| args.with_limit(v));
         self
     }
 
+    /// Set the page size of list operation.
+    #[deprecated(note = "Please use `chunk` instead")]
+    pub fn limit(self, v: usize) -> Self {
+        self.chunk(v)
+    }
+
+    /// Set the hard limit of entries returned by this list operation.
+    ///
+    /// See [`FutureLister::max_entries`](crate::operator_futures::FutureLister::max_entries) for details.
+    pub fn max_entries(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_entries(v));
+        self
+    }
+
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     pub fn start_after(mut self, v: &str) -> Self {
@@ -275,6 +454,46 @@ impl FunctionList {
         self
     }
 
+    /// The delimiter is used to group entries by common prefixes.
+    ///
+    /// See [`FutureLister::delimiter`](crate::operator_futures::FutureLister::delimiter) for details.
+    pub fn delimiter(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_delimiter(v));
+        self
+    }
+
+    /// Only return entries whose path matches the given glob pattern.
+    ///
+    /// See [`FutureLister::glob`](crate::operator_futures::FutureLister::glob) for details.
+    pub fn glob(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_glob(v));
+        self
+    }
+
+    /// Controls whether the list operation should return file versions.
+    ///
+    /// See [`FutureLister::versions`](crate::operator_futures::FutureLister::versions) for details.
+    pub fn versions(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_versions(v));
+        self
+    }
+
+    /// Controls whether the list operation should include deleted files (or versions).
+    ///
+    /// See [`FutureLister::deleted`](crate::operator_futures::FutureLister::deleted) for details.
+    pub fn deleted(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_deleted(v));
+        self
+    }
+
+    /// Resume the list from the continuation token returned by a previous list.
+    ///
+    /// Only services that support `list_with_start_token` accept this option.
+    pub fn start_token(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_start_token(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<Vec<Entry>> {
@@ -288,15 +507,29 @@ impl FunctionList {
 pub struct FunctionLister(pub(crate) OperatorFunction<OpList, BlockingLister>);
 
 impl FunctionLister {
-    /// The limit passed to underlying service to specify the max results
+    /// Set the page size passed to underlying service to specify the max results
     /// that could return per-request.
     ///
-    /// Users could use this to control the memory usage of list operation.
-    pub fn limit(mut self, v: usize) -> Self {
+    /// See [`FutureLister::chunk`](crate::operator_futures::FutureLister::chunk) for details.
+    pub fn chunk(mut self, v: usize) -> Self {
         self.0 = self.0.map_args(|args| args.with_limit(v));
         self
     }
 
+    /// Set the page size of list operation.
+    #[deprecated(note = "Please use `chunk` instead")]
+    pub fn limit(self, v: usize) -> Self {
+        self.chunk(v)
+    }
+
+    /// Set the hard limit of entries returned by this list operation.
+    ///
+    /// See [`FutureLister::max_entries`](crate::operator_futures::FutureLister::max_entries) for details.
+    pub fn max_entries(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_entries(v));
+        self
+    }
+
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     pub fn start_after(mut self, v: &str) -> Self {
@@ -315,6 +548,46 @@ impl FunctionLister {
         self
     }
 
+    /// The delimiter is used to group entries by common prefixes.
+    ///
+    /// See [`FutureLister::delimiter`](crate::operator_futures::FutureLister::delimiter) for details.
+    pub fn delimiter(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_delimiter(v));
+        self
+    }
+
+    /// Only return entries whose path matches the given glob pattern.
+    ///
+    /// See [`FutureLister::glob`](crate::operator_futures::FutureLister::glob) for details.
+    pub fn glob(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_glob(v));
+        self
+    }
+
+    /// Controls whether the list operation should return file versions.
+    ///
+    /// See [`FutureLister::versions`](crate::operator_futures::FutureLister::versions) for details.
+    pub fn versions(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_versions(v));
+        self
+    }
+
+    /// Controls whether the list operation should include deleted files (or versions).
+    ///
+    /// See [`FutureLister::deleted`](crate::operator_futures::FutureLister::deleted) for details.
+    pub fn deleted(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_deleted(v));
+        self
+    }
+
+    /// Resume the list from the continuation token returned by a previous list.
+    ///
+    /// Only services that support `list_with_start_token` accept this option.
+    pub fn start_token(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_start_token(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<BlockingLister> {
@@ -334,6 +607,54 @@ impl FunctionRead {
         self
     }
 
+    /// Set the version for this operation.
+    pub fn version(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, range)| (args.with_version(v), range));
+        self
+    }
+
+    /// Set the If-Match for this operation.
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, range)| (args.with_if_match(v), range));
+        self
+    }
+
+    /// Set the If-None-Match for this operation.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, range)| (args.with_if_none_match(v), range));
+        self
+    }
+
+    /// Set the If-Modified-Since for this operation.
+    pub fn if_modified_since(mut self, v: DateTime<Utc>) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, range)| (args.with_if_modified_since(v), range));
+        self
+    }
+
+    /// Set the If-Unmodified-Since for this operation.
+    pub fn if_unmodified_since(mut self, v: DateTime<Utc>) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, range)| (args.with_if_unmodified_since(v), range));
+        self
+    }
+
+    /// Set the base64 encoded customer key (SSE-C) for this operation.
+    pub fn server_side_encryption_customer_key(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, range)| (args.with_server_side_encryption_customer_key(v), range));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<Buffer> {
@@ -389,6 +710,26 @@ impl FunctionReader {
         self
     }
 
+    /// Set the If-Modified-Since for this operation.
+    pub fn if_modified_since(mut self, v: DateTime<Utc>) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_modified_since(v));
+        self
+    }
+
+    /// Set the If-Unmodified-Since for this operation.
+    pub fn if_unmodified_since(mut self, v: DateTime<Utc>) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_unmodified_since(v));
+        self
+    }
+
+    /// Set the base64 encoded customer key (SSE-C) for this operation.
+    pub fn server_side_encryption_customer_key(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_server_side_encryption_customer_key(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<BlockingReader> {
@@ -420,6 +761,26 @@ impl FunctionStat {
         self
     }
 
+    /// Set the If-Modified-Since for this operation.
+    pub fn if_modified_since(mut self, v: DateTime<Utc>) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_modified_since(v));
+        self
+    }
+
+    /// Set the If-Unmodified-Since for this operation.
+    pub fn if_unmodified_since(mut self, v: DateTime<Utc>) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_unmodified_since(v));
+        self
+    }
+
+    /// Set the base64 encoded customer key (SSE-C) for this operation.
+    pub fn server_side_encryption_customer_key(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_server_side_encryption_customer_key(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<Metadata> {
@@ -70,7 +70,7 @@ impl<I, O, F: Future<Output = Result<O>>> OperatorFuture<I, O, F> {
 
 impl<I, O, F: Future<Output = Result<O>>> OperatorFuture<I, O, F> {
     /// Change the operation's args.
-    fn map(mut self, f: impl FnOnce(I) -> I) -> Self {
+    pub(crate) fn map(mut self, f: impl FnOnce(I) -> I) -> Self {
         self.args = f(self.args);
         self
     }
@@ -118,6 +118,51 @@ impl<F: Future<Output = Result<Metadata>>> FutureStat<F> {
     pub fn version(self, v: &str) -> Self {
         self.map(|args| args.with_version(v))
     }
+
+    /// Set the base64 encoded customer key (SSE-C) for this operation.
+    pub fn server_side_encryption_customer_key(self, v: &str) -> Self {
+        self.map(|args| args.with_server_side_encryption_customer_key(v))
+    }
+}
+
+/// Future that generated by [`Operator::create_dir_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureCreateDir<F> = OperatorFuture<OpCreateDir, (), F>;
+
+impl<F: Future<Output = Result<()>>> FutureCreateDir<F> {
+    /// Create all missing parent dirs as well.
+    ///
+    /// This is useful for services that emulate dirs by marker objects, like s3,
+    /// whose parent dirs won't be created implicitly.
+    pub fn recursive(self, v: bool) -> Self {
+        self.map(|args| args.with_recursive(v))
+    }
+}
+
+/// Future that generated by [`Operator::concat_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureConcat<F> = OperatorFuture<OpConcat, (), F>;
+
+impl<F: Future<Output = Result<()>>> FutureConcat<F> {
+    /// Emulate concat by reading sources and writing them back if services
+    /// don't support concat natively.
+    pub fn emulate(self, v: bool) -> Self {
+        self.map(|args| args.with_emulate(v))
+    }
+}
+
+/// Future that generated by [`Operator::diagnose_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureDiagnose<F> = OperatorFuture<bool, Diagnosis, F>;
+
+impl<F: Future<Output = Result<Diagnosis>>> FutureDiagnose<F> {
+    /// Write a tiny file under root and delete it to check write permissions.
+    pub fn write(self, v: bool) -> Self {
+        self.map(|_| v)
+    }
 }
 
 /// Future that generated by [`Operator::presign_stat_with`].
@@ -187,22 +232,79 @@ impl<F: Future<Output = Result<PresignedRequest>>> FuturePresignRead<F> {
 /// Future that generated by [`Operator::presign_write_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
-pub type FuturePresignWrite<F> = OperatorFuture<(OpWrite, Duration), PresignedRequest, F>;
+pub type FuturePresignWrite<F> =
+    OperatorFuture<(OpWrite, Duration, http::HeaderMap), PresignedRequest, F>;
 
 impl<F: Future<Output = Result<PresignedRequest>>> FuturePresignWrite<F> {
     /// Set the content type of option
     pub fn content_type(self, v: &str) -> Self {
-        self.map(|(args, dur)| (args.with_content_type(v), dur))
+        self.map(|(args, dur, headers)| (args.with_content_type(v), dur, headers))
     }
 
     /// Set the content disposition of option
     pub fn content_disposition(self, v: &str) -> Self {
-        self.map(|(args, dur)| (args.with_content_disposition(v), dur))
+        self.map(|(args, dur, headers)| (args.with_content_disposition(v), dur, headers))
     }
 
     /// Set the content type of option
     pub fn cache_control(self, v: &str) -> Self {
-        self.map(|(args, dur)| (args.with_cache_control(v), dur))
+        self.map(|(args, dur, headers)| (args.with_cache_control(v), dur, headers))
+    }
+
+    /// Set the extra headers that should be included in the signed request.
+    ///
+    /// Clients must send the same headers while using the presigned request.
+    pub fn headers(self, v: http::HeaderMap) -> Self {
+        self.map(|(args, dur, _)| (args, dur, v))
+    }
+}
+
+/// Future that generated by [`Operator::presign_upload_part_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FuturePresignUploadPart<F> =
+    OperatorFuture<(OpUploadPart, Duration, http::HeaderMap), PresignedRequest, F>;
+
+impl<F: Future<Output = Result<PresignedRequest>>> FuturePresignUploadPart<F> {
+    /// Set the content length of this part.
+    ///
+    /// Clients can only upload a part of exactly this size with the presigned request.
+    pub fn content_length(self, v: u64) -> Self {
+        self.map(|(args, dur, headers)| (args.with_content_length(v), dur, headers))
+    }
+
+    /// Set the extra headers that should be included in the signed request.
+    ///
+    /// Clients must send the same headers while using the presigned request.
+    pub fn headers(self, v: http::HeaderMap) -> Self {
+        self.map(|(args, dur, _)| (args, dur, v))
+    }
+}
+
+/// Future that generated by [`Operator::presign_post_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FuturePresignPost<F> = OperatorFuture<(OpPresignPost, Duration), PresignedRequest, F>;
+
+impl<F: Future<Output = Result<PresignedRequest>>> FuturePresignPost<F> {
+    /// Treat the path as a key prefix, so that clients can upload to any key starting with it.
+    pub fn key_prefix(self, v: bool) -> Self {
+        self.map(|(args, dur)| (args.with_key_prefix(v), dur))
+    }
+
+    /// Set the content type that uploads must match exactly.
+    pub fn content_type(self, v: &str) -> Self {
+        self.map(|(args, dur)| (args.with_content_type(v), dur))
+    }
+
+    /// Set the prefix that the content type of uploads must start with, like `image/`.
+    pub fn content_type_prefix(self, v: &str) -> Self {
+        self.map(|(args, dur)| (args.with_content_type_prefix(v), dur))
+    }
+
+    /// Set the inclusive range that the content length of uploads must be in.
+    pub fn content_length_range(self, min: u64, max: u64) -> Self {
+        self.map(|(args, dur)| (args.with_content_length_range(min, max), dur))
     }
 }
 
@@ -277,6 +379,26 @@ impl<F: Future<Output = Result<Buffer>>> FutureRead<F> {
         self.map(|(args, op_reader)| (args, op_reader.with_chunk(chunk_size)))
     }
 
+    /// Set `resume_times` for the reader.
+    ///
+    /// If the read fails in the middle of the body with a temporary error (including errors
+    /// that `RetryLayer` has given up retrying), OpenDAL will reissue a ranged request from
+    /// the already delivered offset instead of returning the error, at most `resume_times`
+    /// times without progress. The resumed requests are pinned to the etag of the first
+    /// response if the service supports `read_with_if_match`.
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = op.read_with("path/to/file").resume_times(3).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resume_times(self, times: usize) -> Self {
+        self.map(|(args, op_reader)| (args, op_reader.with_resume_times(times)))
+    }
+
     /// Set `version` for this `read` request.
     ///
     /// This feature can be used to retrieve the data of a specified version of the given path.
@@ -377,6 +499,26 @@ impl<F: Future<Output = Result<Buffer>>> FutureRead<F> {
     pub fn if_unmodified_since(self, v: DateTime<Utc>) -> Self {
         self.map(|(args, op_reader)| (args.with_if_unmodified_since(v), op_reader))
     }
+
+    /// Set `server_side_encryption_customer_key` for this `read` request.
+    ///
+    /// The base64 encoded key must be the same one used while writing the file
+    /// with SSE-C, otherwise the service will reject the request.
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator, key: &str) -> Result<()> {
+    /// let bs = op
+    ///     .read_with("path/to/file")
+    ///     .server_side_encryption_customer_key(key)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn server_side_encryption_customer_key(self, v: &str) -> Self {
+        self.map(|(args, op_reader)| (args.with_server_side_encryption_customer_key(v), op_reader))
+    }
 }
 
 /// Future that generated by [`Operator::read_with`] or [`Operator::reader_with`].
@@ -481,6 +623,26 @@ impl<F: Future<Output = Result<Reader>>> FutureReader<F> {
         self.map(|(op_read, op_reader)| (op_read, op_reader.with_gap(gap_size)))
     }
 
+    /// Set `resume_times` for the reader.
+    ///
+    /// If the read fails in the middle of the body with a temporary error (including errors
+    /// that `RetryLayer` has given up retrying), OpenDAL will reissue a ranged request from
+    /// the already delivered offset instead of returning the error, at most `resume_times`
+    /// times without progress. The resumed requests are pinned to the etag of the first
+    /// response if the service supports `read_with_if_match`.
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let r = op.reader_with("path/to/file").resume_times(3).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resume_times(self, times: usize) -> Self {
+        self.map(|(op_read, op_reader)| (op_read, op_reader.with_resume_times(times)))
+    }
+
     /// Set `if-match` for this `read` request.
     ///
     /// This feature can be used to check if the file's `ETag` matches the given `ETag`.
@@ -560,6 +722,19 @@ impl<F: Future<Output = Result<Reader>>> FutureReader<F> {
     pub fn if_unmodified_since(self, v: DateTime<Utc>) -> Self {
         self.map(|(op_read, op_reader)| (op_read.with_if_unmodified_since(v), op_reader))
     }
+
+    /// Set `server_side_encryption_customer_key` for this `reader`.
+    ///
+    /// The base64 encoded key must be the same one used while writing the file
+    /// with SSE-C, otherwise the service will reject the request.
+    pub fn server_side_encryption_customer_key(self, v: &str) -> Self {
+        self.map(|(op_read, op_reader)| {
+            (
+                op_read.with_server_side_encryption_customer_key(v),
+                op_reader,
+            )
+        })
+    }
 }
 
 /// Future that generated by [`Operator::write_with`].
@@ -979,6 +1154,72 @@ impl<F: Future<Output = Result<()>>> FutureWrite<F> {
         self.map(|(args, options, bs)| (args.with_if_not_exists(b), options, bs))
     }
 
+    /// Sets the expire duration for this write request.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_expire_after`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the object will be removed by the service once the duration elapsed
+    /// - Services with expiry support (like `redis` and `memcached`) will use this value
+    ///   instead of their configured `default_ttl`
+    /// - The duration will be rounded up to the precision of service, like seconds for
+    ///   `memcached`, and a duration of zero will return an error
+    /// - If not supported, the write will return an `Unsupported` error
+    ///
+    /// This is useful for cache-like usage which doesn't want to clean up objects by hand.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("path/to/file", vec![0; 4096])
+    ///     .expire_after(Duration::from_secs(60))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expire_after(self, expire: Duration) -> Self {
+        self.map(|(args, options, bs)| (args.with_expire_after(expire), options, bs))
+    }
+
+    /// Sets the permissions for this write request.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_permissions`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the POSIX permission bits (like `0o600`) will be applied to the written file
+    /// - The bits are applied as is, without being masked by the process umask
+    /// - If not supported, the write will return an `Unsupported` error
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("path/to/file", vec![0; 4096])
+    ///     .permissions(0o600)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn permissions(self, permissions: u32) -> Self {
+        self.map(|(args, options, bs)| (args.with_permissions(permissions), options, bs))
+    }
+
     /// Sets user metadata for this write request.
     ///
     /// ### Capability
@@ -1029,6 +1270,73 @@ impl<F: Future<Output = Result<()>>> FutureWrite<F> {
             )
         })
     }
+
+    /// Sets server side encryption method for this write request.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_server_side_encryption`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - The value is service specific, for example `AES256` or `aws:kms` for s3.
+    /// - Overrides the server side encryption configured in the service builder.
+    /// - If not supported, will return an error
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("path/to/file", vec![0; 4096])
+    ///     .server_side_encryption("aws:kms")
+    ///     .server_side_encryption_aws_kms_key_id("my-key-id")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn server_side_encryption(self, v: &str) -> Self {
+        self.map(|(args, options, bs)| (args.with_server_side_encryption(v), options, bs))
+    }
+
+    /// Sets the kms key id used by server side encryption for this write request.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_server_side_encryption`] before using this feature.
+    pub fn server_side_encryption_aws_kms_key_id(self, v: &str) -> Self {
+        self.map(|(args, options, bs)| {
+            (
+                args.with_server_side_encryption_aws_kms_key_id(v),
+                options,
+                bs,
+            )
+        })
+    }
+
+    /// Sets the base64 encoded customer key (SSE-C) for this write request.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_server_side_encryption_customer_key`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - The same key must be provided while reading or stating the file later.
+    /// - Overrides the customer key configured in the service builder.
+    /// - If not supported, will return an error
+    pub fn server_side_encryption_customer_key(self, v: &str) -> Self {
+        self.map(|(args, options, bs)| {
+            (
+                args.with_server_side_encryption_customer_key(v),
+                options,
+                bs,
+            )
+        })
+    }
 }
 
 /// Future that generated by [`Operator::writer_with`].
@@ -1484,6 +1792,152 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
         self.map(|(args, options)| (args.with_if_not_exists(b), options))
     }
 
+    /// Sets the expire duration for this write request.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_expire_after`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the object will be removed by the service once the duration elapsed
+    /// - Services with expiry support (like `redis` and `memcached`) will use this value
+    ///   instead of their configured `default_ttl`
+    /// - The duration will be rounded up to the precision of service, like seconds for
+    ///   `memcached`, and a duration of zero will return an error
+    /// - If not supported, the write will return an `Unsupported` error
+    ///
+    /// This is useful for cache-like usage which doesn't want to clean up objects by hand.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("path/to/file")
+    ///     .expire_after(Duration::from_secs(60))
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expire_after(self, expire: Duration) -> Self {
+        self.map(|(args, options)| (args.with_expire_after(expire), options))
+    }
+
+    /// Sets the permissions for this write request.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_permissions`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the POSIX permission bits (like `0o600`) will be applied to the written file
+    /// - The bits are applied as is, without being masked by the process umask
+    /// - If not supported, the write will return an `Unsupported` error
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("path/to/file")
+    ///     .permissions(0o600)
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn permissions(self, permissions: u32) -> Self {
+        self.map(|(args, options)| (args.with_permissions(permissions), options))
+    }
+
+    /// Track the progress of the multipart upload so that it can be resumed later.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_resume`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - The progress can be fetched via [`Writer::upload_progress`] at any time
+    /// - The multipart upload won't be aborted if the writer is dropped without `close`
+    ///   or `abort`, users must resume or abort it by themselves
+    /// - If not supported, the writer will return an `Unsupported` error
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op.writer_with("path/to/file").resumable(true).await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// // Persist the progress somewhere.
+    /// let progress = w.upload_progress();
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resumable(self, v: bool) -> Self {
+        self.map(|(args, options)| {
+            let args = if v {
+                args.with_progress(oio::MultipartProgress::new())
+            } else {
+                args
+            };
+            (args, options)
+        })
+    }
+
+    /// Resume the multipart upload from the given progress.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_resume`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - The finished parts in progress won't be uploaded again, data must be written
+    ///   from [`UploadProgress::size`]
+    /// - The writer is [`resumable`](Self::resumable) as well
+    /// - If not supported, the writer will return an `Unsupported` error
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use opendal::UploadProgress;
+    ///
+    /// # async fn test(op: Operator, progress: UploadProgress, data: Vec<u8>) -> Result<()> {
+    /// let offset = progress.size as usize;
+    /// let mut w = op.writer_with("path/to/file").resume(progress).await?;
+    /// w.write(data[offset..].to_vec()).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resume(self, progress: UploadProgress) -> Self {
+        self.map(|(args, options)| {
+            (
+                args.with_progress(oio::MultipartProgress::resume(progress)),
+                options,
+            )
+        })
+    }
+
     /// Sets user metadata for this write request.
     ///
     /// ### Capability
@@ -1530,6 +1984,33 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
     pub fn user_metadata(self, data: impl IntoIterator<Item = (String, String)>) -> Self {
         self.map(|(args, options)| (args.with_user_metadata(HashMap::from_iter(data)), options))
     }
+
+    /// Sets server side encryption method for this writer.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_server_side_encryption`] before using this feature.
+    pub fn server_side_encryption(self, v: &str) -> Self {
+        self.map(|(args, options)| (args.with_server_side_encryption(v), options))
+    }
+
+    /// Sets the kms key id used by server side encryption for this writer.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_server_side_encryption`] before using this feature.
+    pub fn server_side_encryption_aws_kms_key_id(self, v: &str) -> Self {
+        self.map(|(args, options)| (args.with_server_side_encryption_aws_kms_key_id(v), options))
+    }
+
+    /// Sets the base64 encoded customer key (SSE-C) for this writer.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_server_side_encryption_customer_key`] before using this feature.
+    pub fn server_side_encryption_customer_key(self, v: &str) -> Self {
+        self.map(|(args, options)| (args.with_server_side_encryption_customer_key(v), options))
+    }
 }
 
 /// Future that generated by [`Operator::delete_with`].
@@ -1555,14 +2036,30 @@ pub type FutureDeleter<F> = OperatorFuture<OpDeleter, (), F>;
 pub type FutureList<F> = OperatorFuture<OpList, Vec<Entry>, F>;
 
 impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
-    /// The limit passed to underlying service to specify the max results
-    /// that could return per-request.
+    /// Set the page size passed to underlying service to specify the max results
+    /// that could return per-request, like `max-keys` of s3 or `maxResults` of gcs.
     ///
-    /// Users could use this to control the memory usage of list operation.
-    pub fn limit(self, v: usize) -> Self {
+    /// Users could use this to tune the throughput and memory usage of list operation.
+    /// Services could have their own limitation for page size, so it's possible that
+    /// less entries are returned per-request.
+    pub fn chunk(self, v: usize) -> Self {
         self.map(|args| args.with_limit(v))
     }
 
+    /// Set the page size of list operation.
+    #[deprecated(note = "Please use `chunk` instead")]
+    pub fn limit(self, v: usize) -> Self {
+        self.chunk(v)
+    }
+
+    /// Set the hard limit of entries returned by this list operation.
+    ///
+    /// The list will stop after `v` entries have been returned, and the page size
+    /// will be capped to `v` to avoid fetching entries that won't be returned.
+    pub fn max_entries(self, v: usize) -> Self {
+        self.map(|args| args.with_max_entries(v))
+    }
+
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     pub fn start_after(self, v: &str) -> Self {
@@ -1579,6 +2076,19 @@ impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
         self.map(|args| args.with_recursive(v))
     }
 
+    /// The delimiter is used to group entries by common prefixes.
+    ///
+    /// - `""` is the same as `recursive(true)`.
+    /// - `"/"` is the same as `recursive(false)`.
+    /// - Other delimiters will be pushed down to services that support
+    ///   `list_with_delimiter`, and the common prefixes will be returned as
+    ///   entries with [`EntryMode::Unknown`] unless they end with `/`.
+    ///
+    /// Default to `"/"`.
+    pub fn delimiter(self, v: &str) -> Self {
+        self.map(|args| args.with_delimiter(v))
+    }
+
     /// The version is used to control whether the object versions should be returned.
     ///
     /// - If `false`, list operation will not return with object versions
@@ -1615,6 +2125,34 @@ impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
     pub fn deleted(self, v: bool) -> Self {
         self.map(|args| args.with_deleted(v))
     }
+
+    /// Only return entries whose path matches the given glob pattern.
+    ///
+    /// The pattern is matched against the full path of entry without the trailing `/`:
+    ///
+    /// - `?` matches any single character except `/`.
+    /// - `*` matches any sequence of characters except `/`.
+    /// - `**` as a whole path segment matches any sequence of characters, `**/` matches
+    ///   zero or more directories.
+    ///
+    /// `[...]`, `{a,b}` and `**` inside a path segment like `a**b` are not supported and
+    /// will return an `Unsupported` error.
+    ///
+    /// For example, use `dir/*.parquet` to filter by suffix.
+    ///
+    /// The filter will be pushed down to services that support `list_with_glob`,
+    /// and applied on the client side for others.
+    pub fn glob(self, v: &str) -> Self {
+        self.map(|args| args.with_glob(v))
+    }
+
+    /// Resume the list from the continuation token returned by [`Lister::next_page`].
+    ///
+    /// Only services that support `list_with_start_token` accept this option,
+    /// others will return an `Unsupported` error instead of listing from the start.
+    pub fn start_token(self, v: &str) -> Self {
+        self.map(|args| args.with_start_token(v))
+    }
 }
 
 /// Future that generated by [`Operator::list_with`] or [`Operator::lister_with`].
@@ -1623,14 +2161,30 @@ impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
 pub type FutureLister<F> = OperatorFuture<OpList, Lister, F>;
 
 impl<F: Future<Output = Result<Lister>>> FutureLister<F> {
-    /// The limit passed to underlying service to specify the max results
-    /// that could return per-request.
+    /// Set the page size passed to underlying service to specify the max results
+    /// that could return per-request, like `max-keys` of s3 or `maxResults` of gcs.
     ///
-    /// Users could use this to control the memory usage of list operation.
-    pub fn limit(self, v: usize) -> Self {
+    /// Users could use this to tune the throughput and memory usage of list operation.
+    /// Services could have their own limitation for page size, so it's possible that
+    /// less entries are returned per-request.
+    pub fn chunk(self, v: usize) -> Self {
         self.map(|args| args.with_limit(v))
     }
 
+    /// Set the page size of list operation.
+    #[deprecated(note = "Please use `chunk` instead")]
+    pub fn limit(self, v: usize) -> Self {
+        self.chunk(v)
+    }
+
+    /// Set the hard limit of entries returned by this list operation.
+    ///
+    /// The list will stop after `v` entries have been returned, and the page size
+    /// will be capped to `v` to avoid fetching entries that won't be returned.
+    pub fn max_entries(self, v: usize) -> Self {
+        self.map(|args| args.with_max_entries(v))
+    }
+
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     pub fn start_after(self, v: &str) -> Self {
@@ -1647,6 +2201,19 @@ impl<F: Future<Output = Result<Lister>>> FutureLister<F> {
         self.map(|args| args.with_recursive(v))
     }
 
+    /// The delimiter is used to group entries by common prefixes.
+    ///
+    /// - `""` is the same as `recursive(true)`.
+    /// - `"/"` is the same as `recursive(false)`.
+    /// - Other delimiters will be pushed down to services that support
+    ///   `list_with_delimiter`, and the common prefixes will be returned as
+    ///   entries with [`EntryMode::Unknown`] unless they end with `/`.
+    ///
+    /// Default to `"/"`.
+    pub fn delimiter(self, v: &str) -> Self {
+        self.map(|args| args.with_delimiter(v))
+    }
+
     /// The version is used to control whether the object versions should be returned.
     ///
     /// - If `false`, list operation will not return with object versions
@@ -1683,4 +2250,120 @@ impl<F: Future<Output = Result<Lister>>> FutureLister<F> {
     pub fn deleted(self, v: bool) -> Self {
         self.map(|args| args.with_deleted(v))
     }
+
+    /// Only return entries whose path matches the given glob pattern.
+    ///
+    /// The pattern is matched against the full path of entry without the trailing `/`:
+    ///
+    /// - `?` matches any single character except `/`.
+    /// - `*` matches any sequence of characters except `/`.
+    /// - `**` as a whole path segment matches any sequence of characters, `**/` matches
+    ///   zero or more directories.
+    ///
+    /// `[...]`, `{a,b}` and `**` inside a path segment like `a**b` are not supported and
+    /// will return an `Unsupported` error.
+    ///
+    /// For example, use `dir/*.parquet` to filter by suffix.
+    ///
+    /// The filter will be pushed down to services that support `list_with_glob`,
+    /// and applied on the client side for others.
+    pub fn glob(self, v: &str) -> Self {
+        self.map(|args| args.with_glob(v))
+    }
+
+    /// Resume the list from the continuation token returned by [`Lister::next_page`].
+    ///
+    /// Only services that support `list_with_start_token` accept this option,
+    /// others will return an `Unsupported` error instead of listing from the start.
+    pub fn start_token(self, v: &str) -> Self {
+        self.map(|args| args.with_start_token(v))
+    }
+}
+
+/// Future that generated by [`Operator::read_to_writer_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureReadToWriter<W, F> = OperatorFuture<(OpRead, OpTransfer, W), u64, F>;
+
+impl<W, F: Future<Output = Result<u64>>> FutureReadToWriter<W, F> {
+    /// Set the buffer size for this operation.
+    ///
+    /// Data will be fetched from service in chunks of this size, default to 256KiB.
+    pub fn buffer_size(self, v: usize) -> Self {
+        self.map(|(args, options, w)| (args, options.with_buffer_size(v), w))
+    }
+
+    /// Verify the transferred data against the content length and content md5
+    /// returned by `stat`.
+    ///
+    /// The read will be pinned to the stat result via `If-Match` if service returns etag.
+    pub fn verify_checksum(self, v: bool) -> Self {
+        self.map(|(args, options, w)| (args, options.with_verify_checksum(v), w))
+    }
+
+    /// Set the version for this operation.
+    pub fn version(self, v: &str) -> Self {
+        self.map(|(args, options, w)| (args.with_version(v), options, w))
+    }
+}
+
+/// Future that generated by [`Operator::write_from_reader_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureWriteFromReader<R, F> = OperatorFuture<(OpWrite, OpWriter, OpTransfer, R), u64, F>;
+
+impl<R, F: Future<Output = Result<u64>>> FutureWriteFromReader<R, F> {
+    /// Set the buffer size for this operation.
+    ///
+    /// Data will be read from the input in chunks of this size, default to 256KiB.
+    pub fn buffer_size(self, v: usize) -> Self {
+        self.map(|(args, options, transfer, r)| (args, options, transfer.with_buffer_size(v), r))
+    }
+
+    /// Verify the written data against the content length and content md5
+    /// returned by `stat` after the write is finished.
+    pub fn verify_checksum(self, v: bool) -> Self {
+        self.map(|(args, options, transfer, r)| {
+            (args, options, transfer.with_verify_checksum(v), r)
+        })
+    }
+
+    /// Set the chunk size of the underlying writer.
+    ///
+    /// Refer to [`FutureWriter::chunk`] for more information.
+    pub fn chunk(self, v: usize) -> Self {
+        self.map(|(args, options, transfer, r)| (args, options.with_chunk(v), transfer, r))
+    }
+
+    /// Set the concurrent of the underlying writer.
+    ///
+    /// Refer to [`FutureWriter::concurrent`] for more information.
+    pub fn concurrent(self, v: usize) -> Self {
+        self.map(|(args, options, transfer, r)| (args.with_concurrent(v), options, transfer, r))
+    }
+
+    /// Set the content type of the written file.
+    pub fn content_type(self, v: &str) -> Self {
+        self.map(|(args, options, transfer, r)| (args.with_content_type(v), options, transfer, r))
+    }
+}
+
+/// Future that generated by [`Operator::copy_to_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureCopyTo<F> = OperatorFuture<(OpTransfer, Operator, String), u64, F>;
+
+impl<F: Future<Output = Result<u64>>> FutureCopyTo<F> {
+    /// Set the buffer size for this operation.
+    ///
+    /// Data will be fetched from the source in chunks of this size, default to 256KiB.
+    pub fn buffer_size(self, v: usize) -> Self {
+        self.map(|(options, target, to)| (options.with_buffer_size(v), target, to))
+    }
+
+    /// Verify the transferred data against the content length and content md5
+    /// returned by `stat` of the source.
+    pub fn verify_checksum(self, v: bool) -> Self {
+        self.map(|(options, target, to)| (options.with_verify_checksum(v), target, to))
+    }
 }
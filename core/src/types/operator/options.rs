@@ -0,0 +1,580 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::raw::*;
+
+/// Options for [`Operator::read_opts`](crate::Operator::read_opts) and [`Operator::reader_opts`](crate::Operator::reader_opts).
+///
+/// All fields are optional, unset fields will keep the defaults of [`Operator`](crate::Operator).
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use opendal::Operator;
+/// use opendal::ReadOptions;
+///
+/// # async fn test(op: Operator) -> Result<()> {
+/// let opts = ReadOptions::default()
+///     .with_offset(1024)
+///     .with_size(4096)
+///     .with_concurrent(4);
+///
+/// let bs = op.read_opts("path/to/file", opts).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReadOptions {
+    /// The offset to start reading from, default to `0`.
+    pub offset: Option<u64>,
+    /// The size to read, default to read until the end.
+    pub size: Option<u64>,
+    /// The version of the file to read.
+    pub version: Option<String>,
+    /// Only read if the etag matches.
+    pub if_match: Option<String>,
+    /// Only read if the etag doesn't match.
+    pub if_none_match: Option<String>,
+    /// Only read if the file has been modified since the given time.
+    pub if_modified_since: Option<DateTime<Utc>>,
+    /// Only read if the file hasn't been modified since the given time.
+    pub if_unmodified_since: Option<DateTime<Utc>>,
+    /// The base64 encoded customer key (SSE-C) to read the file.
+    pub server_side_encryption_customer_key: Option<String>,
+    /// The number of concurrent read requests.
+    pub concurrent: Option<usize>,
+    /// The size of each read request.
+    pub chunk: Option<usize>,
+    /// The max gap to merge ranges while reading.
+    pub gap: Option<usize>,
+    /// The max times to resume reading from the delivered offset on temporary errors.
+    pub resume_times: Option<usize>,
+}
+
+impl ReadOptions {
+    /// Set the offset to start reading from, default to `0`.
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the size to read, default to read until the end.
+    pub fn with_size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the version of the file to read.
+    pub fn with_version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Only read if the etag matches.
+    pub fn with_if_match(mut self, if_match: &str) -> Self {
+        self.if_match = Some(if_match.to_string());
+        self
+    }
+
+    /// Only read if the etag doesn't match.
+    pub fn with_if_none_match(mut self, if_none_match: &str) -> Self {
+        self.if_none_match = Some(if_none_match.to_string());
+        self
+    }
+
+    /// Only read if the file has been modified since the given time.
+    pub fn with_if_modified_since(mut self, if_modified_since: DateTime<Utc>) -> Self {
+        self.if_modified_since = Some(if_modified_since);
+        self
+    }
+
+    /// Only read if the file hasn't been modified since the given time.
+    pub fn with_if_unmodified_since(mut self, if_unmodified_since: DateTime<Utc>) -> Self {
+        self.if_unmodified_since = Some(if_unmodified_since);
+        self
+    }
+
+    /// Set the base64 encoded customer key (SSE-C) to read the file.
+    pub fn with_server_side_encryption_customer_key(
+        mut self,
+        server_side_encryption_customer_key: &str,
+    ) -> Self {
+        self.server_side_encryption_customer_key =
+            Some(server_side_encryption_customer_key.to_string());
+        self
+    }
+
+    /// Set the number of concurrent read requests.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = Some(concurrent);
+        self
+    }
+
+    /// Set the size of each read request.
+    pub fn with_chunk(mut self, chunk: usize) -> Self {
+        self.chunk = Some(chunk);
+        self
+    }
+
+    /// Set the max gap to merge ranges while reading.
+    pub fn with_gap(mut self, gap: usize) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    /// Set the max times to resume reading from the delivered offset on temporary errors.
+    pub fn with_resume_times(mut self, resume_times: usize) -> Self {
+        self.resume_times = Some(resume_times);
+        self
+    }
+
+    /// Apply options on the args and reader options of read.
+    pub(crate) fn apply(self, mut args: OpRead, mut options: OpReader) -> (OpRead, OpReader) {
+        if self.offset.is_some() || self.size.is_some() {
+            args = args.with_range(BytesRange::new(self.offset.unwrap_or_default(), self.size));
+        }
+        if let Some(v) = &self.version {
+            args = args.with_version(v);
+        }
+        if let Some(v) = &self.if_match {
+            args = args.with_if_match(v);
+        }
+        if let Some(v) = &self.if_none_match {
+            args = args.with_if_none_match(v);
+        }
+        if let Some(v) = self.if_modified_since {
+            args = args.with_if_modified_since(v);
+        }
+        if let Some(v) = self.if_unmodified_since {
+            args = args.with_if_unmodified_since(v);
+        }
+        if let Some(v) = &self.server_side_encryption_customer_key {
+            args = args.with_server_side_encryption_customer_key(v);
+        }
+
+        if let Some(v) = self.concurrent {
+            options = options.with_concurrent(v);
+        }
+        if let Some(v) = self.chunk {
+            options = options.with_chunk(v);
+        }
+        if let Some(v) = self.gap {
+            options = options.with_gap(v);
+        }
+        if let Some(v) = self.resume_times {
+            options = options.with_resume_times(v);
+        }
+
+        (args, options)
+    }
+}
+
+/// Options for [`Operator::write_opts`](crate::Operator::write_opts) and [`Operator::writer_opts`](crate::Operator::writer_opts).
+///
+/// All fields are optional, unset fields will keep the defaults of [`Operator`](crate::Operator).
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use opendal::Operator;
+/// use opendal::WriteOptions;
+///
+/// # async fn test(op: Operator) -> Result<()> {
+/// let opts = WriteOptions::default()
+///     .with_content_type("text/plain")
+///     .with_if_not_exists(true);
+///
+/// op.write_opts("path/to/file", "hello, world!", opts).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct WriteOptions {
+    /// Append to the end of the file instead of overwriting it.
+    pub append: bool,
+    /// The size of each write request.
+    pub chunk: Option<usize>,
+    /// The number of concurrent write requests.
+    pub concurrent: Option<usize>,
+    /// The `Content-Type` of the file.
+    pub content_type: Option<String>,
+    /// The `Content-Disposition` of the file.
+    pub content_disposition: Option<String>,
+    /// The `Content-Encoding` of the file.
+    pub content_encoding: Option<String>,
+    /// The `Cache-Control` of the file.
+    pub cache_control: Option<String>,
+    /// Only write if the etag matches.
+    pub if_match: Option<String>,
+    /// Only write if the etag doesn't match.
+    pub if_none_match: Option<String>,
+    /// Only write if the file doesn't exist.
+    pub if_not_exists: bool,
+    /// The user defined metadata of the file.
+    pub user_metadata: Option<HashMap<String, String>>,
+    /// The duration after which the file will be expired.
+    pub expire_after: Option<Duration>,
+    /// The unix permissions of the file.
+    pub permissions: Option<u32>,
+    /// The server side encryption algorithm, like `aws:kms` or `AES256`.
+    pub server_side_encryption: Option<String>,
+    /// The kms key id of server side encryption.
+    pub server_side_encryption_aws_kms_key_id: Option<String>,
+    /// The base64 encoded customer key (SSE-C) to write the file.
+    pub server_side_encryption_customer_key: Option<String>,
+}
+
+impl WriteOptions {
+    /// Append to the end of the file instead of overwriting it.
+    pub fn with_append(mut self, append: bool) -> Self {
+        self.append = append;
+        self
+    }
+
+    /// Set the size of each write request.
+    pub fn with_chunk(mut self, chunk: usize) -> Self {
+        self.chunk = Some(chunk);
+        self
+    }
+
+    /// Set the number of concurrent write requests.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = Some(concurrent);
+        self
+    }
+
+    /// Set the `Content-Type` of the file.
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Set the `Content-Disposition` of the file.
+    pub fn with_content_disposition(mut self, content_disposition: &str) -> Self {
+        self.content_disposition = Some(content_disposition.to_string());
+        self
+    }
+
+    /// Set the `Content-Encoding` of the file.
+    pub fn with_content_encoding(mut self, content_encoding: &str) -> Self {
+        self.content_encoding = Some(content_encoding.to_string());
+        self
+    }
+
+    /// Set the `Cache-Control` of the file.
+    pub fn with_cache_control(mut self, cache_control: &str) -> Self {
+        self.cache_control = Some(cache_control.to_string());
+        self
+    }
+
+    /// Only write if the etag matches.
+    pub fn with_if_match(mut self, if_match: &str) -> Self {
+        self.if_match = Some(if_match.to_string());
+        self
+    }
+
+    /// Only write if the etag doesn't match.
+    pub fn with_if_none_match(mut self, if_none_match: &str) -> Self {
+        self.if_none_match = Some(if_none_match.to_string());
+        self
+    }
+
+    /// Only write if the file doesn't exist.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Set the user defined metadata of the file.
+    pub fn with_user_metadata(mut self, user_metadata: HashMap<String, String>) -> Self {
+        self.user_metadata = Some(user_metadata);
+        self
+    }
+
+    /// Set the duration after which the file will be expired.
+    pub fn with_expire_after(mut self, expire_after: Duration) -> Self {
+        self.expire_after = Some(expire_after);
+        self
+    }
+
+    /// Set the unix permissions of the file.
+    pub fn with_permissions(mut self, permissions: u32) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    /// Set the server side encryption algorithm, like `aws:kms` or `AES256`.
+    pub fn with_server_side_encryption(mut self, server_side_encryption: &str) -> Self {
+        self.server_side_encryption = Some(server_side_encryption.to_string());
+        self
+    }
+
+    /// Set the kms key id of server side encryption.
+    pub fn with_server_side_encryption_aws_kms_key_id(
+        mut self,
+        server_side_encryption_aws_kms_key_id: &str,
+    ) -> Self {
+        self.server_side_encryption_aws_kms_key_id =
+            Some(server_side_encryption_aws_kms_key_id.to_string());
+        self
+    }
+
+    /// Set the base64 encoded customer key (SSE-C) to write the file.
+    pub fn with_server_side_encryption_customer_key(
+        mut self,
+        server_side_encryption_customer_key: &str,
+    ) -> Self {
+        self.server_side_encryption_customer_key =
+            Some(server_side_encryption_customer_key.to_string());
+        self
+    }
+
+    /// Apply options on the args and writer options of write.
+    pub(crate) fn apply(self, mut args: OpWrite, mut options: OpWriter) -> (OpWrite, OpWriter) {
+        if self.append {
+            args = args.with_append(true);
+        }
+        if let Some(v) = self.chunk {
+            options = options.with_chunk(v);
+        }
+        if let Some(v) = self.concurrent {
+            args = args.with_concurrent(v);
+        }
+        if let Some(v) = &self.content_type {
+            args = args.with_content_type(v);
+        }
+        if let Some(v) = &self.content_disposition {
+            args = args.with_content_disposition(v);
+        }
+        if let Some(v) = &self.content_encoding {
+            args = args.with_content_encoding(v);
+        }
+        if let Some(v) = &self.cache_control {
+            args = args.with_cache_control(v);
+        }
+        if let Some(v) = &self.if_match {
+            args = args.with_if_match(v);
+        }
+        if let Some(v) = &self.if_none_match {
+            args = args.with_if_none_match(v);
+        }
+        if self.if_not_exists {
+            args = args.with_if_not_exists(true);
+        }
+        if let Some(v) = self.user_metadata {
+            args = args.with_user_metadata(v);
+        }
+        if let Some(v) = self.expire_after {
+            args = args.with_expire_after(v);
+        }
+        if let Some(v) = self.permissions {
+            args = args.with_permissions(v);
+        }
+        if let Some(v) = &self.server_side_encryption {
+            args = args.with_server_side_encryption(v);
+        }
+        if let Some(v) = &self.server_side_encryption_aws_kms_key_id {
+            args = args.with_server_side_encryption_aws_kms_key_id(v);
+        }
+        if let Some(v) = &self.server_side_encryption_customer_key {
+            args = args.with_server_side_encryption_customer_key(v);
+        }
+
+        (args, options)
+    }
+}
+
+/// Options for [`Operator::list_opts`](crate::Operator::list_opts) and [`Operator::lister_opts`](crate::Operator::lister_opts).
+///
+/// All fields are optional, unset fields will keep the defaults of [`Operator`](crate::Operator).
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use opendal::ListOptions;
+/// use opendal::Operator;
+///
+/// # async fn test(op: Operator) -> Result<()> {
+/// let opts = ListOptions::default()
+///     .with_recursive(true)
+///     .with_glob("path/to/dir/**/*.parquet");
+///
+/// let entries = op.list_opts("path/to/dir/", opts).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ListOptions {
+    /// The page size passed to underlying service.
+    pub chunk: Option<usize>,
+    /// The hard limit of entries returned by this list.
+    pub max_entries: Option<usize>,
+    /// The key to start listing after.
+    pub start_after: Option<String>,
+    /// List all entries that starts with given path recursively.
+    pub recursive: bool,
+    /// The delimiter to group entries by common prefixes.
+    pub delimiter: Option<String>,
+    /// Return all versions of files.
+    pub versions: bool,
+    /// Return deleted files (or versions).
+    pub deleted: bool,
+    /// Only return entries whose path matches the given glob pattern.
+    pub glob: Option<String>,
+    /// Resume the list from the continuation token returned by [`Lister::next_page`](crate::Lister::next_page).
+    pub start_token: Option<String>,
+}
+
+impl ListOptions {
+    /// Set the page size passed to underlying service.
+    pub fn with_chunk(mut self, chunk: usize) -> Self {
+        self.chunk = Some(chunk);
+        self
+    }
+
+    /// Set the hard limit of entries returned by this list.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// Set the key to start listing after.
+    pub fn with_start_after(mut self, start_after: &str) -> Self {
+        self.start_after = Some(start_after.to_string());
+        self
+    }
+
+    /// List all entries that starts with given path recursively.
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Set the delimiter to group entries by common prefixes.
+    pub fn with_delimiter(mut self, delimiter: &str) -> Self {
+        self.delimiter = Some(delimiter.to_string());
+        self
+    }
+
+    /// Return all versions of files.
+    pub fn with_versions(mut self, versions: bool) -> Self {
+        self.versions = versions;
+        self
+    }
+
+    /// Return deleted files (or versions).
+    pub fn with_deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
+    }
+
+    /// Only return entries whose path matches the given glob pattern.
+    pub fn with_glob(mut self, glob: &str) -> Self {
+        self.glob = Some(glob.to_string());
+        self
+    }
+
+    /// Resume the list from the continuation token returned by [`Lister::next_page`](crate::Lister::next_page).
+    pub fn with_start_token(mut self, start_token: &str) -> Self {
+        self.start_token = Some(start_token.to_string());
+        self
+    }
+
+    /// Apply options on the args of list.
+    pub(crate) fn apply(self, mut args: OpList) -> OpList {
+        if let Some(v) = self.chunk {
+            args = args.with_limit(v);
+        }
+        if let Some(v) = self.max_entries {
+            args = args.with_max_entries(v);
+        }
+        if let Some(v) = &self.start_after {
+            args = args.with_start_after(v);
+        }
+        if self.recursive {
+            args = args.with_recursive(true);
+        }
+        if let Some(v) = &self.delimiter {
+            args = args.with_delimiter(v);
+        }
+        if self.versions {
+            args = args.with_versions(true);
+        }
+        if self.deleted {
+            args = args.with_deleted(true);
+        }
+        if let Some(v) = &self.glob {
+            args = args.with_glob(v);
+        }
+        if let Some(v) = &self.start_token {
+            args = args.with_start_token(v);
+        }
+
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_options_apply() {
+        let opts = ReadOptions::default()
+            .with_offset(1)
+            .with_version("v1")
+            .with_concurrent(4);
+
+        let (args, options) = opts.apply(OpRead::default(), OpReader::default());
+        assert_eq!(args.range(), BytesRange::new(1, None));
+        assert_eq!(args.version(), Some("v1"));
+        assert_eq!(options.concurrent(), 4);
+        assert_eq!(options.chunk(), None);
+    }
+
+    #[test]
+    fn test_read_options_keep_defaults() {
+        let (args, options) =
+            ReadOptions::default().apply(OpRead::default(), OpReader::default().with_chunk(1024));
+        assert_eq!(args.range(), BytesRange::default());
+        assert_eq!(options.chunk(), Some(1024));
+    }
+
+    #[test]
+    fn test_list_options_apply() {
+        let opts = ListOptions::default()
+            .with_recursive(true)
+            .with_glob("**/*.parquet");
+
+        let args = opts.apply(OpList::default());
+        assert!(args.recursive());
+        assert_eq!(args.glob(), Some("**/*.parquet"));
+        assert!(!args.versions());
+    }
+}
@@ -0,0 +1,163 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers to stream data between operators and async io.
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use md5::Digest;
+use md5::Md5;
+
+use crate::raw::*;
+use crate::*;
+
+/// The default buffer size used while transferring data.
+pub(super) const DEFAULT_BUFFER_SIZE: usize = 256 * 1024;
+
+/// Checksum tracks the size and digest of transferred data.
+pub(super) struct Checksum {
+    size: u64,
+    md5: Option<Md5>,
+}
+
+impl Checksum {
+    /// Create a new checksum, the digest will only be calculated if `verify` is true.
+    pub(super) fn new(verify: bool) -> Self {
+        Self {
+            size: 0,
+            md5: verify.then(Md5::new),
+        }
+    }
+
+    pub(super) fn update(&mut self, bs: &[u8]) {
+        self.size += bs.len() as u64;
+        if let Some(md5) = &mut self.md5 {
+            md5.update(bs);
+        }
+    }
+
+    /// Verify the transferred data against the metadata and return the transferred size.
+    ///
+    /// Fields that are not returned by service will be skipped.
+    pub(super) fn verify(self, meta: &Metadata, path: &str) -> Result<u64> {
+        if meta.contains(MetadataField::ContentLength) && meta.content_length() != self.size {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "transferred size doesn't match content length",
+            )
+            .with_context("path", path)
+            .with_context("expect", meta.content_length().to_string())
+            .with_context("actual", self.size.to_string()));
+        }
+
+        if let (Some(md5), Some(expect)) = (self.md5, meta.content_md5()) {
+            let actual = general_purpose::STANDARD.encode(md5.finalize());
+            if actual != expect {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "transferred data doesn't match content md5",
+                )
+                .with_context("path", path)
+                .with_context("expect", expect)
+                .with_context("actual", actual));
+            }
+        }
+
+        Ok(self.size)
+    }
+
+    pub(super) fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// Open the source file as a bytes stream.
+///
+/// If checksum verification is enabled, the metadata of source will be returned
+/// as well, and the read will be pinned to it via `If-Match` if etag is available.
+pub(super) async fn open_source(
+    acc: Accessor,
+    path: String,
+    mut args: OpRead,
+    options: &OpTransfer,
+) -> Result<(FuturesBytesStream, Option<Metadata>)> {
+    if !validate_path(&path, EntryMode::FILE) {
+        return Err(
+            Error::new(ErrorKind::IsADirectory, "read path is a directory")
+                .with_context("service", acc.info().scheme())
+                .with_context("path", path),
+        );
+    }
+
+    let meta = if options.verify_checksum() {
+        let mut op = OpStat::new();
+        if let Some(version) = args.version() {
+            op = op.with_version(version);
+        }
+        let meta = acc.stat(&path, op).await?.into_metadata();
+        // Make sure the content we read matches the checksum if possible.
+        if acc.info().full_capability().read_with_if_match {
+            if let Some(etag) = meta.etag() {
+                args = args.with_if_match(etag);
+            }
+        }
+        Some(meta)
+    } else {
+        None
+    };
+
+    let chunk = options.buffer_size().unwrap_or(DEFAULT_BUFFER_SIZE);
+    let ctx = ReadContext::new(acc, path, args, OpReader::new().with_chunk(chunk));
+    let s = Reader::new(ctx).into_bytes_stream(..).await?;
+    Ok((s, meta))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::format_content_md5;
+
+    #[test]
+    fn test_checksum_verify() {
+        let content = b"hello, world";
+
+        let mut checksum = Checksum::new(true);
+        checksum.update(&content[..5]);
+        checksum.update(&content[5..]);
+        let meta = Metadata::new(EntryMode::FILE)
+            .with_content_length(content.len() as u64)
+            .with_content_md5(format_content_md5(content));
+        assert_eq!(checksum.verify(&meta, "path").unwrap(), 12);
+
+        let mut checksum = Checksum::new(true);
+        checksum.update(b"hello, wOrld");
+        let err = checksum.verify(&meta, "path").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+
+        // Digest is skipped if verify is disabled.
+        let mut checksum = Checksum::new(false);
+        checksum.update(b"hello, wOrld");
+        assert!(checksum.verify(&meta, "path").is_ok());
+
+        // Missing fields are skipped.
+        let mut checksum = Checksum::new(true);
+        checksum.update(b"hi");
+        assert!(checksum
+            .verify(&Metadata::new(EntryMode::FILE), "path")
+            .is_ok());
+    }
+}
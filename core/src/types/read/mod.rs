@@ -27,3 +27,6 @@ pub use futures_async_reader::FuturesAsyncReader;
 
 mod futures_bytes_stream;
 pub use futures_bytes_stream::FuturesBytesStream;
+
+mod tokio_async_reader;
+pub use tokio_async_reader::TokioAsyncReader;
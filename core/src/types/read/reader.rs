@@ -29,6 +29,13 @@ use crate::*;
 /// Reader is designed to read data from given path in an asynchronous
 /// manner.
 ///
+/// # Cancellation Safety
+///
+/// `Reader` doesn't hold any cursor state: every read request carries its own
+/// range. So dropping the futures returned by `read`, `read_into` or `fetch`
+/// at any await point is safe, the `Reader` can still be used afterwards.
+/// Note that a cancelled `read_into` could have filled part of the given buffer.
+///
 /// # Usage
 ///
 /// [`Reader`] provides multiple ways to read data from given reader.
@@ -268,6 +275,42 @@ impl Reader {
         Ok(FuturesAsyncReader::new(self.ctx, range))
     }
 
+    /// Convert reader into [`TokioAsyncReader`] which implements [`tokio::io::AsyncRead`],
+    /// [`tokio::io::AsyncSeek`] and [`tokio::io::AsyncBufRead`].
+    ///
+    /// This is the tokio version of [`Reader::into_futures_async_read`], please refer to it
+    /// for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use opendal::Operator;
+    /// use opendal::Result;
+    /// use tokio::io::AsyncReadExt;
+    ///
+    /// async fn test(op: Operator) -> io::Result<()> {
+    ///     let mut r = op
+    ///         .reader("hello.txt")
+    ///         .await?
+    ///         .into_tokio_async_read(1024..2048)
+    ///         .await?;
+    ///     let mut bs = Vec::new();
+    ///     r.read_to_end(&mut bs).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub async fn into_tokio_async_read(
+        self,
+        range: impl RangeBounds<u64>,
+    ) -> Result<TokioAsyncReader> {
+        let range = self.ctx.parse_into_range(range).await?;
+        Ok(TokioAsyncReader::new(self.ctx, range))
+    }
+
     /// Convert reader into [`FuturesBytesStream`] which implements [`futures::Stream`].
     ///
     /// # Examples
@@ -0,0 +1,156 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io;
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::AsyncBufRead as _;
+use futures::AsyncRead as _;
+use futures::AsyncSeek as _;
+use tokio::io::AsyncBufRead;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncSeek;
+use tokio::io::ReadBuf;
+
+use crate::*;
+
+/// TokioAsyncReader is the adapter of [`AsyncRead`], [`AsyncBufRead`]
+/// and [`AsyncSeek`] from tokio generated by [`Reader::into_tokio_async_read`].
+///
+/// Users can use this adapter in cases where they need to use tokio's
+/// [`AsyncRead`] related trait. TokioAsyncReader reuses the same concurrent
+/// and chunk settings from [`Reader`].
+///
+/// TokioAsyncReader also implements [`Unpin`], [`Send`] and [`Sync`]
+pub struct TokioAsyncReader {
+    inner: FuturesAsyncReader,
+    /// The pending seek started by `start_seek`.
+    seek: Option<SeekFrom>,
+}
+
+impl TokioAsyncReader {
+    /// NOTE: don't allow users to create TokioAsyncReader directly.
+    #[inline]
+    pub(super) fn new(ctx: Arc<ReadContext>, range: Range<u64>) -> Self {
+        TokioAsyncReader {
+            inner: FuturesAsyncReader::new(ctx, range),
+            seek: None,
+        }
+    }
+}
+
+impl AsyncRead for TokioAsyncReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        let n = ready!(Pin::new(&mut this.inner).poll_read(cx, buf.initialize_unfilled()))?;
+        buf.advance(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncBufRead for TokioAsyncReader {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Pin::new(&mut self.get_mut().inner).poll_fill_buf(cx)
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        Pin::new(&mut self.get_mut().inner).consume(amt)
+    }
+}
+
+impl AsyncSeek for TokioAsyncReader {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        let this = self.get_mut();
+        if this.seek.is_some() {
+            return Err(io::Error::other(
+                "another seek is in progress, call poll_complete first",
+            ));
+        }
+        this.seek = Some(position);
+        Ok(())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+        // tokio calls poll_complete without start_seek to fetch current position.
+        let pos = this.seek.take().unwrap_or(SeekFrom::Current(0));
+        Pin::new(&mut this.inner).poll_seek(cx, pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bytes::Bytes;
+    use pretty_assertions::assert_eq;
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncSeekExt;
+
+    use super::*;
+    use crate::raw::*;
+
+    #[tokio::test]
+    async fn test_trait() -> Result<()> {
+        let acc = Operator::via_iter(Scheme::Memory, [])?.into_inner();
+        let ctx = Arc::new(ReadContext::new(
+            acc,
+            "test".to_string(),
+            OpRead::new(),
+            OpReader::new(),
+        ));
+
+        let v = TokioAsyncReader::new(ctx, 4..8);
+
+        let _: Box<dyn Unpin + MaybeSend + Sync + 'static> = Box::new(v);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tokio_async_read() -> Result<()> {
+        let op = Operator::via_iter(Scheme::Memory, [])?;
+        op.write(
+            "test",
+            Buffer::from(vec![Bytes::from("Hello"), Bytes::from("World")]),
+        )
+        .await?;
+
+        let mut r = op.reader("test").await?.into_tokio_async_read(4..8).await?;
+        let mut bs = vec![];
+        r.read_to_end(&mut bs).await.unwrap();
+        assert_eq!(&bs, "oWor".as_bytes());
+
+        let pos = r.seek(SeekFrom::Current(-2)).await.unwrap();
+        assert_eq!(pos, 2);
+        let mut bs = vec![];
+        r.read_to_end(&mut bs).await.unwrap();
+        assert_eq!(&bs, "or".as_bytes());
+
+        Ok(())
+    }
+}
@@ -117,6 +117,8 @@ pub enum Scheme {
     Dropbox,
     /// [oss][crate::services::Oss]: Aliyun Object Storage Services
     Oss,
+    /// [ozone][crate::services::Ozone]: Apache Ozone services via S3 gateway.
+    Ozone,
     /// [persy][crate::services::Persy]: persy backend support.
     Persy,
     /// [redis][crate::services::Redis]: Redis services
@@ -147,6 +149,8 @@ pub enum Scheme {
     Webdav,
     /// [webhdfs][crate::services::Webhdfs]: WebHDFS RESTful API Services
     Webhdfs,
+    /// [rados][crate::services::Rados]: Ceph RADOS services via librados.
+    Rados,
     /// [redb][crate::services::Redb]: Redb Services
     Redb,
     /// [tikv][crate::services::Tikv]: Tikv Services
@@ -271,6 +275,8 @@ impl Scheme {
             Scheme::Gdrive,
             #[cfg(feature = "services-oss")]
             Scheme::Oss,
+            #[cfg(feature = "services-ozone")]
+            Scheme::Ozone,
             #[cfg(feature = "services-persy")]
             Scheme::Persy,
             #[cfg(feature = "services-redis")]
@@ -307,6 +313,8 @@ impl Scheme {
             Scheme::Webdav,
             #[cfg(feature = "services-webhdfs")]
             Scheme::Webhdfs,
+            #[cfg(feature = "services-rados")]
+            Scheme::Rados,
             #[cfg(feature = "services-redb")]
             Scheme::Redb,
             #[cfg(feature = "services-mongodb")]
@@ -361,6 +369,7 @@ impl FromStr for Scheme {
             "dropbox" => Ok(Scheme::Dropbox),
             "etcd" => Ok(Scheme::Etcd),
             "dbfs" => Ok(Scheme::Dbfs),
+            "foundationdb" => Ok(Scheme::Foundationdb),
             "fs" => Ok(Scheme::Fs),
             "gcs" => Ok(Scheme::Gcs),
             "gdrive" => Ok(Scheme::Gdrive),
@@ -387,6 +396,7 @@ impl FromStr for Scheme {
             "onedrive" => Ok(Scheme::Onedrive),
             "persy" => Ok(Scheme::Persy),
             "postgresql" => Ok(Scheme::Postgresql),
+            "rados" => Ok(Scheme::Rados),
             "redb" => Ok(Scheme::Redb),
             "redis" => Ok(Scheme::Redis),
             "rocksdb" => Ok(Scheme::Rocksdb),
@@ -400,6 +410,7 @@ impl FromStr for Scheme {
             "supabase" => Ok(Scheme::Supabase),
             "swift" => Ok(Scheme::Swift),
             "oss" => Ok(Scheme::Oss),
+            "ozone" => Ok(Scheme::Ozone),
             "vercel_artifacts" => Ok(Scheme::VercelArtifacts),
             "vercel_blob" => Ok(Scheme::VercelBlob),
             "webdav" => Ok(Scheme::Webdav),
@@ -471,8 +482,10 @@ impl From<Scheme> for &'static str {
             Scheme::VercelArtifacts => "vercel_artifacts",
             Scheme::VercelBlob => "vercel_blob",
             Scheme::Oss => "oss",
+            Scheme::Ozone => "ozone",
             Scheme::Webdav => "webdav",
             Scheme::Webhdfs => "webhdfs",
+            Scheme::Rados => "rados",
             Scheme::Redb => "redb",
             Scheme::Tikv => "tikv",
             Scheme::Azfile => "azfile",
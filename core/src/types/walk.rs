@@ -0,0 +1,233 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::stream::FuturesUnordered;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// The task that lists a dir, returns the dir, its entries and depth.
+type WalkTask = BoxedStaticFuture<Result<(String, Vec<Entry>, usize)>>;
+
+/// Walker walks the directory tree and returns entries in a stream.
+///
+/// Users can construct Walker by [`Operator::walk`].
+///
+/// - Walker implements `Stream<Item = Result<Entry>>`.
+/// - Directories are listed level by level, up to `concurrent` directories are
+///   listed at the same time, so entries are not returned in lexical order.
+/// - Walker will return `None` if there is no more entries or error has been returned.
+///
+/// # Patterns
+///
+/// Patterns of `include` and `exclude` are matched against the path of entry
+/// without the trailing `/`:
+///
+/// - `?` matches any single character except `/`.
+/// - `*` matches any sequence of characters except `/`.
+/// - `**` as a whole path segment matches any sequence of characters, `**/`
+///   matches zero or more directories. Otherwise it's the same as `*`.
+pub struct Walker {
+    op: Operator,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    max_depth: Option<usize>,
+    concurrent: usize,
+    executor: Executor,
+
+    dirs: VecDeque<(String, usize)>,
+    tasks: FuturesUnordered<WalkTask>,
+    entries: VecDeque<Entry>,
+    errored: bool,
+}
+
+impl Walker {
+    /// Create a new walker that walks the dir at given path.
+    pub(crate) fn new(op: Operator, path: &str) -> Self {
+        let mut path = normalize_path(path);
+        if !path.ends_with('/') {
+            path.push('/');
+        }
+
+        Self {
+            include: Vec::new(),
+            exclude: Vec::new(),
+            max_depth: None,
+            concurrent: op.limit(),
+            executor: op.default_executor().unwrap_or_default(),
+            op,
+
+            dirs: VecDeque::from([(path, 0)]),
+            tasks: FuturesUnordered::new(),
+            entries: VecDeque::new(),
+            errored: false,
+        }
+    }
+
+    /// Only return entries that match given pattern.
+    ///
+    /// Entries will be returned if they match any of the include patterns. All
+    /// entries will be returned if no include pattern is set.
+    ///
+    /// Directories that don't match are still walked, so that the entries under
+    /// them can be matched.
+    pub fn include(mut self, pattern: &str) -> Self {
+        self.include.push(pattern.to_string());
+        self
+    }
+
+    /// Skip entries that match given pattern.
+    ///
+    /// Excluded directories will not be walked.
+    pub fn exclude(mut self, pattern: &str) -> Self {
+        self.exclude.push(pattern.to_string());
+        self
+    }
+
+    /// Set the max depth to walk, entries directly under the walked path have
+    /// depth `1`.
+    ///
+    /// Walk the whole tree by default.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Set the number of directories to list concurrently.
+    ///
+    /// Default to the limit of operator, see [`Operator::with_limit`].
+    pub fn concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent.max(1);
+        self
+    }
+
+    fn handle(&mut self, dir: String, entries: Vec<Entry>, depth: usize) {
+        let depth = depth + 1;
+
+        for entry in entries {
+            // The dir itself could be returned by list.
+            if entry.path() == dir {
+                continue;
+            }
+
+            let path = entry.path().trim_end_matches('/');
+            if self.exclude.iter().any(|p| glob_match(p, path)) {
+                continue;
+            }
+            let included =
+                self.include.is_empty() || self.include.iter().any(|p| glob_match(p, path));
+
+            if entry.metadata().is_dir() && !matches!(self.max_depth, Some(v) if depth >= v) {
+                self.dirs.push_back((entry.path().to_string(), depth));
+            }
+            if included {
+                self.entries.push_back(entry);
+            }
+        }
+    }
+}
+
+impl Stream for Walker {
+    type Item = Result<Entry>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Returns `None` if we have errored.
+        if self.errored {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            if let Some(entry) = self.entries.pop_front() {
+                return Poll::Ready(Some(Ok(entry)));
+            }
+
+            while self.tasks.len() < self.concurrent {
+                let Some((dir, depth)) = self.dirs.pop_front() else {
+                    break;
+                };
+
+                let op = self.op.clone();
+                let fut = async move {
+                    let entries = op.list(&dir).await?;
+                    Ok((dir, entries, depth))
+                };
+                // Run in background if concurrent, pending tasks will be cancelled
+                // once walker is dropped.
+                if self.concurrent > 1 {
+                    let task = self.executor.execute(fut);
+                    self.tasks.push(Box::pin(task));
+                } else {
+                    self.tasks.push(Box::pin(fut));
+                }
+            }
+
+            match ready!(self.tasks.poll_next_unpin(cx)) {
+                Some(Ok((dir, entries, depth))) => self.handle(dir, entries, depth),
+                Some(Err(err)) => {
+                    self.errored = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_walk() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        for path in ["root/a.txt", "root/dir/b.txt", "root/dir/sub/c.csv"] {
+            op.write(path, "test").await?;
+        }
+
+        let paths = |entries: Vec<Entry>| {
+            let mut paths: Vec<_> = entries.iter().map(|e| e.path().to_string()).collect();
+            paths.sort();
+            paths
+        };
+
+        let entries: Vec<_> = op
+            .walk("root")
+            .include("**/*.txt")
+            .concurrent(4)
+            .try_collect()
+            .await?;
+        assert_eq!(paths(entries), ["root/a.txt", "root/dir/b.txt"]);
+
+        let entries: Vec<_> = op.walk("root/").max_depth(1).try_collect().await?;
+        assert_eq!(paths(entries), ["root/a.txt", "root/dir/"]);
+
+        let entries: Vec<_> = op.walk("root/").exclude("root/dir").try_collect().await?;
+        assert_eq!(paths(entries), ["root/a.txt"]);
+        Ok(())
+    }
+}
@@ -24,3 +24,8 @@ mod futures_async_writer;
 pub use futures_async_writer::FuturesAsyncWriter;
 mod futures_bytes_sink;
 pub use futures_bytes_sink::FuturesBytesSink;
+mod tokio_async_writer;
+pub use tokio_async_writer::TokioAsyncWriter;
+mod upload_progress;
+pub use upload_progress::UploadPart;
+pub use upload_progress::UploadProgress;
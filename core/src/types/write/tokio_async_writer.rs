@@ -0,0 +1,86 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::AsyncWrite as _;
+use tokio::io::AsyncWrite;
+
+use crate::raw::*;
+use crate::*;
+
+/// TokioAsyncWriter is the adapter of tokio's [`AsyncWrite`] for [`Writer`].
+///
+/// Users can use this adapter in cases where they need to use tokio's
+/// [`AsyncWrite`] related trait. Please make sure `shutdown` is called
+/// to commit the written data.
+///
+/// TokioAsyncWriter also implements [`Unpin`], [`Send`] and [`Sync`]
+pub struct TokioAsyncWriter {
+    inner: FuturesAsyncWriter,
+}
+
+impl TokioAsyncWriter {
+    /// NOTE: don't allow users to create directly.
+    #[inline]
+    pub(crate) fn new(w: WriteGenerator<oio::Writer>) -> Self {
+        TokioAsyncWriter {
+            inner: FuturesAsyncWriter::new(w),
+        }
+    }
+}
+
+impl AsyncWrite for TokioAsyncWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::AsyncWriteExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_tokio_async_write() -> Result<()> {
+        let op = Operator::via_iter(Scheme::Memory, [])?;
+
+        let mut w = op.writer("test").await?.into_tokio_async_write();
+        w.write_all(b"Hello, ").await.unwrap();
+        w.write_all(b"World!").await.unwrap();
+        w.shutdown().await.unwrap();
+
+        assert_eq!(op.read("test").await?.to_vec(), b"Hello, World!");
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::oio::MultipartPart;
+
+/// UploadProgress is the progress of a multipart upload.
+///
+/// Users can persist it via [`Writer::upload_progress`](crate::Writer::upload_progress)
+/// and resume the upload later via `writer_with(path).resume(progress)`, even
+/// after the process restarted. It can be serialized with serde for persisting.
+///
+/// Data must be written from [`UploadProgress::size`] after resuming.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadProgress {
+    /// The upload id returned by service.
+    pub upload_id: String,
+    /// The finished parts, ordered by part number starting from 0.
+    pub parts: Vec<UploadPart>,
+    /// The total size of finished parts.
+    pub size: u64,
+}
+
+impl UploadProgress {
+    /// Create a new progress for the given upload id without any finished parts.
+    pub fn new(upload_id: &str) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            parts: Vec::new(),
+            size: 0,
+        }
+    }
+}
+
+/// UploadPart is a finished part of a multipart upload.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadPart {
+    /// The number of the part, starting from 0.
+    pub part_number: usize,
+    /// The etag of the part.
+    pub etag: String,
+    /// The checksum of the part.
+    pub checksum: Option<String>,
+}
+
+impl From<MultipartPart> for UploadPart {
+    fn from(part: MultipartPart) -> Self {
+        Self {
+            part_number: part.part_number,
+            etag: part.etag,
+            checksum: part.checksum,
+        }
+    }
+}
+
+impl From<UploadPart> for MultipartPart {
+    fn from(part: UploadPart) -> Self {
+        Self {
+            part_number: part.part_number,
+            etag: part.etag,
+            checksum: part.checksum,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upload_progress_serde() {
+        let progress = UploadProgress {
+            upload_id: "upload".to_string(),
+            parts: vec![UploadPart {
+                part_number: 0,
+                etag: "\"etag\"".to_string(),
+                checksum: None,
+            }],
+            size: 16,
+        };
+
+        let content = serde_json::to_string(&progress).unwrap();
+        let actual: UploadProgress = serde_json::from_str(&content).unwrap();
+        assert_eq!(actual, progress);
+    }
+}
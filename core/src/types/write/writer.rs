@@ -30,6 +30,16 @@ use crate::*;
 /// Please make sure either `close` or `abort` has been called before
 /// dropping the writer otherwise the data could be lost.
 ///
+/// ## Cancellation Safety
+///
+/// The futures returned by `write`, `close` and `abort` can be dropped at any
+/// await point. Data that has been accepted by a finished `write` call won't be
+/// lost, and the cancelled call can be retried on the same writer.
+///
+/// If a writer backed by a multipart upload is dropped before `close` or
+/// `abort` succeeded, OpenDAL will try to abort the upload in background via
+/// the executor, so that uploaded parts won't be leaked on the storage.
+///
 /// ## Usage
 ///
 /// ### Write Multiple Chunks
@@ -99,7 +109,7 @@ use crate::*;
 ///   creating writer with `append` enabled.
 pub struct Writer {
     /// Keep a reference to write context in writer.
-    _ctx: Arc<WriteContext>,
+    ctx: Arc<WriteContext>,
     inner: WriteGenerator<oio::Writer>,
 }
 
@@ -109,7 +119,19 @@ impl Writer {
         let ctx = Arc::new(ctx);
         let inner = WriteGenerator::create(ctx.clone()).await?;
 
-        Ok(Self { _ctx: ctx, inner })
+        Ok(Self { ctx, inner })
+    }
+
+    /// Get the progress of the ongoing multipart upload.
+    ///
+    /// Returns `None` if the writer is not [`resumable`](crate::operator_futures::FutureWriter::resumable),
+    /// or no multipart upload is ongoing: the upload has not been started, or has
+    /// been completed or aborted.
+    ///
+    /// The progress can be persisted to resume the upload later, even after the
+    /// process restarted.
+    pub fn upload_progress(&self) -> Option<UploadProgress> {
+        self.ctx.args().progress().and_then(|v| v.get())
     }
 
     /// Write [`Buffer`] into writer.
@@ -164,7 +186,7 @@ impl Writer {
     /// ## Notes
     ///
     /// Abort should only be called when the writer is not closed or
-    /// aborted, otherwise an unexpected error could be returned.
+    /// aborted, otherwise an unexpected error will be returned.
     pub async fn abort(&mut self) -> Result<()> {
         self.inner.abort().await
     }
@@ -173,8 +195,9 @@ impl Writer {
     ///
     /// ## Notes
     ///
-    /// Close should only be called when the writer is not closed or
-    /// aborted, otherwise an unexpected error could be returned.
+    /// Close is idempotent: calling `close` again after it succeeded is a
+    /// no-op. Calling `close` after the writer has been aborted will return
+    /// an unexpected error.
     pub async fn close(&mut self) -> Result<()> {
         self.inner.close().await
     }
@@ -237,6 +260,37 @@ impl Writer {
         FuturesAsyncWriter::new(self.inner)
     }
 
+    /// Convert writer into [`TokioAsyncWriter`] which implements [`tokio::io::AsyncWrite`].
+    ///
+    /// This is the tokio version of [`Writer::into_futures_async_write`], please refer to it
+    /// for more details.
+    ///
+    /// # Notes
+    ///
+    /// Please make sure `shutdown` is called to commit the written data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use opendal::Operator;
+    /// use opendal::Result;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// async fn test(op: Operator) -> io::Result<()> {
+    ///     let mut w = op.writer("hello.txt").await?.into_tokio_async_write();
+    ///     let bs = "Hello, World!".as_bytes();
+    ///     w.write_all(bs).await?;
+    ///     w.shutdown().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn into_tokio_async_write(self) -> TokioAsyncWriter {
+        TokioAsyncWriter::new(self.inner)
+    }
+
     /// Convert writer into [`FuturesBytesSink`] which implements [`futures::Sink<Bytes>`].
     ///
     /// # Notes
@@ -349,4 +403,25 @@ mod tests {
 
         assert_eq!(buf.to_bytes(), content);
     }
+
+    #[tokio::test]
+    async fn test_writer_close_is_idempotent() {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        let path = "test_file";
+
+        let content = gen_random_bytes();
+        let mut writer = op.writer(path).await.unwrap();
+        writer
+            .write(content.clone())
+            .await
+            .expect("write must succeed");
+        writer.close().await.expect("close must succeed");
+        writer.close().await.expect("close again must succeed");
+        assert!(writer.abort().await.is_err());
+        assert!(writer.write(content.clone()).await.is_err());
+
+        let buf = op.read(path).await.expect("read to end mut succeed");
+
+        assert_eq!(buf.to_bytes(), content);
+    }
 }
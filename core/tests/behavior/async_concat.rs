@@ -0,0 +1,163 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.concat {
+        tests.extend(async_trials!(
+            op,
+            test_concat,
+            test_concat_many_sources,
+            test_concat_into_source,
+            test_concat_non_existing_source
+        ))
+    }
+
+    if cap.read && cap.write && !cap.concat {
+        tests.extend(async_trials!(op, test_concat_without_emulate))
+    }
+
+    if cap.read && cap.write && cap.write_can_multi && !cap.concat {
+        tests.extend(async_trials!(
+            op,
+            test_concat_with_emulate,
+            test_concat_with_emulate_into_source
+        ))
+    }
+}
+
+/// Concat files and test contents.
+pub async fn test_concat(op: Operator) -> Result<()> {
+    let (first, first_content, _) = TEST_FIXTURE.new_file(op.clone());
+    let (second, second_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&first, first_content.clone()).await?;
+    op.write(&second, second_content.clone()).await?;
+
+    let path = TEST_FIXTURE.new_file_path();
+    op.concat(&path, &[&first, &second]).await?;
+
+    let actual = op.read(&path).await?.to_vec();
+    assert_eq!(actual, [first_content, second_content].concat());
+
+    Ok(())
+}
+
+/// Concat more sources than services could handle in one request.
+pub async fn test_concat_many_sources(op: Operator) -> Result<()> {
+    let mut sources = Vec::new();
+    let mut expected = Vec::new();
+    for i in 0..40 {
+        let source = TEST_FIXTURE.new_file_path();
+        let content = format!("part-{i};").into_bytes();
+        op.write(&source, content.clone()).await?;
+        sources.push(source);
+        expected.extend(content);
+    }
+
+    let path = TEST_FIXTURE.new_file_path();
+    let sources: Vec<&str> = sources.iter().map(|v| v.as_str()).collect();
+    op.concat(&path, &sources).await?;
+
+    let actual = op.read(&path).await?.to_vec();
+    assert_eq!(actual, expected);
+
+    Ok(())
+}
+
+/// Concat into one of the sources must append to it.
+pub async fn test_concat_into_source(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    let (source, source_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content.clone()).await?;
+    op.write(&source, source_content.clone()).await?;
+
+    op.concat(&path, &[&path, &source]).await?;
+
+    let actual = op.read(&path).await?.to_vec();
+    assert_eq!(actual, [content, source_content].concat());
+
+    Ok(())
+}
+
+/// Concat with non existing source must fail.
+pub async fn test_concat_non_existing_source(op: Operator) -> Result<()> {
+    let source = TEST_FIXTURE.new_file_path();
+    let path = TEST_FIXTURE.new_file_path();
+
+    let err = op
+        .concat(&path, &[&source])
+        .await
+        .expect_err("concat with non existing source must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+
+    Ok(())
+}
+
+/// Concat without emulate must be unsupported if services don't support it.
+pub async fn test_concat_without_emulate(op: Operator) -> Result<()> {
+    let (source, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&source, content).await?;
+
+    let path = TEST_FIXTURE.new_file_path();
+    let err = op
+        .concat(&path, &[&source])
+        .await
+        .expect_err("concat without emulate must fail");
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+    Ok(())
+}
+
+/// Concat with emulate must read and write all sources.
+pub async fn test_concat_with_emulate(op: Operator) -> Result<()> {
+    let (first, first_content, _) = TEST_FIXTURE.new_file(op.clone());
+    let (second, second_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&first, first_content.clone()).await?;
+    op.write(&second, second_content.clone()).await?;
+
+    let path = TEST_FIXTURE.new_file_path();
+    op.concat_with(&path, &[&first, &second])
+        .emulate(true)
+        .await?;
+
+    let actual = op.read(&path).await?.to_vec();
+    assert_eq!(actual, [first_content, second_content].concat());
+
+    Ok(())
+}
+
+/// Concat with emulate into one of the sources must append to it.
+pub async fn test_concat_with_emulate_into_source(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    let (source, source_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content.clone()).await?;
+    op.write(&source, source_content.clone()).await?;
+
+    op.concat_with(&path, &[&path, &source, &path])
+        .emulate(true)
+        .await?;
+
+    let actual = op.read(&path).await?.to_vec();
+    assert_eq!(actual, [content.clone(), source_content, content].concat());
+
+    Ok(())
+}
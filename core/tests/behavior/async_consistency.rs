@@ -0,0 +1,218 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+use futures::future::join_all;
+use futures::TryStreamExt;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::*;
+
+/// Consistency tests describe the consistency model that users can rely on:
+///
+/// - Only one of concurrent conditional creates to the same path could win.
+/// - Read, stat and list after write must see the latest content.
+/// - Delete while reading must not return partial or corrupted content.
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.stat {
+        tests.extend(async_trials!(
+            op,
+            test_consistency_read_after_write,
+            test_consistency_read_after_overwrite
+        ));
+    }
+
+    if cap.read && cap.write && cap.write_with_if_not_exists {
+        tests.extend(async_trials!(
+            op,
+            test_consistency_concurrent_write_same_path
+        ));
+    }
+
+    if cap.read && cap.write && cap.list {
+        tests.extend(async_trials!(op, test_consistency_list_after_write));
+    }
+
+    if cap.read && cap.write && cap.delete {
+        tests.extend(async_trials!(op, test_consistency_delete_while_read));
+    }
+}
+
+/// Read after write must return the content just written.
+pub async fn test_consistency_read_after_write(op: Operator) -> Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    let bs = op.read(&path).await.expect("read must succeed");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(bs.to_vec())),
+        format!("{:x}", Sha256::digest(&content)),
+        "read after write must return the latest content"
+    );
+
+    Ok(())
+}
+
+/// Read after overwrite must return the new content instead of the old one.
+pub async fn test_consistency_read_after_overwrite(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await.expect("write must succeed");
+
+    let (new_content, new_size) = gen_bytes(op.info().full_capability());
+    op.write(&path, new_content.clone())
+        .await
+        .expect("overwrite must succeed");
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(
+        meta.content_length(),
+        new_size as u64,
+        "stat after overwrite must return the latest size"
+    );
+
+    let bs = op.read(&path).await.expect("read must succeed");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(bs.to_vec())),
+        format!("{:x}", Sha256::digest(&new_content)),
+        "read after overwrite must return the latest content"
+    );
+
+    Ok(())
+}
+
+/// Concurrent writes to the same path with `if_not_exists` must have exactly
+/// one winner, and the final content must be the whole content of the winner.
+///
+/// Plain concurrent writes are last-writer-wins on most services and could
+/// even be interleaved on services without atomic writes, so we rely on
+/// conditional writes to get a deterministic result.
+pub async fn test_consistency_concurrent_write_same_path(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let contents: Vec<_> = (0..4)
+        .map(|_| gen_bytes(op.info().full_capability()).0)
+        .collect();
+
+    let results = join_all(contents.iter().map(|content| {
+        let op = op.clone();
+        let path = path.clone();
+        let content = content.clone();
+        async move { op.write_with(&path, content).if_not_exists(true).await }
+    }))
+    .await;
+
+    let winners: Vec<_> = contents
+        .iter()
+        .zip(results.iter())
+        .filter(|(_, res)| res.is_ok())
+        .map(|(content, _)| content)
+        .collect();
+    assert_eq!(
+        winners.len(),
+        1,
+        "exactly one concurrent write must succeed: {results:?}"
+    );
+    for res in results.iter().filter_map(|res| res.as_ref().err()) {
+        assert_eq!(res.kind(), ErrorKind::ConditionNotMatch);
+    }
+
+    let bs = op.read(&path).await.expect("read must succeed").to_vec();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(winners[0])),
+        "content of concurrent writes must not be interleaved"
+    );
+
+    Ok(())
+}
+
+/// List after write must contain the file just written.
+pub async fn test_consistency_list_after_write(op: Operator) -> Result<()> {
+    let parent = TEST_FIXTURE.new_dir_path();
+    let path = format!("{parent}{}", gen_uuid());
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let entries: Vec<_> = op.lister(&parent).await?.try_collect().await?;
+    let entry = entries
+        .iter()
+        .find(|e| e.path() == path)
+        .expect("list after write must contain the written file");
+    if op.info().full_capability().list_has_content_length {
+        assert_eq!(entry.metadata().content_length(), size as u64);
+    }
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Delete while reading must either return the whole content or fail with
+/// an error, partial or corrupted content is not allowed.
+///
+/// The first chunk is read before deleting so that the read is in progress.
+pub async fn test_consistency_delete_while_read(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let (content, size) = gen_bytes_with_range(2..4 * 1024 * 1024);
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let reader = op
+        .reader_with(&path)
+        .chunk(size / 2)
+        .await
+        .expect("reader must be created");
+    let mut stream = reader
+        .into_bytes_stream(..)
+        .await
+        .expect("stream must be created");
+    let mut bs = stream
+        .try_next()
+        .await
+        .expect("read before delete must succeed")
+        .expect("first chunk must exist")
+        .to_vec();
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    match stream.try_collect::<Vec<_>>().await {
+        Ok(chunks) => {
+            chunks.iter().for_each(|chunk| bs.extend_from_slice(chunk));
+            assert_eq!(
+                format!("{:x}", Sha256::digest(&bs)),
+                format!("{:x}", Sha256::digest(&content)),
+                "read while deleting must return the whole content"
+            )
+        }
+        Err(err) => assert_eq!(
+            err.kind(),
+            std::io::ErrorKind::NotFound,
+            "read after delete must fail with NotFound"
+        ),
+    }
+
+    Ok(())
+}
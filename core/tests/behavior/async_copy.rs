@@ -41,12 +41,12 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
 
 /// Copy a file with ascii name and test contents.
 pub async fn test_copy_file_with_ascii_name(op: Operator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone()).await?;
 
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let target_path = gen_uuid().to_string();
 
     op.copy(&source_path, &target_path).await?;
 
@@ -96,8 +96,8 @@ pub async fn test_copy_file_with_non_ascii_name(op: Operator) -> Result<()> {
 
 /// Copy a nonexistent source should return an error.
 pub async fn test_copy_non_existing_source(op: Operator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
+    let target_path = gen_uuid().to_string();
 
     let err = op
         .copy(&source_path, &target_path)
@@ -113,8 +113,8 @@ pub async fn test_copy_source_dir(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let source_path = format!("{}/", uuid::Uuid::new_v4());
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let source_path = format!("{}/", gen_uuid());
+    let target_path = gen_uuid().to_string();
 
     op.create_dir(&source_path).await?;
 
@@ -132,12 +132,12 @@ pub async fn test_copy_target_dir(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, content).await?;
 
-    let target_path = format!("{}/", uuid::Uuid::new_v4());
+    let target_path = format!("{}/", gen_uuid());
 
     op.create_dir(&target_path).await?;
 
@@ -154,7 +154,7 @@ pub async fn test_copy_target_dir(op: Operator) -> Result<()> {
 
 /// Copy a file to self should return an error.
 pub async fn test_copy_self(op: Operator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, content).await?;
@@ -171,17 +171,12 @@ pub async fn test_copy_self(op: Operator) -> Result<()> {
 
 /// Copy to a nested path, parent path should be created successfully.
 pub async fn test_copy_nested(op: Operator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone()).await?;
 
-    let target_path = format!(
-        "{}/{}/{}",
-        uuid::Uuid::new_v4(),
-        uuid::Uuid::new_v4(),
-        uuid::Uuid::new_v4()
-    );
+    let target_path = format!("{}/{}/{}", gen_uuid(), gen_uuid(), gen_uuid());
 
     op.copy(&source_path, &target_path).await?;
 
@@ -202,12 +197,12 @@ pub async fn test_copy_nested(op: Operator) -> Result<()> {
 
 /// Copy to a exist path should overwrite successfully.
 pub async fn test_copy_overwrite(op: Operator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone()).await?;
 
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let target_path = gen_uuid().to_string();
     let (target_content, _) = gen_bytes(op.info().full_capability());
     assert_ne!(source_content, target_content);
 
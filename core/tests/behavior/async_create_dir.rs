@@ -23,7 +23,12 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     let cap = op.info().full_capability();
 
     if cap.create_dir && cap.stat {
-        tests.extend(async_trials!(op, test_create_dir, test_create_dir_existing))
+        tests.extend(async_trials!(
+            op,
+            test_create_dir,
+            test_create_dir_existing,
+            test_create_dir_recursive
+        ))
     }
 }
 
@@ -51,3 +56,19 @@ pub async fn test_create_dir_existing(op: Operator) -> Result<()> {
 
     Ok(())
 }
+
+/// Create dir recursively should create all parent dirs.
+pub async fn test_create_dir_recursive(op: Operator) -> Result<()> {
+    let parent = TEST_FIXTURE.new_dir_path();
+    let path = format!("{parent}a/b/");
+
+    op.create_dir_with(&path).recursive(true).await?;
+
+    for p in [parent.clone(), format!("{parent}a/"), path.clone()] {
+        let meta = op.stat(&p).await?;
+        assert_eq!(meta.mode(), EntryMode::DIR, "{p} should be a dir");
+    }
+
+    op.remove_all(&parent).await?;
+    Ok(())
+}
@@ -37,7 +37,11 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_delete_with_not_existing_version
         ));
         if cap.list_with_recursive {
-            tests.extend(async_trials!(op, test_remove_all_basic));
+            tests.extend(async_trials!(
+                op,
+                test_remove_all_basic,
+                test_remove_all_with_limit
+            ));
             if !cap.create_dir {
                 tests.extend(async_trials!(op, test_remove_all_with_prefix_exists));
             }
@@ -87,7 +91,7 @@ pub async fn test_delete_with_special_chars(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let path = format!("{} !@#$%^&()_+-=;',.txt", uuid::Uuid::new_v4());
+    let path = format!("{} !@#$%^&()_+-=;',.txt", gen_uuid());
     let (path, content, _) = TEST_FIXTURE.new_file_with_path(op.clone(), &path);
 
     op.write(&path, content).await.expect("write must succeed");
@@ -102,7 +106,7 @@ pub async fn test_delete_with_special_chars(op: Operator) -> Result<()> {
 
 /// Delete not existing file should also succeed.
 pub async fn test_delete_not_existing(op: Operator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
 
     op.delete(&path).await?;
 
@@ -145,7 +149,7 @@ pub async fn test_delete_stream(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let dir = uuid::Uuid::new_v4().to_string();
+    let dir = gen_uuid().to_string();
     op.create_dir(&format!("{dir}/"))
         .await
         .expect("create must succeed");
@@ -202,13 +206,24 @@ async fn test_blocking_remove_all_with_objects(
 
 /// Remove all under a prefix
 pub async fn test_remove_all_basic(op: Operator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
     test_blocking_remove_all_with_objects(op, parent, ["a/b", "a/c", "a/d/e"]).await
 }
 
+/// Remove all with concurrent tasks should remove all files and dirs.
+pub async fn test_remove_all_with_limit(op: Operator) -> Result<()> {
+    let parent = gen_uuid().to_string();
+    test_blocking_remove_all_with_objects(
+        op.with_limit(4),
+        parent,
+        ["a/b", "a/c", "a/d/e", "a/d/f", "g"],
+    )
+    .await
+}
+
 /// Remove all under a prefix, while the prefix itself is also an object
 pub async fn test_remove_all_with_prefix_exists(op: Operator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
     let (content, _) = gen_bytes(op.info().full_capability());
     op.write(&parent, content)
         .await
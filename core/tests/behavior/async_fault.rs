@@ -0,0 +1,160 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::time::Duration;
+
+use crate::*;
+use opendal::layers::RetryLayer;
+use opendal::raw::tests::FaultInjectLayer;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// The seed of injected faults, failures can be reproduced by the same seed.
+const SEED: u64 = 0x0123_4567_89ab_cdef;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.stat {
+        tests.extend(async_trials!(
+            op,
+            test_fault_read_with_retry,
+            test_fault_reader_with_retry,
+            test_fault_short_read,
+            test_fault_write_with_retry,
+            test_fault_stat_with_retry
+        ))
+    }
+}
+
+/// Build an operator that injects temporary errors and retries them.
+fn retry_on_fault(op: Operator) -> Operator {
+    op.layer(
+        FaultInjectLayer::new(SEED)
+            .with_error(ErrorKind::Unexpected, 0.3)
+            .with_latency(Duration::from_millis(5), 0.1)
+            .with_short_read(0.3),
+    )
+    .layer(
+        RetryLayer::new()
+            .with_min_delay(Duration::from_millis(1))
+            .with_max_delay(Duration::from_millis(10))
+            .with_max_times(10),
+    )
+}
+
+/// Read should succeed with the correct content while faults are retried.
+pub async fn test_fault_read_with_retry(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let bs = retry_on_fault(op).read(&path).await?.to_bytes();
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    Ok(())
+}
+
+/// Reader with chunks should read the correct range while faults are retried.
+pub async fn test_fault_reader_with_retry(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+    let (offset, length) = gen_offset_length(size);
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let r = retry_on_fault(op)
+        .reader_with(&path)
+        .chunk(1024)
+        .concurrent(2)
+        .await?;
+    let bs = r.read(offset..offset + length).await?.to_bytes();
+    assert_eq!(length as usize, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!(
+            "{:x}",
+            Sha256::digest(&content[offset as usize..(offset + length) as usize])
+        ),
+        "read content"
+    );
+
+    Ok(())
+}
+
+/// Short reads must not be treated as EOF.
+pub async fn test_fault_short_read(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let fop = op.layer(FaultInjectLayer::new(SEED).with_short_read(1.0));
+    let bs = fop.read(&path).await?.to_bytes();
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    Ok(())
+}
+
+/// Write should succeed with the correct content while faults are retried.
+pub async fn test_fault_write_with_retry(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    retry_on_fault(op.clone())
+        .write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    Ok(())
+}
+
+/// Stat should succeed while faults are retried.
+pub async fn test_fault_stat_with_retry(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let fop = retry_on_fault(op);
+    for _ in 0..10 {
+        let meta = fop.stat(&path).await?;
+        assert_eq!(meta.content_length(), size as u64);
+    }
+
+    Ok(())
+}
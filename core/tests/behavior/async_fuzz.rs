@@ -41,7 +41,7 @@ async fn test_fuzz_read(
         return Ok(());
     }
 
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
 
     let mut checker = ReadChecker::new(size, range.into());
 
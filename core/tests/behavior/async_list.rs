@@ -36,6 +36,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_list_dir,
             test_list_prefix,
             test_list_rich_dir,
+            test_list_with_max_entries,
             test_list_empty_dir,
             test_list_non_exist_dir,
             test_list_sub_dir,
@@ -44,6 +45,8 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_list_with_start_after,
             test_list_dir_with_recursive,
             test_list_dir_with_recursive_no_trailing_slash,
+            test_list_dir_with_glob,
+            test_list_dir_with_user_metadata,
             test_list_file_with_recursive,
             test_list_root_with_recursive,
             test_remove_all,
@@ -54,6 +57,10 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
         ))
     }
 
+    if cap.read && cap.write && cap.list && cap.list_with_limit && cap.list_with_start_token {
+        tests.extend(async_trials!(op, test_list_with_start_token))
+    }
+
     if cap.read && !cap.write && cap.list {
         tests.extend(async_trials!(op, test_list_only))
     }
@@ -66,10 +73,39 @@ pub async fn test_check(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List dir should return user metadata if the service supports it.
+pub async fn test_list_dir_with_user_metadata(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !(cap.write_with_user_metadata && cap.list_has_user_metadata) {
+        return Ok(());
+    }
+
+    let parent = gen_uuid().to_string();
+    let path = format!("{parent}/{}", gen_uuid());
+    let target_user_metadata = vec![("location".to_string(), "everywhere".to_string())];
+    op.write_with(&path, "Hello, World!")
+        .user_metadata(target_user_metadata.clone())
+        .await?;
+
+    let entry = op
+        .list(&format!("{parent}/"))
+        .await?
+        .into_iter()
+        .find(|e| e.path() == path)
+        .expect("written file must be listed");
+    assert_eq!(
+        entry.metadata().user_metadata(),
+        Some(&target_user_metadata.into_iter().collect::<HashMap<_, _>>())
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// List dir should return newly created file.
 pub async fn test_list_dir(op: Operator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
-    let path = format!("{parent}/{}", uuid::Uuid::new_v4());
+    let parent = gen_uuid().to_string();
+    let path = format!("{parent}/{}", gen_uuid());
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
 
@@ -95,7 +131,7 @@ pub async fn test_list_dir(op: Operator) -> Result<()> {
 
 /// List prefix should return newly created file.
 pub async fn test_list_prefix(op: Operator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     debug!("Generate a random file: {}", &path);
     let (content, _) = gen_bytes(op.info().full_capability());
 
@@ -127,7 +163,7 @@ pub async fn test_list_rich_dir(op: Operator) -> Result<()> {
     }
     expected.push(parent.to_string());
 
-    let mut objects = op.lister_with(parent).limit(5).await?;
+    let mut objects = op.lister_with(parent).chunk(5).await?;
     let mut actual = vec![];
     while let Some(o) = objects.try_next().await? {
         let path = o.path().to_string();
@@ -142,9 +178,33 @@ pub async fn test_list_rich_dir(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List with max_entries should stop after given entries.
+pub async fn test_list_with_max_entries(op: Operator) -> Result<()> {
+    let parent = format!("{}/", gen_uuid());
+    op.create_dir(&parent).await?;
+
+    for num in 0..5 {
+        op.write(&format!("{parent}file-{num}"), "test_list_with_max_entries")
+            .await?;
+    }
+
+    let entries = op.list_with(&parent).recursive(true).max_entries(3).await?;
+    assert_eq!(entries.len(), 3, "list must stop at max_entries");
+
+    let mut lister = op.lister_with(&parent).chunk(2).max_entries(4).await?;
+    let mut actual = vec![];
+    while let Some(o) = lister.try_next().await? {
+        actual.push(o.path().to_string());
+    }
+    assert_eq!(actual.len(), 4, "lister must stop at max_entries");
+
+    op.remove_all(&parent).await?;
+    Ok(())
+}
+
 /// List empty dir should return itself.
 pub async fn test_list_empty_dir(op: Operator) -> Result<()> {
-    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let dir = format!("{}/", gen_uuid());
 
     op.create_dir(&dir).await.expect("write must succeed");
 
@@ -221,7 +281,7 @@ pub async fn test_list_empty_dir(op: Operator) -> Result<()> {
 
 /// List non exist dir should return nothing.
 pub async fn test_list_non_exist_dir(op: Operator) -> Result<()> {
-    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let dir = format!("{}/", gen_uuid());
 
     let mut obs = op.lister(&dir).await?;
     let mut objects = HashMap::new();
@@ -236,7 +296,7 @@ pub async fn test_list_non_exist_dir(op: Operator) -> Result<()> {
 
 /// List dir should return correct sub dir.
 pub async fn test_list_sub_dir(op: Operator) -> Result<()> {
-    let path = format!("{}/", uuid::Uuid::new_v4());
+    let path = format!("{}/", gen_uuid());
 
     op.create_dir(&path).await.expect("create must succeed");
 
@@ -265,21 +325,21 @@ pub async fn test_list_sub_dir(op: Operator) -> Result<()> {
 
 /// List dir should also to list nested dir.
 pub async fn test_list_nested_dir(op: Operator) -> Result<()> {
-    let parent = format!("{}/", uuid::Uuid::new_v4());
+    let parent = format!("{}/", gen_uuid());
     op.create_dir(&parent)
         .await
         .expect("create dir must succeed");
 
-    let dir = format!("{parent}{}/", uuid::Uuid::new_v4());
+    let dir = format!("{parent}{}/", gen_uuid());
     op.create_dir(&dir).await.expect("create must succeed");
 
-    let file_name = uuid::Uuid::new_v4().to_string();
+    let file_name = gen_uuid().to_string();
     let file_path = format!("{dir}{file_name}");
     op.write(&file_path, "test_list_nested_dir")
         .await
         .expect("create must succeed");
 
-    let dir_name = format!("{}/", uuid::Uuid::new_v4());
+    let dir_name = format!("{}/", gen_uuid());
     let dir_path = format!("{dir}{dir_name}");
     op.create_dir(&dir_path).await.expect("create must succeed");
 
@@ -344,8 +404,8 @@ pub async fn test_list_nested_dir(op: Operator) -> Result<()> {
 
 /// List with path file should auto add / suffix.
 pub async fn test_list_dir_with_file_path(op: Operator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
-    let file = format!("{parent}/{}", uuid::Uuid::new_v4());
+    let parent = gen_uuid().to_string();
+    let file = format!("{parent}/{}", gen_uuid());
 
     let (content, _) = gen_bytes(op.info().full_capability());
     op.write(&file, content).await?;
@@ -366,12 +426,12 @@ pub async fn test_list_with_start_after(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let dir = &format!("{}/", uuid::Uuid::new_v4());
+    let dir = &format!("{}/", gen_uuid());
     op.create_dir(dir).await?;
 
     let given: Vec<String> = ["file-0", "file-1", "file-2", "file-3", "file-4", "file-5"]
         .iter()
-        .map(|name| format!("{dir}{name}-{}", uuid::Uuid::new_v4()))
+        .map(|name| format!("{dir}{name}-{}", gen_uuid()))
         .collect();
 
     given
@@ -421,7 +481,7 @@ pub async fn test_list_root_with_recursive(op: Operator) -> Result<()> {
 
 // Walk top down should output as expected
 pub async fn test_list_dir_with_recursive(op: Operator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
 
     let paths = [
         "x/", "x/x/", "x/x/x/", "x/x/x/x/", "x/x/x/y", "x/x/y", "x/y", "x/yy",
@@ -457,9 +517,83 @@ pub async fn test_list_dir_with_recursive(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List with start_token should resume from the page returned before.
+pub async fn test_list_with_start_token(op: Operator) -> Result<()> {
+    let dir = &format!("{}/", gen_uuid());
+
+    let mut expected = (0..5).map(|i| format!("{dir}file-{i}")).collect::<Vec<_>>();
+    for path in expected.iter() {
+        op.write(path, "test_list_with_start_token").await?;
+    }
+
+    let mut lister = op.lister_with(dir).chunk(2).await?;
+    let (mut actual, token) = lister.next_page().await?;
+    let token = token.expect("token must be returned since there are more entries");
+    drop(lister);
+
+    let mut lister = op.lister_with(dir).chunk(2).start_token(&token).await?;
+    loop {
+        let (entries, token) = lister.next_page().await?;
+        actual.extend(entries);
+        if token.is_none() {
+            break;
+        }
+    }
+
+    let mut actual = actual
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .filter(|v| v != dir)
+        .collect::<Vec<_>>();
+    actual.sort();
+    expected.sort();
+    assert_eq!(actual, expected);
+
+    op.remove_all(dir).await?;
+    Ok(())
+}
+
+/// List with glob should only return entries that match the pattern.
+pub async fn test_list_dir_with_glob(op: Operator) -> Result<()> {
+    let parent = gen_uuid().to_string();
+
+    let paths = ["x/a.txt", "x/b.csv", "x/y/c.txt", "x/y/d.csv"];
+    for path in paths.iter() {
+        op.write(&format!("{parent}/{path}"), "test_glob").await?;
+    }
+
+    let collect = |entries: Vec<Entry>| {
+        let mut actual = entries
+            .into_iter()
+            .map(|v| {
+                v.path()
+                    .strip_prefix(&format!("{parent}/"))
+                    .unwrap()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        actual.sort();
+        actual
+    };
+
+    let entries = op
+        .list_with(&format!("{parent}/x/"))
+        .glob(&format!("{parent}/x/*.txt"))
+        .await?;
+    assert_eq!(collect(entries), vec!["x/a.txt"]);
+
+    let entries = op
+        .list_with(&format!("{parent}/x/"))
+        .recursive(true)
+        .glob(&format!("{parent}/**/*.csv"))
+        .await?;
+    assert_eq!(collect(entries), vec!["x/b.csv", "x/y/d.csv"]);
+    Ok(())
+}
+
 // same as test_list_dir_with_recursive except listing 'x' instead of 'x/'
 pub async fn test_list_dir_with_recursive_no_trailing_slash(op: Operator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
 
     let paths = [
         "x/", "x/x/", "x/x/x/", "x/x/x/x/", "x/x/x/y", "x/x/y", "x/y", "x/yy",
@@ -494,7 +628,7 @@ pub async fn test_list_dir_with_recursive_no_trailing_slash(op: Operator) -> Res
 }
 
 pub async fn test_list_file_with_recursive(op: Operator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
 
     let paths = ["y", "yy"];
     for path in paths.iter() {
@@ -528,7 +662,7 @@ pub async fn test_list_file_with_recursive(op: Operator) -> Result<()> {
 
 // Remove all should remove all in this path.
 pub async fn test_remove_all(op: Operator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
 
     let expected = [
         "x/", "x/y", "x/x/", "x/x/y", "x/x/x/", "x/x/x/y", "x/x/x/x/",
@@ -661,7 +795,7 @@ pub async fn test_list_with_versions_and_limit(op: Operator) -> Result<()> {
         .collect();
     expected.push(parent.to_string());
 
-    let mut objects = op.lister_with(parent).versions(true).limit(5).await?;
+    let mut objects = op.lister_with(parent).versions(true).chunk(5).await?;
     let mut actual = vec![];
     while let Some(o) = objects.try_next().await? {
         let path = o.path().to_string();
@@ -681,11 +815,11 @@ pub async fn test_list_with_versions_and_start_after(op: Operator) -> Result<()>
         return Ok(());
     }
 
-    let dir = &format!("{}/", uuid::Uuid::new_v4());
+    let dir = &format!("{}/", gen_uuid());
 
     let given: Vec<String> = ["file-0", "file-1", "file-2", "file-3", "file-4", "file-5"]
         .iter()
-        .map(|name| format!("{dir}{name}-{}", uuid::Uuid::new_v4()))
+        .map(|name| format!("{dir}{name}-{}", gen_uuid()))
         .collect();
 
     given
@@ -39,11 +39,15 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_presign_stat
         ))
     }
+
+    if cap.write && cap.presign_write_multi {
+        tests.extend(async_trials!(op, test_presign_write_multi))
+    }
 }
 
 /// Presign write should succeed.
 pub async fn test_presign_write(op: Operator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
 
@@ -75,7 +79,7 @@ pub async fn test_presign_write(op: Operator) -> Result<()> {
 }
 
 pub async fn test_presign_stat(op: Operator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
     op.write(&path, content.clone())
@@ -105,7 +109,7 @@ pub async fn test_presign_stat(op: Operator) -> Result<()> {
 
 // Presign read should read content successfully.
 pub async fn test_presign_read(op: Operator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
 
@@ -138,3 +142,80 @@ pub async fn test_presign_read(op: Operator) -> Result<()> {
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Presign initiate multipart, upload part and complete multipart should succeed.
+pub async fn test_presign_write_multi(op: Operator) -> Result<()> {
+    let path = gen_uuid().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+    let content_length = content.len() as u64;
+
+    let client = reqwest::Client::new();
+
+    let signed_req = op
+        .presign_initiate_multipart(&path, Duration::from_secs(3600))
+        .await?;
+    debug!("Generated request: {signed_req:?}");
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+    let resp = req.send().await.expect("send request must succeed");
+    assert_eq!(resp.status(), http::StatusCode::OK, "initiate multipart");
+
+    let body = resp.text().await.expect("read response must succeed");
+    let upload_id = body
+        .split("<UploadId>")
+        .nth(1)
+        .and_then(|v| v.split("</UploadId>").next())
+        .expect("upload id must be returned");
+
+    let signed_req = op
+        .presign_upload_part_with(&path, upload_id, 1, Duration::from_secs(3600))
+        .content_length(content_length)
+        .await?;
+    debug!("Generated request: {signed_req:?}");
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+    req = req.body(reqwest::Body::from(content));
+    let resp = req.send().await.expect("send request must succeed");
+    assert_eq!(resp.status(), http::StatusCode::OK, "upload part");
+    let etag = resp
+        .headers()
+        .get(header::ETAG)
+        .expect("upload part must return etag")
+        .to_str()
+        .expect("etag must be valid")
+        .to_string();
+
+    let signed_req = op
+        .presign_complete_multipart(&path, upload_id, Duration::from_secs(3600))
+        .await?;
+    debug!("Generated request: {signed_req:?}");
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+    req = req.body(format!(
+        "<CompleteMultipartUpload><Part><PartNumber>1</PartNumber><ETag>{etag}</ETag></Part></CompleteMultipartUpload>"
+    ));
+    let resp = req.send().await.expect("send request must succeed");
+    assert_eq!(resp.status(), http::StatusCode::OK, "complete multipart");
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), content_length, "uploaded size");
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
@@ -36,6 +36,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             op,
             test_read_full,
             test_read_range,
+            test_read_opts,
             test_reader,
             test_reader_with_if_match,
             test_reader_with_if_none_match,
@@ -52,10 +53,17 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_read_with_override_content_disposition,
             test_read_with_override_content_type,
             test_read_with_version,
-            test_read_with_not_existing_version
+            test_read_with_not_existing_version,
+            test_read_to_writer,
+            test_write_from_reader,
+            test_copy_to
         ))
     }
 
+    if cap.read && cap.write && cap.list {
+        tests.extend(async_trials!(op, test_copy_all_to_with_limit))
+    }
+
     if cap.read && !cap.write {
         tests.extend(async_trials!(
             op,
@@ -91,6 +99,111 @@ pub async fn test_read_full(op: Operator) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Read into async writer should match.
+pub async fn test_read_to_writer(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let mut w = futures::io::Cursor::new(Vec::new());
+    let n = op
+        .read_to_writer_with(&path, &mut w)
+        .buffer_size(1024)
+        .verify_checksum(true)
+        .await?;
+    assert_eq!(n, size as u64, "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(w.get_ref())),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    Ok(())
+}
+
+/// Write from async reader should match.
+pub async fn test_write_from_reader(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    let n = op
+        .write_from_reader_with(&path, futures::io::Cursor::new(content.clone()))
+        .buffer_size(1024)
+        .verify_checksum(true)
+        .await?;
+    assert_eq!(n, size as u64, "write size");
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    Ok(())
+}
+
+/// Copy to another path via streaming should match.
+pub async fn test_copy_to(op: Operator) -> anyhow::Result<()> {
+    let (source_path, content, size) = TEST_FIXTURE.new_file(op.clone());
+    let target_path = TEST_FIXTURE.new_file_path();
+
+    op.write(&source_path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let n = op
+        .copy_to_with(&source_path, &op, &target_path)
+        .verify_checksum(true)
+        .await?;
+    assert_eq!(n, size as u64, "copy size");
+
+    let bs = op.read(&target_path).await?.to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    Ok(())
+}
+
+/// Copy all files under a dir with concurrent tasks should match.
+pub async fn test_copy_all_to_with_limit(op: Operator) -> anyhow::Result<()> {
+    let source_dir = TEST_FIXTURE.new_dir_path();
+    let target_dir = TEST_FIXTURE.new_dir_path();
+
+    let mut expected = 0;
+    for path in ["a", "b/c", "b/d/e", "f"] {
+        let (content, size) = gen_bytes(op.info().full_capability());
+        op.write(&format!("{source_dir}{path}"), content)
+            .await
+            .expect("write must succeed");
+        expected += size as u64;
+    }
+
+    let n = op
+        .with_limit(4)
+        .copy_all_to(&source_dir, &op, &target_dir)
+        .await?;
+    assert_eq!(n, expected, "copy size");
+
+    for path in ["a", "b/c", "b/d/e", "f"] {
+        let source = op.read(&format!("{source_dir}{path}")).await?.to_bytes();
+        let target = op.read(&format!("{target_dir}{path}")).await?.to_bytes();
+        assert_eq!(
+            format!("{:x}", Sha256::digest(&source)),
+            format!("{:x}", Sha256::digest(&target)),
+            "read content of {path}"
+        );
+    }
+
+    op.remove_all(&source_dir).await?;
+    op.remove_all(&target_dir).await?;
+    Ok(())
+}
+
 /// Read range content should match.
 pub async fn test_read_range(op: Operator) -> anyhow::Result<()> {
     let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
@@ -118,6 +231,30 @@ pub async fn test_read_range(op: Operator) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Read range content with typed options should match.
+pub async fn test_read_opts(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+    let (offset, length) = gen_offset_length(size);
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let opts = ReadOptions::default().with_offset(offset).with_size(length);
+    let bs = op.read_opts(&path, opts).await?.to_bytes();
+    assert_eq!(bs.len() as u64, length, "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!(
+            "{:x}",
+            Sha256::digest(&content[offset as usize..(offset + length) as usize])
+        ),
+        "read content"
+    );
+
+    Ok(())
+}
+
 /// Read full content should match.
 pub async fn test_reader(op: Operator) -> anyhow::Result<()> {
     let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
@@ -173,7 +310,7 @@ pub async fn test_reader(op: Operator) -> anyhow::Result<()> {
 
 /// Read not exist file should return NotFound
 pub async fn test_read_not_exist(op: Operator) -> anyhow::Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
 
     let bs = op.read(&path).await;
     assert!(bs.is_err());
@@ -394,7 +531,7 @@ pub async fn test_read_with_special_chars(op: Operator) -> anyhow::Result<()> {
         return Ok(());
     }
 
-    let path = format!("{} !@#$%^&()_+-=;',.txt", uuid::Uuid::new_v4());
+    let path = format!("{} !@#$%^&()_+-=;',.txt", gen_uuid());
     let (path, content, size) = TEST_FIXTURE.new_file_with_path(op.clone(), &path);
 
     op.write(&path, content.clone())
@@ -664,7 +801,7 @@ pub async fn test_read_only_read_with_range(op: Operator) -> anyhow::Result<()>
 
 /// Read not exist file should return NotFound
 pub async fn test_read_only_read_not_exist(op: Operator) -> anyhow::Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
 
     let bs = op.read(&path).await;
     assert!(bs.is_err());
@@ -675,7 +812,7 @@ pub async fn test_read_only_read_not_exist(op: Operator) -> anyhow::Result<()> {
 
 /// Read with dir path should return an error.
 pub async fn test_read_only_read_with_dir_path(op: Operator) -> anyhow::Result<()> {
-    let path = format!("{}/", uuid::Uuid::new_v4());
+    let path = format!("{}/", gen_uuid());
 
     let result = op.read(&path).await;
     assert!(result.is_err());
@@ -40,12 +40,12 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
 
 /// Rename a file and test with stat.
 pub async fn test_rename_file(op: Operator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone()).await?;
 
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let target_path = gen_uuid().to_string();
 
     op.rename(&source_path, &target_path).await?;
 
@@ -69,8 +69,8 @@ pub async fn test_rename_file(op: Operator) -> Result<()> {
 
 /// Rename a nonexistent source should return an error.
 pub async fn test_rename_non_existing_source(op: Operator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
+    let target_path = gen_uuid().to_string();
 
     let err = op
         .rename(&source_path, &target_path)
@@ -86,8 +86,8 @@ pub async fn test_rename_source_dir(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let source_path = format!("{}/", uuid::Uuid::new_v4());
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let source_path = format!("{}/", gen_uuid());
+    let target_path = gen_uuid().to_string();
 
     op.create_dir(&source_path).await?;
 
@@ -105,12 +105,12 @@ pub async fn test_rename_target_dir(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, content).await?;
 
-    let target_path = format!("{}/", uuid::Uuid::new_v4());
+    let target_path = format!("{}/", gen_uuid());
 
     op.create_dir(&target_path).await?;
 
@@ -127,7 +127,7 @@ pub async fn test_rename_target_dir(op: Operator) -> Result<()> {
 
 /// Rename a file to self should return an error.
 pub async fn test_rename_self(op: Operator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, content).await?;
@@ -144,17 +144,12 @@ pub async fn test_rename_self(op: Operator) -> Result<()> {
 
 /// Rename to a nested path, parent path should be created successfully.
 pub async fn test_rename_nested(op: Operator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone()).await?;
 
-    let target_path = format!(
-        "{}/{}/{}",
-        uuid::Uuid::new_v4(),
-        uuid::Uuid::new_v4(),
-        uuid::Uuid::new_v4()
-    );
+    let target_path = format!("{}/{}/{}", gen_uuid(), gen_uuid(), gen_uuid());
 
     op.rename(&source_path, &target_path).await?;
 
@@ -178,12 +173,12 @@ pub async fn test_rename_nested(op: Operator) -> Result<()> {
 
 /// Rename to a exist path should overwrite successfully.
 pub async fn test_rename_overwrite(op: Operator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone()).await?;
 
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let target_path = gen_uuid().to_string();
     let (target_content, _) = gen_bytes(op.info().full_capability());
     assert_ne!(source_content, target_content);
 
@@ -46,7 +46,8 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_stat_with_override_content_type,
             test_stat_root,
             test_stat_with_version,
-            stat_with_not_existing_version
+            stat_with_not_existing_version,
+            test_stat_with_delete_marker_version
         ))
     }
 
@@ -113,8 +114,8 @@ pub async fn test_stat_nested_parent_dir(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let parent = format!("{}", uuid::Uuid::new_v4());
-    let file = format!("{}", uuid::Uuid::new_v4());
+    let parent = format!("{}", gen_uuid());
+    let file = format!("{}", gen_uuid());
     let (path, content, _) =
         TEST_FIXTURE.new_file_with_path(op.clone(), &format!("{parent}/{file}"));
 
@@ -141,7 +142,7 @@ pub async fn test_stat_with_special_chars(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let path = format!("{} !@#$%^&()_+-=;',.txt", uuid::Uuid::new_v4());
+    let path = format!("{} !@#$%^&()_+-=;',.txt", gen_uuid());
     let (path, content, size) = TEST_FIXTURE.new_file_with_path(op.clone(), &path);
 
     op.write(&path, content).await.expect("write must succeed");
@@ -168,7 +169,7 @@ pub async fn test_stat_not_cleaned_path(op: Operator) -> Result<()> {
 
 /// Stat not exist file should return NotFound
 pub async fn test_stat_not_exist(op: Operator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
 
     // Stat not exist file should return NotFound.
     let meta = op.stat(&path).await;
@@ -493,7 +494,7 @@ pub async fn test_read_only_stat_not_cleaned_path(op: Operator) -> Result<()> {
 
 /// Stat not exist file should return NotFound
 pub async fn test_read_only_stat_not_exist(op: Operator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
 
     let meta = op.stat(&path).await;
     assert!(meta.is_err());
@@ -630,3 +631,37 @@ pub async fn stat_with_not_existing_version(op: Operator) -> Result<()> {
 
     Ok(())
 }
+
+pub async fn test_stat_with_delete_marker_version(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !(cap.stat_with_version && cap.list_with_versions && cap.list_with_deleted) {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(path.as_str(), content)
+        .await
+        .expect("write must success");
+    op.delete(path.as_str()).await.expect("delete must success");
+
+    // Deleting in a versioned bucket creates a delete marker as the current version.
+    let ret = op.stat(path.as_str()).await;
+    assert_eq!(ret.unwrap_err().kind(), ErrorKind::NotFound);
+
+    let entries = op
+        .list_with(path.as_str())
+        .versions(true)
+        .deleted(true)
+        .await?;
+    let marker = entries
+        .iter()
+        .find(|e| e.path() == path && e.metadata().is_deleted())
+        .expect("delete marker must be listed");
+    assert_eq!(marker.metadata().is_current(), Some(true));
+
+    let version = marker.metadata().version().expect("must have version");
+    let ret = op.stat_with(path.as_str()).version(version).await;
+    assert_eq!(ret.unwrap_err().kind(), ErrorKind::NotFound);
+
+    Ok(())
+}
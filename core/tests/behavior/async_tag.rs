@@ -0,0 +1,90 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.tag {
+        tests.extend(async_trials!(
+            op,
+            test_tag_set_and_get,
+            test_tag_overwrite,
+            test_tag_delete
+        ))
+    }
+}
+
+/// Set tags on a file and read them back.
+pub async fn test_tag_set_and_get(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    let tags = HashMap::from([
+        ("project".to_string(), "opendal".to_string()),
+        ("env".to_string(), "test".to_string()),
+    ]);
+    op.set_tags(&path, tags.clone()).await?;
+
+    let actual = op.tags(&path).await?;
+    assert_eq!(actual, tags);
+
+    Ok(())
+}
+
+/// Setting tags must replace all existing tags.
+pub async fn test_tag_overwrite(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    op.set_tags(
+        &path,
+        HashMap::from([("project".to_string(), "opendal".to_string())]),
+    )
+    .await?;
+
+    let tags = HashMap::from([("env".to_string(), "test".to_string())]);
+    op.set_tags(&path, tags.clone()).await?;
+
+    let actual = op.tags(&path).await?;
+    assert_eq!(actual, tags);
+
+    Ok(())
+}
+
+/// Delete tags must remove all tags of the file.
+pub async fn test_tag_delete(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    op.set_tags(
+        &path,
+        HashMap::from([("project".to_string(), "opendal".to_string())]),
+    )
+    .await?;
+    op.delete_tags(&path).await?;
+
+    let actual = op.tags(&path).await?;
+    assert!(actual.is_empty(), "tags must be empty after delete");
+
+    Ok(())
+}
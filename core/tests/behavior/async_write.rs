@@ -16,6 +16,7 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use anyhow::Result;
 use bytes::Bytes;
@@ -28,6 +29,7 @@ use futures::StreamExt;
 use log::warn;
 use sha2::Digest;
 use sha2::Sha256;
+use tokio::time::sleep;
 
 use crate::*;
 
@@ -43,12 +45,15 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_write_with_special_chars,
             test_write_with_cache_control,
             test_write_with_content_type,
+            test_write_opts,
             test_write_with_content_disposition,
             test_write_with_content_encoding,
             test_write_with_if_none_match,
             test_write_with_if_not_exists,
+            test_write_with_expire_after,
             test_write_with_if_match,
             test_write_with_user_metadata,
+            test_write_with_permissions,
             test_writer_write,
             test_writer_write_with_overwrite,
             test_writer_write_with_concurrent,
@@ -127,7 +132,7 @@ pub async fn test_write_with_special_chars(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let path = format!("{} !@#$%^&()_+-=;',.txt", uuid::Uuid::new_v4());
+    let path = format!("{} !@#$%^&()_+-=;',.txt", gen_uuid());
     let (path, content, size) = TEST_FIXTURE.new_file_with_path(op.clone(), &path);
 
     op.write(&path, content).await?;
@@ -144,7 +149,7 @@ pub async fn test_write_with_cache_control(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     let (content, _) = gen_bytes(op.info().full_capability());
 
     let target_cache_control = "no-cache, no-store, max-age=300";
@@ -188,6 +193,28 @@ pub async fn test_write_with_content_type(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a single file with typed options should succeed.
+pub async fn test_write_opts(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_content_type {
+        return Ok(());
+    }
+
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    let target_content_type = "application/json";
+    let opts = WriteOptions::default().with_content_type(target_content_type);
+    op.write_opts(&path, content, opts).await?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(
+        meta.content_type().expect("content type must exist"),
+        target_content_type
+    );
+    assert_eq!(meta.content_length(), size as u64);
+
+    Ok(())
+}
+
 /// Write a single file with content disposition should succeed.
 pub async fn test_write_with_content_disposition(op: Operator) -> Result<()> {
     if !op.info().full_capability().write_with_content_disposition {
@@ -257,6 +284,23 @@ pub async fn test_write_with_user_metadata(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// write a single file with permissions should succeed.
+pub async fn test_write_with_permissions(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_permissions {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write_with(&path, content).permissions(0o600).await?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    if op.info().full_capability().stat_has_permissions {
+        assert_eq!(meta.permissions(), Some(0o600));
+    }
+
+    Ok(())
+}
+
 /// Delete existing file should succeed.
 pub async fn test_writer_abort(op: Operator) -> Result<()> {
     let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
@@ -585,7 +629,7 @@ pub async fn test_write_with_append(op: Operator) -> Result<()> {
 
 /// Copy data from reader to writer
 pub async fn test_writer_with_append(op: Operator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     let (content, size): (Vec<u8>, usize) =
         gen_bytes_with_range(10 * 1024 * 1024..20 * 1024 * 1024);
 
@@ -621,7 +665,7 @@ pub async fn test_writer_write_with_overwrite(op: Operator) -> Result<()> {
         return Ok(());
     }
 
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     let (content_one, _) = gen_bytes(op.info().full_capability());
     let (content_two, _) = gen_bytes(op.info().full_capability());
 
@@ -699,6 +743,28 @@ pub async fn test_write_with_if_not_exists(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a file with expire_after and it must be gone after the duration.
+pub async fn test_write_with_expire_after(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_expire_after {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write_with(&path, content)
+        .expire_after(Duration::from_secs(1))
+        .await?;
+    assert!(op.exists(&path).await?);
+
+    sleep(Duration::from_secs(3)).await;
+    assert!(
+        !op.exists(&path).await?,
+        "file must be expired after expire_after elapsed"
+    );
+
+    Ok(())
+}
+
 /// Write an file with if_match will get a ConditionNotMatch error if file's etag does not match.
 pub async fn test_write_with_if_match(op: Operator) -> Result<()> {
     if !op.info().full_capability().write_with_if_match {
@@ -40,12 +40,12 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
 
 /// Copy a file and test with stat.
 pub fn test_blocking_copy_file(op: BlockingOperator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone())?;
 
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let target_path = gen_uuid().to_string();
 
     op.copy(&source_path, &target_path)?;
 
@@ -62,8 +62,8 @@ pub fn test_blocking_copy_file(op: BlockingOperator) -> Result<()> {
 
 /// Copy a nonexistent source should return an error.
 pub fn test_blocking_copy_non_existing_source(op: BlockingOperator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
+    let target_path = gen_uuid().to_string();
 
     let err = op
         .copy(&source_path, &target_path)
@@ -78,8 +78,8 @@ pub fn test_blocking_copy_source_dir(op: BlockingOperator) -> Result<()> {
         return Ok(());
     }
 
-    let source_path = format!("{}/", uuid::Uuid::new_v4());
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let source_path = format!("{}/", gen_uuid());
+    let target_path = gen_uuid().to_string();
 
     op.create_dir(&source_path)?;
 
@@ -96,12 +96,12 @@ pub fn test_blocking_copy_target_dir(op: BlockingOperator) -> Result<()> {
         return Ok(());
     }
 
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content)?;
 
-    let target_path = format!("{}/", uuid::Uuid::new_v4());
+    let target_path = format!("{}/", gen_uuid());
 
     op.create_dir(&target_path)?;
 
@@ -117,7 +117,7 @@ pub fn test_blocking_copy_target_dir(op: BlockingOperator) -> Result<()> {
 
 /// Copy a file to self should return an error.
 pub fn test_blocking_copy_self(op: BlockingOperator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _size) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content)?;
@@ -133,17 +133,12 @@ pub fn test_blocking_copy_self(op: BlockingOperator) -> Result<()> {
 
 /// Copy to a nested path, parent path should be created successfully.
 pub fn test_blocking_copy_nested(op: BlockingOperator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone())?;
 
-    let target_path = format!(
-        "{}/{}/{}",
-        uuid::Uuid::new_v4(),
-        uuid::Uuid::new_v4(),
-        uuid::Uuid::new_v4()
-    );
+    let target_path = format!("{}/{}/{}", gen_uuid(), gen_uuid(), gen_uuid());
 
     op.copy(&source_path, &target_path)?;
 
@@ -160,12 +155,12 @@ pub fn test_blocking_copy_nested(op: BlockingOperator) -> Result<()> {
 
 /// Copy to a exist path should overwrite successfully.
 pub fn test_blocking_copy_overwrite(op: BlockingOperator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone())?;
 
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let target_path = gen_uuid().to_string();
     let (target_content, _) = gen_bytes(op.info().full_capability());
     assert_ne!(source_content, target_content);
 
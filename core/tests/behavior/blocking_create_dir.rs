@@ -37,7 +37,7 @@ pub fn test_blocking_create_dir(op: BlockingOperator) -> Result<()> {
         return Ok(());
     }
 
-    let path = format!("{}/", uuid::Uuid::new_v4());
+    let path = format!("{}/", gen_uuid());
 
     op.create_dir(&path)?;
 
@@ -54,7 +54,7 @@ pub fn test_blocking_create_dir_existing(op: BlockingOperator) -> Result<()> {
         return Ok(());
     }
 
-    let path = format!("{}/", uuid::Uuid::new_v4());
+    let path = format!("{}/", gen_uuid());
 
     op.create_dir(&path)?;
 
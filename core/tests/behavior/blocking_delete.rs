@@ -43,7 +43,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
 
 // Delete existing file should succeed.
 pub fn test_blocking_delete_file(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     debug!("Generate a random file: {}", &path);
     let (content, _) = gen_bytes(op.info().full_capability());
 
@@ -59,7 +59,7 @@ pub fn test_blocking_delete_file(op: BlockingOperator) -> Result<()> {
 
 /// Remove one file
 pub fn test_blocking_remove_one_file(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     let (content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&path, content).expect("write must succeed");
@@ -100,13 +100,13 @@ fn test_blocking_remove_all_with_objects(
 
 /// Remove all under a prefix
 pub fn test_blocking_remove_all_basic(op: BlockingOperator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
     test_blocking_remove_all_with_objects(op, parent, ["a/b", "a/c", "a/d/e"])
 }
 
 /// Remove all under a prefix, while the prefix itself is also an object
 pub fn test_blocking_remove_all_with_prefix_exists(op: BlockingOperator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
     let (content, _) = gen_bytes(op.info().full_capability());
     op.write(&parent, content).expect("write must succeed");
     test_blocking_remove_all_with_objects(op, parent, ["a", "a/b", "a/c", "a/b/e"])
@@ -40,8 +40,8 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
 
 /// List dir should return newly created file.
 pub fn test_blocking_list_dir(op: BlockingOperator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
-    let path = format!("{parent}/{}", uuid::Uuid::new_v4());
+    let parent = gen_uuid().to_string();
+    let path = format!("{parent}/{}", gen_uuid());
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
 
@@ -68,7 +68,7 @@ pub fn test_blocking_list_dir(op: BlockingOperator) -> Result<()> {
 
 /// List non exist dir should return nothing.
 pub fn test_blocking_list_non_exist_dir(op: BlockingOperator) -> Result<()> {
-    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let dir = format!("{}/", gen_uuid());
 
     let obs = op.lister(&dir)?;
     let mut objects = HashMap::new();
@@ -84,7 +84,7 @@ pub fn test_blocking_list_non_exist_dir(op: BlockingOperator) -> Result<()> {
 
 // Remove all should remove all in this path.
 pub fn test_blocking_remove_all(op: BlockingOperator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
 
     let expected = [
         "x/", "x/y", "x/x/", "x/x/y", "x/x/x/", "x/x/x/y", "x/x/x/x/",
@@ -115,7 +115,7 @@ pub fn test_blocking_remove_all(op: BlockingOperator) -> Result<()> {
 
 // Walk top down should output as expected
 pub fn test_blocking_list_dir_with_recursive(op: BlockingOperator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
 
     let paths = [
         "x/", "x/x/", "x/x/x/", "x/x/x/x/", "x/x/x/y", "x/x/y", "x/y", "x/yy",
@@ -153,7 +153,7 @@ pub fn test_blocking_list_dir_with_recursive(op: BlockingOperator) -> Result<()>
 // Walk top down should output as expected
 // same as test_list_dir_with_recursive except listing 'x' instead of 'x/'
 pub fn test_blocking_list_dir_with_recursive_no_trailing_slash(op: BlockingOperator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
 
     let paths = [
         "x/", "x/x/", "x/x/x/", "x/x/x/x/", "x/x/x/y", "x/x/y", "x/y", "x/yy",
@@ -189,7 +189,7 @@ pub fn test_blocking_list_dir_with_recursive_no_trailing_slash(op: BlockingOpera
 // Walk top down should output as expected
 // same as test_list_dir_with_recursive except listing 'x' instead of 'x/'
 pub fn test_blocking_list_file_with_recursive(op: BlockingOperator) -> Result<()> {
-    let parent = uuid::Uuid::new_v4().to_string();
+    let parent = gen_uuid().to_string();
 
     let paths = ["y", "yy"];
     for path in paths.iter() {
@@ -30,6 +30,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             op,
             test_blocking_read_full,
             test_blocking_read_range,
+            test_blocking_read_opts,
             test_blocking_read_not_exist
         ))
     }
@@ -49,7 +50,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
 
 /// Read full content should match.
 pub fn test_blocking_read_full(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
 
@@ -70,7 +71,7 @@ pub fn test_blocking_read_full(op: BlockingOperator) -> Result<()> {
 
 /// Read range content should match.
 pub fn test_blocking_read_range(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
     let (offset, length) = gen_offset_length(size);
@@ -97,9 +98,41 @@ pub fn test_blocking_read_range(op: BlockingOperator) -> Result<()> {
     Ok(())
 }
 
+/// Read range content with typed options should match.
+pub fn test_blocking_read_opts(op: BlockingOperator) -> Result<()> {
+    let path = gen_uuid().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+    let (offset, length) = gen_offset_length(size);
+
+    op.write(&path, content.clone())
+        .expect("write must succeed");
+
+    let opts = ReadOptions::default()
+        .with_offset(offset)
+        .with_size(length)
+        .with_chunk(1024);
+    let bs = op.read_opts(&path, opts)?.to_bytes();
+    assert_eq!(bs.len() as u64, length, "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!(
+            "{:x}",
+            Sha256::digest(&content[offset as usize..(offset + length) as usize])
+        ),
+        "read content"
+    );
+
+    let res = op.read_opts(&path, ReadOptions::default().with_concurrent(4));
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+    op.delete(&path).expect("delete must succeed");
+    Ok(())
+}
+
 /// Read not exist file should return NotFound
 pub fn test_blocking_read_not_exist(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
 
     let bs = op.read(&path);
     assert!(bs.is_err());
@@ -134,7 +167,7 @@ pub fn test_blocking_read_only_stat_special_chars(op: BlockingOperator) -> Resul
 
 /// Stat not exist file should return NotFound
 pub fn test_blocking_read_only_stat_not_exist(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
 
     let meta = op.stat(&path);
     assert!(meta.is_err());
@@ -175,7 +208,7 @@ pub fn test_blocking_read_only_read_with_range(op: BlockingOperator) -> Result<(
 
 /// Read not exist file should return NotFound
 pub fn test_blocking_read_only_read_not_exist(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
 
     let bs = op.read(&path);
     assert!(bs.is_err());
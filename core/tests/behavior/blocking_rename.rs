@@ -40,12 +40,12 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
 
 /// Rename a file and test with stat.
 pub fn test_blocking_rename_file(op: BlockingOperator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone())?;
 
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let target_path = gen_uuid().to_string();
 
     op.rename(&source_path, &target_path)?;
 
@@ -65,8 +65,8 @@ pub fn test_blocking_rename_file(op: BlockingOperator) -> Result<()> {
 
 /// Rename a nonexistent source should return an error.
 pub fn test_blocking_rename_non_existing_source(op: BlockingOperator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
+    let target_path = gen_uuid().to_string();
 
     let err = op
         .rename(&source_path, &target_path)
@@ -81,8 +81,8 @@ pub fn test_blocking_rename_source_dir(op: BlockingOperator) -> Result<()> {
         return Ok(());
     }
 
-    let source_path = format!("{}/", uuid::Uuid::new_v4());
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let source_path = format!("{}/", gen_uuid());
+    let target_path = gen_uuid().to_string();
 
     op.create_dir(&source_path)?;
 
@@ -99,12 +99,12 @@ pub fn test_blocking_rename_target_dir(op: BlockingOperator) -> Result<()> {
         return Ok(());
     }
 
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content)?;
 
-    let target_path = format!("{}/", uuid::Uuid::new_v4());
+    let target_path = format!("{}/", gen_uuid());
 
     op.create_dir(&target_path)?;
 
@@ -120,7 +120,7 @@ pub fn test_blocking_rename_target_dir(op: BlockingOperator) -> Result<()> {
 
 /// Rename a file to self should return an error.
 pub fn test_blocking_rename_self(op: BlockingOperator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _size) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content)?;
@@ -136,17 +136,12 @@ pub fn test_blocking_rename_self(op: BlockingOperator) -> Result<()> {
 
 /// Rename to a nested path, parent path should be created successfully.
 pub fn test_blocking_rename_nested(op: BlockingOperator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone())?;
 
-    let target_path = format!(
-        "{}/{}/{}",
-        uuid::Uuid::new_v4(),
-        uuid::Uuid::new_v4(),
-        uuid::Uuid::new_v4()
-    );
+    let target_path = format!("{}/{}/{}", gen_uuid(), gen_uuid(), gen_uuid());
 
     op.rename(&source_path, &target_path)?;
 
@@ -166,12 +161,12 @@ pub fn test_blocking_rename_nested(op: BlockingOperator) -> Result<()> {
 
 /// Rename to a exist path should overwrite successfully.
 pub fn test_blocking_rename_overwrite(op: BlockingOperator) -> Result<()> {
-    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_path = gen_uuid().to_string();
     let (source_content, _) = gen_bytes(op.info().full_capability());
 
     op.write(&source_path, source_content.clone())?;
 
-    let target_path = uuid::Uuid::new_v4().to_string();
+    let target_path = gen_uuid().to_string();
     let (target_content, _) = gen_bytes(op.info().full_capability());
     assert_ne!(source_content, target_content);
 
@@ -37,7 +37,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
 
 /// Stat existing file should return metadata
 pub fn test_blocking_stat_file(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
 
@@ -57,7 +57,7 @@ pub fn test_blocking_stat_dir(op: BlockingOperator) -> Result<()> {
         return Ok(());
     }
 
-    let path = format!("{}/", uuid::Uuid::new_v4());
+    let path = format!("{}/", gen_uuid());
 
     op.create_dir(&path).expect("write must succeed");
 
@@ -81,7 +81,7 @@ pub fn test_blocking_stat_with_special_chars(op: BlockingOperator) -> Result<()>
         return Ok(());
     }
 
-    let path = format!("{} !@#$%^&()_+-=;',.txt", uuid::Uuid::new_v4());
+    let path = format!("{} !@#$%^&()_+-=;',.txt", gen_uuid());
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
 
@@ -97,7 +97,7 @@ pub fn test_blocking_stat_with_special_chars(op: BlockingOperator) -> Result<()>
 
 /// Stat not exist file should return NotFound
 pub fn test_blocking_stat_not_exist(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
 
     let meta = op.stat(&path);
     assert!(meta.is_err());
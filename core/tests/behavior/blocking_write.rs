@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::io::BufReader;
 use std::io::Cursor;
 
@@ -34,7 +35,9 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             op,
             test_blocking_write_file,
             test_blocking_write_with_dir_path,
-            test_blocking_write_with_special_chars
+            test_blocking_write_with_special_chars,
+            test_blocking_write_with_if_not_exists,
+            test_blocking_write_with_user_metadata
         ))
     }
 
@@ -49,7 +52,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
 
 /// Write a single file and test with stat.
 pub fn test_blocking_write_file(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
 
@@ -62,9 +65,56 @@ pub fn test_blocking_write_file(op: BlockingOperator) -> Result<()> {
     Ok(())
 }
 
+/// Write an file with if_not_exists will get a ConditionNotMatch error if file exists.
+pub fn test_blocking_write_with_if_not_exists(op: BlockingOperator) -> Result<()> {
+    if !op.info().full_capability().write_with_if_not_exists {
+        return Ok(());
+    }
+
+    let path = gen_uuid().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write_with(&path, content.clone())
+        .if_not_exists(true)
+        .call()
+        .expect("write must succeed");
+
+    let res = op.write_with(&path, content).if_not_exists(true).call();
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    op.delete(&path).expect("delete must succeed");
+    Ok(())
+}
+
+/// Write a file with user metadata and it must be returned by stat.
+pub fn test_blocking_write_with_user_metadata(op: BlockingOperator) -> Result<()> {
+    if !op.info().full_capability().write_with_user_metadata {
+        return Ok(());
+    }
+
+    let path = gen_uuid().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+    let target_user_metadata = vec![("location".to_string(), "everywhere".to_string())];
+
+    op.write_with(&path, content)
+        .user_metadata(target_user_metadata.clone())
+        .call()?;
+
+    let meta = op.stat(&path).expect("stat must succeed");
+    let resp_meta = meta.user_metadata().expect("meta data must exist");
+    assert_eq!(
+        *resp_meta,
+        target_user_metadata.into_iter().collect::<HashMap<_, _>>()
+    );
+
+    op.delete(&path).expect("delete must succeed");
+    Ok(())
+}
+
 /// Write file with dir path should return an error
 pub fn test_blocking_write_with_dir_path(op: BlockingOperator) -> Result<()> {
-    let path = format!("{}/", uuid::Uuid::new_v4());
+    let path = format!("{}/", gen_uuid());
     let (content, _) = gen_bytes(op.info().full_capability());
 
     let result = op.write(&path, content);
@@ -87,7 +137,7 @@ pub fn test_blocking_write_with_special_chars(op: BlockingOperator) -> Result<()
         return Ok(());
     }
 
-    let path = format!("{} !@#$%^&()_+-=;',.txt", uuid::Uuid::new_v4());
+    let path = format!("{} !@#$%^&()_+-=;',.txt", gen_uuid());
     debug!("Generate a random file: {}", &path);
     let (content, size) = gen_bytes(op.info().full_capability());
 
@@ -102,7 +152,7 @@ pub fn test_blocking_write_with_special_chars(op: BlockingOperator) -> Result<()
 
 /// Test append to a file must success.
 pub fn test_blocking_write_with_append(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     let (content_one, size_one) = gen_bytes(op.info().full_capability());
     let (content_two, size_two) = gen_bytes(op.info().full_capability());
 
@@ -129,7 +179,7 @@ pub fn test_blocking_write_with_append(op: BlockingOperator) -> Result<()> {
 
 /// Copy data from reader to writer
 pub fn test_blocking_writer_with_append(op: BlockingOperator) -> Result<()> {
-    let path = uuid::Uuid::new_v4().to_string();
+    let path = gen_uuid().to_string();
     let (content, size): (Vec<u8>, usize) =
         gen_bytes_with_range(10 * 1024 * 1024..20 * 1024 * 1024);
 
@@ -21,15 +21,19 @@ mod utils;
 
 pub use utils::*;
 
+mod async_concat;
+mod async_consistency;
 mod async_copy;
 mod async_create_dir;
 mod async_delete;
+mod async_fault;
 // mod async_fuzz;
 mod async_list;
 mod async_presign;
 mod async_read;
 mod async_rename;
 mod async_stat;
+mod async_tag;
 mod async_write;
 
 // Blocking test cases
@@ -45,16 +49,23 @@ mod blocking_write;
 // External dependencies
 use libtest_mimic::Arguments;
 use libtest_mimic::Trial;
-use opendal::raw::tests::init_test_service;
+use opendal::raw::tests::init_test_service_with_http_client;
 use opendal::raw::tests::TEST_RUNTIME;
 use opendal::*;
 
 pub static TEST_FIXTURE: Fixture = Fixture::new();
 
 fn main() -> anyhow::Result<()> {
-    let args = Arguments::from_args();
+    let mut args = Arguments::from_args();
 
-    let op = if let Some(op) = init_test_service()? {
+    let http_client = init_http_fixture_client()?;
+    // Http fixtures are assigned to the running test case, so test cases
+    // must run one by one.
+    if http_client.is_some() {
+        args.test_threads = Some(1);
+    }
+
+    let op = if let Some(op) = init_test_service_with_http_client(http_client)? {
         op
     } else {
         return Ok(());
@@ -62,14 +73,18 @@ fn main() -> anyhow::Result<()> {
 
     let mut tests = Vec::new();
 
+    async_concat::tests(&op, &mut tests);
+    async_consistency::tests(&op, &mut tests);
     async_copy::tests(&op, &mut tests);
     async_create_dir::tests(&op, &mut tests);
     async_delete::tests(&op, &mut tests);
+    async_fault::tests(&op, &mut tests);
     async_list::tests(&op, &mut tests);
     async_presign::tests(&op, &mut tests);
     async_read::tests(&op, &mut tests);
     async_rename::tests(&op, &mut tests);
     async_stat::tests(&op, &mut tests);
+    async_tag::tests(&op, &mut tests);
     async_write::tests(&op, &mut tests);
 
     blocking_copy::tests(&op, &mut tests);
@@ -92,6 +107,7 @@ fn main() -> anyhow::Result<()> {
     let conclusion = libtest_mimic::run(&args, tests);
 
     // Cleanup the fixtures.
+    set_http_fixture_test(None);
     TEST_RUNTIME.block_on(TEST_FIXTURE.cleanup(op));
 
     conclusion.exit()
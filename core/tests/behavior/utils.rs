@@ -15,20 +15,95 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::env;
+use std::fs;
 use std::mem;
+use std::path::Path;
+use std::path::PathBuf;
 use std::sync::Mutex;
+use std::sync::OnceLock;
 
 use futures::Future;
 use libtest_mimic::Failed;
 use libtest_mimic::Trial;
+use opendal::raw::tests::seed_test_rng;
+use opendal::raw::tests::test_rng;
+use opendal::raw::tests::HttpFixtureFetcher;
+use opendal::raw::tests::HttpFixtureMode;
 use opendal::raw::tests::TEST_RUNTIME;
 use opendal::raw::*;
 use opendal::*;
 use rand::distributions::uniform::SampleRange;
 use rand::prelude::*;
 
+/// The http fixture fetcher shared by all test cases if enabled.
+static HTTP_FIXTURE: OnceLock<HttpFixtureFetcher> = OnceLock::new();
+
+/// Config keys stored along with http fixtures, so that requests can be
+/// replayed with the same paths. Credentials must never be listed here.
+const HTTP_FIXTURE_CONFIG_KEYS: &[&str] = &["root", "bucket", "container", "endpoint", "region"];
+
+/// Build the http client that records or replays http fixtures.
+///
+/// Fixtures are enabled if `OPENDAL_TEST_HTTP_FIXTURE` is `record` or `replay`,
+/// and stored in `OPENDAL_TEST_HTTP_FIXTURE_DIR`, default to
+/// `fixtures/http/{scheme}` under the crate root.
+///
+/// The non-secret config used while recording is saved in `{dir}/config.env`
+/// and loaded while replaying, only credentials need to be provided (with
+/// any value) to replay fixtures.
+pub fn init_http_fixture_client() -> anyhow::Result<Option<HttpClient>> {
+    let _ = dotenvy::dotenv();
+
+    let Ok(mode) = env::var("OPENDAL_TEST_HTTP_FIXTURE") else {
+        return Ok(None);
+    };
+    let mode: HttpFixtureMode = mode.parse()?;
+    let scheme = env::var("OPENDAL_TEST").unwrap_or_default();
+    let dir = env::var("OPENDAL_TEST_HTTP_FIXTURE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("fixtures/http")
+                .join(&scheme)
+        });
+
+    let fetcher = HttpFixtureFetcher::new(mode, &dir)?;
+    let config = dir.join("config.env");
+    match mode {
+        HttpFixtureMode::Record => {
+            let content: String = HTTP_FIXTURE_CONFIG_KEYS
+                .iter()
+                .map(|key| format!("OPENDAL_{scheme}_{key}").to_uppercase())
+                .filter_map(|key| Some(format!("{key}={}\n", env::var(&key).ok()?)))
+                .collect();
+            fs::write(&config, content)?;
+        }
+        // Existing env will not be overridden.
+        HttpFixtureMode::Replay => {
+            dotenvy::from_path(&config).map_err(|err| {
+                anyhow::anyhow!("load http fixture config {}: {err}", config.display())
+            })?;
+        }
+    }
+
+    let _ = HTTP_FIXTURE.set(fetcher.clone());
+    Ok(Some(HttpClient::with(fetcher)))
+}
+
+/// Assign the following http fixtures to given test case, or to no test
+/// case if `None`.
+pub fn set_http_fixture_test(name: Option<&str>) {
+    if let Some(fetcher) = HTTP_FIXTURE.get() {
+        match name {
+            Some(name) => fetcher.start_test(name),
+            None => fetcher.finish_test(),
+        }
+    }
+}
+
 pub fn gen_bytes_with_range(range: impl SampleRange<usize>) -> (Vec<u8>, usize) {
-    let mut rng = thread_rng();
+    let mut rng = test_rng();
 
     let size = rng.gen_range(range);
     let mut content = vec![0; size];
@@ -48,8 +123,13 @@ pub fn gen_fixed_bytes(size: usize) -> Vec<u8> {
     content
 }
 
+/// Generate a new uuid, which is deterministic while using http fixture.
+pub fn gen_uuid() -> uuid::Uuid {
+    uuid::Builder::from_random_bytes(test_rng().gen()).into_uuid()
+}
+
 pub fn gen_offset_length(size: usize) -> (u64, u64) {
-    let mut rng = thread_rng();
+    let mut rng = test_rng();
 
     // Make sure at least one byte is read.
     let offset = rng.gen_range(0..size - 1);
@@ -67,7 +147,11 @@ where
     let handle = TEST_RUNTIME.handle().clone();
     let op = op.clone();
 
-    Trial::test(format!("behavior::{name}"), move || {
+    let test = name.to_string();
+    let name = format!("behavior::{name}");
+    Trial::test(name.clone(), move || {
+        seed_test_rng(&name);
+        set_http_fixture_test(Some(&test));
         handle
             .block_on(f(op))
             .map_err(|err| Failed::from(err.to_string()))
@@ -90,7 +174,11 @@ where
 {
     let op = op.blocking();
 
-    Trial::test(format!("behavior::{name}"), move || {
+    let test = name.to_string();
+    let name = format!("behavior::{name}");
+    Trial::test(name.clone(), move || {
+        seed_test_rng(&name);
+        set_http_fixture_test(Some(&test));
         f(op).map_err(|err| Failed::from(err.to_string()))
     })
 }
@@ -124,7 +212,7 @@ impl Fixture {
 
     /// Create a new dir path
     pub fn new_dir_path(&self) -> String {
-        let path = format!("{}/", uuid::Uuid::new_v4());
+        let path = format!("{}/", gen_uuid());
         self.paths.lock().unwrap().push(path.clone());
 
         path
@@ -132,7 +220,7 @@ impl Fixture {
 
     /// Create a new file path
     pub fn new_file_path(&self) -> String {
-        let path = format!("{}", uuid::Uuid::new_v4());
+        let path = format!("{}", gen_uuid());
         self.paths.lock().unwrap().push(path.clone());
 
         path
@@ -147,7 +235,7 @@ impl Fixture {
             .write_total_max_size
             .unwrap_or(4 * 1024 * 1024);
 
-        self.new_file_with_range(uuid::Uuid::new_v4().to_string(), 1..max_size)
+        self.new_file_with_range(gen_uuid().to_string(), 1..max_size)
     }
 
     pub fn new_file_with_path(
@@ -174,7 +262,7 @@ impl Fixture {
         let path = path.into();
         self.paths.lock().unwrap().push(path.clone());
 
-        let mut rng = thread_rng();
+        let mut rng = test_rng();
 
         let size = rng.gen_range(range);
         let mut content = vec![0; size];
@@ -0,0 +1,66 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use dav_server::fs::DavDirEntry;
+use dav_server::fs::DavMetaData;
+use dav_server::fs::FsFuture;
+use futures::FutureExt;
+use opendal::Entry;
+use opendal::MetadataField;
+use opendal::Operator;
+
+use crate::metadata::OpendalMetaData;
+use crate::utils::convert_error;
+
+/// OpendalDirEntry implements `DavDirEntry` on top of OpenDAL's [`Entry`].
+///
+/// Metadata missing from the list response will be fetched on demand.
+#[derive(Debug)]
+pub struct OpendalDirEntry {
+    op: Operator,
+    entry: Entry,
+}
+
+impl OpendalDirEntry {
+    /// Create a new dav dir entry.
+    pub fn new(op: Operator, entry: Entry) -> Self {
+        Self { op, entry }
+    }
+}
+
+impl DavDirEntry for OpendalDirEntry {
+    fn name(&self) -> Vec<u8> {
+        self.entry.name().trim_end_matches('/').as_bytes().to_vec()
+    }
+
+    fn metadata(&self) -> FsFuture<'_, Box<dyn DavMetaData>> {
+        async move {
+            // Dirs don't have content, so there is no need to stat them.
+            let fields: &[MetadataField] = if self.entry.metadata().is_dir() {
+                &[]
+            } else {
+                &[MetadataField::ContentLength, MetadataField::LastModified]
+            };
+            self.op
+                .metadata(&self.entry, fields)
+                .await
+                .map(|meta| Box::new(OpendalMetaData::new(meta)) as Box<dyn DavMetaData>)
+                .map_err(convert_error)
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::SeekFrom;
+
+use bytes::Buf;
+use bytes::Bytes;
+use dav_server::fs::DavFile;
+use dav_server::fs::DavMetaData;
+use dav_server::fs::FsError;
+use dav_server::fs::FsFuture;
+use futures::FutureExt;
+use opendal::Operator;
+use opendal::Reader;
+use opendal::Writer;
+
+use crate::metadata::OpendalMetaData;
+use crate::utils::convert_error;
+
+/// OpendalFile implements `DavFile` on top of OpenDAL's [`Reader`] and [`Writer`].
+///
+/// - Files opened for read support `seek` to any position.
+/// - Files opened for write only support sequential writes, the content will be
+///   committed on `flush`.
+pub struct OpendalFile {
+    op: Operator,
+    path: String,
+    state: State,
+    pos: u64,
+}
+
+enum State {
+    Read { reader: Reader, size: u64 },
+    Write { writer: Option<Writer> },
+}
+
+impl OpendalFile {
+    pub(crate) fn new_reader(op: Operator, path: String, reader: Reader, size: u64) -> Self {
+        Self {
+            op,
+            path,
+            state: State::Read { reader, size },
+            pos: 0,
+        }
+    }
+
+    pub(crate) fn new_writer(op: Operator, path: String, writer: Writer) -> Self {
+        Self {
+            op,
+            path,
+            state: State::Write {
+                writer: Some(writer),
+            },
+            pos: 0,
+        }
+    }
+
+    async fn write(&mut self, bs: Bytes) -> Result<(), FsError> {
+        let State::Write { writer } = &mut self.state else {
+            return Err(FsError::Forbidden);
+        };
+        let Some(writer) = writer.as_mut() else {
+            return Err(FsError::GeneralFailure);
+        };
+
+        let size = bs.len() as u64;
+        writer.write(bs).await.map_err(convert_error)?;
+        self.pos += size;
+        Ok(())
+    }
+}
+
+impl Debug for OpendalFile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpendalFile")
+            .field("path", &self.path)
+            .field("pos", &self.pos)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DavFile for OpendalFile {
+    fn metadata(&mut self) -> FsFuture<'_, Box<dyn DavMetaData>> {
+        async move {
+            self.op
+                .stat(&self.path)
+                .await
+                .map(|meta| Box::new(OpendalMetaData::new(meta)) as Box<dyn DavMetaData>)
+                .map_err(convert_error)
+        }
+        .boxed()
+    }
+
+    fn write_buf(&mut self, mut buf: Box<dyn Buf + Send>) -> FsFuture<'_, ()> {
+        async move {
+            let bs = buf.copy_to_bytes(buf.remaining());
+            self.write(bs).await
+        }
+        .boxed()
+    }
+
+    fn write_bytes(&mut self, buf: Bytes) -> FsFuture<'_, ()> {
+        async move { self.write(buf).await }.boxed()
+    }
+
+    fn read_bytes(&mut self, count: usize) -> FsFuture<'_, Bytes> {
+        async move {
+            let State::Read { reader, size } = &self.state else {
+                return Err(FsError::Forbidden);
+            };
+
+            let end = (self.pos + count as u64).min(*size);
+            if self.pos >= end {
+                return Ok(Bytes::new());
+            }
+            let bs = reader
+                .read(self.pos..end)
+                .await
+                .map_err(convert_error)?
+                .to_bytes();
+            self.pos += bs.len() as u64;
+            Ok(bs)
+        }
+        .boxed()
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> FsFuture<'_, u64> {
+        async move {
+            let new_pos = match (&self.state, pos) {
+                (_, SeekFrom::Start(n)) => Some(n),
+                (_, SeekFrom::Current(n)) => self.pos.checked_add_signed(n),
+                (State::Read { size, .. }, SeekFrom::End(n)) => size.checked_add_signed(n),
+                (State::Write { .. }, SeekFrom::End(_)) => None,
+            };
+            let new_pos = new_pos.ok_or(FsError::GeneralFailure)?;
+
+            // Writer can't move the position since it only supports sequential writes.
+            if matches!(self.state, State::Write { .. }) && new_pos != self.pos {
+                return Err(FsError::NotImplemented);
+            }
+
+            self.pos = new_pos;
+            Ok(new_pos)
+        }
+        .boxed()
+    }
+
+    fn flush(&mut self) -> FsFuture<'_, ()> {
+        async move {
+            if let State::Write { writer } = &mut self.state {
+                if let Some(mut w) = writer.take() {
+                    w.close().await.map_err(convert_error)?;
+                }
+            }
+            Ok(())
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,264 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use dav_server::davpath::DavPath;
+use dav_server::fs::DavDirEntry;
+use dav_server::fs::DavFile;
+use dav_server::fs::DavFileSystem;
+use dav_server::fs::DavMetaData;
+use dav_server::fs::FsError;
+use dav_server::fs::FsFuture;
+use dav_server::fs::FsStream;
+use dav_server::fs::OpenOptions;
+use dav_server::fs::ReadDirMeta;
+use futures::FutureExt;
+use futures::StreamExt;
+use opendal::ErrorKind;
+use opendal::Operator;
+
+use crate::dir::OpendalDirEntry;
+use crate::file::OpendalFile;
+use crate::metadata::OpendalMetaData;
+use crate::utils::convert_error;
+use crate::utils::convert_path;
+
+/// OpendalFs implements `DavFileSystem` on top of OpenDAL's [`Operator`].
+///
+/// # Example
+///
+/// ```no_run
+/// use dav_server::DavHandler;
+/// use dav_server_opendalfs::OpendalFs;
+/// use opendal::services::Memory;
+/// use opendal::Operator;
+///
+/// # fn main() -> opendal::Result<()> {
+/// let op = Operator::new(Memory::default())?.finish();
+///
+/// let handler = DavHandler::builder()
+///     .filesystem(OpendalFs::new(op))
+///     .build_handler();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct OpendalFs {
+    op: Operator,
+}
+
+impl OpendalFs {
+    /// Create a new dav file system backed by given operator.
+    pub fn new(op: Operator) -> Box<OpendalFs> {
+        Box::new(OpendalFs { op })
+    }
+
+    /// Stat the given path, fallback to dir if file is not found.
+    ///
+    /// dav clients could stat a dir without the trailing `/`.
+    async fn stat(&self, path: &str) -> Result<opendal::Metadata, FsError> {
+        match self.op.stat(path).await {
+            Ok(meta) => Ok(meta),
+            Err(err) if err.kind() == ErrorKind::NotFound && !path.ends_with('/') => self
+                .op
+                .stat(&format!("{path}/"))
+                .await
+                .map_err(convert_error),
+            Err(err) => Err(convert_error(err)),
+        }
+    }
+}
+
+impl DavFileSystem for OpendalFs {
+    fn open<'a>(
+        &'a self,
+        path: &'a DavPath,
+        options: OpenOptions,
+    ) -> FsFuture<'a, Box<dyn DavFile>> {
+        async move {
+            let path = convert_path(path);
+
+            if !options.write {
+                let meta = self.stat(&path).await?;
+                if meta.is_dir() {
+                    return Err(FsError::Forbidden);
+                }
+                let reader = self.op.reader(&path).await.map_err(convert_error)?;
+                let file =
+                    OpendalFile::new_reader(self.op.clone(), path, reader, meta.content_length());
+                return Ok(Box::new(file) as Box<dyn DavFile>);
+            }
+
+            if options.create_new && self.op.exists(&path).await.map_err(convert_error)? {
+                return Err(FsError::Exists);
+            }
+            if options.append && !self.op.info().full_capability().write_can_append {
+                return Err(FsError::NotImplemented);
+            }
+
+            let writer = self
+                .op
+                .writer_with(&path)
+                .append(options.append)
+                .await
+                .map_err(convert_error)?;
+            Ok(
+                Box::new(OpendalFile::new_writer(self.op.clone(), path, writer))
+                    as Box<dyn DavFile>,
+            )
+        }
+        .boxed()
+    }
+
+    fn read_dir<'a>(
+        &'a self,
+        path: &'a DavPath,
+        _meta: ReadDirMeta,
+    ) -> FsFuture<'a, FsStream<Box<dyn DavDirEntry>>> {
+        async move {
+            let mut path = convert_path(path);
+            if !path.ends_with('/') {
+                path.push('/');
+            }
+
+            let op = self.op.clone();
+            let lister = self.op.lister(&path).await.map_err(convert_error)?;
+            let stream = lister.filter_map(move |entry| {
+                let op = op.clone();
+                let path = path.clone();
+                async move {
+                    match entry {
+                        // The dir itself could be returned by list.
+                        Ok(entry) if entry.path() == path => None,
+                        Ok(entry) => Some(Ok(
+                            Box::new(OpendalDirEntry::new(op, entry)) as Box<dyn DavDirEntry>
+                        )),
+                        Err(err) => Some(Err(convert_error(err))),
+                    }
+                }
+            });
+            Ok(Box::pin(stream) as FsStream<Box<dyn DavDirEntry>>)
+        }
+        .boxed()
+    }
+
+    fn metadata<'a>(&'a self, path: &'a DavPath) -> FsFuture<'a, Box<dyn DavMetaData>> {
+        async move {
+            let meta = self.stat(&convert_path(path)).await?;
+            Ok(Box::new(OpendalMetaData::new(meta)) as Box<dyn DavMetaData>)
+        }
+        .boxed()
+    }
+
+    fn create_dir<'a>(&'a self, path: &'a DavPath) -> FsFuture<'a, ()> {
+        async move {
+            let mut path = convert_path(path);
+            if !path.ends_with('/') {
+                path.push('/');
+            }
+            if self.op.exists(&path).await.map_err(convert_error)? {
+                return Err(FsError::Exists);
+            }
+            self.op.create_dir(&path).await.map_err(convert_error)
+        }
+        .boxed()
+    }
+
+    fn remove_dir<'a>(&'a self, path: &'a DavPath) -> FsFuture<'a, ()> {
+        async move {
+            let mut path = convert_path(path);
+            if !path.ends_with('/') {
+                path.push('/');
+            }
+            self.op.delete(&path).await.map_err(convert_error)
+        }
+        .boxed()
+    }
+
+    fn remove_file<'a>(&'a self, path: &'a DavPath) -> FsFuture<'a, ()> {
+        async move {
+            self.op
+                .delete(&convert_path(path))
+                .await
+                .map_err(convert_error)
+        }
+        .boxed()
+    }
+
+    fn rename<'a>(&'a self, from: &'a DavPath, to: &'a DavPath) -> FsFuture<'a, ()> {
+        async move {
+            self.op
+                .rename(&convert_path(from), &convert_path(to))
+                .await
+                .map_err(convert_error)
+        }
+        .boxed()
+    }
+
+    fn copy<'a>(&'a self, from: &'a DavPath, to: &'a DavPath) -> FsFuture<'a, ()> {
+        async move {
+            self.op
+                .copy(&convert_path(from), &convert_path(to))
+                .await
+                .map_err(convert_error)
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::SeekFrom;
+
+    use futures::TryStreamExt;
+    use opendal::services::Memory;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_opendal_fs() {
+        let op = Operator::new(Memory::default()).unwrap().finish();
+        let fs = OpendalFs::new(op);
+
+        let path = DavPath::new("/dir/").unwrap();
+        fs.create_dir(&path).await.unwrap();
+
+        let path = DavPath::new("/dir/test.txt").unwrap();
+        let mut file = fs.open(&path, OpenOptions::write()).await.unwrap();
+        file.write_bytes("Hello, World!".into()).await.unwrap();
+        file.flush().await.unwrap();
+
+        let meta = fs.metadata(&path).await.unwrap();
+        assert!(!meta.is_dir());
+        assert_eq!(meta.len(), 13);
+
+        let mut file = fs.open(&path, OpenOptions::read()).await.unwrap();
+        file.seek(SeekFrom::Start(7)).await.unwrap();
+        assert_eq!(file.read_bytes(1024).await.unwrap(), "World!");
+
+        let dir = DavPath::new("/dir").unwrap();
+        assert!(fs.metadata(&dir).await.unwrap().is_dir());
+        let entries: Vec<_> = fs
+            .read_dir(&dir, ReadDirMeta::None)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let names: Vec<_> = entries.iter().map(|e| e.name()).collect();
+        assert_eq!(names, vec![b"test.txt".to_vec()]);
+    }
+}
@@ -0,0 +1,51 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! dav-server-opendalfs is a [dav-server-rs](https://github.com/messense/dav-server-rs) integration
+//! of Apache OpenDAL, it allows users to serve any storage service supported by OpenDAL over WebDAV.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use dav_server::DavHandler;
+//! use dav_server_opendalfs::OpendalFs;
+//! use opendal::services::Memory;
+//! use opendal::Operator;
+//!
+//! # fn main() -> opendal::Result<()> {
+//! let op = Operator::new(Memory::default())?.finish();
+//!
+//! let handler = DavHandler::builder()
+//!     .filesystem(OpendalFs::new(op))
+//!     .locksystem(dav_server::fakels::FakeLs::new())
+//!     .build_handler();
+//! # Ok(())
+//! # }
+//! ```
+
+#![deny(missing_docs)]
+
+mod dir;
+mod file;
+mod fs;
+mod metadata;
+mod utils;
+
+pub use dir::OpendalDirEntry;
+pub use file::OpendalFile;
+pub use fs::OpendalFs;
+pub use metadata::OpendalMetaData;
@@ -0,0 +1,50 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use dav_server::davpath::DavPath;
+use dav_server::fs::FsError;
+
+/// Convert the given dav path into the path used by OpenDAL.
+///
+/// Dir paths (collections) will always end with `/`.
+pub fn convert_path(path: &DavPath) -> String {
+    let mut p = path
+        .as_rel_ospath()
+        .to_string_lossy()
+        .replace(std::path::MAIN_SEPARATOR, "/");
+
+    if p.is_empty() {
+        return "/".to_string();
+    }
+    if path.is_collection() && !p.ends_with('/') {
+        p.push('/');
+    }
+    p
+}
+
+/// Convert the error returned by OpenDAL into `FsError`.
+pub fn convert_error(err: opendal::Error) -> FsError {
+    match err.kind() {
+        opendal::ErrorKind::NotFound => FsError::NotFound,
+        opendal::ErrorKind::AlreadyExists | opendal::ErrorKind::ConditionNotMatch => {
+            FsError::Exists
+        }
+        opendal::ErrorKind::PermissionDenied => FsError::Forbidden,
+        opendal::ErrorKind::Unsupported => FsError::NotImplemented,
+        _ => FsError::GeneralFailure,
+    }
+}
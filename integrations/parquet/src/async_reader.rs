@@ -0,0 +1,194 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use opendal::Reader;
+use parquet::arrow::async_reader::AsyncFileReader;
+use parquet::arrow::async_reader::MetadataLoader;
+use parquet::errors::ParquetError;
+use parquet::errors::Result;
+use parquet::file::metadata::ParquetMetaData;
+
+/// The default size to prefetch for the footer, which is large enough to
+/// contain the metadata of most files so it can be fetched in one request.
+const DEFAULT_PREFETCH_FOOTER_SIZE: usize = 512 * 1024;
+
+/// AsyncReader implements [`AsyncFileReader`] on top of OpenDAL's [`Reader`].
+///
+/// - Ranges requested by `get_byte_ranges` are coalesced by [`Reader::fetch`],
+///   users can tune the gap by `reader_with(path).gap(size)`.
+/// - The footer is prefetched in one request, and the decoded metadata is cached
+///   so later calls of `get_metadata` won't touch the storage again.
+pub struct AsyncReader {
+    inner: Reader,
+    content_length: u64,
+
+    prefetch_footer_size: usize,
+    preload_column_index: bool,
+    preload_offset_index: bool,
+    metadata: Option<Arc<ParquetMetaData>>,
+}
+
+impl AsyncReader {
+    /// Create a new async reader with given reader and the size of the file.
+    ///
+    /// Users can fetch the size by `op.stat(path).await?.content_length()`.
+    pub fn new(reader: Reader, content_length: u64) -> Self {
+        Self {
+            inner: reader,
+            content_length,
+
+            prefetch_footer_size: DEFAULT_PREFETCH_FOOTER_SIZE,
+            preload_column_index: false,
+            preload_offset_index: false,
+            metadata: None,
+        }
+    }
+
+    /// Set the size to prefetch for the footer.
+    ///
+    /// Default to 512KiB.
+    pub fn with_prefetch_footer_size(mut self, size: usize) -> Self {
+        self.prefetch_footer_size = size;
+        self
+    }
+
+    /// Load the column index while loading metadata.
+    pub fn with_preload_column_index(mut self, preload: bool) -> Self {
+        self.preload_column_index = preload;
+        self
+    }
+
+    /// Load the offset index while loading metadata.
+    pub fn with_preload_offset_index(mut self, preload: bool) -> Self {
+        self.preload_offset_index = preload;
+        self
+    }
+
+    /// Use the given metadata instead of loading it from storage.
+    ///
+    /// This is useful to share the cached metadata between readers of the same file.
+    pub fn with_metadata(mut self, metadata: Arc<ParquetMetaData>) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+}
+
+fn new_parquet_error(err: opendal::Error) -> ParquetError {
+    ParquetError::External(Box::new(err))
+}
+
+impl AsyncFileReader for AsyncReader {
+    fn get_bytes(&mut self, range: Range<usize>) -> BoxFuture<'_, Result<Bytes>> {
+        async move {
+            self.inner
+                .read(range.start as u64..range.end as u64)
+                .await
+                .map(|buf| buf.to_bytes())
+                .map_err(new_parquet_error)
+        }
+        .boxed()
+    }
+
+    fn get_byte_ranges(&mut self, ranges: Vec<Range<usize>>) -> BoxFuture<'_, Result<Vec<Bytes>>> {
+        async move {
+            if ranges.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let ranges = ranges
+                .into_iter()
+                .map(|range| range.start as u64..range.end as u64)
+                .collect();
+            let bufs = self.inner.fetch(ranges).await.map_err(new_parquet_error)?;
+            Ok(bufs.into_iter().map(|buf| buf.to_bytes()).collect())
+        }
+        .boxed()
+    }
+
+    fn get_metadata(&mut self) -> BoxFuture<'_, Result<Arc<ParquetMetaData>>> {
+        async move {
+            if let Some(metadata) = &self.metadata {
+                return Ok(metadata.clone());
+            }
+
+            let file_size = self.content_length as usize;
+            let prefetch = Some(self.prefetch_footer_size);
+            let (column_index, offset_index) =
+                (self.preload_column_index, self.preload_offset_index);
+
+            let mut loader = MetadataLoader::load(&mut *self, file_size, prefetch).await?;
+            loader.load_page_index(column_index, offset_index).await?;
+            let metadata = Arc::new(loader.finish());
+
+            self.metadata = Some(metadata.clone());
+            Ok(metadata)
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::ArrayRef;
+    use arrow_array::Int64Array;
+    use arrow_array::RecordBatch;
+    use futures::TryStreamExt;
+    use opendal::services::Memory;
+    use opendal::Operator;
+    use parquet::arrow::ArrowWriter;
+    use parquet::arrow::ParquetRecordBatchStreamBuilder;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_async_reader() {
+        let op = Operator::new(Memory::default()).unwrap().finish();
+        let path = "test.parquet";
+
+        let col = Arc::new(Int64Array::from_iter_values(0..1024)) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("col", col)]).unwrap();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        op.write(path, buf).await.unwrap();
+
+        let size = op.stat(path).await.unwrap().content_length();
+        let reader = op.reader(path).await.unwrap();
+        let mut reader = AsyncReader::new(reader, size).with_prefetch_footer_size(16);
+
+        let metadata = reader.get_metadata().await.unwrap();
+        assert_eq!(metadata.file_metadata().num_rows(), 1024);
+
+        let reader = reader.with_metadata(metadata);
+        let stream = ParquetRecordBatchStreamBuilder::new(reader)
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches, vec![batch]);
+    }
+}
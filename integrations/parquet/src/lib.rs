@@ -0,0 +1,47 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! parquet-opendal provides parquet IO utils backed by Apache OpenDAL.
+//!
+//! [`AsyncReader`] implements [`parquet::arrow::async_reader::AsyncFileReader`] on top of
+//! [`opendal::Reader`], so users can read Parquet from any storage service supported by OpenDAL.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use futures::TryStreamExt;
+//! use opendal::Operator;
+//! use parquet::arrow::ParquetRecordBatchStreamBuilder;
+//! use parquet_opendal::AsyncReader;
+//!
+//! # async fn test(op: Operator) -> Result<(), Box<dyn std::error::Error>> {
+//! let path = "data/test.parquet";
+//! let meta = op.stat(path).await?;
+//! let reader = op.reader_with(path).concurrent(4).await?;
+//!
+//! let reader = AsyncReader::new(reader, meta.content_length());
+//! let stream = ParquetRecordBatchStreamBuilder::new(reader).await?.build()?;
+//! let batches: Vec<_> = stream.try_collect().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#![deny(missing_docs)]
+
+mod async_reader;
+
+pub use async_reader::AsyncReader;